@@ -37,6 +37,7 @@ use databend_query::local;
 use databend_query::servers::admin::AdminService;
 use databend_query::servers::flight::FlightService;
 use databend_query::servers::metrics::MetricService;
+use databend_query::servers::ClickHouseHandler;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
@@ -175,6 +176,28 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         );
     }
 
+    // ClickHouse native TCP handler.
+    if conf.query.clickhouse_handler_enabled {
+        let hostname = conf.query.clickhouse_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.clickhouse_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.clickhouse_handler_tcp_keepalive_timeout_secs;
+
+        let mut handler =
+            ClickHouseHandler::create(tcp_keepalive_timeout_secs).with_context(make_error)?;
+        let listening = handler
+            .start(listening.parse().with_context(make_error)?)
+            .await
+            .with_context(make_error)?;
+        shutdown_handle.add_service("ClickHouseTcpHandler", handler);
+
+        info!(
+            "Listening for ClickHouse compatibility native protocol: {}, Usage: clickhouse-client --host {} --port {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -360,6 +383,17 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    if conf.query.clickhouse_handler_enabled {
+        println!("Clickhouse(native)");
+        println!(
+            "    listened at {}:{}",
+            conf.query.clickhouse_handler_host, conf.query.clickhouse_handler_port
+        );
+        println!(
+            "    connect via: clickhouse-client --host {} --port {} --user ${{USER}} --password ${{PASSWORD}}",
+            conf.query.clickhouse_handler_host, conf.query.clickhouse_handler_port
+        );
+    }
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
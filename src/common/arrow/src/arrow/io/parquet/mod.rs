@@ -25,6 +25,10 @@ pub use parquet2::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 
+/// Name of the extension type of UUID columns, they are `FIXED_LEN_BYTE_ARRAY(16)`
+/// with the `UUID` logical type in parquet.
+pub const UUID_EXTENSION_NAME: &str = "Uuid";
+
 impl From<parquet2::error::Error> for Error {
     fn from(error: parquet2::error::Error) -> Self {
         match error {
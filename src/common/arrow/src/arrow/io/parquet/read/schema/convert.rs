@@ -31,6 +31,7 @@ use crate::arrow::datatypes::Field;
 use crate::arrow::datatypes::IntervalUnit;
 use crate::arrow::datatypes::TimeUnit;
 use crate::arrow::io::parquet::read::schema::SchemaInferenceOptions;
+use crate::arrow::io::parquet::UUID_EXTENSION_NAME;
 
 /// Converts [`ParquetType`]s to a [`Field`], ignoring parquet fields that do not contain
 /// any physical column.
@@ -200,6 +201,11 @@ fn from_fixed_len_byte_array(
                 DataType::Decimal256(precision, scale)
             }
         }
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => DataType::Extension(
+            UUID_EXTENSION_NAME.to_string(),
+            Box::new(DataType::FixedSizeBinary(length)),
+            None,
+        ),
         (None, Some(PrimitiveConvertedType::Interval)) => {
             // There is currently no reliable way of determining which IntervalUnit
             // to return. Thus without the original Arrow schema, the results
@@ -437,6 +443,7 @@ mod tests {
     use crate::arrow::datatypes::Field;
     use crate::arrow::datatypes::TimeUnit;
     use crate::arrow::error::Result;
+    use crate::arrow::io::parquet::write::to_parquet_type;
 
     #[test]
     fn test_flat_primitives() -> Result<()> {
@@ -484,6 +491,7 @@ mod tests {
             REQUIRED FIXED_LEN_BYTE_ARRAY (20) fixed_binary;
             REQUIRED FIXED_LEN_BYTE_ARRAY (7) decimal_128 (Decimal(16, 2)) ;
             REQUIRED FIXED_LEN_BYTE_ARRAY (32) decimal_256 (Decimal(44, 2)) ;
+            OPTIONAL FIXED_LEN_BYTE_ARRAY (16) uuid (UUID);
         }
         ";
         let expected = vec![
@@ -491,6 +499,15 @@ mod tests {
             Field::new("fixed_binary", DataType::FixedSizeBinary(20), false),
             Field::new("decimal_128", DataType::Decimal(16, 2), false),
             Field::new("decimal_256", DataType::Decimal256(44, 2), false),
+            Field::new(
+                "uuid",
+                DataType::Extension(
+                    UUID_EXTENSION_NAME.to_string(),
+                    Box::new(DataType::FixedSizeBinary(16)),
+                    None,
+                ),
+                true,
+            ),
         ];
 
         let parquet_schema = SchemaDescriptor::try_from_message(message)?;
@@ -1126,4 +1143,29 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_uuid_round_trip() -> Result<()> {
+        let field = Field::new(
+            "uuid",
+            DataType::Extension(
+                UUID_EXTENSION_NAME.to_string(),
+                Box::new(DataType::FixedSizeBinary(16)),
+                None,
+            ),
+            false,
+        );
+        let parquet_type = to_parquet_type(&field)?;
+        assert_eq!(
+            parquet_type,
+            SchemaDescriptor::try_from_message(
+                "message schema { REQUIRED FIXED_LEN_BYTE_ARRAY (16) uuid (UUID); }"
+            )?
+            .fields()[0]
+        );
+
+        let fields = parquet_to_arrow_schema(&[parquet_type]);
+        assert_eq!(fields, vec![field]);
+        Ok(())
+    }
 }
@@ -27,6 +27,7 @@ use parquet2::schema::types::TimeUnit as ParquetTimeUnit;
 use parquet2::schema::Repetition;
 
 use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::UUID_EXTENSION_NAME;
 use crate::arrow::datatypes::DataType;
 use crate::arrow::datatypes::Field;
 use crate::arrow::datatypes::Schema;
@@ -64,6 +65,19 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
     } else {
         Repetition::Required
     };
+    // UUID is an extension type, annotate it before the extension is dropped
+    if let DataType::Extension(extension, inner, _) = field.data_type() {
+        if extension == UUID_EXTENSION_NAME && **inner == DataType::FixedSizeBinary(16) {
+            return Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::FixedLenByteArray(16),
+                repetition,
+                None,
+                Some(PrimitiveLogicalType::Uuid),
+                None,
+            )?);
+        }
+    }
     // create type from field
     match field.data_type().to_logical_type() {
         DataType::Null => Ok(ParquetType::try_from_primitive(
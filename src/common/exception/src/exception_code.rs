@@ -256,6 +256,12 @@ build_exceptions! {
     RoleAlreadyExists(2216),
     IllegalRole(2217),
     IllegalUser(2218),
+    UnknownWorkloadGroup(2219),
+    WorkloadGroupAlreadyExists(2220),
+    IllegalWorkloadGroup(2221),
+    WorkloadGroupIsUsedByUser(2222),
+    UserLimitExceeded(2223),
+    WorkloadGroupIsUsedByRole(2224),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -392,6 +398,9 @@ build_exceptions! {
     UnknownProcedure(3130),
     ProcedureAlreadyExists(3131),
     IllegalProcedureFormat(3132),
+
+    // Prepared statement
+    UnknownPreparedStatement(3140),
 }
 
 // Storage errors [3001, 4000].
@@ -17,10 +17,13 @@ use std::str::FromStr;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use geo::BoundingRect;
 use geo::Geometry;
+use geo::Point;
 use geozero::wkb::Ewkb;
 use geozero::CoordDimensions;
 use geozero::GeozeroGeometry;
+use geozero::ToGeo;
 use geozero::ToJson;
 use geozero::ToWkb;
 use geozero::ToWkt;
@@ -227,3 +230,87 @@ pub fn geometry_format<T: GeometryFormatOutput>(
 ) -> Result<String> {
     geometry.format(format_type)
 }
+
+/// The axis-aligned bounding box of a geometry.
+///
+/// It is stored in the column statistics of fuse blocks as two points,
+/// the lower-left corner as `min` and the upper-right corner as `max`,
+/// and is used to prune blocks and candidate pairs of spatial predicates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl BoundingBox {
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    pub fn merge(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Grow the box by `distance` in every direction.
+    pub fn expand(&self, distance: f64) -> BoundingBox {
+        BoundingBox {
+            min_x: self.min_x - distance,
+            min_y: self.min_y - distance,
+            max_x: self.max_x + distance,
+            max_y: self.max_y + distance,
+        }
+    }
+
+    /// Encode the box as the EWKB of its lower-left and upper-right corners.
+    pub fn to_ewkb_points(&self) -> Result<(Vec<u8>, Vec<u8>)> {
+        let min = Geometry::Point(Point::new(self.min_x, self.min_y))
+            .to_ewkb(CoordDimensions::xy(), None)
+            .map_err(ErrorCode::from)?;
+        let max = Geometry::Point(Point::new(self.max_x, self.max_y))
+            .to_ewkb(CoordDimensions::xy(), None)
+            .map_err(ErrorCode::from)?;
+        Ok((min, max))
+    }
+
+    /// Decode the box from the EWKB of its lower-left and upper-right corners.
+    pub fn from_ewkb_points(min: &[u8], max: &[u8]) -> Result<BoundingBox> {
+        let to_point = |buf: &[u8]| match Ewkb(buf).to_geo() {
+            Ok(Geometry::Point(point)) => Ok(point),
+            Ok(_) => Err(ErrorCode::GeometryError(
+                "bounding box corner must be a point",
+            )),
+            Err(e) => Err(ErrorCode::GeometryError(e.to_string())),
+        };
+        let min = to_point(min)?;
+        let max = to_point(max)?;
+        Ok(BoundingBox {
+            min_x: min.x(),
+            min_y: min.y(),
+            max_x: max.x(),
+            max_y: max.y(),
+        })
+    }
+}
+
+/// Compute the bounding box of an EWKB geometry, returns `None` for empty geometries.
+pub fn geometry_bbox(ewkb: &[u8]) -> Result<Option<BoundingBox>> {
+    let geometry = Ewkb(ewkb)
+        .to_geo()
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
+    Ok(geometry.bounding_rect().map(|rect| BoundingBox {
+        min_x: rect.min().x,
+        min_y: rect.min().y,
+        max_x: rect.max().x,
+        max_y: rect.max().y,
+    }))
+}
@@ -51,10 +51,12 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::geometry_bbox;
 pub use geometry::geometry_format;
 pub use geometry::parse_bytes_to_ewkb;
 pub use geometry::parse_to_ewkb;
 pub use geometry::parse_to_subtype;
 pub use geometry::Axis;
+pub use geometry::BoundingBox;
 pub use geometry::Extremum;
 pub use geometry::GeometryDataType;
@@ -61,7 +61,8 @@ impl ColumnNodes {
         init: Vec<InitNested>,
         leaf_id: &mut usize,
     ) -> ColumnNode {
-        match &field.data_type {
+        // Extension types such as `Vector` may wrap a nested arrow type.
+        match field.data_type.to_logical_type() {
             ArrowType::Struct(inner_fields) => {
                 let mut child_column_nodes = Vec::with_capacity(inner_fields.len());
                 let mut child_leaf_ids = Vec::with_capacity(inner_fields.len());
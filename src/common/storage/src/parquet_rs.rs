@@ -15,13 +15,16 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Schema as ArrowSchema;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::EXTENSION_KEY;
+use databend_common_expression::converts::arrow2::ARROW_EXT_TYPE_UUID;
 use databend_common_expression::FieldIndex;
 use opendal::Operator;
 use parquet::arrow::parquet_to_arrow_schema;
+use parquet::basic::LogicalType;
 use parquet::file::footer::decode_footer;
 use parquet::file::footer::decode_metadata;
 use parquet::file::metadata::FileMetaData;
@@ -81,6 +84,26 @@ pub fn infer_schema_with_extension(meta: &FileMetaData) -> Result<ArrowSchema> {
         }
         arrow_schema = ArrowSchema::new_with_metadata(new_fields, arrow_schema.metadata);
     }
+    // Files written by other tools have no extension meta for UUID,
+    // use the `UUID` logical type of `FIXED_LEN_BYTE_ARRAY(16)` instead.
+    let new_fields = arrow_schema
+        .fields
+        .iter()
+        .zip(meta.schema_descr().root_schema().get_fields())
+        .map(|(field, parquet_type)| {
+            if field.data_type() == &ArrowDataType::FixedSizeBinary(16)
+                && parquet_type.get_basic_info().logical_type() == Some(LogicalType::Uuid)
+                && !field.metadata().contains_key(EXTENSION_KEY)
+            {
+                let mut metadata = field.metadata().clone();
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_UUID.to_string());
+                Arc::new(field.as_ref().clone().with_metadata(metadata))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    arrow_schema = ArrowSchema::new_with_metadata(new_fields, arrow_schema.metadata);
 
     Ok(arrow_schema)
 }
@@ -29,6 +29,7 @@ use logforth::Dispatch;
 use logforth::Logger;
 use opentelemetry_otlp::WithExportConfig;
 
+use crate::config::OTLPEndpointConfig;
 use crate::config::OTLPProtocol;
 use crate::loggers::get_layout;
 use crate::loggers::new_rolling_file_appender;
@@ -95,7 +96,6 @@ pub fn init_logging(
 
     // initialize tracing reporter
     if cfg.tracing.on {
-        let endpoint = cfg.tracing.otlp.endpoint.clone();
         let mut kvs = cfg
             .tracing
             .otlp
@@ -110,26 +110,7 @@ pub fn init_logging(
         for (k, v) in &labels {
             kvs.push(opentelemetry::KeyValue::new(k.to_string(), v.to_string()));
         }
-        let exporter = match cfg.tracing.otlp.protocol {
-            OTLPProtocol::Grpc => opentelemetry_otlp::new_exporter()
-                .tonic()
-                .with_endpoint(endpoint)
-                .with_protocol(opentelemetry_otlp::Protocol::Grpc)
-                .with_timeout(Duration::from_secs(
-                    opentelemetry_otlp::OTEL_EXPORTER_OTLP_TIMEOUT_DEFAULT,
-                ))
-                .build_span_exporter()
-                .expect("initialize oltp grpc exporter"),
-            OTLPProtocol::Http => opentelemetry_otlp::new_exporter()
-                .http()
-                .with_endpoint(endpoint)
-                .with_protocol(opentelemetry_otlp::Protocol::HttpBinary)
-                .with_timeout(Duration::from_secs(
-                    opentelemetry_otlp::OTEL_EXPORTER_OTLP_TIMEOUT_DEFAULT,
-                ))
-                .build_span_exporter()
-                .expect("initialize oltp http exporter"),
-        };
+        let exporter = new_otlp_span_exporter(&cfg.tracing.otlp);
         let (reporter_rt, otlp_reporter) = Thread::spawn(|| {
             // init runtime with 2 threads
             let rt = tokio::runtime::Builder::new_multi_thread()
@@ -390,6 +371,43 @@ pub fn init_logging(
     _drop_guards
 }
 
+/// Creates the span exporter that ships traces to the OTLP collector.
+///
+/// Like the log exporters, the http endpoint is the collector's base address and the
+/// signal path is appended here, since the exporter uses an explicit endpoint as is.
+/// An endpoint which is already the full traces url is used as is.
+fn new_otlp_span_exporter(cfg: &OTLPEndpointConfig) -> opentelemetry_otlp::SpanExporter {
+    match cfg.protocol {
+        OTLPProtocol::Grpc => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(cfg.endpoint.clone())
+            .with_protocol(opentelemetry_otlp::Protocol::Grpc)
+            .with_timeout(Duration::from_secs(
+                opentelemetry_otlp::OTEL_EXPORTER_OTLP_TIMEOUT_DEFAULT,
+            ))
+            .build_span_exporter()
+            .expect("initialize oltp grpc exporter"),
+        OTLPProtocol::Http => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(otlp_traces_http_endpoint(&cfg.endpoint))
+            .with_protocol(opentelemetry_otlp::Protocol::HttpBinary)
+            .with_timeout(Duration::from_secs(
+                opentelemetry_otlp::OTEL_EXPORTER_OTLP_TIMEOUT_DEFAULT,
+            ))
+            .build_span_exporter()
+            .expect("initialize oltp http exporter"),
+    }
+}
+
+fn otlp_traces_http_endpoint(endpoint: &str) -> String {
+    const TRACES_PATH: &str = "/v1/traces";
+    let endpoint = endpoint.trim_end_matches('/');
+    match endpoint.ends_with(TRACES_PATH) {
+        true => endpoint.to_string(),
+        false => format!("{}{}", endpoint, TRACES_PATH),
+    }
+}
+
 /// Creates a log filter that matches log entries based on specified target prefixes or severity.
 fn make_log_filter(prefix_filter: &str) -> CustomFilter {
     let prefixes = prefix_filter
@@ -421,3 +439,23 @@ fn is_severe(meta: &Metadata) -> bool {
     // For other component, output logs with level <= WARN
     meta.level() <= LevelFilter::Warn
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::init::otlp_traces_http_endpoint;
+
+    #[test]
+    fn test_otlp_traces_http_endpoint() {
+        for endpoint in [
+            "http://127.0.0.1:4318",
+            "http://127.0.0.1:4318/",
+            "http://127.0.0.1:4318/v1/traces",
+            "http://127.0.0.1:4318/v1/traces/",
+        ] {
+            assert_eq!(
+                otlp_traces_http_endpoint(endpoint),
+                "http://127.0.0.1:4318/v1/traces"
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+use databend_common_tracing::init_logging;
+use databend_common_tracing::Config;
+use databend_common_tracing::OTLPProtocol;
+use fastrace::prelude::*;
+
+struct ExportRequest {
+    request_line: String,
+    body: Vec<u8>,
+}
+
+/// A stand-in OTLP/HTTP collector: accepts every export request, answers it with an
+/// empty success response and forwards it to the returned receiver.
+fn start_collector() -> (String, Receiver<ExportRequest>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                break;
+            };
+
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                    break;
+                }
+
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\n\
+                    content-type: application/x-protobuf\r\n\
+                    content-length: 0\r\n\
+                    connection: close\r\n\r\n",
+                )
+                .unwrap();
+
+            if tx.send(ExportRequest { request_line, body }).is_err() {
+                break;
+            }
+        }
+    });
+
+    (endpoint, rx)
+}
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+#[test]
+fn test_tracing_exports_spans_to_otlp_collector() {
+    let (endpoint, requests) = start_collector();
+
+    let mut cfg = Config::default();
+    cfg.file.on = false;
+    cfg.stderr.on = false;
+    cfg.tracing.on = true;
+    cfg.tracing.otlp.endpoint = endpoint;
+    cfg.tracing.otlp.protocol = OTLPProtocol::Http;
+    let guards = init_logging("test_otlp", &cfg, BTreeMap::new());
+
+    {
+        let root = Span::root("test-otlp-root", SpanContext::random());
+        let _guard = root.set_local_parent();
+        let _span = LocalSpan::enter_with_local_parent("test-otlp-child");
+    }
+    fastrace::flush();
+
+    let request = requests
+        .recv_timeout(Duration::from_secs(30))
+        .expect("the collector must receive the exported spans");
+    assert!(
+        request.request_line.starts_with("POST /v1/traces "),
+        "unexpected export request: {}",
+        request.request_line
+    );
+    assert!(contains(&request.body, "test-otlp-root"));
+    assert!(contains(&request.body, "test-otlp-child"));
+    assert!(contains(&request.body, "test_otlp"));
+
+    drop(guards);
+}
@@ -83,3 +83,55 @@ pub fn l2_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
         .sum::<f64>()
         .sqrt())
 }
+
+/// Number of independent accumulators used by the kernels below.
+///
+/// Splitting the reduction into `LANES` partial sums removes the loop-carried
+/// dependency, which lets the compiler vectorize the inner loop into SIMD instructions.
+const LANES: usize = 8;
+
+fn check_length(from: &[f32], to: &[f32]) -> Result<()> {
+    if from.len() != to.len() {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "Vector length not equal: {:} != {:}",
+            from.len(),
+            to.len(),
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn reduce_2<F>(from: &[f32], to: &[f32], f: F) -> f32
+where F: Fn(f32, f32) -> f32 {
+    let mut acc = [0.0f32; LANES];
+    let from_chunks = from.chunks_exact(LANES);
+    let to_chunks = to.chunks_exact(LANES);
+    let from_rem = from_chunks.remainder();
+    let to_rem = to_chunks.remainder();
+    for (a, b) in from_chunks.zip(to_chunks) {
+        for i in 0..LANES {
+            acc[i] += f(a[i], b[i]);
+        }
+    }
+    let mut sum = acc.iter().sum::<f32>();
+    for (a, b) in from_rem.iter().zip(to_rem.iter()) {
+        sum += f(*a, *b);
+    }
+    sum
+}
+
+pub fn inner_product(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+    Ok(reduce_2(from, to, |a, b| a * b))
+}
+
+pub fn l1_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+    Ok(reduce_2(from, to, |a, b| (a - b).abs()))
+}
+
+/// Returns the euclidean (L2) norm of the vector.
+pub fn vector_norm(vector: &[f32]) -> f32 {
+    reduce_2(vector, vector, |a, b| a * b).sqrt()
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use databend_common_vector::cosine_distance;
+use databend_common_vector::inner_product;
+use databend_common_vector::l1_distance;
+use databend_common_vector::vector_norm;
 
 #[test]
 fn test_cosine() {
@@ -39,3 +42,48 @@ fn test_cosine() {
         assert!(d.is_err());
     }
 }
+
+#[test]
+fn test_inner_product() {
+    {
+        let x: Vec<f32> = (1..20).map(|v| v as f32).collect();
+        let y: Vec<f32> = (1..20).map(|v| (v * 2) as f32).collect();
+        let d = inner_product(&x, &y).unwrap();
+        // 2 * (1^2 + 2^2 + ... + 19^2)
+        approx::assert_relative_eq!(d, 4940.0);
+    }
+
+    {
+        let x = vec![3.0, 45.0, 7.0];
+        let y = vec![2.0, 54.0];
+        assert!(inner_product(&x, &y).is_err());
+    }
+}
+
+#[test]
+fn test_l1_distance() {
+    {
+        let x = vec![3.0, 45.0, 7.0, 2.0, 5.0, 20.0, 13.0, 12.0, 1.0];
+        let y = vec![2.0, 54.0, 13.0, 15.0, 22.0, 34.0, 50.0, 1.0, -1.0];
+        let d = l1_distance(&x, &y).unwrap();
+        approx::assert_relative_eq!(d, 110.0);
+    }
+
+    {
+        let x = vec![3.0, 45.0, 7.0];
+        let y = vec![2.0, 54.0];
+        assert!(l1_distance(&x, &y).is_err());
+    }
+}
+
+#[test]
+fn test_vector_norm() {
+    let x = vec![3.0, 4.0];
+    approx::assert_relative_eq!(vector_norm(&x), 5.0);
+
+    let x: Vec<f32> = vec![1.0; 16];
+    approx::assert_relative_eq!(vector_norm(&x), 4.0);
+
+    let x: Vec<f32> = vec![];
+    approx::assert_relative_eq!(vector_norm(&x), 0.0);
+}
@@ -34,6 +34,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 mod ownership_object;
 
@@ -55,6 +56,7 @@ pub mod user_setting_ident;
 pub mod user_stage_ident;
 pub mod user_token;
 pub mod user_token_ident;
+pub mod workload_group_ident;
 
 pub use connection::*;
 pub use file_format::*;
@@ -104,6 +106,7 @@ pub use user_grant::GrantObject;
 pub use user_grant::UserGrantSet;
 pub use user_identity::UserIdentity;
 pub use user_info::UserInfo;
+pub use user_info::UserLimits;
 pub use user_info::UserOption;
 pub use user_info::UserOptionFlag;
 pub use user_privilege::UserPrivilegeSet;
@@ -114,3 +117,5 @@ pub use user_setting::UserSettingValue;
 pub use user_setting_ident::SettingIdent;
 pub use user_stage::*;
 pub use user_stage_ident::StageIdent;
+pub use workload_group::WorkloadGroup;
+pub use workload_group_ident::WorkloadGroupIdent;
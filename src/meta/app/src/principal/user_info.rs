@@ -17,6 +17,7 @@ use std::convert::TryFrom;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_ast::ast::UserLimitsOption;
 use databend_common_ast::ast::UserOptionItem;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -189,6 +190,8 @@ pub struct UserOption {
     password_policy: Option<String>,
     disabled: Option<bool>,
     must_change_password: Option<bool>,
+    workload_group: Option<String>,
+    limits: UserLimits,
 }
 
 impl UserOption {
@@ -200,6 +203,8 @@ impl UserOption {
             password_policy: None,
             disabled: None,
             must_change_password: None,
+            workload_group: None,
+            limits: UserLimits::default(),
         }
     }
 
@@ -237,6 +242,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_workload_group(mut self, workload_group: Option<String>) -> Self {
+        self.workload_group = workload_group;
+        self
+    }
+
+    pub fn with_limits(mut self, limits: UserLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -266,6 +281,14 @@ impl UserOption {
         self.must_change_password.as_ref()
     }
 
+    pub fn workload_group(&self) -> Option<&String> {
+        self.workload_group.as_ref()
+    }
+
+    pub fn limits(&self) -> &UserLimits {
+        &self.limits
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -286,6 +309,14 @@ impl UserOption {
         self.must_change_password = must_change_password;
     }
 
+    pub fn set_workload_group(&mut self, workload_group: Option<String>) {
+        self.workload_group = workload_group;
+    }
+
+    pub fn set_limits(&mut self, limits: UserLimits) {
+        self.limits = limits;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -326,10 +357,77 @@ impl UserOption {
             UserOptionItem::UnsetPasswordPolicy => self.password_policy = None,
             UserOptionItem::Disabled(v) => self.disabled = Some(*v),
             UserOptionItem::MustChangePassword(v) => self.must_change_password = Some(*v),
+            UserOptionItem::SetWorkloadGroup(v) => self.workload_group = Some(v.clone()),
+            UserOptionItem::UnsetWorkloadGroup => self.workload_group = None,
+            UserOptionItem::SetLimits(v) => self.limits.apply(v),
+            UserOptionItem::UnsetLimits => self.limits = UserLimits::default(),
         }
     }
 }
 
+/// The resource limits of a user, `None` means unlimited.
+///
+/// A limit of `0` also means unlimited, the same as `SET LIMITS (...)` with value `0`,
+/// the limits are enforced by the [`UserLimits::normalized`] of them.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UserLimits {
+    /// The max running queries of the user in the cluster.
+    pub max_concurrent_queries: Option<u64>,
+    /// The max rows returned by a query.
+    pub max_result_rows: Option<u64>,
+    /// The max bytes scanned by a query.
+    pub max_scan_bytes_per_query: Option<u64>,
+    /// The max bytes scanned by the queries of the user in a day (UTC).
+    pub max_scan_bytes_per_day: Option<u64>,
+    /// The max execution time of a query in seconds.
+    pub max_execution_time_secs: Option<u64>,
+}
+
+impl UserLimits {
+    pub fn is_empty(&self) -> bool {
+        self == &UserLimits::default()
+    }
+
+    /// The limits with the ones of value `0` removed, which may be stored by older versions.
+    pub fn normalized(&self) -> UserLimits {
+        let normalize = |limit: Option<u64>| limit.filter(|value| *value != 0);
+        UserLimits {
+            max_concurrent_queries: normalize(self.max_concurrent_queries),
+            max_result_rows: normalize(self.max_result_rows),
+            max_scan_bytes_per_query: normalize(self.max_scan_bytes_per_query),
+            max_scan_bytes_per_day: normalize(self.max_scan_bytes_per_day),
+            max_execution_time_secs: normalize(self.max_execution_time_secs),
+        }
+    }
+
+    /// Merge the limits of `SET LIMITS (...)`, the limit with value `0` is removed.
+    pub fn apply(&mut self, option: &UserLimitsOption) {
+        fn merge(limit: &mut Option<u64>, value: Option<u64>) {
+            match value {
+                Some(0) => *limit = None,
+                Some(value) => *limit = Some(value),
+                None => {}
+            }
+        }
+
+        merge(
+            &mut self.max_concurrent_queries,
+            option.max_concurrent_queries,
+        );
+        merge(&mut self.max_result_rows, option.max_result_rows);
+        merge(
+            &mut self.max_scan_bytes_per_query,
+            option.max_scan_bytes_per_query,
+        );
+        merge(
+            &mut self.max_scan_bytes_per_day,
+            option.max_scan_bytes_per_day,
+        );
+        merge(&mut self.max_execution_time_secs, option.max_execution_time);
+    }
+}
+
 #[bitflags]
 #[repr(u64)]
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, num_derive::FromPrimitive)]
@@ -0,0 +1,34 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A workload group limits the resources used by the queries of the users assigned to it.
+///
+/// All the quotas are disabled if set to 0.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// The percentage of the cpu cores of a node that a query of the group can use.
+    pub cpu_quota: u64,
+    /// The percentage of the server memory that all the queries of the group can use.
+    pub memory_quota: u64,
+    /// The max number of queries of the group running concurrently on a node.
+    pub max_concurrency: u64,
+    /// The max seconds a query can wait in the queue of the group.
+    pub queue_timeout_secs: u64,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
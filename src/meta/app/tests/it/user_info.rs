@@ -14,10 +14,12 @@
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_ast::ast::UserLimitsOption;
 use databend_common_exception::exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::PasswordHashMethod;
 use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserLimits;
 
 #[test]
 fn test_user_info() -> Result<()> {
@@ -56,3 +58,34 @@ fn test_user_info() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_user_limits() -> Result<()> {
+    let mut limits = UserLimits::default();
+    limits.apply(&UserLimitsOption {
+        max_concurrent_queries: Some(4),
+        max_result_rows: Some(1000),
+        ..Default::default()
+    });
+    assert_eq!(limits.max_concurrent_queries, Some(4));
+    assert_eq!(limits.max_result_rows, Some(1000));
+
+    // A limit of `0` means unlimited.
+    limits.apply(&UserLimitsOption {
+        max_concurrent_queries: Some(0),
+        ..Default::default()
+    });
+    assert_eq!(limits.max_concurrent_queries, None);
+    assert_eq!(limits.max_result_rows, Some(1000));
+
+    // The limits of `0` stored by older versions are unlimited too.
+    let stored = UserLimits {
+        max_concurrent_queries: Some(0),
+        max_execution_time_secs: Some(0),
+        ..Default::default()
+    };
+    assert!(!stored.is_empty());
+    assert!(stored.normalized().is_empty());
+
+    Ok(())
+}
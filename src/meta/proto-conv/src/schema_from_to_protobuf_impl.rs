@@ -259,9 +259,24 @@ impl FromToProto for ex::TableDataType {
                     Dt24::VariantT(_) => ex::TableDataType::Variant,
                     Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::GeographyT(_) => ex::TableDataType::Geography,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::TimeT(_) => ex::TableDataType::Time,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
+                    Dt24::UuidT(_) => ex::TableDataType::Uuid,
                     Dt24::DecimalT(x) => {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
+                    Dt24::LargeIntT(x) => ex::TableDataType::LargeInt(
+                        ex::types::large_int::LargeIntDataType::from_pb(x)?,
+                    ),
+                    Dt24::VectorT(x) => {
+                        reader_check_msg(x.ver, x.min_reader_ver)?;
+                        ex::TableDataType::Vector(x.dimension)
+                    }
+                    Dt24::EnumT(x) => {
+                        reader_check_msg(x.ver, x.min_reader_ver)?;
+                        ex::TableDataType::Enum(x.values.into())
+                    }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
                 };
                 Ok(x)
@@ -289,6 +304,10 @@ impl FromToProto for ex::TableDataType {
                 let x = n.to_pb()?;
                 new_pb_dt24(Dt24::DecimalT(x))
             }
+            TableDataType::LargeInt(n) => {
+                let x = n.to_pb()?;
+                new_pb_dt24(Dt24::LargeIntT(x))
+            }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
             TableDataType::Nullable(v) => {
@@ -326,6 +345,20 @@ impl FromToProto for ex::TableDataType {
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Geography => new_pb_dt24(Dt24::GeographyT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::Time => new_pb_dt24(Dt24::TimeT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
+            TableDataType::Uuid => new_pb_dt24(Dt24::UuidT(pb::Empty {})),
+            TableDataType::Vector(dimension) => new_pb_dt24(Dt24::VectorT(pb::Vector {
+                ver: VER,
+                min_reader_ver: MIN_READER_VER,
+                dimension: *dimension,
+            })),
+            TableDataType::Enum(values) => new_pb_dt24(Dt24::EnumT(pb::Enum {
+                ver: VER,
+                min_reader_ver: MIN_READER_VER,
+                values: values.to_vec(),
+            })),
         };
         Ok(x)
     }
@@ -425,6 +458,45 @@ impl FromToProto for ex::types::DecimalDataType {
     }
 }
 
+impl FromToProto for ex::types::large_int::LargeIntDataType {
+    type PB = pb::LargeInt;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::LargeInt) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let large_int = p.large_int.ok_or_else(|| Incompatible {
+            reason: "Invalid LargeInt: .large_int can not be None".to_string(),
+        })?;
+
+        let x = match large_int {
+            pb::large_int::LargeInt::Int128Type(_) => Self::Int128,
+            pb::large_int::LargeInt::Uint128Type(_) => Self::UInt128,
+            pb::large_int::LargeInt::Int256Type(_) => Self::Int256,
+            pb::large_int::LargeInt::Uint256Type(_) => Self::UInt256,
+        };
+        Ok(x)
+    }
+
+    fn to_pb(&self) -> Result<pb::LargeInt, Incompatible> {
+        let x = match self {
+            Self::Int128 => pb::large_int::LargeInt::Int128Type(pb::Empty {}),
+            Self::UInt128 => pb::large_int::LargeInt::Uint128Type(pb::Empty {}),
+            Self::Int256 => pb::large_int::LargeInt::Int256Type(pb::Empty {}),
+            Self::UInt256 => pb::large_int::LargeInt::Uint256Type(pb::Empty {}),
+        };
+        Ok(pb::LargeInt {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+
+            large_int: Some(x),
+        })
+    }
+}
+
 impl FromToProto for ex::types::decimal::DecimalSize {
     type PB = pb::DecimalSize;
 
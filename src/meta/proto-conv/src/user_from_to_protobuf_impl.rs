@@ -106,7 +106,12 @@ impl FromToProto for mt::principal::UserOption {
             .with_network_policy(p.network_policy)
             .with_password_policy(p.password_policy)
             .with_disabled(p.disabled)
-            .with_must_change_password(p.must_change_password))
+            .with_must_change_password(p.must_change_password)
+            .with_workload_group(p.workload_group)
+            .with_limits(match p.limits {
+                Some(limits) => mt::principal::UserLimits::from_pb(limits)?,
+                None => mt::principal::UserLimits::default(),
+            }))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -119,6 +124,43 @@ impl FromToProto for mt::principal::UserOption {
             password_policy: self.password_policy().cloned(),
             disabled: self.disabled().cloned(),
             must_change_password: self.must_change_password().cloned(),
+            workload_group: self.workload_group().cloned(),
+            limits: if self.limits().is_empty() {
+                None
+            } else {
+                Some(self.limits().to_pb()?)
+            },
+        })
+    }
+}
+
+impl FromToProto for mt::principal::UserLimits {
+    type PB = pb::UserLimits;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UserLimits) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::principal::UserLimits {
+            max_concurrent_queries: p.max_concurrent_queries,
+            max_result_rows: p.max_result_rows,
+            max_scan_bytes_per_query: p.max_scan_bytes_per_query,
+            max_scan_bytes_per_day: p.max_scan_bytes_per_day,
+            max_execution_time_secs: p.max_execution_time_secs,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UserLimits, Incompatible> {
+        Ok(pb::UserLimits {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            max_concurrent_queries: self.max_concurrent_queries,
+            max_result_rows: self.max_result_rows,
+            max_scan_bytes_per_query: self.max_scan_bytes_per_query,
+            max_scan_bytes_per_day: self.max_scan_bytes_per_day,
+            max_execution_time_secs: self.max_execution_time_secs,
         })
     }
 }
@@ -527,3 +569,43 @@ impl FromToProto for mt::principal::PasswordPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::WorkloadGroup {
+    type PB = pb::WorkloadGroup;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WorkloadGroup) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::WorkloadGroup {
+            name: p.name.clone(),
+            cpu_quota: p.cpu_quota,
+            memory_quota: p.memory_quota,
+            max_concurrency: p.max_concurrency,
+            queue_timeout_secs: p.queue_timeout_secs,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WorkloadGroup, Incompatible> {
+        Ok(pb::WorkloadGroup {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            cpu_quota: self.cpu_quota,
+            memory_quota: self.memory_quota,
+            max_concurrency: self.max_concurrency,
+            queue_timeout_secs: self.queue_timeout_secs,
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
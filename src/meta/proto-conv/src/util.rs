@@ -140,6 +140,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (108, "2024-08-29: Add: procedure.proto: ProcedureMeta and ProcedureIdentity"),
     (109, "2024-08-29: Refactor: ProcedureMeta add arg_names"),
     (110, "2024-09-18: Add: database.proto: DatabaseMeta.gc_in_progress"),
+    (111, "2024-10-19: Add: user.proto: WorkloadGroup, UserOption.workload_group"),
+    (112, "2024-10-19: Add: user.proto: UserLimits, UserOption.limits"),
+    (113, "2024-10-20: Add: datatype.proto/DataType Interval type"),
+    (114, "2024-10-21: Add: datatype.proto/DataType Time and TimestampTz type"),
+    (115, "2024-10-22: Add: datatype.proto/DataType LargeInt type"),
+    (116, "2024-10-23: Add: datatype.proto/DataType Vector type"),
+    (117, "2024-10-24: Add: datatype.proto/DataType Enum type"),
+    (118, "2024-10-25: Add: datatype.proto/DataType Uuid type"),
+    (119, "2024-10-26: Add: role.proto/RoleInfo workload_group"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -108,3 +108,12 @@ mod v107_geography_datatype;
 mod v108_procedure;
 mod v109_procedure_with_args;
 mod v110_database_meta_gc_in_progress;
+mod v111_workload_group;
+mod v112_user_limits;
+mod v113_interval_datatype;
+mod v114_time_timestamp_tz_datatype;
+mod v115_large_int_datatype;
+mod v116_vector_datatype;
+mod v117_enum_datatype;
+mod v118_uuid_datatype;
+mod v119_role_workload_group;
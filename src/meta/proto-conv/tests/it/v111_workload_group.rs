@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v111_workload_group() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 3, 101, 116, 108, 16, 40, 24, 30, 32, 4, 40, 60, 50, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48, 49, 52, 45,
+        49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 111, 168,
+        6, 24,
+    ];
+
+    let want = || databend_common_meta_app::principal::WorkloadGroup {
+        name: "etl".to_string(),
+        cpu_quota: 40,
+        memory_quota: 30,
+        max_concurrency: 4,
+        queue_timeout_secs: 60,
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 111, want())?;
+
+    // user option with workload group
+    let bytes: Vec<u8> = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 58, 3, 101, 116, 108, 160, 6, 111, 168, 6, 24,
+    ];
+
+    let want = || {
+        databend_common_meta_app::principal::UserOption::default()
+            .with_set_flag(databend_common_meta_app::principal::UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".into()))
+            .with_workload_group(Some("etl".to_string()))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 111, want())
+}
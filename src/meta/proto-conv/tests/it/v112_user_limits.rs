@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::UserLimits;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_meta_app::principal::UserOptionFlag;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v112_user_limits() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 66, 14, 8, 4, 32, 128, 128, 128, 128, 4, 160, 6, 112,
+        168, 6, 24, 160, 6, 112, 168, 6, 24,
+    ];
+
+    let want = || {
+        UserOption::default()
+            .with_set_flag(UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".into()))
+            .with_limits(UserLimits {
+                max_concurrent_queries: Some(4),
+                max_scan_bytes_per_day: Some(1024 * 1024 * 1024),
+                ..Default::default()
+            })
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 112, want())
+}
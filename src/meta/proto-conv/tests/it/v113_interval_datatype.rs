@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v113_schema() -> anyhow::Result<()> {
+    let table_schema_v113 = vec![
+        10, 23, 10, 4, 105, 118, 97, 108, 26, 9, 138, 3, 0, 160, 6, 113, 168, 6, 24, 160, 6, 113,
+        168, 6, 24, 10, 35, 10, 5, 110, 105, 118, 97, 108, 26, 18, 178, 2, 9, 138, 3, 0, 160, 6,
+        113, 168, 6, 24, 160, 6, 113, 168, 6, 24, 32, 1, 160, 6, 113, 168, 6, 24, 24, 2, 160, 6,
+        113, 168, 6, 24,
+    ];
+
+    let fields = vec![
+        TableField::new("ival", TableDataType::Interval),
+        TableField::new(
+            "nival",
+            TableDataType::Nullable(Box::new(TableDataType::Interval)),
+        ),
+    ];
+
+    let want = || TableSchema::new(fields.clone());
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_schema_v113.as_slice(), 113, want())?;
+    Ok(())
+}
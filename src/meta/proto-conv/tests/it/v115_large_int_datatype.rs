@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::LargeIntDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v115_schema() -> anyhow::Result<()> {
+    let table_schema_v115 = vec![
+        10, 28, 10, 1, 97, 26, 17, 162, 3, 8, 10, 0, 160, 6, 115, 168, 6, 24, 160, 6, 115, 168, 6,
+        24, 160, 6, 115, 168, 6, 24, 10, 30, 10, 1, 98, 26, 17, 162, 3, 8, 34, 0, 160, 6, 115, 168,
+        6, 24, 160, 6, 115, 168, 6, 24, 32, 1, 160, 6, 115, 168, 6, 24, 24, 2, 160, 6, 115, 168, 6,
+        24,
+    ];
+
+    let fields = vec![
+        TableField::new("a", TableDataType::LargeInt(LargeIntDataType::Int128)),
+        TableField::new("b", TableDataType::LargeInt(LargeIntDataType::UInt256)),
+    ];
+
+    let want = || TableSchema::new(fields.clone());
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_schema_v115.as_slice(), 115, want())?;
+    Ok(())
+}
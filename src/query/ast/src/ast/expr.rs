@@ -229,6 +229,9 @@ pub enum Expr {
     },
 }
 
+/// The name of the hole parsed from a `?` placeholder of prepared statements.
+pub const PLACEHOLDER: &str = "?";
+
 impl Expr {
     pub fn span(&self) -> Span {
         match self {
@@ -709,6 +712,9 @@ impl Display for Expr {
                 Expr::DateTrunc { unit, date, .. } => {
                     write!(f, "DATE_TRUNC({unit}, {date})")?;
                 }
+                Expr::Hole { name, .. } if name == PLACEHOLDER => {
+                    write!(f, "{PLACEHOLDER}")?;
+                }
                 Expr::Hole { name, .. } => {
                     write!(f, ":{name}")?;
                 }
@@ -918,6 +924,10 @@ pub enum TypeName {
     Int16,
     Int32,
     Int64,
+    Int128,
+    UInt128,
+    Int256,
+    UInt256,
     Float32,
     Float64,
     Decimal {
@@ -941,6 +951,12 @@ pub enum TypeName {
     Variant,
     Geometry,
     Geography,
+    Interval,
+    Time,
+    TimestampTz,
+    Vector(u64),
+    Enum(Vec<String>),
+    Uuid,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -996,6 +1012,18 @@ impl Display for TypeName {
             TypeName::Int64 => {
                 write!(f, "Int64")?;
             }
+            TypeName::Int128 => {
+                write!(f, "Int128")?;
+            }
+            TypeName::UInt128 => {
+                write!(f, "UInt128")?;
+            }
+            TypeName::Int256 => {
+                write!(f, "Int256")?;
+            }
+            TypeName::UInt256 => {
+                write!(f, "UInt256")?;
+            }
             TypeName::Float32 => {
                 write!(f, "Float32")?;
             }
@@ -1063,6 +1091,31 @@ impl Display for TypeName {
             TypeName::Geography => {
                 write!(f, "GEOGRAPHY")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
+            TypeName::Time => {
+                write!(f, "TIME")?;
+            }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP WITH TIME ZONE")?;
+            }
+            TypeName::Vector(dimension) => {
+                write!(f, "VECTOR({dimension})")?;
+            }
+            TypeName::Uuid => {
+                write!(f, "UUID")?;
+            }
+            TypeName::Enum(values) => {
+                write!(f, "ENUM(")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", QuotedString(value, '\''))?;
+                }
+                write!(f, ")")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
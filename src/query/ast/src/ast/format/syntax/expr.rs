@@ -23,6 +23,7 @@ use crate::ast::BinaryOperator;
 use crate::ast::Expr;
 use crate::ast::FunctionCall;
 use crate::ast::MapAccessor;
+use crate::ast::PLACEHOLDER;
 
 pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
     match expr {
@@ -426,6 +427,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
+        Expr::Hole { name, .. } if name == PLACEHOLDER => RcDoc::text(PLACEHOLDER),
         Expr::Hole { name, .. } => RcDoc::text(":").append(RcDoc::text(name.to_string())),
     }
 }
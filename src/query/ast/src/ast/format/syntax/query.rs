@@ -464,6 +464,22 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             }),
+        TableReference::JsonTable {
+            span: _,
+            lateral,
+            json_table,
+            alias,
+        } => (if lateral {
+            RcDoc::text("LATERAL ")
+        } else {
+            RcDoc::nil()
+        })
+        .append(RcDoc::text(json_table.to_string()))
+        .append(if let Some(alias) = alias {
+            RcDoc::text(format!(" AS {alias}"))
+        } else {
+            RcDoc::nil()
+        }),
     }
 }
 
@@ -19,6 +19,7 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::quote::QuotedString;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_comma_separated_string_map;
 use crate::ast::write_dot_separated_list;
@@ -27,7 +28,9 @@ use crate::ast::FileLocation;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Lambda;
+use crate::ast::Literal;
 use crate::ast::SelectStageOptions;
+use crate::ast::TypeName;
 use crate::ast::WindowDefinition;
 use crate::Span;
 
@@ -723,6 +726,14 @@ pub enum TableReference {
         options: SelectStageOptions,
         alias: Option<TableAlias>,
     },
+    // `JSON_TABLE(expr, 'path' COLUMNS (...))[ AS alias ]`
+    JsonTable {
+        span: Span,
+        /// Whether the `LATERAL` keyword is written, `JSON_TABLE` is always lateral in a join
+        lateral: bool,
+        json_table: Box<JsonTable>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl TableReference {
@@ -743,6 +754,7 @@ impl TableReference {
     pub fn is_lateral_table_function(&self) -> bool {
         match self {
             TableReference::TableFunction { lateral, .. } => *lateral,
+            TableReference::JsonTable { .. } => true,
             _ => false,
         }
     }
@@ -904,11 +916,154 @@ impl Display for TableReference {
                     write!(f, " AS {alias}")?;
                 }
             }
+            TableReference::JsonTable {
+                span: _,
+                lateral,
+                json_table,
+                alias,
+            } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
+                write!(f, "{json_table}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// The SQL/JSON `JSON_TABLE` table function.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct JsonTable {
+    pub input: Expr,
+    /// The row path, each item it selects produces a row.
+    pub path: String,
+    pub columns: Vec<JsonTableColumn>,
+    /// `ERROR ON ERROR` or `EMPTY ON ERROR` for errors of the input and the row path.
+    pub on_error: Option<JsonTableOnError>,
+}
+
+impl Display for JsonTable {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "JSON_TABLE({}, {} COLUMNS (",
+            self.input,
+            QuotedString(&self.path, '\'')
+        )?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ")")?;
+        if let Some(on_error) = &self.on_error {
+            write!(f, " {on_error} ON ERROR")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum JsonTableOnError {
+    Error,
+    Empty,
+}
+
+impl Display for JsonTableOnError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            JsonTableOnError::Error => write!(f, "ERROR"),
+            JsonTableOnError::Empty => write!(f, "EMPTY"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum JsonTableColumn {
+    /// `name FOR ORDINALITY`
+    Ordinality { name: Identifier },
+    /// `name type [PATH 'path'] [behavior ON EMPTY] [behavior ON ERROR]`
+    Path {
+        name: Identifier,
+        data_type: TypeName,
+        path: Option<String>,
+        on_empty: Option<JsonTableBehavior>,
+        on_error: Option<JsonTableBehavior>,
+    },
+    /// `name type EXISTS [PATH 'path']`
+    Exists {
+        name: Identifier,
+        data_type: TypeName,
+        path: Option<String>,
+    },
+    /// `NESTED [PATH] 'path' COLUMNS (...)`
+    Nested {
+        path: String,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+impl Display for JsonTableColumn {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            JsonTableColumn::Ordinality { name } => write!(f, "{name} FOR ORDINALITY"),
+            JsonTableColumn::Path {
+                name,
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            } => {
+                write!(f, "{name} {data_type}")?;
+                if let Some(path) = path {
+                    write!(f, " PATH {}", QuotedString(path, '\''))?;
+                }
+                if let Some(on_empty) = on_empty {
+                    write!(f, " {on_empty} ON EMPTY")?;
+                }
+                if let Some(on_error) = on_error {
+                    write!(f, " {on_error} ON ERROR")?;
+                }
+                Ok(())
+            }
+            JsonTableColumn::Exists {
+                name,
+                data_type,
+                path,
+            } => {
+                write!(f, "{name} {data_type} EXISTS")?;
+                if let Some(path) = path {
+                    write!(f, " PATH {}", QuotedString(path, '\''))?;
+                }
+                Ok(())
+            }
+            JsonTableColumn::Nested { path, columns } => {
+                write!(f, "NESTED PATH {} COLUMNS (", QuotedString(path, '\''))?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// The value of a `JSON_TABLE` column when its path selects nothing or fails.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum JsonTableBehavior {
+    Null,
+    Error,
+    Default(Literal),
+}
+
+impl Display for JsonTableBehavior {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            JsonTableBehavior::Null => write!(f, "NULL"),
+            JsonTableBehavior::Error => write!(f, "ERROR"),
+            JsonTableBehavior::Default(value) => write!(f, "DEFAULT {value}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct TableAlias {
     pub name: Identifier,
@@ -22,6 +22,8 @@ pub enum ExplainKind {
     // The display string will be filled by optimizer, as we
     // don't want to expose `Memo` to other crates.
     Memo(String),
+    // The Substrait plan in JSON, filled by optimizer as well.
+    Substrait(String),
     Graph,
     Pipeline,
     Fragments,
@@ -34,6 +34,7 @@ mod network_policy;
 mod notification;
 mod password_policy;
 mod pipe;
+mod prepare;
 mod presign;
 mod principal;
 mod priority;
@@ -54,6 +55,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -77,6 +79,7 @@ pub use network_policy::*;
 pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
+pub use prepare::*;
 pub use presign::*;
 pub use principal::*;
 pub use priority::*;
@@ -97,3 +100,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
@@ -213,6 +213,7 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -287,6 +288,12 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -336,6 +343,11 @@ pub enum Statement {
 
     // System actions
     System(SystemStmt),
+
+    // Prepared statements
+    Prepare(PrepareStmt),
+    Execute(ExecuteStmt),
+    Deallocate(DeallocateStmt),
 }
 
 impl Statement {
@@ -413,6 +425,7 @@ impl Display for Statement {
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::Join => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::Substrait(_) => write!(f, " SUBSTRAIT")?,
                 }
                 write!(f, " {query}")?;
             }
@@ -671,6 +684,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants {
                 principal,
@@ -768,6 +782,10 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -812,6 +830,9 @@ impl Display for Statement {
             }
             Statement::System(stmt) => write!(f, "{stmt}")?,
             Statement::CallProcedure(stmt) => write!(f, "{stmt}")?,
+            Statement::Prepare(stmt) => write!(f, "{stmt}")?,
+            Statement::Execute(stmt) => write!(f, "{stmt}")?,
+            Statement::Deallocate(stmt) => write!(f, "{stmt}")?,
         }
         Ok(())
     }
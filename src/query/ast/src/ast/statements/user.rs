@@ -103,6 +103,38 @@ impl Display for AlterUserStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct AlterRoleStmt {
+    pub if_exists: bool,
+    pub role_name: String,
+    pub action: AlterRoleAction,
+}
+
+impl Display for AlterRoleStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER ROLE")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " '{}' {}", self.role_name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum AlterRoleAction {
+    SetWorkloadGroup(String),
+    UnsetWorkloadGroup,
+}
+
+impl Display for AlterRoleAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterRoleAction::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD GROUP = '{}'", v),
+            AlterRoleAction::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD GROUP"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct GrantStmt {
     pub source: AccountMgrSource,
@@ -262,6 +294,10 @@ pub enum UserOptionItem {
     SetPasswordPolicy(String),
     UnsetPasswordPolicy,
     MustChangePassword(bool),
+    SetWorkloadGroup(String),
+    UnsetWorkloadGroup,
+    SetLimits(UserLimitsOption),
+    UnsetLimits,
 }
 
 impl Display for UserOptionItem {
@@ -276,6 +312,48 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
             UserOptionItem::Disabled(v) => write!(f, "DISABLED = {}", v),
             UserOptionItem::MustChangePassword(v) => write!(f, "MUST_CHANGE_PASSWORD = {}", v),
+            UserOptionItem::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD GROUP = '{}'", v),
+            UserOptionItem::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD GROUP"),
+            UserOptionItem::SetLimits(v) => write!(f, "SET LIMITS ({})", v),
+            UserOptionItem::UnsetLimits => write!(f, "UNSET LIMITS"),
         }
     }
 }
+
+/// The resource limits of a user, the limit with value `0` is removed.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Drive, DriveMut)]
+pub struct UserLimitsOption {
+    pub max_concurrent_queries: Option<u64>,
+    pub max_result_rows: Option<u64>,
+    pub max_scan_bytes_per_query: Option<u64>,
+    pub max_scan_bytes_per_day: Option<u64>,
+    pub max_execution_time: Option<u64>,
+}
+
+impl UserLimitsOption {
+    fn items(&self) -> Vec<(&'static str, u64)> {
+        let items = [
+            ("MAX_CONCURRENT_QUERIES", self.max_concurrent_queries),
+            ("MAX_RESULT_ROWS", self.max_result_rows),
+            ("MAX_SCAN_BYTES_PER_QUERY", self.max_scan_bytes_per_query),
+            ("MAX_SCAN_BYTES_PER_DAY", self.max_scan_bytes_per_day),
+            ("MAX_EXECUTION_TIME", self.max_execution_time),
+        ];
+        items
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .collect()
+    }
+}
+
+impl Display for UserLimitsOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (idx, (name, value)) in self.items().into_iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} = {}", name, value)?;
+        }
+        Ok(())
+    }
+}
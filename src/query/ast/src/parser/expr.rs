@@ -101,6 +101,14 @@ pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
                             op: UnaryOperator::Minus,
                         };
                     }
+                    // replace `?` to the placeholder of prepared statements, ...
+                    ExprElement::JsonOp {
+                        op: JsonOperator::Question,
+                    } => {
+                        *elem = ExprElement::Hole {
+                            name: PLACEHOLDER.to_string(),
+                        };
+                    }
                     // replace `:ident` to hole, ...
                     ExprElement::MapAccess {
                         accessor: MapAccessor::Colon { key },
@@ -1186,6 +1194,18 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             unit,
         },
     );
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.tokens),
+                value: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
     let date_trunc = map(
         rule! {
             DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
@@ -1219,6 +1239,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let time_expr = map(
+        rule! {
+            TIME ~ #consumed(literal_string)
+        },
+        |(_, (span, time))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.tokens),
+                value: Literal::String(time),
+            }),
+            target_type: TypeName::Time,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1257,7 +1290,9 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 | #date_trunc: "`DATE_TRUNC((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND), ...)`"
                 | #date_expr: "`DATE <str_literal>`"
                 | #timestamp_expr: "`TIMESTAMP <str_literal>`"
+                | #time_expr: "`TIME <str_literal>`"
                 | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+                | #interval_expr: "`INTERVAL <str_literal>`"
                 | #pg_cast : "`::<type_name>`"
                 | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
                 | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
@@ -1541,6 +1576,10 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         TypeName::Int64,
         rule! { ( INT64 | SIGNED | BIGINT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
+    let ty_int128 = value(TypeName::Int128, rule! { INT128 });
+    let ty_uint128 = value(TypeName::UInt128, rule! { UINT128 });
+    let ty_int256 = value(TypeName::Int256, rule! { INT256 });
+    let ty_uint256 = value(TypeName::UInt256, rule! { UINT256 });
     let ty_float32 = value(TypeName::Float32, rule! { FLOAT32 | FLOAT });
     let ty_float64 = value(
         TypeName::Float64,
@@ -1599,8 +1638,19 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     );
     let ty_date = value(TypeName::Date, rule! { DATE });
     let ty_datetime = map(
-        rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
-        |(_, _)| TypeName::Timestamp,
+        rule! {
+            ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )?
+            ~ ( WITHOUT ~ ^TIME ~ ^ZONE )?
+        },
+        |(_, _, _)| TypeName::Timestamp,
+    );
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! {
+        ( TIMESTAMP ~ ( "(" ~ ^#literal_u64 ~ ^")" )? ~ WITH ~ ^TIME ~ ^ZONE )
+        | ( ( TIMESTAMPTZ | TIMESTAMP_TZ ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? )
+    });
+    let ty_time = value(
+        TypeName::Time,
+        rule! { TIME ~ ( "(" ~ ^#literal_u64 ~ ^")" )? ~ ( WITHOUT ~ ^TIME ~ ^ZONE )? },
     );
     let ty_binary = value(
         TypeName::Binary,
@@ -1613,6 +1663,16 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_geography = value(TypeName::Geography, rule! { GEOGRAPHY });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
+    let ty_uuid = value(TypeName::Uuid, rule! { UUID });
+    let ty_vector = map(
+        rule! { VECTOR ~ "(" ~ ^#literal_u64 ~ ^")" },
+        |(_, _, dimension, _)| TypeName::Vector(dimension),
+    );
+    let ty_enum = map(
+        rule! { ENUM ~ "(" ~ ^#comma_separated_list1(literal_string) ~ ^")" },
+        |(_, _, values, _)| TypeName::Enum(values),
+    );
     map_res(
         alt((
             rule! {
@@ -1637,12 +1697,22 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             },
             rule! {
             ( #ty_date
+            | #ty_timestamp_tz
             | #ty_datetime
+            | #ty_time
             | #ty_binary
             | #ty_string
             | #ty_variant
             | #ty_geometry
             | #ty_geography
+            | #ty_interval
+            | #ty_int128
+            | #ty_uint128
+            | #ty_int256
+            | #ty_uint256
+            | #ty_vector
+            | #ty_enum : "ENUM('<value>', ...)"
+            | #ty_uuid
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -182,6 +182,7 @@ fn reset_ast(mut stmt: StatementWithFormat) -> StatementWithFormat {
                 ExplainKind::Ast(_) => *kind = ExplainKind::Ast("".to_string()),
                 ExplainKind::Syntax(_) => *kind = ExplainKind::Syntax("".to_string()),
                 ExplainKind::Memo(_) => *kind = ExplainKind::Memo("".to_string()),
+                ExplainKind::Substrait(_) => *kind = ExplainKind::Substrait("".to_string()),
                 _ => (),
             }
         }
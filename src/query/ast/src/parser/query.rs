@@ -733,6 +733,12 @@ pub enum TableReferenceElement {
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
     },
+    // `JSON_TABLE(expr, 'path' COLUMNS (...))[ AS alias ]`
+    JsonTable {
+        lateral: bool,
+        json_table: Box<JsonTable>,
+        alias: Option<TableAlias>,
+    },
 }
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
@@ -823,6 +829,25 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
             }
         },
     );
+    let json_table = map(
+        rule! {
+            LATERAL? ~ JSON_TABLE ~ "(" ~ ^#expr ~ ^"," ~ ^#literal_string
+            ~ ^COLUMNS ~ ^"(" ~ ^#comma_separated_list1(json_table_column) ~ ^")"
+            ~ ( #json_table_on_error ~ ON ~ ERROR )? ~ ^")" ~ #table_alias?
+        },
+        |(lateral, _, _, input, _, path, _, _, columns, _, on_error, _, alias)| {
+            TableReferenceElement::JsonTable {
+                lateral: lateral.is_some(),
+                json_table: Box::new(JsonTable {
+                    input,
+                    path,
+                    columns,
+                    on_error: on_error.map(|(on_error, _, _)| on_error),
+                }),
+                alias,
+            }
+        },
+    );
     let subquery = map(
         rule! {
             LATERAL? ~ "(" ~ #query ~ ")" ~ #table_alias?
@@ -858,6 +883,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
 
     let (rest, (span, elem)) = consumed(rule! {
         #aliased_stage
+        | #json_table
         | #table_function
         | #aliased_table
         | #subquery
@@ -998,6 +1024,16 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     alias,
                 }
             }
+            TableReferenceElement::JsonTable {
+                lateral,
+                json_table,
+                alias,
+            } => TableReference::JsonTable {
+                span: transform_span(input.span.tokens),
+                lateral,
+                json_table,
+                alias,
+            },
             _ => unreachable!(),
         };
         Ok(table_ref)
@@ -1064,6 +1100,65 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
     }
 }
 
+pub fn json_table_column(i: Input) -> IResult<JsonTableColumn> {
+    let nested = map(
+        rule! {
+            NESTED ~ PATH? ~ ^#literal_string ~ ^COLUMNS
+            ~ ^"(" ~ ^#comma_separated_list1(json_table_column) ~ ^")"
+        },
+        |(_, _, path, _, _, columns, _)| JsonTableColumn::Nested { path, columns },
+    );
+    let ordinality = map(rule! { #ident ~ FOR ~ ^ORDINALITY }, |(name, _, _)| {
+        JsonTableColumn::Ordinality { name }
+    });
+    let exists = map(
+        rule! { #ident ~ #type_name ~ EXISTS ~ ( PATH ~ ^#literal_string )? },
+        |(name, data_type, _, path)| JsonTableColumn::Exists {
+            name,
+            data_type,
+            path: path.map(|(_, path)| path),
+        },
+    );
+    let path = map(
+        rule! {
+            #ident ~ #type_name ~ ( PATH ~ ^#literal_string )?
+            ~ ( #json_table_behavior ~ ON ~ EMPTY )?
+            ~ ( #json_table_behavior ~ ON ~ ERROR )?
+        },
+        |(name, data_type, path, on_empty, on_error)| JsonTableColumn::Path {
+            name,
+            data_type,
+            path: path.map(|(_, path)| path),
+            on_empty: on_empty.map(|(behavior, _, _)| behavior),
+            on_error: on_error.map(|(behavior, _, _)| behavior),
+        },
+    );
+
+    rule!(
+        #nested : "NESTED PATH '<path>' COLUMNS (...)"
+        | #ordinality : "<column> FOR ORDINALITY"
+        | #exists : "<column> <type> EXISTS [PATH '<path>']"
+        | #path : "<column> <type> [PATH '<path>']"
+    )(i)
+}
+
+pub fn json_table_behavior(i: Input) -> IResult<JsonTableBehavior> {
+    alt((
+        value(JsonTableBehavior::Null, rule! { NULL }),
+        value(JsonTableBehavior::Error, rule! { ERROR }),
+        map(rule! { DEFAULT ~ ^#literal }, |(_, value)| {
+            JsonTableBehavior::Default(value)
+        }),
+    ))(i)
+}
+
+pub fn json_table_on_error(i: Input) -> IResult<JsonTableOnError> {
+    alt((
+        value(JsonTableOnError::Error, rule! { ERROR }),
+        value(JsonTableOnError::Empty, rule! { EMPTY }),
+    ))(i)
+}
+
 pub fn group_by_items(i: Input) -> IResult<GroupBy> {
     let normal = map(rule! { ^#comma_separated_list1(expr) }, |groups| {
         GroupBy::Normal(groups)
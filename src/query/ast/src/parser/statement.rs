@@ -56,7 +56,7 @@ pub enum CreateDatabaseOption {
 pub fn statement_body(i: Input) -> IResult<Statement> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( "(" ~ #comma_separated_list1(explain_option) ~ ")" )? ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
+            EXPLAIN ~ ( "(" ~ #comma_separated_list1(explain_option) ~ ")" )? ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO | SUBSTRAIT )? ~ #statement
         },
         |(_, options, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -75,6 +75,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::OPTIMIZED) => ExplainKind::Optimized,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::SUBSTRAIT) => ExplainKind::Substrait("".to_string()),
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -1329,17 +1330,20 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
             ~ ( WITH ~ ^#comma_separated_list1(user_option) )?
+            ~ ( #user_limits_option )?
         },
-        |(_, _, user, opt_auth_option, opt_user_option)| {
+        |(_, _, user, opt_auth_option, opt_user_option, opt_limits_option)| {
+            let mut user_options = opt_user_option
+                .map(|(_, user_options)| user_options)
+                .unwrap_or_default();
+            user_options.extend(opt_limits_option);
             Statement::AlterUser(AlterUserStmt {
                 user,
                 auth_option: opt_auth_option.map(|(_, opt_auth_type, opt_password)| AuthOption {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
-                user_options: opt_user_option
-                    .map(|(_, user_options)| user_options)
-                    .unwrap_or_default(),
+                user_options,
             })
         },
     );
@@ -1371,6 +1375,18 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             role_name,
         },
     );
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ ( IF ~ ^EXISTS )? ~ #role_name ~ #alter_role_action
+        },
+        |(_, _, opt_if_exists, role_name, action)| {
+            Statement::AlterRole(AlterRoleStmt {
+                if_exists: opt_if_exists.is_some(),
+                role_name,
+                action,
+            })
+        },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -1869,6 +1885,50 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ ( WITH ~ ^#set_table_option )?
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, opt_options)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateWorkloadGroupStmt {
+                create_option,
+                name: name.to_string(),
+                options: opt_options.map(|(_, options)| options).unwrap_or_default(),
+            };
+            Ok(Statement::CreateWorkloadGroup(stmt))
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident ~ ^SET ~ ^#set_table_option
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            Statement::AlterWorkloadGroup(AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ ^GROUPS },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2017,6 +2077,37 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, script)| Statement::ExecuteImmediate(ExecuteImmediateStmt { script }),
     );
 
+    let prepare = map(
+        rule! {
+            PREPARE ~ #ident ~ FROM ~ #statement
+        },
+        |(_, name, _, statement)| {
+            Statement::Prepare(PrepareStmt {
+                name,
+                statement: Box::new(statement.stmt),
+            })
+        },
+    );
+
+    let execute = map(
+        rule! {
+            EXECUTE ~ #ident ~ ( USING ~ #comma_separated_list1(expr) )?
+        },
+        |(_, name, opt_params)| {
+            Statement::Execute(ExecuteStmt {
+                name,
+                params: opt_params.map(|(_, params)| params).unwrap_or_default(),
+            })
+        },
+    );
+
+    let deallocate = map(
+        rule! {
+            ( DEALLOCATE | DROP ) ~ PREPARE ~ #ident
+        },
+        |(_, _, name)| Statement::Deallocate(DeallocateStmt { name }),
+    );
+
     let system_action = map(
         rule! {
             SYSTEM ~ #action
@@ -2234,6 +2325,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
         ),
+        // workload group
+        rule!(
+            #create_workload_group: "`CREATE [OR REPLACE] WORKLOAD GROUP [IF NOT EXISTS] name [WITH cpu_quota = '<percent>', memory_quota = '<percent>', max_concurrency = <u64_literal>, queue_timeout = <seconds>]`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET <option> = <value>, ...`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
+        ),
         rule!(
             #conditional_multi_table_insert() : "`INSERT [OVERWRITE] {FIRST|ALL} { WHEN <condition> THEN intoClause [ ... ] } [ ... ] [ ELSE intoClause ] <subquery>`"
             | #unconditional_multi_table_insert() : "`INSERT [OVERWRITE] ALL intoClause [ ... ] <subquery>`"
@@ -2250,11 +2348,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #show_users : "`SHOW USERS`"
             | #describe_user: "`DESCRIBE USER <user_name>`"
             | #create_user : "`CREATE [OR REPLACE] USER [IF NOT EXISTS] '<username>' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
-            | #alter_user : "`ALTER USER ('<username>' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
+            | #alter_user : "`ALTER USER ('<username>' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...] [SET LIMITS (<limit> = <u64_literal>, ...) | UNSET LIMITS]`"
             | #drop_user : "`DROP USER [IF EXISTS] '<username>'`"
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE [IF EXISTS] <role_name> {SET WORKLOAD GROUP = '<name>' | UNSET WORKLOAD GROUP}`"
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
@@ -2401,6 +2500,9 @@ AS
             | #show_procedures : "`SHOW PROCEDURES [<show_options>]()`"
             | #describe_procedure : "`DESC PROCEDURE <procedure_name>()`"
             | #call_procedure : "`CALL PROCEDURE <procedure_name>()`"
+            | #prepare : "`PREPARE <name> FROM <statement>`"
+            | #execute : "`EXECUTE <name> [USING <expr>, ...]`"
+            | #deallocate : "`{DEALLOCATE | DROP} PREPARE <name>`"
         ),
     ))(i)
 }
@@ -4056,9 +4158,22 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, val)| UserOptionItem::MustChangePassword(val),
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| UserOptionItem::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
+    );
 
     rule!(
-        #tenant_setting
+        #user_limits_option
+        | #tenant_setting
         | #no_tenant_setting
         | #default_role_option
         | #set_network_policy
@@ -4067,6 +4182,67 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_password_policy
         | #set_disabled_option
         | #must_change_password
+        | #set_workload_group
+        | #unset_workload_group
+    )(i)
+}
+
+pub fn alter_role_action(i: Input) -> IResult<AlterRoleAction> {
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| AlterRoleAction::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| AlterRoleAction::UnsetWorkloadGroup,
+    );
+
+    rule!(
+        #set_workload_group
+        | #unset_workload_group
+    )(i)
+}
+
+pub fn user_limits_option(i: Input) -> IResult<UserOptionItem> {
+    let limit = map(
+        rule! {
+            #ident ~ "=" ~ #literal_u64
+        },
+        |(name, _, value)| (name.name.to_lowercase(), value),
+    );
+    let set_limits = map_res(
+        rule! {
+            SET ~ LIMITS ~ ^"(" ~ ^#comma_separated_list1(limit) ~ ^")"
+        },
+        |(_, _, _, limits, _)| {
+            let mut option = UserLimitsOption::default();
+            for (name, value) in limits {
+                let limit = match name.as_str() {
+                    "max_concurrent_queries" => &mut option.max_concurrent_queries,
+                    "max_result_rows" => &mut option.max_result_rows,
+                    "max_scan_bytes_per_query" => &mut option.max_scan_bytes_per_query,
+                    "max_scan_bytes_per_day" => &mut option.max_scan_bytes_per_day,
+                    "max_execution_time" => &mut option.max_execution_time,
+                    _ => {
+                        return Err(nom::Err::Failure(ErrorKind::Other(
+                            "unknown user limit, expected one of MAX_CONCURRENT_QUERIES, MAX_RESULT_ROWS, MAX_SCAN_BYTES_PER_QUERY, MAX_SCAN_BYTES_PER_DAY, MAX_EXECUTION_TIME",
+                        )));
+                    }
+                };
+                *limit = Some(value);
+            }
+            Ok(UserOptionItem::SetLimits(option))
+        },
+    );
+    let unset_limits = value(UserOptionItem::UnsetLimits, rule! { UNSET ~ LIMITS });
+
+    rule!(
+        #set_limits
+        | #unset_limits
     )(i)
 }
 
@@ -488,6 +488,8 @@ pub enum TokenKind {
     DATETIME,
     #[token("DAY", ignore(ascii_case))]
     DAY,
+    #[token("DEALLOCATE", ignore(ascii_case))]
+    DEALLOCATE,
     #[token("DECADE", ignore(ascii_case))]
     DECADE,
     #[token("DECIMAL", ignore(ascii_case))]
@@ -550,6 +552,8 @@ pub enum TokenKind {
     ELSE,
     #[token("EMPTY_FIELD_AS", ignore(ascii_case))]
     EMPTY_FIELD_AS,
+    #[token("EMPTY", ignore(ascii_case))]
+    EMPTY,
     #[token("ENABLE", ignore(ascii_case))]
     ENABLE,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
@@ -562,10 +566,14 @@ pub enum TokenKind {
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
     ENGINES,
+    #[token("ENUM", ignore(ascii_case))]
+    ENUM,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
     ERROR_ON_COLUMN_COUNT_MISMATCH,
+    #[token("ERROR", ignore(ascii_case))]
+    ERROR,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
     #[token("EXCEPTION_BACKTRACE", ignore(ascii_case))]
@@ -650,6 +658,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -692,8 +702,12 @@ pub enum TokenKind {
     INSERT,
     #[token("INT", ignore(ascii_case))]
     INT,
+    #[token("INT128", ignore(ascii_case))]
+    INT128,
     #[token("INT16", ignore(ascii_case))]
     INT16,
+    #[token("INT256", ignore(ascii_case))]
+    INT256,
     #[token("INT32", ignore(ascii_case))]
     INT32,
     #[token("INT64", ignore(ascii_case))]
@@ -724,6 +738,8 @@ pub enum TokenKind {
     JOIN,
     #[token("JSON", ignore(ascii_case))]
     JSON,
+    #[token("JSON_TABLE", ignore(ascii_case))]
+    JSON_TABLE,
     #[token("JULIAN", ignore(ascii_case))]
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
@@ -761,6 +777,8 @@ pub enum TokenKind {
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
     LIMIT,
+    #[token("LIMITS", ignore(ascii_case))]
+    LIMITS,
     #[token("LIST", ignore(ascii_case))]
     LIST,
     #[token("LOW", ignore(ascii_case))]
@@ -805,6 +823,8 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NESTED", ignore(ascii_case))]
+    NESTED,
     #[token("DISABLED", ignore(ascii_case))]
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
@@ -845,6 +865,8 @@ pub enum TokenKind {
     ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("ORDINALITY", ignore(ascii_case))]
+    ORDINALITY,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -883,6 +905,8 @@ pub enum TokenKind {
     PASSWORD_LOCKOUT_TIME_MINS,
     #[token("PASSWORD_HISTORY", ignore(ascii_case))]
     PASSWORD_HISTORY,
+    #[token("PATH", ignore(ascii_case))]
+    PATH,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
@@ -963,6 +987,8 @@ pub enum TokenKind {
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
     PRECISION,
+    #[token("PREPARE", ignore(ascii_case))]
+    PREPARE,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
     #[token("PRIVILEGES", ignore(ascii_case))]
@@ -1081,6 +1107,8 @@ pub enum TokenKind {
     STREAMS,
     #[token("STRING", ignore(ascii_case))]
     STRING,
+    #[token("SUBSTRAIT", ignore(ascii_case))]
+    SUBSTRAIT,
     #[token("SUBSTRING", ignore(ascii_case))]
     SUBSTRING,
     #[token("SUBSTR", ignore(ascii_case))]
@@ -1117,8 +1145,14 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
+    #[token("TIMESTAMPTZ", ignore(ascii_case))]
+    TIMESTAMPTZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -1153,8 +1187,12 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UINT128", ignore(ascii_case))]
+    UINT128,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
+    #[token("UINT256", ignore(ascii_case))]
+    UINT256,
     #[token("UINT32", ignore(ascii_case))]
     UINT32,
     #[token("UINT64", ignore(ascii_case))]
@@ -1179,6 +1217,8 @@ pub enum TokenKind {
     USERS,
     #[token("USING", ignore(ascii_case))]
     USING,
+    #[token("UUID", ignore(ascii_case))]
+    UUID,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
     #[token("VALUES", ignore(ascii_case))]
@@ -1193,6 +1233,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VARIABLE", ignore(ascii_case))]
     VARIABLE,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
     #[token("VIEW", ignore(ascii_case))]
@@ -1211,6 +1253,10 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WITHOUT", ignore(ascii_case))]
+    WITHOUT,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1219,6 +1265,8 @@ pub enum TokenKind {
     XZ,
     #[token("YEAR", ignore(ascii_case))]
     YEAR,
+    #[token("ZONE", ignore(ascii_case))]
+    ZONE,
     #[token("ZSTD", ignore(ascii_case))]
     ZSTD,
     #[token("NULLIF", ignore(ascii_case))]
@@ -183,6 +183,11 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, DISABLED=true, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET WORKLOAD GROUP = 'etl';"#,
+        r#"ALTER USER u1 SET LIMITS (max_concurrent_queries = 4, max_scan_bytes_per_day = 1073741824);"#,
+        r#"ALTER USER u1 UNSET LIMITS;"#,
+        r#"ALTER ROLE r1 SET WORKLOAD GROUP = 'etl';"#,
+        r#"ALTER ROLE IF EXISTS 'r1' UNSET WORKLOAD GROUP;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH disabled=true"#,
@@ -607,6 +612,9 @@ fn test_statement() {
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"CREATE OR REPLACE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl WITH cpu_quota='40%', memory_quota='30%', max_concurrency=4, queue_timeout=60"#,
+        r#"ALTER WORKLOAD GROUP etl SET max_concurrency=8"#,
+        r#"DROP WORKLOAD GROUP IF EXISTS etl"#,
         // dynamic tables
         r#"
             CREATE OR REPLACE DYNAMIC TABLE db.MyDynamic LIKE t
@@ -1101,6 +1109,7 @@ fn test_query() {
         r#"select * from t left join lateral(select 1) on true, lateral(select 2)"#,
         r#"select * from t, lateral flatten(input => u.col) f"#,
         r#"select * from flatten(input => parse_json('{"a":1, "b":[77,88]}'), outer => true)"#,
+        r#"select * from t, json_table(t.j, '$[*]' columns (id for ordinality, a int path '$.a' default 0 on empty)) as jt"#,
     ];
 
     for case in cases {
@@ -1183,6 +1192,12 @@ fn test_expr() {
         r#"substring(a from b for c)"#,
         r#"substring(a, b, c)"#,
         r#"col1::UInt8"#,
+        r#"INTERVAL '1 day 3 hours'"#,
+        r#"TIME '12:30:00'"#,
+        r#"CAST(col1 AS TIMESTAMP WITH TIME ZONE)"#,
+        r#"CAST(col1 AS VECTOR(3))"#,
+        r#"CAST(col1 AS ENUM('a', 'b'))"#,
+        r#"CAST(col1 AS UUID)"#,
         r#"(arr[0]:a).b"#,
         r#"arr[4]["k"]"#,
         r#"a rlike '^11'"#,
@@ -22,9 +22,9 @@ use databend_common_meta_app::schema::TableInfo;
 pub struct ResultScanTableInfo {
     pub table_info: TableInfo,
     pub query_id: String,
-    pub location: String,
+    /// The locations and the sizes of the result files.
+    pub files: Vec<(String, u64)>,
     pub schema: Schema,
-    pub file_size: u64,
 }
 
 impl ResultScanTableInfo {
@@ -22,8 +22,10 @@ use std::time::Duration;
 use std::time::SystemTime;
 
 use dashmap::DashMap;
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::MemStat;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
@@ -101,6 +103,10 @@ pub struct ProcessInfo {
     pub created_time: SystemTime,
     pub status_info: Option<String>,
     pub current_query_id: Option<String>,
+    /// The position in the queries queue, if the query is waiting in the queue.
+    pub queue_position: Option<u64>,
+    /// The time the query waits, or has waited, in the queries queue.
+    pub queued_duration: Duration,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -120,6 +126,15 @@ impl Display for ProcessInfoState {
     }
 }
 
+/// The runtime status of a workload group on this node.
+#[derive(Debug, Clone, Default)]
+pub struct WorkloadGroupStatus {
+    pub name: String,
+    pub running_queries: u64,
+    pub queued_queries: u64,
+    pub memory_usage: i64,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -127,6 +142,14 @@ pub struct StageAttachment {
     pub copy_options: Option<BTreeMap<String, String>>,
 }
 
+/// A statement prepared by `PREPARE` or a client protocol, kept in the session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedStatement {
+    /// The statement with `?` placeholders.
+    pub statement: Statement,
+    pub param_count: usize,
+}
+
 #[derive(Debug, Default)]
 pub struct FilteredCopyFiles {
     pub files_to_copy: Vec<StageFileInfo>,
@@ -247,6 +270,9 @@ pub trait TableContext: Send + Sync {
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queued_queries(&self) -> Vec<ProcessInfo>;
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>>;
+    fn get_workload_groups_status(&self) -> Vec<WorkloadGroupStatus>;
+    /// The memory stat of the workload group the query belongs to, if any.
+    fn get_workload_group_mem_stat(&self) -> Option<Arc<MemStat>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
@@ -353,6 +379,10 @@ pub trait TableContext: Send + Sync {
     fn get_variable(&self, key: &str) -> Option<Scalar>;
     fn get_all_variables(&self) -> HashMap<String, Scalar>;
 
+    fn set_prepared_statement(&self, name: String, stmt: PreparedStatement);
+    fn get_prepared_statement(&self, name: &str) -> Option<PreparedStatement>;
+    fn remove_prepared_statement(&self, name: &str) -> Option<PreparedStatement>;
+
     async fn load_datalake_schema(
         &self,
         _kind: &str,
@@ -1372,6 +1372,28 @@ impl<'de> serde::de::Visitor<'de> for SettingVisitor {
     }
 }
 
+/// A named queue of the queries queue.
+///
+/// ```toml
+/// [[query.queues]]
+/// name = "etl"
+/// max_running_queries = 2
+/// priority = 0
+/// timeout_secs = 3600
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QueryQueueConfig {
+    pub name: String,
+    /// The max running queries of this queue, 0 means only limited by the node.
+    pub max_running_queries: u64,
+    /// The queries of a queue with higher priority are admitted first.
+    pub priority: u64,
+    /// The max time in seconds a query waits in this queue, 0 means using the
+    /// `statement_queued_timeout_in_seconds` setting.
+    pub timeout_secs: u64,
+}
+
 /// Query config group.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default, deny_unknown_fields)]
@@ -1408,6 +1430,23 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The max total estimated memory in bytes of the queries admitted by the queries queue,
+    /// 0 means unlimited.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub queries_queue_max_memory_usage: u64,
+
+    /// The max total threads of the queries admitted by the queries queue, 0 means unlimited.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub queries_queue_max_threads: u64,
+
+    /// The max running queries of the whole cluster, coordinated by leases in meta service.
+    /// 0 means the queries queue of each node works independently.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub cluster_max_running_queries: u64,
+
+    #[clap(skip)]
+    queues: Vec<QueryQueueConfig>,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1420,14 +1459,25 @@ pub struct QueryConfig {
     )]
     pub max_memory_limit_enabled: bool,
 
-    #[deprecated(note = "clickhouse tcp support is deprecated")]
+    /// Start the handler of the ClickHouse native protocol, which is off by default
+    /// because the port 9000 is often used by other services.
+    #[clap(
+        long,
+        value_name = "VALUE",
+        value_parser = clap::value_parser!(bool),
+        default_value = "false"
+    )]
+    pub clickhouse_handler_enabled: bool,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
     pub clickhouse_handler_host: String,
 
-    #[deprecated(note = "clickhouse tcp support is deprecated")]
     #[clap(long, value_name = "VALUE", default_value = "9000")]
     pub clickhouse_handler_port: u16,
 
+    #[clap(long, value_name = "VALUE", default_value = "120")]
+    pub clickhouse_handler_tcp_keepalive_timeout_secs: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
     pub clickhouse_http_handler_host: String,
 
@@ -1715,8 +1765,17 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            queries_queue_max_memory_usage: self.queries_queue_max_memory_usage,
+            queries_queue_max_threads: self.queries_queue_max_threads,
+            cluster_max_running_queries: self.cluster_max_running_queries,
+            queues: self.queues,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
+            clickhouse_handler_enabled: self.clickhouse_handler_enabled,
+            clickhouse_handler_host: self.clickhouse_handler_host,
+            clickhouse_handler_port: self.clickhouse_handler_port,
+            clickhouse_handler_tcp_keepalive_timeout_secs: self
+                .clickhouse_handler_tcp_keepalive_timeout_secs,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
             clickhouse_http_handler_port: self.clickhouse_http_handler_port,
             http_handler_host: self.http_handler_host,
@@ -1785,7 +1844,6 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
     }
 }
 
-#[allow(deprecated)]
 impl From<InnerQueryConfig> for QueryConfig {
     fn from(inner: InnerQueryConfig) -> Self {
         Self {
@@ -1800,13 +1858,17 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            queries_queue_max_memory_usage: inner.queries_queue_max_memory_usage,
+            queries_queue_max_threads: inner.queries_queue_max_threads,
+            cluster_max_running_queries: inner.cluster_max_running_queries,
+            queues: inner.queues,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
-
-            // clickhouse tcp is deprecated
-            clickhouse_handler_host: "127.0.0.1".to_string(),
-            clickhouse_handler_port: 9000,
-
+            clickhouse_handler_enabled: inner.clickhouse_handler_enabled,
+            clickhouse_handler_host: inner.clickhouse_handler_host,
+            clickhouse_handler_port: inner.clickhouse_handler_port,
+            clickhouse_handler_tcp_keepalive_timeout_secs: inner
+                .clickhouse_handler_tcp_keepalive_timeout_secs,
             clickhouse_http_handler_host: inner.clickhouse_http_handler_host,
             clickhouse_http_handler_port: inner.clickhouse_http_handler_port,
             http_handler_host: inner.http_handler_host,
@@ -2856,6 +2918,18 @@ pub struct CacheConfig {
     )]
     pub table_data_deserialized_memory_ratio: u64,
 
+    /// Max bytes of the on-disk tier of table meta caches. By default it is 0 (disabled)
+    ///
+    /// If enabled, table snapshots, segments, bloom index and inverted index files evicted
+    /// from (or missing in) the in-memory caches are looked up in a local disk cache, which
+    /// is located in the `disk.path` directory and survives restarts.
+    #[clap(
+        long = "cache-table-meta-disk-cache-bytes",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub table_meta_disk_cache_bytes: u64,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count", value_name = "VALUE")]
@@ -3069,6 +3143,7 @@ mod cache_config_converters {
                 data_cache_key_reload_policy: value.data_cache_key_reload_policy.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                table_meta_disk_cache_bytes: value.table_meta_disk_cache_bytes,
             })
         }
     }
@@ -3096,6 +3171,7 @@ mod cache_config_converters {
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                table_meta_disk_cache_bytes: value.table_meta_disk_cache_bytes,
                 table_meta_segment_count: None,
             }
         }
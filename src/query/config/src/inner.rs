@@ -36,6 +36,7 @@ use databend_common_tracing::Config as LogConfig;
 
 use super::config::Commands;
 use super::config::Config;
+use super::config::QueryQueueConfig;
 use crate::background_config::InnerBackgroundConfig;
 use crate::BuiltInConfig;
 
@@ -172,8 +173,17 @@ pub struct QueryConfig {
     pub mysql_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    pub queries_queue_max_memory_usage: u64,
+    pub queries_queue_max_threads: u64,
+    pub cluster_max_running_queries: u64,
+    /// The named queues, the queries choose a queue by the `query_queue` setting.
+    pub queues: Vec<QueryQueueConfig>,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
+    pub clickhouse_handler_enabled: bool,
+    pub clickhouse_handler_host: String,
+    pub clickhouse_handler_port: u16,
+    pub clickhouse_handler_tcp_keepalive_timeout_secs: u64,
     pub clickhouse_http_handler_host: String,
     pub clickhouse_http_handler_port: u16,
     pub http_handler_host: String,
@@ -264,8 +274,16 @@ impl Default for QueryConfig {
             mysql_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 8,
+            queries_queue_max_memory_usage: 0,
+            queries_queue_max_threads: 0,
+            cluster_max_running_queries: 0,
+            queues: vec![],
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
+            clickhouse_handler_enabled: false,
+            clickhouse_handler_host: "127.0.0.1".to_string(),
+            clickhouse_handler_port: 9000,
+            clickhouse_handler_tcp_keepalive_timeout_secs: 120,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
             clickhouse_http_handler_port: 8124,
             http_handler_host: "127.0.0.1".to_string(),
@@ -610,6 +628,13 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_memory_ratio: u64,
+
+    /// Max bytes of the on-disk tier of table meta caches. By default it is 0 (disabled)
+    ///
+    /// If enabled, table snapshots, segments, bloom index and inverted index files evicted
+    /// from (or missing in) the in-memory caches are looked up in a local disk cache, which
+    /// is located in the `disk.path` directory and survives restarts.
+    pub table_meta_disk_cache_bytes: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -704,6 +729,7 @@ impl Default for CacheConfig {
             data_cache_key_reload_policy: Default::default(),
             table_data_deserialized_data_bytes: 0,
             table_data_deserialized_memory_ratio: 0,
+            table_meta_disk_cache_bytes: 0,
         }
     }
 }
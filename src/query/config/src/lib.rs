@@ -41,6 +41,7 @@ pub use config::CacheStorageTypeConfig;
 pub use config::Commands;
 pub use config::Config;
 pub use config::QueryConfig;
+pub use config::QueryQueueConfig;
 pub use config::StorageConfig;
 pub use global::GlobalConfig;
 pub use inner::CacheConfig;
@@ -23,6 +23,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storages_fuse::index::VirtualColumnIndex;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::io::SnapshotLiteExtended;
 use databend_common_storages_fuse::io::SnapshotsIO;
@@ -40,6 +41,7 @@ pub struct SnapshotReferencedFiles {
     pub segments: HashSet<String>,
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
+    pub virtual_indexes: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -54,6 +56,9 @@ impl SnapshotReferencedFiles {
         for file in &self.blocks_index {
             files.push(file.clone());
         }
+        for file in &self.virtual_indexes {
+            files.push(file.clone());
+        }
         files
     }
 }
@@ -128,10 +133,16 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let virtual_indexes = locations_referenced
+        .block_location
+        .iter()
+        .map(|loc| TableMetaLocationGenerator::gen_virtual_index_location_from_block_location(loc))
+        .collect();
     Ok(Some(SnapshotReferencedFiles {
         segments,
         blocks: locations_referenced.block_location,
         blocks_index: locations_referenced.bloom_location,
+        virtual_indexes,
     }))
 }
 
@@ -266,6 +277,33 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
+    // 5. Purge orphan virtual column index files.
+    // 5.1 Get orphan virtual column index files to be purged
+    let virtual_index_locations_to_be_purged =
+        get_orphan_files_to_be_purged(fuse_table, referenced_files.virtual_indexes, retention_time)
+            .await?;
+    let status = format!(
+        "gc orphan: read virtual_index_locations_to_be_purged:{}, cost:{:?}",
+        virtual_index_locations_to_be_purged.len(),
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
+    // 5.2 Delete all the orphan virtual column index files to be purged
+    let purged_file_num = virtual_index_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files_and_cache::<VirtualColumnIndex, _>(
+            ctx.clone(),
+            HashSet::from_iter(virtual_index_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged virtual column index files:{}, cost:{:?}",
+        purged_file_num,
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
     Ok(())
 }
 
@@ -334,6 +372,22 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     purge_files.extend(index_locations_to_be_purged);
+    if purge_files.len() >= dry_run_limit {
+        return Ok(());
+    }
+
+    // 5. Get purge orphan virtual column index files.
+    let virtual_index_locations_to_be_purged =
+        get_orphan_files_to_be_purged(fuse_table, referenced_files.virtual_indexes, retention_time)
+            .await?;
+    let status = format!(
+        "dry_run orphan: read virtual_index_locations_to_be_purged:{}, cost:{:?}",
+        virtual_index_locations_to_be_purged.len(),
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(virtual_index_locations_to_be_purged);
 
     Ok(())
 }
@@ -30,6 +30,7 @@ use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_sql::parse_computed_expr;
+use databend_common_storages_fuse::index::VirtualColumnIndex;
 use databend_common_storages_fuse::io::serialize_block;
 use databend_common_storages_fuse::io::write_data;
 use databend_common_storages_fuse::io::MetaReaders;
@@ -134,7 +135,12 @@ pub async fn do_refresh_virtual_column(
             } else {
                 false
             };
-            if all_generated {
+            // blocks refreshed by old versions may have no virtual column index
+            let virtual_index_loc =
+                TableMetaLocationGenerator::gen_virtual_index_location_from_block_location(
+                    &block_meta.location.0,
+                );
+            if all_generated && operator.is_exist(&virtual_index_loc).await? {
                 continue;
             }
 
@@ -143,6 +149,7 @@ pub async fn do_refresh_virtual_column(
                 operator,
                 &write_settings,
                 &virtual_loc,
+                &virtual_index_loc,
                 source_schema.clone(),
                 &virtual_exprs,
                 block,
@@ -160,6 +167,7 @@ async fn materialize_virtual_columns(
     operator: &Operator,
     write_settings: &WriteSettings,
     location: &str,
+    index_location: &str,
     source_schema: DataSchemaRef,
     virtual_exprs: &Vec<String>,
     block: DataBlock,
@@ -182,10 +190,14 @@ async fn materialize_virtual_columns(
     let virtual_schema = TableSchemaRefExt::create(virtual_fields);
     let virtual_block = DataBlock::new(virtual_columns, len);
 
+    // Collect the statistics and filters of virtual columns to prune blocks.
+    let virtual_index = VirtualColumnIndex::try_create(&func_ctx, &virtual_block, virtual_exprs)?;
+
     let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
     let _ = serialize_block(write_settings, &virtual_schema, virtual_block, &mut buffer)?;
 
     write_data(buffer, operator, location).await?;
+    write_data(virtual_index.to_bytes()?, operator, index_location).await?;
 
     Ok(())
 }
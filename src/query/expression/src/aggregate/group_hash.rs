@@ -14,9 +14,12 @@
 
 use databend_common_base::base::OrderedFloat;
 use ethnum::i256;
+use ethnum::u256;
 
 use crate::types::decimal::DecimalType;
 use crate::types::geometry::GeometryType;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::large_int::LargeIntType;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::BinaryType;
@@ -25,12 +28,15 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::NumberColumn;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
+use crate::with_large_int_mapped_type;
 use crate::with_number_mapped_type;
 use crate::Column;
 use crate::InputColumns;
@@ -74,14 +80,24 @@ pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut
                 combine_group_hash_type_column::<IS_FIRST, DecimalType<i256>>(c, values)
             }
         },
+        DataType::LargeInt(v) => with_large_int_mapped_type!(|LARGE_INT| match v {
+            LargeIntDataType::LARGE_INT => {
+                combine_group_hash_type_column::<IS_FIRST, LargeIntType<LARGE_INT>>(c, values)
+            }
+        }),
         DataType::Boolean => combine_group_hash_type_column::<IS_FIRST, BooleanType>(c, values),
         DataType::Timestamp => combine_group_hash_type_column::<IS_FIRST, TimestampType>(c, values),
         DataType::Date => combine_group_hash_type_column::<IS_FIRST, DateType>(c, values),
+        DataType::Uuid => combine_group_hash_type_column::<IS_FIRST, UuidType>(c, values),
         DataType::Binary => combine_group_hash_string_column::<IS_FIRST, BinaryType>(c, values),
         DataType::String => combine_group_hash_string_column::<IS_FIRST, StringType>(c, values),
         DataType::Bitmap => combine_group_hash_string_column::<IS_FIRST, BitmapType>(c, values),
         DataType::Variant => combine_group_hash_string_column::<IS_FIRST, VariantType>(c, values),
         DataType::Geometry => combine_group_hash_string_column::<IS_FIRST, GeometryType>(c, values),
+        DataType::Enum(_) => {
+            let codes = Column::Number(NumberColumn::UInt16(c.as_enum().unwrap().codes.clone()));
+            combine_group_hash_type_column::<IS_FIRST, NumberType<u16>>(&codes, values)
+        }
         DataType::Nullable(_) => {
             let col = c.as_nullable().unwrap();
             if IS_FIRST {
@@ -229,12 +245,24 @@ impl AggHash for i128 {
     }
 }
 
+impl AggHash for u128 {
+    fn agg_hash(&self) -> u64 {
+        self.to_le_bytes().agg_hash()
+    }
+}
+
 impl AggHash for i256 {
     fn agg_hash(&self) -> u64 {
         self.to_le_bytes().agg_hash()
     }
 }
 
+impl AggHash for u256 {
+    fn agg_hash(&self) -> u64 {
+        self.to_le_bytes().agg_hash()
+    }
+}
+
 impl AggHash for OrderedFloat<f32> {
     #[inline(always)]
     fn agg_hash(&self) -> u64 {
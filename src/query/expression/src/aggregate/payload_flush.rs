@@ -24,6 +24,8 @@ use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::Decimal;
 use crate::types::decimal::DecimalType;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::large_int::LargeIntType;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::ArgType;
@@ -31,10 +33,13 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalSize;
+use crate::types::EnumColumn;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
+use crate::with_large_int_mapped_type;
 use crate::with_number_mapped_type;
 use crate::AggregateFunctionRef;
 use crate::Column;
@@ -244,8 +249,18 @@ impl Payload {
                     self.flush_decimal_column::<i256>(col_offset, state, s)
                 }
             },
+            DataType::LargeInt(v) => with_large_int_mapped_type!(|LARGE_INT| match v {
+                LargeIntDataType::LARGE_INT =>
+                    self.flush_type_column::<LargeIntType<LARGE_INT>>(col_offset, state),
+            }),
             DataType::Timestamp => self.flush_type_column::<TimestampType>(col_offset, state),
             DataType::Date => self.flush_type_column::<DateType>(col_offset, state),
+            DataType::Uuid => self.flush_type_column::<UuidType>(col_offset, state),
+            DataType::Enum(values) => {
+                let codes = self.flush_type_column::<NumberType<u16>>(col_offset, state);
+                let codes = NumberType::<u16>::try_downcast_column(&codes).unwrap();
+                Column::Enum(EnumColumn::new(codes, values))
+            }
             DataType::Binary => Column::Binary(self.flush_binary_column(col_offset, state)),
             DataType::String => Column::String(self.flush_string_column(col_offset, state)),
             DataType::Bitmap => Column::Bitmap(self.flush_binary_column(col_offset, state)),
@@ -23,6 +23,8 @@ use crate::store;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalType;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntType;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::BinaryType;
@@ -32,8 +34,10 @@ use crate::types::DateType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
+use crate::with_large_int_mapped_type;
 use crate::with_number_mapped_type;
 use crate::Column;
 use crate::InputColumns;
@@ -49,15 +53,22 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
             crate::types::DecimalDataType::Decimal128(_) => 16,
             crate::types::DecimalDataType::Decimal256(_) => 32,
         },
+        DataType::LargeInt(n) => n.bit_width() as usize / 8,
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Enum(_) => 2,
+        DataType::Uuid => 16,
         // use address instead
         DataType::Binary
         | DataType::String
         | DataType::Bitmap
         | DataType::Variant
         | DataType::Geometry
-        | DataType::Geography => 4 + 8, // u32 len + address
+        | DataType::Geography
+        | DataType::Interval
+        | DataType::Time
+        | DataType::TimestampTz
+        | DataType::Vector(_) => 4 + 8, // u32 len + address
         DataType::Nullable(x) => rowformat_size(x),
         DataType::Array(_) | DataType::Map(_) | DataType::Tuple(_) => 4 + 8,
         DataType::Generic(_) => unreachable!(),
@@ -92,6 +103,15 @@ pub unsafe fn serialize_column_to_rowformat(
                 }
             })
         }
+        Column::LargeInt(v) => {
+            with_large_int_mapped_type!(|LARGE_INT| match v {
+                LargeIntColumn::LARGE_INT(buffer) => {
+                    for index in select_vector.iter().take(rows).copied() {
+                        store(&buffer[index], address[index].add(offset) as *mut u8);
+                    }
+                }
+            })
+        }
         Column::Boolean(v) => {
             if v.unset_bits() == 0 || v.unset_bits() == v.len() {
                 let val: u8 = if v.unset_bits() == 0 { 1 } else { 0 };
@@ -138,6 +158,16 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Enum(col) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&col.codes[index], address[index].add(offset) as *mut u8);
+            }
+        }
+        Column::Uuid(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -266,6 +296,22 @@ pub unsafe fn row_match_column(
                 no_match_count,
             ),
         },
+        Column::LargeInt(v) => with_large_int_mapped_type!(|LARGE_INT| match v {
+            LargeIntColumn::LARGE_INT(_) => {
+                row_match_column_type::<LargeIntType<LARGE_INT>>(
+                    col,
+                    validity,
+                    address,
+                    select_vector,
+                    temp_vector,
+                    count,
+                    validity_offset,
+                    col_offset,
+                    no_match,
+                    no_match_count,
+                )
+            }
+        }),
         Column::Boolean(_) => row_match_column_type::<BooleanType>(
             col,
             validity,
@@ -302,6 +348,33 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Enum(v) => {
+            let codes = Column::Number(NumberColumn::UInt16(v.codes.clone()));
+            row_match_column_type::<NumberType<u16>>(
+                &codes,
+                validity,
+                address,
+                select_vector,
+                temp_vector,
+                count,
+                validity_offset,
+                col_offset,
+                no_match,
+                no_match_count,
+            )
+        }
+        Column::Uuid(_) => row_match_column_type::<UuidType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
         Column::Bitmap(v) | Column::Binary(v) | Column::Variant(v) | Column::Geometry(v) => {
             row_match_binary_column(
                 v,
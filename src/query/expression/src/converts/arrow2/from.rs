@@ -24,14 +24,30 @@ use databend_common_exception::Result;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_ENUM;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INT128;
+use super::ARROW_EXT_TYPE_INT256;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
+use super::ARROW_EXT_TYPE_UINT128;
+use super::ARROW_EXT_TYPE_UINT256;
+use super::ARROW_EXT_TYPE_UUID;
 use super::ARROW_EXT_TYPE_VARIANT;
+use super::ARROW_EXT_TYPE_VECTOR;
 use crate::types::array::ArrayColumn;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::enumeration::EnumColumn;
 use crate::types::geography::GeographyColumn;
+use crate::types::interval::months_days_micros;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntDataType;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
+use crate::types::timestamp_tz::timestamp_tz;
+use crate::types::uuid::UUID_BYTES;
+use crate::types::vector::VectorColumn;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::DecimalSize;
@@ -121,7 +137,9 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
 
         ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => TableDataType::String,
 
-        ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
+        ArrowDataType::Timestamp(_, None) => TableDataType::Timestamp,
+        ArrowDataType::Timestamp(_, Some(_)) => TableDataType::TimestampTz,
+        ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
         ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
         ArrowDataType::Map(f, _) => {
             let inner_ty = arrow_type_to_table_type(&f.data_type, f.is_nullable)?;
@@ -139,12 +157,30 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
                 fields_type,
             }
         }
-        ArrowDataType::Extension(custom_name, data_type, _) => match custom_name.as_str() {
+        ArrowDataType::Extension(custom_name, data_type, metadata) => match custom_name.as_str() {
             ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
             ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
             ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
             ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
             ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+            ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+            ARROW_EXT_TYPE_TIMESTAMP_TZ => TableDataType::TimestampTz,
+            ARROW_EXT_TYPE_INT128 => TableDataType::LargeInt(LargeIntDataType::Int128),
+            ARROW_EXT_TYPE_UINT128 => TableDataType::LargeInt(LargeIntDataType::UInt128),
+            ARROW_EXT_TYPE_INT256 => TableDataType::LargeInt(LargeIntDataType::Int256),
+            ARROW_EXT_TYPE_UINT256 => TableDataType::LargeInt(LargeIntDataType::UInt256),
+            ARROW_EXT_TYPE_VECTOR => match data_type.as_ref() {
+                ArrowDataType::FixedSizeList(_, size) => TableDataType::Vector(*size as u64),
+                _ => arrow_type_to_table_type(data_type, is_nullable)?,
+            },
+            ARROW_EXT_TYPE_UUID => TableDataType::Uuid,
+            ARROW_EXT_TYPE_ENUM => match metadata
+                .as_ref()
+                .and_then(|values| serde_json::from_str::<Vec<String>>(values).ok())
+            {
+                Some(values) => TableDataType::Enum(values.into()),
+                None => arrow_type_to_table_type(data_type, is_nullable)?,
+            },
             _ => arrow_type_to_table_type(data_type, is_nullable)?,
         },
         _ => {
@@ -518,6 +554,51 @@ impl Column {
                     };
                     Column::Timestamp(values)
                 }
+                (DataType::TimestampTz, ArrowDataType::Timestamp(uint, _)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Int64Array>()
+                        .expect(
+                            "fail to read `TimestampTz` from arrow: array should be `Int64Array`",
+                        )
+                        .values();
+                    let convert = match uint {
+                        TimeUnit::Second => (1_000_000, 1),
+                        TimeUnit::Millisecond => (1_000, 1),
+                        TimeUnit::Microsecond => (1, 1),
+                        TimeUnit::Nanosecond => (1, 1_000),
+                    };
+                    // Values of an arrow timestamp with time zone are already in UTC.
+                    let values = values
+                        .iter()
+                        .map(|x| timestamp_tz::new(x * convert.0 / convert.1, 0))
+                        .collect::<Vec<_>>();
+                    Column::TimestampTz(values.into())
+                }
+                (DataType::Time, ArrowDataType::Time64(uint)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Int64Array>()
+                        .expect("fail to read `Time` from arrow: array should be `Int64Array`")
+                        .values();
+                    let values = match uint {
+                        TimeUnit::Nanosecond => values.iter().map(|x| x / 1_000).collect(),
+                        _ => values.clone(),
+                    };
+                    Column::Time(values)
+                }
+                (DataType::Time, ArrowDataType::Time32(uint)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Int32Array>()
+                        .expect("fail to read `Time` from arrow: array should be `Int32Array`")
+                        .values();
+                    let factor = match uint {
+                        TimeUnit::Second => 1_000_000,
+                        _ => 1_000,
+                    };
+                    Column::Time(values.iter().map(|x| *x as i64 * factor).collect())
+                }
                 (DataType::Date, ArrowDataType::Date32) => Column::Date(
                     arrow_col
                         .as_any()
@@ -797,6 +878,137 @@ impl Column {
                         offsets,
                     )))
                 }
+                (DataType::LargeInt(ty), ArrowDataType::Decimal(_, _)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `LargeInt` from arrow: array should be `PrimitiveArray<i128>`",
+                        );
+                    let values = arrow_col.values().clone();
+                    match ty {
+                        LargeIntDataType::Int128 => {
+                            Column::LargeInt(LargeIntColumn::Int128(values))
+                        }
+                        LargeIntDataType::UInt128 => {
+                            let values = unsafe {
+                                std::mem::transmute::<Buffer<i128>, Buffer<u128>>(values)
+                            };
+                            Column::LargeInt(LargeIntColumn::UInt128(values))
+                        }
+                        _ => {
+                            return Err(ErrorCode::Unimplemented(format!(
+                                "unsupported arrow type {arrow_type:?} for {ty}",
+                            )));
+                        }
+                    }
+                }
+                (DataType::LargeInt(ty), ArrowDataType::Decimal256(_, _)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<
+                            databend_common_arrow::arrow::types::i256,
+                        >>()
+                        .expect(
+                            "fail to read `LargeInt` from arrow: array should be `PrimitiveArray<i256>`",
+                        );
+                    match ty {
+                        LargeIntDataType::Int256 => {
+                            let values = unsafe {
+                                std::mem::transmute::<
+                                    Buffer<databend_common_arrow::arrow::types::i256>,
+                                    Buffer<ethnum::I256>,
+                                >(arrow_col.values().clone())
+                            };
+                            Column::LargeInt(LargeIntColumn::Int256(values))
+                        }
+                        LargeIntDataType::UInt256 => {
+                            let values = unsafe {
+                                std::mem::transmute::<
+                                    Buffer<databend_common_arrow::arrow::types::i256>,
+                                    Buffer<ethnum::U256>,
+                                >(arrow_col.values().clone())
+                            };
+                            Column::LargeInt(LargeIntColumn::UInt256(values))
+                        }
+                        _ => {
+                            return Err(ErrorCode::Unimplemented(format!(
+                                "unsupported arrow type {arrow_type:?} for {ty}",
+                            )));
+                        }
+                    }
+                }
+                (DataType::Interval, ArrowDataType::Decimal(_, _)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `Interval` from arrow: array should be `PrimitiveArray<i128>`",
+                        );
+                    let values = unsafe {
+                        std::mem::transmute::<Buffer<i128>, Buffer<months_days_micros>>(
+                            arrow_col.values().clone(),
+                        )
+                    };
+                    Column::Interval(values)
+                }
+                (DataType::TimestampTz, ArrowDataType::Decimal(_, _)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `TimestampTz` from arrow: array should be `PrimitiveArray<i128>`",
+                        );
+                    let values = unsafe {
+                        std::mem::transmute::<Buffer<i128>, Buffer<timestamp_tz>>(
+                            arrow_col.values().clone(),
+                        )
+                    };
+                    Column::TimestampTz(values)
+                }
+                (DataType::Vector(dim), ArrowDataType::FixedSizeList(_, size))
+                    if *dim == *size as u64 =>
+                {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::FixedSizeListArray>()
+                        .expect(
+                            "fail to read `Vector` from arrow: array should be `FixedSizeListArray`",
+                        );
+                    let values = arrow_col
+                        .values()
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Float32Array>()
+                        .expect("fail to read `Vector` from arrow: values should be `Float32Array`")
+                        .values()
+                        .clone();
+                    let values = unsafe { std::mem::transmute::<Buffer<f32>, Buffer<F32>>(values) };
+                    Column::Vector(VectorColumn::new(values, *size))
+                }
+                (DataType::Enum(values), ArrowDataType::UInt16) => {
+                    let codes = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::UInt16Array>()
+                        .expect("fail to read `Enum` from arrow: array should be `UInt16Array`")
+                        .values()
+                        .clone();
+                    Column::Enum(EnumColumn::new(codes, values.clone()))
+                }
+                (DataType::Uuid, ArrowDataType::FixedSizeBinary(UUID_BYTES)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::FixedSizeBinaryArray>()
+                        .expect(
+                            "fail to read `Uuid` from arrow: array should be `FixedSizeBinaryArray`",
+                        );
+                    Column::Uuid(
+                        arrow_col
+                            .values()
+                            .chunks_exact(UUID_BYTES)
+                            .map(|bytes| u128::from_be_bytes(bytes.try_into().unwrap()))
+                            .collect(),
+                    )
+                }
                 (data_type, ArrowDataType::Extension(_, arrow_type, _)) => {
                     from_arrow_with_arrow_type(arrow_col, arrow_type, data_type)?
                 }
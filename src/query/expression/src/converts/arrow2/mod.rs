@@ -21,5 +21,14 @@ pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 pub const ARROW_EXT_TYPE_GEOGRAPHY: &str = "Geography";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_TIMESTAMP_TZ: &str = "TimestampTz";
+pub const ARROW_EXT_TYPE_INT128: &str = "Int128";
+pub const ARROW_EXT_TYPE_UINT128: &str = "UInt128";
+pub const ARROW_EXT_TYPE_INT256: &str = "Int256";
+pub const ARROW_EXT_TYPE_UINT256: &str = "UInt256";
+pub const ARROW_EXT_TYPE_VECTOR: &str = "Vector";
+pub const ARROW_EXT_TYPE_ENUM: &str = "Enum";
+pub const ARROW_EXT_TYPE_UUID: &str = "Uuid";
 
 pub use to::set_validities;
@@ -23,11 +23,25 @@ use databend_common_arrow::arrow::offset::OffsetsBuffer;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_ENUM;
 use super::ARROW_EXT_TYPE_GEOGRAPHY;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INT128;
+use super::ARROW_EXT_TYPE_INT256;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
+use super::ARROW_EXT_TYPE_UINT128;
+use super::ARROW_EXT_TYPE_UINT256;
+use super::ARROW_EXT_TYPE_UUID;
 use super::ARROW_EXT_TYPE_VARIANT;
+use super::ARROW_EXT_TYPE_VECTOR;
 use crate::types::decimal::DecimalColumn;
 use crate::types::geography::GeographyColumn;
+use crate::types::interval::months_days_micros;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::timestamp_tz::timestamp_tz;
+use crate::types::uuid::UUID_BYTES;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
@@ -103,7 +117,23 @@ fn table_type_to_arrow_type(ty: &TableDataType) -> ArrowDataType {
         TableDataType::Decimal(DecimalDataType::Decimal256(size)) => {
             ArrowDataType::Decimal256(size.precision as usize, size.scale as usize)
         }
+        TableDataType::LargeInt(ty) => {
+            let (name, storage) = match ty {
+                LargeIntDataType::Int128 => (ARROW_EXT_TYPE_INT128, ArrowDataType::Decimal(38, 0)),
+                LargeIntDataType::UInt128 => {
+                    (ARROW_EXT_TYPE_UINT128, ArrowDataType::Decimal(38, 0))
+                }
+                LargeIntDataType::Int256 => {
+                    (ARROW_EXT_TYPE_INT256, ArrowDataType::Decimal256(76, 0))
+                }
+                LargeIntDataType::UInt256 => {
+                    (ARROW_EXT_TYPE_UINT256, ArrowDataType::Decimal256(76, 0))
+                }
+            };
+            ArrowDataType::Extension(name.to_string(), Box::new(storage), None)
+        }
         TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+        TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
         TableDataType::Date => ArrowDataType::Date32,
         TableDataType::Nullable(ty) => table_type_to_arrow_type(ty.as_ref()),
         TableDataType::Array(ty) => {
@@ -170,6 +200,36 @@ fn table_type_to_arrow_type(ty: &TableDataType) -> ArrowDataType {
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        TableDataType::TimestampTz => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        TableDataType::Vector(dim) => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_VECTOR.to_string(),
+            Box::new(ArrowDataType::FixedSizeList(
+                Box::new(ArrowField::new("_vector", ArrowDataType::Float32, false)),
+                *dim as usize,
+            )),
+            None,
+        ),
+        // the dictionary is kept in the metadata, only the codes are stored in the array
+        TableDataType::Enum(values) => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_ENUM.to_string(),
+            Box::new(ArrowDataType::UInt16),
+            Some(serde_json::to_string(values).unwrap()),
+        ),
+        // stored as the 16 bytes in network order, so parquet keeps them as `FIXED_LEN_BYTE_ARRAY(16)`
+        TableDataType::Uuid => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_UUID.to_string(),
+            Box::new(ArrowDataType::FixedSizeBinary(UUID_BYTES)),
+            None,
+        ),
     }
 }
 
@@ -282,6 +342,111 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::LargeInt(LargeIntColumn::Int128(col)) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::LargeInt(LargeIntColumn::UInt128(col)) => {
+                let values =
+                    unsafe { std::mem::transmute::<Buffer<u128>, Buffer<i128>>(col.clone()) };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::LargeInt(LargeIntColumn::Int256(col)) => {
+                let values = unsafe {
+                    std::mem::transmute::<
+                        Buffer<ethnum::I256>,
+                        Buffer<databend_common_arrow::arrow::types::i256>,
+                    >(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<
+                        databend_common_arrow::arrow::types::i256,
+                    >::try_new(arrow_type, values, None)
+                    .unwrap(),
+                )
+            }
+            Column::LargeInt(LargeIntColumn::UInt256(col)) => {
+                let values = unsafe {
+                    std::mem::transmute::<
+                        Buffer<ethnum::U256>,
+                        Buffer<databend_common_arrow::arrow::types::i256>,
+                    >(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<
+                        databend_common_arrow::arrow::types::i256,
+                    >::try_new(arrow_type, values, None)
+                    .unwrap(),
+                )
+            }
+            Column::Interval(col) => {
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<months_days_micros>, Buffer<i128>>(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::TimestampTz(col) => {
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<timestamp_tz>, Buffer<i128>>(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::Uuid(col) => {
+                let values = col.iter().flat_map(|v| v.to_be_bytes()).collect::<Vec<_>>();
+                Box::new(
+                    databend_common_arrow::arrow::array::FixedSizeBinaryArray::try_new(
+                        arrow_type,
+                        values.into(),
+                        None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::Enum(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<u16>::try_new(
+                    arrow_type,
+                    col.codes.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::Vector(col) => {
+                let values =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(col.values.clone()) };
+                let values = Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<f32>::try_new(
+                        ArrowDataType::Float32,
+                        values,
+                        None,
+                    )
+                    .unwrap(),
+                );
+                Box::new(
+                    databend_common_arrow::arrow::array::FixedSizeListArray::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => {
                 let values = unsafe {
                     std::mem::transmute::<
@@ -330,6 +495,14 @@ impl Column {
                     .unwrap(),
                 )
             }
+            Column::Time(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<i64>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
             Column::Timestamp(col) => Box::new(
                 databend_common_arrow::arrow::array::PrimitiveArray::<i64>::try_new(
                     arrow_type,
@@ -36,6 +36,7 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             crate::types::number::NumberScalar::Float64(x) => DataValue::Float64((*x).into()),
         },
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
+        Scalar::LargeInt(_) => unimplemented!("large integer type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
@@ -56,7 +57,13 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         | Scalar::Binary(_)
         | Scalar::Map(_)
         | Scalar::Bitmap(_)
-        | Scalar::Geography(_) => {
+        | Scalar::Geography(_)
+        | Scalar::Interval(_)
+        | Scalar::Time(_)
+        | Scalar::TimestampTz(_)
+        | Scalar::Vector(_)
+        | Scalar::Enum(_)
+        | Scalar::Uuid(_) => {
             unimplemented!()
         }
     }
@@ -150,7 +150,16 @@ impl From<Scalar> for LegacyScalar {
             Scalar::Timestamp(ts) => LegacyScalar::Timestamp(ts),
             Scalar::Date(date) => LegacyScalar::Date(date),
             Scalar::Boolean(b) => LegacyScalar::Boolean(b),
-            Scalar::Binary(_) | Scalar::Geometry(_) | Scalar::Geography(_) => unreachable!(),
+            Scalar::Binary(_)
+            | Scalar::Geometry(_)
+            | Scalar::Geography(_)
+            | Scalar::LargeInt(_)
+            | Scalar::Interval(_)
+            | Scalar::Time(_)
+            | Scalar::TimestampTz(_)
+            | Scalar::Vector(_)
+            | Scalar::Enum(_)
+            | Scalar::Uuid(_) => unreachable!(),
             Scalar::String(string) => LegacyScalar::String(string.as_bytes().to_vec()),
             Scalar::Array(column) => LegacyScalar::Array(column.into()),
             Scalar::Map(column) => LegacyScalar::Map(column.into()),
@@ -170,7 +179,16 @@ impl From<Column> for LegacyColumn {
             Column::Number(num_col) => LegacyColumn::Number(num_col),
             Column::Decimal(dec_col) => LegacyColumn::Decimal(dec_col),
             Column::Boolean(bmp) => LegacyColumn::Boolean(bmp),
-            Column::Binary(_) | Column::Geometry(_) | Column::Geography(_) => unreachable!(),
+            Column::Binary(_)
+            | Column::Geometry(_)
+            | Column::Geography(_)
+            | Column::LargeInt(_)
+            | Column::Interval(_)
+            | Column::Time(_)
+            | Column::TimestampTz(_)
+            | Column::Vector(_)
+            | Column::Enum(_)
+            | Column::Uuid(_) => unreachable!(),
             Column::String(str_col) => LegacyColumn::String(str_col.into()),
             Column::Timestamp(buf) => LegacyColumn::Timestamp(buf),
             Column::Date(buf) => LegacyColumn::Date(buf),
@@ -22,6 +22,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::types::decimal::DecimalScalar;
+use crate::types::large_int::LargeIntScalar;
 use crate::types::number::NumberScalar;
 use crate::Scalar;
 
@@ -38,6 +39,7 @@ pub enum IndexScalar {
     String(Vec<u8>),
     Tuple(Vec<IndexScalar>),
     BinaryV2(Vec<u8>),
+    LargeInt(LargeIntScalar),
 }
 
 impl TryFrom<IndexScalar> for Scalar {
@@ -55,6 +57,7 @@ impl TryFrom<IndexScalar> for Scalar {
                 ErrorCode::InvalidUtf8String(format!("invalid utf8 data for string type: {}", e))
             })?),
             IndexScalar::BinaryV2(s) => Scalar::Binary(s),
+            IndexScalar::LargeInt(i) => Scalar::LargeInt(i),
             IndexScalar::Tuple(tuple) => Scalar::Tuple(
                 tuple
                     .into_iter()
@@ -78,6 +81,7 @@ impl TryFrom<Scalar> for IndexScalar {
             Scalar::Boolean(b) => IndexScalar::Boolean(b),
             Scalar::String(string) => IndexScalar::String(string.as_bytes().to_vec()),
             Scalar::Binary(s) => IndexScalar::BinaryV2(s),
+            Scalar::LargeInt(i) => IndexScalar::LargeInt(i),
             Scalar::Tuple(tuple) => IndexScalar::Tuple(
                 tuple
                     .into_iter()
@@ -90,6 +94,12 @@ impl TryFrom<Scalar> for IndexScalar {
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
             | Scalar::Geography(_)
+            | Scalar::Interval(_)
+            | Scalar::Time(_)
+            | Scalar::TimestampTz(_)
+            | Scalar::Vector(_)
+            | Scalar::Enum(_)
+            | Scalar::Uuid(_)
             | Scalar::EmptyArray
             | Scalar::EmptyMap => return Err(ErrorCode::Unimplemented("Unsupported scalar type")),
         })
@@ -40,6 +40,8 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::NullableType;
+use crate::types::NumberColumn;
+use crate::types::NumberDataType;
 use crate::types::NumberScalar;
 use crate::types::VariantType;
 use crate::values::Column;
@@ -505,6 +507,20 @@ impl<'a> Evaluator<'a> {
                 }
                 other => unreachable!("source: {}", other),
             },
+            (DataType::Vector(_), DataType::Array(_)) => {
+                let value = match value {
+                    Value::Scalar(Scalar::Vector(v)) => Value::Scalar(Scalar::Array(
+                        Column::Number(NumberColumn::Float32(v.into())),
+                    )),
+                    Value::Column(Column::Vector(col)) => {
+                        Value::Column(Column::Array(Box::new(col.to_array_column())))
+                    }
+                    other => unreachable!("source: {}", other),
+                };
+                let array_type =
+                    DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+                self.run_cast(span, &array_type, dest_type, value, validity, options)
+            }
             (DataType::Variant, DataType::Array(inner_dest_ty)) => {
                 let empty_vec = vec![];
                 let temp_array: jsonb::Value;
@@ -792,6 +808,20 @@ impl<'a> Evaluator<'a> {
                 }
                 other => unreachable!("source: {}", other),
             },
+            (DataType::Vector(_), DataType::Array(_)) => {
+                let value = match value {
+                    Value::Scalar(Scalar::Vector(v)) => Value::Scalar(Scalar::Array(
+                        Column::Number(NumberColumn::Float32(v.into())),
+                    )),
+                    Value::Column(Column::Vector(col)) => {
+                        Value::Column(Column::Array(Box::new(col.to_array_column())))
+                    }
+                    other => unreachable!("source: {}", other),
+                };
+                let array_type =
+                    DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+                self.run_try_cast(span, &array_type, dest_type, value)
+            }
             (DataType::Array(inner_src_ty), DataType::Array(inner_dest_ty)) => match value {
                 Value::Scalar(Scalar::Array(array)) => {
                     let new_array = self
@@ -914,13 +944,19 @@ impl<'a> Evaluator<'a> {
             display_name: String::new(),
         };
 
-        let params = if let DataType::Decimal(ty) = dest_type.remove_nullable() {
-            vec![
+        let params = match dest_type.remove_nullable() {
+            DataType::Decimal(ty) => vec![
                 Scalar::Number(NumberScalar::Int64(ty.precision() as _)),
                 Scalar::Number(NumberScalar::Int64(ty.scale() as _)),
-            ]
-        } else {
-            vec![]
+            ],
+            DataType::Vector(dimension) => {
+                vec![Scalar::Number(NumberScalar::Int64(dimension as _))]
+            }
+            DataType::Enum(values) => values
+                .iter()
+                .map(|value| Scalar::String(value.clone()))
+                .collect(),
+            _ => vec![],
         };
 
         let cast_expr = match check_function(span, cast_fn, &params, &[expr], self.fn_registry) {
@@ -2133,13 +2169,19 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
             display_name: String::new(),
         };
 
-        let params = if let DataType::Decimal(ty) = dest_type {
-            vec![
+        let params = match dest_type {
+            DataType::Decimal(ty) => vec![
                 Scalar::Number(NumberScalar::Int64(ty.precision() as _)),
                 Scalar::Number(NumberScalar::Int64(ty.scale() as _)),
-            ]
-        } else {
-            vec![]
+            ],
+            DataType::Vector(dimension) => {
+                vec![Scalar::Number(NumberScalar::Int64(*dimension as _))]
+            }
+            DataType::Enum(values) => values
+                .iter()
+                .map(|value| Scalar::String(value.clone()))
+                .collect(),
+            _ => vec![],
         };
         let cast_expr = check_function(span, cast_fn, &params, &[expr], self.fn_registry).ok()?;
 
@@ -31,6 +31,7 @@ use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
 use crate::types::geography::GeographyColumn;
 use crate::types::geometry::GeometryType;
+use crate::types::large_int::LargeIntColumn;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -41,15 +42,22 @@ use crate::types::ArrayType;
 use crate::types::BinaryType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
+use crate::types::EnumColumn;
 use crate::types::GeographyType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
+use crate::types::TimestampTzType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
+use crate::types::VectorColumn;
 use crate::types::F32;
 use crate::types::F64;
 use crate::with_decimal_type;
+use crate::with_large_int_type;
 use crate::with_number_mapped_type;
 use crate::BlockEntry;
 use crate::Column;
@@ -256,6 +264,18 @@ impl Column {
                     Column::Decimal(DecimalColumn::Decimal256(builder.into(), size))
                 }
             }),
+            Column::LargeInt(col) => with_large_int_type!(|LARGE_INT| match col {
+                LargeIntColumn::LARGE_INT(_) => {
+                    let builder = Self::concat_primitive_types(
+                        columns.map(|col| match col {
+                            Column::LargeInt(LargeIntColumn::LARGE_INT(col)) => col,
+                            _ => unreachable!(),
+                        }),
+                        capacity,
+                    );
+                    Column::LargeInt(LargeIntColumn::LARGE_INT(builder.into()))
+                }
+            }),
             Column::Boolean(_) => Column::Boolean(Self::concat_boolean_types(
                 columns.map(|col| col.into_boolean().unwrap()),
                 capacity,
@@ -365,6 +385,50 @@ impl Column {
                     capacity,
                 )))
             }
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                );
+                IntervalType::upcast_column(IntervalType::column_from_vec(builder, &[]))
+            }
+            Column::Time(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_time().unwrap()),
+                    capacity,
+                );
+                TimeType::upcast_column(TimeType::column_from_vec(builder, &[]))
+            }
+            Column::TimestampTz(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_timestamp_tz().unwrap()),
+                    capacity,
+                );
+                TimestampTzType::upcast_column(TimestampTzType::column_from_vec(builder, &[]))
+            }
+            Column::Vector(col) => {
+                let dimension = col.dimension;
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_vector().unwrap().values),
+                    capacity * dimension,
+                );
+                Column::Vector(VectorColumn::new(builder.into(), dimension))
+            }
+            Column::Enum(col) => {
+                let values = col.values.clone();
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_enum().unwrap().codes),
+                    capacity,
+                );
+                Column::Enum(EnumColumn::new(builder.into(), values))
+            }
+            Column::Uuid(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_uuid().unwrap()),
+                    capacity,
+                );
+                UuidType::upcast_column(UuidType::column_from_vec(builder, &[]))
+            }
         };
         Ok(column)
     }
@@ -247,6 +247,15 @@ impl<'a> ValueVisitor for FilterVisitor<'a> {
         Ok(())
     }
 
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        let codes = self.filter_primitive_types(column.codes);
+        self.result = Some(Value::Column(EnumType::upcast_column(EnumColumn::new(
+            codes,
+            column.values,
+        ))));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
@@ -259,6 +268,13 @@ impl<'a> ValueVisitor for FilterVisitor<'a> {
         Ok(())
     }
 
+    fn visit_large_int<T: crate::types::LargeInt>(&mut self, buffer: Buffer<T>) -> Result<()> {
+        self.result = Some(Value::Column(LargeIntType::<T>::upcast_column(
+            self.filter_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_boolean(&mut self, mut bitmap: Bitmap) -> Result<()> {
         // faster path for all bits set
         if bitmap.unset_bits() == 0 {
@@ -67,6 +67,9 @@ impl DataBlock {
             if not_null_type.is_numeric()
                 || not_null_type.is_date_or_date_time()
                 || not_null_type.is_decimal()
+                || not_null_type.is_large_int()
+                || not_null_type.is_enum()
+                || not_null_type.is_uuid()
             {
                 group_key_len += not_null_type.numeric_byte_size().unwrap();
 
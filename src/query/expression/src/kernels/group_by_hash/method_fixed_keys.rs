@@ -29,6 +29,10 @@ use micromarshal::Marshal;
 use crate::types::boolean::BooleanType;
 use crate::types::decimal::Decimal;
 use crate::types::decimal::DecimalColumn;
+use crate::types::enumeration::EnumColumn;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::large_int::LargeIntType;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::Number;
 use crate::types::number::NumberColumn;
@@ -39,6 +43,7 @@ use crate::types::NumberType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
 use crate::with_integer_mapped_type;
+use crate::with_large_int_mapped_type;
 use crate::with_number_mapped_type;
 use crate::Column;
 use crate::ColumnBuilder;
@@ -148,6 +153,33 @@ where T: Clone
                     _ => {}
                 })
             }
+
+            if let DataType::LargeInt(ty) = group_items[0].1 {
+                with_large_int_mapped_type!(|LARGE_INT| match ty {
+                    LargeIntDataType::LARGE_INT => {
+                        let buffer: Buffer<T> = keys.into();
+                        let col =
+                            unsafe { std::mem::transmute::<Buffer<T>, Buffer<LARGE_INT>>(buffer) };
+                        return Ok(vec![LargeIntType::<LARGE_INT>::upcast_column(col)]);
+                    }
+                })
+            }
+
+            if let DataType::Enum(values) = &group_items[0].1 {
+                if std::mem::size_of::<T>() == std::mem::size_of::<u16>() {
+                    let buffer: Buffer<T> = keys.into();
+                    let codes = unsafe { std::mem::transmute::<Buffer<T>, Buffer<u16>>(buffer) };
+                    return Ok(vec![Column::Enum(EnumColumn::new(codes, values.clone()))]);
+                }
+            }
+
+            if group_items[0].1 == DataType::Uuid
+                && std::mem::size_of::<T>() == std::mem::size_of::<u128>()
+            {
+                let buffer: Buffer<T> = keys.into();
+                let col = unsafe { std::mem::transmute::<Buffer<T>, Buffer<u128>>(buffer) };
+                return Ok(vec![Column::Uuid(col)]);
+            }
         }
 
         let mut keys = keys;
@@ -253,6 +285,16 @@ macro_rules! impl_hash_method_fixed_keys {
                             unsafe { std::mem::transmute::<Buffer<$signed_ty>, Buffer<$ty>>(col) };
                         return Ok(KeysState::Column(NumberType::<$ty>::upcast_column(buffer)));
                     }
+
+                    // the codes of enum are used as the keys directly
+                    if let Column::Enum(col) = column {
+                        if std::mem::size_of::<$ty>() == std::mem::size_of::<u16>() {
+                            let buffer = unsafe {
+                                std::mem::transmute::<Buffer<u16>, Buffer<$ty>>(col.codes.clone())
+                            };
+                            return Ok(KeysState::Column(NumberType::<$ty>::upcast_column(buffer)));
+                        }
+                    }
                 }
 
                 let keys = self.build_keys_vec(group_columns, rows)?;
@@ -334,6 +376,27 @@ macro_rules! impl_hash_method_fixed_large_keys {
                             _ => {}
                         })
                     }
+
+                    if group_columns[0].data_type().is_large_int() {
+                        with_large_int_mapped_type!(|LARGE_INT| match &group_columns[0] {
+                            Column::LargeInt(LargeIntColumn::LARGE_INT(c)) => {
+                                let buffer = unsafe {
+                                    std::mem::transmute::<Buffer<LARGE_INT>, Buffer<$ty>>(c.clone())
+                                };
+                                return Ok(KeysState::$name(buffer));
+                            }
+                            _ => {}
+                        })
+                    }
+
+                    if let Column::Uuid(c) = &group_columns[0] {
+                        if std::mem::size_of::<$ty>() == std::mem::size_of::<u128>() {
+                            let buffer = unsafe {
+                                std::mem::transmute::<Buffer<u128>, Buffer<$ty>>(c.clone())
+                            };
+                            return Ok(KeysState::$name(buffer));
+                        }
+                    }
                 }
 
                 let keys = self.build_keys_vec(group_columns, rows)?;
@@ -588,6 +651,95 @@ pub fn fixed_hash(
                 }
             })
         }
+        Column::LargeInt(c) => {
+            with_large_int_mapped_type!(|LARGE_INT| match c {
+                LargeIntColumn::LARGE_INT(t) => {
+                    let mut ptr = ptr;
+                    let count = std::mem::size_of::<LARGE_INT>();
+                    match nulls {
+                        Some((offsize, Some(bitmap))) => {
+                            for (value, valid) in t.iter().zip(bitmap.iter()) {
+                                unsafe {
+                                    if valid {
+                                        let slice = std::slice::from_raw_parts_mut(ptr, count);
+                                        value.marshal(slice);
+                                    } else {
+                                        ptr.add(offsize).write(1u8);
+                                    }
+
+                                    ptr = ptr.add(step);
+                                }
+                            }
+                        }
+                        _ => {
+                            for value in t.iter() {
+                                unsafe {
+                                    let slice = std::slice::from_raw_parts_mut(ptr, count);
+                                    value.marshal(slice);
+                                    ptr = ptr.add(step);
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        }
+        Column::Enum(c) => {
+            let mut ptr = ptr;
+            match nulls {
+                Some((offsize, Some(bitmap))) => {
+                    for (value, valid) in c.codes.iter().zip(bitmap.iter()) {
+                        unsafe {
+                            if valid {
+                                let slice = std::slice::from_raw_parts_mut(ptr, 2);
+                                value.marshal(slice);
+                            } else {
+                                ptr.add(offsize).write(1u8);
+                            }
+
+                            ptr = ptr.add(step);
+                        }
+                    }
+                }
+                _ => {
+                    for value in c.codes.iter() {
+                        unsafe {
+                            let slice = std::slice::from_raw_parts_mut(ptr, 2);
+                            value.marshal(slice);
+                            ptr = ptr.add(step);
+                        }
+                    }
+                }
+            }
+        }
+        Column::Uuid(c) => {
+            let mut ptr = ptr;
+            match nulls {
+                Some((offsize, Some(bitmap))) => {
+                    for (value, valid) in c.iter().zip(bitmap.iter()) {
+                        unsafe {
+                            if valid {
+                                let slice = std::slice::from_raw_parts_mut(ptr, 16);
+                                value.marshal(slice);
+                            } else {
+                                ptr.add(offsize).write(1u8);
+                            }
+
+                            ptr = ptr.add(step);
+                        }
+                    }
+                }
+                _ => {
+                    for value in c.iter() {
+                        unsafe {
+                            let slice = std::slice::from_raw_parts_mut(ptr, 16);
+                            value.marshal(slice);
+                            ptr = ptr.add(step);
+                        }
+                    }
+                }
+            }
+        }
         _ => {
             return Err(ErrorCode::BadDataValueType(format!(
                 "Unsupported apply fn fixed_hash operation for column: {:?}",
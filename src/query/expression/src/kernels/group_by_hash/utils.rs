@@ -20,8 +20,10 @@ use crate::kernels::utils::store_advance;
 use crate::kernels::utils::store_advance_aligned;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::large_int::LargeIntColumn;
 use crate::types::NumberColumn;
 use crate::with_decimal_mapped_type;
+use crate::with_large_int_mapped_type;
 use crate::with_number_mapped_type;
 use crate::Column;
 use crate::InputColumns;
@@ -76,6 +78,13 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
                 }
             })
         }
+        Column::LargeInt(v) => {
+            with_large_int_mapped_type!(|LARGE_INT| match v {
+                LargeIntColumn::LARGE_INT(v) => {
+                    store_advance::<LARGE_INT>(&v[row], row_space);
+                }
+            })
+        }
         Column::Boolean(v) => store_advance::<bool>(&v.get_bit(row), row_space),
         Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) | Column::Geometry(v) => {
             let value = unsafe { v.index_unchecked(row) };
@@ -98,6 +107,16 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => store_advance::<i64>(&v[row], row_space),
         Column::Date(v) => store_advance::<i32>(&v[row], row_space),
+        Column::Interval(v) => store_advance::<i128>(&v[row].0, row_space),
+        Column::Time(v) => store_advance::<i64>(&v[row], row_space),
+        Column::TimestampTz(v) => store_advance::<i128>(&v[row].0, row_space),
+        Column::Enum(v) => store_advance::<u16>(&v.codes[row], row_space),
+        Column::Uuid(v) => store_advance::<u128>(&v[row], row_space),
+        Column::Vector(v) => {
+            let value = unsafe { v.index_unchecked(row) };
+            let len = std::mem::size_of_val(value);
+            copy_advance_aligned::<u8>(value.as_ptr() as *const u8, row_space, len);
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             store_advance::<u64>(&(data.len() as u64), row_space);
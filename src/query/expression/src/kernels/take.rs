@@ -150,6 +150,15 @@ where I: databend_common_arrow::arrow::types::Index
         Ok(())
     }
 
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        let codes = self.take_primitive_types(column.codes);
+        self.result = Some(Value::Column(EnumType::upcast_column(EnumColumn::new(
+            codes,
+            column.values,
+        ))));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
@@ -162,6 +171,13 @@ where I: databend_common_arrow::arrow::types::Index
         Ok(())
     }
 
+    fn visit_large_int<T: crate::types::LargeInt>(&mut self, buffer: Buffer<T>) -> Result<()> {
+        self.result = Some(Value::Column(LargeIntType::<T>::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_boolean(&mut self, col: Bitmap) -> Result<()> {
         let num_rows = self.indices.len();
         // Fast path: avoid iterating column to generate a new bitmap.
@@ -30,6 +30,9 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalColumnVec;
 use crate::types::geography::GeographyColumn;
 use crate::types::geometry::GeometryType;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntColumnVec;
+use crate::types::large_int::LargeIntType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnVec;
@@ -37,6 +40,8 @@ use crate::types::number::NumberColumn;
 use crate::types::string::StringColumn;
 use crate::types::*;
 use crate::with_decimal_type;
+use crate::with_large_int_mapped_type;
+use crate::with_large_int_type;
 use crate::with_number_mapped_type;
 use crate::BlockEntry;
 use crate::Column;
@@ -280,6 +285,14 @@ impl Column {
                     Column::Decimal(DecimalColumn::DECIMAL_TYPE(builder.into(), *size))
                 }
             }),
+            Column::LargeInt(column) => with_large_int_mapped_type!(|LARGE_INT| match column {
+                LargeIntColumn::LARGE_INT(_) => {
+                    let builder = LargeIntType::<LARGE_INT>::create_builder(result_size, &[]);
+                    Self::take_block_value_types::<LargeIntType<LARGE_INT>>(
+                        columns, builder, indices,
+                    )
+                }
+            }),
             Column::Boolean(_) => {
                 let builder = BooleanType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BooleanType>(columns, builder, indices)
@@ -401,6 +414,30 @@ impl Column {
                 let builder = GeographyType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<GeographyType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
+            Column::Time(_) => {
+                let builder = TimeType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimeType>(columns, builder, indices)
+            }
+            Column::TimestampTz(_) => {
+                let builder = TimestampTzType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimestampTzType>(columns, builder, indices)
+            }
+            Column::Vector(col) => {
+                let builder = VectorColumnBuilder::with_capacity(col.dimension, result_size);
+                Self::take_block_value_types::<VectorType>(columns, builder, indices)
+            }
+            Column::Enum(col) => {
+                let builder = EnumColumnBuilder::with_capacity(col.values.clone(), result_size);
+                Self::take_block_value_types::<EnumType>(columns, builder, indices)
+            }
+            Column::Uuid(_) => {
+                let builder = UuidType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<UuidType>(columns, builder, indices)
+            }
         }
     }
 
@@ -493,6 +530,15 @@ impl Column {
                     ColumnVec::Decimal(DecimalColumnVec::DECIMAL_TYPE(columns, *size))
                 }
             }),
+            Column::LargeInt(column) => with_large_int_mapped_type!(|LARGE_INT| match column {
+                LargeIntColumn::LARGE_INT(_) => {
+                    let columns = columns
+                        .iter()
+                        .map(|col| LargeIntType::<LARGE_INT>::try_downcast_column(col).unwrap())
+                        .collect_vec();
+                    ColumnVec::LargeInt(LargeIntColumnVec::LARGE_INT(columns))
+                }
+            }),
             Column::Boolean(_) => {
                 let columns = columns
                     .iter()
@@ -623,6 +669,48 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Geography(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
+            Column::Time(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimeType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Time(columns)
+            }
+            Column::TimestampTz(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimestampTzType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::TimestampTz(columns)
+            }
+            Column::Vector(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| VectorType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Vector(columns)
+            }
+            Column::Enum(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| EnumType::try_downcast_column(col).unwrap().codes)
+                    .collect_vec();
+                ColumnVec::Enum(columns)
+            }
+            Column::Uuid(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| UuidType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Uuid(columns)
+            }
         }
     }
 
@@ -652,6 +740,12 @@ impl Column {
                     Column::Decimal(DecimalColumn::DECIMAL_TYPE(builder.into(), *size))
                 }
             }),
+            ColumnVec::LargeInt(column) => with_large_int_type!(|LARGE_INT| match column {
+                LargeIntColumnVec::LARGE_INT(columns) => {
+                    let builder = Self::take_block_vec_primitive_types(columns, indices);
+                    Column::LargeInt(LargeIntColumn::LARGE_INT(builder.into()))
+                }
+            }),
             ColumnVec::Boolean(columns) => {
                 Column::Boolean(Self::take_block_vec_boolean_types(columns, indices))
             }
@@ -771,6 +865,36 @@ impl Column {
                     binary_items_buf.as_mut(),
                 )))
             }
+            ColumnVec::Interval(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                IntervalType::upcast_column(IntervalType::column_from_vec(builder, &[]))
+            }
+            ColumnVec::Time(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                TimeType::upcast_column(TimeType::column_from_vec(builder, &[]))
+            }
+            ColumnVec::TimestampTz(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                TimestampTzType::upcast_column(TimestampTzType::column_from_vec(builder, &[]))
+            }
+            ColumnVec::Vector(columns) => {
+                let dimension = data_type.as_vector().copied().unwrap() as usize;
+                let mut builder = VectorColumnBuilder::with_capacity(dimension, indices.len());
+                for row_ptr in indices {
+                    let col = unsafe { columns.get_unchecked(row_ptr.chunk_index as usize) };
+                    builder.push(unsafe { col.index_unchecked(row_ptr.row_index as usize) });
+                }
+                VectorType::upcast_column(builder.build())
+            }
+            ColumnVec::Enum(columns) => {
+                let values = data_type.as_enum().cloned().unwrap();
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Enum(EnumColumn::new(builder.into(), values))
+            }
+            ColumnVec::Uuid(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                UuidType::upcast_column(UuidType::column_from_vec(builder, &[]))
+            }
         }
     }
 
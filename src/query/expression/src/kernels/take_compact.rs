@@ -154,6 +154,13 @@ impl<'a> ValueVisitor for TakeCompactVisitor<'a> {
         Ok(())
     }
 
+    fn visit_large_int<T: crate::types::LargeInt>(&mut self, buffer: Buffer<T>) -> Result<()> {
+        self.result = Some(Value::Column(LargeIntType::<T>::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_binary(&mut self, col: BinaryColumn) -> Result<()> {
         self.result = Some(Value::Column(BinaryType::upcast_column(
             self.take_binary_types(&col),
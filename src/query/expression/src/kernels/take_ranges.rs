@@ -158,6 +158,13 @@ impl<'a> ValueVisitor for TakeRangeVisitor<'a> {
         Ok(())
     }
 
+    fn visit_large_int<T: crate::types::LargeInt>(&mut self, buffer: Buffer<T>) -> Result<()> {
+        self.result = Some(Value::Column(LargeIntType::<T>::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_boolean(&mut self, bitmap: Bitmap) -> Result<()> {
         let capacity = self.num_rows.saturating_add(7) / 8;
         let mut builder: Vec<u8> = Vec::with_capacity(capacity);
@@ -19,6 +19,10 @@ use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::large_int::LargeIntDomain;
+use crate::types::large_int::LargeIntScalar;
+use crate::types::large_int::LargeIntType;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -38,6 +42,8 @@ use crate::types::StringType;
 use crate::types::TimestampType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
+use crate::with_large_int_mapped_type;
+use crate::with_large_int_type;
 use crate::with_number_type;
 use crate::Scalar;
 
@@ -95,6 +101,7 @@ pub enum FunctionDomain<T: ValueType> {
 pub enum Domain {
     Number(NumberDomain),
     Decimal(DecimalDomain),
+    LargeInt(LargeIntDomain),
     Boolean(BooleanDomain),
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
@@ -175,6 +182,11 @@ impl Domain {
                     Domain::Decimal(DecimalDomain::Decimal256(Decimal256Type::full_domain(), *x))
                 }
             },
+            DataType::LargeInt(ty) => with_large_int_mapped_type!(|LARGE_INT| match ty {
+                LargeIntDataType::LARGE_INT => Domain::LargeInt(LargeIntDomain::LARGE_INT(
+                    LargeIntType::<LARGE_INT>::full_domain()
+                )),
+            }),
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
@@ -196,7 +208,13 @@ impl Domain {
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
-            | DataType::Geography => Domain::Undefined,
+            | DataType::Geography
+            | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
+            | DataType::Vector(_)
+            | DataType::Enum(_)
+            | DataType::Uuid => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -226,6 +244,16 @@ impl Domain {
                     _ => unreachable!("unable to merge {this:?} with {other:?}"),
                 })
             }
+            (Domain::LargeInt(this), Domain::LargeInt(other)) => {
+                with_large_int_type!(|TYPE| match (this, other) {
+                    (LargeIntDomain::TYPE(this), LargeIntDomain::TYPE(other)) =>
+                        Domain::LargeInt(LargeIntDomain::TYPE(SimpleDomain {
+                            min: this.min.min(other.min),
+                            max: this.max.max(other.max),
+                        })),
+                    _ => unreachable!("unable to merge {this:?} with {other:?}"),
+                })
+            }
             (Domain::Boolean(this), Domain::Boolean(other)) => Domain::Boolean(BooleanDomain {
                 has_false: this.has_false || other.has_false,
                 has_true: this.has_true || other.has_true,
@@ -370,6 +398,12 @@ impl Domain {
             {
                 Some(Scalar::Decimal(DecimalScalar::Decimal256(*min, *sz)))
             }
+            Domain::LargeInt(domain) => with_large_int_type!(|TYPE| match domain {
+                LargeIntDomain::TYPE(SimpleDomain { min, max }) if min == max => {
+                    Some(Scalar::LargeInt(LargeIntScalar::TYPE(*min)))
+                }
+                _ => None,
+            }),
             Domain::Boolean(BooleanDomain {
                 has_false: true,
                 has_true: false,
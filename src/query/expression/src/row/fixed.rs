@@ -14,9 +14,12 @@
 
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use ethnum::i256;
+use ethnum::u256;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
+use crate::types::interval::months_days_micros;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -75,6 +78,8 @@ encode_unsigned!(1, u8);
 encode_unsigned!(2, u16);
 encode_unsigned!(4, u32);
 encode_unsigned!(8, u64);
+encode_unsigned!(16, u128);
+encode_unsigned!(32, u256);
 
 impl FixedLengthEncoding for F32 {
     type Encoded = [u8; 4];
@@ -98,6 +103,26 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthEncoding for months_days_micros {
+    type Encoded = [u8; 32];
+
+    fn encode(self) -> [u8; 32] {
+        // Keep the same order as `Ord`: by the total duration, then by the raw value.
+        let mut b = [0; 32];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..].copy_from_slice(&self.0.encode());
+        b
+    }
+}
+
+impl FixedLengthEncoding for timestamp_tz {
+    type Encoded = [u8; 16];
+
+    fn encode(self) -> [u8; 16] {
+        self.0.encode()
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut BinaryColumnBuilder,
     iter: I,
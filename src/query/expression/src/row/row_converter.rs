@@ -24,11 +24,17 @@ use super::variable;
 use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
 use crate::with_decimal_type;
+use crate::with_large_int_mapped_type;
+use crate::with_large_int_type;
 use crate::with_number_mapped_type;
 use crate::with_number_type;
 use crate::Column;
@@ -63,6 +69,7 @@ impl RowConverter {
             | DataType::Map(_)
             | DataType::Bitmap
             | DataType::Tuple(_)
+            | DataType::Vector(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
             _ => true,
@@ -117,12 +124,34 @@ impl RowConverter {
                         .iter_mut()
                         .for_each(|x| *x += i256::ENCODED_LEN as u64),
                 },
+                DataType::LargeInt(t) => with_large_int_mapped_type!(|LARGE_INT| match t {
+                    LargeIntDataType::LARGE_INT => {
+                        lengths
+                            .iter_mut()
+                            .for_each(|x| *x += LARGE_INT::ENCODED_LEN as u64)
+                    }
+                }),
                 DataType::Timestamp => lengths
                     .iter_mut()
                     .for_each(|x| *x += i64::ENCODED_LEN as u64),
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += months_days_micros::ENCODED_LEN as u64),
+                DataType::Time => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i64::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += timestamp_tz::ENCODED_LEN as u64),
+                DataType::Enum(_) => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += u16::ENCODED_LEN as u64),
+                DataType::Uuid => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += u128::ENCODED_LEN as u64),
                 DataType::Binary => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -252,8 +281,21 @@ fn encode_column(out: &mut BinaryColumnBuilder, column: &Column, asc: bool, null
                 }
             })
         }
+        Column::LargeInt(col) => {
+            with_large_int_type!(|LARGE_INT| match col {
+                LargeIntColumn::LARGE_INT(c) => {
+                    fixed::encode(out, c, validity, asc, nulls_first)
+                }
+            })
+        }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Time(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::TimestampTz(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        // Codes follow the declaration order of the values, so they can be compared directly.
+        Column::Enum(col) => fixed::encode(out, col.codes, validity, asc, nulls_first),
+        Column::Uuid(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::String(col) => variable::encode(
             out,
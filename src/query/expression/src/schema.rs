@@ -27,6 +27,9 @@ use serde::Serialize;
 
 use crate::display::display_tuple_field_name;
 use crate::types::decimal::DecimalDataType;
+use crate::types::enumeration::EnumValues;
+use crate::types::enumeration::EnumValuesDisplay;
+use crate::types::large_int::LargeIntDataType;
 use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::BlockMetaInfo;
@@ -195,6 +198,7 @@ pub enum TableDataType {
     String,
     Number(NumberDataType),
     Decimal(DecimalDataType),
+    LargeInt(LargeIntDataType),
     Timestamp,
     Date,
     Nullable(Box<TableDataType>),
@@ -208,6 +212,12 @@ pub enum TableDataType {
     Variant,
     Geometry,
     Geography,
+    Interval,
+    Time,
+    TimestampTz,
+    Vector(u64),
+    Enum(EnumValues),
+    Uuid,
 }
 
 impl DataSchema {
@@ -1187,6 +1197,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::String => DataType::String,
             TableDataType::Number(ty) => DataType::Number(*ty),
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
+            TableDataType::LargeInt(ty) => DataType::LargeInt(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
@@ -1199,6 +1210,12 @@ impl From<&TableDataType> for DataType {
             TableDataType::Variant => DataType::Variant,
             TableDataType::Geometry => DataType::Geometry,
             TableDataType::Geography => DataType::Geography,
+            TableDataType::Interval => DataType::Interval,
+            TableDataType::Time => DataType::Time,
+            TableDataType::TimestampTz => DataType::TimestampTz,
+            TableDataType::Vector(dim) => DataType::Vector(*dim),
+            TableDataType::Enum(values) => DataType::Enum(values.clone()),
+            TableDataType::Uuid => DataType::Uuid,
         }
     }
 }
@@ -1303,6 +1320,7 @@ impl TableDataType {
             },
             TableDataType::String => "VARCHAR".to_string(),
             TableDataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
+            TableDataType::Enum(values) => format!("ENUM({})", EnumValuesDisplay(values)),
             _ => self.to_string().to_uppercase(),
         }
     }
@@ -1425,6 +1443,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Number(number_type) => Ok(TableDataType::Number(*number_type)),
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
+        DataType::LargeInt(x) => Ok(TableDataType::LargeInt(*x)),
         DataType::Date => Ok(TableDataType::Date),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
@@ -1439,6 +1458,12 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Variant => Ok(TableDataType::Variant),
         DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Geography => Ok(TableDataType::Geography),
+        DataType::Interval => Ok(TableDataType::Interval),
+        DataType::Time => Ok(TableDataType::Time),
+        DataType::TimestampTz => Ok(TableDataType::TimestampTz),
+        DataType::Vector(dim) => Ok(TableDataType::Vector(*dim)),
+        DataType::Enum(values) => Ok(TableDataType::Enum(values.clone())),
+        DataType::Uuid => Ok(TableDataType::Uuid),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -30,7 +30,9 @@ use crate::types::decimal::MAX_DECIMAL128_PRECISION;
 use crate::types::decimal::MAX_DECIMAL256_PRECISION;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
+use crate::types::LargeIntDataType;
 use crate::types::Number;
+use crate::types::NumberDataType;
 use crate::types::NumberScalar;
 use crate::AutoCastRules;
 use crate::ColumnIndex;
@@ -178,13 +180,19 @@ pub fn check_cast<Index: ColumnIndex>(
     } else {
         // fast path to eval function for cast
         if let Some(cast_fn) = get_simple_cast_function(is_try, expr.data_type(), dest_type) {
-            let params = if let DataType::Decimal(ty) = dest_type {
-                vec![
+            let params = match dest_type {
+                DataType::Decimal(ty) => vec![
                     Scalar::Number(NumberScalar::Int64(ty.precision() as _)),
                     Scalar::Number(NumberScalar::Int64(ty.scale() as _)),
-                ]
-            } else {
-                vec![]
+                ],
+                DataType::Vector(dimension) => {
+                    vec![Scalar::Number(NumberScalar::Int64(*dimension as _))]
+                }
+                DataType::Enum(values) => values
+                    .iter()
+                    .map(|value| Scalar::String(value.clone()))
+                    .collect(),
+                _ => vec![],
             };
 
             if let Ok(cast_expr) =
@@ -599,6 +607,17 @@ pub fn can_auto_cast_to(
         }
         // Only available for decimal --> f64, otherwise `sqrt(1234.56789)` will have signature: `sqrt(1234.56789::Float32)`
         (DataType::Decimal(_), DataType::Number(n)) if n.is_float64() => true,
+        (DataType::String, DataType::LargeInt(_)) => true,
+        (DataType::LargeInt(x), DataType::LargeInt(y)) => x.can_lossless_cast_to(y),
+        (DataType::Number(n), DataType::LargeInt(l)) if n.is_integer() => {
+            l.is_signed() || !n.is_signed()
+        }
+        (DataType::LargeInt(_), DataType::Number(n)) if n.is_float64() => true,
+        (DataType::Array(box inner_ty), DataType::Vector(_)) => {
+            matches!(inner_ty, DataType::Number(_) | DataType::Decimal(_))
+        }
+        (DataType::Enum(_), DataType::String) => true,
+        (DataType::String, DataType::Uuid) => true,
         _ => false,
     }
 }
@@ -683,6 +702,24 @@ pub fn common_super_type(
         {
             Some(DataType::Number(num_ty))
         }
+        // Mixing signed and unsigned 128-bit integers widens to `Int256`.
+        (DataType::LargeInt(a), DataType::LargeInt(b))
+            if a.bit_width() == 128 && b.bit_width() == 128 =>
+        {
+            Some(DataType::LargeInt(LargeIntDataType::Int256))
+        }
+        (DataType::Number(num_ty), DataType::LargeInt(LargeIntDataType::UInt128))
+        | (DataType::LargeInt(LargeIntDataType::UInt128), DataType::Number(num_ty))
+            if num_ty.is_integer() =>
+        {
+            Some(DataType::LargeInt(LargeIntDataType::Int256))
+        }
+        (DataType::Number(num_ty), DataType::LargeInt(_))
+        | (DataType::LargeInt(_), DataType::Number(num_ty))
+            if num_ty.is_float() =>
+        {
+            Some(DataType::Number(NumberDataType::Float64))
+        }
         (ty1, ty2) => {
             let ty1_can_cast_to = auto_cast_rules
                 .iter()
@@ -714,6 +751,12 @@ pub fn get_simple_cast_function(
     {
         // parse JSON string to variant instead of cast
         "parse_json".to_owned()
+    } else if dest_type.remove_nullable() == DataType::TimestampTz {
+        "to_timestamp_tz".to_owned()
+    } else if dest_type.remove_nullable().is_vector() {
+        "to_vector".to_owned()
+    } else if dest_type.remove_nullable().is_enum() {
+        "to_enum".to_owned()
     } else {
         format!("to_{}", dest_type.to_string().to_lowercase())
     };
@@ -746,6 +789,16 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_decimal",
     "to_bitmap",
     "to_geometry",
+    "to_interval",
+    "to_time",
+    "to_timestamp_tz",
+    "to_int128",
+    "to_uint128",
+    "to_int256",
+    "to_uint256",
+    "to_vector",
+    "to_enum",
+    "to_uuid",
     "parse_json",
 ];
 
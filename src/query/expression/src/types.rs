@@ -21,17 +21,24 @@ pub mod date;
 pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
+pub mod enumeration;
 pub mod generic;
 pub mod geography;
 pub mod geometry;
+pub mod interval;
+pub mod large_int;
 pub mod map;
 pub mod null;
 pub mod nullable;
 pub mod number;
 pub mod number_class;
 pub mod string;
+pub mod time;
 pub mod timestamp;
+pub mod timestamp_tz;
+pub mod uuid;
 pub mod variant;
+pub mod vector;
 
 use std::cmp::Ordering;
 use std::fmt::Debug;
@@ -54,9 +61,12 @@ pub use self::date::DateType;
 pub use self::decimal::*;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
+pub use self::enumeration::*;
 pub use self::generic::GenericType;
 pub use self::geography::GeographyColumn;
 pub use self::geography::GeographyType;
+pub use self::interval::IntervalType;
+pub use self::large_int::*;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableColumn;
@@ -65,8 +75,12 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringColumn;
 pub use self::string::StringType;
+pub use self::time::TimeType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTzType;
+pub use self::uuid::UuidType;
 pub use self::variant::VariantType;
+pub use self::vector::*;
 use crate::property::Domain;
 use crate::values::Column;
 use crate::values::Scalar;
@@ -85,6 +99,7 @@ pub enum DataType {
     String,
     Number(NumberDataType),
     Decimal(DecimalDataType),
+    LargeInt(LargeIntDataType),
     Timestamp,
     Date,
     Nullable(Box<DataType>),
@@ -95,6 +110,12 @@ pub enum DataType {
     Variant,
     Geometry,
     Geography,
+    Interval,
+    Time,
+    TimestampTz,
+    Vector(u64),
+    Enum(EnumValues),
+    Uuid,
 
     // Used internally for generic types
     Generic(usize),
@@ -144,12 +165,19 @@ impl DataType {
             | DataType::String
             | DataType::Number(_)
             | DataType::Decimal(_)
+            | DataType::LargeInt(_)
             | DataType::Timestamp
             | DataType::Date
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
-            | DataType::Geography => false,
+            | DataType::Geography
+            | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
+            | DataType::Vector(_)
+            | DataType::Enum(_)
+            | DataType::Uuid => false,
             DataType::Nullable(ty) => ty.has_generic(),
             DataType::Array(ty) => ty.has_generic(),
             DataType::Map(ty) => ty.has_generic(),
@@ -168,12 +196,19 @@ impl DataType {
             | DataType::String
             | DataType::Number(_)
             | DataType::Decimal(_)
+            | DataType::LargeInt(_)
             | DataType::Timestamp
             | DataType::Date
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
             | DataType::Geography
+            | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
+            | DataType::Vector(_)
+            | DataType::Enum(_)
+            | DataType::Uuid
             | DataType::Generic(_) => false,
             DataType::Nullable(box DataType::Nullable(_) | box DataType::Null) => true,
             DataType::Nullable(ty) => ty.has_nested_nullable(),
@@ -224,6 +259,21 @@ impl DataType {
         matches!(self, DataType::Decimal(_ty))
     }
 
+    #[inline]
+    pub fn is_large_int(&self) -> bool {
+        matches!(self, DataType::LargeInt(_ty))
+    }
+
+    #[inline]
+    pub fn is_vector(&self) -> bool {
+        matches!(self, DataType::Vector(_))
+    }
+
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+        matches!(self, DataType::Enum(_))
+    }
+
     #[inline]
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(self, DataType::Timestamp | DataType::Date)
@@ -257,6 +307,9 @@ impl DataType {
 
             DataType::Decimal(DecimalDataType::Decimal128(_)) => Ok(16),
             DataType::Decimal(DecimalDataType::Decimal256(_)) => Ok(32),
+            DataType::LargeInt(ty) => Ok(ty.bit_width() as usize / 8),
+            DataType::Enum(_) => Ok(2),
+            DataType::Uuid => Ok(16),
             _ => Result::Err(format!(
                 "Function number_byte_size argument must be numeric types, but got {:?}",
                 self
@@ -0,0 +1,531 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_arrow::arrow::trusted_len::TrustedLen;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::DecimalSize;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// The maximum number of values of an `ENUM` type, codes are stored as `u16`.
+pub const MAX_ENUM_VALUES: usize = u16::MAX as usize + 1;
+
+/// The dictionary of an `ENUM` type, shared by the data type, columns and scalars.
+pub type EnumValues = Arc<[String]>;
+
+/// A dictionary-encoded string with a fixed set of values.
+///
+/// Each row only stores the `u16` code of its value, the string is looked up from
+/// the dictionary carried by the data type. Filters, hash keys and sorting work on
+/// the codes directly, and codes are ordered by the declaration order of the values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumType;
+
+impl EnumType {
+    pub fn check_values(values: &[String]) -> Result<()> {
+        if values.is_empty() {
+            return Err(ErrorCode::BadArguments("Enum must have at least one value"));
+        }
+        if values.len() > MAX_ENUM_VALUES {
+            return Err(ErrorCode::BadArguments(format!(
+                "Enum can have at most {MAX_ENUM_VALUES} values, but got {}",
+                values.len()
+            )));
+        }
+        let mut seen = HashSet::with_capacity(values.len());
+        for value in values {
+            if !seen.insert(value.as_str()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicate value '{value}' in Enum"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a lookup table from value to code, used when encoding many strings.
+    pub fn build_index(values: &[String]) -> HashMap<&str, u16> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(code, value)| (value.as_str(), code as u16))
+            .collect()
+    }
+
+    pub fn find_code(values: &[String], value: &str) -> Option<u16> {
+        values
+            .iter()
+            .position(|v| v == value)
+            .map(|code| code as u16)
+    }
+}
+
+/// Displays the values of an `ENUM` type as a list of quoted strings, e.g. `'a', 'b'`.
+pub struct EnumValuesDisplay<'a>(pub &'a [String]);
+
+impl<'a> Display for EnumValuesDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "'")?;
+            for c in value.chars() {
+                match c {
+                    '\'' => write!(f, "\\'")?,
+                    '\\' => write!(f, "\\\\")?,
+                    _ => write!(f, "{c}")?,
+                }
+            }
+            write!(f, "'")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnumScalar {
+    pub code: u16,
+    pub values: EnumValues,
+}
+
+impl EnumScalar {
+    pub fn as_ref(&self) -> EnumScalarRef<'_> {
+        EnumScalarRef {
+            code: self.code,
+            values: &self.values,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.values[self.code as usize]
+    }
+}
+
+impl PartialEq for EnumScalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for EnumScalar {}
+
+impl BorshSerialize for EnumScalar {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.code, writer)?;
+        BorshSerialize::serialize(&self.values[..], writer)
+    }
+}
+
+impl BorshDeserialize for EnumScalar {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let code = u16::deserialize_reader(reader)?;
+        let values = Vec::<String>::deserialize_reader(reader)?;
+        Ok(EnumScalar {
+            code,
+            values: values.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EnumScalarRef<'a> {
+    pub code: u16,
+    pub values: &'a EnumValues,
+}
+
+impl<'a> EnumScalarRef<'a> {
+    pub fn to_owned(self) -> EnumScalar {
+        EnumScalar {
+            code: self.code,
+            values: self.values.clone(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        &self.values[self.code as usize]
+    }
+}
+
+impl<'a> PartialEq for EnumScalarRef<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+            && (Arc::ptr_eq(self.values, other.values) || self.values == other.values)
+    }
+}
+
+impl<'a> Eq for EnumScalarRef<'a> {}
+
+impl<'a> Hash for EnumScalarRef<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code.hash(state);
+    }
+}
+
+impl ValueType for EnumType {
+    type Scalar = EnumScalar;
+    type ScalarRef<'a> = EnumScalarRef<'a>;
+    type Column = EnumColumn;
+    type Domain = ();
+    type ColumnIterator<'a> = EnumIterator<'a>;
+    type ColumnBuilder = EnumColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: EnumScalarRef<'long>) -> EnumScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar.to_owned()
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        scalar.as_ref()
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_enum().cloned()
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        col.as_enum().cloned()
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Enum(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Enum(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Enum(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Enum(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Enum(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.index(index)
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        EnumColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.push(item.code)
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.push_repeat(item.code, n)
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push_default()
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size(_scalar: &Self::ScalarRef<'_>) -> usize {
+        std::mem::size_of::<u16>()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.memory_size()
+    }
+
+    #[inline(always)]
+    fn compare(lhs: Self::ScalarRef<'_>, rhs: Self::ScalarRef<'_>) -> Ordering {
+        lhs.code.cmp(&rhs.code)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumColumn {
+    pub codes: Buffer<u16>,
+    pub values: EnumValues,
+}
+
+impl EnumColumn {
+    pub fn new(codes: Buffer<u16>, values: EnumValues) -> Self {
+        EnumColumn { codes, values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.codes.len() * std::mem::size_of::<u16>()
+    }
+
+    pub fn index(&self, index: usize) -> Option<EnumScalarRef<'_>> {
+        let code = *self.codes.get(index)?;
+        Some(EnumScalarRef {
+            code,
+            values: &self.values,
+        })
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    #[inline]
+    pub unsafe fn index_unchecked(&self, index: usize) -> EnumScalarRef<'_> {
+        EnumScalarRef {
+            code: *self.codes.get_unchecked(index),
+            values: &self.values,
+        }
+    }
+
+    /// Returns the string value of the row at `index`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    #[inline]
+    pub unsafe fn value_unchecked(&self, index: usize) -> &str {
+        let code = *self.codes.get_unchecked(index);
+        self.values.get_unchecked(code as usize)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        EnumColumn {
+            codes: self
+                .codes
+                .clone()
+                .sliced(range.start, range.end - range.start),
+            values: self.values.clone(),
+        }
+    }
+
+    pub fn iter(&self) -> EnumIterator<'_> {
+        EnumIterator {
+            codes: self.codes.iter(),
+            values: &self.values,
+        }
+    }
+
+    pub fn check_valid(&self) -> Result<()> {
+        let len = self.values.len();
+        if let Some(code) = self.codes.iter().find(|code| **code as usize >= len) {
+            return Err(ErrorCode::Internal(format!(
+                "EnumColumn code {code} is out of range of {len} values"
+            )));
+        }
+        Ok(())
+    }
+}
+
+pub struct EnumIterator<'a> {
+    codes: std::slice::Iter<'a, u16>,
+    values: &'a EnumValues,
+}
+
+impl<'a> Iterator for EnumIterator<'a> {
+    type Item = EnumScalarRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.codes.next().map(|code| EnumScalarRef {
+            code: *code,
+            values: self.values,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.codes.size_hint()
+    }
+}
+
+unsafe impl<'a> TrustedLen for EnumIterator<'a> {}
+
+unsafe impl<'a> std::iter::TrustedLen for EnumIterator<'a> {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumColumnBuilder {
+    pub codes: Vec<u16>,
+    pub values: EnumValues,
+}
+
+impl EnumColumnBuilder {
+    pub fn with_capacity(values: EnumValues, capacity: usize) -> Self {
+        EnumColumnBuilder {
+            codes: Vec::with_capacity(capacity),
+            values,
+        }
+    }
+
+    pub fn from_column(col: EnumColumn) -> Self {
+        EnumColumnBuilder {
+            codes: buffer_into_mut(col.codes),
+            values: col.values,
+        }
+    }
+
+    pub fn repeat(scalar: EnumScalarRef<'_>, n: usize) -> Self {
+        EnumColumnBuilder {
+            codes: vec![scalar.code; n],
+            values: scalar.values.clone(),
+        }
+    }
+
+    pub fn repeat_default(values: EnumValues, n: usize) -> Self {
+        EnumColumnBuilder {
+            codes: vec![0; n],
+            values,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.codes.len() * std::mem::size_of::<u16>()
+    }
+
+    #[inline]
+    pub fn push(&mut self, code: u16) {
+        debug_assert!((code as usize) < self.values.len());
+        self.codes.push(code);
+    }
+
+    pub fn push_repeat(&mut self, code: u16, n: usize) {
+        debug_assert!((code as usize) < self.values.len());
+        self.codes.resize(self.codes.len() + n, code);
+    }
+
+    pub fn push_default(&mut self) {
+        self.codes.push(0);
+    }
+
+    /// Encodes and pushes a string value, used when reading the text formats.
+    pub fn push_value(&mut self, value: &str) -> Result<()> {
+        match EnumType::find_code(&self.values, value) {
+            Some(code) => {
+                self.push(code);
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!("Unknown enum value '{value}'"))),
+        }
+    }
+
+    pub fn append_column(&mut self, other: &EnumColumn) {
+        debug_assert_eq!(self.values, other.values);
+        self.codes.extend_from_slice(&other.codes);
+    }
+
+    pub fn pop(&mut self) -> Option<EnumScalar> {
+        self.codes.pop().map(|code| EnumScalar {
+            code,
+            values: self.values.clone(),
+        })
+    }
+
+    pub fn build(self) -> EnumColumn {
+        EnumColumn {
+            codes: self.codes.into(),
+            values: self.values,
+        }
+    }
+
+    pub fn build_scalar(self) -> EnumScalar {
+        assert_eq!(self.len(), 1);
+        EnumScalar {
+            code: self.codes[0],
+            values: self.values,
+        }
+    }
+}
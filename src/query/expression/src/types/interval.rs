@@ -0,0 +1,477 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_PER_SEC: i64 = 1_000_000;
+pub const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SEC;
+pub const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+pub const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+pub const DAYS_PER_MONTH: i64 = 30;
+pub const MONTHS_PER_YEAR: i32 = 12;
+
+/// An interval of months, days and microseconds.
+///
+/// The three parts are kept separately since the length of a month or a day is not fixed,
+/// e.g. `1 month` added to `2024-01-31` is `2024-02-29`. The parts are packed into an `i128`
+/// so that an interval column has a fixed width.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        let months_bits = (months as u32 as u128) << 96;
+        let days_bits = (days as u32 as u128) << 64;
+        let micros_bits = microseconds as u64 as u128;
+        months_days_micros((months_bits | days_bits | micros_bits) as i128)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        ((self.0 as u128) >> 96) as u32 as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        ((self.0 as u128) >> 64) as u32 as i32
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.0 as u128 as u64 as i64
+    }
+
+    /// The approximate length of the interval in microseconds, a month is 30 days and a day
+    /// is 24 hours. Used to compare intervals.
+    pub fn total_micros(&self) -> i128 {
+        let days = self.months() as i128 * DAYS_PER_MONTH as i128 + self.days() as i128;
+        days * MICROS_PER_DAY as i128 + self.microseconds() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(months_days_micros::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.microseconds().checked_add(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(months_days_micros::new(
+            self.months().checked_sub(other.months())?,
+            self.days().checked_sub(other.days())?,
+            self.microseconds().checked_sub(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(months_days_micros::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.microseconds().checked_neg()?,
+        ))
+    }
+
+    /// Multiply every part of the interval, the fraction of months and days are carried
+    /// into the smaller parts.
+    pub fn checked_mul(&self, factor: f64) -> Option<Self> {
+        let months = self.months() as f64 * factor;
+        let days = self.days() as f64 * factor + months.fract() * DAYS_PER_MONTH as f64;
+        let micros = self.microseconds() as f64 * factor + days.fract() * MICROS_PER_DAY as f64;
+
+        let (months, days, micros) = (months.trunc(), days.trunc(), micros.round());
+        if !(i32::MIN as f64..=i32::MAX as f64).contains(&months)
+            || !(i32::MIN as f64..=i32::MAX as f64).contains(&days)
+            || !(i64::MIN as f64..=i64::MAX as f64).contains(&micros)
+        {
+            return None;
+        }
+        Some(months_days_micros::new(
+            months as i32,
+            days as i32,
+            micros as i64,
+        ))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl std::fmt::Debug for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn plural(n: i64) -> &'static str {
+            if n == 1 || n == -1 { "" } else { "s" }
+        }
+
+        let mut parts = vec![];
+        let years = self.months() / MONTHS_PER_YEAR;
+        let months = self.months() % MONTHS_PER_YEAR;
+        if years != 0 {
+            parts.push(format!("{} year{}", years, plural(years as i64)));
+        }
+        if months != 0 {
+            parts.push(format!("{} month{}", months, plural(months as i64)));
+        }
+        if self.days() != 0 {
+            parts.push(format!("{} day{}", self.days(), plural(self.days() as i64)));
+        }
+
+        let micros = self.microseconds();
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.unsigned_abs();
+            let secs = micros / MICROS_PER_SEC as u64;
+            let fraction = micros % MICROS_PER_SEC as u64;
+            let mut time = format!(
+                "{}{:02}:{:02}:{:02}",
+                sign,
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            if fraction != 0 {
+                time.push_str(&format!(".{:06}", fraction));
+            }
+            parts.push(time);
+        }
+
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Parse an interval like `1 year 2 months 3 days 04:05:06.7` or `-1 day 3 hours`.
+///
+/// The quantities are followed by a unit, a quantity without unit must be a time of
+/// `[-]HH:MM[:SS[.ffffff]]`. The fraction of months and days are carried into the smaller
+/// units, e.g. `1.5 days` is `1 day 12:00:00`.
+pub fn string_to_interval(s: &str) -> Result<months_days_micros, String> {
+    let err = || format!("invalid interval '{}'", s);
+    let tokens = s.split_whitespace().collect::<Vec<_>>();
+    if tokens.is_empty() {
+        return Err(err());
+    }
+
+    let (mut months, mut days, mut micros) = (0i64, 0i64, 0i128);
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let token = tokens[idx];
+        if token.contains(':') {
+            micros += parse_time(token).ok_or_else(err)? as i128;
+            idx += 1;
+            continue;
+        }
+
+        let quantity = token
+            .parse::<f64>()
+            .ok()
+            .filter(|quantity| quantity.is_finite())
+            .ok_or_else(err)?;
+        let unit = tokens.get(idx + 1).ok_or_else(err)?.to_ascii_lowercase();
+        let (quantity_months, quantity_days, quantity_micros) = match unit.as_str() {
+            "year" | "years" | "y" => (quantity * MONTHS_PER_YEAR as f64, 0.0, 0.0),
+            "month" | "months" | "mon" => (quantity, 0.0, 0.0),
+            "week" | "weeks" | "w" => (0.0, quantity * 7.0, 0.0),
+            "day" | "days" | "d" => (0.0, quantity, 0.0),
+            "hour" | "hours" | "h" => (0.0, 0.0, quantity * MICROS_PER_HOUR as f64),
+            "minute" | "minutes" | "min" => (0.0, 0.0, quantity * MICROS_PER_MINUTE as f64),
+            "second" | "seconds" | "sec" | "s" => (0.0, 0.0, quantity * MICROS_PER_SEC as f64),
+            "millisecond" | "milliseconds" | "ms" => (0.0, 0.0, quantity * 1000.0),
+            "microsecond" | "microseconds" | "us" => (0.0, 0.0, quantity),
+            _ => return Err(err()),
+        };
+        let quantity_days = quantity_days + quantity_months.fract() * DAYS_PER_MONTH as f64;
+        let quantity_micros = quantity_micros + quantity_days.fract() * MICROS_PER_DAY as f64;
+        months = months.saturating_add(quantity_months.trunc() as i64);
+        days = days.saturating_add(quantity_days.trunc() as i64);
+        micros = micros.saturating_add(quantity_micros.round() as i128);
+        idx += 2;
+    }
+
+    let months = i32::try_from(months).map_err(|_| err())?;
+    let days = i32::try_from(days).map_err(|_| err())?;
+    let micros = i64::try_from(micros).map_err(|_| err())?;
+    Ok(months_days_micros::new(months, days, micros))
+}
+
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+
+    let micros = hours
+        .checked_mul(MICROS_PER_HOUR)?
+        .checked_add(minutes * MICROS_PER_MINUTE)?
+        .checked_add((seconds * MICROS_PER_SEC as f64).round() as i64)?;
+    Some(if negative { -micros } else { micros })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        match domain {
+            Domain::Undefined => Some(()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.resize(builder.len() + n, item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> Ordering {
+        left.cmp(&right)
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
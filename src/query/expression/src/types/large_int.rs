@@ -0,0 +1,859 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use enum_as_inner::EnumAsInner;
+pub use ethnum::i256;
+pub use ethnum::u256;
+use itertools::Itertools;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::SimpleDomain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::Column;
+use crate::ColumnBuilder;
+use crate::Domain;
+use crate::Scalar;
+use crate::ScalarRef;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LargeIntType<T: LargeInt>(PhantomData<T>);
+
+pub type Int128Type = LargeIntType<i128>;
+pub type UInt128Type = LargeIntType<u128>;
+pub type Int256Type = LargeIntType<i256>;
+pub type UInt256Type = LargeIntType<u256>;
+
+impl<Num: LargeInt> ValueType for LargeIntType<Num> {
+    type Scalar = Num;
+    type ScalarRef<'a> = Num;
+    type Column = Buffer<Num>;
+    type Domain = SimpleDomain<Num>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, Num>>;
+    type ColumnBuilder = Vec<Num>;
+
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        Num::try_downcast_scalar(scalar.as_large_int()?)
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        Num::try_downcast_column(col.as_large_int()?)
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        Num::try_downcast_domain(domain.as_large_int()?)
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::LargeInt(builder) => Num::try_downcast_builder(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::LargeInt(builder) => Num::try_downcast_owned_builder(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::LargeInt(Num::upcast_builder(builder)))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::LargeInt(Num::upcast_scalar(scalar))
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::LargeInt(Num::upcast_column(col))
+    }
+
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::LargeInt(Num::upcast_domain(domain))
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.push(item)
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        if n == 1 {
+            builder.push(item)
+        } else {
+            builder.resize(builder.len() + n, item)
+        }
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Num::default())
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(lhs: Self::ScalarRef<'_>, rhs: Self::ScalarRef<'_>) -> Ordering {
+        lhs.cmp(&rhs)
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl<Num: LargeInt> ArgType for LargeIntType<Num> {
+    fn data_type() -> DataType {
+        DataType::LargeInt(Num::data_type())
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: Num::MIN,
+            max: Num::MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+pub const ALL_LARGE_INT_TYPES: &[LargeIntDataType] = &[
+    LargeIntDataType::Int128,
+    LargeIntDataType::UInt128,
+    LargeIntDataType::Int256,
+    LargeIntDataType::UInt256,
+];
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, EnumAsInner,
+)]
+pub enum LargeIntDataType {
+    Int128,
+    UInt128,
+    Int256,
+    UInt256,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumAsInner,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub enum LargeIntScalar {
+    Int128(i128),
+    UInt128(u128),
+    Int256(i256),
+    UInt256(u256),
+}
+
+#[derive(Clone, PartialEq, EnumAsInner)]
+pub enum LargeIntColumn {
+    Int128(Buffer<i128>),
+    UInt128(Buffer<u128>),
+    Int256(Buffer<i256>),
+    UInt256(Buffer<u256>),
+}
+
+#[derive(Clone, PartialEq, EnumAsInner, Debug)]
+pub enum LargeIntColumnVec {
+    Int128(Vec<Buffer<i128>>),
+    UInt128(Vec<Buffer<u128>>),
+    Int256(Vec<Buffer<i256>>),
+    UInt256(Vec<Buffer<u256>>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumAsInner)]
+pub enum LargeIntColumnBuilder {
+    Int128(Vec<i128>),
+    UInt128(Vec<u128>),
+    Int256(Vec<i256>),
+    UInt256(Vec<u256>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumAsInner)]
+pub enum LargeIntDomain {
+    Int128(SimpleDomain<i128>),
+    UInt128(SimpleDomain<u128>),
+    Int256(SimpleDomain<i256>),
+    UInt256(SimpleDomain<u256>),
+}
+
+/// Integers wider than 64 bits.
+///
+/// Values are converted between the large integer types and from the builtin integers through
+/// their sign and magnitude, so that a conversion only fails when the value is out of range.
+pub trait LargeInt:
+    Sized
+    + Default
+    + Debug
+    + Display
+    + Copy
+    + Clone
+    + PartialEq
+    + Eq
+    + PartialOrd
+    + Ord
+    + Sync
+    + Send
+    + 'static
+{
+    const MIN: Self;
+    const MAX: Self;
+    const SIGNED: bool;
+
+    fn zero() -> Self;
+
+    fn mem_size() -> usize;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+
+    fn to_sign_magnitude(self) -> (bool, u256);
+    fn from_sign_magnitude(negative: bool, magnitude: u256) -> Option<Self>;
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Self::from_sign_magnitude(value < 0, u256::from(value.unsigned_abs()))
+    }
+
+    fn from_u64(value: u64) -> Option<Self> {
+        Self::from_sign_magnitude(false, u256::from(value))
+    }
+
+    fn from_large_int<T: LargeInt>(value: T) -> Option<Self> {
+        let (negative, magnitude) = value.to_sign_magnitude();
+        Self::from_sign_magnitude(negative, magnitude)
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        i64::try_from(self.to_i128()?).ok()
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        let (negative, magnitude) = self.to_sign_magnitude();
+        i128::from_sign_magnitude(negative, magnitude)
+    }
+
+    fn to_f64(self) -> f64;
+    fn parse(s: &str) -> Option<Self>;
+    fn de_binary(bytes: &mut &[u8]) -> Self;
+
+    fn data_type() -> LargeIntDataType;
+
+    fn try_downcast_scalar(scalar: &LargeIntScalar) -> Option<Self>;
+    fn try_downcast_column(column: &LargeIntColumn) -> Option<Buffer<Self>>;
+    fn try_downcast_builder(builder: &mut LargeIntColumnBuilder) -> Option<&mut Vec<Self>>;
+    fn try_downcast_owned_builder(builder: LargeIntColumnBuilder) -> Option<Vec<Self>>;
+    fn try_downcast_domain(domain: &LargeIntDomain) -> Option<SimpleDomain<Self>>;
+
+    fn upcast_scalar(scalar: Self) -> LargeIntScalar;
+    fn upcast_column(col: Buffer<Self>) -> LargeIntColumn;
+    fn upcast_builder(builder: Vec<Self>) -> LargeIntColumnBuilder;
+    fn upcast_domain(domain: SimpleDomain<Self>) -> LargeIntDomain;
+}
+
+macro_rules! impl_large_int_common {
+    ($ty:ty, $variant:ident, $signed:expr, $size:expr) => {
+        const MIN: Self = <$ty>::MIN;
+        const MAX: Self = <$ty>::MAX;
+        const SIGNED: bool = $signed;
+
+        fn mem_size() -> usize {
+            $size
+        }
+
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+            self.checked_add(rhs)
+        }
+
+        fn checked_sub(self, rhs: Self) -> Option<Self> {
+            self.checked_sub(rhs)
+        }
+
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            self.checked_mul(rhs)
+        }
+
+        fn checked_div(self, rhs: Self) -> Option<Self> {
+            self.checked_div(rhs)
+        }
+
+        fn checked_rem(self, rhs: Self) -> Option<Self> {
+            self.checked_rem(rhs)
+        }
+
+        fn checked_neg(self) -> Option<Self> {
+            self.checked_neg()
+        }
+
+        fn parse(s: &str) -> Option<Self> {
+            s.trim().parse::<$ty>().ok()
+        }
+
+        fn de_binary(bytes: &mut &[u8]) -> Self {
+            let bs: [u8; $size] = bytes[0..$size].try_into().unwrap();
+            *bytes = &bytes[$size..];
+
+            <$ty>::from_le_bytes(bs)
+        }
+
+        fn data_type() -> LargeIntDataType {
+            LargeIntDataType::$variant
+        }
+
+        fn try_downcast_scalar(scalar: &LargeIntScalar) -> Option<Self> {
+            match scalar {
+                LargeIntScalar::$variant(val) => Some(*val),
+                _ => None,
+            }
+        }
+
+        fn try_downcast_column(column: &LargeIntColumn) -> Option<Buffer<Self>> {
+            match column {
+                LargeIntColumn::$variant(col) => Some(col.clone()),
+                _ => None,
+            }
+        }
+
+        fn try_downcast_builder(builder: &mut LargeIntColumnBuilder) -> Option<&mut Vec<Self>> {
+            match builder {
+                LargeIntColumnBuilder::$variant(b) => Some(b),
+                _ => None,
+            }
+        }
+
+        fn try_downcast_owned_builder(builder: LargeIntColumnBuilder) -> Option<Vec<Self>> {
+            match builder {
+                LargeIntColumnBuilder::$variant(b) => Some(b),
+                _ => None,
+            }
+        }
+
+        fn try_downcast_domain(domain: &LargeIntDomain) -> Option<SimpleDomain<Self>> {
+            match domain {
+                LargeIntDomain::$variant(domain) => Some(*domain),
+                _ => None,
+            }
+        }
+
+        fn upcast_scalar(scalar: Self) -> LargeIntScalar {
+            LargeIntScalar::$variant(scalar)
+        }
+
+        fn upcast_column(col: Buffer<Self>) -> LargeIntColumn {
+            LargeIntColumn::$variant(col)
+        }
+
+        fn upcast_builder(builder: Vec<Self>) -> LargeIntColumnBuilder {
+            LargeIntColumnBuilder::$variant(builder)
+        }
+
+        fn upcast_domain(domain: SimpleDomain<Self>) -> LargeIntDomain {
+            LargeIntDomain::$variant(domain)
+        }
+    };
+}
+
+impl LargeInt for i128 {
+    impl_large_int_common!(i128, Int128, true, 16);
+
+    fn zero() -> Self {
+        0
+    }
+
+    fn to_sign_magnitude(self) -> (bool, u256) {
+        (self < 0, u256::from(self.unsigned_abs()))
+    }
+
+    fn from_sign_magnitude(negative: bool, magnitude: u256) -> Option<Self> {
+        if !negative && magnitude <= u256::from(i128::MAX as u128) {
+            Some(magnitude.as_u128() as i128)
+        } else if negative && magnitude <= u256::from(1u128 << 127) {
+            Some((magnitude.as_u128() as i128).wrapping_neg())
+        } else {
+            None
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl LargeInt for u128 {
+    impl_large_int_common!(u128, UInt128, false, 16);
+
+    fn zero() -> Self {
+        0
+    }
+
+    fn to_sign_magnitude(self) -> (bool, u256) {
+        (false, u256::from(self))
+    }
+
+    fn from_sign_magnitude(negative: bool, magnitude: u256) -> Option<Self> {
+        if (negative && magnitude != u256::ZERO) || magnitude > u256::from(u128::MAX) {
+            None
+        } else {
+            Some(magnitude.as_u128())
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl LargeInt for i256 {
+    impl_large_int_common!(i256, Int256, true, 32);
+
+    fn zero() -> Self {
+        i256::ZERO
+    }
+
+    fn to_sign_magnitude(self) -> (bool, u256) {
+        (self.is_negative(), self.unsigned_abs())
+    }
+
+    fn from_sign_magnitude(negative: bool, magnitude: u256) -> Option<Self> {
+        if !negative && magnitude <= i256::MAX.as_u256() {
+            Some(magnitude.as_i256())
+        } else if negative && magnitude <= u256::ONE << 255 {
+            Some(magnitude.as_i256().wrapping_neg())
+        } else {
+            None
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self.as_f64()
+    }
+}
+
+impl LargeInt for u256 {
+    impl_large_int_common!(u256, UInt256, false, 32);
+
+    fn zero() -> Self {
+        u256::ZERO
+    }
+
+    fn to_sign_magnitude(self) -> (bool, u256) {
+        (false, self)
+    }
+
+    fn from_sign_magnitude(negative: bool, magnitude: u256) -> Option<Self> {
+        if negative && magnitude != u256::ZERO {
+            None
+        } else {
+            Some(magnitude)
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self.as_f64()
+    }
+}
+
+impl LargeIntDataType {
+    pub fn bit_width(&self) -> u16 {
+        match self {
+            LargeIntDataType::Int128 | LargeIntDataType::UInt128 => 128,
+            LargeIntDataType::Int256 | LargeIntDataType::UInt256 => 256,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, LargeIntDataType::Int128 | LargeIntDataType::Int256)
+    }
+
+    /// Whether every value of `self` can be represented by `other`.
+    pub fn can_lossless_cast_to(&self, other: &LargeIntDataType) -> bool {
+        match (self.is_signed(), other.is_signed()) {
+            (true, true) | (false, false) => self.bit_width() <= other.bit_width(),
+            (false, true) => self.bit_width() < other.bit_width(),
+            (true, false) => false,
+        }
+    }
+
+    pub fn default_scalar(&self) -> LargeIntScalar {
+        crate::with_large_int_mapped_type!(|LARGE_INT| match self {
+            LargeIntDataType::LARGE_INT => LargeIntScalar::LARGE_INT(LARGE_INT::zero()),
+        })
+    }
+}
+
+impl LargeIntScalar {
+    pub fn domain(&self) -> LargeIntDomain {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntScalar::LARGE_INT(num) => LargeIntDomain::LARGE_INT(SimpleDomain {
+                min: *num,
+                max: *num,
+            }),
+        })
+    }
+
+    pub fn data_type(&self) -> LargeIntDataType {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntScalar::LARGE_INT(_) => LargeIntDataType::LARGE_INT,
+        })
+    }
+
+    pub fn is_positive(&self) -> bool {
+        crate::with_large_int_mapped_type!(|LARGE_INT| match self {
+            LargeIntScalar::LARGE_INT(num) => *num > LARGE_INT::zero(),
+        })
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntScalar::LARGE_INT(num) => num.to_f64(),
+        })
+    }
+}
+
+impl LargeIntColumn {
+    pub fn len(&self) -> usize {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(col) => col.len(),
+        })
+    }
+
+    pub fn data_type(&self) -> LargeIntDataType {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(_) => LargeIntDataType::LARGE_INT,
+        })
+    }
+
+    pub fn index(&self, index: usize) -> Option<LargeIntScalar> {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(col) =>
+                Some(LargeIntScalar::LARGE_INT(col.get(index).cloned()?)),
+        })
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> LargeIntScalar {
+        debug_assert!(index < self.len());
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(col) => LargeIntScalar::LARGE_INT(*col.get_unchecked(index)),
+        })
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        assert!(
+            range.end <= self.len(),
+            "range {:?} out of len {}",
+            range,
+            self.len()
+        );
+
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(col) => {
+                LargeIntColumn::LARGE_INT(col.clone().sliced(range.start, range.end - range.start))
+            }
+        })
+    }
+
+    pub fn domain(&self) -> LargeIntDomain {
+        assert!(self.len() > 0);
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                LargeIntDomain::LARGE_INT(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+        })
+    }
+
+    pub fn memory_size(&self) -> usize {
+        crate::with_large_int_mapped_type!(|LARGE_INT| match self {
+            LargeIntColumn::LARGE_INT(col) => col.len() * LARGE_INT::mem_size(),
+        })
+    }
+}
+
+impl LargeIntColumnBuilder {
+    pub fn from_column(col: LargeIntColumn) -> Self {
+        crate::with_large_int_type!(|LARGE_INT| match col {
+            LargeIntColumn::LARGE_INT(col) =>
+                LargeIntColumnBuilder::LARGE_INT(buffer_into_mut(col)),
+        })
+    }
+
+    pub fn repeat(scalar: LargeIntScalar, n: usize) -> LargeIntColumnBuilder {
+        crate::with_large_int_type!(|LARGE_INT| match scalar {
+            LargeIntScalar::LARGE_INT(num) => LargeIntColumnBuilder::LARGE_INT(vec![num; n]),
+        })
+    }
+
+    pub fn repeat_default(ty: &LargeIntDataType, n: usize) -> Self {
+        crate::with_large_int_mapped_type!(|LARGE_INT| match ty {
+            LargeIntDataType::LARGE_INT =>
+                LargeIntColumnBuilder::LARGE_INT(vec![LARGE_INT::zero(); n]),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(col) => col.len(),
+        })
+    }
+
+    pub fn data_type(&self) -> LargeIntDataType {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(_) => LargeIntDataType::LARGE_INT,
+        })
+    }
+
+    pub fn memory_size(&self) -> usize {
+        crate::with_large_int_mapped_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(col) => col.len() * LARGE_INT::mem_size(),
+        })
+    }
+
+    pub fn with_capacity(ty: &LargeIntDataType, capacity: usize) -> Self {
+        crate::with_large_int_type!(|LARGE_INT| match ty {
+            LargeIntDataType::LARGE_INT =>
+                LargeIntColumnBuilder::LARGE_INT(Vec::with_capacity(capacity)),
+        })
+    }
+
+    pub fn push(&mut self, item: LargeIntScalar) {
+        self.push_repeat(item, 1)
+    }
+
+    pub fn push_repeat(&mut self, item: LargeIntScalar, n: usize) {
+        crate::with_large_int_type!(|LARGE_INT| match (self, item) {
+            (LargeIntColumnBuilder::LARGE_INT(builder), LargeIntScalar::LARGE_INT(value)) => {
+                if n == 1 {
+                    builder.push(value)
+                } else {
+                    builder.resize(builder.len() + n, value)
+                }
+            }
+            (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
+        })
+    }
+
+    pub fn push_default(&mut self) {
+        crate::with_large_int_mapped_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(builder) => builder.push(LARGE_INT::zero()),
+        })
+    }
+
+    pub fn append_column(&mut self, other: &LargeIntColumn) {
+        crate::with_large_int_type!(|LARGE_INT| match (self, other) {
+            (LargeIntColumnBuilder::LARGE_INT(builder), LargeIntColumn::LARGE_INT(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (builder, other) => unreachable!(
+                "unable append column(data type: {}) into builder(data type: {})",
+                other.data_type(),
+                builder.data_type()
+            ),
+        })
+    }
+
+    pub fn build(self) -> LargeIntColumn {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(builder) => LargeIntColumn::LARGE_INT(builder.into()),
+        })
+    }
+
+    pub fn build_scalar(self) -> LargeIntScalar {
+        assert_eq!(self.len(), 1);
+
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(builder) => LargeIntScalar::LARGE_INT(builder[0]),
+        })
+    }
+
+    pub fn pop(&mut self) -> Option<LargeIntScalar> {
+        crate::with_large_int_type!(|LARGE_INT| match self {
+            LargeIntColumnBuilder::LARGE_INT(builder) =>
+                builder.pop().map(LargeIntScalar::LARGE_INT),
+        })
+    }
+}
+
+impl PartialOrd for LargeIntScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        crate::with_large_int_type!(|LARGE_INT| match (self, other) {
+            (LargeIntScalar::LARGE_INT(lhs), LargeIntScalar::LARGE_INT(rhs)) =>
+                lhs.partial_cmp(rhs),
+            _ => None,
+        })
+    }
+}
+
+impl PartialOrd for LargeIntColumn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        crate::with_large_int_type!(|LARGE_INT| match (self, other) {
+            (LargeIntColumn::LARGE_INT(lhs), LargeIntColumn::LARGE_INT(rhs)) =>
+                lhs.iter().partial_cmp(rhs.iter()),
+            _ => None,
+        })
+    }
+}
+
+#[macro_export]
+macro_rules! with_large_int_type {
+    ( | $t:tt | $($tail:tt)* ) => {
+        match_template::match_template! {
+            $t = [Int128, UInt128, Int256, UInt256],
+            $($tail)*
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! with_large_int_mapped_type {
+    (| $t:tt | $($tail:tt)*) => {
+        match_template::match_template! {
+            $t = [
+                Int128 => i128, UInt128 => u128,
+                Int256 => $crate::types::large_int::i256,
+                UInt256 => $crate::types::large_int::u256
+            ],
+            $($tail)*
+        }
+    }
+}
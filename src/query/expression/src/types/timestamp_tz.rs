@@ -0,0 +1,338 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono_tz::Tz;
+use databend_common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::check_timestamp;
+use crate::types::timestamp::string_to_timestamp;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::utils::date_helper::DateConverter;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const TIMESTAMP_TZ_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f %:z";
+
+/// A timestamp with the UTC offset it was written in.
+///
+/// The instant is kept as microseconds since the epoch in UTC, like `Timestamp`, and the
+/// offset in seconds east of UTC is kept alongside so that the value is displayed in the
+/// zone it came from instead of the session time zone. Both are packed into an `i128`
+/// so that a column has a fixed width.
+///
+/// Values are compared, hashed and sorted by the packed `i128`, i.e. by the instant and
+/// then by the offset, so the same instant in different offsets are different values.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct timestamp_tz(pub i128);
+
+impl timestamp_tz {
+    pub fn new(timestamp: i64, seconds_offset: i32) -> Self {
+        let timestamp_bits = (timestamp as u64 as u128) << 64;
+        let offset_bits = seconds_offset as u32 as u128;
+        timestamp_tz((timestamp_bits | offset_bits) as i128)
+    }
+
+    /// Microseconds since `1970-01-01 00:00:00 UTC`.
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        (self.0 >> 64) as i64
+    }
+
+    /// Seconds east of UTC.
+    #[inline]
+    pub fn seconds_offset(&self) -> i32 {
+        self.0 as u128 as u32 as i32
+    }
+
+    pub fn to_datetime(&self) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(self.seconds_offset())
+            .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+        self.timestamp()
+            .to_timestamp(Tz::UTC)
+            .with_timezone(&offset)
+    }
+}
+
+impl std::fmt::Debug for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_datetime().format(TIMESTAMP_TZ_FORMAT))
+    }
+}
+
+/// Parse a timestamp with an optional UTC offset, e.g. `2024-01-01 10:00:00+08:00`.
+///
+/// If the string has no offset, it is read in `tz` and the offset of `tz` at that instant
+/// is kept.
+pub fn string_to_timestamp_tz(s: &str, tz: Tz) -> Result<timestamp_tz, String> {
+    const FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S%.f%:z",
+        "%Y-%m-%d %H:%M:%S%.f %:z",
+        "%Y-%m-%d %H:%M:%S%.f%#z",
+        "%Y-%m-%d %H:%M:%S%.f %#z",
+        "%Y-%m-%dT%H:%M:%S%.f%:z",
+        "%Y-%m-%dT%H:%M:%S%.f%#z",
+    ];
+
+    let s = s.trim();
+    let dt = match FORMATS
+        .iter()
+        .find_map(|fmt| DateTime::parse_from_str(s, fmt).ok())
+    {
+        Some(dt) => dt,
+        None => {
+            let dt = string_to_timestamp(s, tz, false).map_err(|e| e.message())?;
+            dt.with_timezone(&dt.offset().fix())
+        }
+    };
+    let timestamp = check_timestamp(dt.timestamp_micros())?;
+    Ok(timestamp_tz::new(timestamp, dt.offset().local_minus_utc()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = timestamp_tz;
+    type ScalarRef<'a> = timestamp_tz;
+    type Column = Buffer<timestamp_tz>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, timestamp_tz>>;
+    type ColumnBuilder = Vec<timestamp_tz>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: timestamp_tz) -> timestamp_tz {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        match domain {
+            Domain::Undefined => Some(()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::TimestampTz(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.resize(builder.len() + n, item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> Ordering {
+        left.cmp(&right)
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
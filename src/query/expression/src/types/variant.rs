@@ -25,8 +25,12 @@ use super::binary::BinaryColumn;
 use super::binary::BinaryColumnBuilder;
 use super::binary::BinaryIterator;
 use super::date::date_to_string;
+use super::large_int::LargeInt;
+use super::large_int::LargeIntScalar;
 use super::number::NumberScalar;
+use super::time::time_to_string;
 use super::timestamp::timestamp_to_string;
+use super::uuid::uuid_to_string;
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
 use crate::types::map::KvPair;
@@ -39,6 +43,7 @@ use crate::types::ValueType;
 use crate::values::Column;
 use crate::values::Scalar;
 use crate::values::ScalarRef;
+use crate::with_large_int_type;
 use crate::ColumnBuilder;
 
 /// JSONB bytes representation of `null`.
@@ -227,11 +232,24 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             NumberScalar::Float64(n) => n.0.into(),
         },
         ScalarRef::Decimal(x) => x.to_float64().into(),
+        ScalarRef::LargeInt(x) => with_large_int_type!(|LARGE_INT| match x {
+            // Values out of the range of `i64` are kept as strings to avoid losing precision.
+            LargeIntScalar::LARGE_INT(v) => match v.to_i64() {
+                Some(n) => n.into(),
+                None => v.to_string().into(),
+            },
+        }),
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
         ScalarRef::Binary(s) => jsonb::Value::String(hex::encode_upper(s).into()),
         ScalarRef::String(s) => jsonb::Value::String(s.into()),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
+        ScalarRef::Time(t) => time_to_string(t).to_string().into(),
+        ScalarRef::TimestampTz(i) => i.to_string().into(),
+        ScalarRef::Vector(v) => jsonb::Value::Array(v.iter().map(|n| n.0.into()).collect()),
+        ScalarRef::Enum(v) => jsonb::Value::String(v.as_str().into()),
+        ScalarRef::Uuid(v) => uuid_to_string(v).to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -245,6 +263,7 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                     ScalarRef::String(v) => v.to_string(),
                     ScalarRef::Number(v) => v.to_string(),
                     ScalarRef::Decimal(v) => v.to_string(),
+                    ScalarRef::LargeInt(v) => v.to_string(),
                     ScalarRef::Boolean(v) => v.to_string(),
                     ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
                     ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
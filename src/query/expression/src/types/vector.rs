@@ -0,0 +1,384 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_arrow::arrow::trusted_len::TrustedLen;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::property::Domain;
+use crate::types::AnyType;
+use crate::types::ArrayColumn;
+use crate::types::DecimalSize;
+use crate::types::NumberColumn;
+use crate::types::ValueType;
+use crate::types::F32;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// The maximum dimension of a `VECTOR(n)` column.
+pub const MAX_VECTOR_DIMENSION: u64 = 16384;
+
+/// A vector of `Float32` with a fixed dimension.
+///
+/// Unlike `Array(Float32)`, the values of all rows are stored contiguously without offsets,
+/// the `i`th row is `values[i * dimension..(i + 1) * dimension]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VectorType;
+
+impl VectorType {
+    pub fn check_dimension(dimension: u64) -> Result<()> {
+        if dimension == 0 || dimension > MAX_VECTOR_DIMENSION {
+            return Err(ErrorCode::BadArguments(format!(
+                "Vector dimension must be between 1 and {MAX_VECTOR_DIMENSION}, but got {dimension}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl ValueType for VectorType {
+    type Scalar = Vec<F32>;
+    type ScalarRef<'a> = &'a [F32];
+    type Column = VectorColumn;
+    type Domain = ();
+    type ColumnIterator<'a> = VectorIterator<'a>;
+    type ColumnBuilder = VectorColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: &'long [F32]) -> &'short [F32] {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_vector().cloned()
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        col.as_vector().cloned()
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Vector(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Vector(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Vector(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Vector(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Vector(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.index(index)
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        VectorColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.push(item)
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.push_repeat(item, n)
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push_default()
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size(scalar: &Self::ScalarRef<'_>) -> usize {
+        std::mem::size_of_val(*scalar)
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.memory_size()
+    }
+
+    #[inline(always)]
+    fn compare(lhs: Self::ScalarRef<'_>, rhs: Self::ScalarRef<'_>) -> Ordering {
+        lhs.cmp(rhs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorColumn {
+    pub values: Buffer<F32>,
+    pub dimension: usize,
+}
+
+impl VectorColumn {
+    pub fn new(values: Buffer<F32>, dimension: usize) -> Self {
+        debug_assert!(dimension > 0);
+        debug_assert_eq!(values.len() % dimension, 0);
+        VectorColumn { values, dimension }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len() / self.dimension
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.values.len() * std::mem::size_of::<F32>()
+    }
+
+    pub fn index(&self, index: usize) -> Option<&[F32]> {
+        if index < self.len() {
+            Some(unsafe { self.index_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    #[inline]
+    pub unsafe fn index_unchecked(&self, index: usize) -> &[F32] {
+        let start = index * self.dimension;
+        self.values.get_unchecked(start..start + self.dimension)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        let values = self.values.clone().sliced(
+            range.start * self.dimension,
+            (range.end - range.start) * self.dimension,
+        );
+        VectorColumn {
+            values,
+            dimension: self.dimension,
+        }
+    }
+
+    pub fn iter(&self) -> VectorIterator<'_> {
+        VectorIterator {
+            values: &self.values,
+            dimension: self.dimension,
+        }
+    }
+
+    /// Converts the column into an `Array(Float32)` column sharing the same values.
+    pub fn to_array_column(&self) -> ArrayColumn<AnyType> {
+        let offsets = (0..=self.len())
+            .map(|i| (i * self.dimension) as u64)
+            .collect::<Vec<_>>();
+        ArrayColumn {
+            values: Column::Number(NumberColumn::Float32(self.values.clone())),
+            offsets: offsets.into(),
+        }
+    }
+
+    pub fn check_valid(&self) -> Result<()> {
+        if self.dimension == 0 || self.values.len() % self.dimension != 0 {
+            return Err(ErrorCode::Internal(format!(
+                "VectorColumn values length {} is not a multiple of dimension {}",
+                self.values.len(),
+                self.dimension
+            )));
+        }
+        Ok(())
+    }
+}
+
+pub struct VectorIterator<'a> {
+    values: &'a [F32],
+    dimension: usize,
+}
+
+impl<'a> Iterator for VectorIterator<'a> {
+    type Item = &'a [F32];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let (item, rest) = self.values.split_at(self.dimension);
+        self.values = rest;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.values.len() / self.dimension;
+        (len, Some(len))
+    }
+}
+
+unsafe impl<'a> TrustedLen for VectorIterator<'a> {}
+
+unsafe impl<'a> std::iter::TrustedLen for VectorIterator<'a> {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorColumnBuilder {
+    pub values: Vec<F32>,
+    pub dimension: usize,
+}
+
+impl VectorColumnBuilder {
+    pub fn with_capacity(dimension: usize, capacity: usize) -> Self {
+        VectorColumnBuilder {
+            values: Vec::with_capacity(capacity * dimension),
+            dimension,
+        }
+    }
+
+    pub fn from_column(col: VectorColumn) -> Self {
+        VectorColumnBuilder {
+            values: buffer_into_mut(col.values),
+            dimension: col.dimension,
+        }
+    }
+
+    pub fn repeat(scalar: &[F32], n: usize) -> Self {
+        let mut builder = Self::with_capacity(scalar.len(), n);
+        builder.push_repeat(scalar, n);
+        builder
+    }
+
+    pub fn repeat_default(dimension: usize, n: usize) -> Self {
+        VectorColumnBuilder {
+            values: vec![F32::default(); dimension * n],
+            dimension,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len() / self.dimension
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.values.len() * std::mem::size_of::<F32>()
+    }
+
+    pub fn push(&mut self, item: &[F32]) {
+        debug_assert_eq!(item.len(), self.dimension);
+        self.values.extend_from_slice(item);
+    }
+
+    pub fn push_repeat(&mut self, item: &[F32], n: usize) {
+        debug_assert_eq!(item.len(), self.dimension);
+        self.values.reserve(item.len() * n);
+        for _ in 0..n {
+            self.values.extend_from_slice(item);
+        }
+    }
+
+    pub fn push_default(&mut self) {
+        self.values
+            .resize(self.values.len() + self.dimension, F32::default());
+    }
+
+    pub fn append_column(&mut self, other: &VectorColumn) {
+        debug_assert_eq!(other.dimension, self.dimension);
+        self.values.extend_from_slice(&other.values);
+    }
+
+    pub fn pop(&mut self) -> Option<Vec<F32>> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let start = self.values.len() - self.dimension;
+        Some(self.values.split_off(start))
+    }
+
+    pub fn build(self) -> VectorColumn {
+        VectorColumn {
+            values: self.values.into(),
+            dimension: self.dimension,
+        }
+    }
+
+    pub fn build_scalar(self) -> Vec<F32> {
+        assert_eq!(self.len(), 1);
+        self.values
+    }
+}
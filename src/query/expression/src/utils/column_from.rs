@@ -62,6 +62,10 @@ impl_from_data! { Float32Type }
 impl_from_data! { Float64Type }
 impl_from_data! { Decimal128Type }
 impl_from_data! { Decimal256Type }
+impl_from_data! { Int128Type }
+impl_from_data! { UInt128Type }
+impl_from_data! { Int256Type }
+impl_from_data! { UInt256Type }
 impl_from_data! { BooleanType }
 impl_from_data! { BinaryType }
 impl_from_data! { StringType }
@@ -71,6 +75,10 @@ impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
 impl_from_data! { GeometryType }
 impl_from_data! { GeographyType }
+impl_from_data! { IntervalType }
+impl_from_data! { TimeType }
+impl_from_data! { TimestampTzType }
+impl_from_data! { UuidType }
 
 impl<'a> FromData<&'a [u8]> for BinaryType {
     fn from_data(d: Vec<&'a [u8]>) -> Column {
@@ -47,6 +47,11 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::enumeration::EnumValuesDisplay;
+use crate::types::large_int::LargeIntColumn;
+use crate::types::large_int::LargeIntDataType;
+use crate::types::large_int::LargeIntDomain;
+use crate::types::large_int::LargeIntScalar;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -56,7 +61,9 @@ use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
+use crate::types::time::time_to_string;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::uuid::uuid_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::NumberClass;
@@ -66,6 +73,7 @@ use crate::values::ScalarRef;
 use crate::values::Value;
 use crate::values::ValueRef;
 use crate::with_integer_mapped_type;
+use crate::with_large_int_type;
 use crate::Column;
 use crate::ColumnIndex;
 use crate::FunctionEval;
@@ -120,6 +128,7 @@ impl<'a> Debug for ScalarRef<'a> {
             ScalarRef::EmptyMap => write!(f, "{{}} :: Map(Nothing)"),
             ScalarRef::Number(val) => write!(f, "{val:?}"),
             ScalarRef::Decimal(val) => write!(f, "{val:?}"),
+            ScalarRef::LargeInt(val) => write!(f, "{val:?}"),
             ScalarRef::Boolean(val) => write!(f, "{val}"),
             ScalarRef::Binary(s) => {
                 for c in *s {
@@ -129,6 +138,12 @@ impl<'a> Debug for ScalarRef<'a> {
             }
             ScalarRef::String(s) => write!(f, "{s:?}"),
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
+            ScalarRef::Time(i) => write!(f, "{i:?}"),
+            ScalarRef::TimestampTz(i) => write!(f, "{i:?}"),
+            ScalarRef::Vector(v) => write!(f, "{v:?}"),
+            ScalarRef::Enum(v) => write!(f, "{:?}", v.as_str()),
+            ScalarRef::Uuid(v) => write!(f, "{}", uuid_to_string(*v)),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
@@ -192,6 +207,7 @@ impl Debug for Column {
             Column::EmptyMap { len } => f.debug_struct("EmptyMap").field("len", len).finish(),
             Column::Number(col) => write!(f, "{col:?}"),
             Column::Decimal(col) => write!(f, "{col:?}"),
+            Column::LargeInt(col) => write!(f, "{col:?}"),
             Column::Boolean(col) => f.debug_tuple("Boolean").field(col).finish(),
             Column::Binary(col) => write!(f, "{col:?}"),
             Column::String(col) => write!(f, "{col:?}"),
@@ -205,6 +221,12 @@ impl Debug for Column {
             Column::Variant(col) => write!(f, "{col:?}"),
             Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Geography(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
+            Column::Time(col) => write!(f, "{col:?}"),
+            Column::TimestampTz(col) => write!(f, "{col:?}"),
+            Column::Vector(col) => write!(f, "{col:?}"),
+            Column::Enum(col) => write!(f, "{col:?}"),
+            Column::Uuid(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -217,6 +239,7 @@ impl<'a> Display for ScalarRef<'a> {
             ScalarRef::EmptyMap => write!(f, "{{}}"),
             ScalarRef::Number(val) => write!(f, "{val}"),
             ScalarRef::Decimal(val) => write!(f, "{val}"),
+            ScalarRef::LargeInt(val) => write!(f, "{val}"),
             ScalarRef::Boolean(val) => write!(f, "{val}"),
             ScalarRef::Binary(s) => {
                 for c in *s {
@@ -226,6 +249,14 @@ impl<'a> Display for ScalarRef<'a> {
             }
             ScalarRef::String(s) => write!(f, "'{s}'"),
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
+            ScalarRef::Time(t) => write!(f, "'{}'", time_to_string(*t)),
+            ScalarRef::TimestampTz(i) => write!(f, "'{i}'"),
+            ScalarRef::Vector(v) => {
+                write!(f, "[{}]", v.iter().map(|n| display_f32(n.0)).join(", "))
+            }
+            ScalarRef::Enum(v) => write!(f, "'{}'", v.as_str()),
+            ScalarRef::Uuid(v) => write!(f, "'{}'", uuid_to_string(*v)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
@@ -360,6 +391,25 @@ impl Display for DecimalScalar {
     }
 }
 
+impl Debug for LargeIntScalar {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LargeIntScalar::Int128(val) => write!(f, "{val}_i128"),
+            LargeIntScalar::UInt128(val) => write!(f, "{val}_u128"),
+            LargeIntScalar::Int256(val) => write!(f, "{val}_i256"),
+            LargeIntScalar::UInt256(val) => write!(f, "{val}_u256"),
+        }
+    }
+}
+
+impl Display for LargeIntScalar {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        with_large_int_type!(|TYPE| match self {
+            LargeIntScalar::TYPE(val) => write!(f, "{val}"),
+        })
+    }
+}
+
 impl Debug for NumberColumn {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -414,6 +464,17 @@ impl Debug for DecimalColumn {
     }
 }
 
+impl Debug for LargeIntColumn {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LargeIntColumn::Int128(val) => f.debug_tuple("Int128").field(val).finish(),
+            LargeIntColumn::UInt128(val) => f.debug_tuple("UInt128").field(val).finish(),
+            LargeIntColumn::Int256(val) => f.debug_tuple("Int256").field(val).finish(),
+            LargeIntColumn::UInt256(val) => f.debug_tuple("UInt256").field(val).finish(),
+        }
+    }
+}
+
 impl Debug for BinaryColumn {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         f.debug_struct("BinaryColumn")
@@ -514,6 +575,7 @@ impl Display for DataType {
             DataType::String => write!(f, "String"),
             DataType::Number(num) => write!(f, "{num}"),
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
+            DataType::LargeInt(ty) => write!(f, "{ty}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
             DataType::Null => write!(f, "NULL"),
@@ -544,6 +606,12 @@ impl Display for DataType {
             DataType::Variant => write!(f, "Variant"),
             DataType::Geometry => write!(f, "Geometry"),
             DataType::Geography => write!(f, "Geography"),
+            DataType::Interval => write!(f, "Interval"),
+            DataType::Time => write!(f, "Time"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
+            DataType::Vector(dim) => write!(f, "Vector({dim})"),
+            DataType::Enum(values) => write!(f, "Enum({})", EnumValuesDisplay(values)),
+            DataType::Uuid => write!(f, "Uuid"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -557,6 +625,7 @@ impl Display for TableDataType {
             TableDataType::String => write!(f, "String"),
             TableDataType::Number(num) => write!(f, "{num}"),
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
+            TableDataType::LargeInt(ty) => write!(f, "{ty}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
             TableDataType::Null => write!(f, "NULL"),
@@ -593,6 +662,12 @@ impl Display for TableDataType {
             TableDataType::Variant => write!(f, "Variant"),
             TableDataType::Geometry => write!(f, "Geometry"),
             TableDataType::Geography => write!(f, "Geography"),
+            TableDataType::Interval => write!(f, "Interval"),
+            TableDataType::Time => write!(f, "Time"),
+            TableDataType::TimestampTz => write!(f, "TimestampTz"),
+            TableDataType::Vector(dim) => write!(f, "Vector({dim})"),
+            TableDataType::Enum(values) => write!(f, "Enum({})", EnumValuesDisplay(values)),
+            TableDataType::Uuid => write!(f, "Uuid"),
         }
     }
 }
@@ -627,6 +702,17 @@ impl Display for DecimalDataType {
     }
 }
 
+impl Display for LargeIntDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match &self {
+            LargeIntDataType::Int128 => write!(f, "Int128"),
+            LargeIntDataType::UInt128 => write!(f, "UInt128"),
+            LargeIntDataType::Int256 => write!(f, "Int256"),
+            LargeIntDataType::UInt256 => write!(f, "UInt256"),
+        }
+    }
+}
+
 impl Display for NumberClass {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match &self {
@@ -1042,6 +1128,14 @@ impl Display for DecimalDomain {
     }
 }
 
+impl Display for LargeIntDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        with_large_int_type!(|TYPE| match self {
+            LargeIntDomain::TYPE(domain) => write!(f, "{domain}"),
+        })
+    }
+}
+
 impl<T: Display> Display for SimpleDomain<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{{{}..={}}}", self.min, self.max)
@@ -1053,6 +1147,7 @@ impl Display for Domain {
         match self {
             Domain::Number(domain) => write!(f, "{domain}"),
             Domain::Decimal(domain) => write!(f, "{domain}"),
+            Domain::LargeInt(domain) => write!(f, "{domain}"),
             Domain::Boolean(domain) => write!(f, "{domain}"),
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
@@ -20,6 +20,7 @@ use chrono::NaiveDate;
 
 use crate::types::decimal::Decimal;
 use crate::types::decimal::DecimalSize;
+use crate::types::large_int::LargeInt;
 
 pub const EPOCH_DAYS_FROM_CE: i32 = 719_163;
 
@@ -327,6 +328,35 @@ pub fn read_decimal_from_json<T: Decimal>(
     }
 }
 
+/// Reads an optionally signed integer literal from the start of `buf`,
+/// returning the value and the number of bytes consumed.
+pub fn read_large_int<T: LargeInt>(buf: &[u8]) -> Result<(T, usize), String> {
+    let mut n_read = usize::from(matches!(buf.first(), Some(b'-' | b'+')));
+    n_read += buf[n_read..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    let n = std::str::from_utf8(&buf[..n_read])
+        .ok()
+        .and_then(T::parse)
+        .ok_or_else(|| large_int_parse_error::<T>(&String::from_utf8_lossy(&buf[..n_read])))?;
+    Ok((n, n_read))
+}
+
+pub fn read_large_int_from_json<T: LargeInt>(value: &serde_json::Value) -> Result<T, String> {
+    match value {
+        serde_json::Value::Number(n) => {
+            T::parse(&n.to_string()).ok_or_else(|| large_int_parse_error::<T>(&n.to_string()))
+        }
+        serde_json::Value::String(s) => T::parse(s).ok_or_else(|| large_int_parse_error::<T>(s)),
+        _ => Err(format!("Incorrect json value for {}", T::data_type())),
+    }
+}
+
+fn large_int_parse_error<T: LargeInt>(s: &str) -> String {
+    format!("unable to parse '{s}' as {}", T::data_type())
+}
+
 fn decimal_parse_error(msg: &str) -> String {
     format!("bad decimal literal: {msg}")
 }
@@ -36,11 +36,18 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::String
         | DataType::Number(_)
         | DataType::Decimal(_)
+        | DataType::LargeInt(_)
         | DataType::Timestamp
         | DataType::Date
         | DataType::Bitmap
         | DataType::Geometry
         | DataType::Geography
+        | DataType::Interval
+        | DataType::Time
+        | DataType::TimestampTz
+        | DataType::Vector(_)
+        | DataType::Enum(_)
+        | DataType::Uuid
         | DataType::Generic(_) => false,
         DataType::Nullable(ty) => contains_variant(ty.as_ref()),
         DataType::Array(ty) => contains_variant(ty.as_ref()),
@@ -75,6 +82,7 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::EmptyMap
         | ScalarRef::Number(_)
         | ScalarRef::Decimal(_)
+        | ScalarRef::LargeInt(_)
         | ScalarRef::Timestamp(_)
         | ScalarRef::Date(_)
         | ScalarRef::Boolean(_)
@@ -82,7 +90,13 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::String(_)
         | ScalarRef::Bitmap(_)
         | ScalarRef::Geometry(_)
-        | ScalarRef::Geography(_) => scalar.to_owned(),
+        | ScalarRef::Geography(_)
+        | ScalarRef::Interval(_)
+        | ScalarRef::Time(_)
+        | ScalarRef::TimestampTz(_)
+        | ScalarRef::Vector(_)
+        | ScalarRef::Enum(_)
+        | ScalarRef::Uuid(_) => scalar.to_owned(),
         ScalarRef::Array(col) => Scalar::Array(transform_column(&col, decode)?),
         ScalarRef::Map(col) => Scalar::Map(transform_column(&col, decode)?),
         ScalarRef::Tuple(scalars) => {
@@ -18,6 +18,8 @@ use databend_common_exception::Result;
 use decimal::DecimalType;
 use geometry::GeometryType;
 
+use crate::types::interval::months_days_micros;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::*;
 use crate::*;
 
@@ -47,6 +49,10 @@ pub trait ValueVisitor {
         self.visit_typed_column::<DecimalType<T>>(column)
     }
 
+    fn visit_large_int<T: LargeInt>(&mut self, column: Buffer<T>) -> Result<()> {
+        self.visit_typed_column::<LargeIntType<T>>(column)
+    }
+
     fn visit_boolean(&mut self, bitmap: Bitmap) -> Result<()> {
         self.visit_typed_column::<BooleanType>(bitmap)
     }
@@ -99,6 +105,30 @@ pub trait ValueVisitor {
         self.visit_typed_column::<GeographyType>(column)
     }
 
+    fn visit_interval(&mut self, buffer: Buffer<months_days_micros>) -> Result<()> {
+        self.visit_typed_column::<IntervalType>(buffer)
+    }
+
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.visit_typed_column::<TimeType>(buffer)
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<timestamp_tz>) -> Result<()> {
+        self.visit_typed_column::<TimestampTzType>(buffer)
+    }
+
+    fn visit_vector(&mut self, column: VectorColumn) -> Result<()> {
+        self.visit_typed_column::<VectorType>(column)
+    }
+
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        self.visit_typed_column::<EnumType>(column)
+    }
+
+    fn visit_uuid(&mut self, buffer: Buffer<u128>) -> Result<()> {
+        self.visit_typed_column::<UuidType>(buffer)
+    }
+
     fn visit_typed_column<T: ValueType>(&mut self, column: <T as ValueType>::Column) -> Result<()>;
 
     fn visit_value(&mut self, value: Value<AnyType>) -> Result<()> {
@@ -123,6 +153,11 @@ pub trait ValueVisitor {
                     DecimalColumn::DECIMAL_TYPE(b, size) => self.visit_decimal(b, size),
                 })
             }
+            Column::LargeInt(column) => {
+                with_large_int_type!(|LARGE_INT| match column {
+                    LargeIntColumn::LARGE_INT(b) => self.visit_large_int(b),
+                })
+            }
             Column::Boolean(bitmap) => self.visit_boolean(bitmap),
             Column::Binary(column) => self.visit_binary(column),
             Column::String(column) => self.visit_string(column),
@@ -136,6 +171,12 @@ pub trait ValueVisitor {
             Column::Variant(column) => self.visit_variant(column),
             Column::Geometry(column) => self.visit_geometry(column),
             Column::Geography(column) => self.visit_geography(column),
+            Column::Interval(buffer) => self.visit_interval(buffer),
+            Column::Time(buffer) => self.visit_time(buffer),
+            Column::TimestampTz(buffer) => self.visit_timestamp_tz(buffer),
+            Column::Vector(column) => self.visit_vector(column),
+            Column::Enum(column) => self.visit_enum(column),
+            Column::Uuid(buffer) => self.visit_uuid(buffer),
         }
     }
 }
@@ -65,6 +65,8 @@ use crate::types::geography::GeographyColumn;
 use crate::types::geography::GeographyRef;
 use crate::types::geometry::compare_geometry;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::months_days_micros;
+use crate::types::interval::MICROS_PER_DAY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -78,9 +80,11 @@ use crate::types::number::F64;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::string::StringDomain;
+use crate::types::time::TIME_MAX;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::variant::JSONB_NULL;
 use crate::types::*;
 use crate::utils::arrow::append_bitmap;
@@ -93,6 +97,8 @@ use crate::values::decimal::DecimalColumnVec;
 use crate::values::map::KvPair;
 use crate::with_decimal_mapped_type;
 use crate::with_decimal_type;
+use crate::with_large_int_mapped_type;
+use crate::with_large_int_type;
 use crate::with_number_mapped_type;
 use crate::with_number_type;
 
@@ -117,6 +123,7 @@ pub enum Scalar {
     EmptyMap,
     Number(NumberScalar),
     Decimal(DecimalScalar),
+    LargeInt(LargeIntScalar),
     Timestamp(i64),
     Date(i32),
     Boolean(bool),
@@ -129,6 +136,12 @@ pub enum Scalar {
     Variant(Vec<u8>),
     Geometry(Vec<u8>),
     Geography(Geography),
+    Interval(months_days_micros),
+    Time(i64),
+    TimestampTz(timestamp_tz),
+    Vector(Vec<F32>),
+    Enum(EnumScalar),
+    Uuid(u128),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -139,6 +152,7 @@ pub enum ScalarRef<'a> {
     EmptyMap,
     Number(NumberScalar),
     Decimal(DecimalScalar),
+    LargeInt(LargeIntScalar),
     Boolean(bool),
     Binary(&'a [u8]),
     String(&'a str),
@@ -151,6 +165,12 @@ pub enum ScalarRef<'a> {
     Variant(&'a [u8]),
     Geometry(&'a [u8]),
     Geography(GeographyRef<'a>),
+    Interval(months_days_micros),
+    Time(i64),
+    TimestampTz(timestamp_tz),
+    Vector(&'a [F32]),
+    Enum(EnumScalarRef<'a>),
+    Uuid(u128),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -160,6 +180,7 @@ pub enum Column {
     EmptyMap { len: usize },
     Number(NumberColumn),
     Decimal(DecimalColumn),
+    LargeInt(LargeIntColumn),
     Boolean(Bitmap),
     Binary(BinaryColumn),
     String(StringColumn),
@@ -173,6 +194,12 @@ pub enum Column {
     Variant(BinaryColumn),
     Geometry(BinaryColumn),
     Geography(GeographyColumn),
+    Interval(Buffer<months_days_micros>),
+    Time(Buffer<i64>),
+    TimestampTz(Buffer<timestamp_tz>),
+    Vector(VectorColumn),
+    Enum(EnumColumn),
+    Uuid(Buffer<u128>),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -182,6 +209,7 @@ pub enum ColumnVec {
     EmptyMap,
     Number(NumberColumnVec),
     Decimal(DecimalColumnVec),
+    LargeInt(LargeIntColumnVec),
     Boolean(Vec<Bitmap>),
     Binary(Vec<BinaryColumn>),
     String(Vec<StringColumn>),
@@ -195,6 +223,12 @@ pub enum ColumnVec {
     Variant(Vec<BinaryColumn>),
     Geometry(Vec<BinaryColumn>),
     Geography(Vec<GeographyColumn>),
+    Interval(Vec<Buffer<months_days_micros>>),
+    Time(Vec<Buffer<i64>>),
+    TimestampTz(Vec<Buffer<timestamp_tz>>),
+    Vector(Vec<VectorColumn>),
+    Enum(Vec<Buffer<u16>>),
+    Uuid(Vec<Buffer<u128>>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -204,6 +238,7 @@ pub enum ColumnBuilder {
     EmptyMap { len: usize },
     Number(NumberColumnBuilder),
     Decimal(DecimalColumnBuilder),
+    LargeInt(LargeIntColumnBuilder),
     Boolean(MutableBitmap),
     Binary(BinaryColumnBuilder),
     String(StringColumnBuilder),
@@ -217,6 +252,12 @@ pub enum ColumnBuilder {
     Variant(BinaryColumnBuilder),
     Geometry(BinaryColumnBuilder),
     Geography(BinaryColumnBuilder),
+    Interval(Vec<months_days_micros>),
+    Time(Vec<i64>),
+    TimestampTz(Vec<timestamp_tz>),
+    Vector(VectorColumnBuilder),
+    Enum(EnumColumnBuilder),
+    Uuid(Vec<u128>),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -361,6 +402,7 @@ impl Scalar {
             Scalar::EmptyMap => ScalarRef::EmptyMap,
             Scalar::Number(n) => ScalarRef::Number(*n),
             Scalar::Decimal(d) => ScalarRef::Decimal(*d),
+            Scalar::LargeInt(i) => ScalarRef::LargeInt(*i),
             Scalar::Boolean(b) => ScalarRef::Boolean(*b),
             Scalar::Binary(s) => ScalarRef::Binary(s.as_slice()),
             Scalar::String(s) => ScalarRef::String(s.as_str()),
@@ -373,6 +415,12 @@ impl Scalar {
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
             Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
             Scalar::Geography(g) => ScalarRef::Geography(g.as_ref()),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
+            Scalar::Time(i) => ScalarRef::Time(*i),
+            Scalar::TimestampTz(i) => ScalarRef::TimestampTz(*i),
+            Scalar::Vector(v) => ScalarRef::Vector(v.as_slice()),
+            Scalar::Enum(v) => ScalarRef::Enum(v.as_ref()),
+            Scalar::Uuid(v) => ScalarRef::Uuid(*v),
         }
     }
 
@@ -397,6 +445,7 @@ impl Scalar {
                 NumberDataType::Float64 => NumberScalar::Float64(OrderedFloat(0.0)),
             }),
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
+            DataType::LargeInt(ty) => Scalar::LargeInt(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
             DataType::Nullable(_) => Scalar::Null,
@@ -415,6 +464,15 @@ impl Scalar {
             DataType::Variant => Scalar::Variant(vec![]),
             DataType::Geometry => Scalar::Geometry(vec![]),
             DataType::Geography => Scalar::Geography(Geography::default()),
+            DataType::Interval => Scalar::Interval(months_days_micros::default()),
+            DataType::Time => Scalar::Time(0),
+            DataType::TimestampTz => Scalar::TimestampTz(timestamp_tz::default()),
+            DataType::Vector(dim) => Scalar::Vector(vec![F32::default(); *dim as usize]),
+            DataType::Enum(values) => Scalar::Enum(EnumScalar {
+                code: 0,
+                values: values.clone(),
+            }),
+            DataType::Uuid => Scalar::Uuid(0),
 
             _ => unimplemented!(),
         }
@@ -427,6 +485,7 @@ impl Scalar {
             | Scalar::EmptyMap
             | Scalar::Number(_)
             | Scalar::Decimal(_)
+            | Scalar::LargeInt(_)
             | Scalar::Timestamp(_)
             | Scalar::Date(_)
             | Scalar::Boolean(_)
@@ -435,7 +494,13 @@ impl Scalar {
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
-            | Scalar::Geography(_) => false,
+            | Scalar::Geography(_)
+            | Scalar::Interval(_)
+            | Scalar::Time(_)
+            | Scalar::TimestampTz(_)
+            | Scalar::Vector(_)
+            | Scalar::Enum(_)
+            | Scalar::Uuid(_) => false,
             Scalar::Array(_) | Scalar::Map(_) | Scalar::Tuple(_) => true,
         }
     }
@@ -444,6 +509,7 @@ impl Scalar {
         match self {
             Scalar::Number(n) => n.is_positive(),
             Scalar::Decimal(d) => d.is_positive(),
+            Scalar::LargeInt(i) => i.is_positive(),
             Scalar::Timestamp(t) => *t > 0,
             Scalar::Date(d) => *d > 0,
             _ => unreachable!("is_positive() called on non-numeric scalar"),
@@ -476,6 +542,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::EmptyMap => Scalar::EmptyMap,
             ScalarRef::Number(n) => Scalar::Number(*n),
             ScalarRef::Decimal(d) => Scalar::Decimal(*d),
+            ScalarRef::LargeInt(i) => Scalar::LargeInt(*i),
             ScalarRef::Boolean(b) => Scalar::Boolean(*b),
             ScalarRef::Binary(s) => Scalar::Binary(s.to_vec()),
             ScalarRef::String(s) => Scalar::String(s.to_string()),
@@ -490,6 +557,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
             ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
             ScalarRef::Geography(s) => Scalar::Geography(s.to_owned()),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
+            ScalarRef::Time(i) => Scalar::Time(*i),
+            ScalarRef::TimestampTz(i) => Scalar::TimestampTz(*i),
+            ScalarRef::Vector(v) => Scalar::Vector(v.to_vec()),
+            ScalarRef::Enum(v) => Scalar::Enum(v.to_owned()),
+            ScalarRef::Uuid(v) => Scalar::Uuid(*v),
         }
     }
 
@@ -513,6 +586,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::EmptyMap => Domain::Map(None),
             ScalarRef::Number(num) => Domain::Number(num.domain()),
             ScalarRef::Decimal(dec) => Domain::Decimal(dec.domain()),
+            ScalarRef::LargeInt(i) => Domain::LargeInt(i.domain()),
             ScalarRef::Boolean(true) => Domain::Boolean(BooleanDomain {
                 has_false: false,
                 has_true: true,
@@ -555,7 +629,13 @@ impl<'a> ScalarRef<'a> {
             | ScalarRef::Bitmap(_)
             | ScalarRef::Variant(_)
             | ScalarRef::Geometry(_)
-            | ScalarRef::Geography(_) => Domain::Undefined,
+            | ScalarRef::Geography(_)
+            | ScalarRef::Interval(_)
+            | ScalarRef::Time(_)
+            | ScalarRef::TimestampTz(_)
+            | ScalarRef::Vector(_)
+            | ScalarRef::Enum(_)
+            | ScalarRef::Uuid(_) => Domain::Undefined,
         }
     }
 
@@ -574,6 +654,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Number(NumberScalar::Int64(_)) => 8,
             ScalarRef::Decimal(DecimalScalar::Decimal128(_, _)) => 16,
             ScalarRef::Decimal(DecimalScalar::Decimal256(_, _)) => 32,
+            ScalarRef::LargeInt(i) => i.data_type().bit_width() as usize / 8,
             ScalarRef::Boolean(_) => 1,
             ScalarRef::Binary(s) => s.len(),
             ScalarRef::String(s) => s.len(),
@@ -586,6 +667,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Variant(buf) => buf.len(),
             ScalarRef::Geometry(buf) => buf.len(),
             ScalarRef::Geography(s) => s.0.len(),
+            ScalarRef::Interval(_) => 16,
+            ScalarRef::Time(_) => 8,
+            ScalarRef::TimestampTz(_) => 16,
+            ScalarRef::Vector(v) => v.len() * 4,
+            ScalarRef::Enum(_) => 2,
+            ScalarRef::Uuid(_) => 16,
         }
     }
 
@@ -602,6 +689,7 @@ impl<'a> ScalarRef<'a> {
                 DecimalScalar::DECIMAL_TYPE(_, size) =>
                     DataType::Decimal(DecimalDataType::DECIMAL_TYPE(*size)),
             }),
+            ScalarRef::LargeInt(i) => DataType::LargeInt(i.data_type()),
             ScalarRef::Boolean(_) => DataType::Boolean,
             ScalarRef::Binary(_) => DataType::Binary,
             ScalarRef::String(_) => DataType::String,
@@ -620,6 +708,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Variant(_) => DataType::Variant,
             ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Geography(_) => DataType::Geography,
+            ScalarRef::Interval(_) => DataType::Interval,
+            ScalarRef::Time(_) => DataType::Time,
+            ScalarRef::TimestampTz(_) => DataType::TimestampTz,
+            ScalarRef::Vector(v) => DataType::Vector(v.len() as u64),
+            ScalarRef::Enum(v) => DataType::Enum(v.values.clone()),
+            ScalarRef::Uuid(_) => DataType::Uuid,
         }
     }
 
@@ -663,6 +757,11 @@ impl<'a> ScalarRef<'a> {
                     _ => None,
                 })
             }
+            (ScalarRef::LargeInt(s1), ScalarRef::LargeInt(s2))
+                if s1.data_type() == s2.data_type() =>
+            {
+                Some(DataType::LargeInt(s1.data_type()))
+            }
             (ScalarRef::Boolean(_), ScalarRef::Boolean(_)) => Some(DataType::Boolean),
             (ScalarRef::Binary(_), ScalarRef::Binary(_)) => Some(DataType::Binary),
             (ScalarRef::String(_), ScalarRef::String(_)) => Some(DataType::String),
@@ -686,6 +785,16 @@ impl<'a> ScalarRef<'a> {
             (ScalarRef::Variant(_), ScalarRef::Variant(_)) => Some(DataType::Variant),
             (ScalarRef::Geometry(_), ScalarRef::Geometry(_)) => Some(DataType::Geometry),
             (ScalarRef::Geography(_), ScalarRef::Geography(_)) => Some(DataType::Geography),
+            (ScalarRef::Interval(_), ScalarRef::Interval(_)) => Some(DataType::Interval),
+            (ScalarRef::Time(_), ScalarRef::Time(_)) => Some(DataType::Time),
+            (ScalarRef::TimestampTz(_), ScalarRef::TimestampTz(_)) => Some(DataType::TimestampTz),
+            (ScalarRef::Vector(v1), ScalarRef::Vector(v2)) if v1.len() == v2.len() => {
+                Some(DataType::Vector(v1.len() as u64))
+            }
+            (ScalarRef::Enum(v1), ScalarRef::Enum(v2)) if v1.values == v2.values => {
+                Some(DataType::Enum(v1.values.clone()))
+            }
+            (ScalarRef::Uuid(_), ScalarRef::Uuid(_)) => Some(DataType::Uuid),
             _ => None,
         }
     }
@@ -700,6 +809,7 @@ impl<'a> ScalarRef<'a> {
                 (ScalarRef::EmptyMap, DataType::EmptyMap) => true,
                 (ScalarRef::Number(_), DataType::Number(_)) => true,
                 (ScalarRef::Decimal(_), DataType::Decimal(_)) => true,
+                (ScalarRef::LargeInt(val), DataType::LargeInt(ty)) => val.data_type() == ty,
                 (ScalarRef::Boolean(_), DataType::Boolean) => true,
                 (ScalarRef::Binary(_), DataType::Binary) => true,
                 (ScalarRef::String(_), DataType::String) => true,
@@ -709,6 +819,12 @@ impl<'a> ScalarRef<'a> {
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Geometry(_), DataType::Geometry) => true,
                 (ScalarRef::Geography(_), DataType::Geography) => true,
+                (ScalarRef::Interval(_), DataType::Interval) => true,
+                (ScalarRef::Time(_), DataType::Time) => true,
+                (ScalarRef::TimestampTz(_), DataType::TimestampTz) => true,
+                (ScalarRef::Vector(v), DataType::Vector(dim)) => v.len() as u64 == *dim,
+                (ScalarRef::Enum(v), DataType::Enum(values)) => v.values == values,
+                (ScalarRef::Uuid(_), DataType::Uuid) => true,
                 (ScalarRef::Array(val), DataType::Array(ty)) => val.data_type() == *ty,
                 (ScalarRef::Map(val), DataType::Map(ty)) => val.data_type() == *ty,
                 (ScalarRef::Tuple(val), DataType::Tuple(ty)) => {
@@ -733,6 +849,7 @@ impl PartialOrd for Scalar {
             (Scalar::EmptyMap, Scalar::EmptyMap) => Some(Ordering::Equal),
             (Scalar::Number(n1), Scalar::Number(n2)) => n1.partial_cmp(n2),
             (Scalar::Decimal(d1), Scalar::Decimal(d2)) => d1.partial_cmp(d2),
+            (Scalar::LargeInt(i1), Scalar::LargeInt(i2)) => i1.partial_cmp(i2),
             (Scalar::Boolean(b1), Scalar::Boolean(b2)) => b1.partial_cmp(b2),
             (Scalar::Binary(s1), Scalar::Binary(s2)) => s1.partial_cmp(s2),
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
@@ -747,6 +864,12 @@ impl PartialOrd for Scalar {
             }
             (Scalar::Geometry(g1), Scalar::Geometry(g2)) => compare_geometry(g1, g2),
             (Scalar::Geography(g1), Scalar::Geography(g2)) => g1.partial_cmp(g2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
+            (Scalar::Time(i1), Scalar::Time(i2)) => i1.partial_cmp(i2),
+            (Scalar::TimestampTz(i1), Scalar::TimestampTz(i2)) => i1.partial_cmp(i2),
+            (Scalar::Vector(v1), Scalar::Vector(v2)) => v1.partial_cmp(v2),
+            (Scalar::Enum(v1), Scalar::Enum(v2)) => v1.code.partial_cmp(&v2.code),
+            (Scalar::Uuid(v1), Scalar::Uuid(v2)) => v1.partial_cmp(v2),
             _ => None,
         }
     }
@@ -772,6 +895,7 @@ impl<'a, 'b> PartialOrd<ScalarRef<'b>> for ScalarRef<'a> {
             (ScalarRef::EmptyMap, ScalarRef::EmptyMap) => Some(Ordering::Equal),
             (ScalarRef::Number(n1), ScalarRef::Number(n2)) => n1.partial_cmp(n2),
             (ScalarRef::Decimal(d1), ScalarRef::Decimal(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::LargeInt(i1), ScalarRef::LargeInt(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Boolean(b1), ScalarRef::Boolean(b2)) => b1.partial_cmp(b2),
             (ScalarRef::Binary(s1), ScalarRef::Binary(s2)) => s1.partial_cmp(s2),
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
@@ -784,6 +908,12 @@ impl<'a, 'b> PartialOrd<ScalarRef<'b>> for ScalarRef<'a> {
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
             (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => compare_geometry(g1, g2),
             (ScalarRef::Geography(g1), ScalarRef::Geography(g2)) => g1.partial_cmp(g2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Time(i1), ScalarRef::Time(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::TimestampTz(i1), ScalarRef::TimestampTz(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Vector(v1), ScalarRef::Vector(v2)) => v1.partial_cmp(v2),
+            (ScalarRef::Enum(v1), ScalarRef::Enum(v2)) => v1.code.partial_cmp(&v2.code),
+            (ScalarRef::Uuid(v1), ScalarRef::Uuid(v2)) => v1.partial_cmp(v2),
 
             // By default, null is biggest in pgsql
             (ScalarRef::Null, _) => Some(Ordering::Greater),
@@ -819,6 +949,11 @@ impl Hash for ScalarRef<'_> {
                     v.hash(state);
                 }
             }),
+            ScalarRef::LargeInt(t) => with_large_int_type!(|LARGE_INT| match t {
+                LargeIntScalar::LARGE_INT(v) => {
+                    v.hash(state);
+                }
+            }),
             ScalarRef::Boolean(v) => v.hash(state),
             ScalarRef::Binary(v) => v.hash(state),
             ScalarRef::String(v) => v.hash(state),
@@ -839,6 +974,12 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::Variant(v) => v.hash(state),
             ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Geography(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
+            ScalarRef::Time(v) => v.hash(state),
+            ScalarRef::TimestampTz(v) => v.hash(state),
+            ScalarRef::Vector(v) => v.hash(state),
+            ScalarRef::Enum(v) => v.hash(state),
+            ScalarRef::Uuid(v) => v.hash(state),
         }
     }
 }
@@ -861,6 +1002,7 @@ impl PartialOrd for Column {
             }
             (Column::Number(col1), Column::Number(col2)) => col1.partial_cmp(col2),
             (Column::Decimal(col1), Column::Decimal(col2)) => col1.partial_cmp(col2),
+            (Column::LargeInt(col1), Column::LargeInt(col2)) => col1.partial_cmp(col2),
             (Column::Boolean(col1), Column::Boolean(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::String(col1), Column::String(col2)) => col1.iter().partial_cmp(col2.iter()),
@@ -884,6 +1026,18 @@ impl PartialOrd for Column {
             (Column::Geography(col1), Column::Geography(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Time(col1), Column::Time(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Vector(col1), Column::Vector(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Enum(col1), Column::Enum(col2)) => {
+                col1.codes.iter().partial_cmp(col2.codes.iter())
+            }
+            (Column::Uuid(col1), Column::Uuid(col2)) => col1.iter().partial_cmp(col2.iter()),
             (a, b) => {
                 if a.len() != b.len() {
                     a.len().partial_cmp(&b.len())
@@ -921,6 +1075,7 @@ impl Column {
             Column::EmptyMap { len } => *len,
             Column::Number(col) => col.len(),
             Column::Decimal(col) => col.len(),
+            Column::LargeInt(col) => col.len(),
             Column::Boolean(col) => col.len(),
             Column::Binary(col) => col.len(),
             Column::String(col) => col.len(),
@@ -934,6 +1089,12 @@ impl Column {
             Column::Variant(col) => col.len(),
             Column::Geometry(col) => col.len(),
             Column::Geography(col) => col.len(),
+            Column::Interval(col) => col.len(),
+            Column::Time(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
+            Column::Vector(col) => col.len(),
+            Column::Enum(col) => col.len(),
+            Column::Uuid(col) => col.len(),
         }
     }
 
@@ -944,6 +1105,7 @@ impl Column {
             Column::EmptyMap { .. } => Some(ScalarRef::EmptyMap),
             Column::Number(col) => Some(ScalarRef::Number(col.index(index)?)),
             Column::Decimal(col) => Some(ScalarRef::Decimal(col.index(index)?)),
+            Column::LargeInt(col) => Some(ScalarRef::LargeInt(col.index(index)?)),
             Column::Boolean(col) => Some(ScalarRef::Boolean(col.get(index)?)),
             Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
@@ -962,6 +1124,12 @@ impl Column {
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
             Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Geography(col) => Some(ScalarRef::Geography(col.index(index)?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
+            Column::Time(col) => Some(ScalarRef::Time(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
+            Column::Vector(col) => Some(ScalarRef::Vector(col.index(index)?)),
+            Column::Enum(col) => Some(ScalarRef::Enum(col.index(index)?)),
+            Column::Uuid(col) => Some(ScalarRef::Uuid(col.get(index).cloned()?)),
         }
     }
 
@@ -975,6 +1143,7 @@ impl Column {
             Column::EmptyMap { .. } => ScalarRef::EmptyMap,
             Column::Number(col) => ScalarRef::Number(col.index_unchecked(index)),
             Column::Decimal(col) => ScalarRef::Decimal(col.index_unchecked(index)),
+            Column::LargeInt(col) => ScalarRef::LargeInt(col.index_unchecked(index)),
             Column::Boolean(col) => ScalarRef::Boolean(col.get_bit_unchecked(index)),
             Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
@@ -993,6 +1162,12 @@ impl Column {
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
             Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Geography(col) => ScalarRef::Geography(col.index_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
+            Column::Time(col) => ScalarRef::Time(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
+            Column::Vector(col) => ScalarRef::Vector(col.index_unchecked(index)),
+            Column::Enum(col) => ScalarRef::Enum(col.index_unchecked(index)),
+            Column::Uuid(col) => ScalarRef::Uuid(*col.get_unchecked(index)),
         }
     }
 
@@ -1021,6 +1196,7 @@ impl Column {
             },
             Column::Number(col) => Column::Number(col.slice(range)),
             Column::Decimal(col) => Column::Decimal(col.slice(range)),
+            Column::LargeInt(col) => Column::LargeInt(col.slice(range)),
             Column::Boolean(col) => {
                 Column::Boolean(col.clone().sliced(range.start, range.end - range.start))
             }
@@ -1045,6 +1221,20 @@ impl Column {
             Column::Variant(col) => Column::Variant(col.slice(range)),
             Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Geography(col) => Column::Geography(col.slice(range)),
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Time(col) => {
+                Column::Time(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Vector(col) => Column::Vector(col.slice(range)),
+            Column::Enum(col) => Column::Enum(col.slice(range)),
+            Column::Uuid(col) => {
+                Column::Uuid(col.clone().sliced(range.start, range.end - range.start))
+            }
         }
     }
 
@@ -1069,6 +1259,7 @@ impl Column {
             Column::EmptyMap { .. } => Domain::Map(None),
             Column::Number(col) => Domain::Number(col.domain()),
             Column::Decimal(col) => Domain::Decimal(col.domain()),
+            Column::LargeInt(col) => Domain::LargeInt(col.domain()),
             Column::Boolean(col) => Domain::Boolean(BooleanDomain {
                 has_false: col.unset_bits() > 0,
                 has_true: col.len() - col.unset_bits() > 0,
@@ -1125,7 +1316,13 @@ impl Column {
             | Column::Bitmap(_)
             | Column::Variant(_)
             | Column::Geometry(_)
-            | Column::Geography(_) => Domain::Undefined,
+            | Column::Geography(_)
+            | Column::Interval(_)
+            | Column::Time(_)
+            | Column::TimestampTz(_)
+            | Column::Vector(_)
+            | Column::Enum(_)
+            | Column::Uuid(_) => Domain::Undefined,
         }
     }
 
@@ -1141,6 +1338,7 @@ impl Column {
                 DecimalColumn::DECIMAL_TYPE(_, size) =>
                     DataType::Decimal(DecimalDataType::DECIMAL_TYPE(*size)),
             }),
+            Column::LargeInt(c) => DataType::LargeInt(c.data_type()),
             Column::Boolean(_) => DataType::Boolean,
             Column::Binary(_) => DataType::Binary,
             Column::String(_) => DataType::String,
@@ -1166,6 +1364,12 @@ impl Column {
             Column::Variant(_) => DataType::Variant,
             Column::Geometry(_) => DataType::Geometry,
             Column::Geography(_) => DataType::Geography,
+            Column::Interval(_) => DataType::Interval,
+            Column::Time(_) => DataType::Time,
+            Column::TimestampTz(_) => DataType::TimestampTz,
+            Column::Vector(col) => DataType::Vector(col.dimension as u64),
+            Column::Enum(col) => DataType::Enum(col.values.clone()),
+            Column::Uuid(_) => DataType::Uuid,
         }
     }
 
@@ -1176,6 +1380,8 @@ impl Column {
             Column::Variant(x) => x.check_valid(),
             Column::Geometry(x) => x.check_valid(),
             Column::Geography(x) => x.check_valid(),
+            Column::Vector(x) => x.check_valid(),
+            Column::Enum(x) => x.check_valid(),
             Column::Bitmap(x) => x.check_valid(),
             Column::Map(x) => {
                 for y in x.iter() {
@@ -1271,6 +1477,15 @@ impl Column {
                     Column::Decimal(DecimalColumn::Decimal256(values.into(), *size))
                 }
             },
+            DataType::LargeInt(ty) => {
+                with_large_int_mapped_type!(|LARGE_INT| match ty {
+                    LargeIntDataType::LARGE_INT => LargeIntType::<LARGE_INT>::from_data(
+                        (0..len)
+                            .map(|_| LARGE_INT::from_u64(rng.gen::<u64>()).unwrap())
+                            .collect_vec(),
+                    ),
+                })
+            }
             DataType::Timestamp => TimestampType::from_data(
                 (0..len)
                     .map(|_| rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX))
@@ -1364,6 +1579,49 @@ impl Column {
                 }
                 Column::Geography(GeographyColumn(builder.build()))
             }
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        months_days_micros::new(
+                            rng.gen_range(-120..=120),
+                            rng.gen_range(-60..=60),
+                            rng.gen_range(-MICROS_PER_DAY..=MICROS_PER_DAY),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::Time => TimeType::from_data(
+                (0..len)
+                    .map(|_| rng.gen_range(0..=TIME_MAX))
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::TimestampTz => TimestampTzType::from_data(
+                (0..len)
+                    .map(|_| {
+                        timestamp_tz::new(
+                            rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX),
+                            rng.gen_range(-14..=14) * 3600,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            DataType::Vector(dim) => Column::Vector(VectorColumn::new(
+                (0..len * *dim as usize)
+                    .map(|_| F32::from(rng.gen::<f32>()))
+                    .collect::<Vec<_>>()
+                    .into(),
+                *dim as usize,
+            )),
+            DataType::Enum(values) => Column::Enum(EnumColumn::new(
+                (0..len)
+                    .map(|_| rng.gen_range(0..values.len()) as u16)
+                    .collect::<Vec<_>>()
+                    .into(),
+                values.clone(),
+            )),
+            DataType::Uuid => {
+                UuidType::from_data((0..len).map(|_| rng.gen::<u128>()).collect::<Vec<_>>())
+            }
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1409,6 +1667,7 @@ impl Column {
             Column::Number(NumberColumn::Int64(col)) => col.len() * 8,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
+            Column::LargeInt(col) => col.memory_size(),
             Column::Boolean(c) => c.as_slice().0.len(),
             Column::Binary(col) => col.memory_size(),
             Column::String(col) => col.memory_size(),
@@ -1422,6 +1681,12 @@ impl Column {
             Column::Variant(col) => col.memory_size(),
             Column::Geometry(col) => col.memory_size(),
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Interval(col) => col.len() * 16,
+            Column::Time(col) => col.len() * 8,
+            Column::TimestampTz(col) => col.len() * 16,
+            Column::Vector(col) => col.memory_size(),
+            Column::Enum(col) => col.memory_size(),
+            Column::Uuid(col) => col.len() * 16,
         }
     }
 
@@ -1440,7 +1705,14 @@ impl Column {
             Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
+            Column::LargeInt(col) => col.memory_size(),
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Interval(col) => col.len() * 16,
+            Column::Time(col) => col.len() * 8,
+            Column::TimestampTz(col) => col.len() * 16,
+            Column::Vector(col) => col.memory_size(),
+            Column::Enum(col) => col.memory_size(),
+            Column::Uuid(col) => col.len() * 16,
             Column::Boolean(c) => c.len(),
             Column::Binary(col)
             | Column::Bitmap(col)
@@ -1531,6 +1803,9 @@ impl ColumnBuilder {
             Column::EmptyMap { len } => ColumnBuilder::EmptyMap { len },
             Column::Number(col) => ColumnBuilder::Number(NumberColumnBuilder::from_column(col)),
             Column::Decimal(col) => ColumnBuilder::Decimal(DecimalColumnBuilder::from_column(col)),
+            Column::LargeInt(col) => {
+                ColumnBuilder::LargeInt(LargeIntColumnBuilder::from_column(col))
+            }
             Column::Boolean(col) => ColumnBuilder::Boolean(bitmap_into_mut(col)),
             Column::Binary(col) => ColumnBuilder::Binary(BinaryColumnBuilder::from_column(col)),
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
@@ -1557,6 +1832,12 @@ impl ColumnBuilder {
             Column::Geography(col) => {
                 ColumnBuilder::Geography(GeographyType::column_to_builder(col))
             }
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
+            Column::Time(col) => ColumnBuilder::Time(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
+            Column::Vector(col) => ColumnBuilder::Vector(VectorColumnBuilder::from_column(col)),
+            Column::Enum(col) => ColumnBuilder::Enum(EnumColumnBuilder::from_column(col)),
+            Column::Uuid(col) => ColumnBuilder::Uuid(buffer_into_mut(col)),
         }
     }
 
@@ -1589,6 +1870,7 @@ impl ColumnBuilder {
             ScalarRef::Decimal(dec) => {
                 ColumnBuilder::Decimal(DecimalColumnBuilder::repeat(*dec, n))
             }
+            ScalarRef::LargeInt(i) => ColumnBuilder::LargeInt(LargeIntColumnBuilder::repeat(*i, n)),
             ScalarRef::Boolean(b) => ColumnBuilder::Boolean(Bitmap::new_constant(*b, n).make_mut()),
             ScalarRef::Binary(s) => ColumnBuilder::Binary(BinaryColumnBuilder::repeat(s, n)),
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
@@ -1617,6 +1899,12 @@ impl ColumnBuilder {
             ScalarRef::Geography(s) => {
                 ColumnBuilder::Geography(BinaryColumnBuilder::repeat(s.0, n))
             }
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
+            ScalarRef::Time(i) => ColumnBuilder::Time(vec![*i; n]),
+            ScalarRef::TimestampTz(i) => ColumnBuilder::TimestampTz(vec![*i; n]),
+            ScalarRef::Vector(v) => ColumnBuilder::Vector(VectorColumnBuilder::repeat(v, n)),
+            ScalarRef::Enum(v) => ColumnBuilder::Enum(EnumColumnBuilder::repeat(*v, n)),
+            ScalarRef::Uuid(v) => ColumnBuilder::Uuid(vec![*v; n]),
         }
     }
 
@@ -1627,6 +1915,7 @@ impl ColumnBuilder {
             ColumnBuilder::EmptyMap { len } => *len,
             ColumnBuilder::Number(col) => col.len(),
             ColumnBuilder::Decimal(col) => col.len(),
+            ColumnBuilder::LargeInt(col) => col.len(),
             ColumnBuilder::Boolean(builder) => builder.len(),
             ColumnBuilder::Binary(builder) => builder.len(),
             ColumnBuilder::String(builder) => builder.len(),
@@ -1640,6 +1929,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(builder) => builder.len(),
             ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Geography(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
+            ColumnBuilder::Time(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
+            ColumnBuilder::Vector(builder) => builder.len(),
+            ColumnBuilder::Enum(builder) => builder.len(),
+            ColumnBuilder::Uuid(builder) => builder.len(),
         }
     }
 
@@ -1664,6 +1959,7 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(DecimalColumnBuilder::Decimal256(builder, _)) => {
                 builder.len() * 32
             }
+            ColumnBuilder::LargeInt(builder) => builder.memory_size(),
             ColumnBuilder::Boolean(c) => c.as_slice().len(),
             ColumnBuilder::Binary(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
@@ -1677,6 +1973,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geography(builder) => builder.memory_size(),
+            ColumnBuilder::Interval(builder) => builder.len() * 16,
+            ColumnBuilder::Time(builder) => builder.len() * 8,
+            ColumnBuilder::TimestampTz(builder) => builder.len() * 16,
+            ColumnBuilder::Vector(builder) => builder.memory_size(),
+            ColumnBuilder::Enum(builder) => builder.memory_size(),
+            ColumnBuilder::Uuid(builder) => builder.len() * 16,
         }
     }
 
@@ -1692,6 +1994,7 @@ impl ColumnBuilder {
                 DecimalColumnBuilder::DECIMAL_TYPE(_, size) =>
                     DataType::Decimal(DecimalDataType::DECIMAL_TYPE(*size)),
             }),
+            ColumnBuilder::LargeInt(col) => DataType::LargeInt(col.data_type()),
             ColumnBuilder::Boolean(_) => DataType::Boolean,
             ColumnBuilder::Binary(_) => DataType::Binary,
             ColumnBuilder::String(_) => DataType::String,
@@ -1713,6 +2016,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(_) => DataType::Variant,
             ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Geography(_) => DataType::Geography,
+            ColumnBuilder::Interval(_) => DataType::Interval,
+            ColumnBuilder::Time(_) => DataType::Time,
+            ColumnBuilder::TimestampTz(_) => DataType::TimestampTz,
+            ColumnBuilder::Vector(builder) => DataType::Vector(builder.dimension as u64),
+            ColumnBuilder::Enum(builder) => DataType::Enum(builder.values.clone()),
+            ColumnBuilder::Uuid(_) => DataType::Uuid,
         }
     }
 
@@ -1737,6 +2046,9 @@ impl ColumnBuilder {
             DataType::Decimal(decimal_ty) => {
                 ColumnBuilder::Decimal(DecimalColumnBuilder::with_capacity(decimal_ty, capacity))
             }
+            DataType::LargeInt(ty) => {
+                ColumnBuilder::LargeInt(LargeIntColumnBuilder::with_capacity(ty, capacity))
+            }
             DataType::Boolean => ColumnBuilder::Boolean(MutableBitmap::with_capacity(capacity)),
             DataType::Binary => {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
@@ -1798,6 +2110,16 @@ impl ColumnBuilder {
                     data_capacity,
                 ))
             }
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
+            DataType::Time => ColumnBuilder::Time(Vec::with_capacity(capacity)),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
+            DataType::Vector(dim) => {
+                ColumnBuilder::Vector(VectorColumnBuilder::with_capacity(*dim as usize, capacity))
+            }
+            DataType::Enum(values) => {
+                ColumnBuilder::Enum(EnumColumnBuilder::with_capacity(values.clone(), capacity))
+            }
+            DataType::Uuid => ColumnBuilder::Uuid(Vec::with_capacity(capacity)),
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -1824,8 +2146,21 @@ impl ColumnBuilder {
             DataType::Decimal(decimal_ty) => {
                 ColumnBuilder::Decimal(DecimalColumnBuilder::repeat_default(decimal_ty, len))
             }
+            DataType::LargeInt(ty) => {
+                ColumnBuilder::LargeInt(LargeIntColumnBuilder::repeat_default(ty, len))
+            }
             DataType::Timestamp => ColumnBuilder::Timestamp(vec![0; len]),
             DataType::Date => ColumnBuilder::Date(vec![0; len]),
+            DataType::Interval => ColumnBuilder::Interval(vec![months_days_micros::default(); len]),
+            DataType::Time => ColumnBuilder::Time(vec![0; len]),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(vec![timestamp_tz::default(); len]),
+            DataType::Vector(dim) => {
+                ColumnBuilder::Vector(VectorColumnBuilder::repeat_default(*dim as usize, len))
+            }
+            DataType::Enum(values) => {
+                ColumnBuilder::Enum(EnumColumnBuilder::repeat_default(values.clone(), len))
+            }
+            DataType::Uuid => ColumnBuilder::Uuid(vec![0; len]),
 
             // binary based
             DataType::Binary => ColumnBuilder::Binary(BinaryColumnBuilder::repeat_default(len)),
@@ -1868,6 +2203,7 @@ impl ColumnBuilder {
             (ColumnBuilder::EmptyMap { len }, ScalarRef::EmptyMap) => *len += 1,
             (ColumnBuilder::Number(builder), ScalarRef::Number(value)) => builder.push(value),
             (ColumnBuilder::Decimal(builder), ScalarRef::Decimal(value)) => builder.push(value),
+            (ColumnBuilder::LargeInt(builder), ScalarRef::LargeInt(value)) => builder.push(value),
             (ColumnBuilder::Boolean(builder), ScalarRef::Boolean(value)) => {
                 BooleanType::push_item(builder, value);
             }
@@ -1913,6 +2249,24 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), ScalarRef::Geography(value)) => {
                 GeographyType::push_item(builder, value);
             }
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
+                IntervalType::push_item(builder, value);
+            }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                TimeType::push_item(builder, value);
+            }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                TimestampTzType::push_item(builder, value);
+            }
+            (ColumnBuilder::Vector(builder), ScalarRef::Vector(value)) => {
+                VectorType::push_item(builder, value);
+            }
+            (ColumnBuilder::Enum(builder), ScalarRef::Enum(value)) => {
+                EnumType::push_item(builder, *value);
+            }
+            (ColumnBuilder::Uuid(builder), ScalarRef::Uuid(value)) => {
+                UuidType::push_item(builder, value);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -1929,6 +2283,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Decimal(builder), ScalarRef::Decimal(value)) => {
                 builder.push_repeat(*value, n)
             }
+            (ColumnBuilder::LargeInt(builder), ScalarRef::LargeInt(value)) => {
+                builder.push_repeat(*value, n)
+            }
             (ColumnBuilder::Boolean(builder), ScalarRef::Boolean(value)) => {
                 BooleanType::push_item_repeat(builder, *value, n);
             }
@@ -1972,6 +2329,24 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), ScalarRef::Geography(value)) => {
                 GeographyType::push_item_repeat(builder, *value, n);
             }
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
+                IntervalType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                TimeType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                TimestampTzType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::Vector(builder), ScalarRef::Vector(value)) => {
+                VectorType::push_item_repeat(builder, value, n);
+            }
+            (ColumnBuilder::Enum(builder), ScalarRef::Enum(value)) => {
+                EnumType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::Uuid(builder), ScalarRef::Uuid(value)) => {
+                UuidType::push_item_repeat(builder, *value, n);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         };
     }
@@ -1983,6 +2358,7 @@ impl ColumnBuilder {
             ColumnBuilder::EmptyMap { len } => *len += 1,
             ColumnBuilder::Number(builder) => builder.push_default(),
             ColumnBuilder::Decimal(builder) => builder.push_default(),
+            ColumnBuilder::LargeInt(builder) => builder.push_default(),
             ColumnBuilder::Boolean(builder) => builder.push(false),
             ColumnBuilder::Binary(builder) => builder.commit_row(),
             ColumnBuilder::String(builder) => builder.commit_row(),
@@ -2003,6 +2379,12 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Geometry(builder) => builder.commit_row(),
             ColumnBuilder::Geography(builder) => builder.commit_row(),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::default()),
+            ColumnBuilder::Time(builder) => builder.push(0),
+            ColumnBuilder::TimestampTz(builder) => builder.push(timestamp_tz::default()),
+            ColumnBuilder::Vector(builder) => builder.push_default(),
+            ColumnBuilder::Enum(builder) => builder.push_default(),
+            ColumnBuilder::Uuid(builder) => builder.push(0),
         }
     }
 
@@ -2023,6 +2405,12 @@ impl ColumnBuilder {
                         builder.push(DECIMAL_TYPE::de_binary(reader)),
                 })
             }
+            ColumnBuilder::LargeInt(builder) => {
+                with_large_int_mapped_type!(|LARGE_INT| match builder {
+                    LargeIntColumnBuilder::LARGE_INT(builder) =>
+                        builder.push(LARGE_INT::de_binary(reader)),
+                })
+            }
             ColumnBuilder::Boolean(builder) => {
                 let v: bool = reader.read_scalar()?;
                 builder.push(v);
@@ -2063,6 +2451,35 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let mut bytes = [0u8; 16];
+                reader.read_exact(&mut bytes)?;
+                builder.push(months_days_micros(i128::from_le_bytes(bytes)));
+            }
+            ColumnBuilder::Time(builder) => {
+                let value: i64 = reader.read_scalar()?;
+                builder.push(value);
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                let mut bytes = [0u8; 16];
+                reader.read_exact(&mut bytes)?;
+                builder.push(timestamp_tz(i128::from_le_bytes(bytes)));
+            }
+            ColumnBuilder::Vector(builder) => {
+                for _ in 0..builder.dimension {
+                    let value: f32 = reader.read_scalar()?;
+                    builder.values.push(value.into());
+                }
+            }
+            ColumnBuilder::Enum(builder) => {
+                let code: u16 = reader.read_scalar()?;
+                builder.push(code);
+            }
+            ColumnBuilder::Uuid(builder) => {
+                let mut bytes = [0u8; 16];
+                reader.read_exact(&mut bytes)?;
+                builder.push(u128::from_le_bytes(bytes));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -2124,6 +2541,16 @@ impl ColumnBuilder {
                     }
                 })
             }
+            ColumnBuilder::LargeInt(builder) => {
+                with_large_int_mapped_type!(|LARGE_INT| match builder {
+                    LargeIntColumnBuilder::LARGE_INT(builder) => {
+                        for row in 0..rows {
+                            let mut reader = &reader[step * row..];
+                            builder.push(LARGE_INT::de_binary(&mut reader));
+                        }
+                    }
+                })
+            }
             ColumnBuilder::Boolean(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2169,6 +2596,50 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let reader = &reader[step * row..];
+                    let bytes: [u8; 16] = reader[..16].try_into().unwrap();
+                    builder.push(months_days_micros(i128::from_le_bytes(bytes)));
+                }
+            }
+            ColumnBuilder::Time(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: i64 = reader.read_scalar()?;
+                    builder.push(value);
+                }
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                for row in 0..rows {
+                    let reader = &reader[step * row..];
+                    let bytes: [u8; 16] = reader[..16].try_into().unwrap();
+                    builder.push(timestamp_tz(i128::from_le_bytes(bytes)));
+                }
+            }
+            ColumnBuilder::Vector(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    for _ in 0..builder.dimension {
+                        let value: f32 = reader.read_scalar()?;
+                        builder.values.push(value.into());
+                    }
+                }
+            }
+            ColumnBuilder::Enum(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let code: u16 = reader.read_scalar()?;
+                    builder.push(code);
+                }
+            }
+            ColumnBuilder::Uuid(builder) => {
+                for row in 0..rows {
+                    let reader = &reader[step * row..];
+                    let bytes: [u8; 16] = reader[..16].try_into().unwrap();
+                    builder.push(u128::from_le_bytes(bytes));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2237,6 +2708,7 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Number(builder) => builder.pop().map(Scalar::Number),
             ColumnBuilder::Decimal(builder) => builder.pop().map(Scalar::Decimal),
+            ColumnBuilder::LargeInt(builder) => builder.pop().map(Scalar::LargeInt),
             ColumnBuilder::Boolean(builder) => builder.pop().map(Scalar::Boolean),
             ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
@@ -2263,6 +2735,12 @@ impl ColumnBuilder {
             ColumnBuilder::Geography(builder) => {
                 builder.pop().map(Geography).map(Scalar::Geography)
             }
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
+            ColumnBuilder::Time(builder) => builder.pop().map(Scalar::Time),
+            ColumnBuilder::TimestampTz(builder) => builder.pop().map(Scalar::TimestampTz),
+            ColumnBuilder::Vector(builder) => builder.pop().map(Scalar::Vector),
+            ColumnBuilder::Enum(builder) => builder.pop().map(Scalar::Enum),
+            ColumnBuilder::Uuid(builder) => builder.pop().map(Scalar::Uuid),
         }
     }
 
@@ -2283,6 +2761,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Decimal(builder), Column::Decimal(column)) => {
                 builder.append_column(column);
             }
+            (ColumnBuilder::LargeInt(builder), Column::LargeInt(column)) => {
+                builder.append_column(column);
+            }
             (ColumnBuilder::Boolean(builder), Column::Boolean(other)) => {
                 append_bitmap(builder, other);
             }
@@ -2307,6 +2788,24 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Time(builder), Column::Time(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::TimestampTz(builder), Column::TimestampTz(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Vector(builder), Column::Vector(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::Enum(builder), Column::Enum(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::Uuid(builder), Column::Uuid(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2340,6 +2839,7 @@ impl ColumnBuilder {
             ColumnBuilder::EmptyMap { len } => Column::EmptyMap { len },
             ColumnBuilder::Number(builder) => Column::Number(builder.build()),
             ColumnBuilder::Decimal(builder) => Column::Decimal(builder.build()),
+            ColumnBuilder::LargeInt(builder) => Column::LargeInt(builder.build()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
@@ -2357,6 +2857,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(b) => Column::Variant(VariantType::build_column(b)),
             ColumnBuilder::Geometry(b) => Column::Geometry(GeometryType::build_column(b)),
             ColumnBuilder::Geography(b) => Column::Geography(GeographyType::build_column(b)),
+            ColumnBuilder::Interval(b) => Column::Interval(IntervalType::build_column(b)),
+            ColumnBuilder::Time(b) => Column::Time(TimeType::build_column(b)),
+            ColumnBuilder::TimestampTz(b) => Column::TimestampTz(TimestampTzType::build_column(b)),
+            ColumnBuilder::Vector(b) => Column::Vector(VectorType::build_column(b)),
+            ColumnBuilder::Enum(b) => Column::Enum(EnumType::build_column(b)),
+            ColumnBuilder::Uuid(b) => Column::Uuid(UuidType::build_column(b)),
         }
     }
 
@@ -2368,6 +2874,7 @@ impl ColumnBuilder {
             ColumnBuilder::EmptyMap { .. } => Scalar::EmptyMap,
             ColumnBuilder::Number(builder) => Scalar::Number(builder.build_scalar()),
             ColumnBuilder::Decimal(builder) => Scalar::Decimal(builder.build_scalar()),
+            ColumnBuilder::LargeInt(builder) => Scalar::LargeInt(builder.build_scalar()),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
@@ -2387,6 +2894,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(b) => Scalar::Variant(VariantType::build_scalar(b)),
             ColumnBuilder::Geometry(b) => Scalar::Geometry(GeometryType::build_scalar(b)),
             ColumnBuilder::Geography(b) => Scalar::Geography(GeographyType::build_scalar(b)),
+            ColumnBuilder::Interval(b) => Scalar::Interval(IntervalType::build_scalar(b)),
+            ColumnBuilder::Time(b) => Scalar::Time(TimeType::build_scalar(b)),
+            ColumnBuilder::TimestampTz(b) => Scalar::TimestampTz(TimestampTzType::build_scalar(b)),
+            ColumnBuilder::Vector(b) => Scalar::Vector(VectorType::build_scalar(b)),
+            ColumnBuilder::Enum(b) => Scalar::Enum(EnumType::build_scalar(b)),
+            ColumnBuilder::Uuid(b) => Scalar::Uuid(UuidType::build_scalar(b)),
         }
     }
 }
@@ -671,3 +671,35 @@ fn test_geography_as_arrow() {
     let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
     assert_eq!(col, got)
 }
+
+#[test]
+fn test_interval_as_arrow() {
+    use databend_common_expression::types::interval::months_days_micros;
+    use databend_common_expression::types::IntervalType;
+    use databend_common_expression::Column;
+    use databend_common_expression::FromData;
+
+    let field = TableField::new(
+        "a",
+        TableDataType::Nullable(Box::new(TableDataType::Interval)),
+    );
+    let arrow_field = ArrowField::from(&field);
+    assert_eq!(TableField::try_from(&arrow_field).unwrap(), field);
+    let arrow_rs_field = arrow_schema::Field::from(&field);
+    let schema = TableSchema::new(vec![field]);
+    assert_eq!(
+        TableSchema::try_from(&arrow_schema::Schema::new(vec![arrow_rs_field])).unwrap(),
+        schema
+    );
+
+    let col = IntervalType::from_data(vec![
+        months_days_micros::new(1, 2, 3),
+        months_days_micros::new(-1, 0, -3_600_000_000),
+        months_days_micros::new(i32::MAX, i32::MIN, i64::MAX),
+    ]);
+    let arr = col.as_arrow();
+    let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
+    assert_eq!(col, got);
+    let got = Column::from_arrow_rs(col.clone().into_arrow_rs(), &col.data_type()).unwrap();
+    assert_eq!(col, got);
+}
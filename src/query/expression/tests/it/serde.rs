@@ -19,7 +19,9 @@ use borsh::BorshSerialize;
 use databend_common_exception::Result;
 use databend_common_expression::arrow::deserialize_column;
 use databend_common_expression::arrow::serialize_column;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::Column;
 use databend_common_expression::FromData;
@@ -77,6 +79,11 @@ fn test_serde_bin_column() -> Result<()> {
     let columns = vec![
         StringType::from_data(vec!["SM CASE", "a", "b", "e", "f", "g"]),
         StringType::from_data(vec!["SM CASE", "axx", "bxx", "xxe", "eef", "fg"]),
+        IntervalType::from_data(vec![
+            months_days_micros::new(1, 2, 3),
+            months_days_micros::new(-1, 0, -3_600_000_000),
+        ]),
+        IntervalType::from_opt_data(vec![Some(months_days_micros::new(0, 1, 0)), None]),
     ];
 
     for col in columns {
@@ -112,3 +119,43 @@ fn test_borsh_serde_column() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_serde_interval() -> Result<()> {
+    #[derive(
+        serde::Serialize, serde::Deserialize, BorshSerialize, BorshDeserialize, Eq, PartialEq, Debug,
+    )]
+    struct Plan {
+        column: Column,
+        scalar: Scalar,
+    }
+
+    let plan = Plan {
+        column: IntervalType::from_data(vec![
+            months_days_micros::new(1, 2, 3),
+            months_days_micros::new(i32::MIN, i32::MAX, i64::MIN),
+        ]),
+        scalar: Scalar::Interval(months_days_micros::new(-1, 0, -3_600_000_000)),
+    };
+
+    {
+        let json = serde_json::to_vec(&plan).unwrap();
+        let new_plan = serde_json::from_slice::<Plan>(&json).unwrap();
+        assert_eq!(plan, new_plan);
+    }
+
+    {
+        let mut vs = vec![];
+        bincode_serialize_into_buf(&mut vs, &plan).unwrap();
+        let new_plan: Plan = bincode_deserialize_from_slice(vs.as_slice()).unwrap();
+        assert_eq!(plan, new_plan);
+    }
+
+    {
+        let mut vs = vec![];
+        borsh_serialize_into_buf(&mut vs, &plan).unwrap();
+        let new_plan: Plan = borsh_deserialize_from_slice(vs.as_slice()).unwrap();
+        assert_eq!(plan, new_plan);
+    }
+    Ok(())
+}
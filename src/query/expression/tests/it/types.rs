@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use chrono_tz::Tz;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::timestamp::timestamp_to_string;
 
 #[test]
@@ -26,3 +28,42 @@ fn test_timestamp_to_string_formats() {
         "2024-01-01 01:02:03.000000"
     );
 }
+
+#[test]
+fn test_string_to_interval() {
+    let cases = [
+        (
+            "1 year 2 months 3 days 04:05:06.7",
+            "1 year 2 months 3 days 04:05:06.700000",
+        ),
+        ("-1 day 3 hours", "-1 days 03:00:00"),
+        ("1.5 days", "1 day 12:00:00"),
+        ("1.5 years", "1 year 6 months"),
+        ("1.5 months", "1 month 15 days"),
+        ("0.5 weeks", "3 days 12:00:00"),
+        ("-1.25 days", "-1 days -06:00:00"),
+        ("1.5 ms", "00:00:00.001500"),
+    ];
+    for (input, expected) in cases {
+        let interval = string_to_interval(input).unwrap();
+        assert_eq!(interval.to_string(), expected, "{input}");
+        assert_eq!(string_to_interval(expected).unwrap(), interval, "{input}");
+    }
+
+    assert_eq!(
+        string_to_interval("1.5 days").unwrap(),
+        months_days_micros::new(0, 1, 12 * 3600 * 1_000_000)
+    );
+
+    for input in [
+        "",
+        "1",
+        "1 fortnight",
+        "NaN days",
+        "inf hours",
+        "-infinity seconds",
+        "1e10 years",
+    ] {
+        assert!(string_to_interval(input).is_err(), "{input}");
+    }
+}
@@ -26,6 +26,7 @@ use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::serialize::read_decimal_with_size;
+use databend_common_expression::serialize::read_large_int;
 use databend_common_expression::serialize::uniform_date;
 use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
@@ -33,13 +34,25 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::large_int::LargeInt;
+use databend_common_expression::types::large_int::LargeIntColumnBuilder;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
+use databend_common_expression::types::EnumColumnBuilder;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
+use databend_common_expression::types::F32;
 use databend_common_expression::with_decimal_type;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::Scalar;
@@ -142,9 +155,13 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
                 DecimalColumnBuilder::DECIMAL_TYPE(c, size) => self.read_decimal(c, *size, reader),
             }),
+            ColumnBuilder::LargeInt(c) => with_large_int_mapped_type!(|LARGE_INT| match c {
+                LargeIntColumnBuilder::LARGE_INT(c) => self.read_large_int(c, reader),
+            }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
             ColumnBuilder::String(c) => self.read_string(c, reader, positions),
+            ColumnBuilder::Enum(c) => self.read_enum(c, reader, positions),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
             ColumnBuilder::Map(c) => self.read_map(c, reader, positions),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader, positions),
@@ -152,6 +169,11 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, positions),
             ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
             ColumnBuilder::Geography(c) => self.read_geography(c, reader, positions),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, positions),
+            ColumnBuilder::Time(c) => self.read_time(c, reader, positions),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader, positions),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, reader, positions),
+            ColumnBuilder::Vector(c) => self.read_vector(c, reader),
             ColumnBuilder::Binary(_) => Err(ErrorCode::Unimplemented("binary literal")),
             ColumnBuilder::EmptyArray { .. } | ColumnBuilder::EmptyMap { .. } => {
                 Err(ErrorCode::Unimplemented("empty array/map literal"))
@@ -254,6 +276,18 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_large_int<R: AsRef<[u8]>, T: LargeInt>(
+        &self,
+        column: &mut Vec<T>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let buf = reader.remaining_slice();
+        let (n, n_read) = read_large_int(buf).map_err(ErrorCode::BadBytes)?;
+        column.push(n);
+        reader.consume(n_read);
+        Ok(())
+    }
+
     fn read_string_inner<R: AsRef<[u8]>>(
         &self,
         reader: &mut Cursor<R>,
@@ -275,6 +309,17 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_enum<R: AsRef<[u8]>>(
+        &self,
+        column: &mut EnumColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        column.push_value(std::str::from_utf8(&buf)?)
+    }
+
     fn read_date<R: AsRef<[u8]>>(
         &self,
         column: &mut Vec<i32>,
@@ -356,6 +401,36 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_vector<R: AsRef<[u8]>>(
+        &self,
+        column: &mut VectorColumnBuilder,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        reader.must_ignore_byte(b'[')?;
+        let mut values = Vec::with_capacity(column.dimension);
+        for idx in 0.. {
+            let _ = reader.ignore_white_spaces_or_comments();
+            if reader.ignore_byte(b']') {
+                break;
+            }
+            if idx != 0 {
+                reader.must_ignore_byte(b',')?;
+            }
+            let _ = reader.ignore_white_spaces_or_comments();
+            let v: f32 = reader.read_float_text()?;
+            values.push(F32::from(v));
+        }
+        if values.len() != column.dimension {
+            return Err(ErrorCode::BadBytes(format!(
+                "Vector dimension mismatch, expect {}, but got {}",
+                column.dimension,
+                values.len()
+            )));
+        }
+        column.push(&values);
+        Ok(())
+    }
+
     fn read_map<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
@@ -515,6 +590,63 @@ impl FastFieldDecoderValues {
         column.commit_row();
         Ok(())
     }
+
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<months_days_micros>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let interval =
+            string_to_interval(buf.to_str().unwrap_or_default()).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let time = string_to_time(buf.to_str().unwrap_or_default()).map_err(ErrorCode::BadBytes)?;
+        column.push(time);
+        Ok(())
+    }
+
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<timestamp_tz>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let ts = string_to_timestamp_tz(
+            buf.to_str().unwrap_or_default(),
+            self.common_settings().timezone,
+        )
+        .map_err(ErrorCode::BadBytes)?;
+        column.push(ts);
+        Ok(())
+    }
+
+    fn read_uuid<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<u128>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let uuid = string_to_uuid(buf.to_str().unwrap_or_default()).map_err(ErrorCode::BadBytes)?;
+        column.push(uuid);
+        Ok(())
+    }
 }
 
 pub struct FastValuesDecoder<'a> {
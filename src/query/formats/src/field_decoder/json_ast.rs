@@ -20,6 +20,7 @@ use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::serialize::read_decimal_from_json;
+use databend_common_expression::serialize::read_large_int_from_json;
 use databend_common_expression::serialize::uniform_date;
 use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
@@ -27,13 +28,25 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::large_int::LargeInt;
+use databend_common_expression::types::large_int::LargeIntColumnBuilder;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
+use databend_common_expression::types::EnumColumnBuilder;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
+use databend_common_expression::types::F32;
 use databend_common_expression::with_decimal_type;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
@@ -94,10 +107,14 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
                 DecimalColumnBuilder::DECIMAL_TYPE(c, size) => self.read_decimal(c, *size, value),
             }),
+            ColumnBuilder::LargeInt(c) => with_large_int_mapped_type!(|LARGE_INT| match c {
+                LargeIntColumnBuilder::LARGE_INT(c) => self.read_large_int(c, value),
+            }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
             ColumnBuilder::Binary(_c) => unimplemented!("binary literal is not supported"),
             ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Enum(c) => self.read_enum(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
@@ -105,6 +122,11 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
             ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
             ColumnBuilder::Geography(c) => self.read_geography(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
+            ColumnBuilder::Time(c) => self.read_time(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, value),
+            ColumnBuilder::Vector(c) => self.read_vector(c, value),
             _ => unimplemented!(),
         }
     }
@@ -236,6 +258,18 @@ impl FieldJsonAstDecoder {
         Ok(())
     }
 
+    fn read_large_int<T: LargeInt>(&self, column: &mut Vec<T>, value: &Value) -> Result<()> {
+        column.push(read_large_int_from_json(value).map_err(ErrorCode::BadBytes)?);
+        Ok(())
+    }
+
+    fn read_enum(&self, column: &mut EnumColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => column.push_value(s),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
     fn read_string(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
         match value {
             Value::String(s) => {
@@ -369,6 +403,50 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let interval = string_to_interval(v).map_err(ErrorCode::BadBytes)?;
+                column.push(interval);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Interval value")),
+        }
+    }
+
+    fn read_time(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let time = string_to_time(v).map_err(ErrorCode::BadBytes)?;
+                column.push(time);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Time value")),
+        }
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<timestamp_tz>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let ts = string_to_timestamp_tz(v, self.timezone).map_err(ErrorCode::BadBytes)?;
+                column.push(ts);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect TimestampTz value")),
+        }
+    }
+
+    fn read_uuid(&self, column: &mut Vec<u128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let uuid = string_to_uuid(v).map_err(ErrorCode::BadBytes)?;
+                column.push(uuid);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Uuid value")),
+        }
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, value: &Value) -> Result<()> {
         match value {
             Value::Array(vals) => {
@@ -382,6 +460,30 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_vector(&self, column: &mut VectorColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::Array(vals) => {
+                if vals.len() != column.dimension {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Vector dimension mismatch, expect {}, but got {}",
+                        column.dimension,
+                        vals.len()
+                    )));
+                }
+                let values = vals
+                    .iter()
+                    .map(|val| match val.as_f64() {
+                        Some(v) => Ok(F32::from(v as f32)),
+                        None => Err(ErrorCode::BadBytes("Incorrect Vector value")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                column.push(&values);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be array")),
+        }
+    }
+
     fn read_map(&self, column: &mut ArrayColumnBuilder<AnyType>, value: &Value) -> Result<()> {
         const KEY: usize = 0;
         const VALUE: usize = 1;
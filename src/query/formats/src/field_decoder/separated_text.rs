@@ -20,6 +20,7 @@ use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::serialize::read_decimal_with_size;
+use databend_common_expression::serialize::read_large_int;
 use databend_common_expression::serialize::uniform_date;
 use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
@@ -27,12 +28,22 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::large_int::LargeInt;
+use databend_common_expression::types::large_int::LargeIntColumnBuilder;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
 use databend_common_expression::with_decimal_type;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
 use databend_common_io::constants::FALSE_BYTES_LOWER;
@@ -128,6 +139,7 @@ impl SeparatedTextDecoder {
                 c.commit_row();
                 Ok(())
             }
+            ColumnBuilder::Enum(c) => c.push_value(std::str::from_utf8(data)?),
             ColumnBuilder::Boolean(c) => self.read_bool(c, data),
             ColumnBuilder::Nullable(c) => self.read_nullable(c, data),
             ColumnBuilder::Number(c) => with_number_mapped_type!(|NUM_TYPE| match c {
@@ -142,6 +154,9 @@ impl SeparatedTextDecoder {
             ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
                 DecimalColumnBuilder::DECIMAL_TYPE(c, size) => self.read_decimal(c, *size, data),
             }),
+            ColumnBuilder::LargeInt(c) => with_large_int_mapped_type!(|LARGE_INT| match c {
+                LargeIntColumnBuilder::LARGE_INT(c) => self.read_large_int(c, data),
+            }),
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
@@ -151,6 +166,11 @@ impl SeparatedTextDecoder {
             ColumnBuilder::Variant(c) => self.read_variant(c, data),
             ColumnBuilder::Geometry(c) => self.read_geometry(c, data),
             ColumnBuilder::Geography(c) => self.read_geography(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
+            ColumnBuilder::Time(c) => self.read_time(c, data),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, data),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, data),
+            ColumnBuilder::Vector(c) => self.read_vector(c, data),
             ColumnBuilder::EmptyArray { .. } => {
                 unreachable!("EmptyArray")
             }
@@ -250,6 +270,17 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_large_int<T: LargeInt>(&self, column: &mut Vec<T>, data: &[u8]) -> Result<()> {
+        let (n, n_read) = read_large_int(data).map_err(ErrorCode::BadBytes)?;
+        if n_read != data.len() {
+            return Err(ErrorCode::BadBytes(
+                "unexpected remaining bytes".to_string(),
+            ));
+        }
+        column.push(n);
+        Ok(())
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, data: &[u8]) -> Result<()> {
         let mut buffer_readr = Cursor::new(&data);
         let date = buffer_readr.read_date_text(
@@ -331,11 +362,47 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, data: &[u8]) -> Result<()> {
+        let interval =
+            string_to_interval(data.to_str().unwrap_or_default()).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_time(&self, column: &mut Vec<i64>, data: &[u8]) -> Result<()> {
+        let time =
+            string_to_time(data.to_str().unwrap_or_default()).map_err(ErrorCode::BadBytes)?;
+        column.push(time);
+        Ok(())
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<timestamp_tz>, data: &[u8]) -> Result<()> {
+        let ts = string_to_timestamp_tz(
+            data.to_str().unwrap_or_default(),
+            self.common_settings().timezone,
+        )
+        .map_err(ErrorCode::BadBytes)?;
+        column.push(ts);
+        Ok(())
+    }
+
+    fn read_uuid(&self, column: &mut Vec<u128>, data: &[u8]) -> Result<()> {
+        let uuid =
+            string_to_uuid(data.to_str().unwrap_or_default()).map_err(ErrorCode::BadBytes)?;
+        column.push(uuid);
+        Ok(())
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, data: &[u8]) -> Result<()> {
         let mut cursor = Cursor::new(data);
         self.nested_decoder.read_array(column, &mut cursor)
     }
 
+    fn read_vector(&self, column: &mut VectorColumnBuilder, data: &[u8]) -> Result<()> {
+        let mut cursor = Cursor::new(data);
+        self.nested_decoder.read_vector(column, &mut cursor)
+    }
+
     fn read_map(&self, column: &mut ArrayColumnBuilder<AnyType>, data: &[u8]) -> Result<()> {
         let mut cursor = Cursor::new(data);
         self.nested_decoder.read_map(column, &mut cursor)
@@ -134,8 +134,20 @@ impl FieldEncoderCSV {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.string_formatter.write_string(buf.as_bytes(), out_buf);
             }
+            Column::Enum(c) => {
+                let value = unsafe { c.index_unchecked(row_index) };
+                self.string_formatter
+                    .write_string(value.as_str().as_bytes(), out_buf);
+            }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) | Column::Variant(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Time(..)
+            | Column::TimestampTz(..)
+            | Column::Uuid(..)
+            | Column::Bitmap(..)
+            | Column::Variant(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
@@ -153,7 +165,7 @@ impl FieldEncoderCSV {
                 self.string_formatter.write_string(wkt.as_bytes(), out_buf);
             }
 
-            Column::Array(..) | Column::Map(..) | Column::Tuple(..) => {
+            Column::Array(..) | Column::Map(..) | Column::Tuple(..) | Column::Vector(..) => {
                 let mut buf = Vec::new();
                 self.nested.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
@@ -164,6 +176,7 @@ impl FieldEncoderCSV {
             | Column::EmptyMap { .. }
             | Column::Number(_)
             | Column::Decimal(_)
+            | Column::LargeInt(_)
             | Column::Boolean(_) => self.simple.write_field(column, row_index, out_buf, false),
         }
     }
@@ -68,8 +68,18 @@ impl FieldEncoderJSON {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.write_string(buf.as_bytes(), out_buf);
             }
+            Column::Enum(c) => {
+                let value = unsafe { c.index_unchecked(row_index) };
+                self.write_string(value.as_str().as_bytes(), out_buf);
+            }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Time(..)
+            | Column::TimestampTz(..)
+            | Column::Uuid(..)
+            | Column::Bitmap(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.write_string(&buf, out_buf);
@@ -97,6 +107,8 @@ impl FieldEncoderJSON {
             | Column::EmptyMap { .. }
             | Column::Number(_)
             | Column::Decimal(_)
+            | Column::LargeInt(_)
+            | Column::Vector(_)
             | Column::Boolean(_) => self.simple.write_field(column, row_index, out_buf, false),
         }
     }
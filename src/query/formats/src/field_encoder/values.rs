@@ -22,11 +22,18 @@ use databend_common_expression::types::binary::BinaryColumn;
 use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::decimal::DecimalColumn;
 use databend_common_expression::types::geography::GeographyColumn;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::large_int::LargeIntColumn;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::string::StringColumn;
+use databend_common_expression::types::time::time_to_string;
 use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
+use databend_common_expression::types::uuid::uuid_to_string;
+use databend_common_expression::types::EnumColumn;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VectorColumn;
 use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_NUM;
 use databend_common_io::constants::INF_BYTES_LONG;
@@ -135,21 +142,28 @@ impl FieldEncoderValues {
                 NumberColumn::Float64(c) => self.write_float(c, row_index, out_buf),
             },
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
+            Column::LargeInt(c) => self.write_large_int(c, row_index, out_buf),
 
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, in_nested),
 
             Column::Binary(c) => self.write_binary(c, row_index, out_buf),
             Column::String(c) => self.write_string(c, row_index, out_buf, in_nested),
+            Column::Enum(c) => self.write_enum(c, row_index, out_buf, in_nested),
             Column::Date(c) => self.write_date(c, row_index, out_buf, in_nested),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
             Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
             Column::Geography(c) => self.write_geography(c, row_index, out_buf, in_nested),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, in_nested),
+            Column::Time(c) => self.write_time(c, row_index, out_buf, in_nested),
+            Column::TimestampTz(c) => self.write_timestamp_tz(c, row_index, out_buf, in_nested),
+            Column::Uuid(c) => self.write_uuid(c, row_index, out_buf, in_nested),
 
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
             Column::Map(box c) => self.write_map(c, row_index, out_buf),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf),
+            Column::Vector(c) => self.write_vector(c, row_index, out_buf),
         }
     }
     fn common_settings(&self) -> &OutputCommonSettings {
@@ -234,6 +248,11 @@ impl FieldEncoderValues {
         out_buf.extend_from_slice(data.as_bytes());
     }
 
+    fn write_large_int(&self, column: &LargeIntColumn, row_index: usize, out_buf: &mut Vec<u8>) {
+        let data = column.index(row_index).unwrap().to_string();
+        out_buf.extend_from_slice(data.as_bytes());
+    }
+
     fn write_binary(&self, column: &BinaryColumn, row_index: usize, out_buf: &mut Vec<u8>) {
         let v = unsafe { column.index_unchecked(row_index) };
         out_buf.extend_from_slice(hex::encode_upper(v).as_bytes());
@@ -253,6 +272,17 @@ impl FieldEncoderValues {
         );
     }
 
+    fn write_enum(
+        &self,
+        column: &EnumColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let value = unsafe { column.index_unchecked(row_index) };
+        self.write_string_inner(value.as_str().as_bytes(), out_buf, in_nested);
+    }
+
     fn write_date(
         &self,
         column: &Buffer<i32>,
@@ -277,6 +307,58 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<months_days_micros>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        self.write_string_inner(v.to_string().as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_time(
+        &self,
+        column: &Buffer<i64>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        self.write_string_inner(
+            time_to_string(*v).to_string().as_bytes(),
+            out_buf,
+            in_nested,
+        );
+    }
+
+    fn write_timestamp_tz(
+        &self,
+        column: &Buffer<timestamp_tz>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        self.write_string_inner(v.to_string().as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_uuid(
+        &self,
+        column: &Buffer<u128>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        self.write_string_inner(
+            uuid_to_string(*v).to_string().as_bytes(),
+            out_buf,
+            in_nested,
+        );
+    }
+
     fn write_bitmap(
         &self,
         _column: &BinaryColumn,
@@ -376,6 +458,18 @@ impl FieldEncoderValues {
         out_buf.push(b']');
     }
 
+    fn write_vector(&self, column: &VectorColumn, row_index: usize, out_buf: &mut Vec<u8>) {
+        let values = unsafe { column.index_unchecked(row_index) };
+        out_buf.push(b'[');
+        for (i, v) in values.iter().enumerate() {
+            if i != 0 {
+                out_buf.extend_from_slice(b",");
+            }
+            v.0.write_field(out_buf, self.common_settings());
+        }
+        out_buf.push(b']');
+    }
+
     fn write_map<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
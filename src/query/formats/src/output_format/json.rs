@@ -14,6 +14,8 @@
 
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::time::time_to_string;
+use databend_common_expression::types::uuid::uuid_to_string;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
@@ -93,6 +95,7 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             }
         },
         ScalarRef::Decimal(x) => serde_json::to_value(x.to_string()).unwrap(),
+        ScalarRef::LargeInt(x) => serde_json::to_value(x.to_string()).unwrap(),
         ScalarRef::Date(v) => {
             let dt = DateConverter::to_date(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d").to_string()).unwrap()
@@ -101,10 +104,15 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => serde_json::to_value(v.to_string()).unwrap(),
+        ScalarRef::Time(v) => serde_json::to_value(time_to_string(v).to_string()).unwrap(),
+        ScalarRef::TimestampTz(v) => serde_json::to_value(v.to_string()).unwrap(),
+        ScalarRef::Uuid(v) => serde_json::to_value(uuid_to_string(v).to_string()).unwrap(),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
         ScalarRef::String(x) => JsonValue::String(x.to_string()),
+        ScalarRef::Enum(x) => JsonValue::String(x.as_str().to_string()),
         ScalarRef::Array(x) => {
             let vals = x
                 .iter()
@@ -112,6 +120,13 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
                 .collect();
             JsonValue::Array(vals)
         }
+        ScalarRef::Vector(x) => {
+            let vals = x
+                .iter()
+                .map(|v| JsonValue::Number(serde_json::Number::from_f64(v.0 as f64).unwrap()))
+                .collect();
+            JsonValue::Array(vals)
+        }
         ScalarRef::Map(x) => {
             let vals = x
                 .iter()
@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
 use databend_common_expression::types::number::*;
 use databend_common_expression::types::*;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::Scalar;
 use ethnum::i256;
@@ -185,6 +186,20 @@ pub fn try_create_aggregate_min_max_any_function<const CMP_TYPE: u8>(
                         display_name, return_type, params, data_type
                     )
                 }
+                DataType::LargeInt(ty) => {
+                    with_large_int_mapped_type!(|LARGE_INT| match ty {
+                        LargeIntDataType::LARGE_INT => {
+                            let return_type = data_type.clone();
+                            AggregateUnaryFunction::<
+                                MinMaxAnyState<LargeIntType<LARGE_INT>, CMP>,
+                                LargeIntType<LARGE_INT>,
+                                LargeIntType<LARGE_INT>,
+                            >::try_create_unary(
+                                display_name, return_type, params, data_type
+                            )
+                        }
+                    })
+                }
                 _ => {
                     let return_type = data_type.clone();
                     let func = AggregateUnaryFunction::<
@@ -0,0 +1,398 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_number;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use num_traits::AsPrimitive;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_sketch_state::*;
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregator_common::assert_params;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::aggregator_common::assert_variadic_params;
+use crate::BUILTIN_FUNCTIONS;
+
+/// The `lg_k` of Theta and HLL sketches, or the `k` of KLL sketches.
+struct SketchData {
+    param: u16,
+}
+
+impl FunctionData for SketchData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn sketch_param(function_data: Option<&dyn FunctionData>, default: u16) -> u16 {
+    function_data
+        .and_then(|data| data.as_any().downcast_ref::<SketchData>())
+        .map_or(default, |data| data.param)
+}
+
+impl UnaryState<AnyType, BinaryType> for ThetaSketch {
+    fn add(
+        &mut self,
+        other: ScalarRef<'_>,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if !self.is_initialized() {
+            let lg_k = sketch_param(function_data, SKETCH_DEFAULT_LG_K as u16);
+            *self = ThetaSketch::new(lg_k as u8);
+        }
+        self.update(sketch_hash(&other));
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        ThetaSketch::merge(self, rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if !self.is_initialized() {
+            let lg_k = sketch_param(function_data, SKETCH_DEFAULT_LG_K as u16);
+            *self = ThetaSketch::new(lg_k as u8);
+        }
+        self.encode(&mut builder.data);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+impl UnaryState<BinaryType, BinaryType> for ThetaSketch {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        ThetaSketch::merge(self, &ThetaSketch::decode(other)?);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        ThetaSketch::merge(self, rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        <Self as UnaryState<AnyType, BinaryType>>::merge_result(self, builder, function_data)
+    }
+}
+
+impl UnaryState<AnyType, BinaryType> for HllSketch {
+    fn add(
+        &mut self,
+        other: ScalarRef<'_>,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if !self.is_initialized() {
+            let lg_k = sketch_param(function_data, SKETCH_DEFAULT_LG_K as u16);
+            *self = HllSketch::new(lg_k as u8);
+        }
+        self.update(sketch_hash(&other));
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        HllSketch::merge(self, rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if !self.is_initialized() {
+            let lg_k = sketch_param(function_data, SKETCH_DEFAULT_LG_K as u16);
+            *self = HllSketch::new(lg_k as u8);
+        }
+        self.encode(&mut builder.data);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+impl UnaryState<BinaryType, BinaryType> for HllSketch {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        HllSketch::merge(self, &HllSketch::decode(other)?);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        HllSketch::merge(self, rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        <Self as UnaryState<AnyType, BinaryType>>::merge_result(self, builder, function_data)
+    }
+}
+
+impl<N> UnaryState<NumberType<N>, BinaryType> for KllSketch
+where N: Number + AsPrimitive<f64>
+{
+    fn add(&mut self, other: N, function_data: Option<&dyn FunctionData>) -> Result<()> {
+        if !self.is_initialized() {
+            *self = KllSketch::new(sketch_param(function_data, KLL_DEFAULT_K));
+        }
+        self.update(other.as_());
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        KllSketch::merge(self, rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        kll_merge_result(self, builder, function_data);
+        Ok(())
+    }
+}
+
+impl UnaryState<BinaryType, BinaryType> for KllSketch {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        KllSketch::merge(self, &KllSketch::decode(other)?);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        KllSketch::merge(self, rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        kll_merge_result(self, builder, function_data);
+        Ok(())
+    }
+}
+
+fn kll_merge_result(
+    state: &mut KllSketch,
+    builder: &mut BinaryColumnBuilder,
+    function_data: Option<&dyn FunctionData>,
+) {
+    if !state.is_initialized() {
+        *state = KllSketch::new(sketch_param(function_data, KLL_DEFAULT_K));
+    }
+    state.encode(&mut builder.data);
+    builder.commit_row();
+}
+
+/// Read the optional sketch parameter, which must be an integer in the given range.
+fn get_sketch_param(
+    display_name: &str,
+    name: &str,
+    params: &[Scalar],
+    range: (u64, u64),
+    default: u16,
+) -> Result<u16> {
+    assert_variadic_params(display_name, params.len(), (0, 1))?;
+    if params.is_empty() {
+        return Ok(default);
+    }
+    let param = check_number::<_, u64>(
+        None,
+        &FunctionContext::default(),
+        &Expr::<usize>::Constant {
+            span: None,
+            scalar: params[0].clone(),
+            data_type: params[0].as_ref().infer_data_type(),
+        },
+        &BUILTIN_FUNCTIONS,
+    )?;
+    if param < range.0 || param > range.1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "{display_name} expect {name} to be between {} and {}, but got {param}",
+            range.0, range.1
+        )));
+    }
+    Ok(param as u16)
+}
+
+fn create_sketch_function<S, T>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    argument: DataType,
+    param: u16,
+) -> Result<Arc<dyn AggregateFunction>>
+where
+    S: UnaryState<T, BinaryType> + 'static,
+    T: ValueType + Send + Sync,
+{
+    let func = AggregateUnaryFunction::<S, T, BinaryType>::try_create(
+        display_name,
+        DataType::Binary,
+        params,
+        argument,
+    )
+    .with_function_data(Box::new(SketchData { param }))
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn try_create_aggregate_theta_sketch_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    let lg_k = get_sketch_param(
+        display_name,
+        "lg_k",
+        &params,
+        (SKETCH_MIN_LG_K, SKETCH_MAX_LG_K),
+        SKETCH_DEFAULT_LG_K as u16,
+    )?;
+    create_sketch_function::<ThetaSketch, AnyType>(display_name, params, arguments[0].clone(), lg_k)
+}
+
+pub fn try_create_aggregate_hll_sketch_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    let lg_k = get_sketch_param(
+        display_name,
+        "lg_k",
+        &params,
+        (SKETCH_MIN_LG_K, SKETCH_MAX_LG_K),
+        SKETCH_DEFAULT_LG_K as u16,
+    )?;
+    create_sketch_function::<HllSketch, AnyType>(display_name, params, arguments[0].clone(), lg_k)
+}
+
+pub fn try_create_aggregate_kll_sketch_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    let k = get_sketch_param(
+        display_name,
+        "k",
+        &params,
+        (KLL_MIN_K, KLL_MAX_K),
+        KLL_DEFAULT_K,
+    )?;
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            create_sketch_function::<KllSketch, NumberType<NUM_TYPE>>(
+                display_name,
+                params,
+                arguments[0].clone(),
+                k,
+            )
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} just support numeric type, but got '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+fn try_create_aggregate_sketch_merge_function<S>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>>
+where
+    S: UnaryState<BinaryType, BinaryType> + 'static,
+{
+    assert_params(display_name, params.len(), 0)?;
+    assert_unary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::Binary {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} just support binary type, but got '{:?}'",
+            display_name, arguments[0]
+        )));
+    }
+    let func = AggregateUnaryFunction::<S, BinaryType, BinaryType>::try_create(
+        display_name,
+        DataType::Binary,
+        params,
+        arguments[0].clone(),
+    )
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn aggregate_theta_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_theta_sketch_function))
+}
+
+pub fn aggregate_hll_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_hll_sketch_function))
+}
+
+pub fn aggregate_kll_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_kll_sketch_function))
+}
+
+pub fn aggregate_theta_sketch_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_sketch_merge_function::<ThetaSketch>,
+    ))
+}
+
+pub fn aggregate_hll_sketch_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_sketch_merge_function::<HllSketch>,
+    ))
+}
+
+pub fn aggregate_kll_sketch_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_sketch_merge_function::<KllSketch>,
+    ))
+}
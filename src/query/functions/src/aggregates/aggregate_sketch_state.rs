@@ -0,0 +1,736 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sketches whose states can be stored in tables as `Binary` values.
+//!
+//! The binary format is independent of the in-memory representation and must
+//! stay readable by future versions, every sketch starts with a header:
+//!
+//! ```text
+//! | family: u8 | version: u8 | lg_k / flags: u8 | reserved: u8 | payload ... |
+//! ```
+//!
+//! All multi-byte values are encoded in little endian.
+
+use std::hash::Hasher;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::geography::GeographyRef;
+use databend_common_expression::types::i256;
+use databend_common_expression::types::LargeIntScalar;
+use databend_common_expression::ScalarRef;
+use twox_hash::XxHash64;
+
+const THETA_FAMILY: u8 = 1;
+const HLL_FAMILY: u8 = 2;
+const KLL_FAMILY: u8 = 3;
+
+const SKETCH_VERSION: u8 = 1;
+const SKETCH_HEADER_SIZE: usize = 4;
+
+/// The seed of the hash function, changing it breaks all stored sketches.
+const SKETCH_HASH_SEED: u64 = 9001;
+
+pub const SKETCH_MIN_LG_K: u64 = 4;
+pub const SKETCH_MAX_LG_K: u64 = 16;
+pub const SKETCH_DEFAULT_LG_K: u8 = 12;
+
+pub const KLL_MIN_K: u64 = 8;
+pub const KLL_MAX_K: u64 = u16::MAX as u64;
+pub const KLL_DEFAULT_K: u16 = 200;
+
+/// Hash a value into 64 bits, the result only depends on the value itself,
+/// so that sketches built from different integer types can be merged.
+///
+/// Every type is hashed from a canonical binary encoding, which must never change,
+/// since the hashes are stored in the sketches. Numbers equal to an integer are
+/// hashed as the integer whatever their type is, the scale of decimals is normalized,
+/// so `2`, `2.0` and `2.00` have the same hash, and so do `1.5` and `1.50`.
+pub fn sketch_hash(value: &ScalarRef) -> u64 {
+    let mut hasher = XxHash64::with_seed(SKETCH_HASH_SEED);
+    match value {
+        ScalarRef::Number(n) => match n.integer_to_i128() {
+            Some(v) => hash_integer(&mut hasher, v),
+            None => {
+                let v = n.float_to_f64().unwrap();
+                if v.is_finite() && v.fract() == 0.0 && v.abs() < 1e38 {
+                    hash_integer(&mut hasher, v as i128);
+                } else {
+                    let v = if v.is_nan() { f64::NAN } else { v };
+                    hasher.write(&[1]);
+                    hasher.write(&v.to_bits().to_le_bytes());
+                }
+            }
+        },
+        ScalarRef::Decimal(DecimalScalar::Decimal128(v, size)) => {
+            hash_decimal(&mut hasher, i256::from(*v), size.scale)
+        }
+        ScalarRef::Decimal(DecimalScalar::Decimal256(v, size)) => {
+            hash_decimal(&mut hasher, *v, size.scale)
+        }
+        ScalarRef::LargeInt(LargeIntScalar::Int128(v)) => hash_integer(&mut hasher, *v),
+        ScalarRef::LargeInt(LargeIntScalar::UInt128(v)) => {
+            hash_large_integer(&mut hasher, i256::from(*v))
+        }
+        ScalarRef::LargeInt(LargeIntScalar::Int256(v)) => hash_large_integer(&mut hasher, *v),
+        ScalarRef::LargeInt(LargeIntScalar::UInt256(v)) => {
+            if *v <= i256::MAX.as_u256() {
+                hash_large_integer(&mut hasher, v.as_i256());
+            } else {
+                hasher.write(&[9]);
+                hasher.write(&v.to_le_bytes());
+            }
+        }
+        ScalarRef::Boolean(v) => hasher.write(&[2, *v as u8]),
+        ScalarRef::String(v) => {
+            hasher.write(&[3]);
+            hasher.write(v.as_bytes());
+        }
+        ScalarRef::Enum(v) => {
+            hasher.write(&[3]);
+            hasher.write(v.as_str().as_bytes());
+        }
+        ScalarRef::Binary(v)
+        | ScalarRef::Variant(v)
+        | ScalarRef::Bitmap(v)
+        | ScalarRef::Geometry(v)
+        | ScalarRef::Geography(GeographyRef(v)) => {
+            hasher.write(&[3]);
+            hasher.write(v);
+        }
+        ScalarRef::Date(v) => {
+            hasher.write(&[4]);
+            hasher.write(&(*v as i64).to_le_bytes());
+        }
+        ScalarRef::Timestamp(v) => {
+            hasher.write(&[5]);
+            hasher.write(&v.to_le_bytes());
+        }
+        ScalarRef::Uuid(v) => {
+            hasher.write(&[6]);
+            hasher.write(&v.to_le_bytes());
+        }
+        ScalarRef::Null => hasher.write(&[7]),
+        ScalarRef::Interval(v) => {
+            hasher.write(&[10]);
+            hasher.write(&v.months().to_le_bytes());
+            hasher.write(&v.days().to_le_bytes());
+            hasher.write(&v.microseconds().to_le_bytes());
+        }
+        ScalarRef::Time(v) => {
+            hasher.write(&[11]);
+            hasher.write(&v.to_le_bytes());
+        }
+        ScalarRef::TimestampTz(v) => {
+            hasher.write(&[12]);
+            hasher.write(&v.timestamp().to_le_bytes());
+            hasher.write(&v.seconds_offset().to_le_bytes());
+        }
+        ScalarRef::Vector(v) => {
+            hasher.write(&[13]);
+            hasher.write(&(v.len() as u64).to_le_bytes());
+            for x in v.iter() {
+                let x = if x.0.is_nan() { f32::NAN } else { x.0 };
+                hasher.write(&x.to_bits().to_le_bytes());
+            }
+        }
+        // Nested values are hashed from the hashes of their items.
+        ScalarRef::EmptyArray => hash_items(&mut hasher, 14, std::iter::empty()),
+        ScalarRef::Array(column) => hash_items(&mut hasher, 14, column.iter()),
+        ScalarRef::EmptyMap => hash_items(&mut hasher, 15, std::iter::empty()),
+        ScalarRef::Map(column) => hash_items(&mut hasher, 15, column.iter()),
+        ScalarRef::Tuple(fields) => hash_items(&mut hasher, 16, fields.iter().cloned()),
+    }
+    hasher.finish()
+}
+
+fn hash_integer(hasher: &mut XxHash64, v: i128) {
+    hasher.write(&[0]);
+    hasher.write(&v.to_le_bytes());
+}
+
+fn hash_large_integer(hasher: &mut XxHash64, v: i256) {
+    if v >= i256::from(i128::MIN) && v <= i256::from(i128::MAX) {
+        hash_integer(hasher, v.as_i128());
+    } else {
+        hasher.write(&[8]);
+        hasher.write(&v.to_le_bytes());
+    }
+}
+
+fn hash_decimal(hasher: &mut XxHash64, mut v: i256, mut scale: u8) {
+    // Remove the trailing zeros of the fraction.
+    let ten = i256::from(10);
+    while scale > 0 && v % ten == i256::ZERO {
+        v /= ten;
+        scale -= 1;
+    }
+    if scale == 0 {
+        hash_large_integer(hasher, v);
+    } else {
+        hasher.write(&[17, scale]);
+        hasher.write(&v.to_le_bytes());
+    }
+}
+
+fn hash_items<'a>(hasher: &mut XxHash64, tag: u8, items: impl Iterator<Item = ScalarRef<'a>>) {
+    hasher.write(&[tag]);
+    let mut len = 0u64;
+    for item in items {
+        hasher.write(&sketch_hash(&item).to_le_bytes());
+        len += 1;
+    }
+    hasher.write(&len.to_le_bytes());
+}
+
+fn write_header(buf: &mut Vec<u8>, family: u8, param: u8, reserved: u8) {
+    buf.extend_from_slice(&[family, SKETCH_VERSION, param, reserved]);
+}
+
+/// A cursor over the encoded sketch, reports truncated or malformed input.
+struct SketchReader<'a> {
+    name: &'static str,
+    data: &'a [u8],
+}
+
+impl<'a> SketchReader<'a> {
+    fn try_create(name: &'static str, family: u8, data: &'a [u8]) -> Result<(Self, u8, u8)> {
+        if data.len() < SKETCH_HEADER_SIZE || data[0] != family {
+            return Err(ErrorCode::BadBytes(format!(
+                "input is not a valid {name} sketch"
+            )));
+        }
+        if data[1] != SKETCH_VERSION {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported {name} sketch version {}",
+                data[1]
+            )));
+        }
+        let reader = SketchReader {
+            name,
+            data: &data[SKETCH_HEADER_SIZE..],
+        };
+        Ok((reader, data[2], data[3]))
+    }
+
+    fn error(&self, msg: &str) -> ErrorCode {
+        ErrorCode::BadBytes(format!("invalid {} sketch: {msg}", self.name))
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(self.error("unexpected end of data"));
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn read_u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        Ok(f64::from_bits(self.read_u64()?))
+    }
+
+    fn finish(&self) -> Result<()> {
+        if !self.data.is_empty() {
+            return Err(self.error("trailing bytes"));
+        }
+        Ok(())
+    }
+}
+
+fn check_lg_k(reader: &SketchReader, lg_k: u8) -> Result<()> {
+    if !(SKETCH_MIN_LG_K..=SKETCH_MAX_LG_K).contains(&(lg_k as u64)) {
+        return Err(reader.error(&format!("lg_k {lg_k} out of range")));
+    }
+    Ok(())
+}
+
+/// Theta sketch, keeps the `k` smallest hash values below `theta`.
+///
+/// Unlike HyperLogLog, it supports intersection and difference in addition to union.
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct ThetaSketch {
+    /// Zero means the sketch is not initialized yet.
+    lg_k: u8,
+    theta: u64,
+    entries: Vec<u64>,
+}
+
+impl ThetaSketch {
+    pub fn new(lg_k: u8) -> Self {
+        ThetaSketch {
+            lg_k,
+            theta: u64::MAX,
+            entries: vec![],
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.lg_k != 0
+    }
+
+    fn k(&self) -> usize {
+        1 << self.lg_k
+    }
+
+    pub fn update(&mut self, hash: u64) {
+        if hash < self.theta {
+            self.entries.push(hash);
+            if self.entries.len() >= 2 * self.k() {
+                self.compact();
+            }
+        }
+    }
+
+    /// Sort and deduplicate the entries, then lower theta to retain at most `k` entries.
+    fn compact(&mut self) {
+        self.entries.sort_unstable();
+        self.entries.dedup();
+        let k = self.k();
+        if self.entries.len() > k {
+            self.theta = self.entries[k];
+            self.entries.truncate(k);
+        }
+    }
+
+    pub fn estimate(&mut self) -> f64 {
+        self.compact();
+        if self.theta == u64::MAX {
+            self.entries.len() as f64
+        } else {
+            self.entries.len() as f64 * (u64::MAX as f64 / self.theta as f64)
+        }
+    }
+
+    pub fn merge(&mut self, rhs: &ThetaSketch) {
+        if !rhs.is_initialized() {
+            return;
+        }
+        if !self.is_initialized() {
+            *self = rhs.clone();
+            return;
+        }
+        self.lg_k = self.lg_k.min(rhs.lg_k);
+        self.theta = self.theta.min(rhs.theta);
+        let theta = self.theta;
+        self.entries.retain(|h| *h < theta);
+        self.entries
+            .extend(rhs.entries.iter().filter(|h| **h < theta));
+        self.compact();
+    }
+
+    pub fn union(&self, rhs: &ThetaSketch) -> ThetaSketch {
+        let mut result = self.clone();
+        result.merge(rhs);
+        result
+    }
+
+    pub fn intersect(&self, rhs: &ThetaSketch) -> ThetaSketch {
+        self.filter_by(rhs, true)
+    }
+
+    pub fn a_not_b(&self, rhs: &ThetaSketch) -> ThetaSketch {
+        self.filter_by(rhs, false)
+    }
+
+    fn filter_by(&self, rhs: &ThetaSketch, contains: bool) -> ThetaSketch {
+        let mut lhs = self.clone();
+        lhs.compact();
+        let mut rhs = rhs.clone();
+        rhs.compact();
+
+        let theta = lhs.theta.min(rhs.theta);
+        let entries = lhs
+            .entries
+            .into_iter()
+            .filter(|h| *h < theta && rhs.entries.binary_search(h).is_ok() == contains)
+            .collect();
+        let mut result = ThetaSketch {
+            lg_k: lhs.lg_k.min(rhs.lg_k),
+            theta,
+            entries,
+        };
+        result.compact();
+        result
+    }
+
+    /// Encoded as header, `theta: u64`, `count: u32` and the sorted entries as `u64`.
+    pub fn encode(&mut self, buf: &mut Vec<u8>) {
+        self.compact();
+        write_header(buf, THETA_FAMILY, self.lg_k, 0);
+        buf.extend_from_slice(&self.theta.to_le_bytes());
+        buf.extend_from_slice(&(self.entries.len() as u32).to_le_bytes());
+        for entry in &self.entries {
+            buf.extend_from_slice(&entry.to_le_bytes());
+        }
+    }
+
+    pub fn decode(data: &[u8]) -> Result<ThetaSketch> {
+        let (mut reader, lg_k, _) = SketchReader::try_create("theta", THETA_FAMILY, data)?;
+        check_lg_k(&reader, lg_k)?;
+        let theta = reader.read_u64()?;
+        let count = reader.read_u32()? as usize;
+        if count > 1 << lg_k {
+            return Err(reader.error("too many entries"));
+        }
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let entry = reader.read_u64()?;
+            if entry >= theta || entries.last().is_some_and(|last| *last >= entry) {
+                return Err(reader.error("entries are not sorted"));
+            }
+            entries.push(entry);
+        }
+        reader.finish()?;
+        Ok(ThetaSketch {
+            lg_k,
+            theta,
+            entries,
+        })
+    }
+}
+
+/// HyperLogLog sketch with `2^lg_k` registers.
+///
+/// Sketches with different `lg_k` can be merged, the result has the lower precision.
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct HllSketch {
+    /// Zero means the sketch is not initialized yet.
+    lg_k: u8,
+    registers: Vec<u8>,
+}
+
+impl HllSketch {
+    pub fn new(lg_k: u8) -> Self {
+        HllSketch {
+            lg_k,
+            registers: vec![0; 1 << lg_k],
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.lg_k != 0
+    }
+
+    pub fn update(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.lg_k)) as usize;
+        let rank = (hash << self.lg_k)
+            .leading_zeros()
+            .min(64 - self.lg_k as u32) as u8
+            + 1;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn estimate(&self) -> f64 {
+        if !self.is_initialized() {
+            return 0.0;
+        }
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for register in &self.registers {
+            sum += 1.0 / (1u64 << *register) as f64;
+            if *register == 0 {
+                zeros += 1;
+            }
+        }
+        let estimate = alpha * m * m / sum;
+        if estimate <= 2.5 * m && zeros > 0 {
+            // Linear counting gives better results for small cardinalities.
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+
+    /// Fold the registers into a sketch with lower precision.
+    fn downsample(&self, lg_k: u8) -> HllSketch {
+        let shift = (self.lg_k - lg_k) as u32;
+        let mut result = HllSketch::new(lg_k);
+        for (index, register) in self.registers.iter().enumerate() {
+            if *register == 0 {
+                continue;
+            }
+            // The low bits of the index become the leading bits of the remaining hash.
+            let low = (index as u64) & ((1 << shift) - 1);
+            let rank = if low == 0 {
+                shift as u8 + *register
+            } else {
+                (shift - (64 - low.leading_zeros())) as u8 + 1
+            };
+            let register = &mut result.registers[index >> shift];
+            *register = (*register).max(rank);
+        }
+        result
+    }
+
+    pub fn merge(&mut self, rhs: &HllSketch) {
+        if !rhs.is_initialized() {
+            return;
+        }
+        if !self.is_initialized() {
+            *self = rhs.clone();
+            return;
+        }
+        if self.lg_k > rhs.lg_k {
+            *self = self.downsample(rhs.lg_k);
+        }
+        let rhs = if rhs.lg_k > self.lg_k {
+            rhs.downsample(self.lg_k)
+        } else {
+            rhs.clone()
+        };
+        for (l, r) in self.registers.iter_mut().zip(rhs.registers.iter()) {
+            *l = (*l).max(*r);
+        }
+    }
+
+    pub fn union(&self, rhs: &HllSketch) -> HllSketch {
+        let mut result = self.clone();
+        result.merge(rhs);
+        result
+    }
+
+    /// Encoded as header and `2^lg_k` one-byte registers.
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        write_header(buf, HLL_FAMILY, self.lg_k, 0);
+        buf.extend_from_slice(&self.registers);
+    }
+
+    pub fn decode(data: &[u8]) -> Result<HllSketch> {
+        let (mut reader, lg_k, _) = SketchReader::try_create("hll", HLL_FAMILY, data)?;
+        check_lg_k(&reader, lg_k)?;
+        let registers = reader.read_bytes(1 << lg_k)?.to_vec();
+        if registers.iter().any(|r| *r as u32 > 65 - lg_k as u32) {
+            return Err(reader.error("register out of range"));
+        }
+        reader.finish()?;
+        Ok(HllSketch { lg_k, registers })
+    }
+}
+
+/// KLL quantile sketch, a hierarchy of compactors where an item at level `h`
+/// has weight `2^h`.
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct KllSketch {
+    /// Zero means the sketch is not initialized yet.
+    k: u16,
+    n: u64,
+    min: f64,
+    max: f64,
+    /// Alternates the items that are promoted while compacting,
+    /// keeps the result deterministic.
+    coin: bool,
+    levels: Vec<Vec<f64>>,
+}
+
+impl KllSketch {
+    pub fn new(k: u16) -> Self {
+        KllSketch {
+            k,
+            n: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            coin: false,
+            levels: vec![vec![]],
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.k != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn level_capacity(&self, level: usize) -> usize {
+        let depth = (self.levels.len() - level - 1) as i32;
+        ((self.k as f64 * (2.0f64 / 3.0).powi(depth)).ceil() as usize).max(8)
+    }
+
+    fn num_retained(&self) -> usize {
+        self.levels.iter().map(|level| level.len()).sum()
+    }
+
+    fn total_capacity(&self) -> usize {
+        (0..self.levels.len())
+            .map(|level| self.level_capacity(level))
+            .sum()
+    }
+
+    pub fn update(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.n += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.levels[0].push(value);
+        self.compress();
+    }
+
+    fn compress(&mut self) {
+        while self.num_retained() > self.total_capacity() {
+            let level = (0..self.levels.len())
+                .find(|level| self.levels[*level].len() >= self.level_capacity(*level))
+                .unwrap();
+            if level + 1 == self.levels.len() {
+                self.levels.push(vec![]);
+            }
+
+            let mut items = std::mem::take(&mut self.levels[level]);
+            items.sort_unstable_by(|a, b| a.total_cmp(b));
+            if items.len() % 2 == 1 {
+                self.levels[level].push(items.pop().unwrap());
+            }
+            let offset = self.coin as usize;
+            self.coin = !self.coin;
+            let promoted = items.into_iter().skip(offset).step_by(2);
+            self.levels[level + 1].extend(promoted);
+        }
+    }
+
+    pub fn merge(&mut self, rhs: &KllSketch) {
+        if !rhs.is_initialized() {
+            return;
+        }
+        if !self.is_initialized() {
+            *self = rhs.clone();
+            return;
+        }
+        self.k = self.k.min(rhs.k);
+        self.n += rhs.n;
+        self.min = self.min.min(rhs.min);
+        self.max = self.max.max(rhs.max);
+        if self.levels.len() < rhs.levels.len() {
+            self.levels.resize(rhs.levels.len(), vec![]);
+        }
+        for (l, r) in self.levels.iter_mut().zip(rhs.levels.iter()) {
+            l.extend_from_slice(r);
+        }
+        self.compress();
+    }
+
+    /// Estimate the value at the given normalized rank in `[0, 1]`.
+    pub fn quantile(&self, rank: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        if rank <= 0.0 {
+            return Some(self.min);
+        }
+        if rank >= 1.0 {
+            return Some(self.max);
+        }
+
+        let mut items = self
+            .levels
+            .iter()
+            .enumerate()
+            .flat_map(|(level, items)| items.iter().map(move |v| (*v, 1u64 << level)))
+            .collect::<Vec<_>>();
+        items.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        let total = items.iter().map(|(_, weight)| weight).sum::<u64>() as f64;
+        let target = rank * total;
+        let mut cumulative = 0.0;
+        for (value, weight) in items.iter() {
+            cumulative += *weight as f64;
+            if cumulative >= target {
+                return Some(*value);
+            }
+        }
+        Some(self.max)
+    }
+
+    /// Encoded as header, `k: u16`, `n: u64`, `min: f64`, `max: f64`, `levels: u8`,
+    /// then every level as `count: u32` followed by its items as `f64`.
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        write_header(buf, KLL_FAMILY, self.coin as u8, 0);
+        buf.extend_from_slice(&self.k.to_le_bytes());
+        buf.extend_from_slice(&self.n.to_le_bytes());
+        buf.extend_from_slice(&self.min.to_bits().to_le_bytes());
+        buf.extend_from_slice(&self.max.to_bits().to_le_bytes());
+        buf.push(self.levels.len() as u8);
+        for level in &self.levels {
+            buf.extend_from_slice(&(level.len() as u32).to_le_bytes());
+            for item in level {
+                buf.extend_from_slice(&item.to_bits().to_le_bytes());
+            }
+        }
+    }
+
+    pub fn decode(data: &[u8]) -> Result<KllSketch> {
+        let (mut reader, flags, _) = SketchReader::try_create("kll", KLL_FAMILY, data)?;
+        let k = reader.read_u16()?;
+        if (k as u64) < KLL_MIN_K {
+            return Err(reader.error(&format!("k {k} out of range")));
+        }
+        let n = reader.read_u64()?;
+        let min = reader.read_f64()?;
+        let max = reader.read_f64()?;
+        let num_levels = reader.read_bytes(1)?[0] as usize;
+        if num_levels == 0 || num_levels > 60 {
+            return Err(reader.error("invalid number of levels"));
+        }
+        let mut levels = Vec::with_capacity(num_levels);
+        for _ in 0..num_levels {
+            let count = reader.read_u32()? as usize;
+            let bytes = reader.read_bytes(count * 8)?;
+            let items = bytes
+                .chunks_exact(8)
+                .map(|b| f64::from_bits(u64::from_le_bytes(b.try_into().unwrap())))
+                .collect::<Vec<_>>();
+            levels.push(items);
+        }
+        reader.finish()?;
+
+        let sketch = KllSketch {
+            k,
+            n,
+            min,
+            max,
+            coin: flags & 1 == 1,
+            levels,
+        };
+        if sketch.num_retained() as u64 > n {
+            return Err(reader.error("too many items"));
+        }
+        Ok(sketch)
+    }
+}
@@ -21,6 +21,7 @@ use databend_common_expression::types::decimal::*;
 use databend_common_expression::types::number::*;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::AggregateFunctionRef;
 use databend_common_expression::Column;
@@ -195,6 +196,50 @@ where
     }
 }
 
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct LargeIntSumState<T>
+where T: LargeInt
+{
+    pub value: T,
+}
+
+impl<T> Default for LargeIntSumState<T>
+where T: LargeInt
+{
+    fn default() -> Self {
+        Self { value: T::zero() }
+    }
+}
+
+impl<T> UnaryState<LargeIntType<T>, LargeIntType<T>> for LargeIntSumState<T>
+where T: LargeInt + BorshSerialize + BorshDeserialize
+{
+    fn add(&mut self, other: T, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.value = self.value.checked_add(other).ok_or_else(|| {
+            ErrorCode::Overflow(format!(
+                "{} overflow: {} + {}",
+                T::data_type(),
+                self.value,
+                other
+            ))
+        })?;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add(rhs.value, None)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<T>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -275,6 +320,18 @@ pub fn try_create_aggregate_sum_function(
                 )
             }
         }
+        DataType::LargeInt(ty) => with_large_int_mapped_type!(|LARGE_INT| match ty {
+            LargeIntDataType::LARGE_INT => AggregateUnaryFunction::<
+                LargeIntSumState<LARGE_INT>,
+                LargeIntType<LARGE_INT>,
+                LargeIntType<LARGE_INT>,
+            >::try_create_unary(
+                display_name,
+                data_type.clone(),
+                params,
+                arguments[0].clone()
+            ),
+        }),
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
@@ -41,8 +41,12 @@ use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
 use crate::aggregates::aggregate_histogram_function_desc;
+use crate::aggregates::aggregate_hll_sketch_function_desc;
+use crate::aggregates::aggregate_hll_sketch_merge_function_desc;
 use crate::aggregates::aggregate_json_array_agg_function_desc;
 use crate::aggregates::aggregate_json_object_agg_function_desc;
+use crate::aggregates::aggregate_kll_sketch_function_desc;
+use crate::aggregates::aggregate_kll_sketch_merge_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
@@ -55,6 +59,8 @@ use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_theta_sketch_function_desc;
+use crate::aggregates::aggregate_theta_sketch_merge_function_desc;
 
 pub struct Aggregators;
 
@@ -100,6 +106,21 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("theta_sketch", aggregate_theta_sketch_function_desc());
+        factory.register("hll_sketch", aggregate_hll_sketch_function_desc());
+        factory.register("kll_sketch", aggregate_kll_sketch_function_desc());
+        factory.register(
+            "theta_sketch_merge",
+            aggregate_theta_sketch_merge_function_desc(),
+        );
+        factory.register(
+            "hll_sketch_merge",
+            aggregate_hll_sketch_merge_function_desc(),
+        );
+        factory.register(
+            "kll_sketch_merge",
+            aggregate_kll_sketch_merge_function_desc(),
+        );
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
@@ -40,6 +40,8 @@ mod aggregate_quantile_tdigest;
 mod aggregate_quantile_tdigest_weighted;
 mod aggregate_retention;
 mod aggregate_scalar_state;
+mod aggregate_sketch;
+mod aggregate_sketch_state;
 mod aggregate_skewness;
 mod aggregate_stddev;
 mod aggregate_string_agg;
@@ -70,6 +72,8 @@ pub use aggregate_quantile_disc::*;
 pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
 pub use aggregate_retention::*;
+pub use aggregate_sketch::*;
+pub use aggregate_sketch_state::*;
 pub use aggregate_skewness::*;
 pub use aggregate_string_agg::*;
 pub use aggregate_sum::*;
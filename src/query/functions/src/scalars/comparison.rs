@@ -19,6 +19,8 @@ use std::sync::Arc;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_expression::generate_like_pattern;
 use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::large_int::LargeIntType;
+use databend_common_expression::types::large_int::ALL_LARGE_INT_TYPES;
 use databend_common_expression::types::string::StringDomain;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::ArgType;
@@ -28,6 +30,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::EmptyArrayType;
 use databend_common_expression::types::GenericType;
+use databend_common_expression::types::LargeIntDataType;
 use databend_common_expression::types::NumberClass;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
@@ -36,6 +39,7 @@ use databend_common_expression::types::ValueType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_NUMBER_CLASSES;
 use databend_common_expression::values::Value;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::Column;
 use databend_common_expression::EvalContext;
@@ -51,14 +55,17 @@ use databend_common_expression::ValueRef;
 use regex::Regex;
 
 use crate::scalars::decimal::register_decimal_compare_op;
+use crate::scalars::enumeration::register_enum_compare_op;
 use crate::scalars::string_multi_args::regexp;
 
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
+    register_enum_compare_op(registry);
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
     register_number_cmp(registry);
+    register_large_int_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
     register_tuple_cmp(registry);
@@ -248,6 +255,16 @@ fn register_number_cmp(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_large_int_cmp(registry: &mut FunctionRegistry) {
+    for ty in ALL_LARGE_INT_TYPES {
+        with_large_int_mapped_type!(|LARGE_INT| match ty {
+            LargeIntDataType::LARGE_INT => {
+                register_simple_domain_type_cmp!(registry, LargeIntType<LARGE_INT>);
+            }
+        });
+    }
+}
+
 fn register_array_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<EmptyArrayType, EmptyArrayType, BooleanType, _, _>(
         "eq",
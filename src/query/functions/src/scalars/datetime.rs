@@ -33,6 +33,7 @@ use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::date::string_to_date;
 use databend_common_expression::types::date::DATE_MAX;
 use databend_common_expression::types::date::DATE_MIN;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::Int64Type;
@@ -42,18 +43,27 @@ use databend_common_expression::types::number::UInt32Type;
 use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::time::string_to_time;
+use databend_common_expression::types::time::time_to_micros;
+use databend_common_expression::types::time::time_to_string;
 use databend_common_expression::types::timestamp::check_timestamp;
 use databend_common_expression::types::timestamp::string_to_timestamp;
 use databend_common_expression::types::timestamp::timestamp_to_string;
 use databend_common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::TimestampTzType;
 use databend_common_expression::types::F64;
 use databend_common_expression::utils::date_helper::*;
 use databend_common_expression::vectorize_1_arg;
@@ -108,6 +118,14 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // cast([string | timestamp] AS time)
+    // to_time([string | timestamp]), to_[hour | minute | second](time)
+    register_time_functions(registry);
+
+    // cast([string | timestamp] AS timestamp with time zone)
+    // to_timestamp_tz([string | timestamp]), to_timestamp(timestamp_tz)
+    register_timestamp_tz_functions(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -1584,23 +1602,10 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
-        |_, lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
-        },
-        |a, b, _| a - b,
+        |_, _, _| FunctionDomain::Full,
+        |a, b, _| months_days_micros::new(0, 0, a - b),
     );
 
     registry.register_passthrough_nullable_2_arg::<DateType, DateType, Float64Type, _, _>(
@@ -1896,6 +1901,244 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
 
 // current we don't consider tz here
 #[inline]
+fn register_time_functions(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_time),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_timestamp_to_time),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimeType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_time_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<TimeType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_time_to_string),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_hour",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / MICROS_IN_A_SEC / 3600) as u8),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_minute",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / MICROS_IN_A_SEC / 60 % 60) as u8),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_second",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / MICROS_IN_A_SEC % 60) as u8),
+    );
+
+    registry.register_2_arg::<TimeType, TimeType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<TimeType, TimeType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<TimeType, TimeType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<TimeType, TimeType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<TimeType, TimeType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<TimeType, TimeType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+
+    fn eval_string_to_time(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<StringType, TimeType>(
+            |val, output, ctx| match string_to_time(val) {
+                Ok(t) => output.push(t),
+                Err(e) => {
+                    ctx.set_error(output.len(), format!("cannot parse to type `TIME`. {e}"));
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+
+    fn eval_timestamp_to_time(
+        val: ValueRef<TimestampType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<TimestampType, TimeType>(|val, output, ctx| {
+            let ts = val.to_timestamp(ctx.func_ctx.tz.tz);
+            output.push(time_to_micros(&ts.time()));
+        })(val, ctx)
+    }
+
+    fn eval_time_to_string(val: ValueRef<TimeType>, ctx: &mut EvalContext) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<TimeType, StringType>(|val, output, _| {
+            write!(output.data, "{}", time_to_string(val)).unwrap();
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_tz_functions(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_timestamp_to_timestamp_tz),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_tz_to_timestamp,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_timestamp_tz_to_timestamp),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_tz_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_timestamp_tz_to_string),
+    );
+
+    // Values are compared by the instant they represent, then by the offset, the same as
+    // they are sorted, hashed and grouped. The same instant in different offsets is not
+    // equal, compare `to_timestamp` of them to compare the instants only.
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `TIMESTAMP WITH TIME ZONE`. {e}"),
+                    );
+                    output.push(timestamp_tz::default());
+                }
+            }
+        })(val, ctx)
+    }
+
+    fn eval_timestamp_to_timestamp_tz(
+        val: ValueRef<TimestampType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<TimestampType, TimestampTzType>(|val, output, ctx| {
+            let ts = val.to_timestamp(ctx.func_ctx.tz.tz);
+            output.push(timestamp_tz::new(val, ts.offset().fix().local_minus_utc()));
+        })(val, ctx)
+    }
+
+    fn eval_timestamp_tz_to_timestamp(
+        val: ValueRef<TimestampTzType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampType> {
+        vectorize_with_builder_1_arg::<TimestampTzType, TimestampType>(|val, output, _| {
+            output.push(val.timestamp());
+        })(val, ctx)
+    }
+
+    fn eval_timestamp_tz_to_string(
+        val: ValueRef<TimestampTzType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
 fn months_between_ts(ts_a: i64, ts_b: i64) -> f64 {
     months_between(
         (ts_a / 86_400_000_000) as i32,
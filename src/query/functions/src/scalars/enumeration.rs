@@ -0,0 +1,336 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::EnumColumnBuilder;
+use databend_common_expression::types::EnumType;
+use databend_common_expression::types::EnumValues;
+use databend_common_expression::Column;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_to_enum(registry);
+    register_enum_to_string(registry);
+}
+
+fn register_to_enum(registry: &mut FunctionRegistry) {
+    let factory = |params: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 1 || params.is_empty() {
+            return None;
+        }
+        let values = params
+            .iter()
+            .map(|param| param.as_string().cloned())
+            .collect::<Option<Vec<_>>>()?;
+        EnumType::check_values(&values).ok()?;
+        let values: EnumValues = values.into();
+
+        let from_type = args_type[0].remove_nullable();
+        if !matches!(from_type, DataType::String | DataType::Enum(_)) {
+            return None;
+        }
+
+        Some(Function {
+            signature: FunctionSignature {
+                name: "to_enum".to_string(),
+                args_type: vec![from_type],
+                return_type: DataType::Enum(values.clone()),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| eval_to_enum(&args[0], ctx, &values)),
+            },
+        })
+    };
+
+    registry.register_function_factory("to_enum", move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory("to_enum", move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+    registry.register_function_factory("try_to_enum", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_enum".to_string();
+        Some(Arc::new(f.error_to_null()))
+    });
+    registry.register_function_factory("try_to_enum", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_enum".to_string();
+        Some(Arc::new(f.error_to_null().passthrough_nullable()))
+    });
+}
+
+fn eval_to_enum(
+    arg: &ValueRef<AnyType>,
+    ctx: &mut EvalContext,
+    values: &EnumValues,
+) -> Value<AnyType> {
+    let index = EnumType::build_index(values);
+    let mut push = |builder: &mut EnumColumnBuilder, value: &str, row: usize| match index.get(value)
+    {
+        Some(code) => builder.push(*code),
+        None => {
+            ctx.set_error(row, format!("Unknown enum value '{value}'"));
+            builder.push_default();
+        }
+    };
+
+    match arg {
+        ValueRef::Scalar(scalar) => {
+            let value = match scalar {
+                ScalarRef::String(s) => *s,
+                ScalarRef::Enum(e) => e.as_str(),
+                _ => unreachable!(),
+            };
+            let mut builder = EnumColumnBuilder::with_capacity(values.clone(), 1);
+            push(&mut builder, value, 0);
+            Value::Scalar(Scalar::Enum(builder.build_scalar()))
+        }
+        ValueRef::Column(Column::String(col)) => {
+            let mut builder = EnumColumnBuilder::with_capacity(values.clone(), col.len());
+            for (row, value) in col.iter().enumerate() {
+                push(&mut builder, value, row);
+            }
+            Value::Column(Column::Enum(builder.build()))
+        }
+        ValueRef::Column(Column::Enum(col)) => {
+            // Only the dictionary differs, so translate each source code once.
+            let mut builder = EnumColumnBuilder::with_capacity(values.clone(), col.len());
+            let mapping = col
+                .values
+                .iter()
+                .map(|value| index.get(value.as_str()).copied())
+                .collect::<Vec<_>>();
+            for (row, code) in col.codes.iter().enumerate() {
+                match mapping[*code as usize] {
+                    Some(code) => builder.push(code),
+                    None => push(&mut builder, &col.values[*code as usize], row),
+                }
+            }
+            Value::Column(Column::Enum(builder.build()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn register_enum_to_string(registry: &mut FunctionRegistry) {
+    let factory = |_: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 1 {
+            return None;
+        }
+        let from_type = args_type[0].remove_nullable();
+        if !from_type.is_enum() {
+            return None;
+        }
+
+        Some(Function {
+            signature: FunctionSignature {
+                name: "to_string".to_string(),
+                args_type: vec![from_type],
+                return_type: DataType::String,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(|args, _| match &args[0] {
+                    ValueRef::Scalar(ScalarRef::Enum(e)) => {
+                        Value::Scalar(Scalar::String(e.as_str().to_string()))
+                    }
+                    ValueRef::Column(Column::Enum(col)) => {
+                        let mut builder = StringColumnBuilder::with_capacity(col.len(), 0);
+                        for value in col.iter() {
+                            builder.put_str(value.as_str());
+                            builder.commit_row();
+                        }
+                        Value::Column(Column::String(builder.build()))
+                    }
+                    _ => unreachable!(),
+                }),
+            },
+        })
+    };
+
+    registry.register_function_factory("to_string", move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory("to_string", move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+    registry.register_function_factory("try_to_string", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_string".to_string();
+        Some(Arc::new(f.error_to_null()))
+    });
+    registry.register_function_factory("try_to_string", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_string".to_string();
+        Some(Arc::new(f.error_to_null().passthrough_nullable()))
+    });
+}
+
+macro_rules! register_enum_compare {
+    ($registry: expr, $name: expr, $op: ident) => {
+        $registry.register_function_factory($name, |_, args_type| {
+            if args_type.len() != 2 {
+                return None;
+            }
+
+            let has_nullable = args_type.iter().any(|x| x.is_nullable_or_null());
+            let args_type: Vec<DataType> = args_type.iter().map(|x| x.remove_nullable()).collect();
+
+            let values = match (&args_type[0], &args_type[1]) {
+                (DataType::Enum(a), DataType::Enum(b)) if a == b => a.clone(),
+                (DataType::Enum(a), DataType::String) | (DataType::String, DataType::Enum(a)) => {
+                    a.clone()
+                }
+                _ => return None,
+            };
+            let has_string = args_type.contains(&DataType::String);
+
+            let function = Function {
+                signature: FunctionSignature {
+                    name: $name.to_string(),
+                    args_type,
+                    return_type: DataType::Boolean,
+                },
+                eval: FunctionEval::Scalar {
+                    calc_domain: Box::new(move |_, _| {
+                        if has_string && !matches!($name, "eq" | "noteq") {
+                            FunctionDomain::MayThrow
+                        } else {
+                            FunctionDomain::Full
+                        }
+                    }),
+                    eval: Box::new(move |args, ctx| {
+                        compare_enum(args, ctx, &values, $name, |ord| ord.$op())
+                    }),
+                },
+            };
+            if has_nullable {
+                Some(Arc::new(function.passthrough_nullable()))
+            } else {
+                Some(Arc::new(function))
+            }
+        });
+    };
+}
+
+/// Registers the comparison of an `ENUM` with an `ENUM` of the same values or with a `STRING`.
+///
+/// Both sides are compared by their codes, so the order follows the declaration order
+/// of the values. A string that is not in the dictionary is never equal to any value,
+/// and ordering against it is an error. Enums with different values fall back to the
+/// string comparison by auto cast.
+pub fn register_enum_compare_op(registry: &mut FunctionRegistry) {
+    register_enum_compare!(registry, "eq", is_eq);
+    register_enum_compare!(registry, "noteq", is_ne);
+    register_enum_compare!(registry, "lt", is_lt);
+    register_enum_compare!(registry, "lte", is_le);
+    register_enum_compare!(registry, "gt", is_gt);
+    register_enum_compare!(registry, "gte", is_ge);
+}
+
+/// The codes of one side of an enum comparison, `None` for a string not in the dictionary.
+enum EnumCodes {
+    Scalar(Option<u16>),
+    Codes(Buffer<u16>),
+    Lookup(Vec<Option<u16>>),
+}
+
+impl EnumCodes {
+    fn new(arg: &ValueRef<AnyType>, values: &EnumValues) -> Self {
+        match arg {
+            ValueRef::Scalar(ScalarRef::Enum(e)) => EnumCodes::Scalar(Some(e.code)),
+            ValueRef::Scalar(ScalarRef::String(s)) => {
+                EnumCodes::Scalar(EnumType::find_code(values, s))
+            }
+            ValueRef::Column(Column::Enum(col)) => EnumCodes::Codes(col.codes.clone()),
+            ValueRef::Column(Column::String(col)) => {
+                let index = EnumType::build_index(values);
+                EnumCodes::Lookup(col.iter().map(|s| index.get(s).copied()).collect())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn get(&self, row: usize) -> Option<u16> {
+        match self {
+            EnumCodes::Scalar(code) => *code,
+            EnumCodes::Codes(codes) => Some(codes[row]),
+            EnumCodes::Lookup(codes) => codes[row],
+        }
+    }
+}
+
+fn compare_enum(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    values: &EnumValues,
+    name: &str,
+    op: impl Fn(Ordering) -> bool,
+) -> Value<AnyType> {
+    let num_rows = ctx.num_rows;
+    let lhs = EnumCodes::new(&args[0], values);
+    let rhs = EnumCodes::new(&args[1], values);
+    let mut cmp = |row: usize| match (lhs.get(row), rhs.get(row)) {
+        (Some(l), Some(r)) => op(l.cmp(&r)),
+        _ => match name {
+            "eq" => false,
+            "noteq" => true,
+            _ => {
+                let value = match (&args[0], &args[1]) {
+                    (ValueRef::Scalar(ScalarRef::String(s)), _)
+                    | (_, ValueRef::Scalar(ScalarRef::String(s))) => s.to_string(),
+                    (ValueRef::Column(Column::String(col)), _)
+                    | (_, ValueRef::Column(Column::String(col))) => {
+                        col.index(row).unwrap_or_default().to_string()
+                    }
+                    _ => unreachable!(),
+                };
+                ctx.set_error(row, format!("Unknown enum value '{value}'"));
+                false
+            }
+        },
+    };
+
+    match (&args[0], &args[1]) {
+        (ValueRef::Scalar(_), ValueRef::Scalar(_)) => Value::Scalar(Scalar::Boolean(cmp(0))),
+        _ => {
+            let mut builder = MutableBitmap::with_capacity(num_rows);
+            for row in 0..num_rows {
+                builder.push(cmp(row));
+            }
+            Value::Column(Column::Boolean(builder.into()))
+        }
+    }
+}
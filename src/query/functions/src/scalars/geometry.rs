@@ -52,6 +52,7 @@ use geohash::encode;
 use geos::geo_types;
 use geos::geo_types::Coord;
 use geos::geo_types::LineString;
+use geos::Geom;
 use geos::Geometry;
 use geozero::geojson::GeoJson;
 use geozero::wkb::Ewkb;
@@ -1573,6 +1574,199 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l_geometry, r_geometry, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match geos_predicate(l_geometry, r_geometry, |l, r| l.intersects(r)) {
+                    Ok(res) => builder.push(res),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_within",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l_geometry, r_geometry, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match geos_predicate(l_geometry, r_geometry, |l, r| l.within(r)) {
+                    Ok(res) => builder.push(res),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType>(
+            |l_geometry, r_geometry, distance, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match geos_predicate(l_geometry, r_geometry, |l, r| {
+                    l.distance(r).map(|d| d <= distance.0)
+                }) {
+                    Ok(res) => builder.push(res),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|geometry, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0_f64));
+                    return;
+                }
+            }
+            match geometries_to_geos(&[geometry]).and_then(|geos| {
+                geos[0]
+                    .area()
+                    .map_err(|e| ErrorCode::GeometryError(e.to_string()).to_string())
+            }) {
+                Ok(area) => builder.push(format!("{:.9}", area).parse().unwrap()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0_f64));
+                }
+            }
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_buffer",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |geometry, distance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+                    // 8 segments per quarter circle, the same as the default of PostGIS.
+                    match geos_unary_op(geometry, |g| g.buffer(distance.0, 8)) {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+        "st_union",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+            |l_geometry, r_geometry, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                match geos_binary_op(l_geometry, r_geometry, |l, r| l.union(r)) {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+        "st_intersection",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+            |l_geometry, r_geometry, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                match geos_binary_op(l_geometry, r_geometry, |l, r| l.intersection(r)) {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_simplify",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |geometry, tolerance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+                    match geos_unary_op(geometry, |g| g.simplify(tolerance.0)) {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_envelope",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(|geometry, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match geos_unary_op(geometry, |g| g.envelope()) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
 }
 
 fn st_transform_impl(
@@ -1614,6 +1808,59 @@ fn st_transform_impl(
         })
 }
 
+/// Evaluate a GEOS predicate on two geometries that must share the same SRID.
+fn geos_predicate(
+    l_geometry: &[u8],
+    r_geometry: &[u8],
+    predicate: impl FnOnce(&Geometry, &Geometry) -> geos::GResult<bool>,
+) -> Result<bool, String> {
+    let geos = geometries_to_geos(&[l_geometry, r_geometry])?;
+    get_shared_srid(&geos).map_err(|e| ErrorCode::GeometryError(e).to_string())?;
+    predicate(&geos[0], &geos[1]).map_err(|e| ErrorCode::GeometryError(e.to_string()).to_string())
+}
+
+/// Evaluate a GEOS overlay operation on two geometries that must share the same SRID,
+/// the result keeps the SRID of the inputs.
+fn geos_binary_op(
+    l_geometry: &[u8],
+    r_geometry: &[u8],
+    op: impl FnOnce(&Geometry, &Geometry) -> geos::GResult<Geometry>,
+) -> Result<Vec<u8>, String> {
+    let geos = geometries_to_geos(&[l_geometry, r_geometry])?;
+    let srid = get_shared_srid(&geos).map_err(|e| ErrorCode::GeometryError(e).to_string())?;
+    op(&geos[0], &geos[1])
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()).to_string())
+        .and_then(|geos| geos_to_ewkb(&geos, srid))
+}
+
+/// Evaluate a GEOS operation on one geometry, the result keeps the SRID of the input.
+fn geos_unary_op(
+    geometry: &[u8],
+    op: impl FnOnce(&Geometry) -> geos::GResult<Geometry>,
+) -> Result<Vec<u8>, String> {
+    let geos = geometries_to_geos(&[geometry])?;
+    let srid = geos[0].srid();
+    op(&geos[0])
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()).to_string())
+        .and_then(|geos| geos_to_ewkb(&geos, srid))
+}
+
+fn geometries_to_geos(geometries: &[&[u8]]) -> Result<Vec<Geometry>, String> {
+    geometries
+        .iter()
+        .map(|geometry| {
+            Ewkb(geometry)
+                .to_geos()
+                .map_err(|e| ErrorCode::GeometryError(e.to_string()).to_string())
+        })
+        .collect()
+}
+
+fn geos_to_ewkb(geos: &Geometry, srid: Option<i32>) -> Result<Vec<u8>, String> {
+    geos.to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()).to_string())
+}
+
 #[inline]
 fn get_shared_srid(geometries: &Vec<Geometry>) -> Result<Option<i32>, String> {
     let mut srid: Option<i32> = None;
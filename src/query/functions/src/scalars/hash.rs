@@ -31,6 +31,7 @@ use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_INTEGER_TYPES;
 use databend_common_expression::types::ALL_NUMBER_CLASSES;
@@ -61,6 +62,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_simple_domain_type_hash::<TimestampType>(registry);
     register_simple_domain_type_hash::<BooleanType>(registry);
     register_simple_domain_type_hash::<BitmapType>(registry);
+    register_simple_domain_type_hash::<UuidType>(registry);
 
     for ty in ALL_NUMBER_CLASSES {
         with_number_mapped_type!(|NUM_TYPE| match ty {
@@ -306,7 +308,8 @@ macro_rules! for_all_integer_types{
             { u16 },
             { u32 },
             { u64 },
-            { i128 }
+            { i128 },
+            { u128 }
         }
     };
 }
@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::date_helper::AddDaysImpl;
+use databend_common_expression::utils::date_helper::AddMonthsImpl;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_interval_cast(registry);
+    register_interval_cmp(registry);
+    register_interval_arith(registry);
+    register_timestamp_interval_arith(registry);
+}
+
+fn register_interval_cast(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_interval_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_interval_to_string),
+    );
+}
+
+fn eval_string_to_interval(
+    val: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<IntervalType> {
+    vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+        match string_to_interval(val) {
+            Ok(v) => output.push(v),
+            Err(err) => {
+                ctx.set_error(
+                    output.len(),
+                    format!("cannot parse to type `INTERVAL`. {err}"),
+                );
+                output.push(months_days_micros::default());
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_interval_to_string(
+    val: ValueRef<IntervalType>,
+    ctx: &mut EvalContext,
+) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+        write!(output.data, "{val}").unwrap();
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
+fn register_interval_arith(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            match val.checked_neg() {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_add(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_sub(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |a, b, output, ctx| match a.checked_mul(*b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+}
+
+fn register_timestamp_interval_arith(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |a, b, output, ctx| match add_interval(a, b, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |a, b, output, ctx| {
+                    let res = b
+                        .checked_neg()
+                        .ok_or_else(|| "interval out of range".to_string())
+                        .and_then(|b| add_interval(a, b, ctx.func_ctx.tz));
+                    match res {
+                        Ok(v) => output.push(v),
+                        Err(err) => {
+                            ctx.set_error(output.len(), err);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+}
+
+/// Months are applied first in the session time zone, then days, then the
+/// sub-day part, so that `'2024-01-31' + INTERVAL '1 month 1 day'` lands on
+/// `2024-03-01`.
+fn add_interval(ts: i64, interval: months_days_micros, tz: TzLUT) -> Result<i64, String> {
+    let mut ts = ts;
+    if interval.months() != 0 {
+        ts = AddMonthsImpl::eval_timestamp(ts, tz, interval.months())?;
+    }
+    if interval.days() != 0 {
+        ts = AddDaysImpl::eval_timestamp(ts, interval.days())?;
+    }
+    let ts = ts
+        .checked_add(interval.microseconds())
+        .ok_or_else(|| "timestamp out of range".to_string())?;
+    check_timestamp(ts)
+}
@@ -0,0 +1,570 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use jsonb::to_serde_json;
+use regex::Regex;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<VariantType, VariantType, BooleanType, _, _>(
+        "json_schema_validate",
+        |_, _, _| FunctionDomain::MayThrow,
+        |val, schema, ctx| match schema {
+            ValueRef::Scalar(schema) => {
+                match to_serde_json(schema)
+                    .map_err(|err| err.to_string())
+                    .and_then(JsonSchema::compile)
+                {
+                    Ok(schema) => eval_json_schema_validate(val, &schema, ctx),
+                    Err(err) => {
+                        ctx.set_error(0, format!("Invalid JSON schema: {err}"));
+                        Value::Scalar(false)
+                    }
+                }
+            }
+            ValueRef::Column(_) => {
+                vectorize_with_builder_2_arg::<VariantType, VariantType, BooleanType>(
+                    |val, schema, output, ctx| match to_serde_json(schema)
+                        .map_err(|err| err.to_string())
+                        .and_then(JsonSchema::compile)
+                    {
+                        Ok(schema) => validate_row(val, &schema, output, ctx),
+                        Err(err) => {
+                            ctx.set_error(output.len(), format!("Invalid JSON schema: {err}"));
+                            output.push(false);
+                        }
+                    },
+                )(val, schema, ctx)
+            }
+        },
+    );
+
+    registry.register_passthrough_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
+        "json_schema_validate",
+        |_, _, _| FunctionDomain::MayThrow,
+        |val, schema, ctx| match schema {
+            ValueRef::Scalar(schema) => {
+                match serde_json::from_str(schema)
+                    .map_err(|err| err.to_string())
+                    .and_then(JsonSchema::compile)
+                {
+                    Ok(schema) => eval_json_schema_validate(val, &schema, ctx),
+                    Err(err) => {
+                        ctx.set_error(0, format!("Invalid JSON schema: {err}"));
+                        Value::Scalar(false)
+                    }
+                }
+            }
+            ValueRef::Column(_) => {
+                vectorize_with_builder_2_arg::<VariantType, StringType, BooleanType>(
+                    |val, schema, output, ctx| match serde_json::from_str(schema)
+                        .map_err(|err| err.to_string())
+                        .and_then(JsonSchema::compile)
+                    {
+                        Ok(schema) => validate_row(val, &schema, output, ctx),
+                        Err(err) => {
+                            ctx.set_error(output.len(), format!("Invalid JSON schema: {err}"));
+                            output.push(false);
+                        }
+                    },
+                )(val, schema, ctx)
+            }
+        },
+    );
+}
+
+fn eval_json_schema_validate(
+    val: ValueRef<VariantType>,
+    schema: &JsonSchema,
+    ctx: &mut EvalContext,
+) -> Value<BooleanType> {
+    vectorize_with_builder_1_arg::<VariantType, BooleanType>(|val, output, ctx| {
+        validate_row(val, schema, output, ctx)
+    })(val, ctx)
+}
+
+fn validate_row(
+    val: &[u8],
+    schema: &JsonSchema,
+    output: &mut MutableBitmap,
+    ctx: &mut EvalContext,
+) {
+    if let Some(validity) = &ctx.validity {
+        if !validity.get_bit(output.len()) {
+            output.push(false);
+            return;
+        }
+    }
+    match to_serde_json(val) {
+        Ok(instance) => output.push(schema.is_valid(&instance)),
+        Err(_) => {
+            ctx.set_error(
+                output.len(),
+                format!("Invalid JSONB value '0x{}'", hex::encode(val)),
+            );
+            output.push(false);
+        }
+    }
+}
+
+/// A JSON Schema checked and ready for validation.
+///
+/// The common keywords of the recent drafts are supported, `$ref` can only
+/// point into the same schema, like `#/definitions/name` or `#/$defs/name`.
+pub struct JsonSchema {
+    root: JsonValue,
+    patterns: HashMap<String, Regex>,
+}
+
+impl JsonSchema {
+    pub fn compile(root: JsonValue) -> Result<Self, String> {
+        let mut patterns = HashMap::new();
+        check_schema(&root, &root, &mut patterns)?;
+        Ok(JsonSchema { root, patterns })
+    }
+
+    pub fn is_valid(&self, instance: &JsonValue) -> bool {
+        self.validate(&self.root, instance)
+    }
+
+    fn validate(&self, schema: &JsonValue, instance: &JsonValue) -> bool {
+        let schema = match schema {
+            JsonValue::Bool(valid) => return *valid,
+            JsonValue::Object(schema) => schema,
+            _ => return false,
+        };
+
+        if let Some(JsonValue::String(reference)) = schema.get("$ref") {
+            match resolve_ref(&self.root, reference) {
+                Some(target) if self.validate(target, instance) => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(ty) = schema.get("type") {
+            let matched = match ty {
+                JsonValue::String(ty) => is_type(ty, instance),
+                JsonValue::Array(tys) => tys
+                    .iter()
+                    .any(|ty| ty.as_str().is_some_and(|ty| is_type(ty, instance))),
+                _ => false,
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(JsonValue::Array(values)) = schema.get("enum") {
+            if !values.iter().any(|value| json_eq(value, instance)) {
+                return false;
+            }
+        }
+        if let Some(value) = schema.get("const") {
+            if !json_eq(value, instance) {
+                return false;
+            }
+        }
+
+        let valid = match instance {
+            JsonValue::Number(n) => self.validate_number(schema, n.as_f64().unwrap_or_default()),
+            JsonValue::String(s) => self.validate_string(schema, s),
+            JsonValue::Array(items) => self.validate_array(schema, items),
+            JsonValue::Object(object) => self.validate_object(schema, object),
+            _ => true,
+        };
+        if !valid {
+            return false;
+        }
+
+        if let Some(JsonValue::Array(schemas)) = schema.get("allOf") {
+            if !schemas.iter().all(|s| self.validate(s, instance)) {
+                return false;
+            }
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("anyOf") {
+            if !schemas.iter().any(|s| self.validate(s, instance)) {
+                return false;
+            }
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("oneOf") {
+            if schemas
+                .iter()
+                .filter(|s| self.validate(s, instance))
+                .count()
+                != 1
+            {
+                return false;
+            }
+        }
+        if let Some(not) = schema.get("not") {
+            if self.validate(not, instance) {
+                return false;
+            }
+        }
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.validate(condition, instance) {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                if !self.validate(branch, instance) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn validate_number(&self, schema: &Map<String, JsonValue>, n: f64) -> bool {
+        let keyword = |name: &str| schema.get(name).and_then(|v| v.as_f64());
+        if keyword("minimum").is_some_and(|min| n < min)
+            || keyword("maximum").is_some_and(|max| n > max)
+            || keyword("exclusiveMinimum").is_some_and(|min| n <= min)
+            || keyword("exclusiveMaximum").is_some_and(|max| n >= max)
+        {
+            return false;
+        }
+        if let Some(m) = keyword("multipleOf") {
+            let quotient = n / m;
+            if (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs().max(1.0) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn validate_string(&self, schema: &Map<String, JsonValue>, s: &str) -> bool {
+        let len = s.chars().count() as u64;
+        if schema
+            .get("minLength")
+            .and_then(|v| v.as_u64())
+            .is_some_and(|min| len < min)
+            || schema
+                .get("maxLength")
+                .and_then(|v| v.as_u64())
+                .is_some_and(|max| len > max)
+        {
+            return false;
+        }
+        if let Some(JsonValue::String(pattern)) = schema.get("pattern") {
+            if !self.patterns[pattern].is_match(s) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn validate_array(&self, schema: &Map<String, JsonValue>, items: &[JsonValue]) -> bool {
+        let len = items.len() as u64;
+        if schema
+            .get("minItems")
+            .and_then(|v| v.as_u64())
+            .is_some_and(|min| len < min)
+            || schema
+                .get("maxItems")
+                .and_then(|v| v.as_u64())
+                .is_some_and(|max| len > max)
+        {
+            return false;
+        }
+        if schema.get("uniqueItems") == Some(&JsonValue::Bool(true)) {
+            for (i, item) in items.iter().enumerate() {
+                if items[i + 1..].iter().any(|other| json_eq(item, other)) {
+                    return false;
+                }
+            }
+        }
+        match schema.get("items") {
+            // The tuple form, the rest items are checked with `additionalItems`.
+            Some(JsonValue::Array(schemas)) => {
+                if !items
+                    .iter()
+                    .zip(schemas)
+                    .all(|(item, s)| self.validate(s, item))
+                {
+                    return false;
+                }
+                if let Some(additional) = schema.get("additionalItems") {
+                    if !items
+                        .iter()
+                        .skip(schemas.len())
+                        .all(|item| self.validate(additional, item))
+                    {
+                        return false;
+                    }
+                }
+            }
+            Some(s) => {
+                if !items.iter().all(|item| self.validate(s, item)) {
+                    return false;
+                }
+            }
+            None => {}
+        }
+        if let Some(contains) = schema.get("contains") {
+            if !items.iter().any(|item| self.validate(contains, item)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn validate_object(
+        &self,
+        schema: &Map<String, JsonValue>,
+        object: &Map<String, JsonValue>,
+    ) -> bool {
+        let len = object.len() as u64;
+        if schema
+            .get("minProperties")
+            .and_then(|v| v.as_u64())
+            .is_some_and(|min| len < min)
+            || schema
+                .get("maxProperties")
+                .and_then(|v| v.as_u64())
+                .is_some_and(|max| len > max)
+        {
+            return false;
+        }
+        if let Some(JsonValue::Array(required)) = schema.get("required") {
+            if !required
+                .iter()
+                .all(|name| name.as_str().is_some_and(|name| object.contains_key(name)))
+            {
+                return false;
+            }
+        }
+
+        let properties = schema.get("properties").and_then(|v| v.as_object());
+        let pattern_properties = schema.get("patternProperties").and_then(|v| v.as_object());
+        let additional = schema.get("additionalProperties");
+        for (name, value) in object {
+            let mut matched = false;
+            if let Some(s) = properties.and_then(|properties| properties.get(name)) {
+                matched = true;
+                if !self.validate(s, value) {
+                    return false;
+                }
+            }
+            for (pattern, s) in pattern_properties.into_iter().flatten() {
+                if self.patterns[pattern].is_match(name) {
+                    matched = true;
+                    if !self.validate(s, value) {
+                        return false;
+                    }
+                }
+            }
+            if !matched {
+                if let Some(additional) = additional {
+                    if !self.validate(additional, value) {
+                        return false;
+                    }
+                }
+            }
+            if let Some(names) = schema.get("propertyNames") {
+                if !self.validate(names, &JsonValue::String(name.clone())) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Checks the schema is well-formed and compiles the regular expressions in it.
+fn check_schema(
+    root: &JsonValue,
+    schema: &JsonValue,
+    patterns: &mut HashMap<String, Regex>,
+) -> Result<(), String> {
+    let schema = match schema {
+        JsonValue::Bool(_) => return Ok(()),
+        JsonValue::Object(schema) => schema,
+        other => {
+            return Err(format!(
+                "a schema must be an object or a boolean, got {other}"
+            ));
+        }
+    };
+
+    for (keyword, value) in schema {
+        match keyword.as_str() {
+            "$ref" => {
+                let reference = value
+                    .as_str()
+                    .ok_or_else(|| "'$ref' must be a string".to_string())?;
+                // Follow the chain of references to reject the circular ones,
+                // they would never make progress in validation.
+                let mut visited = vec![reference];
+                let mut target = resolve_ref(root, reference);
+                while let Some(JsonValue::String(next)) = target.and_then(|t| t.get("$ref")) {
+                    if visited.contains(&next.as_str()) {
+                        return Err(format!("circular '$ref' '{reference}'"));
+                    }
+                    visited.push(next);
+                    target = resolve_ref(root, next);
+                }
+                if target.is_none() {
+                    return Err(format!("cannot resolve '$ref' '{reference}'"));
+                }
+            }
+            "type" => {
+                let valid = match value {
+                    JsonValue::String(ty) => is_type_name(ty),
+                    JsonValue::Array(tys) => {
+                        tys.iter().all(|ty| ty.as_str().is_some_and(is_type_name))
+                    }
+                    _ => false,
+                };
+                if !valid {
+                    return Err(format!("invalid 'type' {value}"));
+                }
+            }
+            "pattern" => {
+                let pattern = value
+                    .as_str()
+                    .ok_or_else(|| "'pattern' must be a string".to_string())?;
+                compile_pattern(pattern, patterns)?;
+            }
+            "enum" | "required" if !value.is_array() => {
+                return Err(format!("'{keyword}' must be an array"));
+            }
+            "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf"
+                if !value.is_number() =>
+            {
+                return Err(format!("'{keyword}' must be a number"));
+            }
+            "minLength" | "maxLength" | "minItems" | "maxItems" | "minProperties"
+            | "maxProperties"
+                if !value.is_u64() =>
+            {
+                return Err(format!("'{keyword}' must be a non-negative integer"));
+            }
+            "patternProperties" => {
+                let properties = value
+                    .as_object()
+                    .ok_or_else(|| "'patternProperties' must be an object".to_string())?;
+                for (pattern, s) in properties {
+                    compile_pattern(pattern, patterns)?;
+                    check_schema(root, s, patterns)?;
+                }
+            }
+            "properties" | "definitions" | "$defs" => {
+                let properties = value
+                    .as_object()
+                    .ok_or_else(|| format!("'{keyword}' must be an object"))?;
+                for s in properties.values() {
+                    check_schema(root, s, patterns)?;
+                }
+            }
+            "allOf" | "anyOf" | "oneOf" => {
+                let schemas = value
+                    .as_array()
+                    .filter(|schemas| !schemas.is_empty())
+                    .ok_or_else(|| format!("'{keyword}' must be a non-empty array"))?;
+                for s in schemas {
+                    check_schema(root, s, patterns)?;
+                }
+            }
+            "items" if value.is_array() => {
+                for s in value.as_array().unwrap() {
+                    check_schema(root, s, patterns)?;
+                }
+            }
+            "items"
+            | "additionalItems"
+            | "contains"
+            | "additionalProperties"
+            | "propertyNames"
+            | "not"
+            | "if"
+            | "then"
+            | "else" => {
+                check_schema(root, value, patterns)?;
+            }
+            // Unknown keywords are ignored, like annotations `title` and `description`.
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn compile_pattern(pattern: &str, patterns: &mut HashMap<String, Regex>) -> Result<(), String> {
+    if !patterns.contains_key(pattern) {
+        let regex =
+            Regex::new(pattern).map_err(|err| format!("invalid pattern '{pattern}': {err}"))?;
+        patterns.insert(pattern.to_string(), regex);
+    }
+    Ok(())
+}
+
+fn resolve_ref<'a>(root: &'a JsonValue, reference: &str) -> Option<&'a JsonValue> {
+    let pointer = reference.strip_prefix('#')?;
+    root.pointer(pointer)
+}
+
+fn is_type_name(ty: &str) -> bool {
+    matches!(
+        ty,
+        "null" | "boolean" | "object" | "array" | "number" | "integer" | "string"
+    )
+}
+
+fn is_type(ty: &str, instance: &JsonValue) -> bool {
+    match ty {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "number" => instance.is_number(),
+        "integer" => match instance {
+            JsonValue::Number(n) => {
+                n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+            }
+            _ => false,
+        },
+        "string" => instance.is_string(),
+        _ => false,
+    }
+}
+
+// Numbers are equal by value, `1` and `1.0` are the same.
+fn json_eq(a: &JsonValue, b: &JsonValue) -> bool {
+    match (a, b) {
+        (JsonValue::Number(a), JsonValue::Number(b)) => a.as_f64() == b.as_f64(),
+        (JsonValue::Array(a), JsonValue::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_eq(a, b))
+        }
+        (JsonValue::Object(a), JsonValue::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(k, v)| b.get(k).is_some_and(|other| json_eq(v, other)))
+        }
+        _ => a == b,
+    }
+}
@@ -0,0 +1,443 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::large_int::LargeInt;
+use databend_common_expression::types::large_int::LargeIntType;
+use databend_common_expression::types::large_int::ALL_LARGE_INT_TYPES;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::number::SimpleDomain;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::LargeIntDataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ALL_INTEGER_TYPES;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::with_large_int_mapped_type;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use num_traits::AsPrimitive;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    for ty in ALL_LARGE_INT_TYPES {
+        with_large_int_mapped_type!(|LARGE_INT| match ty {
+            LargeIntDataType::LARGE_INT => {
+                register_large_int_cast::<LARGE_INT>(registry);
+                register_large_int_arith::<LARGE_INT>(registry);
+            }
+        });
+    }
+}
+
+fn register_large_int_cast<T: LargeInt>(registry: &mut FunctionRegistry) {
+    let name = format!("to_{}", T::data_type().to_string().to_lowercase());
+    let try_name = format!("try_{name}");
+
+    registry.register_passthrough_nullable_1_arg::<StringType, LargeIntType<T>, _, _>(
+        &name,
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_large_int::<T>,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, LargeIntType<T>, _, _>(
+        &try_name,
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_large_int::<T>),
+    );
+
+    for src in ALL_INTEGER_TYPES {
+        with_integer_mapped_type!(|NUM| match src {
+            NumberDataType::NUM => register_cast_from_integer::<NUM, T>(registry, &name, &try_name),
+            _ => unreachable!(),
+        });
+    }
+
+    for src in ALL_LARGE_INT_TYPES {
+        with_large_int_mapped_type!(|LARGE_INT| match src {
+            LargeIntDataType::LARGE_INT => {
+                if LARGE_INT::data_type() != T::data_type() {
+                    register_cast_from_large_int::<LARGE_INT, T>(registry, &name, &try_name);
+                }
+            }
+        });
+    }
+
+    registry.register_passthrough_nullable_1_arg::<LargeIntType<T>, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_large_int_to_string::<T>,
+    );
+    registry.register_combine_nullable_1_arg::<LargeIntType<T>, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_large_int_to_string::<T>),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<LargeIntType<T>, Float64Type, _, _>(
+        "to_float64",
+        |_, domain| {
+            FunctionDomain::Domain(SimpleDomain {
+                min: F64::from(domain.min.to_f64()),
+                max: F64::from(domain.max.to_f64()),
+            })
+        },
+        vectorize_with_builder_1_arg::<LargeIntType<T>, Float64Type>(|val, output, _| {
+            output.push(F64::from(val.to_f64()))
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<LargeIntType<T>, Int64Type, _, _>(
+        "to_int64",
+        |_, _| FunctionDomain::MayThrow,
+        eval_large_int_to_int64::<T>,
+    );
+    registry.register_combine_nullable_1_arg::<LargeIntType<T>, Int64Type, _, _>(
+        "try_to_int64",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_large_int_to_int64::<T>),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<LargeIntType<T>, UInt64Type, _, _>(
+        "to_uint64",
+        |_, _| FunctionDomain::MayThrow,
+        eval_large_int_to_uint64::<T>,
+    );
+    registry.register_combine_nullable_1_arg::<LargeIntType<T>, UInt64Type, _, _>(
+        "try_to_uint64",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_large_int_to_uint64::<T>),
+    );
+}
+
+fn register_cast_from_integer<N, T>(registry: &mut FunctionRegistry, name: &str, try_name: &str)
+where
+    N: Number + AsPrimitive<i128>,
+    T: LargeInt,
+{
+    registry.register_passthrough_nullable_1_arg::<NumberType<N>, LargeIntType<T>, _, _>(
+        name,
+        |_, domain| match (
+            T::from_large_int::<i128>(domain.min.as_()),
+            T::from_large_int::<i128>(domain.max.as_()),
+        ) {
+            (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+            _ => FunctionDomain::MayThrow,
+        },
+        eval_integer_to_large_int::<N, T>,
+    );
+    registry.register_combine_nullable_1_arg::<NumberType<N>, LargeIntType<T>, _, _>(
+        try_name,
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_integer_to_large_int::<N, T>),
+    );
+}
+
+fn register_cast_from_large_int<S, T>(registry: &mut FunctionRegistry, name: &str, try_name: &str)
+where
+    S: LargeInt,
+    T: LargeInt,
+{
+    registry.register_passthrough_nullable_1_arg::<LargeIntType<S>, LargeIntType<T>, _, _>(
+        name,
+        |_, domain| match (T::from_large_int(domain.min), T::from_large_int(domain.max)) {
+            (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+            _ => FunctionDomain::MayThrow,
+        },
+        eval_large_int_to_large_int::<S, T>,
+    );
+    registry.register_combine_nullable_1_arg::<LargeIntType<S>, LargeIntType<T>, _, _>(
+        try_name,
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_large_int_to_large_int::<S, T>),
+    );
+}
+
+fn eval_string_to_large_int<T: LargeInt>(
+    val: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<LargeIntType<T>> {
+    vectorize_with_builder_1_arg::<StringType, LargeIntType<T>>(|val, output, ctx| {
+        match T::parse(val) {
+            Some(v) => output.push(v),
+            None => {
+                ctx.set_error(
+                    output.len(),
+                    format!("cannot parse to type `{}`", T::data_type()),
+                );
+                output.push(T::zero());
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_integer_to_large_int<N, T>(
+    val: ValueRef<NumberType<N>>,
+    ctx: &mut EvalContext,
+) -> Value<LargeIntType<T>>
+where
+    N: Number + AsPrimitive<i128>,
+    T: LargeInt,
+{
+    vectorize_with_builder_1_arg::<NumberType<N>, LargeIntType<T>>(|val, output, ctx| {
+        match T::from_large_int::<i128>(val.as_()) {
+            Some(v) => output.push(v),
+            None => {
+                ctx.set_error(
+                    output.len(),
+                    format!(
+                        "number overflowed while casting to type `{}`",
+                        T::data_type()
+                    ),
+                );
+                output.push(T::zero());
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_large_int_to_large_int<S, T>(
+    val: ValueRef<LargeIntType<S>>,
+    ctx: &mut EvalContext,
+) -> Value<LargeIntType<T>>
+where
+    S: LargeInt,
+    T: LargeInt,
+{
+    vectorize_with_builder_1_arg::<LargeIntType<S>, LargeIntType<T>>(|val, output, ctx| {
+        match T::from_large_int(val) {
+            Some(v) => output.push(v),
+            None => {
+                ctx.set_error(
+                    output.len(),
+                    format!(
+                        "number overflowed while casting to type `{}`",
+                        T::data_type()
+                    ),
+                );
+                output.push(T::zero());
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_large_int_to_string<T: LargeInt>(
+    val: ValueRef<LargeIntType<T>>,
+    ctx: &mut EvalContext,
+) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<LargeIntType<T>, StringType>(|val, output, _| {
+        write!(output.data, "{val}").unwrap();
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn eval_large_int_to_int64<T: LargeInt>(
+    val: ValueRef<LargeIntType<T>>,
+    ctx: &mut EvalContext,
+) -> Value<Int64Type> {
+    vectorize_with_builder_1_arg::<LargeIntType<T>, Int64Type>(|val, output, ctx| {
+        match val.to_i64() {
+            Some(v) => output.push(v),
+            None => {
+                ctx.set_error(
+                    output.len(),
+                    "number overflowed while casting to type `Int64`",
+                );
+                output.push(0);
+            }
+        }
+    })(val, ctx)
+}
+
+fn eval_large_int_to_uint64<T: LargeInt>(
+    val: ValueRef<LargeIntType<T>>,
+    ctx: &mut EvalContext,
+) -> Value<UInt64Type> {
+    vectorize_with_builder_1_arg::<LargeIntType<T>, UInt64Type>(|val, output, ctx| {
+        match val.to_i128().and_then(|v| u64::try_from(v).ok()) {
+            Some(v) => output.push(v),
+            None => {
+                ctx.set_error(
+                    output.len(),
+                    "number overflowed while casting to type `UInt64`",
+                );
+                output.push(0);
+            }
+        }
+    })(val, ctx)
+}
+
+fn register_large_int_arith<T: LargeInt>(registry: &mut FunctionRegistry) {
+    if T::SIGNED {
+        registry.register_passthrough_nullable_1_arg::<LargeIntType<T>, LargeIntType<T>, _, _>(
+            "minus",
+            |_, domain| match (domain.max.checked_neg(), domain.min.checked_neg()) {
+                (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+                _ => FunctionDomain::MayThrow,
+            },
+            vectorize_with_builder_1_arg::<LargeIntType<T>, LargeIntType<T>>(|val, output, ctx| {
+                match val.checked_neg() {
+                    Some(v) => output.push(v),
+                    None => {
+                        ctx.set_error(output.len(), overflow_error::<T>());
+                        output.push(T::zero());
+                    }
+                }
+            }),
+        );
+    }
+
+    registry
+        .register_passthrough_nullable_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>, _, _>(
+            "plus",
+            |_, lhs, rhs| {
+                match (
+                    lhs.min.checked_add(rhs.min),
+                    lhs.max.checked_add(rhs.max),
+                ) {
+                    (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+                    _ => FunctionDomain::MayThrow,
+                }
+            },
+            vectorize_large_int_arith::<T>(|a, b| a.checked_add(b)),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>, _, _>(
+            "minus",
+            |_, lhs, rhs| {
+                match (
+                    lhs.min.checked_sub(rhs.max),
+                    lhs.max.checked_sub(rhs.min),
+                ) {
+                    (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+                    _ => FunctionDomain::MayThrow,
+                }
+            },
+            vectorize_large_int_arith::<T>(|a, b| a.checked_sub(b)),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>, _, _>(
+            "multiply",
+            |_, lhs, rhs| {
+                let products = [
+                    lhs.min.checked_mul(rhs.min),
+                    lhs.min.checked_mul(rhs.max),
+                    lhs.max.checked_mul(rhs.min),
+                    lhs.max.checked_mul(rhs.max),
+                ];
+                match products.into_iter().collect::<Option<Vec<_>>>() {
+                    Some(products) => FunctionDomain::Domain(SimpleDomain {
+                        min: products.iter().copied().min().unwrap(),
+                        max: products.iter().copied().max().unwrap(),
+                    }),
+                    None => FunctionDomain::MayThrow,
+                }
+            },
+            vectorize_large_int_arith::<T>(|a, b| a.checked_mul(b)),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>, _, _>(
+            "div",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_large_int_div::<T>(|a, b| a.checked_div(b)),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>, _, _>(
+            "modulo",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_large_int_div::<T>(|a, b| a.checked_rem(b)),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<LargeIntType<T>, LargeIntType<T>, Float64Type, _, _>(
+            "divide",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<LargeIntType<T>, LargeIntType<T>, Float64Type>(
+                |a, b, output, ctx| {
+                    if b == T::zero() {
+                        ctx.set_error(output.len(), "divided by zero");
+                        output.push(F64::default());
+                    } else {
+                        output.push(F64::from(a.to_f64() / b.to_f64()));
+                    }
+                },
+            ),
+        );
+}
+
+fn vectorize_large_int_arith<T: LargeInt>(
+    func: impl Fn(T, T) -> Option<T> + Copy + Send + Sync,
+) -> impl Fn(
+    ValueRef<LargeIntType<T>>,
+    ValueRef<LargeIntType<T>>,
+    &mut EvalContext,
+) -> Value<LargeIntType<T>>
++ Copy
++ Send
++ Sync {
+    vectorize_with_builder_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>>(
+        move |a, b, output, ctx| match func(a, b) {
+            Some(v) => output.push(v),
+            None => {
+                ctx.set_error(output.len(), overflow_error::<T>());
+                output.push(T::zero());
+            }
+        },
+    )
+}
+
+fn vectorize_large_int_div<T: LargeInt>(
+    func: impl Fn(T, T) -> Option<T> + Copy + Send + Sync,
+) -> impl Fn(
+    ValueRef<LargeIntType<T>>,
+    ValueRef<LargeIntType<T>>,
+    &mut EvalContext,
+) -> Value<LargeIntType<T>>
++ Copy
++ Send
++ Sync {
+    vectorize_with_builder_2_arg::<LargeIntType<T>, LargeIntType<T>, LargeIntType<T>>(
+        move |a, b, output, ctx| {
+            if b == T::zero() {
+                ctx.set_error(output.len(), "divided by zero");
+                output.push(T::zero());
+                return;
+            }
+            match func(a, b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), overflow_error::<T>());
+                    output.push(T::zero());
+                }
+            }
+        },
+    )
+}
+
+fn overflow_error<T: LargeInt>() -> String {
+    format!("number overflowed in `{}` arithmetic", T::data_type())
+}
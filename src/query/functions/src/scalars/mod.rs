@@ -24,18 +24,25 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod enumeration;
 mod geo;
 mod geo_h3;
 mod geography;
 mod geometry;
 mod hash;
 mod hilbert;
+mod interval;
+mod json_schema;
+mod large_int;
 mod map;
 mod math;
 mod other;
+mod sketch;
 mod string;
 mod string_multi_args;
+mod string_similarity;
 mod tuple;
+mod uuid;
 mod variant;
 mod vector;
 
@@ -44,6 +51,7 @@ pub use string::ALL_STRING_FUNC_NAMES;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
+    json_schema::register(registry);
     arithmetic::register(registry);
     array::register(registry);
     boolean::register(registry);
@@ -55,6 +63,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     string::register(registry);
     binary::register(registry);
     string_multi_args::register(registry);
+    string_similarity::register(registry);
     tuple::register(registry);
     geo::register(registry);
     geo_h3::register(registry);
@@ -63,7 +72,12 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register_to_decimal(registry);
     vector::register(registry);
     bitmap::register(registry);
+    sketch::register(registry);
     geometry::register(registry);
     geography::register(registry);
     hilbert::register(registry);
+    interval::register(registry);
+    large_int::register(registry);
+    enumeration::register(registry);
+    uuid::register(registry);
 }
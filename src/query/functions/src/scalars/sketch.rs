@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+
+use crate::aggregates::HllSketch;
+use crate::aggregates::KllSketch;
+use crate::aggregates::ThetaSketch;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UInt64Type, _, _>(
+        "theta_sketch_estimate",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, UInt64Type>(|sketch, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(0);
+                    return;
+                }
+            }
+            match ThetaSketch::decode(sketch) {
+                Ok(mut sketch) => builder.push(sketch.estimate().round() as u64),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UInt64Type, _, _>(
+        "hll_sketch_estimate",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, UInt64Type>(|sketch, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(0);
+                    return;
+                }
+            }
+            match HllSketch::decode(sketch) {
+                Ok(sketch) => builder.push(sketch.estimate().round() as u64),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push(0);
+                }
+            }
+        }),
+    );
+
+    // Returns NULL if the sketch is empty.
+    registry.register_combine_nullable_2_arg::<BinaryType, Float64Type, Float64Type, _, _>(
+        "kll_sketch_estimate",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BinaryType, Float64Type, NullableType<Float64Type>>(
+            |sketch, rank, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.push_null();
+                        return;
+                    }
+                }
+                if !(0.0..=1.0).contains(&rank.0) {
+                    ctx.set_error(
+                        output.len(),
+                        format!("rank must be between 0 and 1, but got {rank}"),
+                    );
+                    output.push_null();
+                    return;
+                }
+                match KllSketch::decode(sketch) {
+                    Ok(sketch) => match sketch.quantile(rank.0) {
+                        Some(value) => output.push(F64::from(value)),
+                        None => output.push_null(),
+                    },
+                    Err(e) => {
+                        ctx.set_error(output.len(), e.message());
+                        output.push_null();
+                    }
+                }
+            },
+        ),
+    );
+
+    register_sketch_set_operation(registry, "theta_sketch_union", |a, b, buf| {
+        ThetaSketch::decode(a)?
+            .union(&ThetaSketch::decode(b)?)
+            .encode(buf);
+        Ok(())
+    });
+    register_sketch_set_operation(registry, "theta_sketch_intersect", |a, b, buf| {
+        ThetaSketch::decode(a)?
+            .intersect(&ThetaSketch::decode(b)?)
+            .encode(buf);
+        Ok(())
+    });
+    register_sketch_set_operation(registry, "theta_sketch_a_not_b", |a, b, buf| {
+        ThetaSketch::decode(a)?
+            .a_not_b(&ThetaSketch::decode(b)?)
+            .encode(buf);
+        Ok(())
+    });
+    register_sketch_set_operation(registry, "hll_sketch_union", |a, b, buf| {
+        HllSketch::decode(a)?
+            .union(&HllSketch::decode(b)?)
+            .encode(buf);
+        Ok(())
+    });
+}
+
+fn register_sketch_set_operation(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(&[u8], &[u8], &mut Vec<u8>) -> Result<()>,
+) {
+    registry.register_passthrough_nullable_2_arg::<BinaryType, BinaryType, BinaryType, _, _>(
+        name,
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BinaryType, BinaryType, BinaryType>(
+            move |a, b, builder, ctx| {
+                set_operation(a, b, builder, ctx, op);
+            },
+        ),
+    );
+}
+
+fn set_operation(
+    a: &[u8],
+    b: &[u8],
+    builder: &mut BinaryColumnBuilder,
+    ctx: &mut EvalContext,
+    op: fn(&[u8], &[u8], &mut Vec<u8>) -> Result<()>,
+) {
+    if let Some(validity) = &ctx.validity {
+        if !validity.get_bit(builder.len()) {
+            builder.commit_row();
+            return;
+        }
+    }
+    let mut buf = Vec::new();
+    match op(a, b, &mut buf) {
+        Ok(()) => builder.put_slice(&buf),
+        Err(e) => ctx.set_error(builder.len(), e.message()),
+    }
+    builder.commit_row();
+}
@@ -81,6 +81,13 @@ pub const ALL_STRING_FUNC_NAMES: &[&str] = &[
     "regexp_like",
     "regexp_replace",
     "regexp_substr",
+    "regexp_extract_all",
+    "jaro_winkler_similarity",
+    "damerau_levenshtein",
+    "ngram_distance",
+    "ngrams",
+    "first_common_ngram",
+    "token_set_ratio",
 ];
 
 pub fn register(registry: &mut FunctionRegistry) {
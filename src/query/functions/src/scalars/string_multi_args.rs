@@ -23,6 +23,9 @@ use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::string::StringDomain;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::*;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::Column;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
@@ -417,6 +420,71 @@ pub fn register(registry: &mut FunctionRegistry) {
             Some(Arc::new(f))
         }
     });
+
+    // The captures of every group in all matches, keyed by the group name,
+    // or the group index if the group is unnamed, `0` is the whole match.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, MapType<StringType, ArrayType<StringType>>, _, _>(
+        "regexp_extract_all",
+        |_, _, _| FunctionDomain::MayThrow,
+        |source, pat, ctx| match pat {
+            ValueRef::Scalar(pat) => {
+                match regexp::build_regexp_from_pattern("regexp_extract_all", pat, None) {
+                    Ok(re) => vectorize_with_builder_1_arg::<StringType, MapType<StringType, ArrayType<StringType>>>(
+                        |s, output, _| regexp::regexp_extract_groups(s, &re, output),
+                    )(source, ctx),
+                    Err(err) => {
+                        ctx.set_error(0, err);
+                        vectorize_with_builder_1_arg::<StringType, MapType<StringType, ArrayType<StringType>>>(
+                            |_, output, _| output.commit_row(),
+                        )(source, ctx)
+                    }
+                }
+            }
+            ValueRef::Column(_) => vectorize_with_builder_2_arg::<StringType, StringType, MapType<StringType, ArrayType<StringType>>>(
+                |s, pat, output, ctx| {
+                    match regexp::build_regexp_from_pattern("regexp_extract_all", pat, None) {
+                        Ok(re) => regexp::regexp_extract_groups(s, &re, output),
+                        Err(err) => {
+                            ctx.set_error(output.len(), err);
+                            output.commit_row();
+                        }
+                    }
+                },
+            )(source, pat, ctx),
+        },
+    );
+
+    // The captures of the given group in all matches.
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, UInt64Type, ArrayType<StringType>, _, _>(
+        "regexp_extract_all",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        |source, pat, group, ctx| match pat {
+            ValueRef::Scalar(pat) => {
+                match regexp::build_regexp_from_pattern("regexp_extract_all", pat, None) {
+                    Ok(re) => vectorize_with_builder_2_arg::<StringType, UInt64Type, ArrayType<StringType>>(
+                        |s, group, output, ctx| regexp::regexp_extract_group(s, &re, group, output, ctx),
+                    )(source, group, ctx),
+                    Err(err) => {
+                        ctx.set_error(0, err);
+                        vectorize_with_builder_2_arg::<StringType, UInt64Type, ArrayType<StringType>>(
+                            |_, _, output, _| output.commit_row(),
+                        )(source, group, ctx)
+                    }
+                }
+            }
+            ValueRef::Column(_) => vectorize_with_builder_3_arg::<StringType, StringType, UInt64Type, ArrayType<StringType>>(
+                |s, pat, group, output, ctx| {
+                    match regexp::build_regexp_from_pattern("regexp_extract_all", pat, None) {
+                        Ok(re) => regexp::regexp_extract_group(s, &re, group, output, ctx),
+                        Err(err) => {
+                            ctx.set_error(output.len(), err);
+                            output.commit_row();
+                        }
+                    }
+                },
+            )(source, pat, group, ctx),
+        },
+    );
 }
 
 fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
@@ -846,7 +914,12 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
 }
 
 pub mod regexp {
+    use databend_common_expression::types::array::ArrayColumnBuilder;
+    use databend_common_expression::types::map::KvPair;
     use databend_common_expression::types::string::StringColumnBuilder;
+    use databend_common_expression::types::ArrayType;
+    use databend_common_expression::types::StringType;
+    use databend_common_expression::EvalContext;
     use regex::Regex;
     use regex::RegexBuilder;
 
@@ -1014,4 +1087,56 @@ pub mod regexp {
         let m = re.find_iter(&s[char_pos..]).nth((occur - 1) as _);
         m.map(|m| m.as_str())
     }
+
+    pub fn regexp_extract_groups(
+        s: &str,
+        re: &Regex,
+        output: &mut ArrayColumnBuilder<KvPair<StringType, ArrayType<StringType>>>,
+    ) {
+        let mut groups = (0..re.captures_len())
+            .map(|_| StringColumnBuilder::with_capacity(0, 0))
+            .collect::<Vec<_>>();
+        for captures in re.captures_iter(s) {
+            for (i, group) in groups.iter_mut().enumerate() {
+                // the group that does not participate in the match is empty.
+                group.put_str(captures.get(i).map_or("", |m| m.as_str()));
+                group.commit_row();
+            }
+        }
+        for ((i, name), group) in re.capture_names().enumerate().zip(groups) {
+            let key = match name {
+                Some(name) => name.to_string(),
+                None => i.to_string(),
+            };
+            output.put_item((key.as_str(), group.build()));
+        }
+        output.commit_row();
+    }
+
+    pub fn regexp_extract_group(
+        s: &str,
+        re: &Regex,
+        group: u64,
+        output: &mut ArrayColumnBuilder<StringType>,
+        ctx: &mut EvalContext,
+    ) {
+        if group as usize >= re.captures_len() {
+            ctx.set_error(
+                output.len(),
+                format!(
+                    "Incorrect arguments to regexp_extract_all: group index {} is out of range, the pattern has {} groups",
+                    group,
+                    re.captures_len() - 1
+                ),
+            );
+        } else {
+            for captures in re.captures_iter(s) {
+                output
+                    .builder
+                    .put_str(captures.get(group as usize).map_or("", |m| m.as_str()));
+                output.builder.commit_row();
+            }
+        }
+        output.commit_row();
+    }
 }
@@ -0,0 +1,316 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_2_arg;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+
+/// The length of grams used by `ngram_distance`.
+const NGRAM_SIZE: usize = 3;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "jaro_winkler_similarity",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<StringType, StringType, Float64Type>(|a, b, _| {
+            F64::from(jaro_winkler_similarity(a, b))
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, UInt64Type, _, _>(
+        "damerau_levenshtein",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<StringType, StringType, UInt64Type>(|a, b, _| {
+            damerau_levenshtein(a, b) as u64
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "ngram_distance",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<StringType, StringType, Float64Type>(|a, b, _| {
+            F64::from(ngram_distance(a, b))
+        }),
+    );
+
+    // `ngrams` and `first_common_ngram` are used to evaluate `ngram_distance` join
+    // conditions as equi-joins on the shared grams, see `RuleNgramJoin`.
+    registry.register_passthrough_nullable_1_arg::<StringType, ArrayType<StringType>, _, _>(
+        "ngrams",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, ArrayType<StringType>>(|s, output, _| {
+            for gram in distinct_ngrams(s) {
+                output.builder.put_str(gram);
+                output.builder.commit_row();
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "first_common_ngram",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, NullableType<StringType>>(
+            |a, b, output, _| match first_common_ngram(a, b) {
+                Some(gram) => output.push(gram),
+                None => output.push_null(),
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "token_set_ratio",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<StringType, StringType, Float64Type>(|a, b, _| {
+            F64::from(token_set_ratio(a, b))
+        }),
+    );
+}
+
+/// Jaro similarity with a bonus for the common prefix of up to 4 characters.
+pub fn jaro_winkler_similarity(a: &str, b: &str) -> f64 {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let jaro = jaro_similarity(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn jaro_similarity(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // characters are only considered matching if they are not farther than the window.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *x {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // count the matched characters which are in a different order.
+    let mut transpositions = 0;
+    let mut j = 0;
+    for (i, x) in a.iter().enumerate() {
+        if !a_matched[i] {
+            continue;
+        }
+        while !b_matched[j] {
+            j += 1;
+        }
+        if *x != b[j] {
+            transpositions += 1;
+        }
+        j += 1;
+    }
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - (transpositions / 2) as f64) / m) / 3.0
+}
+
+/// The minimum number of insertions, deletions, substitutions and transpositions
+/// of two adjacent characters to change one string into the other.
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return n.max(m);
+    }
+
+    let max_dist = n + m;
+    let width = m + 2;
+    let mut d = vec![0; (n + 2) * width];
+    d[0] = max_dist;
+    for i in 0..=n {
+        d[(i + 1) * width] = max_dist;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=m {
+        d[j + 1] = max_dist;
+        d[width + j + 1] = j;
+    }
+
+    // the last row in which each character was seen.
+    let mut last_row = HashMap::new();
+    for i in 1..=n {
+        let mut last_match_col = 0;
+        for j in 1..=m {
+            let i1 = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let j1 = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            d[(i + 1) * width + j + 1] = (d[i * width + j] + cost)
+                .min(d[(i + 1) * width + j] + 1)
+                .min(d[i * width + j + 1] + 1)
+                .min(d[i1 * width + j1] + (i - i1 - 1) + 1 + (j - j1 - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[(n + 1) * width + m + 1]
+}
+
+/// The size of the symmetric difference of the character trigrams of two strings,
+/// divided by the total number of trigrams, `0` means identical and `1` means
+/// they have no trigram in common.
+pub fn ngram_distance(a: &str, b: &str) -> f64 {
+    let a = ngrams(a);
+    let b = ngrams(b);
+    let total = a.values().sum::<usize>() + b.values().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+    let common = a
+        .iter()
+        .map(|(gram, count)| (*count).min(b.get(gram).copied().unwrap_or(0)))
+        .sum::<usize>();
+    (total - 2 * common) as f64 / total as f64
+}
+
+/// The distinct grams of a string in ascending order, a string shorter than a gram,
+/// including the empty string, is a gram by itself. Two strings have an `ngram_distance`
+/// less than `1` if and only if they share one of these grams.
+pub fn distinct_ngrams(s: &str) -> BTreeSet<&str> {
+    if s.is_empty() {
+        return BTreeSet::from([s]);
+    }
+    ngrams(s).into_keys().collect()
+}
+
+/// The smallest gram shared by two strings, if any.
+pub fn first_common_ngram<'a>(a: &'a str, b: &str) -> Option<&'a str> {
+    let b = distinct_ngrams(b);
+    distinct_ngrams(a).into_iter().find(|gram| b.contains(gram))
+}
+
+fn ngrams(s: &str) -> HashMap<&str, usize> {
+    let mut grams = HashMap::new();
+    let offsets = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect::<Vec<_>>();
+    if offsets.len() <= NGRAM_SIZE {
+        // the string is shorter than a gram, use the whole string.
+        if !s.is_empty() {
+            grams.insert(s, 1);
+        }
+        return grams;
+    }
+    for window in offsets.windows(NGRAM_SIZE + 1) {
+        *grams.entry(&s[window[0]..window[NGRAM_SIZE]]).or_insert(0) += 1;
+    }
+    grams
+}
+
+/// Compare the sets of words of two strings, ignoring case, order and duplicated words,
+/// the result is between `0` and `100`.
+pub fn token_set_ratio(a: &str, b: &str) -> f64 {
+    let a = tokens(a);
+    let b = tokens(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let intersection = a.intersection(&b).cloned().collect::<Vec<_>>();
+    let diff_ab = a.difference(&b).cloned().collect::<Vec<_>>();
+    let diff_ba = b.difference(&a).cloned().collect::<Vec<_>>();
+    if !intersection.is_empty() && (diff_ab.is_empty() || diff_ba.is_empty()) {
+        return 100.0;
+    }
+
+    let sect = intersection.join(" ");
+    let combined_ab = intersection
+        .iter()
+        .chain(diff_ab.iter())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let combined_ba = intersection
+        .iter()
+        .chain(diff_ba.iter())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+    ratio(&sect, &combined_ab)
+        .max(ratio(&sect, &combined_ba))
+        .max(ratio(&combined_ab, &combined_ba))
+}
+
+fn tokens(s: &str) -> BTreeSet<String> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+/// Normalized similarity based on the longest common subsequence.
+fn ratio(a: &str, b: &str) -> f64 {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    if a.is_empty() && b.is_empty() {
+        return 100.0;
+    }
+    let mut prev = vec![0; b.len() + 1];
+    let mut curr = vec![0; b.len() + 1];
+    for x in a.iter() {
+        for (j, y) in b.iter().enumerate() {
+            curr[j + 1] = if x == y {
+                prev[j] + 1
+            } else {
+                prev[j + 1].max(curr[j])
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    let lcs = prev[b.len()];
+    200.0 * lcs as f64 / (a.len() + b.len()) as f64
+}
@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::uuid::Uuid;
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::uuid::bytes_to_uuid;
+use databend_common_expression::types::uuid::string_to_uuid;
+use databend_common_expression::types::uuid::uuid_to_string;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UuidType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionProperty;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use rand::Rng;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_uuid_cast(registry);
+    register_uuid_compare(registry);
+    register_uuid_generators(registry);
+}
+
+fn register_uuid_cast(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, UuidType, _, _>(
+        "to_uuid",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_uuid,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, UuidType, _, _>(
+        "try_to_uuid",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_uuid),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UuidType, _, _>(
+        "to_uuid",
+        |_, _| FunctionDomain::MayThrow,
+        eval_binary_to_uuid,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, UuidType, _, _>(
+        "try_to_uuid",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_binary_to_uuid),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UuidType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_uuid_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<UuidType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_uuid_to_string),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UuidType, BinaryType, _, _>(
+        "to_binary",
+        |_, _| FunctionDomain::Full,
+        eval_uuid_to_binary,
+    );
+    registry.register_combine_nullable_1_arg::<UuidType, BinaryType, _, _>(
+        "try_to_binary",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_uuid_to_binary),
+    );
+
+    fn eval_string_to_uuid(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<UuidType> {
+        vectorize_with_builder_1_arg::<StringType, UuidType>(
+            |val, output, ctx| match string_to_uuid(val) {
+                Ok(uuid) => output.push(uuid),
+                Err(e) => {
+                    ctx.set_error(output.len(), format!("cannot parse to type `UUID`. {e}"));
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+
+    fn eval_binary_to_uuid(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Value<UuidType> {
+        vectorize_with_builder_1_arg::<BinaryType, UuidType>(
+            |val, output, ctx| match bytes_to_uuid(val) {
+                Ok(uuid) => output.push(uuid),
+                Err(e) => {
+                    ctx.set_error(output.len(), format!("cannot cast to type `UUID`. {e}"));
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+
+    fn eval_uuid_to_string(val: ValueRef<UuidType>, ctx: &mut EvalContext) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<UuidType, StringType>(|val, output, _| {
+            write!(output.data, "{}", uuid_to_string(val)).unwrap();
+            output.commit_row();
+        })(val, ctx)
+    }
+
+    fn eval_uuid_to_binary(val: ValueRef<UuidType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+        vectorize_with_builder_1_arg::<UuidType, BinaryType>(|val, output, _| {
+            output.put_slice(&val.to_be_bytes());
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_uuid_compare(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<UuidType, UuidType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<UuidType, UuidType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<UuidType, UuidType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<UuidType, UuidType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<UuidType, UuidType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<UuidType, UuidType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
+/// Registers the time-ordered generators.
+///
+/// Both put the unix milliseconds in the leading 48 bits, so values generated later
+/// sort after the earlier ones and newly inserted keys stay close in the same blocks.
+fn register_uuid_generators(registry: &mut FunctionRegistry) {
+    registry.properties.insert(
+        "uuid_v7".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+    registry.properties.insert(
+        "gen_ulid".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+
+    registry.register_0_arg_core::<UuidType, _, _>(
+        "uuid_v7",
+        |_| FunctionDomain::Full,
+        |ctx| {
+            let values = (0..ctx.num_rows)
+                .map(|_| Uuid::now_v7().as_u128())
+                .collect::<Vec<_>>();
+            Value::Column(values.into())
+        },
+    );
+
+    // The 128 bits of the ULID are kept in a `UUID`, the text form is the UUID form.
+    registry.register_0_arg_core::<UuidType, _, _>(
+        "gen_ulid",
+        |_| FunctionDomain::Full,
+        |ctx| {
+            let millis = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or_default();
+            let mut rng = rand::thread_rng();
+            let values = (0..ctx.num_rows)
+                .map(|_| {
+                    let random = rng.gen::<u128>() & ((1 << 80) - 1);
+                    ((millis & ((1 << 48) - 1)) << 80) | random
+                })
+                .collect::<Vec<_>>();
+            Value::Column(values.into())
+        },
+    );
+}
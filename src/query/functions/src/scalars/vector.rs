@@ -12,22 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::AnyType;
 use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::Float32Type;
 use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VectorColumnBuilder;
+use databend_common_expression::types::VectorType;
 use databend_common_expression::types::F32;
 use databend_common_expression::types::F64;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::with_decimal_mapped_type;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
 use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
 use databend_common_openai::OpenAI;
 use databend_common_vector::cosine_distance;
 use databend_common_vector::cosine_distance_64;
+use databend_common_vector::inner_product;
+use databend_common_vector::l1_distance;
 use databend_common_vector::l2_distance;
 use databend_common_vector::l2_distance_64;
+use databend_common_vector::vector_norm;
+use num_traits::AsPrimitive;
 
 pub fn register(registry: &mut FunctionRegistry) {
     // cosine_distance
@@ -127,6 +154,71 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
+        "inner_product",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>,  Float32Type>(
+            |lhs, rhs, output, ctx| {
+                let l =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
+                let r =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
+
+                match inner_product(l.as_slice(), r.as_slice()) {
+                    Ok(dist) => {
+                        output.push(F32::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F32::from(0.0));
+                    }
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
+        "l1_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>,  Float32Type>(
+            |lhs, rhs, output, ctx| {
+                let l =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
+                let r =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
+
+                match l1_distance(l.as_slice(), r.as_slice()) {
+                    Ok(dist) => {
+                        output.push(F32::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F32::from(0.0));
+                    }
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<Float32Type>, Float32Type, _, _>(
+        "vector_norm",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<Float32Type>, Float32Type>(|val, output, _| {
+            let v = unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(val) };
+            output.push(F32::from(vector_norm(v.as_slice())));
+        }),
+    );
+
+    register_vector_cast(registry);
+    register_vector_distance(registry, "cosine_distance", cosine_distance);
+    register_vector_distance(registry, "l2_distance", l2_distance);
+    register_vector_distance(registry, "inner_product", inner_product);
+    register_vector_distance(registry, "l1_distance", l1_distance);
+    register_vector_norm(registry);
+    register_vector_arithmetic(registry, "plus", |a, b| a + b);
+    register_vector_arithmetic(registry, "minus", |a, b| a - b);
+    register_vector_arithmetic(registry, "multiply", |a, b| a * b);
+
     // embedding_vector
     // This function takes two strings as input, sends an API request to OpenAI, and returns the Float32 array of embeddings.
     // The OpenAI API key is pre-configured during the binder phase, so we rewrite this function and set the API key.
@@ -230,3 +322,289 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+fn register_vector_cast(registry: &mut FunctionRegistry) {
+    let factory = |params: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 1 || params.len() != 1 {
+            return None;
+        }
+        let dimension = params[0].get_i64()? as u64;
+        VectorType::check_dimension(dimension).ok()?;
+
+        let from_type = args_type[0].remove_nullable();
+        if !is_numeric_array(&from_type) {
+            return None;
+        }
+
+        Some(Function {
+            signature: FunctionSignature {
+                name: "to_vector".to_string(),
+                args_type: vec![from_type],
+                return_type: DataType::Vector(dimension),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| array_to_vector(&args[0], ctx, dimension as usize)),
+            },
+        })
+    };
+
+    registry.register_function_factory("to_vector", move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory("to_vector", move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+    registry.register_function_factory("try_to_vector", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_vector".to_string();
+        Some(Arc::new(f.error_to_null()))
+    });
+    registry.register_function_factory("try_to_vector", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_vector".to_string();
+        Some(Arc::new(f.error_to_null().passthrough_nullable()))
+    });
+}
+
+fn register_vector_distance(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    distance: fn(&[f32], &[f32]) -> Result<f32>,
+) {
+    let factory = move |_: &[Scalar], args_type: &[DataType]| {
+        let dimension = vector_2_arg_dimension(args_type)?;
+        Some(Function {
+            signature: FunctionSignature {
+                name: name.to_string(),
+                args_type: vec![DataType::Vector(dimension); 2],
+                return_type: DataType::Number(NumberDataType::Float32),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, ctx| {
+                    let builder = Vec::with_capacity(ctx.num_rows);
+                    eval_vector_2_arg::<Float32Type>(args, ctx, builder, |lhs, rhs, output, _| {
+                        // Both sides have the same dimension, so the distance never fails.
+                        output.push(F32::from(distance(lhs, rhs).unwrap_or_default()));
+                    })
+                }),
+            },
+        })
+    };
+
+    registry.register_function_factory(name, move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory(name, move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+}
+
+fn register_vector_norm(registry: &mut FunctionRegistry) {
+    let factory = |_: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 1 {
+            return None;
+        }
+        let dimension = *args_type[0].remove_nullable().as_vector()?;
+        Some(Function {
+            signature: FunctionSignature {
+                name: "vector_norm".to_string(),
+                args_type: vec![DataType::Vector(dimension)],
+                return_type: DataType::Number(NumberDataType::Float32),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(
+                    |args, _| match args[0].try_downcast::<VectorType>().unwrap() {
+                        ValueRef::Scalar(v) => {
+                            let norm = F32::from(vector_norm(as_f32_slice(v)));
+                            Value::Scalar(Float32Type::upcast_scalar(norm))
+                        }
+                        ValueRef::Column(col) => {
+                            let values = col
+                                .iter()
+                                .map(|v| F32::from(vector_norm(as_f32_slice(v))))
+                                .collect::<Vec<_>>();
+                            Value::Column(Float32Type::upcast_column(values.into()))
+                        }
+                    },
+                ),
+            },
+        })
+    };
+
+    registry.register_function_factory("vector_norm", move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory("vector_norm", move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+}
+
+fn register_vector_arithmetic(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    op: fn(f32, f32) -> f32,
+) {
+    let factory = move |_: &[Scalar], args_type: &[DataType]| {
+        let dimension = vector_2_arg_dimension(args_type)?;
+        Some(Function {
+            signature: FunctionSignature {
+                name: name.to_string(),
+                args_type: vec![DataType::Vector(dimension); 2],
+                return_type: DataType::Vector(dimension),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, ctx| {
+                    let builder =
+                        VectorColumnBuilder::with_capacity(dimension as usize, ctx.num_rows);
+                    eval_vector_2_arg::<VectorType>(args, ctx, builder, |lhs, rhs, output, _| {
+                        output.values.extend(
+                            lhs.iter()
+                                .zip(rhs.iter())
+                                .map(|(a, b)| F32::from(op(*a, *b))),
+                        );
+                    })
+                }),
+            },
+        })
+    };
+
+    registry.register_function_factory(name, move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory(name, move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+}
+
+/// Returns the dimension if both arguments are vectors of the same dimension,
+/// or one of them is a vector and the other is a numeric array that can be cast to it.
+fn vector_2_arg_dimension(args_type: &[DataType]) -> Option<u64> {
+    if args_type.len() != 2 {
+        return None;
+    }
+    match (
+        args_type[0].remove_nullable(),
+        args_type[1].remove_nullable(),
+    ) {
+        (DataType::Vector(lhs), DataType::Vector(rhs)) if lhs == rhs => Some(lhs),
+        (DataType::Vector(dimension), other) | (other, DataType::Vector(dimension))
+            if is_numeric_array(&other) =>
+        {
+            Some(dimension)
+        }
+        _ => None,
+    }
+}
+
+fn is_numeric_array(ty: &DataType) -> bool {
+    matches!(
+        ty,
+        DataType::Array(box DataType::Number(_) | box DataType::Decimal(_))
+    )
+}
+
+#[inline]
+fn as_f32_slice(v: &[F32]) -> &[f32] {
+    // Safety: `F32` is a `repr(transparent)` wrapper of `f32`.
+    unsafe { std::slice::from_raw_parts(v.as_ptr() as *const f32, v.len()) }
+}
+
+#[inline]
+fn vector_at<'a>(value: &'a ValueRef<VectorType>, row: usize) -> &'a [f32] {
+    match value {
+        ValueRef::Scalar(v) => as_f32_slice(v),
+        ValueRef::Column(col) => as_f32_slice(unsafe { col.index_unchecked(row) }),
+    }
+}
+
+fn eval_vector_2_arg<O: ValueType>(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    mut builder: O::ColumnBuilder,
+    func: impl Fn(&[f32], &[f32], &mut O::ColumnBuilder, &mut EvalContext),
+) -> Value<AnyType> {
+    let lhs = args[0].try_downcast::<VectorType>().unwrap();
+    let rhs = args[1].try_downcast::<VectorType>().unwrap();
+    match (&lhs, &rhs) {
+        (ValueRef::Scalar(_), ValueRef::Scalar(_)) => {
+            func(vector_at(&lhs, 0), vector_at(&rhs, 0), &mut builder, ctx);
+            Value::Scalar(O::upcast_scalar(O::build_scalar(builder)))
+        }
+        _ => {
+            for row in 0..ctx.num_rows {
+                func(
+                    vector_at(&lhs, row),
+                    vector_at(&rhs, row),
+                    &mut builder,
+                    ctx,
+                );
+            }
+            Value::Column(O::upcast_column(O::build_column(builder)))
+        }
+    }
+}
+
+fn array_to_vector(
+    arg: &ValueRef<AnyType>,
+    ctx: &mut EvalContext,
+    dimension: usize,
+) -> Value<AnyType> {
+    match arg {
+        ValueRef::Scalar(ScalarRef::Array(col)) => {
+            let values = column_to_f32(col);
+            let mut builder = VectorColumnBuilder::with_capacity(dimension, 1);
+            push_vector(&mut builder, &values, 0, ctx);
+            Value::Scalar(Scalar::Vector(builder.build_scalar()))
+        }
+        ValueRef::Column(Column::Array(box col)) => {
+            let values = column_to_f32(&col.values);
+            let mut builder = VectorColumnBuilder::with_capacity(dimension, col.len());
+            for (row, offsets) in col.offsets.windows(2).enumerate() {
+                let item = &values[offsets[0] as usize..offsets[1] as usize];
+                push_vector(&mut builder, item, row, ctx);
+            }
+            Value::Column(Column::Vector(builder.build()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn push_vector(builder: &mut VectorColumnBuilder, item: &[F32], row: usize, ctx: &mut EvalContext) {
+    if item.len() != builder.dimension {
+        ctx.set_error(
+            row,
+            format!(
+                "Vector dimension mismatch, expect {}, but got {}",
+                builder.dimension,
+                item.len()
+            ),
+        );
+        builder.push_default();
+    } else {
+        builder.push(item);
+    }
+}
+
+fn column_to_f32(col: &Column) -> Vec<F32> {
+    match col {
+        Column::Number(col) => with_number_mapped_type!(|NUM_TYPE| match col {
+            NumberColumn::NUM_TYPE(values) =>
+                values.iter().map(|v| AsPrimitive::<F32>::as_(*v)).collect(),
+        }),
+        Column::Decimal(col) => with_decimal_mapped_type!(|DECIMAL_TYPE| match col {
+            DecimalColumn::DECIMAL_TYPE(values, size) => values
+                .iter()
+                .map(|v| F32::from(v.to_float32(size.scale)))
+                .collect(),
+        }),
+        _ => unreachable!(),
+    }
+}
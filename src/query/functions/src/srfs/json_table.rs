@@ -0,0 +1,491 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::EvalContext;
+use databend_common_expression::EvaluateOptions;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionKind;
+use databend_common_expression::FunctionProperty;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use jsonb::as_str;
+use jsonb::is_null;
+use jsonb::jsonpath::parse_json_path;
+use jsonb::jsonpath::Mode as SelectorMode;
+use jsonb::jsonpath::Selector;
+use jsonb::parse_value;
+use jsonb::to_string;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::BUILTIN_FUNCTIONS;
+
+/// The plan of a `JSON_TABLE`, built by the binder and passed to the
+/// `json_table` function as its only parameter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonTableSpec {
+    /// The row path, each item it selects from the input produces a row.
+    pub path: String,
+    pub columns: Vec<JsonTableColumnSpec>,
+    /// Raise errors of the input and the row path instead of returning no rows.
+    pub error_on_error: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum JsonTableColumnSpec {
+    Ordinality {
+        name: String,
+    },
+    Path {
+        name: String,
+        data_type: DataType,
+        path: String,
+        on_empty: JsonTableBehaviorSpec,
+        on_error: JsonTableBehaviorSpec,
+    },
+    Exists {
+        name: String,
+        data_type: DataType,
+        path: String,
+    },
+    Nested {
+        path: String,
+        columns: Vec<JsonTableColumnSpec>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum JsonTableBehaviorSpec {
+    Null,
+    Error,
+    /// The default value in JSON text.
+    Default(String),
+}
+
+impl JsonTableSpec {
+    pub fn to_param(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    pub fn from_param(param: &Scalar) -> Option<Self> {
+        match param {
+            Scalar::String(spec) => serde_json::from_str(spec).ok(),
+            _ => None,
+        }
+    }
+
+    /// The names of the output columns, nested columns are flattened in order.
+    pub fn column_names(&self) -> Vec<String> {
+        fn collect(columns: &[JsonTableColumnSpec], names: &mut Vec<String>) {
+            for column in columns {
+                match column {
+                    JsonTableColumnSpec::Ordinality { name }
+                    | JsonTableColumnSpec::Path { name, .. }
+                    | JsonTableColumnSpec::Exists { name, .. } => names.push(name.clone()),
+                    JsonTableColumnSpec::Nested { columns, .. } => collect(columns, names),
+                }
+            }
+        }
+        let mut names = Vec::new();
+        collect(&self.columns, &mut names);
+        names
+    }
+
+    /// The types of the output columns, all of them are nullable.
+    pub fn column_types(&self) -> Vec<DataType> {
+        fn collect(columns: &[JsonTableColumnSpec], types: &mut Vec<DataType>) {
+            for column in columns {
+                match column {
+                    JsonTableColumnSpec::Ordinality { .. } => types.push(DataType::Nullable(
+                        Box::new(DataType::Number(NumberDataType::UInt64)),
+                    )),
+                    JsonTableColumnSpec::Path { data_type, .. }
+                    | JsonTableColumnSpec::Exists { data_type, .. } => {
+                        types.push(data_type.wrap_nullable())
+                    }
+                    JsonTableColumnSpec::Nested { columns, .. } => collect(columns, types),
+                }
+            }
+        }
+        let mut types = Vec::new();
+        collect(&self.columns, &mut types);
+        types
+    }
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.properties.insert(
+        "json_table".to_string(),
+        FunctionProperty::default().kind(FunctionKind::SRF),
+    );
+
+    registry.register_function_factory("json_table", |params, args_type| {
+        if params.len() != 1 || args_type.len() != 1 {
+            return None;
+        }
+        if !matches!(
+            args_type[0].remove_nullable(),
+            DataType::Variant | DataType::String | DataType::Null
+        ) {
+            return None;
+        }
+        let spec = JsonTableSpec::from_param(&params[0])?;
+        let return_type = DataType::Tuple(spec.column_types());
+
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_table".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: return_type.clone(),
+            },
+
+            eval: FunctionEval::SRF {
+                eval: Box::new(move |args, ctx, max_nums_per_row| {
+                    let columns = match compile_columns(&spec.columns) {
+                        Ok(columns) => columns,
+                        Err(err) => {
+                            ctx.set_error(0, err);
+                            return vec![(empty_result(&return_type), 0); ctx.num_rows];
+                        }
+                    };
+                    let row_selector = match compile_path(&spec.path) {
+                        Ok(selector) => selector,
+                        Err(err) => {
+                            ctx.set_error(0, err);
+                            return vec![(empty_result(&return_type), 0); ctx.num_rows];
+                        }
+                    };
+
+                    let input_arg = args[0].clone().to_owned();
+                    let mut results = Vec::with_capacity(ctx.num_rows);
+                    for (row, max_nums_per_row) in
+                        max_nums_per_row.iter_mut().enumerate().take(ctx.num_rows)
+                    {
+                        let input = unsafe { input_arg.index_unchecked(row) };
+                        let rows = match eval_row(&spec, &row_selector, &columns, input, ctx) {
+                            Ok(rows) => rows,
+                            Err(err) => {
+                                ctx.set_error(row, err);
+                                break;
+                            }
+                        };
+
+                        let fields = return_type
+                            .as_tuple()
+                            .unwrap()
+                            .iter()
+                            .enumerate()
+                            .map(|(i, ty)| {
+                                let mut builder = ColumnBuilder::with_capacity(ty, rows.len());
+                                for values in &rows {
+                                    builder.push(values[i].as_ref());
+                                }
+                                builder.build()
+                            })
+                            .collect::<Vec<_>>();
+                        let num_rows = rows.len();
+                        *max_nums_per_row = std::cmp::max(*max_nums_per_row, num_rows);
+                        results.push((Value::Column(Column::Tuple(fields)), num_rows));
+                    }
+                    results
+                }),
+            },
+        }))
+    });
+}
+
+enum CompiledColumn<'a> {
+    Ordinality,
+    Path {
+        data_type: &'a DataType,
+        selector: Selector<'a>,
+        on_empty: &'a JsonTableBehaviorSpec,
+        on_error: &'a JsonTableBehaviorSpec,
+    },
+    Exists {
+        data_type: &'a DataType,
+        selector: Selector<'a>,
+    },
+    Nested {
+        selector: Selector<'a>,
+        columns: Vec<CompiledColumn<'a>>,
+        width: usize,
+    },
+}
+
+fn compile_columns(columns: &[JsonTableColumnSpec]) -> Result<Vec<CompiledColumn<'_>>, String> {
+    columns
+        .iter()
+        .map(|column| {
+            Ok(match column {
+                JsonTableColumnSpec::Ordinality { .. } => CompiledColumn::Ordinality,
+                JsonTableColumnSpec::Path {
+                    data_type,
+                    path,
+                    on_empty,
+                    on_error,
+                    ..
+                } => CompiledColumn::Path {
+                    data_type,
+                    selector: compile_path(path)?,
+                    on_empty,
+                    on_error,
+                },
+                JsonTableColumnSpec::Exists {
+                    data_type, path, ..
+                } => CompiledColumn::Exists {
+                    data_type,
+                    selector: compile_path(path)?,
+                },
+                JsonTableColumnSpec::Nested { path, columns } => CompiledColumn::Nested {
+                    selector: compile_path(path)?,
+                    width: num_columns(columns),
+                    columns: compile_columns(columns)?,
+                },
+            })
+        })
+        .collect()
+}
+
+fn compile_path(path: &str) -> Result<Selector<'_>, String> {
+    match parse_json_path(path.as_bytes()) {
+        Ok(json_path) => Ok(Selector::new(json_path, SelectorMode::All)),
+        Err(_) => Err(format!("Invalid JSON Path '{path}'")),
+    }
+}
+
+fn num_columns(columns: &[JsonTableColumnSpec]) -> usize {
+    columns
+        .iter()
+        .map(|column| match column {
+            JsonTableColumnSpec::Nested { columns, .. } => num_columns(columns),
+            _ => 1,
+        })
+        .sum()
+}
+
+fn empty_result(return_type: &DataType) -> Value<AnyType> {
+    Value::Column(ColumnBuilder::with_capacity(return_type, 0).build())
+}
+
+/// Produces the rows of one input value.
+fn eval_row(
+    spec: &JsonTableSpec,
+    row_selector: &Selector,
+    columns: &[CompiledColumn],
+    input: ScalarRef,
+    ctx: &EvalContext,
+) -> Result<Vec<Vec<Scalar>>, String> {
+    let items = match input {
+        ScalarRef::Variant(val) => select(row_selector, val),
+        ScalarRef::String(val) => match parse_value(val.as_bytes()) {
+            Ok(val) => {
+                let mut buf = Vec::new();
+                val.write_to_vec(&mut buf);
+                select(row_selector, &buf)
+            }
+            Err(err) => Err(err.to_string()),
+        },
+        _ => Ok(vec![]),
+    };
+    let items = match items {
+        Ok(items) => items,
+        Err(err) if spec.error_on_error => return Err(err),
+        Err(_) => return Ok(vec![]),
+    };
+
+    let mut rows = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        rows.extend(eval_item(columns, item, i + 1, ctx)?);
+    }
+    Ok(rows)
+}
+
+/// Produces the rows of one item selected by the row path or a nested path.
+///
+/// The rows of sibling nested paths are unioned, the columns of the other
+/// siblings are NULL in them. If no nested path selects anything, a single
+/// row is produced with all the nested columns NULL.
+fn eval_item(
+    columns: &[CompiledColumn],
+    item: &[u8],
+    ordinality: usize,
+    ctx: &EvalContext,
+) -> Result<Vec<Vec<Scalar>>, String> {
+    let mut values = Vec::with_capacity(columns.len());
+    let mut nested_rows = Vec::new();
+    for column in columns {
+        match column {
+            CompiledColumn::Ordinality => {
+                values.push(Scalar::Number(NumberScalar::UInt64(ordinality as u64)));
+            }
+            CompiledColumn::Path {
+                data_type,
+                selector,
+                on_empty,
+                on_error,
+            } => {
+                let value = match select(selector, item) {
+                    Ok(matches) if matches.is_empty() => {
+                        eval_behavior(on_empty, data_type, "no item found", ctx)?
+                    }
+                    Ok(matches) if matches.len() > 1 => {
+                        eval_behavior(on_error, data_type, "more than one item found", ctx)?
+                    }
+                    Ok(matches) => match convert(&matches[0], data_type, ctx) {
+                        Ok(value) => value,
+                        Err(err) => eval_behavior(on_error, data_type, &err, ctx)?,
+                    },
+                    Err(err) => eval_behavior(on_error, data_type, &err, ctx)?,
+                };
+                values.push(value);
+            }
+            CompiledColumn::Exists {
+                data_type,
+                selector,
+            } => {
+                let exists = !select(selector, item)?.is_empty();
+                values.push(cast(
+                    Scalar::Boolean(exists),
+                    &DataType::Boolean,
+                    data_type,
+                    ctx,
+                )?);
+            }
+            CompiledColumn::Nested {
+                selector,
+                columns,
+                width,
+            } => {
+                let mut rows = Vec::new();
+                for (i, child) in select(selector, item)?.iter().enumerate() {
+                    rows.extend(eval_item(columns, child, i + 1, ctx)?);
+                }
+                // Mark the position of the nested columns, they are filled below.
+                nested_rows.push((values.len(), *width, rows));
+            }
+        }
+    }
+
+    let width = values.len() + nested_rows.iter().map(|(_, w, _)| w).sum::<usize>();
+    let expand = |nested: Option<(usize, &Vec<Scalar>)>| {
+        let mut row = Vec::with_capacity(width);
+        let mut start = 0;
+        for (j, (pos, width, _)) in nested_rows.iter().enumerate() {
+            row.extend_from_slice(&values[start..*pos]);
+            match nested {
+                Some((k, child)) if k == j => row.extend_from_slice(child),
+                _ => row.extend(std::iter::repeat(Scalar::Null).take(*width)),
+            }
+            start = *pos;
+        }
+        row.extend_from_slice(&values[start..]);
+        row
+    };
+
+    if nested_rows.iter().all(|(_, _, rows)| rows.is_empty()) {
+        return Ok(vec![expand(None)]);
+    }
+    let mut rows = Vec::new();
+    for (j, (_, _, children)) in nested_rows.iter().enumerate() {
+        for child in children {
+            rows.push(expand(Some((j, child))));
+        }
+    }
+    Ok(rows)
+}
+
+fn select(selector: &Selector, val: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let mut builder = BinaryColumnBuilder::with_capacity(0, 0);
+    selector
+        .select(val, &mut builder.data, &mut builder.offsets)
+        .map_err(|_| format!("Invalid JSONB value '0x{}'", hex::encode(val)))?;
+    Ok(builder.build().iter().map(|item| item.to_vec()).collect())
+}
+
+fn eval_behavior(
+    behavior: &JsonTableBehaviorSpec,
+    data_type: &DataType,
+    err: &str,
+    ctx: &EvalContext,
+) -> Result<Scalar, String> {
+    match behavior {
+        JsonTableBehaviorSpec::Null => Ok(Scalar::Null),
+        JsonTableBehaviorSpec::Error => Err(err.to_string()),
+        JsonTableBehaviorSpec::Default(json) => match parse_value(json.as_bytes()) {
+            Ok(val) => {
+                let mut buf = Vec::new();
+                val.write_to_vec(&mut buf);
+                convert(&buf, data_type, ctx)
+            }
+            Err(err) => Err(err.to_string()),
+        },
+    }
+}
+
+/// Converts a selected JSON value to the column type, JSON null is SQL NULL.
+fn convert(val: &[u8], data_type: &DataType, ctx: &EvalContext) -> Result<Scalar, String> {
+    if is_null(val) {
+        return Ok(Scalar::Null);
+    }
+    match data_type {
+        DataType::String => Ok(Scalar::String(match as_str(val) {
+            Some(s) => s.to_string(),
+            None => to_string(val),
+        })),
+        DataType::Variant => Ok(Scalar::Variant(val.to_vec())),
+        _ => cast(
+            Scalar::Variant(val.to_vec()),
+            &DataType::Variant,
+            data_type,
+            ctx,
+        ),
+    }
+}
+
+fn cast(
+    scalar: Scalar,
+    src_type: &DataType,
+    dest_type: &DataType,
+    ctx: &EvalContext,
+) -> Result<Scalar, String> {
+    let block = DataBlock::empty();
+    let evaluator = Evaluator::new(&block, ctx.func_ctx, &BUILTIN_FUNCTIONS);
+    evaluator
+        .run_cast(
+            None,
+            src_type,
+            dest_type,
+            Value::Scalar(scalar),
+            None,
+            &mut EvaluateOptions::default(),
+        )
+        .map_err(|err| err.message())
+        .map(|value| value.into_scalar().unwrap())
+}
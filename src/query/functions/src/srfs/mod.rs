@@ -13,11 +13,16 @@
 // limitations under the License.
 
 mod array;
+mod json_table;
 mod variant;
 
 use databend_common_expression::FunctionRegistry;
+pub use json_table::JsonTableBehaviorSpec;
+pub use json_table::JsonTableColumnSpec;
+pub use json_table::JsonTableSpec;
 
 pub fn register(registry: &mut FunctionRegistry) {
     array::register(registry);
+    json_table::register(registry);
     variant::register(registry);
 }
@@ -30,6 +30,7 @@ use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalScalar;
 use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::LargeIntDataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ConstantFolder;
@@ -532,6 +533,14 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
         databend_common_ast::ast::TypeName::Int16 => DataType::Number(NumberDataType::Int16),
         databend_common_ast::ast::TypeName::Int32 => DataType::Number(NumberDataType::Int32),
         databend_common_ast::ast::TypeName::Int64 => DataType::Number(NumberDataType::Int64),
+        databend_common_ast::ast::TypeName::Int128 => DataType::LargeInt(LargeIntDataType::Int128),
+        databend_common_ast::ast::TypeName::UInt128 => {
+            DataType::LargeInt(LargeIntDataType::UInt128)
+        }
+        databend_common_ast::ast::TypeName::Int256 => DataType::LargeInt(LargeIntDataType::Int256),
+        databend_common_ast::ast::TypeName::UInt256 => {
+            DataType::LargeInt(LargeIntDataType::UInt256)
+        }
         databend_common_ast::ast::TypeName::Float32 => DataType::Number(NumberDataType::Float32),
         databend_common_ast::ast::TypeName::Float64 => DataType::Number(NumberDataType::Float64),
         databend_common_ast::ast::TypeName::Decimal { precision, scale } => {
@@ -559,6 +568,12 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
         databend_common_ast::ast::TypeName::Variant => DataType::Variant,
         databend_common_ast::ast::TypeName::Geometry => DataType::Geometry,
         databend_common_ast::ast::TypeName::Geography => DataType::Geography,
+        databend_common_ast::ast::TypeName::Interval => DataType::Interval,
+        databend_common_ast::ast::TypeName::Time => DataType::Time,
+        databend_common_ast::ast::TypeName::TimestampTz => DataType::TimestampTz,
+        databend_common_ast::ast::TypeName::Vector(dimension) => DataType::Vector(dimension),
+        databend_common_ast::ast::TypeName::Enum(values) => DataType::Enum(values.into()),
+        databend_common_ast::ast::TypeName::Uuid => DataType::Uuid,
         databend_common_ast::ast::TypeName::NotNull(inner_type) => transform_data_type(*inner_type),
     }
 }
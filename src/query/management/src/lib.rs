@@ -19,6 +19,7 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod queue_lease;
 mod quota;
 mod role;
 mod serde;
@@ -26,6 +27,8 @@ mod setting;
 mod stage;
 pub mod udf;
 mod user;
+mod user_usage;
+mod workload_group;
 
 mod client_session;
 pub mod errors;
@@ -39,6 +42,10 @@ pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyMgr;
 pub use procedure::ProcedureMgr;
+pub use queue_lease::LeaseReleaseWatcher;
+pub use queue_lease::QueueLease;
+pub use queue_lease::QueueLeaseMgr;
+pub use queue_lease::QUEUE_LEASE_KEY_PREFIX;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -52,3 +59,7 @@ pub use stage::StageApi;
 pub use stage::StageMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use user_usage::UserDailyUsage;
+pub use user_usage::UserUsageMgr;
+pub use user_usage::USER_USAGE_KEY_PREFIX;
+pub use workload_group::WorkloadGroupMgr;
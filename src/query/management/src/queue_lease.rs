@@ -0,0 +1,312 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_store::WatchStream;
+use databend_common_meta_types::protobuf::watch_request::FilterType;
+use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::TxnCondition;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
+use futures::StreamExt;
+use rand::Rng;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub static QUEUE_LEASE_KEY_PREFIX: &str = "__fd_queries_queue";
+
+/// The min and max backoff between the retries of a conflicted transaction.
+pub(crate) const TXN_MIN_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+const TXN_MAX_RETRY_BACKOFF: Duration = Duration::from_millis(500);
+/// The interval to check the leases if the meta store can not be watched.
+const POLL_RELEASE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A lease of the cluster queue, held by a running query.
+///
+/// The lease is kept alive by heartbeat, if the node holding it is gone, the lease
+/// expires and its permits are returned to the cluster.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct QueueLease {
+    pub id: String,
+    pub node_id: String,
+    pub permits: u64,
+}
+
+/// Coordinates the permits of the queries queue between the nodes of a cluster.
+///
+/// Every admitted query holds a lease with ttl, the sum of the permits of the alive leases
+/// is the number of permits in use. All the acquisitions are serialized by a version key,
+/// so that the capacity is never exceeded by concurrent acquisitions from different nodes.
+pub struct QueueLeaseMgr {
+    metastore: MetaStore,
+    lease_ttl: Duration,
+    queue_prefix: String,
+}
+
+impl QueueLeaseMgr {
+    pub fn create(
+        metastore: MetaStore,
+        tenant: &str,
+        cluster_id: &str,
+        lease_ttl: Duration,
+    ) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while queue lease mgr create)",
+            ));
+        }
+
+        Ok(QueueLeaseMgr {
+            metastore,
+            lease_ttl,
+            queue_prefix: format!(
+                "{}/{}/{}",
+                QUEUE_LEASE_KEY_PREFIX,
+                escape_for_key(tenant)?,
+                escape_for_key(cluster_id)?
+            ),
+        })
+    }
+
+    /// Create the lease manager of a queue under the given key prefix.
+    pub(crate) fn create_with_prefix(
+        metastore: MetaStore,
+        queue_prefix: String,
+        lease_ttl: Duration,
+    ) -> Self {
+        QueueLeaseMgr {
+            metastore,
+            lease_ttl,
+            queue_prefix,
+        }
+    }
+
+    pub fn lease_ttl(&self) -> Duration {
+        self.lease_ttl
+    }
+
+    fn version_key(&self) -> String {
+        format!("{}/version", self.queue_prefix)
+    }
+
+    fn leases_prefix(&self) -> String {
+        format!("{}/leases", self.queue_prefix)
+    }
+
+    fn lease_key(&self, lease_id: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.leases_prefix(),
+            escape_for_key(lease_id)?
+        ))
+    }
+
+    /// List the alive leases of the cluster.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_leases(&self) -> Result<Vec<QueueLease>> {
+        let values = self.metastore.prefix_list_kv(&self.leases_prefix()).await?;
+
+        let mut leases = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            leases.push(serde_json::from_slice::<QueueLease>(&value.data)?);
+        }
+
+        Ok(leases)
+    }
+
+    /// The number of permits held by the alive leases of the cluster.
+    #[async_backtrace::framed]
+    pub async fn acquired_permits(&self) -> Result<u64> {
+        let leases = self.list_leases().await?;
+        Ok(leases.iter().map(|x| x.permits).sum())
+    }
+
+    /// Try to acquire a lease, returns false if there are not enough permits in the cluster.
+    ///
+    /// A lease is always granted if no permit is in use, even it requires more permits than
+    /// the capacity, otherwise such queries would never be admitted.
+    ///
+    /// The acquisition is retried with a randomized backoff if it conflicts with concurrent
+    /// acquisitions, the caller bounds the total waiting time by its own timeout.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn try_acquire(&self, lease: &QueueLease, capacity: u64) -> Result<bool> {
+        let version_key = self.version_key();
+        let lease_key = self.lease_key(&lease.id)?;
+        let value = serde_json::to_vec(lease)?;
+
+        let mut backoff = TXN_MIN_RETRY_BACKOFF;
+        loop {
+            let version_seq = match self.metastore.get_kv(&version_key).await? {
+                Some(seq_v) => seq_v.seq,
+                None => 0,
+            };
+
+            let acquired = self.acquired_permits().await?;
+            if acquired != 0 && acquired + lease.permits > capacity {
+                return Ok(false);
+            }
+
+            let txn_req = TxnRequest {
+                // no other acquisition happened since the permits are counted.
+                condition: vec![TxnCondition::eq_seq(&version_key, version_seq)],
+                if_then: vec![
+                    TxnOp::put(&version_key, vec![]),
+                    TxnOp::put_with_ttl(&lease_key, value.clone(), Some(self.lease_ttl)),
+                ],
+                else_then: vec![],
+            };
+
+            let tx_reply = self.metastore.transaction(txn_req).await?;
+            if tx_reply.success {
+                return Ok(true);
+            }
+
+            sleep_before_retry(&mut backoff).await;
+        }
+    }
+
+    /// Watch the leases of the cluster, the returned watcher is notified when a lease is
+    /// released or expired.
+    ///
+    /// The watch should be created before the acquisition is tried, so that no release
+    /// happened in between is missed.
+    #[async_backtrace::framed]
+    pub async fn watch_release(&self) -> Result<LeaseReleaseWatcher> {
+        // The embedded meta store can not be watched, fall back to polling.
+        if let MetaStore::L(_) = &self.metastore {
+            return Ok(LeaseReleaseWatcher { stream: None });
+        }
+
+        let (key, key_end) = kvapi::prefix_to_range(&format!("{}/", self.leases_prefix()))
+            .map_err(|e| ErrorCode::MetaServiceError(e.to_string()))?;
+        let stream = self
+            .metastore
+            .watch(WatchRequest {
+                key,
+                key_end: Some(key_end),
+                filter_type: FilterType::Delete.into(),
+            })
+            .await?;
+        Ok(LeaseReleaseWatcher {
+            stream: Some(stream),
+        })
+    }
+
+    /// Extend the ttl of the lease, returns false if the lease has expired.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn heartbeat(&self, lease: &QueueLease) -> Result<bool> {
+        let lease_key = self.lease_key(&lease.id)?;
+        let meta = Some(MetaSpec::new_ttl(self.lease_ttl));
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(
+                &lease_key,
+                MatchSeq::GE(1),
+                Operation::AsIs,
+                meta,
+            ))
+            .await?;
+
+        Ok(reply.result.is_some())
+    }
+
+    /// Put the lease back after it has expired, e.g. the heartbeat was delayed.
+    ///
+    /// The query holding the lease is already running, so the lease is put regardless of
+    /// the capacity.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn renew(&self, lease: &QueueLease) -> Result<()> {
+        let lease_key = self.lease_key(&lease.id)?;
+        let value = serde_json::to_vec(lease)?;
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: vec![
+                // make the concurrent acquisitions count the permits again.
+                TxnOp::put(&self.version_key(), vec![]),
+                TxnOp::put_with_ttl(&lease_key, value, Some(self.lease_ttl)),
+            ],
+            else_then: vec![],
+        };
+        self.metastore.transaction(txn_req).await?;
+        Ok(())
+    }
+
+    /// Release the lease, the permits are returned to the cluster immediately.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn release(&self, lease_id: &str) -> Result<()> {
+        let lease_key = self.lease_key(lease_id)?;
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &lease_key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Notified when a lease of the cluster is released or expired.
+pub struct LeaseReleaseWatcher {
+    stream: Option<WatchStream>,
+}
+
+impl LeaseReleaseWatcher {
+    /// Wait until a lease is released, or the timeout elapses.
+    #[async_backtrace::framed]
+    pub async fn wait(self, timeout: Duration) {
+        let Some(mut stream) = self.stream else {
+            tokio::time::sleep(std::cmp::min(timeout, POLL_RELEASE_INTERVAL)).await;
+            return;
+        };
+
+        let _ = tokio::time::timeout(timeout, async move {
+            while let Some(Ok(resp)) = stream.next().await {
+                if let Some(event) = resp.event {
+                    if event.current.is_none() {
+                        break;
+                    }
+                }
+            }
+        })
+        .await;
+    }
+}
+
+/// Sleep a randomized backoff before retrying a conflicted transaction, and double the
+/// backoff for the next retry.
+pub(crate) async fn sleep_before_retry(backoff: &mut Duration) {
+    // Randomize the backoff, so that the conflicted nodes don't retry in lockstep.
+    let jitter = rand::thread_rng().gen_range(0..=backoff.as_millis() as u64);
+    tokio::time::sleep(*backoff + Duration::from_millis(jitter)).await;
+    *backoff = std::cmp::min(*backoff * 2, TXN_MAX_RETRY_BACKOFF);
+}
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::queue_lease::sleep_before_retry;
+use crate::queue_lease::TXN_MIN_RETRY_BACKOFF;
+use crate::QueueLeaseMgr;
+
+pub static USER_USAGE_KEY_PREFIX: &str = "__fd_user_usage";
+
+/// The daily usage counters are kept a little longer than a day, so that the usage of
+/// the whole day is still readable around the midnight.
+const DAILY_USAGE_TTL: Duration = Duration::from_secs(2 * 24 * 60 * 60);
+
+/// The resource usage of a user in a day.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(default)]
+pub struct UserDailyUsage {
+    pub queries: u64,
+    pub scan_bytes: u64,
+}
+
+/// The usage counters of a user shared by all the nodes of the tenant, used to enforce
+/// the limits of the user cluster-wide.
+pub struct UserUsageMgr {
+    metastore: MetaStore,
+    user_prefix: String,
+}
+
+impl UserUsageMgr {
+    pub fn create(metastore: MetaStore, tenant: &Tenant, user: &str) -> Result<Self> {
+        Ok(UserUsageMgr {
+            metastore,
+            user_prefix: format!(
+                "{}/{}/{}",
+                USER_USAGE_KEY_PREFIX,
+                escape_for_key(tenant.tenant_name())?,
+                escape_for_key(user)?
+            ),
+        })
+    }
+
+    fn daily_usage_key(&self, day: &str) -> Result<String> {
+        Ok(format!(
+            "{}/daily/{}",
+            self.user_prefix,
+            escape_for_key(day)?
+        ))
+    }
+
+    /// The leases of the running queries of the user, each query holds a lease with
+    /// one permit.
+    pub fn running_queries(&self, lease_ttl: Duration) -> QueueLeaseMgr {
+        QueueLeaseMgr::create_with_prefix(
+            self.metastore.clone(),
+            format!("{}/queries", self.user_prefix),
+            lease_ttl,
+        )
+    }
+
+    /// Get the usage of the user in the day, the day is formatted as `YYYYMMDD`.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_daily_usage(&self, day: &str) -> Result<UserDailyUsage> {
+        let key = self.daily_usage_key(day)?;
+        match self.metastore.get_kv(&key).await? {
+            Some(seq_v) => Ok(serde_json::from_slice(&seq_v.data)?),
+            None => Ok(UserDailyUsage::default()),
+        }
+    }
+
+    /// Add a finished query to the usage of the user in the day, returns the usage after
+    /// the addition.
+    ///
+    /// The update is retried with a randomized backoff until it does not conflict with the
+    /// concurrent updates of the other queries of the user.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn add_daily_usage(&self, day: &str, scan_bytes: u64) -> Result<UserDailyUsage> {
+        let key = self.daily_usage_key(day)?;
+
+        let mut backoff = TXN_MIN_RETRY_BACKOFF;
+        loop {
+            let (seq, mut usage) = match self.metastore.get_kv(&key).await? {
+                Some(seq_v) => (
+                    seq_v.seq,
+                    serde_json::from_slice::<UserDailyUsage>(&seq_v.data)?,
+                ),
+                None => (0, UserDailyUsage::default()),
+            };
+
+            usage.queries += 1;
+            usage.scan_bytes = usage.scan_bytes.saturating_add(scan_bytes);
+
+            let reply = self
+                .metastore
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::Exact(seq),
+                    Operation::Update(serde_json::to_vec(&usage)?),
+                    Some(MetaSpec::new_ttl(DAILY_USAGE_TTL)),
+                ))
+                .await?;
+
+            if reply.is_changed() {
+                return Ok(usage);
+            }
+
+            sleep_before_retry(&mut backoff).await;
+        }
+    }
+}
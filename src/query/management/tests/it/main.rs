@@ -15,9 +15,11 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod queue_lease;
 mod quota;
 mod role;
 mod setting;
 mod stage;
 mod udf;
 mod user;
+mod user_usage;
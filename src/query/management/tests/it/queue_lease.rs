@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::seq_value::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_acquire_lease() -> Result<()> {
+    let now_ms = SeqV::<()>::now_ms();
+    let (kv_api, lease_mgr) = new_queue_lease_mgr().await?;
+
+    let lease = create_test_lease("query_1", 1);
+    assert!(lease_mgr.try_acquire(&lease, 2).await?);
+
+    let value = kv_api
+        .get_kv("__fd_queries_queue/test%2dtenant%2did/test%2dcluster%2did/leases/query_1")
+        .await?;
+
+    match value {
+        Some(SeqV { meta, data, .. }) => {
+            assert!(meta.unwrap().get_expire_at_ms().unwrap() - now_ms >= 59_000);
+            assert_eq!(data, serde_json::to_vec(&lease)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    assert_eq!(lease_mgr.acquired_permits().await?, 1);
+    assert_eq!(lease_mgr.list_leases().await?, vec![lease]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_acquire_lease_exceed_capacity() -> Result<()> {
+    let (_, lease_mgr) = new_queue_lease_mgr().await?;
+
+    // The first lease is always granted, even it exceeds the capacity.
+    assert!(
+        lease_mgr
+            .try_acquire(&create_test_lease("query_1", 3), 2)
+            .await?
+    );
+    assert!(
+        !lease_mgr
+            .try_acquire(&create_test_lease("query_2", 1), 2)
+            .await?
+    );
+
+    lease_mgr.release("query_1").await?;
+    assert_eq!(lease_mgr.acquired_permits().await?, 0);
+
+    assert!(
+        lease_mgr
+            .try_acquire(&create_test_lease("query_2", 1), 2)
+            .await?
+    );
+    assert!(
+        lease_mgr
+            .try_acquire(&create_test_lease("query_3", 1), 2)
+            .await?
+    );
+    assert!(
+        !lease_mgr
+            .try_acquire(&create_test_lease("query_4", 1), 2)
+            .await?
+    );
+    assert_eq!(lease_mgr.acquired_permits().await?, 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_heartbeat_lease() -> Result<()> {
+    let (kv_api, lease_mgr) = new_queue_lease_mgr().await?;
+
+    let lease = create_test_lease("query_1", 1);
+    assert!(!lease_mgr.heartbeat(&lease).await?);
+    assert!(lease_mgr.try_acquire(&lease, 1).await?);
+
+    let now_ms = SeqV::<()>::now_ms();
+    assert!(lease_mgr.heartbeat(&lease).await?);
+
+    let value = kv_api
+        .get_kv("__fd_queries_queue/test%2dtenant%2did/test%2dcluster%2did/leases/query_1")
+        .await?;
+    assert!(value.unwrap().meta.unwrap().get_expire_at_ms().unwrap() - now_ms >= 59_000);
+
+    lease_mgr.release(&lease.id).await?;
+    assert!(!lease_mgr.heartbeat(&lease).await?);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_renew_lease() -> Result<()> {
+    let (_, lease_mgr) = new_queue_lease_mgr().await?;
+
+    let lease = create_test_lease("query_1", 1);
+    assert!(lease_mgr.try_acquire(&lease, 1).await?);
+
+    // The lease expired, e.g. the heartbeat was delayed.
+    lease_mgr.release(&lease.id).await?;
+    assert!(!lease_mgr.heartbeat(&lease).await?);
+
+    // The lease is put back regardless of the capacity.
+    assert!(
+        lease_mgr
+            .try_acquire(&create_test_lease("query_2", 1), 1)
+            .await?
+    );
+    lease_mgr.renew(&lease).await?;
+    assert!(lease_mgr.heartbeat(&lease).await?);
+    assert_eq!(lease_mgr.acquired_permits().await?, 2);
+    Ok(())
+}
+
+fn create_test_lease(id: &str, permits: u64) -> QueueLease {
+    QueueLease {
+        id: id.to_string(),
+        node_id: String::from("test_node"),
+        permits,
+    }
+}
+
+async fn new_queue_lease_mgr() -> Result<(MetaStore, QueueLeaseMgr)> {
+    let test_api = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    let lease_mgr = QueueLeaseMgr::create(
+        test_api.clone(),
+        "test-tenant-id",
+        "test-cluster-id",
+        Duration::from_secs(60),
+    )?;
+    Ok((test_api, lease_mgr))
+}
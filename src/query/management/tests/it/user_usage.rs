@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_store::MetaStore;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_daily_usage() -> Result<()> {
+    let (kv_api, usage_mgr) = new_user_usage_mgr().await?;
+
+    assert_eq!(
+        usage_mgr.get_daily_usage("20241019").await?,
+        UserDailyUsage::default()
+    );
+
+    usage_mgr.add_daily_usage("20241019", 100).await?;
+    let usage = usage_mgr.add_daily_usage("20241019", 50).await?;
+    assert_eq!(usage, UserDailyUsage {
+        queries: 2,
+        scan_bytes: 150,
+    });
+    assert_eq!(usage_mgr.get_daily_usage("20241019").await?, usage);
+
+    // The usage of another day is counted separately.
+    assert_eq!(
+        usage_mgr.get_daily_usage("20241020").await?,
+        UserDailyUsage::default()
+    );
+
+    let value = kv_api
+        .get_kv("__fd_user_usage/test%2dtenant/test%2duser/daily/20241019")
+        .await?;
+    assert!(value.unwrap().meta.unwrap().get_expire_at_ms().is_some());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_add_daily_usage_concurrently() -> Result<()> {
+    let (_kv_api, usage_mgr) = new_user_usage_mgr().await?;
+    let usage_mgr = Arc::new(usage_mgr);
+
+    // The conflicted updates are retried until all of them succeed.
+    let handles = (0..32)
+        .map(|_| {
+            let usage_mgr = usage_mgr.clone();
+            tokio::spawn(async move { usage_mgr.add_daily_usage("20241019", 10).await })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.await.unwrap()?;
+    }
+
+    assert_eq!(
+        usage_mgr.get_daily_usage("20241019").await?,
+        UserDailyUsage {
+            queries: 32,
+            scan_bytes: 320,
+        }
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_running_queries() -> Result<()> {
+    let (kv_api, usage_mgr) = new_user_usage_mgr().await?;
+    let running_queries = usage_mgr.running_queries(Duration::from_secs(60));
+
+    let lease = |id: &str| QueueLease {
+        id: id.to_string(),
+        node_id: String::from("test_node"),
+        permits: 1,
+    };
+
+    assert!(running_queries.try_acquire(&lease("query_1"), 1).await?);
+    assert!(!running_queries.try_acquire(&lease("query_2"), 1).await?);
+
+    let value = kv_api
+        .get_kv("__fd_user_usage/test%2dtenant/test%2duser/queries/leases/query_1")
+        .await?;
+    assert!(value.is_some());
+
+    running_queries.release("query_1").await?;
+    assert!(running_queries.try_acquire(&lease("query_2"), 1).await?);
+    Ok(())
+}
+
+async fn new_user_usage_mgr() -> Result<(MetaStore, UserUsageMgr)> {
+    let test_api = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    let usage_mgr = UserUsageMgr::create(
+        test_api.clone(),
+        &Tenant::new_literal("test-tenant"),
+        "test-user",
+    )?;
+    Ok((test_api, usage_mgr))
+}
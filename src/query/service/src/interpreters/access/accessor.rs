@@ -16,6 +16,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_common_sql::plans::Insert;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::InsertValue;
 
 use crate::interpreters::access::PrivilegeAccess;
 use crate::interpreters::ManagementModeAccess;
@@ -51,4 +55,28 @@ impl Accessor {
         }
         Ok(())
     }
+
+    /// Check the access permission of appending to a table, for the interpreters which
+    /// write into a table without a plan, e.g. the ingestion of Flight SQL and the streaming load.
+    ///
+    /// It's checked as an `INSERT`, which also needs the `DELETE` privilege if `overwrite`.
+    #[async_backtrace::framed]
+    pub async fn check_insert(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        overwrite: bool,
+    ) -> Result<()> {
+        let plan = Plan::Insert(Box::new(Insert {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            schema: Arc::new(TableSchema::empty()),
+            overwrite,
+            source: InsertInputSource::Values(InsertValue::Values { rows: vec![] }),
+            table_info: None,
+        }));
+        self.check(&plan).await
+    }
 }
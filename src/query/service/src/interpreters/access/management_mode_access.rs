@@ -98,6 +98,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::ShowRoles(_)
                 | Plan::CreateRole(_)
                 | Plan::DropRole(_)
+                | Plan::AlterRole(_)
 
                 // Privilege.
                 | Plan::GrantPriv(_)
@@ -115,6 +116,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -1156,6 +1156,15 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::AlterRole(_) => {
+                self.validate_access(
+                    &GrantObject::Global,
+                    UserPrivilegeType::CreateRole,
+                    false,
+                    false,
+                )
+                    .await?;
+            }
             Plan::DropRole(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -1215,6 +1224,9 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -1274,6 +1286,8 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::Commit => {}
             Plan::Abort => {}
+            // The privileges of the prepared statement are checked when it's executed.
+            Plan::Prepare(_) | Plan::Deallocate(_) => {}
         }
 
         Ok(())
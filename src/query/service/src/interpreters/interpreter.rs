@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Statement;
 use databend_common_base::base::short_sql;
@@ -56,6 +57,7 @@ use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::ServiceQueryExecutor;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
+use crate::sessions::UserLimitsGuard;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -104,6 +106,9 @@ pub trait Interpreter: Sync + Send {
             );
             return Err(err);
         }
+        // The limits of the user apply to the queries of all the handlers.
+        UserLimitsGuard::attach(&ctx).await?;
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(err) => {
@@ -142,10 +147,11 @@ pub trait Interpreter: Sync + Send {
             let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
 
             ctx.set_executor(pulling_executor.get_inner())?;
-            Ok(Box::pin(ProgressStream::try_create(
+            let stream = Box::pin(ProgressStream::try_create(
                 Box::pin(PullingExecutorStream::create(pulling_executor)?),
                 ctx.get_result_progress(),
-            )?))
+            )?);
+            Ok(UserLimitsGuard::limit_result_rows(&ctx, stream))
         }
     }
 
@@ -203,17 +209,29 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
 ///
 /// This function is used to plan the SQL. If an error occurs, we will log the query start and finished.
 pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(Plan, PlanExtras)> {
+    interpreter_plan_sql_with_params(ctx, sql, None).await
+}
+
+/// Plan the SQL like `interpreter_plan_sql`, with the values bound to its `?` placeholders.
+pub async fn interpreter_plan_sql_with_params(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    params: Option<&[Expr]>,
+) -> Result<(Plan, PlanExtras)> {
     let mut planner = Planner::new_with_sample_executor(
         ctx.clone(),
         Arc::new(ServiceQueryExecutor::new(ctx.clone())),
     );
-    let result = planner.plan_sql(sql).await;
+    let result = match params {
+        Some(params) => planner.plan_sql_with_params(sql, params).await,
+        None => planner.plan_sql(sql).await,
+    };
     let short_sql = short_sql(
         sql.to_string(),
         ctx.get_settings().get_short_sql_max_length()?,
     );
     let mut stmt = if let Ok((_, extras)) = &result {
-        Some(extras.statement.clone())
+        extras.statement.clone()
     } else {
         // Only log if there's an error
         ctx.attach_query_str(QueryKind::Unknown, short_sql.to_string());
@@ -227,6 +245,30 @@ pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(
     result
 }
 
+/// Plan a serialized Substrait plan like `interpreter_plan_sql`.
+pub async fn interpreter_plan_substrait(
+    ctx: Arc<QueryContext>,
+    plan: &[u8],
+) -> Result<(Plan, PlanExtras)> {
+    let mut planner = Planner::new_with_sample_executor(
+        ctx.clone(),
+        Arc::new(ServiceQueryExecutor::new(ctx.clone())),
+    );
+    let result = planner.plan_substrait(plan).await;
+    if let Err(err) = &result {
+        // Only log if there's an error
+        ctx.attach_query_str(QueryKind::Unknown, String::new());
+        log_query_start(&ctx);
+        log_query_finished(&ctx, Some(err.clone()), false);
+    }
+
+    // A Substrait plan has no SQL text, hash the plan itself.
+    let query_hash = format!("{:x}", Md5::digest(plan));
+    ctx.attach_query_hash(query_hash.clone(), query_hash);
+
+    result
+}
+
 fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
@@ -241,7 +241,8 @@ impl Interpreter for ExplainInterpreter {
 
             ExplainKind::Ast(display_string)
             | ExplainKind::Syntax(display_string)
-            | ExplainKind::Memo(display_string) => {
+            | ExplainKind::Memo(display_string)
+            | ExplainKind::Substrait(display_string) => {
                 let line_split_result: Vec<&str> = display_string.lines().collect();
                 let column = StringType::from_data(line_split_result);
                 vec![DataBlock::new_from_columns(vec![column])]
@@ -386,6 +386,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -516,6 +520,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -611,6 +626,12 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            // Prepared statements
+            Plan::Prepare(p) => Ok(Arc::new(PrepareInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::Deallocate(p) => Ok(Arc::new(DeallocateInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             // Plan::ShowCreateProcedure(_) => {}
             //
             // Plan::RenameProcedure(p) => Ok(Arc::new(RenameProcedureInterpreter::try_create(
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_pipeline_sources::AsyncStreamSource;
+use databend_common_sql::executor::physical_plans::MutationKind;
+use parking_lot::Mutex;
+
+use crate::interpreters::access::Accessor;
+use crate::interpreters::HookOperator;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Append a stream of data blocks to a table, which is used by the bulk ingestion of Flight SQL
+/// and the inserts of the ClickHouse native protocol.
+///
+/// The blocks are committed to the table at once when the stream is finished,
+/// so the table is unchanged if the ingestion fails.
+pub struct IngestInterpreter {
+    ctx: Arc<QueryContext>,
+    catalog: String,
+    database: String,
+    table: String,
+    source_schema: DataSchemaRef,
+    stream: Mutex<Option<SendableDataBlockStream>>,
+    overwrite: bool,
+}
+
+impl IngestInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        catalog: String,
+        database: String,
+        table: String,
+        source_schema: DataSchemaRef,
+        stream: SendableDataBlockStream,
+        overwrite: bool,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(IngestInterpreter {
+            ctx,
+            catalog,
+            database,
+            table,
+            source_schema,
+            stream: Mutex::new(Some(stream)),
+            overwrite,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for IngestInterpreter {
+    fn name(&self) -> &str {
+        "IngestInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // Not planned by the `InterpreterFactory`, so the access is checked here.
+        Accessor::create(self.ctx.clone())
+            .check_insert(&self.catalog, &self.database, &self.table, self.overwrite)
+            .await?;
+
+        let table = self
+            .ctx
+            .get_table(&self.catalog, &self.database, &self.table)
+            .await?;
+        table.check_mutable()?;
+
+        // The source columns are matched with the table columns by name,
+        // the missing columns are filled with the default values.
+        let table_schema = table.schema().remove_computed_fields();
+        let insert_fields = self
+            .source_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let table_field = table_schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.name() == field.name())
+                    .or_else(|| {
+                        table_schema
+                            .fields()
+                            .iter()
+                            .find(|f| f.name().eq_ignore_ascii_case(field.name()))
+                    })
+                    .ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "column {} not found in table {}.{}",
+                            field.name(),
+                            self.database,
+                            self.table
+                        ))
+                    })?;
+                Ok(DataField::from(table_field))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let insert_schema = DataSchemaRefExt::create(insert_fields);
+
+        let mut build_res = PipelineBuildResult::create();
+        build_res.main_pipeline.add_source(
+            |output| {
+                let stream = self.stream.lock().take();
+                AsyncStreamSource::<true>::create(self.ctx.clone(), stream, output)
+            },
+            1,
+        )?;
+
+        if self.source_schema != insert_schema {
+            let func_ctx = self.ctx.get_function_context()?;
+            build_res.main_pipeline.try_add_transformer(|| {
+                TransformCastSchema::try_new(
+                    self.source_schema.clone(),
+                    insert_schema.clone(),
+                    func_ctx.clone(),
+                )
+            })?;
+        }
+
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            table.clone(),
+            insert_schema,
+            None,
+            vec![],
+            self.overwrite,
+            unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+        )?;
+
+        //  Execute the hook operator.
+        {
+            let hook_operator = HookOperator::create(
+                self.ctx.clone(),
+                self.catalog.clone(),
+                self.database.clone(),
+                self.table.clone(),
+                MutationKind::Insert,
+                LockTableOption::LockNoRetry,
+            );
+            hook_operator.execute(&mut build_res.main_pipeline).await;
+        }
+
+        Ok(build_res)
+    }
+}
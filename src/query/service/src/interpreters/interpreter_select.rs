@@ -22,11 +22,10 @@ use databend_common_expression::infer_table_schema;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
-use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
-use databend_common_meta_store::MetaStore;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
@@ -162,13 +161,11 @@ impl SelectInterpreter {
         Ok(build_res)
     }
 
-    /// Add pipelines for writing query result cache.
+    /// Add pipelines for writing query result cache or the persisted query result,
+    /// the sink is created by `create_sink` with its input ports.
     fn add_result_cache(
-        &self,
-        key: &str,
-        schema: TableSchemaRef,
         pipeline: &mut Pipeline,
-        kv_store: Arc<MetaStore>,
+        create_sink: impl FnOnce(Vec<Arc<InputPort>>) -> Result<ProcessorPtr>,
     ) -> Result<()> {
         //              ┌─────────┐ 1  ┌─────────┐ 1
         //              │         ├───►│         ├───►Dummy───►Downstream
@@ -216,13 +213,7 @@ impl SelectInterpreter {
             sink_inputs.push(InputPort::create());
         }
         items.push(PipeItem::create(
-            WriteResultCacheSink::try_create(
-                self.ctx.clone(),
-                key,
-                schema,
-                sink_inputs.clone(),
-                kv_store,
-            )?,
+            create_sink(sink_inputs.clone())?,
             sink_inputs,
             vec![],
         ));
@@ -290,6 +281,23 @@ impl Interpreter for SelectInterpreter {
 
         info!("Query physical plan: \n{}", query_plan);
 
+        // The complete result of the query is persisted and can be retrieved by the query id later,
+        // it is not combined with the result cache to make sure the result is always written.
+        if self.ctx.get_settings().get_persist_query_result()? && !self.ignore_result {
+            let mut build_res = self.build_pipeline(physical_plan).await?;
+            let schema = infer_table_schema(&self.bind_context.output_schema())?;
+            let kv_store = UserApiProvider::instance().get_meta_store_client();
+            Self::add_result_cache(&mut build_res.main_pipeline, |inputs| {
+                WriteResultCacheSink::try_create_persisted(
+                    self.ctx.clone(),
+                    schema,
+                    inputs,
+                    kv_store,
+                )
+            })?;
+            return Ok(build_res);
+        }
+
         if self.ctx.get_settings().get_enable_query_result_cache()?
             && self.ctx.get_cacheable()
             && self.formatted_ast.is_some()
@@ -340,7 +348,15 @@ impl Interpreter for SelectInterpreter {
                     let mut build_res = self.build_pipeline(physical_plan).await?;
                     // 2.2 If not found result in cache, add pipelines to write the result to cache.
                     let schema = infer_table_schema(&self.bind_context.output_schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
+                    Self::add_result_cache(&mut build_res.main_pipeline, |inputs| {
+                        WriteResultCacheSink::try_create(
+                            self.ctx.clone(),
+                            &key,
+                            schema,
+                            inputs,
+                            kv_store,
+                        )
+                    })?;
                     return Ok(build_res);
                 }
                 Err(e) => {
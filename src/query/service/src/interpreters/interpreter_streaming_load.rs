@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_sql::field_default_value;
+use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::BytesStream;
+use databend_common_storages_stage::StageTable;
+use parking_lot::Mutex;
+
+use crate::interpreters::access::Accessor;
+use crate::interpreters::HookOperator;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Parse a file from a stream of bytes and append the rows to a table,
+/// which is used by the streaming load of the HTTP handler.
+///
+/// The rows are parsed as the bytes arrive, with the file format and `ON_ERROR`
+/// of `stage_info`. Like [`super::IngestInterpreter`], the table is committed once
+/// the stream is finished.
+pub struct StreamingLoadInterpreter {
+    ctx: Arc<QueryContext>,
+    catalog: String,
+    database: String,
+    table: String,
+    stage_info: StageInfo,
+    stream: Mutex<Option<BytesStream>>,
+}
+
+impl StreamingLoadInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        catalog: String,
+        database: String,
+        table: String,
+        stage_info: StageInfo,
+        stream: BytesStream,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(StreamingLoadInterpreter {
+            ctx,
+            catalog,
+            database,
+            table,
+            stage_info,
+            stream: Mutex::new(Some(stream)),
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for StreamingLoadInterpreter {
+    fn name(&self) -> &str {
+        "StreamingLoadInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // Not planned by the `InterpreterFactory`, so the access is checked here.
+        Accessor::create(self.ctx.clone())
+            .check_insert(&self.catalog, &self.database, &self.table, false)
+            .await?;
+
+        let table = self
+            .ctx
+            .get_table(&self.catalog, &self.database, &self.table)
+            .await?;
+        table.check_mutable()?;
+
+        let table_schema = Arc::new(table.schema().remove_computed_fields());
+        // The empty fields are filled with the default values of the columns.
+        let default_values = table_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let scalar = field_default_value(self.ctx.clone(), field)?;
+                Ok(RemoteExpr::Constant {
+                    span: None,
+                    scalar,
+                    data_type: field.data_type().into(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let stage_table_info = StageTableInfo {
+            schema: table_schema.clone(),
+            default_values: Some(default_values),
+            files_info: StageFilesInfo {
+                path: format!("streaming load into {}.{}", self.database, self.table),
+                files: None,
+                pattern: None,
+            },
+            stage_info: self.stage_info.clone(),
+            files_to_copy: None,
+            duplicated_files_detected: vec![],
+            is_select: false,
+        };
+
+        self.ctx
+            .set_read_block_thresholds(table.get_block_thresholds());
+        let stream = self
+            .stream
+            .lock()
+            .take()
+            .ok_or_else(|| ErrorCode::Internal("The stream has been consumed."))?;
+
+        let mut build_res = PipelineBuildResult::create();
+        StageTable::read_stream(
+            self.ctx.clone(),
+            &stage_table_info,
+            stream,
+            &mut build_res.main_pipeline,
+        )?;
+
+        let insert_schema = DataSchemaRefExt::create(
+            table_schema
+                .fields()
+                .iter()
+                .map(DataField::from)
+                .collect::<Vec<_>>(),
+        );
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            table.clone(),
+            insert_schema,
+            None,
+            vec![],
+            false,
+            unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+        )?;
+
+        //  Execute the hook operator.
+        {
+            let hook_operator = HookOperator::create(
+                self.ctx.clone(),
+                self.catalog.clone(),
+                self.database.clone(),
+                self.table.clone(),
+                MutationKind::Insert,
+                LockTableOption::LockNoRetry,
+            );
+            hook_operator.execute(&mut build_res.main_pipeline).await;
+        }
+
+        Ok(build_res)
+    }
+}
@@ -48,6 +48,7 @@ mod interpreter_file_format_show;
 mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_index_refresh;
+mod interpreter_ingest;
 mod interpreter_insert;
 mod interpreter_insert_multi_table;
 mod interpreter_kill;
@@ -69,6 +70,7 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_prepare;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -76,6 +78,7 @@ mod interpreter_procedure_call;
 mod interpreter_procedure_create;
 mod interpreter_procedure_drop;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -90,6 +93,7 @@ mod interpreter_set;
 mod interpreter_set_priority;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
+mod interpreter_streaming_load;
 mod interpreter_system_action;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
@@ -144,12 +148,18 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 mod util;
 
+pub use access::Accessor;
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
 pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
+pub use interpreter::interpreter_plan_sql_with_params;
+pub use interpreter::interpreter_plan_substrait;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
@@ -167,6 +177,7 @@ pub use interpreter_execute_immediate::ExecuteImmediateInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
+pub use interpreter_ingest::IngestInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
 pub use interpreter_kill::KillInterpreter;
@@ -184,9 +195,12 @@ pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_prepare::DeallocateInterpreter;
+pub use interpreter_prepare::PrepareInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
@@ -200,6 +214,7 @@ pub use interpreter_set::SetInterpreter;
 pub use interpreter_set_priority::SetPriorityInterpreter;
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
+pub use interpreter_streaming_load::StreamingLoadInterpreter;
 pub use interpreter_system_action::SystemActionInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
@@ -242,3 +257,6 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
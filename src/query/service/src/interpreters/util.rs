@@ -99,6 +99,11 @@ impl Client for ScriptClient {
             .get_current_session()
             .create_query_context()
             .await?;
+        // The statements of the script are a part of the script query, which holds the
+        // limits of the user.
+        if let Some(guard) = self.ctx.get_user_limits_guard() {
+            ctx.set_user_limits_guard(guard);
+        }
 
         let mut planner = Planner::new(ctx.clone());
         let (plan, _) = planner.plan_sql(query).await?;
@@ -136,15 +136,13 @@ impl SessionExecutor {
         let context = session.create_query_context().await?;
         let mut planner = Planner::new(context.clone());
         let (plan, extras) = planner.plan_sql(sql).await?;
+        let stmt = extras
+            .statement
+            .ok_or_else(|| ErrorCode::Internal("planned SQL without a statement"))?;
 
         let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
         let ctx = context.clone();
-        Ok((
-            interpreter.execute(context).await?,
-            ctx,
-            plan,
-            extras.statement,
-        ))
+        Ok((interpreter.execute(context).await?, ctx, plan, stmt))
     }
 
     pub async fn handle(&mut self, query_sql: &str) {
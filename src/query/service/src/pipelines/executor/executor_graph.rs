@@ -87,6 +87,8 @@ pub(crate) struct Node {
     pub(crate) processor: ProcessorPtr,
 
     pub(crate) tracking_payload: TrackingPayload,
+    /// Span of the plan this processor belongs to, shared by every processor of the plan.
+    pub(crate) plan_span: Option<Arc<Span>>,
     updated_list: Arc<UpdateList>,
     inputs_port: Vec<Arc<InputPort>>,
     outputs_port: Vec<Arc<OutputPort>>,
@@ -96,6 +98,7 @@ impl Node {
     pub fn create(
         pid: usize,
         scope: Option<PlanScope>,
+        plan_span: Option<Arc<Span>>,
         processor: &ProcessorPtr,
         inputs_port: &[Arc<InputPort>],
         outputs_port: &[Arc<OutputPort>],
@@ -145,6 +148,7 @@ impl Node {
             inputs_port: inputs_port.to_vec(),
             outputs_port: outputs_port.to_vec(),
             tracking_payload,
+            plan_span,
         })
     }
 
@@ -196,7 +200,8 @@ impl ExecutingGraph {
         finish_condvar_notify: Option<Arc<(Mutex<bool>, Condvar)>>,
     ) -> Result<ExecutingGraph> {
         let mut graph = StableGraph::new();
-        Self::init_graph(&mut pipeline, &mut graph);
+        let mut plan_spans = HashMap::new();
+        Self::init_graph(&mut pipeline, &mut graph, &mut plan_spans);
         Ok(ExecutingGraph {
             graph,
             finished_nodes: AtomicUsize::new(0),
@@ -217,9 +222,10 @@ impl ExecutingGraph {
         finish_condvar_notify: Option<Arc<(Mutex<bool>, Condvar)>>,
     ) -> Result<ExecutingGraph> {
         let mut graph = StableGraph::new();
+        let mut plan_spans = HashMap::new();
 
         for pipeline in &mut pipelines {
-            Self::init_graph(pipeline, &mut graph);
+            Self::init_graph(pipeline, &mut graph, &mut plan_spans);
         }
 
         Ok(ExecutingGraph {
@@ -235,7 +241,11 @@ impl ExecutingGraph {
         })
     }
 
-    fn init_graph(pipeline: &mut Pipeline, graph: &mut StableGraph<Arc<Node>, EdgeInfo>) {
+    fn init_graph(
+        pipeline: &mut Pipeline,
+        graph: &mut StableGraph<Arc<Node>, EdgeInfo>,
+        plan_spans: &mut HashMap<u32, Arc<Span>>,
+    ) {
         #[derive(Debug)]
         struct Edge {
             source_port: usize,
@@ -254,11 +264,28 @@ impl ExecutingGraph {
             let mut edge_index = 0;
             let mut pipe_edges = Vec::with_capacity(pipe.output_length);
 
+            let plan_span = pipe.scope.as_ref().map(|scope| {
+                plan_spans
+                    .entry(scope.id)
+                    .or_insert_with(|| {
+                        let span = Span::enter_with_local_parent(scope.name.clone())
+                            .with_properties(|| {
+                                [
+                                    ("plan-id", scope.id.to_string()),
+                                    ("plan-title", scope.title.to_string()),
+                                ]
+                            });
+                        Arc::new(span)
+                    })
+                    .clone()
+            });
+
             for item in &pipe.items {
                 let pid = graph.node_count();
                 let node = Node::create(
                     pid,
                     pipe.scope.clone(),
+                    plan_span.clone(),
                     &item.processor,
                     &item.inputs_port,
                     &item.outputs_port,
@@ -552,6 +579,9 @@ impl ScheduleQueue {
             let node_index = proc.processor.id();
             let tracking_payload = graph.get_node_tracking_payload(node_index);
             let _guard = ThreadTracker::tracking(tracking_payload.clone());
+            let _span_guard = graph
+                .get_node_plan_span(node_index)
+                .map(|x| x.set_local_parent());
             let process_future = proc.processor.async_process();
             executor.async_runtime.spawn(
                 ProcessorAsyncTask::create(
@@ -667,6 +697,9 @@ impl ScheduleQueue {
             let node_index = proc.processor.id();
             let tracking_payload = graph.get_node_tracking_payload(node_index);
             let _guard = ThreadTracker::tracking(tracking_payload.clone());
+            let _span_guard = graph
+                .get_node_plan_span(node_index)
+                .map(|x| x.set_local_parent());
             let process_future = proc.processor.async_process();
             executor.async_runtime.spawn(
                 ProcessorAsyncTask::create(
@@ -733,6 +766,10 @@ impl RunningGraph {
         &self.0.graph[pid].tracking_payload
     }
 
+    pub(crate) fn get_node_plan_span(&self, pid: NodeIndex) -> Option<&Span> {
+        self.0.graph[pid].plan_span.as_deref()
+    }
+
     pub fn get_proc_profiles(&self) -> Vec<Arc<Profile>> {
         self.0
             .graph
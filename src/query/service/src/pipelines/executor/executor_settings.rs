@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use databend_common_base::runtime::MemStat;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 
@@ -25,6 +26,8 @@ pub struct ExecutorSettings {
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
     pub executor_node_id: String,
+    /// The parent of the query memory stat, to enforce the memory quota of the workload group.
+    pub workload_group_mem_stat: Option<Arc<MemStat>>,
 }
 
 impl ExecutorSettings {
@@ -40,6 +43,7 @@ impl ExecutorSettings {
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            workload_group_mem_stat: ctx.get_workload_group_mem_stat(),
         })
     }
 }
@@ -163,6 +163,10 @@ impl ExecutorWorkerContext {
     ) -> Result<Option<(NodeIndex, Arc<RunningGraph>)>> {
         let payload = proc.graph.get_node_tracking_payload(proc.processor.id());
         let _guard = ThreadTracker::tracking(payload.clone());
+        let _span_guard = proc
+            .graph
+            .get_node_plan_span(proc.processor.id())
+            .map(|x| x.set_local_parent());
 
         let instant = Instant::now();
 
@@ -189,6 +193,9 @@ impl ExecutorWorkerContext {
             let node_index = proc.processor.id();
             let tracking_payload = graph.get_node_tracking_payload(node_index);
             let _guard = ThreadTracker::tracking(tracking_payload.clone());
+            let _span_guard = graph
+                .get_node_plan_span(node_index)
+                .map(|x| x.set_local_parent());
             executor.async_runtime.spawn(
                 ProcessorAsyncTask::create(
                     query_id,
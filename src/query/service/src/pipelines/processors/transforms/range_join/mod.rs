@@ -16,6 +16,7 @@ mod ie_join_state;
 mod ie_join_util;
 mod merge_join_state;
 mod range_join_state;
+mod spatial_join_state;
 mod transform_range_join;
 
 pub(crate) use ie_join_state::IEJoinState;
@@ -59,6 +59,7 @@ pub struct RangeJoinState {
     // Row index offset for left/right
     pub(crate) row_offset: RwLock<Vec<(usize, usize)>>,
     pub(crate) finished_tasks: AtomicU64,
+    pub(crate) range_join_type: RangeJoinType,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
 }
@@ -87,6 +88,7 @@ impl RangeJoinState {
             tasks: RwLock::new(vec![]),
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
+            range_join_type: range_join.range_join_type.clone(),
             ie_join_state,
         }
     }
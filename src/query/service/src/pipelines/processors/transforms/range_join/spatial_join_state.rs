@@ -0,0 +1,191 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::geometry_bbox;
+use databend_common_io::BoundingBox;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+impl RangeJoinState {
+    // Spatial join: build a uniform grid on the bounding boxes of the right block,
+    // probe it with the bounding boxes of the left block to find the candidate pairs,
+    // then the exact spatial predicate is checked by `other_conditions`.
+    pub fn spatial_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let tasks = self.tasks.read();
+        let (left_idx, right_idx) = tasks[task_id];
+        let left_sorted_blocks = self.left_sorted_blocks.read();
+        let right_sorted_blocks = self.right_sorted_blocks.read();
+
+        let left_keys = &left_sorted_blocks[left_idx];
+        let right_keys = &right_sorted_blocks[right_idx];
+        let left_bboxes = bounding_boxes(
+            &left_keys.columns()[0].value.convert_to_full_column(
+                self.conditions[0]
+                    .left_expr
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .data_type(),
+                left_keys.num_rows(),
+            ),
+        )?;
+        let right_bboxes = bounding_boxes(
+            &right_keys.columns()[0].value.convert_to_full_column(
+                self.conditions[0]
+                    .right_expr
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .data_type(),
+                right_keys.num_rows(),
+            ),
+        )?;
+
+        let grid = SpatialGrid::new(&right_bboxes);
+        let mut left_indices = Vec::new();
+        let mut right_indices = Vec::new();
+        let mut candidates = Vec::new();
+        for (i, bbox) in left_bboxes.iter().enumerate() {
+            if let Some(bbox) = bbox {
+                grid.probe(bbox, &right_bboxes, &mut candidates);
+                for j in candidates.drain(..) {
+                    left_indices.push(i as u32);
+                    right_indices.push(j);
+                }
+            }
+        }
+        if left_indices.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+        let mut result_block = left_table[left_idx].take(&left_indices, &mut None)?;
+        let right_result_block = right_table[right_idx].take(&right_indices, &mut None)?;
+        for col in right_result_block.columns() {
+            result_block.add_column(col.clone());
+        }
+        for filter in self.other_conditions.iter() {
+            result_block = filter_block(result_block, filter)?;
+        }
+        Ok(vec![result_block])
+    }
+}
+
+// NULL and empty geometries have no bounding box, they never match a spatial predicate.
+fn bounding_boxes(column: &Column) -> Result<Vec<Option<BoundingBox>>> {
+    column
+        .iter()
+        .map(|scalar| match scalar {
+            ScalarRef::Geometry(buf) => geometry_bbox(buf),
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+/// A uniform grid over the extent of the bounding boxes,
+/// each cell holds the rows whose bounding box overlaps it.
+struct SpatialGrid {
+    extent: BoundingBox,
+    cols: usize,
+    rows: usize,
+    cell_width: f64,
+    cell_height: f64,
+    cells: Vec<Vec<u32>>,
+}
+
+impl SpatialGrid {
+    fn new(bboxes: &[Option<BoundingBox>]) -> Self {
+        let extent = bboxes
+            .iter()
+            .flatten()
+            .fold(None, |acc: Option<BoundingBox>, bbox| {
+                Some(acc.map_or(*bbox, |v| v.merge(bbox)))
+            })
+            .unwrap_or(BoundingBox {
+                min_x: 0.0,
+                min_y: 0.0,
+                max_x: 0.0,
+                max_y: 0.0,
+            });
+        // About one box per cell if the boxes are evenly distributed.
+        let num = bboxes.iter().flatten().count();
+        let cols = ((num as f64).sqrt().ceil() as usize).clamp(1, 1024);
+        let rows = cols;
+        let cell_width = (extent.max_x - extent.min_x) / cols as f64;
+        let cell_height = (extent.max_y - extent.min_y) / rows as f64;
+
+        let mut grid = SpatialGrid {
+            extent,
+            cols,
+            rows,
+            cell_width,
+            cell_height,
+            cells: vec![vec![]; cols * rows],
+        };
+        for (idx, bbox) in bboxes.iter().enumerate() {
+            if let Some(bbox) = bbox {
+                let (x0, y0, x1, y1) = grid.cell_range(bbox);
+                for y in y0..=y1 {
+                    for x in x0..=x1 {
+                        grid.cells[y * cols + x].push(idx as u32);
+                    }
+                }
+            }
+        }
+        grid
+    }
+
+    fn cell_range(&self, bbox: &BoundingBox) -> (usize, usize, usize, usize) {
+        let cell = |v: f64, min: f64, size: f64, n: usize| {
+            if size > 0.0 {
+                (((v - min) / size).floor().max(0.0) as usize).min(n - 1)
+            } else {
+                0
+            }
+        };
+        (
+            cell(bbox.min_x, self.extent.min_x, self.cell_width, self.cols),
+            cell(bbox.min_y, self.extent.min_y, self.cell_height, self.rows),
+            cell(bbox.max_x, self.extent.min_x, self.cell_width, self.cols),
+            cell(bbox.max_y, self.extent.min_y, self.cell_height, self.rows),
+        )
+    }
+
+    // Collect the rows whose bounding box intersects `bbox`, each row at most once.
+    fn probe(&self, bbox: &BoundingBox, bboxes: &[Option<BoundingBox>], output: &mut Vec<u32>) {
+        if !self.extent.intersects(bbox) {
+            return;
+        }
+        let (x0, y0, x1, y1) = self.cell_range(bbox);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                for idx in self.cells[y * self.cols + x].iter() {
+                    let other = bboxes[*idx as usize].as_ref().unwrap();
+                    if !other.intersects(bbox) {
+                        continue;
+                    }
+                    // A box spanning several cells is reported by the first cell
+                    // shared with the probe box only.
+                    let (ox0, oy0, _, _) = self.cell_range(other);
+                    if x == x0.max(ox0) && y == y0.max(oy0) {
+                        output.push(*idx);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -23,6 +23,7 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_sinks::Sink;
+use databend_common_sql::executor::physical_plans::RangeJoinType;
 
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
 
@@ -65,10 +66,10 @@ impl TransformRangeJoinLeft {
 #[async_trait::async_trait]
 impl Processor for TransformRangeJoinLeft {
     fn name(&self) -> String {
-        if self.state.ie_join_state.is_some() {
-            "TransformIEJoinLeft".to_string()
-        } else {
-            "TransformMergeJoinLeft".to_string()
+        match self.state.range_join_type {
+            RangeJoinType::IEJoin => "TransformIEJoinLeft".to_string(),
+            RangeJoinType::Merge => "TransformMergeJoinLeft".to_string(),
+            RangeJoinType::Spatial => "TransformSpatialJoinLeft".to_string(),
         }
     }
 
@@ -134,9 +135,10 @@ impl Processor for TransformRangeJoinLeft {
             RangeJoinStep::Execute => {
                 let task_id = self.state.task_id();
                 if let Some(task_id) = task_id {
-                    let res = match self.state.ie_join_state {
-                        Some(ref _ie_join_state) => self.state.ie_join(task_id)?,
-                        None => self.state.merge_join(task_id)?,
+                    let res = match self.state.range_join_type {
+                        RangeJoinType::IEJoin => self.state.ie_join(task_id)?,
+                        RangeJoinType::Merge => self.state.merge_join(task_id)?,
+                        RangeJoinType::Spatial => self.state.spatial_join(task_id)?,
                     };
                     for block in res {
                         if !block.is_empty() {
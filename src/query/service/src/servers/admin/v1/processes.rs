@@ -34,6 +34,8 @@ pub struct ProcessInfo {
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
+    pub queue_position: Option<u64>,
+    pub queued_duration_ms: u64,
 }
 
 #[poem::handler]
@@ -63,6 +65,8 @@ pub async fn processlist_handler() -> poem::Result<impl IntoResponse> {
             mysql_connection_id: process.mysql_connection_id,
             created_time: process.created_time,
             status_info: process.status_info.clone(),
+            queue_position: process.queue_position,
+            queued_duration_ms: process.queued_duration.as_millis() as u64,
         })
         .collect::<Vec<_>>();
     Ok(Json(processes))
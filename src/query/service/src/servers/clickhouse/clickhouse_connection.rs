@@ -0,0 +1,874 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::Shutdown;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_raw_insert_stmt;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::io::BufReader;
+use databend_common_base::base::tokio::io::BufWriter;
+use databend_common_base::base::tokio::net::tcp::OwnedReadHalf;
+use databend_common_base::base::tokio::net::tcp::OwnedWriteHalf;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_base::runtime::Runtime;
+use databend_common_base::runtime::Thread;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+use databend_common_formats::ClickhouseFormatType;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::NameResolutionContext;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_session::drop_all_temp_tables;
+use futures::future::select;
+use futures::future::Either;
+use futures::StreamExt;
+use log::error;
+use log::info;
+use log::warn;
+
+use super::native::read_block;
+use super::native::write_block;
+use super::native::NativeBlock;
+use super::native::NativeType;
+use super::protocol::write_exception;
+use super::protocol::write_packet;
+use super::protocol::write_string;
+use super::protocol::PacketReader;
+use super::protocol::ProfileInfo;
+use super::protocol::Progress;
+use super::protocol::ServerHello;
+use super::protocol::CLIENT_CANCEL;
+use super::protocol::CLIENT_DATA;
+use super::protocol::CLIENT_HELLO;
+use super::protocol::CLIENT_PING;
+use super::protocol::CLIENT_QUERY;
+use super::protocol::INTERFACE_TCP;
+use super::protocol::MIN_CLIENT_REVISION;
+use super::protocol::REVISION_WITH_ADDENDUM;
+use super::protocol::REVISION_WITH_DISTRIBUTED_DEPTH;
+use super::protocol::REVISION_WITH_INITIAL_QUERY_START_TIME;
+use super::protocol::REVISION_WITH_INTERSERVER_SECRET;
+use super::protocol::REVISION_WITH_OPENTELEMETRY;
+use super::protocol::REVISION_WITH_PARALLEL_REPLICAS;
+use super::protocol::REVISION_WITH_PARAMETERS;
+use super::protocol::REVISION_WITH_QUOTA_KEY_IN_CLIENT_INFO;
+use super::protocol::REVISION_WITH_VERSION_PATCH;
+use super::protocol::SERVER_DATA;
+use super::protocol::SERVER_END_OF_STREAM;
+use super::protocol::SERVER_PONG;
+use super::protocol::SERVER_REVISION;
+use super::protocol::STAGE_COMPLETE;
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::Accessor;
+use crate::interpreters::IngestInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::compress_block;
+use crate::servers::http::CLICKHOUSE_VERSION;
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::QueryContext;
+use crate::sessions::QueryEntry;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+
+// default size of the write buffer of the data packets: 100KB
+const DEFAULT_WRITE_BUFFER_SIZE: usize = 100 * 1024;
+
+pub struct ClickHouseConnection;
+
+impl ClickHouseConnection {
+    pub fn run_on_stream(session: Session, stream: TcpStream) -> Result<()> {
+        let blocking_stream = Self::convert_stream(stream)?;
+        let host = blocking_stream.peer_addr().ok();
+        let blocking_stream_ref = blocking_stream.try_clone()?;
+
+        let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("clickhouse-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(async move {
+                let (r, w) = non_blocking_stream.into_split();
+                let mut worker = ClickHouseWorker::create(r, w);
+
+                let session = match worker.handshake(session, host).await {
+                    Ok(session) => session,
+                    Err(error) => {
+                        warn!("ClickHouse handshake failed: {:?}", error);
+                        worker.send_exception(&error).await.ok();
+                        return;
+                    }
+                };
+
+                session.attach(host, move || {
+                    if let Err(error) = blocking_stream_ref.shutdown(Shutdown::Both) {
+                        error!("Cannot shutdown ClickHouse session io {}", error);
+                    }
+                });
+
+                if let Err(error) = worker.run(&session).await {
+                    warn!("ClickHouse connection closed with error: {:?}", error);
+                }
+
+                let tenant = session.get_current_tenant();
+                let session_id = session.get_id();
+                UserApiProvider::instance()
+                    .client_session_api(&tenant)
+                    .drop_client_session_id(&session_id)
+                    .await
+                    .ok();
+                drop_all_temp_tables(&session_id, session.temp_tbl_mgr())
+                    .await
+                    .ok();
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
+        let stream = stream.into_std().map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+        stream.set_nonblocking(false).map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+
+        Ok(stream)
+    }
+}
+
+/// A query sent by the `Query` packet.
+struct QueryPacket {
+    query_id: String,
+    settings: HashMap<String, String>,
+    parameters: HashMap<String, String>,
+    stage: u64,
+    compression: bool,
+    query: String,
+}
+
+struct ClickHouseWorker {
+    reader: PacketReader<BufReader<OwnedReadHalf>>,
+    writer: BufWriter<OwnedWriteHalf>,
+    revision: u64,
+    compression: bool,
+}
+
+impl ClickHouseWorker {
+    fn create(reader: OwnedReadHalf, writer: OwnedWriteHalf) -> Self {
+        ClickHouseWorker {
+            reader: PacketReader::create(BufReader::new(reader)),
+            writer: BufWriter::with_capacity(DEFAULT_WRITE_BUFFER_SIZE, writer),
+            revision: SERVER_REVISION,
+            compression: false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn send(&mut self, buf: &[u8]) -> Result<()> {
+        self.writer.write_all(buf).await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn send_exception(&mut self, error: &ErrorCode) -> Result<()> {
+        let mut buf = vec![];
+        write_exception(&mut buf, error);
+        self.send(&buf).await
+    }
+
+    /// Receive the client hello, authenticate the user and reply the server hello.
+    #[async_backtrace::framed]
+    async fn handshake(
+        &mut self,
+        mut session: Session,
+        host: Option<SocketAddr>,
+    ) -> Result<Arc<Session>> {
+        match self.reader.read_packet_type().await? {
+            Some(CLIENT_HELLO) => {}
+            Some(packet) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unexpected packet {packet}, expect client hello"
+                )));
+            }
+            None => return Err(ErrorCode::AbortedSession("connection closed by the client")),
+        }
+        let client_name = self.reader.read_string().await?;
+        let major = self.reader.read_varuint().await?;
+        let minor = self.reader.read_varuint().await?;
+        let client_revision = self.reader.read_varuint().await?;
+        let database = self.reader.read_string().await?;
+        let user = self.reader.read_string().await?;
+        let password = self.reader.read_string().await?;
+
+        if client_revision < MIN_CLIENT_REVISION {
+            return Err(ErrorCode::Unimplemented(format!(
+                "ClickHouse client revision {client_revision} is not supported, requires {MIN_CLIENT_REVISION} at least"
+            )));
+        }
+        self.revision = client_revision.min(SERVER_REVISION);
+
+        let credential = Credential::Password {
+            name: user.clone(),
+            password: (!password.is_empty()).then(|| password.into_bytes()),
+            client_ip: host.map(|host| host.ip().to_string()),
+        };
+        AuthMgr::instance()
+            .auth(&mut session, &credential, false)
+            .await?;
+
+        let settings = session.get_settings();
+        if !settings.get_enable_clickhouse_handler()? {
+            return Err(ErrorCode::PermissionDenied(
+                "default settings: enable_clickhouse_handler is 0",
+            ));
+        }
+        if !database.is_empty() {
+            session.set_current_database(database);
+        }
+        let session = SessionManager::instance().register_session(session)?;
+
+        let mut version = CLICKHOUSE_VERSION
+            .split('.')
+            .map(|v| v.parse::<u64>().unwrap_or_default());
+        let hello = ServerHello {
+            name: "Databend",
+            version: (
+                version.next().unwrap_or_default(),
+                version.next().unwrap_or_default(),
+                version.next().unwrap_or_default(),
+            ),
+            timezone: &settings.get_timezone()?,
+            display_name: "databend",
+        };
+        let mut buf = vec![];
+        hello.write(&mut buf, self.revision);
+        self.send(&buf).await?;
+
+        if self.revision >= REVISION_WITH_ADDENDUM {
+            // quota key
+            self.reader.read_string().await?;
+        }
+
+        info!(
+            "ClickHouse client {} {}.{} connected as {}, revision {}",
+            client_name, major, minor, user, self.revision
+        );
+        Ok(session)
+    }
+
+    #[async_backtrace::framed]
+    async fn run(&mut self, session: &Arc<Session>) -> Result<()> {
+        loop {
+            match self.reader.read_packet_type().await? {
+                None => return Ok(()),
+                Some(CLIENT_PING) => {
+                    let mut buf = vec![];
+                    write_packet(&mut buf, SERVER_PONG);
+                    self.send(&buf).await?;
+                }
+                // The query is already finished.
+                Some(CLIENT_CANCEL) => {}
+                Some(CLIENT_QUERY) => {
+                    let query = self.read_query().await?;
+                    self.compression = query.compression;
+                    self.read_external_tables().await?;
+
+                    if let Err(error) = self.execute(session, query).await {
+                        if error.code() == ErrorCode::ABORTED_SESSION {
+                            return Err(error);
+                        }
+                        self.send_exception(&error).await?;
+                    }
+                }
+                Some(packet) => {
+                    return Err(ErrorCode::BadBytes(format!("unexpected packet {packet}")));
+                }
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_query(&mut self) -> Result<QueryPacket> {
+        let revision = self.revision;
+        let reader = &mut self.reader;
+        let query_id = reader.read_string().await?;
+
+        // client info
+        let query_kind = reader.read_u8().await?;
+        if query_kind != 0 {
+            // initial user, initial query id and initial address
+            reader.read_string().await?;
+            reader.read_string().await?;
+            reader.read_string().await?;
+            if revision >= REVISION_WITH_INITIAL_QUERY_START_TIME {
+                reader.read_u64().await?;
+            }
+            let interface = reader.read_u8().await?;
+            if interface != INTERFACE_TCP {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported client interface {interface}"
+                )));
+            }
+            // os user, client hostname, client name and client version
+            reader.read_string().await?;
+            reader.read_string().await?;
+            reader.read_string().await?;
+            reader.read_varuint().await?;
+            reader.read_varuint().await?;
+            reader.read_varuint().await?;
+            if revision >= REVISION_WITH_QUOTA_KEY_IN_CLIENT_INFO {
+                reader.read_string().await?;
+            }
+            if revision >= REVISION_WITH_DISTRIBUTED_DEPTH {
+                reader.read_varuint().await?;
+            }
+            if revision >= REVISION_WITH_VERSION_PATCH {
+                reader.read_varuint().await?;
+            }
+            if revision >= REVISION_WITH_OPENTELEMETRY && reader.read_u8().await? != 0 {
+                // trace id, span id, trace state and trace flags
+                reader.read_bytes(16).await?;
+                reader.read_u64().await?;
+                reader.read_string().await?;
+                reader.read_u8().await?;
+            }
+            if revision >= REVISION_WITH_PARALLEL_REPLICAS {
+                reader.read_varuint().await?;
+                reader.read_varuint().await?;
+                reader.read_varuint().await?;
+            }
+        }
+
+        let settings = Self::read_settings(reader).await?;
+        if revision >= REVISION_WITH_INTERSERVER_SECRET {
+            reader.read_string().await?;
+        }
+        let stage = reader.read_varuint().await?;
+        let compression = reader.read_varuint().await? != 0;
+        let query = reader.read_string().await?;
+        let parameters = if revision >= REVISION_WITH_PARAMETERS {
+            Self::read_settings(reader).await?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(QueryPacket {
+            query_id,
+            settings,
+            parameters,
+            stage,
+            compression,
+            query,
+        })
+    }
+
+    /// Read the settings serialized as strings, which is ended by an empty name.
+    #[async_backtrace::framed]
+    async fn read_settings(
+        reader: &mut PacketReader<BufReader<OwnedReadHalf>>,
+    ) -> Result<HashMap<String, String>> {
+        let mut settings = HashMap::new();
+        loop {
+            let name = reader.read_string().await?;
+            if name.is_empty() {
+                return Ok(settings);
+            }
+            // flags
+            reader.read_varuint().await?;
+            let value = reader.read_string().await?;
+            settings.insert(name, value);
+        }
+    }
+
+    /// Read a `Data` packet, the block is compressed if the query enables compression.
+    #[async_backtrace::framed]
+    async fn read_data(&mut self) -> Result<NativeBlock> {
+        match self.reader.read_packet_type().await? {
+            Some(CLIENT_DATA) => {}
+            Some(CLIENT_CANCEL) => return Err(ErrorCode::AbortedQuery("cancelled by the client")),
+            Some(packet) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unexpected packet {packet}, expect data"
+                )));
+            }
+            None => return Err(ErrorCode::AbortedSession("connection closed by the client")),
+        }
+        // the name of the temporary table
+        self.reader.read_string().await?;
+        self.reader.set_compressed(self.compression);
+        let block = read_block(&mut self.reader, self.revision).await;
+        self.reader.set_compressed(false);
+        block
+    }
+
+    /// The external tables of the query are sent before executing the query,
+    /// which are ended by an empty block.
+    #[async_backtrace::framed]
+    async fn read_external_tables(&mut self) -> Result<()> {
+        let mut has_external_tables = false;
+        loop {
+            let block = self.read_data().await?;
+            if block.is_empty() {
+                break;
+            }
+            has_external_tables = true;
+        }
+        if has_external_tables {
+            return Err(ErrorCode::Unimplemented(
+                "external tables are not supported",
+            ));
+        }
+        Ok(())
+    }
+
+    fn write_data(
+        &self,
+        buf: &mut Vec<u8>,
+        names: &[String],
+        types: &[NativeType],
+        columns: &[Column],
+        num_rows: usize,
+        encoder: &FieldEncoderValues,
+    ) -> Result<()> {
+        write_packet(buf, SERVER_DATA);
+        write_string(buf, "");
+        if self.compression {
+            let mut block = vec![];
+            write_block(
+                &mut block,
+                names,
+                types,
+                columns,
+                num_rows,
+                self.revision,
+                encoder,
+            )?;
+            buf.extend_from_slice(&compress_block(block)?);
+        } else {
+            write_block(buf, names, types, columns, num_rows, self.revision, encoder)?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn execute(&mut self, session: &Arc<Session>, query: QueryPacket) -> Result<()> {
+        if query.stage != STAGE_COMPLETE {
+            return Err(ErrorCode::Unimplemented(format!(
+                "query processing stage {} is not supported",
+                query.stage
+            )));
+        }
+        if !query.parameters.is_empty() {
+            return Err(ErrorCode::Unimplemented(
+                "query parameters are not supported",
+            ));
+        }
+
+        let settings = session.get_settings();
+        settings.set_batch_settings(&query.settings)?;
+        let context = session.create_query_context().await?;
+        if !query.query_id.is_empty() {
+            context.set_id(query.query_id.clone());
+        }
+
+        let start = Instant::now();
+        match parse_native_insert(&query.query, settings.get_sql_dialect()?)? {
+            Some(stmt) => {
+                info!("ClickHouse native insert: {}", query.query);
+                self.execute_insert(context, &query.query, stmt, start)
+                    .await
+            }
+            None => {
+                info!("ClickHouse native query: {}", query.query);
+                self.execute_query(session, context, &query.query, start)
+                    .await
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_query(
+        &mut self,
+        session: &Arc<Session>,
+        context: Arc<QueryContext>,
+        query: &str,
+        start: Instant,
+    ) -> Result<()> {
+        // Use interpreter_plan_sql, we can write the query log if an error occurs.
+        let (plan, extras) = interpreter_plan_sql(context.clone(), query).await?;
+        let entry = QueryEntry::create(&context, &plan, &extras).await?;
+        let _guard = QueriesQueueManager::instance().acquire(entry).await?;
+
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+        let mut blocks = context
+            .try_spawn({
+                let ctx = context.clone();
+                async move { interpreter.execute(ctx).await }
+            })?
+            .await
+            .map_err_to_code(
+                ErrorCode::TokioError,
+                || "Cannot join handle from context's runtime",
+            )??;
+
+        let schema = plan.schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        let types = schema
+            .fields()
+            .iter()
+            .map(|field| NativeType::from_data_type(field.data_type()))
+            .collect::<Vec<_>>();
+        let format = context.get_format_settings()?;
+        let encoder =
+            FieldEncoderValues::create_for_http_handler(format.timezone, format.geometry_format);
+
+        let has_result_set = plan.has_result_set();
+        let mut buf = vec![];
+        if has_result_set {
+            self.write_data(&mut buf, &names, &types, &[], 0, &encoder)?;
+            self.send(&buf).await?;
+        }
+
+        let mut profile = ProfileInfo::default();
+        let mut reported = Progress::default();
+        loop {
+            let packet = Box::pin(self.reader.read_packet_type());
+            let next = match select(blocks.next(), packet).await {
+                Either::Left((block, _)) => Either::Left(block),
+                Either::Right((packet, _)) => Either::Right(packet),
+            };
+            let block = match next {
+                Either::Left(block) => block,
+                Either::Right(packet) => {
+                    match packet? {
+                        Some(CLIENT_CANCEL) => {
+                            session.force_kill_query(ErrorCode::AbortedQuery(
+                                "cancelled by the client",
+                            ));
+                        }
+                        Some(CLIENT_PING) => {
+                            buf.clear();
+                            write_packet(&mut buf, SERVER_PONG);
+                            self.send(&buf).await?;
+                        }
+                        Some(packet) => {
+                            return Err(ErrorCode::BadBytes(format!(
+                                "unexpected packet {packet} during query"
+                            )));
+                        }
+                        None => {
+                            session.force_kill_query(ErrorCode::AbortedQuery(
+                                "connection closed by the client",
+                            ));
+                            return Err(ErrorCode::AbortedSession(
+                                "connection closed by the client",
+                            ));
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            let block = match block {
+                None => break,
+                Some(block) => block?,
+            };
+            if block.num_rows() == 0 {
+                continue;
+            }
+            profile.rows += block.num_rows() as u64;
+            profile.blocks += 1;
+            profile.bytes += block.memory_size() as u64;
+
+            let columns = block
+                .convert_to_full()
+                .columns()
+                .iter()
+                .map(|entry| entry.value.clone().into_column().unwrap())
+                .collect::<Vec<_>>();
+            buf.clear();
+            self.write_data(
+                &mut buf,
+                &names,
+                &types,
+                &columns,
+                block.num_rows(),
+                &encoder,
+            )?;
+            progress(&context, &mut reported, start).write(&mut buf, self.revision);
+            self.send(&buf).await?;
+        }
+
+        buf.clear();
+        progress(&context, &mut reported, start).write(&mut buf, self.revision);
+        if has_result_set {
+            profile.write(&mut buf);
+        }
+        write_packet(&mut buf, SERVER_END_OF_STREAM);
+        self.send(&buf).await
+    }
+
+    /// The rows of the insert are sent by the client in data packets after the server sends
+    /// the header of the columns, and the last data packet is an empty block.
+    #[async_backtrace::framed]
+    async fn execute_insert(
+        &mut self,
+        context: Arc<QueryContext>,
+        query: &str,
+        stmt: InsertStmt,
+        start: Instant,
+    ) -> Result<()> {
+        let settings = context.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let catalog = stmt
+            .catalog
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &name_resolution_ctx).name)
+            .unwrap_or_else(|| context.get_current_catalog());
+        let database = stmt
+            .database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &name_resolution_ctx).name)
+            .unwrap_or_else(|| context.get_current_database());
+        let table = normalize_identifier(&stmt.table, &name_resolution_ctx).name;
+        // Checked as an `INSERT` of SQL, before the client starts to send the data.
+        Accessor::create(context.clone())
+            .check_insert(&catalog, &database, &table, stmt.overwrite)
+            .await?;
+
+        let table_schema = context
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema()
+            .remove_computed_fields();
+        let fields = if stmt.columns.is_empty() {
+            table_schema.fields().clone()
+        } else {
+            stmt.columns
+                .iter()
+                .map(|ident| {
+                    let name = normalize_identifier(ident, &name_resolution_ctx).name;
+                    table_schema.field_with_name(&name).cloned()
+                })
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        let names = fields
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        let types = fields
+            .iter()
+            .map(|field| NativeType::from_data_type(&DataType::from(field.data_type())))
+            .collect::<Vec<_>>();
+        let source_schema = DataSchemaRefExt::create(
+            names
+                .iter()
+                .zip(types.iter())
+                .map(|(name, ty)| Ok(DataField::new(name, ty.data_type()?)))
+                .collect::<Result<Vec<_>>>()?,
+        );
+
+        let format = context.get_format_settings()?;
+        let encoder =
+            FieldEncoderValues::create_for_http_handler(format.timezone, format.geometry_format);
+        let mut buf = vec![];
+        self.write_data(&mut buf, &names, &types, &[], 0, &encoder)?;
+        self.send(&buf).await?;
+
+        context.attach_query_str(QueryKind::Insert, query.to_string());
+        let (tx, rx) = async_channel::bounded(1);
+        let interpreter = IngestInterpreter::try_create(
+            context.clone(),
+            catalog,
+            database,
+            table,
+            source_schema,
+            Box::pin(rx),
+            stmt.overwrite,
+        )?;
+        // The pipeline is executed in the runtime of the query, the blocks are received
+        // from the client concurrently.
+        let handle = context.try_spawn({
+            let ctx = context.clone();
+            async move {
+                let mut stream = interpreter.execute(ctx).await?;
+                while let Some(block) = stream.next().await {
+                    block?;
+                }
+                Ok::<_, ErrorCode>(())
+            }
+        })?;
+
+        let received = self.receive_blocks(&types, tx).await;
+        let executed = handle.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?;
+        received?;
+        executed?;
+
+        let mut reported = Progress::default();
+        buf.clear();
+        progress(&context, &mut reported, start).write(&mut buf, self.revision);
+        write_packet(&mut buf, SERVER_END_OF_STREAM);
+        self.send(&buf).await
+    }
+
+    /// Receive the data packets of an insert until an empty block.
+    ///
+    /// An error is sent to the ingestion before returning, so the table is unchanged.
+    #[async_backtrace::framed]
+    async fn receive_blocks(
+        &mut self,
+        types: &[NativeType],
+        tx: async_channel::Sender<Result<DataBlock>>,
+    ) -> Result<()> {
+        loop {
+            let block = self.read_data().await.and_then(|block| {
+                if block.is_empty() || block.types.as_slice() == types {
+                    Ok(block)
+                } else {
+                    Err(ErrorCode::BadArguments(format!(
+                        "the columns of the data block ({}) don't match the insert columns",
+                        block
+                            .types
+                            .iter()
+                            .map(|ty| ty.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )))
+                }
+            });
+            match block {
+                Ok(block) if block.is_empty() => return Ok(()),
+                Ok(block) => {
+                    // The ingestion has failed if the receiver is closed, the rest blocks
+                    // are discarded.
+                    tx.send(Ok(DataBlock::new_from_columns(block.columns)))
+                        .await
+                        .ok();
+                }
+                Err(error) => {
+                    tx.send(Err(error.clone())).await.ok();
+                    return Err(error);
+                }
+            }
+        }
+    }
+}
+
+/// The increments of the progress since the last progress packet.
+fn progress(context: &Arc<QueryContext>, reported: &mut Progress, start: Instant) -> Progress {
+    let scan = context.get_scan_progress_value();
+    let write = context.get_write_progress_value();
+    let current = Progress {
+        read_rows: scan.rows as u64,
+        read_bytes: scan.bytes as u64,
+        total_rows: 0,
+        written_rows: write.rows as u64,
+        written_bytes: write.bytes as u64,
+        elapsed_ns: start.elapsed().as_nanos() as u64,
+    };
+    let delta = Progress {
+        read_rows: current.read_rows.saturating_sub(reported.read_rows),
+        read_bytes: current.read_bytes.saturating_sub(reported.read_bytes),
+        total_rows: 0,
+        written_rows: current.written_rows.saturating_sub(reported.written_rows),
+        written_bytes: current.written_bytes.saturating_sub(reported.written_bytes),
+        elapsed_ns: current.elapsed_ns.saturating_sub(reported.elapsed_ns),
+    };
+    *reported = current;
+    delta
+}
+
+/// Returns the statement if the query is an `INSERT` without inline data, such as
+/// `INSERT INTO t VALUES` or `INSERT INTO t FORMAT Native`, the rows are sent by data packets.
+fn parse_native_insert(query: &str, dialect: Dialect) -> Result<Option<InsertStmt>> {
+    let query = query.trim().trim_end_matches(';').trim_end();
+    let is_insert = query
+        .split_whitespace()
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case("INSERT"));
+    if !is_insert {
+        return Ok(None);
+    }
+
+    // The data of any format is parsed by the client and sent as native blocks.
+    let query = match strip_format_clause(query) {
+        Some((head, format)) => {
+            if !format.eq_ignore_ascii_case("native") && !format.eq_ignore_ascii_case("values") {
+                ClickhouseFormatType::parse_clickhouse_format(format)?;
+            }
+            format!("{head} VALUES")
+        }
+        None => query.to_string(),
+    };
+
+    let Ok(tokens) = tokenize_sql(&query) else {
+        return Ok(None);
+    };
+    match parse_raw_insert_stmt(&tokens, dialect) {
+        Ok(Statement::Insert(stmt)) => match &stmt.source {
+            InsertSource::RawValues { rest_str, .. } if rest_str.trim().is_empty() => {
+                Ok(Some(stmt))
+            }
+            _ => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Split `INSERT INTO t FORMAT CSV` into `INSERT INTO t` and `CSV`.
+fn strip_format_clause(query: &str) -> Option<(&str, &str)> {
+    let (head, format) = query.rsplit_once(char::is_whitespace)?;
+    let head = head.trim_end();
+    let keyword_start = head.len().checked_sub("FORMAT".len())?;
+    let keyword = head.get(keyword_start..)?;
+    let before = &head[..keyword_start];
+    if keyword.eq_ignore_ascii_case("FORMAT") && before.ends_with(char::is_whitespace) {
+        Some((before.trim_end(), format))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_base::base::tokio::task::JoinHandle;
+use databend_common_base::runtime::Runtime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use futures::future::AbortHandle;
+use futures::future::AbortRegistration;
+use futures::future::Abortable;
+use futures::StreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use socket2::SockRef;
+use socket2::TcpKeepalive;
+use tokio_stream::wrappers::TcpListenerStream;
+
+use crate::servers::clickhouse::clickhouse_connection::ClickHouseConnection;
+use crate::servers::clickhouse::protocol::write_exception;
+use crate::servers::server::ListeningStream;
+use crate::servers::server::Server;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The server of the ClickHouse native TCP protocol, which is used by `clickhouse-client`
+/// and the native drivers of ClickHouse.
+pub struct ClickHouseHandler {
+    abort_handle: AbortHandle,
+    abort_registration: Option<AbortRegistration>,
+    join_handle: Option<JoinHandle<()>>,
+    keepalive: TcpKeepalive,
+}
+
+impl ClickHouseHandler {
+    pub fn create(tcp_keepalive_timeout_secs: u64) -> Result<Box<dyn Server>> {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let keepalive = TcpKeepalive::new()
+            .with_time(std::time::Duration::from_secs(tcp_keepalive_timeout_secs));
+
+        Ok(Box::new(ClickHouseHandler {
+            abort_handle,
+            abort_registration: Some(registration),
+            join_handle: None,
+            keepalive,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn listener_tcp(listening: SocketAddr) -> Result<(TcpListenerStream, SocketAddr)> {
+        let listener = tokio::net::TcpListener::bind(listening)
+            .await
+            .map_err(|e| {
+                ErrorCode::TokioError(format!("{{{}:{}}} {}", listening.ip(), listening.port(), e))
+            })?;
+        let listener_addr = listener.local_addr()?;
+        Ok((TcpListenerStream::new(listener), listener_addr))
+    }
+
+    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+        let keepalive = self.keepalive.clone();
+
+        stream.for_each(move |accept_socket| {
+            let keepalive = keepalive.clone();
+            let executor = rt.clone();
+            let sessions = SessionManager::instance();
+            async move {
+                match accept_socket {
+                    Err(error) => error!("Broken session connection: {}", error),
+                    Ok(socket) => {
+                        ClickHouseHandler::accept_socket(sessions, executor, socket, keepalive)
+                    }
+                };
+            }
+        })
+    }
+
+    fn accept_socket(
+        session_manager: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        keepalive: TcpKeepalive,
+    ) {
+        executor.spawn(async move {
+            match session_manager
+                .create_session(SessionType::Clickhouse)
+                .await
+            {
+                Err(error) => {
+                    warn!("create session failed, {:?}", error);
+                    Self::reject_session(socket, error).await
+                }
+                Ok(session) => {
+                    info!("ClickHouse connection coming: {:?}", socket.peer_addr());
+
+                    // TcpStream must implement AsFd for socket2 0.5, wait https://github.com/tokio-rs/tokio/pull/5514
+                    if let Err(e) = SockRef::from(&socket).set_tcp_keepalive(&keepalive) {
+                        warn!("failed to set socket option keepalive {}", e);
+                    }
+
+                    // The session is registered after the client is authenticated.
+                    if let Err(error) = ClickHouseConnection::run_on_stream(session, socket) {
+                        error!("Unexpected error occurred during query: {:?}", error);
+                    };
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn reject_session(mut stream: TcpStream, error: ErrorCode) {
+        let mut buf = vec![];
+        write_exception(&mut buf, &error);
+        if let Err(error) = stream.write_all(&buf).await {
+            error!(
+                "Unexpected error occurred during reject connection: {:?}",
+                error
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Server for ClickHouseHandler {
+    #[async_backtrace::framed]
+    async fn shutdown(&mut self, graceful: bool) {
+        if !graceful {
+            return;
+        }
+
+        self.abort_handle.abort();
+
+        if let Some(join_handle) = self.join_handle.take() {
+            if let Err(error) = join_handle.await {
+                error!(
+                    "Unexpected error during shutdown ClickHouseHandler. cause {}",
+                    error
+                );
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        match self.abort_registration.take() {
+            None => Err(ErrorCode::Internal("ClickHouseHandler already running.")),
+            Some(registration) => {
+                let rejected_rt = Arc::new(Runtime::with_worker_threads(
+                    1,
+                    Some("clickhouse-handler".to_string()),
+                )?);
+                let (stream, listener) = Self::listener_tcp(listening).await?;
+                let stream = Abortable::new(stream, registration);
+                self.join_handle = Some(databend_common_base::runtime::spawn(
+                    self.listen_loop(stream, rejected_rt),
+                ));
+                Ok(listener)
+            }
+        }
+    }
+}
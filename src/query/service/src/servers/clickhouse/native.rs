@@ -0,0 +1,933 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The columnar `Native` format of ClickHouse, used by the data packets of the native protocol.
+//!
+//! A block is the block info, the number of columns and rows, then the name, the type name
+//! and the data of each column. The data of a column is the values of the rows one after
+//! another, nested types are flattened: `Nullable` writes the null map before the values,
+//! `Array` and `Map` write the end offsets before the flattened values.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::i256;
+use databend_common_expression::types::large_int::LargeIntColumn;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::number::NumberColumn;
+use databend_common_expression::types::number::F32;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::u256;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::ArrayColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::LargeIntDataType;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+
+use super::protocol::write_binary;
+use super::protocol::write_string;
+use super::protocol::write_varuint;
+use super::protocol::PacketReader;
+use super::protocol::REVISION_WITH_CUSTOM_SERIALIZATION;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const TIMESTAMP_PRECISION: u32 = 6;
+/// The max precision of `DateTime64` in ClickHouse.
+const MAX_DATETIME64_PRECISION: u32 = 9;
+/// The max length of `FixedString` in ClickHouse.
+const MAX_FIXED_STRING_LENGTH: usize = 0xFFFFFF;
+/// The limits of a block read from the client, the number of rows also limits the
+/// flattened values of `Array` and `Map`.
+const MAX_BLOCK_ROWS: usize = 1 << 24;
+const MAX_BLOCK_COLUMNS: usize = 1 << 16;
+
+/// A ClickHouse data type, only the types with a Databend counterpart are supported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NativeType {
+    Nothing,
+    Bool,
+    Number(NumberDataType),
+    LargeInt(LargeIntDataType),
+    String,
+    FixedString(usize),
+    Date,
+    Date32,
+    DateTime,
+    DateTime64(u32),
+    Decimal(DecimalSize),
+    Uuid,
+    Enum8(Vec<(String, i16)>),
+    Enum16(Vec<(String, i16)>),
+    Nullable(Box<NativeType>),
+    Array(Box<NativeType>),
+    Tuple(Vec<NativeType>),
+    Map(Box<NativeType>, Box<NativeType>),
+}
+
+impl NativeType {
+    /// The type used to send a column of the data type to the client.
+    ///
+    /// The data types without a ClickHouse counterpart, such as `VARIANT`, are sent as strings.
+    pub fn from_data_type(data_type: &DataType) -> NativeType {
+        match data_type {
+            DataType::Null => NativeType::Nullable(Box::new(NativeType::Nothing)),
+            DataType::EmptyArray => NativeType::Array(Box::new(NativeType::Nothing)),
+            DataType::Boolean => NativeType::Bool,
+            DataType::Binary | DataType::String => NativeType::String,
+            DataType::Number(ty) => NativeType::Number(*ty),
+            DataType::LargeInt(ty) => NativeType::LargeInt(*ty),
+            DataType::Decimal(ty) => NativeType::Decimal(ty.size()),
+            DataType::Timestamp => NativeType::DateTime64(TIMESTAMP_PRECISION),
+            DataType::Date => NativeType::Date32,
+            DataType::Uuid => NativeType::Uuid,
+            DataType::Enum(values) if values.len() <= i8::MAX as usize + 1 => NativeType::Enum8(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(code, value)| (value.clone(), code as i16))
+                    .collect(),
+            ),
+            DataType::Enum(values) if values.len() <= i16::MAX as usize + 1 => NativeType::Enum16(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(code, value)| (value.clone(), code as i16))
+                    .collect(),
+            ),
+            // ClickHouse doesn't allow nullable composite types, the null rows are sent as
+            // the default values.
+            DataType::Nullable(inner) => match NativeType::from_data_type(inner) {
+                ty @ (NativeType::Array(_) | NativeType::Tuple(_) | NativeType::Map(_, _)) => ty,
+                ty => NativeType::Nullable(Box::new(ty)),
+            },
+            DataType::Array(inner) => {
+                NativeType::Array(Box::new(NativeType::from_data_type(inner)))
+            }
+            DataType::Map(inner) => match inner.as_ref() {
+                DataType::Tuple(kv) if kv.len() == 2 => NativeType::Map(
+                    Box::new(NativeType::from_data_type(&kv[0])),
+                    Box::new(NativeType::from_data_type(&kv[1])),
+                ),
+                _ => NativeType::String,
+            },
+            DataType::Tuple(fields) => {
+                NativeType::Tuple(fields.iter().map(NativeType::from_data_type).collect())
+            }
+            _ => NativeType::String,
+        }
+    }
+
+    /// The data type of the columns decoded from the type.
+    pub fn data_type(&self) -> Result<DataType> {
+        Ok(match self {
+            NativeType::Nothing => DataType::Null,
+            NativeType::Bool => DataType::Boolean,
+            NativeType::Number(ty) => DataType::Number(*ty),
+            NativeType::LargeInt(ty) => DataType::LargeInt(*ty),
+            NativeType::String
+            | NativeType::FixedString(_)
+            | NativeType::Enum8(_)
+            | NativeType::Enum16(_) => DataType::String,
+            NativeType::Date | NativeType::Date32 => DataType::Date,
+            NativeType::DateTime | NativeType::DateTime64(_) => DataType::Timestamp,
+            NativeType::Decimal(size) => DataType::Decimal(DecimalDataType::from_size(*size)?),
+            NativeType::Uuid => DataType::Uuid,
+            NativeType::Nullable(inner) => match inner.as_ref() {
+                NativeType::Nothing => DataType::Null,
+                inner => inner.data_type()?.wrap_nullable(),
+            },
+            NativeType::Array(inner) => match inner.as_ref() {
+                NativeType::Nothing => DataType::EmptyArray,
+                inner => DataType::Array(Box::new(inner.data_type()?)),
+            },
+            NativeType::Tuple(fields) => DataType::Tuple(
+                fields
+                    .iter()
+                    .map(|field| field.data_type())
+                    .collect::<Result<_>>()?,
+            ),
+            NativeType::Map(key, value) => DataType::Map(Box::new(DataType::Tuple(vec![
+                key.data_type()?,
+                value.data_type()?,
+            ]))),
+        })
+    }
+
+    /// Parse a ClickHouse type name, such as `Nullable(DateTime64(3, 'UTC'))`.
+    pub fn parse(name: &str) -> Result<NativeType> {
+        let name = name.trim();
+        let (base, args) = match name.find('(') {
+            Some(pos) if name.ends_with(')') => (
+                name[..pos].trim(),
+                split_args(&name[pos + 1..name.len() - 1]),
+            ),
+            _ => (name, vec![]),
+        };
+        let unsupported = || ErrorCode::BadDataValueType(format!("unsupported type: {name}"));
+
+        let ty = match (base, args.as_slice()) {
+            ("Nothing", []) => NativeType::Nothing,
+            ("Bool", []) => NativeType::Bool,
+            ("UInt8", []) => NativeType::Number(NumberDataType::UInt8),
+            ("UInt16", []) => NativeType::Number(NumberDataType::UInt16),
+            ("UInt32", []) => NativeType::Number(NumberDataType::UInt32),
+            ("UInt64", []) => NativeType::Number(NumberDataType::UInt64),
+            ("Int8", []) => NativeType::Number(NumberDataType::Int8),
+            ("Int16", []) => NativeType::Number(NumberDataType::Int16),
+            ("Int32", []) => NativeType::Number(NumberDataType::Int32),
+            ("Int64", []) => NativeType::Number(NumberDataType::Int64),
+            ("Float32", []) => NativeType::Number(NumberDataType::Float32),
+            ("Float64", []) => NativeType::Number(NumberDataType::Float64),
+            ("Int128", []) => NativeType::LargeInt(LargeIntDataType::Int128),
+            ("UInt128", []) => NativeType::LargeInt(LargeIntDataType::UInt128),
+            ("Int256", []) => NativeType::LargeInt(LargeIntDataType::Int256),
+            ("UInt256", []) => NativeType::LargeInt(LargeIntDataType::UInt256),
+            ("String", []) => NativeType::String,
+            ("FixedString", [n]) => {
+                let n = parse_number(n)?;
+                if n == 0 || n > MAX_FIXED_STRING_LENGTH {
+                    return Err(ErrorCode::BadDataValueType(format!(
+                        "invalid length of FixedString: {n}"
+                    )));
+                }
+                NativeType::FixedString(n)
+            }
+            ("Date", []) => NativeType::Date,
+            ("Date32", []) => NativeType::Date32,
+            ("DateTime", []) | ("DateTime", [_]) => NativeType::DateTime,
+            ("DateTime64", [p]) | ("DateTime64", [p, _]) => {
+                let precision = parse_number(p)?;
+                if precision > MAX_DATETIME64_PRECISION {
+                    return Err(ErrorCode::BadDataValueType(format!(
+                        "invalid precision of DateTime64: {precision}"
+                    )));
+                }
+                NativeType::DateTime64(precision)
+            }
+            ("Decimal", [p, s]) => NativeType::Decimal(DecimalSize {
+                precision: parse_number(p)?,
+                scale: parse_number(s)?,
+            }),
+            ("Decimal32" | "Decimal64" | "Decimal128" | "Decimal256", [s]) => {
+                let precision = match base {
+                    "Decimal32" => 9,
+                    "Decimal64" => 18,
+                    "Decimal128" => 38,
+                    _ => 76,
+                };
+                NativeType::Decimal(DecimalSize {
+                    precision,
+                    scale: parse_number(s)?,
+                })
+            }
+            ("UUID", []) => NativeType::Uuid,
+            ("Enum8", values) => NativeType::Enum8(parse_enum_values(values)?),
+            ("Enum16", values) => NativeType::Enum16(parse_enum_values(values)?),
+            ("Nullable", [inner]) => NativeType::Nullable(Box::new(NativeType::parse(inner)?)),
+            ("Array", [inner]) => NativeType::Array(Box::new(NativeType::parse(inner)?)),
+            ("Tuple", fields) if !fields.is_empty() => NativeType::Tuple(
+                fields
+                    .iter()
+                    .map(|field| parse_tuple_field(field))
+                    .collect::<Result<_>>()?,
+            ),
+            ("Map", [key, value]) => NativeType::Map(
+                Box::new(NativeType::parse(key)?),
+                Box::new(NativeType::parse(value)?),
+            ),
+            _ => return Err(unsupported()),
+        };
+        Ok(ty)
+    }
+}
+
+impl Display for NativeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NativeType::Nothing => write!(f, "Nothing"),
+            NativeType::Bool => write!(f, "Bool"),
+            NativeType::Number(ty) => match ty {
+                NumberDataType::UInt8 => write!(f, "UInt8"),
+                NumberDataType::UInt16 => write!(f, "UInt16"),
+                NumberDataType::UInt32 => write!(f, "UInt32"),
+                NumberDataType::UInt64 => write!(f, "UInt64"),
+                NumberDataType::Int8 => write!(f, "Int8"),
+                NumberDataType::Int16 => write!(f, "Int16"),
+                NumberDataType::Int32 => write!(f, "Int32"),
+                NumberDataType::Int64 => write!(f, "Int64"),
+                NumberDataType::Float32 => write!(f, "Float32"),
+                NumberDataType::Float64 => write!(f, "Float64"),
+            },
+            NativeType::LargeInt(ty) => match ty {
+                LargeIntDataType::Int128 => write!(f, "Int128"),
+                LargeIntDataType::UInt128 => write!(f, "UInt128"),
+                LargeIntDataType::Int256 => write!(f, "Int256"),
+                LargeIntDataType::UInt256 => write!(f, "UInt256"),
+            },
+            NativeType::String => write!(f, "String"),
+            NativeType::FixedString(n) => write!(f, "FixedString({n})"),
+            NativeType::Date => write!(f, "Date"),
+            NativeType::Date32 => write!(f, "Date32"),
+            NativeType::DateTime => write!(f, "DateTime"),
+            NativeType::DateTime64(precision) => write!(f, "DateTime64({precision})"),
+            NativeType::Decimal(size) => write!(f, "Decimal({}, {})", size.precision, size.scale),
+            NativeType::Uuid => write!(f, "UUID"),
+            NativeType::Enum8(values) | NativeType::Enum16(values) => {
+                let bits = if matches!(self, NativeType::Enum8(_)) {
+                    8
+                } else {
+                    16
+                };
+                write!(f, "Enum{bits}(")?;
+                for (i, (name, value)) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(
+                        f,
+                        "'{}' = {value}",
+                        name.replace('\\', "\\\\").replace('\'', "\\'")
+                    )?;
+                }
+                write!(f, ")")
+            }
+            NativeType::Nullable(inner) => write!(f, "Nullable({inner})"),
+            NativeType::Array(inner) => write!(f, "Array({inner})"),
+            NativeType::Tuple(fields) => {
+                write!(f, "Tuple(")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{field}")?;
+                }
+                write!(f, ")")
+            }
+            NativeType::Map(key, value) => write!(f, "Map({key}, {value})"),
+        }
+    }
+}
+
+/// Split the arguments of a type name by the top-level commas.
+fn split_args(args: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '\'' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                result.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !args[start..].trim().is_empty() {
+        result.push(args[start..].trim());
+    }
+    result
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str) -> Result<T> {
+    arg.trim()
+        .parse()
+        .map_err(|_| ErrorCode::BadDataValueType(format!("invalid type argument: {arg}")))
+}
+
+/// Parse a quoted string of a type argument, such as the time zone or the name of an enum value.
+fn parse_quoted(arg: &str) -> Result<String> {
+    let arg = arg.trim();
+    if arg.len() < 2 || !arg.starts_with('\'') || !arg.ends_with('\'') {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "invalid quoted string: {arg}"
+        )));
+    }
+    let mut result = String::with_capacity(arg.len());
+    let mut chars = arg[1..arg.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            c => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+fn parse_enum_values(values: &[&str]) -> Result<Vec<(String, i16)>> {
+    values
+        .iter()
+        .map(|value| {
+            let pos = value.rfind('=').ok_or_else(|| {
+                ErrorCode::BadDataValueType(format!("invalid enum value: {value}"))
+            })?;
+            Ok((
+                parse_quoted(&value[..pos])?,
+                parse_number(&value[pos + 1..])?,
+            ))
+        })
+        .collect()
+}
+
+/// The elements of a tuple may be named, such as `Tuple(a String, b Int32)`.
+fn parse_tuple_field(field: &str) -> Result<NativeType> {
+    match NativeType::parse(field) {
+        Ok(ty) => Ok(ty),
+        Err(e) => match field.split_once(' ') {
+            Some((name, ty)) if !name.contains('(') => NativeType::parse(ty),
+            _ => Err(e),
+        },
+    }
+}
+
+/// The fixed size values which are stored as little-endian bytes.
+trait NativeValue: Copy + Send + Sync + 'static {
+    const SIZE: usize;
+
+    fn write(self, buf: &mut Vec<u8>);
+
+    fn read(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_native_value {
+    ($($t:ty),*) => {
+        $(
+            impl NativeValue for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn write(self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read(bytes: &[u8]) -> Self {
+                    <$t>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_native_value!(u8, u16, u32, u64, i8, i16, i32, i64, i128, u128, i256, u256);
+
+impl NativeValue for F32 {
+    const SIZE: usize = 4;
+
+    fn write(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0.to_le_bytes());
+    }
+
+    fn read(bytes: &[u8]) -> Self {
+        F32::from(f32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+impl NativeValue for F64 {
+    const SIZE: usize = 8;
+
+    fn write(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0.to_le_bytes());
+    }
+
+    fn read(bytes: &[u8]) -> Self {
+        F64::from(f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+fn write_values<T: NativeValue>(buf: &mut Vec<u8>, values: &[T]) {
+    buf.reserve(values.len() * T::SIZE);
+    for value in values {
+        value.write(buf);
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_values<T: NativeValue, R: AsyncRead + Unpin + Send>(
+    reader: &mut PacketReader<R>,
+    rows: usize,
+) -> Result<Vec<T>> {
+    let bytes = reader.read_bytes(bytes_of_rows(rows, T::SIZE)?).await?;
+    Ok(bytes.chunks_exact(T::SIZE).map(T::read).collect())
+}
+
+fn bytes_of_rows(rows: usize, size: usize) -> Result<usize> {
+    rows.checked_mul(size)
+        .ok_or_else(|| ErrorCode::BadBytes(format!("too many rows: {rows}")))
+}
+
+fn check_rows(rows: u64) -> Result<usize> {
+    if rows > MAX_BLOCK_ROWS as u64 {
+        return Err(ErrorCode::BadBytes(format!(
+            "too many rows: {rows}, the limit is {MAX_BLOCK_ROWS}"
+        )));
+    }
+    Ok(rows as usize)
+}
+
+/// The size in bytes of a decimal of the precision in ClickHouse.
+fn decimal_width(precision: u8) -> usize {
+    match precision {
+        0..=9 => 4,
+        10..=18 => 8,
+        19..=38 => 16,
+        _ => 32,
+    }
+}
+
+fn write_decimal(buf: &mut Vec<u8>, precision: u8, value: i256) {
+    match decimal_width(precision) {
+        4 => value.as_i32().write(buf),
+        8 => value.as_i64().write(buf),
+        16 => value.as_i128().write(buf),
+        _ => value.write(buf),
+    }
+}
+
+/// Write a block of the columns, the columns must be encodable by the native types.
+pub fn write_block(
+    buf: &mut Vec<u8>,
+    names: &[String],
+    types: &[NativeType],
+    columns: &[Column],
+    num_rows: usize,
+    revision: u64,
+    encoder: &FieldEncoderValues,
+) -> Result<()> {
+    // block info: is_overflows = false, bucket_num = -1
+    write_varuint(buf, 1);
+    buf.push(0);
+    write_varuint(buf, 2);
+    buf.extend_from_slice(&(-1i32).to_le_bytes());
+    write_varuint(buf, 0);
+
+    write_varuint(buf, names.len() as u64);
+    write_varuint(buf, num_rows as u64);
+    for (i, (name, ty)) in names.iter().zip(types.iter()).enumerate() {
+        write_string(buf, name);
+        write_string(buf, &ty.to_string());
+        if revision >= REVISION_WITH_CUSTOM_SERIALIZATION {
+            buf.push(0);
+        }
+        if num_rows > 0 {
+            write_column(buf, ty, &columns[i], encoder)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_column(
+    buf: &mut Vec<u8>,
+    ty: &NativeType,
+    column: &Column,
+    encoder: &FieldEncoderValues,
+) -> Result<()> {
+    match (ty, column) {
+        (NativeType::Nullable(inner), Column::Null { len }) => {
+            buf.extend(std::iter::repeat(1u8).take(*len));
+            write_column(buf, inner, column, encoder)?;
+        }
+        (NativeType::Nothing, Column::Null { len }) => {
+            buf.extend(std::iter::repeat(b'0').take(*len));
+        }
+        (NativeType::Nullable(inner), Column::Nullable(c)) => {
+            buf.extend(c.validity.iter().map(|valid| (!valid) as u8));
+            write_column(buf, inner, &c.column, encoder)?;
+        }
+        (_, Column::Nullable(c)) => write_column(buf, ty, &c.column, encoder)?,
+        (NativeType::Bool, Column::Boolean(c)) => buf.extend(c.iter().map(|v| v as u8)),
+        (NativeType::Number(_), Column::Number(c)) => {
+            with_number_mapped_type!(|NUM_TYPE| match c {
+                NumberColumn::NUM_TYPE(values) => write_values(buf, values),
+            })
+        }
+        (NativeType::LargeInt(_), Column::LargeInt(c)) => match c {
+            LargeIntColumn::Int128(values) => write_values(buf, values),
+            LargeIntColumn::UInt128(values) => write_values(buf, values),
+            LargeIntColumn::Int256(values) => write_values(buf, values),
+            LargeIntColumn::UInt256(values) => write_values(buf, values),
+        },
+        (NativeType::Decimal(size), Column::Decimal(c)) => match c {
+            DecimalColumn::Decimal128(values, _) => {
+                for value in values.iter() {
+                    write_decimal(buf, size.precision, i256::from(*value));
+                }
+            }
+            DecimalColumn::Decimal256(values, _) => {
+                for value in values.iter() {
+                    write_decimal(buf, size.precision, *value);
+                }
+            }
+        },
+        (NativeType::DateTime64(TIMESTAMP_PRECISION), Column::Timestamp(values)) => {
+            write_values(buf, values)
+        }
+        (NativeType::Date32, Column::Date(values)) => write_values(buf, values),
+        (NativeType::Uuid, Column::Uuid(values)) => {
+            for value in values.iter() {
+                ((*value >> 64) as u64).write(buf);
+                (*value as u64).write(buf);
+            }
+        }
+        (NativeType::Enum8(_), Column::Enum(c)) => {
+            for code in c.codes.iter() {
+                (*code as i8).write(buf);
+            }
+        }
+        (NativeType::Enum16(_), Column::Enum(c)) => {
+            for code in c.codes.iter() {
+                (*code as i16).write(buf);
+            }
+        }
+        (NativeType::Array(inner), Column::EmptyArray { len }) => {
+            write_values(buf, &vec![0u64; *len]);
+            debug_assert_eq!(inner.as_ref(), &NativeType::Nothing);
+        }
+        (NativeType::Array(inner), Column::Array(c)) => {
+            let values = write_offsets(buf, c);
+            write_column(buf, inner, &values, encoder)?;
+        }
+        (NativeType::Map(key, value), Column::Map(c)) => {
+            let values = write_offsets(buf, c);
+            match &values {
+                Column::Tuple(kv) if kv.len() == 2 => {
+                    write_column(buf, key, &kv[0], encoder)?;
+                    write_column(buf, value, &kv[1], encoder)?;
+                }
+                _ => return Err(ErrorCode::Internal("invalid map column")),
+            }
+        }
+        (NativeType::Tuple(fields), Column::Tuple(columns)) => {
+            for (field, column) in fields.iter().zip(columns.iter()) {
+                write_column(buf, field, column, encoder)?;
+            }
+        }
+        (NativeType::String, Column::String(c)) => {
+            for value in c.iter() {
+                write_string(buf, value);
+            }
+        }
+        (NativeType::String, Column::Binary(c)) => {
+            for value in c.iter() {
+                write_binary(buf, value);
+            }
+        }
+        (NativeType::String, _) => {
+            let mut value = Vec::new();
+            for row in 0..column.len() {
+                value.clear();
+                encoder.write_field(column, row, &mut value, false);
+                write_binary(buf, &value);
+            }
+        }
+        _ => {
+            return Err(ErrorCode::Internal(format!(
+                "cannot encode column of {} as {ty}",
+                column.data_type()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Write the end offsets of the rows, returns the flattened values of the rows.
+fn write_offsets(buf: &mut Vec<u8>, column: &ArrayColumn<AnyType>) -> Column {
+    let start = column.offsets[0];
+    let end = column.offsets[column.offsets.len() - 1];
+    for offset in column.offsets.iter().skip(1) {
+        (*offset - start).write(buf);
+    }
+    column.values.slice(start as usize..end as usize)
+}
+
+/// A block decoded from a data packet.
+pub struct NativeBlock {
+    pub names: Vec<String>,
+    pub types: Vec<NativeType>,
+    pub columns: Vec<Column>,
+    pub num_rows: usize,
+}
+
+impl NativeBlock {
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty() && self.num_rows == 0
+    }
+}
+
+#[async_backtrace::framed]
+pub async fn read_block<R: AsyncRead + Unpin + Send>(
+    reader: &mut PacketReader<R>,
+    revision: u64,
+) -> Result<NativeBlock> {
+    loop {
+        match reader.read_varuint().await? {
+            0 => break,
+            1 => {
+                reader.read_u8().await?;
+            }
+            2 => {
+                reader.read_i32().await?;
+            }
+            field => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unknown field of block info: {field}"
+                )));
+            }
+        }
+    }
+
+    let num_columns = reader.read_varuint().await?;
+    if num_columns > MAX_BLOCK_COLUMNS as u64 {
+        return Err(ErrorCode::BadBytes(format!(
+            "too many columns: {num_columns}, the limit is {MAX_BLOCK_COLUMNS}"
+        )));
+    }
+    let num_columns = num_columns as usize;
+    let num_rows = check_rows(reader.read_varuint().await?)?;
+    let mut block = NativeBlock {
+        names: Vec::with_capacity(num_columns),
+        types: Vec::with_capacity(num_columns),
+        columns: Vec::with_capacity(num_columns),
+        num_rows,
+    };
+    for _ in 0..num_columns {
+        let name = reader.read_string().await?;
+        let ty = NativeType::parse(&reader.read_string().await?)?;
+        if revision >= REVISION_WITH_CUSTOM_SERIALIZATION && reader.read_u8().await? != 0 {
+            return Err(ErrorCode::BadBytes(format!(
+                "custom serialization of column {name} is not supported"
+            )));
+        }
+        let column = read_column(reader, &ty, num_rows).await?;
+        block.names.push(name);
+        block.types.push(ty);
+        block.columns.push(column);
+    }
+    Ok(block)
+}
+
+#[async_recursion::async_recursion]
+async fn read_column<R: AsyncRead + Unpin + Send>(
+    reader: &mut PacketReader<R>,
+    ty: &NativeType,
+    rows: usize,
+) -> Result<Column> {
+    let column = match ty {
+        NativeType::Nothing => {
+            reader.read_bytes(rows).await?;
+            Column::Null { len: rows }
+        }
+        NativeType::Bool => {
+            let values = reader.read_bytes(rows).await?;
+            Column::Boolean(values.iter().map(|v| *v != 0).collect())
+        }
+        NativeType::Number(ty) => with_number_mapped_type!(|NUM_TYPE| match ty {
+            NumberDataType::NUM_TYPE => Column::Number(NUM_TYPE::upcast_column(
+                read_values::<NUM_TYPE, R>(reader, rows).await?.into()
+            )),
+        }),
+        NativeType::LargeInt(ty) => Column::LargeInt(match ty {
+            LargeIntDataType::Int128 => {
+                LargeIntColumn::Int128(read_values::<i128, R>(reader, rows).await?.into())
+            }
+            LargeIntDataType::UInt128 => {
+                LargeIntColumn::UInt128(read_values::<u128, R>(reader, rows).await?.into())
+            }
+            LargeIntDataType::Int256 => {
+                LargeIntColumn::Int256(read_values::<i256, R>(reader, rows).await?.into())
+            }
+            LargeIntDataType::UInt256 => {
+                LargeIntColumn::UInt256(read_values::<u256, R>(reader, rows).await?.into())
+            }
+        }),
+        NativeType::String => {
+            let mut builder = StringColumnBuilder::with_capacity(rows, 0);
+            for _ in 0..rows {
+                let value = reader.read_binary().await?;
+                builder.put_str(utf8(&value)?);
+                builder.commit_row();
+            }
+            Column::String(builder.build())
+        }
+        NativeType::FixedString(n) => {
+            let bytes = reader.read_bytes(bytes_of_rows(rows, *n)?).await?;
+            let mut builder = StringColumnBuilder::with_capacity(rows, bytes.len());
+            for value in bytes.chunks(*n) {
+                let len = value.iter().rposition(|b| *b != 0).map_or(0, |pos| pos + 1);
+                builder.put_str(utf8(&value[..len])?);
+                builder.commit_row();
+            }
+            Column::String(builder.build())
+        }
+        NativeType::Date => {
+            let values = read_values::<u16, R>(reader, rows).await?;
+            Column::Date(values.into_iter().map(|v| v as i32).collect())
+        }
+        NativeType::Date32 => Column::Date(read_values::<i32, R>(reader, rows).await?.into()),
+        NativeType::DateTime => {
+            let values = read_values::<u32, R>(reader, rows).await?;
+            Column::Timestamp(
+                values
+                    .into_iter()
+                    .map(|v| v as i64 * MICROS_PER_SECOND)
+                    .collect(),
+            )
+        }
+        NativeType::DateTime64(precision) => {
+            let values = read_values::<i64, R>(reader, rows).await?;
+            let values = if *precision <= TIMESTAMP_PRECISION {
+                let scale = 10i64.pow(TIMESTAMP_PRECISION - precision);
+                values
+                    .into_iter()
+                    .map(|v| {
+                        v.checked_mul(scale).ok_or_else(|| {
+                            ErrorCode::BadBytes(format!("DateTime64 value out of range: {v}"))
+                        })
+                    })
+                    .collect::<Result<_>>()?
+            } else {
+                let scale = 10i64.pow(precision - TIMESTAMP_PRECISION);
+                values.into_iter().map(|v| v / scale).collect()
+            };
+            Column::Timestamp(values)
+        }
+        NativeType::Decimal(size) => {
+            let values: Vec<i256> = match decimal_width(size.precision) {
+                4 => read_values::<i32, R>(reader, rows)
+                    .await?
+                    .into_iter()
+                    .map(i256::from)
+                    .collect(),
+                8 => read_values::<i64, R>(reader, rows)
+                    .await?
+                    .into_iter()
+                    .map(i256::from)
+                    .collect(),
+                16 => read_values::<i128, R>(reader, rows)
+                    .await?
+                    .into_iter()
+                    .map(i256::from)
+                    .collect(),
+                _ => read_values::<i256, R>(reader, rows).await?,
+            };
+            Column::Decimal(match DecimalDataType::from_size(*size)? {
+                DecimalDataType::Decimal128(size) => DecimalColumn::Decimal128(
+                    values.into_iter().map(|v| v.as_i128()).collect(),
+                    size,
+                ),
+                DecimalDataType::Decimal256(size) => DecimalColumn::Decimal256(values.into(), size),
+            })
+        }
+        NativeType::Uuid => {
+            let values = read_values::<u64, R>(reader, bytes_of_rows(rows, 2)?).await?;
+            Column::Uuid(
+                values
+                    .chunks_exact(2)
+                    .map(|v| ((v[0] as u128) << 64) | v[1] as u128)
+                    .collect(),
+            )
+        }
+        NativeType::Enum8(values) | NativeType::Enum16(values) => {
+            let codes: Vec<i16> = if matches!(ty, NativeType::Enum8(_)) {
+                read_values::<i8, R>(reader, rows)
+                    .await?
+                    .into_iter()
+                    .map(|v| v as i16)
+                    .collect()
+            } else {
+                read_values::<i16, R>(reader, rows).await?
+            };
+            let mut builder = StringColumnBuilder::with_capacity(rows, 0);
+            for code in codes {
+                let (name, _) = values
+                    .iter()
+                    .find(|(_, v)| *v == code)
+                    .ok_or_else(|| ErrorCode::BadBytes(format!("unknown enum value: {code}")))?;
+                builder.put_str(name);
+                builder.commit_row();
+            }
+            Column::String(builder.build())
+        }
+        NativeType::Nullable(inner) => {
+            let nulls = reader.read_bytes(rows).await?;
+            let column = read_column(reader, inner, rows).await?;
+            match column {
+                Column::Null { .. } => column,
+                column => NullableColumn::new_column(
+                    column,
+                    nulls.iter().map(|null| *null == 0).collect(),
+                ),
+            }
+        }
+        NativeType::Array(inner) | NativeType::Map(inner, _) => {
+            let ends = read_values::<u64, R>(reader, rows).await?;
+            let mut offsets = Vec::with_capacity(rows + 1);
+            offsets.push(0);
+            for end in ends {
+                if end < offsets[offsets.len() - 1] {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "offsets of {ty} are not increasing"
+                    )));
+                }
+                offsets.push(end);
+            }
+            let total = check_rows(offsets[offsets.len() - 1])?;
+            let values = match ty {
+                NativeType::Map(key, value) => Column::Tuple(vec![
+                    read_column(reader, key, total).await?,
+                    read_column(reader, value, total).await?,
+                ]),
+                _ => read_column(reader, inner, total).await?,
+            };
+            match (ty, values) {
+                (NativeType::Array(_), Column::Null { .. }) => Column::EmptyArray { len: rows },
+                (NativeType::Array(_), values) => Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: offsets.into(),
+                })),
+                (_, values) => Column::Map(Box::new(ArrayColumn {
+                    values,
+                    offsets: offsets.into(),
+                })),
+            }
+        }
+        NativeType::Tuple(fields) => {
+            let mut columns = Vec::with_capacity(fields.len());
+            for field in fields {
+                columns.push(read_column(reader, field, rows).await?);
+            }
+            Column::Tuple(columns)
+        }
+    };
+    Ok(column)
+}
+
+fn utf8(value: &[u8]) -> Result<&str> {
+    std::str::from_utf8(value).map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 string: {e}")))
+}
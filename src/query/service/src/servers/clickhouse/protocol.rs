@@ -0,0 +1,341 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The packets and primitive encodings of the ClickHouse native protocol.
+//!
+//! Integers are little-endian, lengths and packet types are LEB128 varints, and strings
+//! are prefixed by their byte length. See `src/Core/Protocol.h` of ClickHouse.
+
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use naive_cityhash::cityhash128;
+
+pub const CLIENT_HELLO: u64 = 0;
+pub const CLIENT_QUERY: u64 = 1;
+pub const CLIENT_DATA: u64 = 2;
+pub const CLIENT_CANCEL: u64 = 3;
+pub const CLIENT_PING: u64 = 4;
+
+pub const SERVER_HELLO: u64 = 0;
+pub const SERVER_DATA: u64 = 1;
+pub const SERVER_EXCEPTION: u64 = 2;
+pub const SERVER_PROGRESS: u64 = 3;
+pub const SERVER_PONG: u64 = 4;
+pub const SERVER_END_OF_STREAM: u64 = 5;
+pub const SERVER_PROFILE_INFO: u64 = 6;
+
+/// The protocol revision of the server, the revision of a connection is the minimum of
+/// the client and the server revisions.
+pub const SERVER_REVISION: u64 = 54460;
+/// The settings of a query are sent as strings since this revision, older clients are rejected.
+pub const MIN_CLIENT_REVISION: u64 = 54429;
+
+pub const REVISION_WITH_SERVER_TIMEZONE: u64 = 54058;
+pub const REVISION_WITH_QUOTA_KEY_IN_CLIENT_INFO: u64 = 54060;
+pub const REVISION_WITH_SERVER_DISPLAY_NAME: u64 = 54372;
+pub const REVISION_WITH_VERSION_PATCH: u64 = 54401;
+pub const REVISION_WITH_CLIENT_WRITE_INFO: u64 = 54420;
+pub const REVISION_WITH_INTERSERVER_SECRET: u64 = 54441;
+pub const REVISION_WITH_OPENTELEMETRY: u64 = 54442;
+pub const REVISION_WITH_DISTRIBUTED_DEPTH: u64 = 54448;
+pub const REVISION_WITH_INITIAL_QUERY_START_TIME: u64 = 54449;
+pub const REVISION_WITH_PARALLEL_REPLICAS: u64 = 54453;
+pub const REVISION_WITH_CUSTOM_SERIALIZATION: u64 = 54454;
+pub const REVISION_WITH_ADDENDUM: u64 = 54458;
+pub const REVISION_WITH_PARAMETERS: u64 = 54459;
+pub const REVISION_WITH_SERVER_QUERY_TIME_IN_PROGRESS: u64 = 54460;
+
+/// The query is executed till the end, other stages are used by distributed queries.
+pub const STAGE_COMPLETE: u64 = 2;
+
+/// The interface field of the client info, only the TCP interface is supported.
+pub const INTERFACE_TCP: u8 = 1;
+
+const COMPRESSION_METHOD_NONE: u8 = 0x02;
+const COMPRESSION_METHOD_LZ4: u8 = 0x82;
+const CHECKSUM_SIZE: usize = 16;
+const COMPRESSION_HEADER_SIZE: usize = 9;
+const MAX_COMPRESSED_SIZE: usize = 0x40000000;
+const READ_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Reads the packets sent by the client.
+///
+/// The data blocks are read in the compressed mode if the query enables compression,
+/// the frames are decompressed on demand and a block never shares a frame with the next packet.
+pub struct PacketReader<R> {
+    reader: R,
+    compressed: bool,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl<R: AsyncRead + Unpin + Send> PacketReader<R> {
+    pub fn create(reader: R) -> Self {
+        PacketReader {
+            reader,
+            compressed: false,
+            buffer: vec![],
+            position: 0,
+        }
+    }
+
+    pub fn set_compressed(&mut self, compressed: bool) {
+        self.compressed = compressed;
+        self.buffer.clear();
+        self.position = 0;
+    }
+
+    /// Read the type of the next packet, returns `None` if the client closed the connection.
+    #[async_backtrace::framed]
+    pub async fn read_packet_type(&mut self) -> Result<Option<u64>> {
+        let mut first = [0u8; 1];
+        if self.reader.read(&mut first).await? == 0 {
+            return Ok(None);
+        }
+        if first[0] & 0x80 == 0 {
+            return Ok(Some(first[0] as u64));
+        }
+        let rest = self.read_varuint().await?;
+        Ok(Some((first[0] & 0x7f) as u64 | (rest << 7)))
+    }
+
+    /// Read `n` bytes, the buffer grows with the received bytes instead of being allocated
+    /// by the size sent by the client.
+    #[async_backtrace::framed]
+    pub async fn read_bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+        if n > MAX_COMPRESSED_SIZE {
+            return Err(ErrorCode::BadBytes(format!("too many bytes to read: {n}")));
+        }
+        let mut bytes = Vec::with_capacity(n.min(READ_BUFFER_SIZE));
+        if !self.compressed {
+            (&mut self.reader)
+                .take(n as u64)
+                .read_to_end(&mut bytes)
+                .await?;
+            if bytes.len() < n {
+                return Err(ErrorCode::BadBytes("unexpected end of packet"));
+            }
+            return Ok(bytes);
+        }
+
+        while bytes.len() < n {
+            if self.position == self.buffer.len() {
+                self.read_frame().await?;
+            }
+            let len = (n - bytes.len()).min(self.buffer.len() - self.position);
+            bytes.extend_from_slice(&self.buffer[self.position..self.position + len]);
+            self.position += len;
+        }
+        Ok(bytes)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1).await?[0])
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_u64(&mut self) -> Result<u64> {
+        let bytes = self.read_bytes(8).await?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_i32(&mut self) -> Result<i32> {
+        let bytes = self.read_bytes(4).await?;
+        Ok(i32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_varuint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let byte = self.read_u8().await?;
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ErrorCode::BadBytes("varuint is too long"))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_binary(&mut self) -> Result<Vec<u8>> {
+        let len = self.read_varuint().await? as usize;
+        if len > MAX_COMPRESSED_SIZE {
+            return Err(ErrorCode::BadBytes(format!("string is too long: {len}")));
+        }
+        self.read_bytes(len).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_string(&mut self) -> Result<String> {
+        let bytes = self.read_binary().await?;
+        String::from_utf8(bytes).map_err_to_code(ErrorCode::BadBytes, || "invalid utf8 string")
+    }
+
+    #[async_backtrace::framed]
+    async fn read_frame(&mut self) -> Result<()> {
+        let mut checksum = [0u8; CHECKSUM_SIZE];
+        self.reader.read_exact(&mut checksum).await?;
+        let mut frame = vec![0u8; COMPRESSION_HEADER_SIZE];
+        self.reader.read_exact(&mut frame).await?;
+
+        let method = frame[0];
+        let compressed_size = u32::from_le_bytes(frame[1..5].try_into().unwrap()) as usize;
+        let decompressed_size = u32::from_le_bytes(frame[5..9].try_into().unwrap()) as usize;
+        if !(COMPRESSION_HEADER_SIZE..=MAX_COMPRESSED_SIZE).contains(&compressed_size)
+            || decompressed_size > MAX_COMPRESSED_SIZE
+        {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid compressed frame size: {compressed_size}, {decompressed_size}"
+            )));
+        }
+        (&mut self.reader)
+            .take((compressed_size - COMPRESSION_HEADER_SIZE) as u64)
+            .read_to_end(&mut frame)
+            .await?;
+        if frame.len() < compressed_size {
+            return Err(ErrorCode::BadBytes("unexpected end of compressed frame"));
+        }
+
+        let hash = cityhash128(&frame);
+        if checksum[..8] != hash.lo.to_le_bytes() || checksum[8..] != hash.hi.to_le_bytes() {
+            return Err(ErrorCode::BadBytes("checksum mismatch of compressed frame"));
+        }
+
+        let data = &frame[COMPRESSION_HEADER_SIZE..];
+        self.buffer = match method {
+            COMPRESSION_METHOD_LZ4 => lz4::block::decompress(data, Some(decompressed_size as i32))
+                .map_err_to_code(ErrorCode::BadBytes, || "lz4 decompress error")?,
+            COMPRESSION_METHOD_NONE => data.to_vec(),
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported compression method: {method:#x}"
+                )));
+            }
+        };
+        self.position = 0;
+        Ok(())
+    }
+}
+
+pub fn write_varuint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+pub fn write_binary(buf: &mut Vec<u8>, value: &[u8]) {
+    write_varuint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+pub fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_binary(buf, value.as_bytes());
+}
+
+/// The `ServerHello` packet, the reply of the client hello.
+pub struct ServerHello<'a> {
+    pub name: &'a str,
+    pub version: (u64, u64, u64),
+    pub timezone: &'a str,
+    pub display_name: &'a str,
+}
+
+impl ServerHello<'_> {
+    pub fn write(&self, buf: &mut Vec<u8>, revision: u64) {
+        write_varuint(buf, SERVER_HELLO);
+        write_string(buf, self.name);
+        write_varuint(buf, self.version.0);
+        write_varuint(buf, self.version.1);
+        write_varuint(buf, SERVER_REVISION);
+        if revision >= REVISION_WITH_SERVER_TIMEZONE {
+            write_string(buf, self.timezone);
+        }
+        if revision >= REVISION_WITH_SERVER_DISPLAY_NAME {
+            write_string(buf, self.display_name);
+        }
+        if revision >= REVISION_WITH_VERSION_PATCH {
+            write_varuint(buf, self.version.2);
+        }
+    }
+}
+
+/// The `Progress` packet, the values are increments since the last progress packet.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Progress {
+    pub read_rows: u64,
+    pub read_bytes: u64,
+    pub total_rows: u64,
+    pub written_rows: u64,
+    pub written_bytes: u64,
+    pub elapsed_ns: u64,
+}
+
+impl Progress {
+    pub fn write(&self, buf: &mut Vec<u8>, revision: u64) {
+        write_varuint(buf, SERVER_PROGRESS);
+        write_varuint(buf, self.read_rows);
+        write_varuint(buf, self.read_bytes);
+        write_varuint(buf, self.total_rows);
+        if revision >= REVISION_WITH_CLIENT_WRITE_INFO {
+            write_varuint(buf, self.written_rows);
+            write_varuint(buf, self.written_bytes);
+        }
+        if revision >= REVISION_WITH_SERVER_QUERY_TIME_IN_PROGRESS {
+            write_varuint(buf, self.elapsed_ns);
+        }
+    }
+}
+
+/// The `ProfileInfo` packet, sent after all the data blocks of a query.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ProfileInfo {
+    pub rows: u64,
+    pub blocks: u64,
+    pub bytes: u64,
+}
+
+impl ProfileInfo {
+    pub fn write(&self, buf: &mut Vec<u8>) {
+        write_varuint(buf, SERVER_PROFILE_INFO);
+        write_varuint(buf, self.rows);
+        write_varuint(buf, self.blocks);
+        write_varuint(buf, self.bytes);
+        // applied_limit, rows_before_limit and calculated_rows_before_limit
+        buf.push(0);
+        write_varuint(buf, 0);
+        buf.push(0);
+    }
+}
+
+pub fn write_exception(buf: &mut Vec<u8>, error: &ErrorCode) {
+    write_varuint(buf, SERVER_EXCEPTION);
+    buf.extend_from_slice(&(error.code() as i32).to_le_bytes());
+    write_string(buf, &format!("DB::Exception({})", error.name()));
+    write_string(buf, &error.message());
+    write_string(buf, "");
+    // has_nested
+    buf.push(0);
+}
+
+pub fn write_packet(buf: &mut Vec<u8>, packet_type: u64) {
+    write_varuint(buf, packet_type);
+}
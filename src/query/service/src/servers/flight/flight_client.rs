@@ -27,6 +27,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use fastrace::func_path;
 use fastrace::future::FutureExt;
+use fastrace::local::LocalSpan;
 use fastrace::Span;
 use futures::StreamExt;
 use futures_util::future::Either;
@@ -234,11 +235,20 @@ impl FlightReceiver {
 
     #[async_backtrace::framed]
     pub async fn recv(&self) -> Result<Option<DataPacket>> {
-        match self.rx.recv().await {
-            Err(_) => Ok(None),
-            Ok(Err(error)) => Err(error),
-            Ok(Ok(message)) => Ok(Some(DataPacket::try_from(message)?)),
+        let span = Span::enter_with_local_parent(func_path!());
+        async move {
+            match self.rx.recv().await {
+                Err(_) => Ok(None),
+                Ok(Err(error)) => Err(error),
+                Ok(Ok(message)) => {
+                    let _span = LocalSpan::enter_with_local_parent("FlightReceiver::decode")
+                        .with_property(|| ("bytes", flight_data_size(&message).to_string()));
+                    Ok(Some(DataPacket::try_from(message)?))
+                }
+            }
         }
+        .in_span(span)
+        .await
     }
 
     pub fn close(&self) {
@@ -262,7 +272,11 @@ impl FlightSender {
 
     #[async_backtrace::framed]
     pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.tx.send(Ok(FlightData::try_from(data)?)).await {
+        let data = FlightData::try_from(data)?;
+        let span = Span::enter_with_local_parent(func_path!())
+            .with_property(|| ("bytes", flight_data_size(&data).to_string()));
+
+        if let Err(_cause) = self.tx.send(Ok(data)).in_span(span).await {
             return Err(ErrorCode::AbortedQuery(
                 "Aborted query, because the remote flight channel is closed.",
             ));
@@ -276,6 +290,10 @@ impl FlightSender {
     }
 }
 
+fn flight_data_size(data: &FlightData) -> usize {
+    data.data_header.len() + data.data_body.len()
+}
+
 pub enum FlightExchange {
     Dummy,
     Receiver {
@@ -147,8 +147,12 @@ impl DataExchangeManager {
 
                     let query_id = env.query_id.clone();
                     let address = source.flight_address.clone();
+                    let span = Span::enter_with_local_parent("FlightExchange::connect")
+                        .with_properties(|| {
+                            [("source", source.id.clone()), ("target", target.id.clone())]
+                        });
 
-                    flight_exchanges.push(async move {
+                    let task = async move {
                         let mut flight_client = Self::create_client(&address, with_cur_rt).await?;
 
                         Ok::<QueryExchange, ErrorCode>(match edge {
@@ -164,7 +168,8 @@ impl DataExchangeManager {
                                     .await?,
                             },
                         })
-                    });
+                    };
+                    flight_exchanges.push(task.in_span(span));
                 }
 
                 let flight_exchanges = futures::future::try_join_all(flight_exchanges).await?;
@@ -865,6 +870,7 @@ impl QueryCoordinator {
 
         let span = if let Some(parent) = SpanContext::current_local_parent() {
             Span::root("Distributed-Executor", parent)
+                .with_property(|| ("query-id", query_id.clone()))
         } else {
             Span::noop()
         };
@@ -17,6 +17,9 @@ use std::sync::Arc;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_flight::sql::CommandGetCatalogs;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_schema::DataType;
 use arrow_schema::Field;
@@ -25,6 +28,7 @@ use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
@@ -45,35 +49,42 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    async fn list_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)>
-    {
+    ) -> databend_common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        if let Some(catalog_name) = catalog_name {
+            Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr
                     .get_catalog(tenant.tenant_name(), &catalog_name, ctx.session_state())
                     .await?,
-            )]
+            )])
         } else {
-            catalog_mgr
+            Ok(catalog_mgr
                 .list_catalogs(&tenant, ctx.session_state())
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
-                .collect()
-        };
+                .collect())
+        }
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)>
+    {
+        let tenant = ctx.get_tenant();
+        let catalogs = Self::list_catalogs(&ctx, catalog_name).await?;
 
         let mut catalog_names = vec![];
         let mut database_names = vec![];
         let mut table_names = vec![];
         let mut table_types = vec![];
-        let table_type = "table".to_string();
         for (catalog_name, catalog) in catalogs.into_iter() {
             let dbs = if let Some(database_name) = &database_name {
                 vec![catalog.get_database(&tenant, database_name).await?]
@@ -94,7 +105,7 @@ impl CatalogInfoProvider {
                     catalog_names.push(catalog_name.clone());
                     database_names.push(db_name.to_string());
                     table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    table_types.push(Self::table_type(table.engine()).to_string());
                 }
             }
         }
@@ -126,6 +137,95 @@ impl CatalogInfoProvider {
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_catalogs(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetCatalogs,
+    ) -> Result<DoGetStream, Status> {
+        let catalogs = Self::list_catalogs(&ctx, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut builder = query.into_builder();
+        for (catalog_name, _) in catalogs {
+            builder.append(catalog_name);
+        }
+        let batch = builder
+            .build()
+            .map_err(|e| Status::internal(format!("build catalogs fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetDbSchemas,
+    ) -> Result<DoGetStream, Status> {
+        let tenant = ctx.get_tenant();
+        let catalogs = Self::list_catalogs(&ctx, query.catalog.clone())
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        // the builder filters the schemas by `db_schema_filter_pattern`.
+        let mut builder = query.into_builder();
+        for (catalog_name, catalog) in catalogs {
+            let dbs = catalog
+                .list_databases(&tenant)
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+            for db in dbs {
+                builder.append(&catalog_name, db.name());
+            }
+        }
+        let batch = builder
+            .build()
+            .map_err(|e| Status::internal(format!("build schemas fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let table_types = vec![
+            Self::table_type("").to_string(),
+            Self::table_type(VIEW_ENGINE).to_string(),
+        ];
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(table_types)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend has no primary key, the table is checked to exist and an empty result is returned.
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetPrimaryKeys,
+    ) -> Result<DoGetStream, Status> {
+        let catalog_name = query.catalog.unwrap_or_else(|| ctx.get_current_catalog());
+        let database_name = query
+            .db_schema
+            .unwrap_or_else(|| ctx.get_current_database());
+        ctx.get_table(&catalog_name, &database_name, &query.table)
+            .await
+            .map_err(|e| Status::not_found(e.message()))?;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
+    fn table_type(engine: &str) -> &'static str {
+        if engine == VIEW_ENGINE {
+            "view"
+        } else {
+            "table"
+        }
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
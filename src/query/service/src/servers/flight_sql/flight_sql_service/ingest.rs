@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::error::FlightError;
+use arrow_flight::sql::server::PeekableFlightDataStream;
+use arrow_flight::sql::CommandStatementIngest;
+use arrow_flight::sql::TableExistsOption;
+use arrow_flight::sql::TableNotExistOption;
+use databend_common_ast::ast::Engine;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::plans::Plan;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use super::FlightSqlServiceImpl;
+use crate::interpreters::IngestInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+
+impl FlightSqlServiceImpl {
+    /// Ingest the record batches into a table, all the batches are committed at once.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub(super) async fn ingest(
+        &self,
+        session: Arc<Session>,
+        cmd: CommandStatementIngest,
+        stream: PeekableFlightDataStream,
+    ) -> Result<i64> {
+        if let Some(transaction_id) = &cmd.transaction_id {
+            let txn_mgr = session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if !txn_mgr.is_active() || txn_mgr.txn_id().as_bytes() != transaction_id.as_ref() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "transaction not found: {}",
+                    String::from_utf8_lossy(transaction_id)
+                )));
+            }
+        }
+
+        let mut batches =
+            FlightRecordBatchStream::new_from_flight_data(stream.map_err(FlightError::Tonic));
+        let first = batches
+            .try_next()
+            .await
+            .map_err(|e| ErrorCode::BadBytes(format!("fail to decode ingest data: {e}")))?;
+        let arrow_schema = batches
+            .schema()
+            .cloned()
+            .ok_or_else(|| ErrorCode::BadArguments("ingest data has no schema"))?;
+        let table_schema = TableSchema::try_from(arrow_schema.as_ref())?;
+        let source_schema = Arc::new(DataSchema::from(&table_schema));
+
+        let context = session.create_query_context().await?;
+        let catalog = cmd
+            .catalog
+            .clone()
+            .unwrap_or_else(|| context.get_current_catalog());
+        let database = cmd
+            .schema
+            .clone()
+            .unwrap_or_else(|| context.get_current_database());
+        let table = cmd.table.clone();
+
+        let options = cmd.table_definition_options.unwrap_or_default();
+        let overwrite = match context.get_table(&catalog, &database, &table).await {
+            Ok(_) => match options.if_exists() {
+                TableExistsOption::Append => false,
+                TableExistsOption::Replace => true,
+                TableExistsOption::Fail | TableExistsOption::Unspecified => {
+                    return Err(ErrorCode::TableAlreadyExists(format!(
+                        "Table '{database}'.'{table}' already exists"
+                    )));
+                }
+            },
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => match options.if_not_exist() {
+                TableNotExistOption::Create => {
+                    Self::create_ingest_table(
+                        &context,
+                        &catalog,
+                        &database,
+                        &table,
+                        cmd.temporary,
+                        table_schema,
+                    )
+                    .await?;
+                    false
+                }
+                TableNotExistOption::Fail | TableNotExistOption::Unspecified => return Err(e),
+            },
+            Err(e) => return Err(e),
+        };
+
+        // the table is created by another query, use a new context to avoid the table cache.
+        let context = session.create_query_context().await?;
+        context.attach_query_str(
+            QueryKind::Insert,
+            format!("INGEST INTO `{database}`.`{table}`"),
+        );
+        let schema = source_schema.clone();
+        let blocks = futures::stream::iter(first.map(Ok))
+            .chain(batches)
+            .map(move |batch| {
+                let batch = batch
+                    .map_err(|e| ErrorCode::BadBytes(format!("fail to decode ingest data: {e}")))?;
+                let (block, _) = DataBlock::from_record_batch(&schema, &batch)?;
+                Ok(block)
+            });
+        let interpreter = IngestInterpreter::try_create(
+            context.clone(),
+            catalog,
+            database,
+            table,
+            source_schema,
+            Box::pin(blocks),
+            overwrite,
+        )?;
+        let mut stream = interpreter.execute(context.clone()).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+
+        let affected_rows = context.get_write_progress_value().rows;
+        Ok(affected_rows as i64)
+    }
+
+    #[async_backtrace::framed]
+    async fn create_ingest_table(
+        context: &Arc<QueryContext>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        temporary: bool,
+        schema: TableSchema,
+    ) -> Result<()> {
+        let mut options = BTreeMap::new();
+        if temporary {
+            options.insert(
+                OPT_KEY_TEMP_PREFIX.to_string(),
+                context.get_temp_table_prefix()?,
+            );
+        }
+        let plan = CreateTablePlan {
+            create_option: CreateOption::Create,
+            tenant: context.get_tenant(),
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            schema: Arc::new(schema),
+            engine: Engine::Fuse,
+            engine_options: BTreeMap::new(),
+            storage_params: None,
+            part_prefix: "".to_string(),
+            options,
+            field_comments: vec![],
+            cluster_key: None,
+            as_select: None,
+            inverted_indexes: None,
+        };
+        context.attach_query_str(
+            QueryKind::Other,
+            format!("CREATE TABLE `{database}`.`{table}`"),
+        );
+        let plan = Plan::CreateTable(Box::new(plan));
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+        let mut stream = interpreter.execute(context.clone()).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+}
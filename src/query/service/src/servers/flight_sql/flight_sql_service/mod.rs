@@ -15,10 +15,12 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 mod catalog;
+mod ingest;
 mod query;
 mod service;
 mod session;
 mod sql_info;
+mod type_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
@@ -32,6 +34,7 @@ use futures::Stream;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
+use type_info::XdbcTypeInfoProvider;
 use uuid::Uuid;
 
 use crate::servers::http::v1::ExpiringMap;
@@ -52,6 +55,8 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
     statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    /// The id of the running query of each prepared statement handle, used to cancel it.
+    running_queries: Arc<DashMap<Uuid, String>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -60,6 +65,7 @@ impl FlightSqlServiceImpl {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
             statements: Arc::new(Default::default()),
+            running_queries: Arc::new(Default::default()),
         }
     }
 }
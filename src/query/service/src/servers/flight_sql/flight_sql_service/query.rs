@@ -18,6 +18,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use arrow_flight::sql::SubstraitPlan;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
@@ -37,12 +38,14 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
+use fastrace::prelude::*;
 use futures::Stream;
 use futures::StreamExt;
 use prost::bytes;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
@@ -110,6 +113,7 @@ impl FlightSqlServiceImpl {
     }
 
     #[async_backtrace::framed]
+    #[fastrace::trace]
     pub async fn plan_sql(
         &self,
         session: &Arc<Session>,
@@ -125,6 +129,24 @@ impl FlightSqlServiceImpl {
     }
 
     #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn plan_substrait(
+        &self,
+        session: &Arc<Session>,
+        plan: Option<SubstraitPlan>,
+    ) -> Result<(Plan, PlanExtras)> {
+        let plan = plan.ok_or_else(|| ErrorCode::BadArguments("substrait plan is missing"))?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let mut planner = Planner::new(context.clone());
+        planner.plan_substrait(&plan.plan).await
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
     pub(super) async fn execute_update(
         &self,
         session: Arc<Session>,
@@ -136,10 +158,7 @@ impl FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
-        context.attach_query_str(
-            get_query_kind(&plan_extras.statement),
-            plan_extras.statement.to_mask_sql(),
-        );
+        attach_query_str(&context, plan, plan_extras);
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let mut blocks = interpreter.execute(context.clone()).await?;
@@ -151,9 +170,11 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    #[fastrace::trace]
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
+        handle: Uuid,
         plan: &Plan,
         plan_extras: &PlanExtras,
     ) -> Result<DoGetStream> {
@@ -164,15 +185,16 @@ impl FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
-        context.attach_query_str(
-            get_query_kind(&plan_extras.statement),
-            plan_extras.statement.to_mask_sql(),
-        );
+        attach_query_str(&context, plan, plan_extras);
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let data_schema = plan.schema();
         let data_stream = interpreter.execute(context.clone()).await?;
 
+        let query_id = context.get_id();
+        self.running_queries.insert(handle, query_id.clone());
+        let running_queries = self.running_queries.clone();
+
         let is_finished = Arc::new(AtomicBool::new(false));
         let is_finished_clone = is_finished.clone();
         let (sender, receiver) = tokio::sync::mpsc::channel(2);
@@ -181,7 +203,8 @@ impl FlightSqlServiceImpl {
             .await;
 
         let s1 = sender.clone();
-        databend_common_base::runtime::spawn(async move {
+        let span = Span::enter_with_local_parent("FlightSQL::stream_result");
+        let task = async move {
             let mut data_stream = data_stream;
 
             while let Some(block) = data_stream.next().await {
@@ -203,8 +226,10 @@ impl FlightSqlServiceImpl {
                     }
                 }
             }
+            running_queries.remove_if(&handle, |_, running_query_id| *running_query_id == query_id);
             is_finished_clone.store(true, Ordering::SeqCst);
-        });
+        };
+        databend_common_base::runtime::spawn(task.in_span(span));
 
         if is_native_client {
             databend_common_base::runtime::spawn(async move {
@@ -291,3 +316,12 @@ struct ProgressValue {
     pub write_rows: usize,
     pub write_bytes: usize,
 }
+
+/// Attach the query text of the statement the plan is planned from, a plan which is not
+/// planned from SQL, e.g. a Substrait plan, has none.
+fn attach_query_str(context: &QueryContext, plan: &Plan, plan_extras: &PlanExtras) {
+    match &plan_extras.statement {
+        Some(stmt) => context.attach_query_str(get_query_kind(stmt), stmt.to_mask_sql()),
+        None => context.attach_query_str(plan.kind(), String::new()),
+    }
+}
@@ -33,6 +33,7 @@ use arrow_flight::sql::ActionCreatePreparedSubstraitPlanRequest;
 use arrow_flight::sql::ActionEndSavepointRequest;
 use arrow_flight::sql::ActionEndTransactionRequest;
 use arrow_flight::sql::Any;
+use arrow_flight::sql::CancelResult;
 use arrow_flight::sql::CommandGetCatalogs;
 use arrow_flight::sql::CommandGetCrossReference;
 use arrow_flight::sql::CommandGetDbSchemas;
@@ -45,11 +46,13 @@ use arrow_flight::sql::CommandGetTables;
 use arrow_flight::sql::CommandGetXdbcTypeInfo;
 use arrow_flight::sql::CommandPreparedStatementQuery;
 use arrow_flight::sql::CommandPreparedStatementUpdate;
+use arrow_flight::sql::CommandStatementIngest;
 use arrow_flight::sql::CommandStatementQuery;
 use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::sql::CommandStatementUpdate;
 use arrow_flight::sql::DoPutPreparedStatementResult;
 use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::EndTransaction;
 use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
@@ -66,7 +69,14 @@ use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
 use databend_common_expression::DataSchema;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
+use databend_common_tracing::start_trace_for_remote_request;
+use databend_storages_common_session::TxnState;
+use fastrace::func_path;
+use fastrace::prelude::*;
 use futures::Stream;
 use log::info;
 use prost::Message;
@@ -79,6 +89,7 @@ use tonic::Streaming;
 
 use super::status;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::Session;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
@@ -185,6 +196,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Ticket>,
         message: Any,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
         let session = self.get_session(&request)?;
         let fetch_results: FetchResults = try_unpack_any(message)?;
 
@@ -199,7 +211,13 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         let handle_plan = self.statements.get(&handle).unwrap();
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(
+                session,
+                handle,
+                &handle_plan.value().0,
+                &handle_plan.value().1,
+            )
+            .in_span(root)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -212,6 +230,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let _guard = root.set_local_parent();
         info!("get_flight_info_sql_info(query={})", query.query);
         let _session = self.get_session(&request)?;
         Ok(simple_flight_info(query))
@@ -329,12 +349,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
@@ -369,7 +388,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
         Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
+            "get_flight_info_cross_reference not implemented",
         ))
     }
 
@@ -399,21 +418,45 @@ impl FlightSqlService for FlightSqlServiceImpl {
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
-        _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        query: CommandGetCatalogs,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        async move {
+            let context = session
+                .create_query_context()
+                .await
+                .map_err(|e| status!("Could not create_query_context", e))?;
+            super::CatalogInfoProvider::get_catalogs(context, query)
+                .await
+                .map(Response::new)
+        }
+        .in_span(root)
+        .await
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        async move {
+            let context = session
+                .create_query_context()
+                .await
+                .map_err(|e| status!("Could not create_query_context", e))?;
+            super::CatalogInfoProvider::get_schemas(context, query)
+                .await
+                .map(Response::new)
+        }
+        .in_span(root)
+        .await
     }
 
     #[async_backtrace::framed]
@@ -423,33 +466,42 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_tables({query:?})");
+        let root = start_trace_for_remote_request(func_path!(), &request);
         let session = self.get_session(&request)?;
-        let context = session
-            .create_query_context()
-            .await
-            .map_err(|e| status!("Could not create_query_context", e))?;
-        Ok(Response::new(
+        async move {
+            let context = session
+                .create_query_context()
+                .await
+                .map_err(|e| status!("Could not create_query_context", e))?;
             super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
-        ))
+                .await
+                .map(Response::new)
+        }
+        .in_span(root)
+        .await
     }
 
     #[async_backtrace::framed]
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let _guard = root.set_local_parent();
         info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_sql_info(
         &self,
         query: CommandGetSqlInfo,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let _guard = root.set_local_parent();
         info!("do_get_sql_info({query:?})");
         Ok(Response::new(super::SqlInfoProvider::all_info()?))
     }
@@ -458,10 +510,22 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        async move {
+            let context = session
+                .create_query_context()
+                .await
+                .map_err(|e| status!("Could not create_query_context", e))?;
+            super::CatalogInfoProvider::get_primary_keys(context, query)
+                .await
+                .map(Response::new)
+        }
+        .in_span(root)
+        .await
     }
 
     #[async_backtrace::framed]
@@ -507,18 +571,42 @@ impl FlightSqlService for FlightSqlServiceImpl {
         ticket: CommandStatementUpdate,
         request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
         let session = self.get_session(&request)?;
         let query = ticket.query;
         info!("do_put_statement_update with query = {query}");
 
-        let (plan, plan_extras) = self
-            .plan_sql(&session, &query)
-            .await
-            .map_err(|e| status!("Error getting result schema", e))?;
+        async move {
+            let (plan, plan_extras) = self
+                .plan_sql(&session, &query)
+                .await
+                .map_err(|e| status!("Error getting result schema", e))?;
+            self.execute_update(session, &plan, &plan_extras)
+                .await
+                .map_err(|e| status!("fail to execute", e))
+        }
+        .in_span(root)
+        .await
+    }
+
+    // called by ADBC bulk ingestion.
+    #[async_backtrace::framed]
+    async fn do_put_statement_ingest(
+        &self,
+        ticket: CommandStatementIngest,
+        request: Request<PeekableFlightDataStream>,
+    ) -> std::result::Result<i64, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        info!(
+            "do_put_statement_ingest with table = {:?}.{:?}.{}",
+            ticket.catalog, ticket.schema, ticket.table
+        );
         let res = self
-            .execute_update(session, &plan, &plan_extras)
+            .ingest(session, ticket, request.into_inner())
+            .in_span(root)
             .await
-            .map_err(|e| status!("fail to execute", e))?;
+            .map_err(|e| status!("fail to ingest", e))?;
         Ok(res)
     }
 
@@ -528,6 +616,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandPreparedStatementQuery,
         request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<DoPutPreparedStatementResult, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
         let session = self.get_session(&request)?;
         let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
@@ -537,6 +626,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let handle_plan = self.statements.get(&handle).unwrap();
         let record_count = self
             .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .in_span(root)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -556,6 +646,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandPreparedStatementUpdate,
         request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
         let session = self.get_session(&request)?;
         let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
@@ -565,6 +656,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let handle_plan = self.statements.get(&handle).unwrap();
         let res = self
             .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .in_span(root)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -578,39 +670,20 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionCreatePreparedStatementRequest,
         request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
+        let root = start_trace_for_remote_request(func_path!(), &request);
         let session = self.get_session(&request)?;
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
         let plan = self
             .plan_sql(&session, &sql)
+            .in_span(root)
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         info!(
             "do_action_create_prepared_statement with handler={handle} query={:?}",
             query.query
         );
-        // JDBC client use call put when schema.fields == 0
-        let data_schema = if plan.0.has_result_set() {
-            plan.0.schema()
-        } else {
-            Arc::new(DataSchema::empty())
-        };
-        info!(
-            "do_action_create_prepared_statement with handler={handle}, query={:?}, return schema={data_schema:?}",
-            query.query
-        );
-        let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
-        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-        let res = ActionCreatePreparedStatementResult {
-            prepared_statement_handle: handle.as_bytes().to_vec().into(),
-            dataset_schema: schema_bytes,
-            parameter_schema: Default::default(), // TODO: parameters
-        };
-        Ok(res)
+        self.create_prepared(handle, plan)
     }
 
     #[async_backtrace::framed]
@@ -647,85 +720,266 @@ impl FlightSqlService for FlightSqlServiceImpl {
     }
 
     /// Get a FlightInfo to extract information about the supported XDBC types.
+    #[async_backtrace::framed]
     async fn get_flight_info_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        info!("get_flight_info_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     /// Get a FlightDataStream containing the data related to the supported XDBC types.
+    #[async_backtrace::framed]
     async fn do_get_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<Ticket>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        unimplemented!()
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let _guard = root.set_local_parent();
+        info!("do_get_xdbc_type_info({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::XdbcTypeInfoProvider::type_info_stream(query)?,
+        ))
     }
 
+    #[async_backtrace::framed]
     async fn get_flight_info_substrait_plan(
         &self,
-        _query: CommandStatementSubstraitPlan,
-        _request: Request<FlightDescriptor>,
+        query: CommandStatementSubstraitPlan,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        let handle = Uuid::new_v4();
+        info!("get_flight_info_substrait_plan with handle={handle}");
+
+        let plan = self
+            .plan_substrait(&session, query.plan)
+            .in_span(root)
+            .await
+            .map_err(|e| status!("Error planning substrait plan", e))?;
+        self.statements.insert(handle, plan);
+        let cmd = CommandPreparedStatementQuery {
+            prepared_statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        self.get_flight_info_prepared_statement(cmd, request).await
     }
 
+    #[async_backtrace::framed]
     async fn do_put_substrait_plan(
         &self,
-        _query: CommandStatementSubstraitPlan,
-        _request: Request<PeekableFlightDataStream>,
+        query: CommandStatementSubstraitPlan,
+        request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
-        unimplemented!()
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        info!("do_put_substrait_plan");
+
+        async move {
+            let (plan, plan_extras) = self
+                .plan_substrait(&session, query.plan)
+                .await
+                .map_err(|e| status!("Error planning substrait plan", e))?;
+            self.execute_update(session, &plan, &plan_extras)
+                .await
+                .map_err(|e| status!("fail to execute", e))
+        }
+        .in_span(root)
+        .await
     }
 
+    #[async_backtrace::framed]
     async fn do_action_create_prepared_substrait_plan(
         &self,
-        _query: ActionCreatePreparedSubstraitPlanRequest,
-        _request: Request<Action>,
+        query: ActionCreatePreparedSubstraitPlanRequest,
+        request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
-        unimplemented!()
+        let root = start_trace_for_remote_request(func_path!(), &request);
+        let session = self.get_session(&request)?;
+        let handle = Uuid::new_v4();
+        info!("do_action_create_prepared_substrait_plan with handler={handle}");
+
+        let plan = self
+            .plan_substrait(&session, query.plan)
+            .in_span(root)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        self.create_prepared(handle, plan)
     }
 
+    #[async_backtrace::framed]
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        if session.txn_mgr().lock().state() != TxnState::AutoCommit {
+            return Err(Status::failed_precondition(
+                "a transaction is already in progress in this session",
+            ));
+        }
+        self.execute_transaction_statement(&session, "BEGIN")
+            .await?;
+        let transaction_id = session.txn_mgr().lock().txn_id().to_string();
+        info!("do_action_begin_transaction with transaction_id={transaction_id}");
+        Ok(ActionBeginTransactionResult {
+            transaction_id: transaction_id.into_bytes().into(),
+        })
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let transaction_id = String::from_utf8_lossy(&query.transaction_id).to_string();
+        info!(
+            "do_action_end_transaction with transaction_id={transaction_id}, action={:?}",
+            query.action()
+        );
+        {
+            let txn_mgr = session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if txn_mgr.state() == TxnState::AutoCommit || txn_mgr.txn_id() != transaction_id {
+                return Err(Status::not_found(format!(
+                    "transaction not found: {transaction_id}"
+                )));
+            }
+        }
+        let sql = match query.action() {
+            EndTransaction::Commit => "COMMIT",
+            EndTransaction::Rollback => "ROLLBACK",
+            EndTransaction::Unspecified => {
+                return Err(Status::invalid_argument(
+                    "end transaction action must be commit or rollback",
+                ));
+            }
+        };
+        self.execute_transaction_statement(&session, sql).await
     }
 
+    #[async_backtrace::framed]
     async fn do_action_begin_savepoint(
         &self,
         _query: ActionBeginSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginSavepointResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("savepoint is not supported"))
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_savepoint(
         &self,
         _query: ActionEndSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented("savepoint is not supported"))
     }
 
+    // The queries are fetched by the handle of the prepared statement in the ticket,
+    // only the running query of the handle is cancelled.
+    #[async_backtrace::framed]
     async fn do_action_cancel_query(
         &self,
-        _query: ActionCancelQueryRequest,
-        _request: Request<Action>,
+        query: ActionCancelQueryRequest,
+        request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let info = FlightInfo::decode(query.info)
+            .map_err(|e| Status::invalid_argument(format!("Error decoding flight info: {e}")))?;
+        let handle = info
+            .endpoint
+            .iter()
+            .filter_map(|endpoint| endpoint.ticket.as_ref())
+            .find_map(|ticket| {
+                let message = Any::decode(ticket.ticket.clone()).ok()?;
+                let fetch_results: FetchResults = try_unpack_any(message).ok()?;
+                Uuid::try_parse(&fetch_results.handle).ok()
+            })
+            .ok_or_else(|| Status::not_found("no query handle found in the flight info"))?;
+
+        let query_id = self
+            .running_queries
+            .get(&handle)
+            .map(|query_id| query_id.value().clone());
+        let result = match query_id {
+            Some(query_id) => {
+                info!("do_action_cancel_query with handle={handle} query_id={query_id}");
+                let killed = session.force_kill_query_by_id(
+                    &query_id,
+                    ErrorCode::AbortedQuery(
+                        "Aborted query, because it was cancelled by the client",
+                    ),
+                );
+                if killed {
+                    CancelResult::Cancelled
+                } else {
+                    CancelResult::NotCancellable
+                }
+            }
+            // the query of the handle is finished
+            None if self.statements.contains_key(&handle) => CancelResult::NotCancellable,
+            None => {
+                return Err(Status::not_found(format!(
+                    "no query found for handle {handle}"
+                )));
+            }
+        };
+        Ok(ActionCancelQueryResult {
+            result: result.into(),
+        })
+    }
+}
+
+impl FlightSqlServiceImpl {
+    fn create_prepared(
+        &self,
+        handle: Uuid,
+        plan: (Plan, PlanExtras),
+    ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
+        // JDBC client use call put when schema.fields == 0
+        let data_schema = if plan.0.has_result_set() {
+            plan.0.schema()
+        } else {
+            Arc::new(DataSchema::empty())
+        };
+        info!("create prepared statement with handler={handle}, return schema={data_schema:?}");
+        let schema = (&*data_schema).into();
+        self.statements.insert(handle, plan);
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+        let res = ActionCreatePreparedStatementResult {
+            prepared_statement_handle: handle.as_bytes().to_vec().into(),
+            dataset_schema: schema_bytes,
+            parameter_schema: Default::default(), // TODO: parameters
+        };
+        Ok(res)
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_transaction_statement(
+        &self,
+        session: &Arc<Session>,
+        sql: &str,
+    ) -> std::result::Result<(), Status> {
+        let (plan, plan_extras) = self
+            .plan_sql(session, sql)
+            .await
+            .map_err(|e| status!("Error planning transaction statement", e))?;
+        self.execute_update(session.clone(), &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(())
     }
 }
 
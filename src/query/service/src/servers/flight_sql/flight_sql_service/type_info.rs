@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+
+use arrow_flight::sql::metadata::XdbcTypeInfo;
+use arrow_flight::sql::metadata::XdbcTypeInfoData;
+use arrow_flight::sql::metadata::XdbcTypeInfoDataBuilder;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::Nullable;
+use arrow_flight::sql::Searchable;
+use arrow_flight::sql::XdbcDataType;
+use arrow_flight::utils::batches_to_flight_data;
+use futures_util::stream;
+use tonic::Status;
+
+use crate::servers::flight_sql::flight_sql_service::DoGetStream;
+
+/// The data types of Databend which can be described with XDBC types.
+static XDBC_TYPE_INFO_DATA: LazyLock<XdbcTypeInfoData> = LazyLock::new(|| {
+    let mut builder = XdbcTypeInfoDataBuilder::new();
+    builder.append(XdbcTypeInfoProvider::type_info(
+        "BOOLEAN",
+        XdbcDataType::XdbcBit,
+        Some(1),
+    ));
+    for (name, data_type, column_size) in [
+        ("TINYINT", XdbcDataType::XdbcTinyint, 3),
+        ("SMALLINT", XdbcDataType::XdbcSmallint, 5),
+        ("INT", XdbcDataType::XdbcInteger, 10),
+        ("BIGINT", XdbcDataType::XdbcBigint, 19),
+    ] {
+        builder.append(XdbcTypeInfo {
+            unsigned_attribute: Some(false),
+            fixed_prec_scale: true,
+            num_prec_radix: Some(10),
+            ..XdbcTypeInfoProvider::type_info(name, data_type, Some(column_size))
+        });
+        let unsigned_name = format!("{name} UNSIGNED");
+        builder.append(XdbcTypeInfo {
+            unsigned_attribute: Some(true),
+            fixed_prec_scale: true,
+            num_prec_radix: Some(10),
+            local_type_name: Some(unsigned_name.clone()),
+            ..XdbcTypeInfoProvider::type_info(&unsigned_name, data_type, Some(column_size))
+        });
+    }
+    for (name, data_type, column_size) in [
+        ("FLOAT", XdbcDataType::XdbcFloat, 24),
+        ("DOUBLE", XdbcDataType::XdbcDouble, 53),
+    ] {
+        builder.append(XdbcTypeInfo {
+            unsigned_attribute: Some(false),
+            num_prec_radix: Some(2),
+            ..XdbcTypeInfoProvider::type_info(name, data_type, Some(column_size))
+        });
+    }
+    builder.append(XdbcTypeInfo {
+        create_params: Some(vec!["precision".to_string(), "scale".to_string()]),
+        unsigned_attribute: Some(false),
+        fixed_prec_scale: true,
+        minimum_scale: Some(0),
+        maximum_scale: Some(76),
+        num_prec_radix: Some(10),
+        ..XdbcTypeInfoProvider::type_info("DECIMAL", XdbcDataType::XdbcDecimal, Some(76))
+    });
+    builder.append(XdbcTypeInfo {
+        literal_prefix: Some("'".to_string()),
+        literal_suffix: Some("'".to_string()),
+        case_sensitive: true,
+        searchable: Searchable::Full,
+        ..XdbcTypeInfoProvider::type_info("VARCHAR", XdbcDataType::XdbcVarchar, None)
+    });
+    builder.append(XdbcTypeInfoProvider::type_info(
+        "BINARY",
+        XdbcDataType::XdbcVarbinary,
+        None,
+    ));
+    builder.append(XdbcTypeInfo {
+        literal_prefix: Some("'".to_string()),
+        literal_suffix: Some("'".to_string()),
+        ..XdbcTypeInfoProvider::type_info("DATE", XdbcDataType::XdbcDate, Some(10))
+    });
+    builder.append(XdbcTypeInfo {
+        literal_prefix: Some("'".to_string()),
+        literal_suffix: Some("'".to_string()),
+        maximum_scale: Some(6),
+        minimum_scale: Some(6),
+        ..XdbcTypeInfoProvider::type_info("TIMESTAMP", XdbcDataType::XdbcTimestamp, Some(26))
+    });
+    builder.build().expect("build xdbc type info")
+});
+
+pub(super) struct XdbcTypeInfoProvider {}
+
+impl XdbcTypeInfoProvider {
+    fn type_info(name: &str, data_type: XdbcDataType, column_size: Option<i32>) -> XdbcTypeInfo {
+        XdbcTypeInfo {
+            type_name: name.to_string(),
+            data_type,
+            column_size,
+            literal_prefix: None,
+            literal_suffix: None,
+            create_params: None,
+            nullable: Nullable::NullabilityNullable,
+            case_sensitive: false,
+            searchable: Searchable::Basic,
+            unsigned_attribute: None,
+            fixed_prec_scale: false,
+            auto_increment: Some(false),
+            local_type_name: Some(name.to_string()),
+            minimum_scale: None,
+            maximum_scale: None,
+            sql_data_type: data_type,
+            datetime_subcode: None,
+            num_prec_radix: None,
+            interval_precision: None,
+        }
+    }
+
+    pub fn type_info_stream(query: CommandGetXdbcTypeInfo) -> Result<DoGetStream, Status> {
+        // the builder filters the types by `data_type` of the query.
+        let batch = query
+            .into_builder(&XDBC_TYPE_INFO_DATA)
+            .build()
+            .map_err(|e| Status::internal(format!("build xdbc type info fail {:?}", e)))?;
+        let schema = (*batch.schema()).clone();
+        let flight_data = batches_to_flight_data(&schema, vec![batch])
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(Ok);
+        Ok(Box::pin(stream::iter(flight_data)))
+    }
+}
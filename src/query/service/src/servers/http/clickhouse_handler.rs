@@ -261,7 +261,9 @@ pub async fn clickhouse_handler_get(
             .map_err(|err| err.display_with_sql(&sql))
             .map_err(BadRequest)?;
 
-        let query_entry = QueryEntry::create(&context, &plan, &extras).map_err(BadRequest)?;
+        let query_entry = QueryEntry::create(&context, &plan, &extras)
+            .await
+            .map_err(BadRequest)?;
         let _guard = QueriesQueueManager::instance()
             .acquire(query_entry)
             .await
@@ -352,7 +354,9 @@ pub async fn clickhouse_handler_post(
             .map_err(|err| err.display_with_sql(&sql))
             .map_err(BadRequest)?;
 
-        let entry = QueryEntry::create(&ctx, &plan, &extras).map_err(BadRequest)?;
+        let entry = QueryEntry::create(&ctx, &plan, &extras)
+            .await
+            .map_err(BadRequest)?;
         let _guard = QueriesQueueManager::instance()
             .acquire(entry)
             .await
@@ -394,7 +398,7 @@ pub fn clickhouse_router() -> impl Endpoint {
 }
 
 // default codec is always lz4
-fn compress_block(input: Vec<u8>) -> Result<Vec<u8>> {
+pub(crate) fn compress_block(input: Vec<u8>) -> Result<Vec<u8>> {
     if input.is_empty() {
         Ok(vec![])
     } else {
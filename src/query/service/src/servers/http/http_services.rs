@@ -38,6 +38,7 @@ use poem::Route;
 
 use super::v1::discovery_nodes;
 use super::v1::logout_handler;
+use super::v1::streaming_load;
 use super::v1::upload_to_stage;
 use super::v1::HttpQueryContext;
 use crate::servers::http::middleware::json_response;
@@ -147,6 +148,13 @@ impl HttpHandler {
                     EndpointKind::StartQuery,
                 )),
             )
+            .at(
+                "/streaming_load",
+                put(streaming_load).with(HTTPSessionMiddleware::create(
+                    self.kind,
+                    EndpointKind::StartQuery,
+                )),
+            )
             .at(
                 "/suggested_background_tasks",
                 get(list_suggestions).with(HTTPSessionMiddleware::create(
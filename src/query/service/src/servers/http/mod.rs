@@ -19,6 +19,7 @@ mod http_services;
 pub mod middleware;
 pub mod v1;
 
+pub(crate) use clickhouse_handler::compress_block;
 pub use clickhouse_federated::ClickHouseFederated;
 pub use http_services::HttpHandler;
 pub use http_services::HttpHandlerKind;
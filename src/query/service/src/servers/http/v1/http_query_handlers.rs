@@ -12,17 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_stream::stream;
 use databend_common_base::base::mask_connection_info;
+use databend_common_base::base::tokio::time::sleep;
 use databend_common_base::headers::HEADER_QUERY_ID;
 use databend_common_base::headers::HEADER_QUERY_PAGE_ROWS;
 use databend_common_base::headers::HEADER_QUERY_STATE;
 use databend_common_base::runtime::drop_guard;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
+use databend_common_pipeline_core::PlanProfile;
 use fastrace::func_path;
 use fastrace::prelude::*;
+use futures::TryStreamExt;
 use highway::HighwayHash;
+use http::header::ACCEPT;
+use http::header::CACHE_CONTROL;
+use http::HeaderMap;
 use http::StatusCode;
 use log::error;
 use log::info;
@@ -33,8 +48,11 @@ use poem::get;
 use poem::post;
 use poem::web::Json;
 use poem::web::Path;
+use poem::web::Query;
+use poem::Body;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -48,13 +66,17 @@ use crate::servers::http::error::QueryError;
 use crate::servers::http::middleware::EndpointKind;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
 use crate::servers::http::middleware::MetricsMiddleware;
+use crate::servers::http::v1::query::result_format::ArrowStreamEncoder;
 use crate::servers::http::v1::query::string_block::StringBlock;
+use crate::servers::http::v1::query::Page;
 use crate::servers::http::v1::query::Progresses;
+use crate::servers::http::v1::query::ResultFormat;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::HttpHandlerKind;
 use crate::sessions::QueryAffect;
+use crate::sessions::TableContext;
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -77,6 +99,10 @@ pub struct QueryStats {
     #[serde(flatten)]
     pub progresses: Progresses,
     pub running_time_ms: i64,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub queue_position: Option<u64>,
+    #[serde(default)]
+    pub queued_duration_ms: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -131,31 +157,65 @@ impl QueryResponse {
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+    ) -> PoemResult<Response> {
+        let result_format = r.result_format;
+        let result_schema = r.result_schema.clone();
+        let (response, page) = Self::create(id, r, is_final);
+        let id = response.id.clone();
+        let state = response.state.to_string();
+        match page {
+            // the JSON response without data is attached to the schema metadata of the binary body.
+            Some(page) if result_format.is_binary() => {
+                let rows = page.num_rows();
+                let schema = result_schema.unwrap_or_else(|| Arc::new(DataSchema::empty()));
+                let header = serde_json::to_string(&response)
+                    .map_err(|e| HttpErrorCode::server_error(ErrorCode::Internal(e.to_string())))?;
+                let body = result_format
+                    .encode(&schema, page.blocks, header)
+                    .map_err(HttpErrorCode::server_error)?;
+                Ok(body
+                    .with_content_type(result_format.content_type())
+                    .with_header(HEADER_QUERY_ID, id)
+                    .with_header(HEADER_QUERY_STATE, state)
+                    .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+                    .into_response())
+            }
+            _ => {
+                let rows = response.data.len();
+                Ok(Json(response)
+                    .with_header(HEADER_QUERY_ID, id)
+                    .with_header(HEADER_QUERY_STATE, state)
+                    .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+                    .into_response())
+            }
+        }
+    }
+
+    fn create(id: String, r: HttpQueryResponseInternal, is_final: bool) -> (Self, Option<Page>) {
         let state = r.state.clone();
-        let (data, next_uri) = if is_final {
-            (StringBlock::empty(), None)
+        let (page, next_uri) = if is_final {
+            (None, None)
         } else {
             match state.state {
                 ExecuteStateKind::Running | ExecuteStateKind::Starting => match r.data {
-                    None => (StringBlock::empty(), Some(make_state_uri(&id))),
+                    None => (None, Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_state_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d.page), uri)
                     }
                 },
-                ExecuteStateKind::Failed => (StringBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (None, Some(make_final_uri(&id))),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (StringBlock::empty(), Some(make_final_uri(&id))),
+                    None => (None, Some(make_final_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_final_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d.page), uri)
                     }
                 },
             }
@@ -169,10 +229,15 @@ impl QueryResponse {
         let stats = QueryStats {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
+            queue_position: state.queue_position,
+            queued_duration_ms: state.queued_duration_ms,
+        };
+        let (data, page) = match page {
+            Some(mut p) => (std::mem::take(&mut p.data), Some(p)),
+            None => (StringBlock::empty(), None),
         };
-        let rows = data.data.len();
 
-        Json(QueryResponse {
+        let response = QueryResponse {
             data: data.into(),
             state: state.state,
             schema: state.schema.clone(),
@@ -189,10 +254,8 @@ impl QueryResponse {
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.map(QueryError::from_error_code),
             has_result_set: r.state.has_result_set,
-        })
-        .with_header(HEADER_QUERY_ID, id.clone())
-        .with_header(HEADER_QUERY_STATE, state.state.to_string())
-        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        };
+        (response, page)
     }
 }
 
@@ -238,7 +301,7 @@ async fn query_final_handler(
                 // it is safe to set these 2 fields to None, because client now check for null/None first.
                 response.session = None;
                 response.state.affect = None;
-                Ok(QueryResponse::from_internal(query_id, response, true))
+                QueryResponse::from_internal(query_id, response, true)
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
         }
@@ -300,7 +363,7 @@ async fn query_state_handler(
                         .get_response_state_only()
                         .await
                         .map_err(HttpErrorCode::server_error)?;
-                    Ok(QueryResponse::from_internal(query_id, response, false))
+                    QueryResponse::from_internal(query_id, response, false)
                 }
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
@@ -341,7 +404,7 @@ async fn query_page_handler(
                         poem::Error::from_string(err.message(), StatusCode::NOT_FOUND)
                     })?;
                     query.update_expire_time(false).await;
-                    Ok(QueryResponse::from_internal(query_id, resp, false))
+                    QueryResponse::from_internal(query_id, resp, false)
                 }
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
@@ -351,57 +414,414 @@ async fn query_page_handler(
     .await
 }
 
+/// Stream all the remaining pages of a query as one Arrow IPC stream.
+///
+/// Only available to queries started with the `arrow` result format. The JSON response of the
+/// first streamed page is attached to the schema metadata, the client should still call `final_uri`.
 #[poem::handler]
-#[async_backtrace::framed]
-pub(crate) async fn query_handler(
+async fn query_stream_handler(
     ctx: &HttpQueryContext,
-    Json(req): Json<HttpQueryRequest>,
+    Path(query_id): Path<String>,
 ) -> PoemResult<impl IntoResponse> {
-    let root = get_http_tracing_span(func_path!(), ctx, &ctx.query_id);
-    let _t = SlowRequestLogTracker::new(ctx);
+    ctx.check_node_id(&query_id)?;
+    let root = get_http_tracing_span(func_path!(), ctx, &query_id);
 
     async {
-        let agent_info = ctx.user_agent.as_ref().map(|s|(format!("(from {s})"))).unwrap_or("".to_string());
-        let client_session_id_info = ctx.client_session_id.as_ref().map(|s|(format!("(client_session_id={s})"))).unwrap_or("".to_string());
-        info!("http query new request{}{}: {}", agent_info, client_session_id_info, mask_connection_info(&format!("{:?}", req)));
         let http_query_manager = HttpQueryManager::instance();
-        let sql = req.sql.clone();
-
-        let query = http_query_manager
-            .try_create_query(ctx, req.clone())
-            .await
-            .map_err(|err| err.display_with_sql(&sql));
-        match query {
-            Ok(query) => {
+        let query = match http_query_manager.get_query(&query_id) {
+            Some(query) => query,
+            None => return Err(query_id_not_found(&query_id, &ctx.node_id)),
+        };
+        if query.user_name != ctx.user_name {
+            return Err(poem::error::Error::from_string(
+                format!(
+                    "wrong user, query {} expect {}, got {}",
+                    query_id, query.user_name, ctx.user_name
+                ),
+                StatusCode::UNAUTHORIZED,
+            ));
+        }
+        query.check_client_session_id(&ctx.client_session_id)?;
+        if let Some(reason) = query.check_removed() {
+            return Err(query_id_removed(&query_id, reason));
+        }
+        if query.result_format() != ResultFormat::Arrow {
+            return Err(PoemError::from_string(
+                format!(
+                    "query {query_id} is not started with the arrow result format, can not be streamed"
+                ),
+                StatusCode::BAD_REQUEST,
+            ));
+        }
+
+        let mut page_no = query.next_page_no().await;
+        let stream = stream! {
+            let mut encoder: Option<ArrowStreamEncoder> = None;
+            while let Some(n) = page_no {
                 query.update_expire_time(true).await;
-                // tmp workaround to tolerant old clients
-                let resp = query
-                    .get_response_page(0)
-                    .await
-                    .map_err(|err| err.display_with_sql(&sql))
-                    .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
-                if matches!(resp.state.state, ExecuteStateKind::Failed) {
-                    ctx.set_fail();
+                let resp = query.get_response_page(n).await;
+                query.update_expire_time(false).await;
+                let mut resp = match resp {
+                    Ok(resp) => resp,
+                    Err(err) => {
+                        yield Err(err);
+                        break;
+                    }
+                };
+                if let Some(err) = &resp.state.error {
+                    yield Err(err.clone().with_context("http query stream failed"));
+                    break;
                 }
-                let (rows, next_page) = match &resp.data {
-                    None => (0, None),
-                    Some(p) => (p.page.data.num_rows(), p.next_page_no),
+                let blocks = match resp.data.as_mut() {
+                    Some(d) => {
+                        page_no = d.next_page_no;
+                        std::mem::take(&mut d.page.blocks)
+                    }
+                    None => vec![],
                 };
-                info!( "http query initial response to http query_id={}, state={:?}, rows={}, next_page={:?}, sql='{}'",
-                        &query.id, &resp.state, rows, next_page, mask_connection_info(&sql)
-                    );
-                query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+                if encoder.is_none() {
+                    let schema = resp
+                        .result_schema
+                        .clone()
+                        .unwrap_or_else(|| Arc::new(DataSchema::empty()));
+                    let (response, _) = QueryResponse::create(query.id.clone(), resp, false);
+                    let header = serde_json::to_string(&response)
+                        .map_err(|e| ErrorCode::Internal(e.to_string()));
+                    match header.and_then(|h| ArrowStreamEncoder::try_create(&schema, h)) {
+                        Ok(e) => encoder = Some(e),
+                        Err(err) => {
+                            yield Err(err);
+                            break;
+                        }
+                    }
+                }
+                if let Some(encoder) = encoder.as_mut() {
+                    yield encoder.write(blocks);
+                }
             }
-            Err(e) => {
-                error!("http query fail to start sql, error: {:?}", e);
-                ctx.set_fail();
-                Ok(req.fail_to_start_sql(e).into_response())
+            if encoder.is_none() {
+                let schema = query.result_schema().unwrap_or_else(|| Arc::new(DataSchema::empty()));
+                match ArrowStreamEncoder::try_create(&schema, String::new()) {
+                    Ok(e) => encoder = Some(e),
+                    Err(err) => yield Err(err),
+                }
+            }
+            if let Some(encoder) = encoder.as_mut() {
+                yield encoder.finish();
             }
+        };
+        let stream = stream.map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err));
+        Ok(Body::from_bytes_stream(stream)
+            .with_content_type(ResultFormat::Arrow.content_type())
+            .with_header(HEADER_QUERY_ID, query_id.clone()))
+    }
+    .in_span(root)
+    .await
+}
+
+/// The profile of a plan node of the query, in the same shape as `system.queries_profiling`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryProfileEvent {
+    pub plan_id: Option<u32>,
+    pub parent_plan_id: Option<u32>,
+    pub plan_name: Option<String>,
+    pub statistics: HashMap<String, usize>,
+    pub errors: serde_json::Value,
+}
+
+impl QueryProfileEvent {
+    fn from_profiles(profiles: &[PlanProfile]) -> Vec<Self> {
+        profiles
+            .iter()
+            .map(|profile| Self {
+                plan_id: profile.id,
+                parent_plan_id: profile.parent_id,
+                plan_name: profile.name.clone(),
+                statistics: profile
+                    .statistics
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, value)| (ProfileStatisticsName::from(idx).to_string(), *value))
+                    .collect(),
+                errors: serde_json::to_value(&profile.errors).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+/// The last event of the query events stream, sent when the query is stopped.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryStateEvent {
+    pub state: ExecuteStateKind,
+    pub error: Option<QueryError>,
+    pub affect: Option<QueryAffect>,
+}
+
+const DEFAULT_EVENTS_INTERVAL_MS: u64 = 1000;
+const MIN_EVENTS_INTERVAL_MS: u64 = 100;
+
+fn default_events_interval_ms() -> u64 {
+    DEFAULT_EVENTS_INTERVAL_MS
+}
+
+#[derive(Deserialize, Debug)]
+struct QueryEventsParams {
+    /// The interval between two rounds of the progress events, in milliseconds.
+    #[serde(default = "default_events_interval_ms")]
+    interval_ms: u64,
+    /// Cancel the query if the events stream is closed before the query is stopped.
+    #[serde(default)]
+    cancel_on_close: bool,
+}
+
+/// Cancel the query when the events stream is dropped before the query is stopped,
+/// i.e. the connection is closed by the client.
+struct CancelOnClose {
+    query_id: String,
+    client_session_id: Option<String>,
+    armed: bool,
+}
+
+impl Drop for CancelOnClose {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
         }
+        let query_id = std::mem::take(&mut self.query_id);
+        let client_session_id = self.client_session_id.take();
+        GlobalIORuntime::instance().spawn(async move {
+            info!(
+                "{}: the events stream is closed by client, cancel the query",
+                query_id
+            );
+            let http_query_manager = HttpQueryManager::instance();
+            if let Err(e) = http_query_manager
+                .remove_query(
+                    &query_id,
+                    &client_session_id,
+                    RemoveReason::Canceled,
+                    ErrorCode::AbortedQuery("canceled by client, the events stream is closed"),
+                )
+                .await
+            {
+                warn!("{}: fail to cancel the query: {}", query_id, e);
+            }
+        });
     }
-        .in_span(root)
+}
+
+fn sse_event(event: &str, data: &impl Serialize) -> Result<String, ErrorCode> {
+    let data = serde_json::to_string(data).map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    Ok(format!("event: {event}\ndata: {data}\n\n"))
+}
+
+/// Push the progress of a query as Server-Sent Events until the query is stopped.
+///
+/// Each round emits a `progress` event, followed by `warnings` and `profile` events if any,
+/// the last event is `state` with the final state of the query. With `cancel_on_close=true`,
+/// closing the connection before the query is stopped cancels the query.
+#[poem::handler]
+async fn query_events_handler(
+    ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+    Query(params): Query<QueryEventsParams>,
+) -> PoemResult<impl IntoResponse> {
+    ctx.check_node_id(&query_id)?;
+    let root = get_http_tracing_span(func_path!(), ctx, &query_id);
+
+    async {
+        let http_query_manager = HttpQueryManager::instance();
+        let query = match http_query_manager.get_query(&query_id) {
+            Some(query) => query,
+            None => return Err(query_id_not_found(&query_id, &ctx.node_id)),
+        };
+        if query.user_name != ctx.user_name {
+            return Err(poem::error::Error::from_string(
+                format!(
+                    "wrong user, query {} expect {}, got {}",
+                    query_id, query.user_name, ctx.user_name
+                ),
+                StatusCode::UNAUTHORIZED,
+            ));
+        }
+        query.check_client_session_id(&ctx.client_session_id)?;
+        if let Some(reason) = query.check_removed() {
+            return Err(query_id_removed(&query_id, reason));
+        }
+
+        let interval = Duration::from_millis(params.interval_ms.max(MIN_EVENTS_INTERVAL_MS));
+        let mut cancel_on_close = CancelOnClose {
+            query_id: query_id.clone(),
+            client_session_id: ctx.client_session_id.clone(),
+            armed: params.cancel_on_close,
+        };
+        let stream = stream! {
+            // keep the context to report the final profiles after the query is stopped.
+            let mut query_ctx = None;
+            loop {
+                // the events stream keeps the query alive like polling.
+                if query.check_removed().is_none() {
+                    query.update_expire_time(false).await;
+                }
+                if query_ctx.is_none() {
+                    query_ctx = query.get_query_context().await;
+                }
+                let state = match query.get_response_state_only().await {
+                    Ok(resp) => resp.state,
+                    Err(err) => {
+                        yield Err(err);
+                        break;
+                    }
+                };
+
+                let stats = QueryStats {
+                    progresses: state.progresses.clone(),
+                    running_time_ms: state.running_time_ms,
+                    queue_position: state.queue_position,
+                    queued_duration_ms: state.queued_duration_ms,
+                };
+                yield sse_event("progress", &stats);
+                if !state.warnings.is_empty() {
+                    yield sse_event("warnings", &state.warnings);
+                }
+                if let Some(query_ctx) = &query_ctx {
+                    let profiles = query_ctx.get_query_profiles();
+                    if !profiles.is_empty() {
+                        yield sse_event("profile", &QueryProfileEvent::from_profiles(&profiles));
+                    }
+                }
+                if state.state.is_stopped() {
+                    cancel_on_close.armed = false;
+                    let event = QueryStateEvent {
+                        state: state.state,
+                        error: state.error.map(QueryError::from_error_code),
+                        affect: state.affect,
+                    };
+                    yield sse_event("state", &event);
+                    break;
+                }
+                sleep(interval).await;
+            }
+        };
+        let stream = stream.map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err));
+        Ok(Body::from_bytes_stream(stream)
+            .with_content_type("text/event-stream")
+            .with_header(CACHE_CONTROL, "no-cache")
+            .with_header(HEADER_QUERY_ID, query_id.clone()))
+    }
+    .in_span(root)
+    .await
+}
+
+#[poem::handler]
+#[async_backtrace::framed]
+pub(crate) async fn query_handler(
+    ctx: &HttpQueryContext,
+    headers: &HeaderMap,
+    Json(mut req): Json<HttpQueryRequest>,
+) -> PoemResult<impl IntoResponse> {
+    let root = get_http_tracing_span(func_path!(), ctx, &ctx.query_id);
+    let _t = SlowRequestLogTracker::new(ctx);
+
+    if req.result_format == ResultFormat::Json {
+        if let Some(format) = headers
+            .get(ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .and_then(ResultFormat::from_accept)
+        {
+            req.result_format = format;
+        }
+    }
+
+    start_query(ctx, req).in_span(root).await
+}
+
+/// Retrieve the persisted result of a query by its id, the query may be executed on other nodes.
+///
+/// A new query reading the result is started, the remaining pages are polled by its `next_uri`.
+#[poem::handler]
+#[async_backtrace::framed]
+async fn query_result_handler(
+    ctx: &HttpQueryContext,
+    headers: &HeaderMap,
+    Path(query_id): Path<String>,
+) -> PoemResult<impl IntoResponse> {
+    let root = get_http_tracing_span(func_path!(), ctx, &ctx.query_id);
+    let _t = SlowRequestLogTracker::new(ctx);
+
+    let result_format = headers
+        .get(ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .and_then(ResultFormat::from_accept)
+        .unwrap_or_default();
+    let req = HttpQueryRequest::for_persisted_result(&query_id, result_format)
+        .map_err(|err| PoemError::from_string(err.message(), StatusCode::BAD_REQUEST))?;
+    start_query(ctx, req).in_span(root).await
+}
+
+#[async_backtrace::framed]
+async fn start_query(ctx: &HttpQueryContext, req: HttpQueryRequest) -> PoemResult<Response> {
+    if let Err(e) = req.substrait_plan_bytes() {
+        error!("http query fail to resolve substrait plan, error: {:?}", e);
+        ctx.set_fail();
+        return Ok(req.fail_to_start_sql(e).into_response());
+    }
+    let agent_info = ctx
+        .user_agent
+        .as_ref()
+        .map(|s| (format!("(from {s})")))
+        .unwrap_or("".to_string());
+    let client_session_id_info = ctx
+        .client_session_id
+        .as_ref()
+        .map(|s| (format!("(client_session_id={s})")))
+        .unwrap_or("".to_string());
+    info!(
+        "http query new request{}{}: {}",
+        agent_info,
+        client_session_id_info,
+        mask_connection_info(&format!("{:?}", req))
+    );
+    let http_query_manager = HttpQueryManager::instance();
+    let sql = req.sql.clone();
+
+    let query = http_query_manager
+        .try_create_query(ctx, req.clone())
         .await
+        .map_err(|err| err.display_with_sql(&sql));
+    match query {
+        Ok(query) => {
+            query.update_expire_time(true).await;
+            // tmp workaround to tolerant old clients
+            let resp = query
+                .get_response_page(0)
+                .await
+                .map_err(|err| err.display_with_sql(&sql))
+                .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
+            if matches!(resp.state.state, ExecuteStateKind::Failed) {
+                ctx.set_fail();
+            }
+            let (rows, next_page) = match &resp.data {
+                None => (0, None),
+                Some(p) => (p.page.num_rows(), p.next_page_no),
+            };
+            info!(
+                "http query initial response to http query_id={}, state={:?}, rows={}, next_page={:?}, sql='{}'",
+                &query.id,
+                &resp.state,
+                rows,
+                next_page,
+                mask_connection_info(&sql)
+            );
+            query.update_expire_time(false).await;
+            QueryResponse::from_internal(query.id.to_string(), resp, false)
+        }
+        Err(e) => {
+            error!("http query fail to start sql, error: {:?}", e);
+            ctx.set_fail();
+            Ok(req.fail_to_start_sql(e).into_response())
+        }
+    }
 }
 
 pub fn query_route(http_handler_kind: HttpHandlerKind) -> Route {
@@ -410,6 +830,9 @@ pub fn query_route(http_handler_kind: HttpHandlerKind) -> Route {
         ("/", post(query_handler)),
         ("/:id", get(query_state_handler)),
         ("/:id/page/:page_no", get(query_page_handler)),
+        ("/:id/stream", get(query_stream_handler)),
+        ("/:id/events", get(query_events_handler)),
+        ("/:id/result", get(query_result_handler)),
         (
             "/:id/kill",
             get(query_cancel_handler).post(query_cancel_handler),
@@ -422,7 +845,8 @@ pub fn query_route(http_handler_kind: HttpHandlerKind) -> Route {
 
     let mut route = Route::new();
     for (path, endpoint) in rules.into_iter() {
-        let kind = if path == "/" {
+        // reading the persisted result starts a new query
+        let kind = if path == "/" || path == "/:id/result" {
             EndpointKind::StartQuery
         } else {
             EndpointKind::PollQuery
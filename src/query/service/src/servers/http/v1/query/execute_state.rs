@@ -16,9 +16,11 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use databend_common_ast::ast::Expr;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::CatchUnwindFuture;
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
@@ -27,6 +29,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_settings::Settings;
+use databend_common_sql::plans::Plan;
 use databend_storages_common_session::TempTblMgrRef;
 use databend_storages_common_session::TxnManagerRef;
 use futures::StreamExt;
@@ -37,7 +40,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use ExecuteState::*;
 
-use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::interpreter_plan_sql_with_params;
+use crate::interpreters::interpreter_plan_substrait;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
@@ -123,6 +127,8 @@ pub struct ExecuteRunning {
     ctx: Arc<QueryContext>,
     schema: Vec<QueryResponseField>,
     has_result_set: bool,
+    // the result is persisted instead of being sent to the client
+    persist_result: bool,
     #[allow(dead_code)]
     queue_guard: AcquireQueueGuard,
 }
@@ -135,6 +141,7 @@ pub struct ExecuteStopped {
     pub reason: Result<(), ExecutionError>,
     pub session_state: ExecutorSessionState,
     pub query_duration_ms: i64,
+    pub queued_duration_ms: u64,
     pub warnings: Vec<String>,
 }
 
@@ -155,6 +162,7 @@ pub struct ExecutorSessionState {
     pub txn_manager: TxnManagerRef,
     pub temp_tbl_mgr: TempTblMgrRef,
     pub variables: HashMap<String, Scalar>,
+    pub prepared_statements: HashMap<String, PreparedStatement>,
 }
 
 impl ExecutorSessionState {
@@ -167,6 +175,7 @@ impl ExecutorSessionState {
             txn_manager: session.txn_mgr(),
             temp_tbl_mgr: session.temp_tbl_mgr(),
             variables: session.get_all_variables(),
+            prepared_statements: session.get_all_prepared_statements(),
         }
     }
 }
@@ -174,8 +183,11 @@ impl ExecutorSessionState {
 impl Executor {
     pub fn get_response_state(&self) -> ResponseState {
         let (exe_state, err) = self.state.extract();
+        let (queue_position, queued_duration_ms) = self.get_queue_info();
         ResponseState {
             running_time_ms: self.get_query_duration_ms(),
+            queue_position,
+            queued_duration_ms,
             progresses: self.get_progress(),
             state: exe_state,
             error: err,
@@ -201,6 +213,21 @@ impl Executor {
         }
     }
 
+    /// Whether the running query persists its result instead of sending it to the client,
+    /// such a query keeps running even if the client stops polling.
+    pub fn is_persisting_result(&self) -> bool {
+        matches!(&self.state, Running(r) if r.persist_result)
+    }
+
+    pub fn get_query_context(&self) -> Option<Arc<QueryContext>> {
+        match &self.state {
+            Starting(ExecuteStarting { ctx }) | Running(ExecuteRunning { ctx, .. }) => {
+                Some(ctx.clone())
+            }
+            Stopped(_) => None,
+        }
+    }
+
     pub fn get_progress(&self) -> Progresses {
         match &self.state {
             Starting(_) => Default::default(),
@@ -242,6 +269,18 @@ impl Executor {
         }
     }
 
+    pub fn get_queue_info(&self) -> (Option<u64>, u64) {
+        match &self.state {
+            Starting(ExecuteStarting { ctx }) | Running(ExecuteRunning { ctx, .. }) => {
+                match QueriesQueueManager::instance().position(&ctx.get_id()) {
+                    Some(p) => (Some(p.position), p.wait_time.as_millis() as u64),
+                    None => (None, ctx.get_query_queued_duration().as_millis() as u64),
+                }
+            }
+            Stopped(f) => (None, f.queued_duration_ms),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn start_to_running(this: &Arc<RwLock<Executor>>, state: ExecuteState) {
         let mut guard = this.write().await;
@@ -289,6 +328,7 @@ impl Executor {
                     reason: reason.clone(),
                     session_state: ExecutorSessionState::new(s.ctx.get_current_session()),
                     query_duration_ms: s.ctx.get_query_duration_ms(),
+                    queued_duration_ms: s.ctx.get_query_queued_duration().as_millis() as u64,
                     warnings: s.ctx.pop_warnings(),
                     affect: Default::default(),
                 }
@@ -311,6 +351,7 @@ impl Executor {
                     reason: reason.clone(),
                     session_state: ExecutorSessionState::new(r.ctx.get_current_session()),
                     query_duration_ms: r.ctx.get_query_duration_ms(),
+                    queued_duration_ms: r.ctx.get_query_queued_duration().as_millis() as u64,
                     warnings: r.ctx.pop_warnings(),
                     affect: r.ctx.get_affect(),
                 }
@@ -336,20 +377,28 @@ impl ExecuteState {
     pub(crate) async fn try_start_query(
         executor: Arc<RwLock<Executor>>,
         sql: String,
+        params: Option<Vec<Expr>>,
+        substrait_plan: Option<Vec<u8>>,
         session: Arc<Session>,
         ctx: Arc<QueryContext>,
         block_sender: SizedChannelSender<DataBlock>,
         format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>>,
+        result_schema: Arc<parking_lot::RwLock<Option<DataSchemaRef>>>,
     ) -> Result<(), ExecutionError> {
         let make_error = || format!("failed to start query: {sql}");
 
         info!("http query prepare to plan sql");
 
         // Use interpreter_plan_sql, we can write the query log if an error occurs.
-        let (plan, extras) = interpreter_plan_sql(ctx.clone(), &sql)
-            .await
-            .map_err(|err| err.display_with_sql(&sql))
-            .with_context(make_error)?;
+        let (plan, extras) = match &substrait_plan {
+            Some(plan) => interpreter_plan_substrait(ctx.clone(), plan)
+                .await
+                .with_context(make_error)?,
+            None => interpreter_plan_sql_with_params(ctx.clone(), &sql, params.as_deref())
+                .await
+                .map_err(|err| err.display_with_sql(&sql))
+                .with_context(make_error)?,
+        };
 
         let query_queue_manager = QueriesQueueManager::instance();
 
@@ -358,7 +407,9 @@ impl ExecuteState {
             query_queue_manager.length()
         );
 
-        let entry = QueryEntry::create(&ctx, &plan, &extras).with_context(make_error)?;
+        let entry = QueryEntry::create(&ctx, &plan, &extras)
+            .await
+            .with_context(make_error)?;
         let queue_guard = query_queue_manager
             .acquire(entry)
             .await
@@ -377,18 +428,29 @@ impl ExecuteState {
             .await
             .with_context(make_error)?;
         let has_result_set = plan.has_result_set();
+        {
+            let mut guard = result_schema.write();
+            *guard = Some(plan.schema());
+        }
         let schema = if has_result_set {
             // check has_result_set first for safety
             QueryResponseField::from_schema(plan.schema())
         } else {
             vec![]
         };
+        // only the result of queries is persisted, see `SelectInterpreter`.
+        let persist_result = matches!(plan, Plan::Query { .. })
+            && ctx
+                .get_settings()
+                .get_persist_query_result()
+                .with_context(make_error)?;
         let running_state = ExecuteRunning {
             session,
             ctx: ctx.clone(),
             queue_guard,
             schema,
             has_result_set,
+            persist_result,
         };
         info!("http query change state to Running");
         Executor::start_to_running(&executor, Running(running_state)).await;
@@ -403,6 +465,7 @@ impl ExecuteState {
             ctx_clone,
             block_sender,
             executor_clone.clone(),
+            persist_result,
         );
         match CatchUnwindFuture::create(res).await {
             Ok(Err(err)) => {
@@ -426,6 +489,7 @@ async fn execute(
     ctx: Arc<QueryContext>,
     block_sender: SizedChannelSender<DataBlock>,
     executor: Arc<RwLock<Executor>>,
+    persist_result: bool,
 ) -> Result<(), ExecutionError> {
     let make_error = || format!("failed to execute {}", interpreter.name());
 
@@ -433,6 +497,22 @@ async fn execute(
         .execute(ctx.clone())
         .await
         .with_context(make_error)?;
+    if persist_result {
+        // The blocks are written to the result storage by the pipeline, the client retrieves
+        // them by the query id later, so the execution is never blocked by the client.
+        while let Some(block_r) = data_stream.next().await {
+            if let Err(err) = block_r {
+                block_sender.close();
+                return Err(err.with_context(make_error()));
+            }
+        }
+        block_sender
+            .send(DataBlock::empty_with_schema(schema), 0)
+            .await;
+        Executor::stop::<()>(&executor, Ok(())).await;
+        block_sender.close();
+        return Ok(());
+    }
     match data_stream.next().await {
         None => {
             let block = DataBlock::empty_with_schema(schema);
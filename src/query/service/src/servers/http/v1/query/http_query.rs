@@ -21,21 +21,32 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_base::base::short_sql;
 use databend_common_base::base::tokio::sync::Mutex as TokioMutex;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::runtime::CatchUnwindFuture;
 use databend_common_base::runtime::GlobalQueryRuntime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
 use databend_common_settings::ScopeLevel;
+use databend_common_sql::count_placeholders;
 use databend_storages_common_session::TxnState;
 use fastrace::prelude::*;
 use http::StatusCode;
@@ -64,12 +75,14 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultFormat;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::QueryResponse;
 use crate::servers::http::v1::QueryStats;
 use crate::sessions::QueryAffect;
+use crate::sessions::QueryContext;
 use crate::sessions::Session;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
@@ -82,15 +95,75 @@ fn default_as_true() -> bool {
 pub struct HttpQueryRequest {
     pub session_id: Option<String>,
     pub session: Option<HttpSessionConf>,
+    #[serde(default)]
     pub sql: String,
+    /// A base64 encoded Substrait plan, executed instead of `sql` when present.
+    pub substrait_plan: Option<String>,
+    /// The values bound to the `?` placeholders in `sql`, in order.
+    pub params: Option<Vec<serde_json::Value>>,
     #[serde(default)]
     pub pagination: PaginationConf,
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// Return the rows as JSON strings (default), Arrow IPC stream or Parquet.
+    /// Can also be negotiated by the `Accept` header.
+    #[serde(default)]
+    pub result_format: ResultFormat,
+    /// Persist the complete result of the query, which can be retrieved later by the query id
+    /// from any node, with `/v1/query/{query_id}/result` or `RESULT_SCAN('{query_id}')`.
+    #[serde(default)]
+    pub persist_result: bool,
 }
 
 impl HttpQueryRequest {
+    /// The request to read the persisted result of the query `query_id`.
+    pub(crate) fn for_persisted_result(
+        query_id: &str,
+        result_format: ResultFormat,
+    ) -> Result<Self> {
+        if query_id.is_empty()
+            || !query_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "invalid query id '{query_id}'"
+            )));
+        }
+        Ok(HttpQueryRequest {
+            session_id: None,
+            session: None,
+            sql: format!("SELECT * FROM RESULT_SCAN('{query_id}')"),
+            substrait_plan: None,
+            params: None,
+            pagination: PaginationConf::default(),
+            string_fields: true,
+            stage_attachment: None,
+            result_format,
+            persist_result: false,
+        })
+    }
+
+    /// Return the serialized `substrait_plan` decoded from base64, if any.
+    pub(crate) fn substrait_plan_bytes(&self) -> Result<Option<Vec<u8>>> {
+        self.substrait_plan
+            .as_ref()
+            .map(|plan| {
+                BASE64_STANDARD.decode(plan).map_err(|e| {
+                    ErrorCode::BadArguments(format!("invalid base64 substrait plan: {e}"))
+                })
+            })
+            .transpose()
+    }
+
+    /// Return `params` as the values of the `?` placeholders in `sql`, if any.
+    pub(crate) fn bound_params(&self) -> Option<Vec<Expr>> {
+        self.params
+            .as_ref()
+            .map(|params| params.iter().map(json_to_expr).collect())
+    }
+
     pub(crate) fn fail_to_start_sql(&self, err: ErrorCode) -> impl IntoResponse {
         metrics_incr_http_response_errors_count(err.name(), err.code());
         let session = self.session.as_ref().map(|s| {
@@ -131,13 +204,41 @@ impl Debug for HttpQueryRequest {
             .field("session_id", &self.session_id)
             .field("session", &self.session)
             .field("sql", &short_sql(self.sql.clone(), 1000))
+            .field("substrait_plan", &self.substrait_plan.is_some())
+            .field("params", &self.params.as_ref().map(|p| p.len()))
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("result_format", &self.result_format)
+            .field("persist_result", &self.persist_result)
             .finish()
     }
 }
 
+fn json_to_expr(value: &serde_json::Value) -> Expr {
+    let literal = |value| Expr::Literal { span: None, value };
+    match value {
+        serde_json::Value::Null => literal(Literal::Null),
+        serde_json::Value::Bool(v) => literal(Literal::Boolean(*v)),
+        serde_json::Value::Number(v) => match (v.as_u64(), v.as_i64()) {
+            (Some(v), _) => literal(Literal::UInt64(v)),
+            (None, Some(v)) => Expr::UnaryOp {
+                span: None,
+                op: UnaryOperator::Minus,
+                expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+            },
+            _ => literal(Literal::Float64(v.as_f64().unwrap_or_default())),
+        },
+        serde_json::Value::String(v) => literal(Literal::String(v.clone())),
+        serde_json::Value::Array(v) => Expr::Array {
+            span: None,
+            exprs: v.iter().map(json_to_expr).collect(),
+        },
+        // Objects are passed as JSON strings, which can be cast to variant.
+        serde_json::Value::Object(_) => literal(Literal::String(value.to_string())),
+    }
+}
+
 const DEFAULT_MAX_ROWS_IN_BUFFER: usize = 5 * 1000 * 1000;
 const DEFAULT_MAX_ROWS_PER_PAGE: usize = 10000;
 const DEFAULT_WAIT_TIME_SECS: u32 = 10;
@@ -195,10 +296,16 @@ pub struct ServerInfo {
 pub struct HttpSessionStateInternal {
     /// value is JSON of Scalar
     variables: Vec<(String, String)>,
+    /// value is SQL of the prepared statement
+    #[serde(default)]
+    prepared_statements: Vec<(String, String)>,
 }
 
 impl HttpSessionStateInternal {
-    fn new(variables: &HashMap<String, Scalar>) -> Self {
+    fn new(
+        variables: &HashMap<String, Scalar>,
+        prepared_statements: &HashMap<String, PreparedStatement>,
+    ) -> Self {
         let variables = variables
             .iter()
             .map(|(k, v)| {
@@ -208,7 +315,31 @@ impl HttpSessionStateInternal {
                 )
             })
             .collect();
-        Self { variables }
+        let prepared_statements = prepared_statements
+            .iter()
+            .map(|(k, v)| (k.clone(), v.statement.to_string()))
+            .collect();
+        Self {
+            variables,
+            prepared_statements,
+        }
+    }
+
+    pub fn get_prepared_statements(
+        &self,
+        dialect: Dialect,
+    ) -> Result<HashMap<String, PreparedStatement>> {
+        let mut stmts = HashMap::with_capacity(self.prepared_statements.len());
+        for (k, v) in self.prepared_statements.iter() {
+            let tokens = tokenize_sql(v)?;
+            let (statement, _) = parse_sql(&tokens, dialect)?;
+            let param_count = count_placeholders(&statement);
+            stmts.insert(k.to_string(), PreparedStatement {
+                statement,
+                param_count,
+            });
+        }
+        Ok(stmts)
     }
 
     pub fn get_variables(&self) -> Result<HashMap<String, Scalar>> {
@@ -313,6 +444,8 @@ pub struct ResponseState {
     pub has_result_set: Option<bool>,
     pub schema: Vec<QueryResponseField>,
     pub running_time_ms: i64,
+    pub queue_position: Option<u64>,
+    pub queued_duration_ms: u64,
     pub progresses: Progresses,
     pub state: ExecuteStateKind,
     pub affect: Option<QueryAffect>,
@@ -326,6 +459,8 @@ pub struct HttpQueryResponseInternal {
     pub session: Option<HttpSessionConf>,
     pub state: ResponseState,
     pub node_id: String,
+    pub result_format: ResultFormat,
+    pub result_schema: Option<DataSchemaRef>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -351,6 +486,7 @@ pub struct HttpQuery {
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     page_manager: Arc<TokioMutex<PageManager>>,
+    result_schema: Arc<parking_lot::RwLock<Option<DataSchemaRef>>>,
     expire_state: Arc<parking_lot::Mutex<ExpireState>>,
     /// The timeout for the query result polling. In the normal case, the client driver
     /// should fetch the paginated result in a timely manner, and the interval should not
@@ -449,6 +585,10 @@ impl HttpQuery {
                 if !state.variables.is_empty() {
                     session.set_all_variables(state.get_variables()?)
                 }
+                let dialect = session.get_settings().get_sql_dialect()?;
+                for (name, stmt) in state.get_prepared_statements(dialect)? {
+                    session.set_prepared_statement(name, stmt);
+                }
             }
             try_set_txn(&ctx.query_id, &session, session_conf, &http_query_manager)?;
             if session_conf.need_sticky
@@ -479,6 +619,9 @@ impl HttpQuery {
         if let Some(ua) = user_agent {
             ctx.set_ua(ua.clone());
         }
+        if request.persist_result {
+            ctx.get_settings().set_persist_query_result(true)?;
+        }
 
         // TODO: validate the query_id to be uuid format
         ctx.set_id(query_id.clone());
@@ -515,6 +658,8 @@ impl HttpQuery {
         let state_clone = state.clone();
         let ctx_clone = ctx.clone();
         let sql = request.sql.clone();
+        let params = request.bound_params();
+        let substrait_plan = request.substrait_plan_bytes()?;
 
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
         let span = if let Some(parent) = SpanContext::current_local_parent() {
@@ -525,6 +670,8 @@ impl HttpQuery {
         };
         let format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>> = Default::default();
         let format_settings_clone = format_settings.clone();
+        let result_schema: Arc<parking_lot::RwLock<Option<DataSchemaRef>>> = Default::default();
+        let result_schema_clone = result_schema.clone();
         let tenant = session.get_current_tenant();
         let user_name = session.get_current_user()?.name;
 
@@ -540,10 +687,13 @@ impl HttpQuery {
                 if let Err(e) = CatchUnwindFuture::create(ExecuteState::try_start_query(
                     state,
                     sql,
+                    params,
+                    substrait_plan,
                     session,
                     ctx_clone.clone(),
                     block_sender,
                     format_settings_clone,
+                    result_schema_clone,
                 ))
                 .await
                 .with_context(|| "failed to start query")
@@ -556,6 +706,8 @@ impl HttpQuery {
                         reason: Err(e.clone()),
                         session_state: ExecutorSessionState::new(ctx_clone.get_current_session()),
                         query_duration_ms: ctx_clone.get_query_duration_ms(),
+                        queued_duration_ms: ctx_clone.get_query_queued_duration().as_millis()
+                            as u64,
                         affect: ctx_clone.get_affect(),
                         warnings: ctx_clone.pop_warnings(),
                     };
@@ -572,6 +724,7 @@ impl HttpQuery {
             request.pagination.max_rows_per_page,
             block_receiver,
             format_settings,
+            request.result_format,
         )));
 
         let query = HttpQuery {
@@ -584,6 +737,7 @@ impl HttpQuery {
             request,
             state,
             page_manager: data,
+            result_schema,
             result_timeout_secs,
 
             expire_state: Arc::new(Mutex::new(ExpireState::Working)),
@@ -612,6 +766,8 @@ impl HttpQuery {
             session: Some(session),
             node_id: self.node_id.clone(),
             session_id: self.session_id.clone(),
+            result_format: self.request.result_format,
+            result_schema: self.result_schema.read().clone(),
         })
     }
 
@@ -625,9 +781,24 @@ impl HttpQuery {
             node_id: self.node_id.clone(),
             state,
             session: None,
+            result_format: self.request.result_format,
+            result_schema: self.result_schema.read().clone(),
         })
     }
 
+    pub fn result_format(&self) -> ResultFormat {
+        self.request.result_format
+    }
+
+    pub fn result_schema(&self) -> Option<DataSchemaRef> {
+        self.result_schema.read().clone()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn next_page_no(&self) -> Option<usize> {
+        self.page_manager.lock().await.next_page_no()
+    }
+
     #[async_backtrace::framed]
     async fn get_state(&self) -> ResponseState {
         let state = self.state.read().await;
@@ -655,8 +826,13 @@ impl HttpQuery {
         let role = session_state.current_role.clone();
         let secondary_roles = session_state.secondary_roles.clone();
         let txn_state = session_state.txn_manager.lock().state();
-        let internal = if !session_state.variables.is_empty() {
-            Some(HttpSessionStateInternal::new(&session_state.variables))
+        let internal = if !session_state.variables.is_empty()
+            || !session_state.prepared_statements.is_empty()
+        {
+            Some(HttpSessionStateInternal::new(
+                &session_state.variables,
+                &session_state.prepared_statements,
+            ))
         } else {
             None
         };
@@ -757,11 +933,21 @@ impl HttpQuery {
     }
 
     #[async_backtrace::framed]
-    async fn detach(&self) {
+    pub(crate) async fn detach(&self) {
         let mut data = self.page_manager.lock().await;
         data.detach().await
     }
 
+    #[async_backtrace::framed]
+    pub async fn get_query_context(&self) -> Option<Arc<QueryContext>> {
+        self.state.read().await.get_query_context()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn is_persisting_result(&self) -> bool {
+        self.state.read().await.is_persisting_result()
+    }
+
     #[async_backtrace::framed]
     pub async fn update_expire_time(&self, before_wait: bool) {
         let duration = Duration::from_secs(self.result_timeout_secs)
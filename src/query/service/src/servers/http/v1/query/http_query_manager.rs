@@ -185,7 +185,18 @@ impl HttpQueryManager {
                 q.check_client_session_id(client_session_id)?;
             }
             if q.mark_removed(reason) {
-                q.kill(error).await;
+                if reason != RemoveReason::Canceled && q.is_persisting_result().await {
+                    // The query keeps running until its result is persisted, which is
+                    // retrieved by the query id later.
+                    log::info!(
+                        "{}: http query removed with reason {}, keep running to persist the result",
+                        q.id,
+                        reason
+                    );
+                    q.detach().await;
+                } else {
+                    q.kill(error).await;
+                }
                 let mut queue = self.removed_queries.lock();
                 if let Some(to_evict) = queue.push(q.id.to_string()) {
                     self.queries.remove(&to_evict);
@@ -19,6 +19,7 @@ mod http_query;
 mod http_query_context;
 mod http_query_manager;
 mod page_manager;
+pub mod result_format;
 pub mod sized_spsc;
 pub mod string_block;
 
@@ -34,6 +35,8 @@ pub use http_query::HttpSessionConf;
 pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub(crate) use http_query_manager::RemoveReason;
+pub use page_manager::Page;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
+pub use result_format::ResultFormat;
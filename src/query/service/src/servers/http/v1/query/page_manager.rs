@@ -25,6 +25,7 @@ use log::debug;
 use log::info;
 use parking_lot::RwLock;
 
+use super::result_format::ResultFormat;
 use super::string_block::block_to_strings;
 use super::string_block::StringBlock;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
@@ -35,9 +36,17 @@ pub enum Wait {
     Deadline(Instant),
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Page {
     pub data: StringBlock,
+    /// The typed blocks of the page, only filled when the result format is not JSON.
+    pub blocks: Vec<DataBlock>,
+}
+
+impl Page {
+    pub fn num_rows(&self) -> usize {
+        self.data.num_rows() + self.blocks.iter().map(|b| b.num_rows()).sum::<usize>()
+    }
 }
 
 pub struct ResponseData {
@@ -53,8 +62,10 @@ pub struct PageManager {
     block_end: bool,
     last_page: Option<Page>,
     row_buffer: VecDeque<Vec<Option<String>>>,
+    block_buffer: VecDeque<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: Arc<RwLock<Option<FormatSettings>>>,
+    result_format: ResultFormat,
 }
 
 impl PageManager {
@@ -62,6 +73,7 @@ impl PageManager {
         max_rows_per_page: usize,
         block_receiver: SizedChannelReceiver<DataBlock>,
         format_settings: Arc<RwLock<Option<FormatSettings>>>,
+        result_format: ResultFormat,
     ) -> PageManager {
         PageManager {
             total_rows: 0,
@@ -70,9 +82,11 @@ impl PageManager {
             end: false,
             block_end: false,
             row_buffer: Default::default(),
+            block_buffer: Default::default(),
             block_receiver,
             max_rows_per_page,
             format_settings,
+            result_format,
         }
     }
 
@@ -89,10 +103,13 @@ impl PageManager {
         let next_no = self.total_pages;
         if page_no == next_no {
             if !self.end {
-                let (block, end) = self.collect_new_page(tp).await?;
-                let num_row = block.num_rows();
+                let (page, end) = if self.result_format.is_binary() {
+                    self.collect_new_blocks(tp).await?
+                } else {
+                    self.collect_new_page(tp).await?
+                };
+                let num_row = page.num_rows();
                 self.total_rows += num_row;
-                let page = Page { data: block };
                 if num_row > 0 {
                     self.total_pages += 1;
                     self.last_page = Some(page.clone());
@@ -103,10 +120,7 @@ impl PageManager {
                 // when end is set to true, client should recv a response with next_url = final_url
                 // but the response may be lost and client will retry,
                 // we simply return an empty page.
-                let page = Page {
-                    data: StringBlock::default(),
-                };
-                Ok(page)
+                Ok(Page::default())
             }
         } else if page_no + 1 == next_no {
             // later, there may be other ways to ack and drop the last page except collect_new_page.
@@ -151,7 +165,7 @@ impl PageManager {
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(StringBlock, bool)> {
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(Page, bool)> {
         let mut res: Vec<Vec<Option<String>>> = Vec::with_capacity(self.max_rows_per_page);
         let mut remain_size = 10 * 1024 * 1024;
         let mut remain_rows = self.max_rows_per_page;
@@ -171,53 +185,104 @@ impl PageManager {
         }
 
         while remain_rows > 0 && remain_size > 0 {
-            match tp {
-                Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => {
-                        self.append_block(&mut res, block, &mut remain_rows, &mut remain_size)?
-                    }
-                    None => break,
-                },
-                Wait::Deadline(t) => {
-                    let now = Instant::now();
-                    let d = *t - now;
-                    if d.is_zero() {
-                        // timeout() will return Ok if the future completes immediately
-                        break;
-                    }
-                    match tokio::time::timeout(d, self.block_receiver.recv()).await {
-                        Ok(Some(block)) => {
-                            debug!("http query got new block with {} rows", block.num_rows());
-                            self.append_block(&mut res, block, &mut remain_rows, &mut remain_size)?;
-                        }
-                        Ok(None) => {
-                            info!("http query reach end of blocks");
-                            break;
-                        }
-                        Err(_) => {
-                            debug!("http query long pulling timeout");
-                            break;
-                        }
-                    }
+            match self.recv_block(tp).await {
+                Some(block) => {
+                    self.append_block(&mut res, block, &mut remain_rows, &mut remain_size)?
                 }
+                None => break,
             }
         }
 
-        let block = StringBlock { data: res };
+        let page = Page {
+            data: StringBlock { data: res },
+            blocks: vec![],
+        };
 
         // try to report 'no more data' earlier to client to avoid unnecessary http call
         if !self.block_end {
             self.block_end = self.block_receiver.is_empty();
         }
         let end = self.block_end && self.row_buffer.is_empty();
-        Ok((block, end))
+        Ok((page, end))
+    }
+
+    /// Collect typed blocks for the binary result formats, split by the same row and size limits as JSON pages.
+    #[async_backtrace::framed]
+    async fn collect_new_blocks(&mut self, tp: &Wait) -> Result<(Page, bool)> {
+        let mut blocks = vec![];
+        let mut remain_size = 10 * 1024 * 1024;
+        let mut remain_rows = self.max_rows_per_page;
+        while remain_rows > 0 && remain_size > 0 {
+            let block = match self.block_buffer.pop_front() {
+                Some(block) => block,
+                None => match self.recv_block(tp).await {
+                    Some(block) => block,
+                    None => break,
+                },
+            };
+            let num_rows = block.num_rows();
+            if num_rows == 0 {
+                continue;
+            }
+            let block = if num_rows > remain_rows {
+                self.block_buffer
+                    .push_front(block.slice(remain_rows..num_rows));
+                block.slice(0..remain_rows)
+            } else {
+                block
+            };
+            remain_rows -= block.num_rows();
+            remain_size = remain_size.saturating_sub(block.memory_size());
+            blocks.push(block);
+        }
+
+        let page = Page {
+            data: StringBlock::default(),
+            blocks,
+        };
+
+        if !self.block_end {
+            self.block_end = self.block_receiver.is_empty();
+        }
+        let end = self.block_end && self.block_buffer.is_empty();
+        Ok((page, end))
+    }
+
+    #[async_backtrace::framed]
+    async fn recv_block(&mut self, tp: &Wait) -> Option<DataBlock> {
+        match tp {
+            Wait::Async => self.block_receiver.try_recv(),
+            Wait::Deadline(t) => {
+                let now = Instant::now();
+                let d = *t - now;
+                if d.is_zero() {
+                    // timeout() will return Ok if the future completes immediately
+                    return None;
+                }
+                match tokio::time::timeout(d, self.block_receiver.recv()).await {
+                    Ok(Some(block)) => {
+                        debug!("http query got new block with {} rows", block.num_rows());
+                        Some(block)
+                    }
+                    Ok(None) => {
+                        info!("http query reach end of blocks");
+                        None
+                    }
+                    Err(_) => {
+                        debug!("http query long pulling timeout");
+                        None
+                    }
+                }
+            }
+        }
     }
 
     #[async_backtrace::framed]
     pub async fn detach(&mut self) {
         self.block_receiver.close();
         self.last_page = None;
-        self.row_buffer.clear()
+        self.row_buffer.clear();
+        self.block_buffer.clear()
     }
 }
 
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use parquet::arrow::ArrowWriter;
+use serde::Deserialize;
+
+/// The key in the Arrow schema metadata under which the JSON response
+/// (everything but `data`) is carried for binary result formats.
+pub const RESPONSE_METADATA_KEY: &str = "databend.response";
+
+pub const CONTENT_TYPE_ARROW_STREAM: &str = "application/vnd.apache.arrow.stream";
+pub const CONTENT_TYPE_PARQUET: &str = "application/vnd.apache.parquet";
+
+/// How the rows of a page are returned to the client.
+///
+/// `Json` keeps the stringified rows in `data`; `Arrow` and `Parquet` return typed
+/// columns as the response body instead.
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultFormat {
+    #[default]
+    Json,
+    Arrow,
+    Parquet,
+}
+
+impl ResultFormat {
+    /// Negotiate the format from an `Accept` header, the first supported media type wins.
+    pub fn from_accept(accept: &str) -> Option<ResultFormat> {
+        accept.split(',').find_map(|item| {
+            let media_type = item.split(';').next().unwrap_or_default().trim();
+            if media_type.eq_ignore_ascii_case(CONTENT_TYPE_ARROW_STREAM) {
+                Some(ResultFormat::Arrow)
+            } else if media_type.eq_ignore_ascii_case(CONTENT_TYPE_PARQUET) {
+                Some(ResultFormat::Parquet)
+            } else if media_type.eq_ignore_ascii_case("application/json") {
+                Some(ResultFormat::Json)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn is_binary(&self) -> bool {
+        !matches!(self, ResultFormat::Json)
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ResultFormat::Json => "application/json",
+            ResultFormat::Arrow => CONTENT_TYPE_ARROW_STREAM,
+            ResultFormat::Parquet => CONTENT_TYPE_PARQUET,
+        }
+    }
+
+    /// Encode the blocks of a page, `response` is the JSON response attached to the schema metadata.
+    pub fn encode(
+        &self,
+        schema: &DataSchema,
+        blocks: Vec<DataBlock>,
+        response: String,
+    ) -> Result<Vec<u8>> {
+        let (arrow_schema, batches) = to_record_batches(schema, blocks, response)?;
+        let mut buf = Vec::new();
+        match self {
+            ResultFormat::Json => {
+                return Err(ErrorCode::Internal(
+                    "JSON results are not encoded as binary",
+                ));
+            }
+            ResultFormat::Arrow => {
+                let mut writer = StreamWriter::try_new(&mut buf, &arrow_schema)?;
+                for batch in batches.iter() {
+                    writer.write(batch)?;
+                }
+                writer.finish()?;
+            }
+            ResultFormat::Parquet => {
+                let mut writer = ArrowWriter::try_new(&mut buf, arrow_schema, None)?;
+                for batch in batches.iter() {
+                    writer.write(batch)?;
+                }
+                writer.close()?;
+            }
+        }
+        Ok(buf)
+    }
+}
+
+/// Incrementally writes pages as a single Arrow IPC stream.
+pub struct ArrowStreamEncoder {
+    schema: Arc<ArrowSchema>,
+    data_schema: DataSchema,
+    writer: StreamWriter<Vec<u8>>,
+}
+
+impl ArrowStreamEncoder {
+    pub fn try_create(schema: &DataSchema, response: String) -> Result<Self> {
+        let (arrow_schema, _) = to_record_batches(schema, vec![], response)?;
+        let writer = StreamWriter::try_new(Vec::new(), &arrow_schema)?;
+        Ok(ArrowStreamEncoder {
+            schema: arrow_schema,
+            data_schema: schema.clone(),
+            writer,
+        })
+    }
+
+    /// Write the blocks and return the bytes encoded since the last call.
+    pub fn write(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<u8>> {
+        for block in blocks {
+            let batch = block.to_record_batch_with_dataschema(&self.data_schema)?;
+            let batch = RecordBatch::try_new(self.schema.clone(), batch.columns().to_vec())?;
+            self.writer.write(&batch)?;
+        }
+        Ok(std::mem::take(self.writer.get_mut()))
+    }
+
+    pub fn finish(&mut self) -> Result<Vec<u8>> {
+        self.writer.finish()?;
+        Ok(std::mem::take(self.writer.get_mut()))
+    }
+}
+
+fn to_record_batches(
+    schema: &DataSchema,
+    blocks: Vec<DataBlock>,
+    response: String,
+) -> Result<(Arc<ArrowSchema>, Vec<RecordBatch>)> {
+    let table_schema = infer_table_schema(schema)?;
+    let mut arrow_schema = table_schema_to_arrow_schema(&table_schema);
+    arrow_schema
+        .metadata
+        .insert(RESPONSE_METADATA_KEY.to_string(), response);
+    let arrow_schema = Arc::new(arrow_schema);
+    let batches = blocks
+        .into_iter()
+        .map(|block| {
+            let batch = block.to_record_batch(&table_schema)?;
+            Ok(RecordBatch::try_new(
+                arrow_schema.clone(),
+                batch.columns().to_vec(),
+            )?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((arrow_schema, batches))
+}
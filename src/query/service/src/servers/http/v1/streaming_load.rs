@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table_context::StageAttachment;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatOptionsReader;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::OnErrorMode;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_sql::Planner;
+use databend_common_storages_stage::StageTable;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use http::StatusCode;
+use log::info;
+use log::warn;
+use poem::web::Json;
+use poem::web::Query;
+use poem::Body;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::HttpQueryContext;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::StreamingLoadInterpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// The directory in the user stage to spool the files that can not be parsed as a stream.
+const STREAMING_LOAD_SPOOL_DIR: &str = "_streaming_load";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StreamingLoadResponse {
+    pub id: String,
+    pub state: String,
+    pub rows_loaded: usize,
+    pub bytes_read: usize,
+    /// The number of rows skipped by `on_error = continue`.
+    pub error_count: usize,
+    pub first_error: Option<String>,
+}
+
+#[derive(Debug)]
+struct StreamingLoadArgs {
+    catalog: String,
+    database: String,
+    table: String,
+    file_format_options: BTreeMap<String, String>,
+    copy_options: BTreeMap<String, String>,
+}
+
+impl StreamingLoadArgs {
+    /// The query parameters are the target table and `on_error`,
+    /// all the others are file format options, e.g. `type=csv&skip_header=1`.
+    fn parse(ctx: &QueryContext, mut params: BTreeMap<String, String>) -> Result<Self> {
+        let table = params
+            .remove("table")
+            .ok_or_else(|| ErrorCode::BadArguments("missing query parameter table"))?;
+        let database = params
+            .remove("database")
+            .unwrap_or_else(|| ctx.get_current_database());
+
+        let mut copy_options = BTreeMap::new();
+        if let Some(on_error) = params.remove("on_error") {
+            copy_options.insert("on_error".to_string(), on_error);
+        }
+
+        Ok(StreamingLoadArgs {
+            catalog: ctx.get_current_catalog(),
+            database,
+            table,
+            file_format_options: params,
+            copy_options,
+        })
+    }
+}
+
+/// Load the request body into a table, the body is a single file in the given format.
+///
+/// CSV, TSV and NDJSON are parsed as the body arrives. Parquet and ORC can not
+/// be read without seeking to the footer, so the body is spooled to the user
+/// stage and loaded from there.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn streaming_load(
+    ctx: &HttpQueryContext,
+    Query(params): Query<BTreeMap<String, String>>,
+    body: Body,
+) -> poem::Result<Json<StreamingLoadResponse>> {
+    let session = ctx.upgrade_session(SessionType::HTTPStreamingLoad)?;
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(poem::error::InternalServerError)?;
+    let args = StreamingLoadArgs::parse(&context, params).map_err(bad_request)?;
+
+    let file_format_params = FileFormatParams::try_from_reader(
+        FileFormatOptionsReader::from_map(args.file_format_options.clone()),
+        false,
+    )
+    .map_err(bad_request)?;
+    let mut stage_info = StageInfo {
+        file_format_params,
+        ..Default::default()
+    };
+    stage_info
+        .copy_options
+        .apply(&args.copy_options, false)
+        .map_err(bad_request)?;
+
+    info!(
+        "streaming load into {}.{} with {}",
+        args.database, args.table, stage_info.file_format_params
+    );
+    // Loaded as COPY, so that options like `empty_field_as` take effect.
+    context.attach_query_str(
+        QueryKind::CopyIntoTable,
+        format!(
+            "STREAMING LOAD INTO {}.{} FILE_FORMAT = ({})",
+            args.database, args.table, stage_info.file_format_params
+        ),
+    );
+
+    let res = match stage_info.file_format_params {
+        FileFormatParams::Csv(_) | FileFormatParams::Tsv(_) | FileFormatParams::NdJson(_) => {
+            load_stream(&context, &args, stage_info, body).await
+        }
+        _ => load_spooled(&context, &args, body).await,
+    };
+    res.map_err(|e| match e.code() {
+        ErrorCode::BAD_ARGUMENTS
+        | ErrorCode::BAD_BYTES
+        | ErrorCode::UNKNOWN_TABLE
+        | ErrorCode::UNKNOWN_DATABASE => bad_request(e),
+        ErrorCode::PERMISSION_DENIED => {
+            poem::Error::from_string(e.message(), StatusCode::FORBIDDEN)
+        }
+        _ => poem::error::InternalServerError(e),
+    })?;
+
+    let (error_count, first_error) = context
+        .get_copy_status()
+        .files
+        .iter()
+        .filter_map(|entry| {
+            let error = entry.value().error.as_ref()?;
+            Some((
+                error.num_errors,
+                format!(
+                    "{} at line {}",
+                    error.first_error.error, error.first_error.line
+                ),
+            ))
+        })
+        .fold((0, None), |(count, first), (num_errors, error)| {
+            (count + num_errors, first.or(Some(error)))
+        });
+
+    Ok(Json(StreamingLoadResponse {
+        id: context.get_id(),
+        state: "SUCCESS".to_string(),
+        rows_loaded: context.get_write_progress_value().rows,
+        bytes_read: context.get_scan_progress_value().bytes,
+        error_count,
+        first_error,
+    }))
+}
+
+async fn load_stream(
+    ctx: &Arc<QueryContext>,
+    args: &StreamingLoadArgs,
+    stage_info: StageInfo,
+    body: Body,
+) -> Result<()> {
+    if matches!(
+        stage_info.copy_options.on_error,
+        OnErrorMode::SkipFileNum(_)
+    ) {
+        return Err(ErrorCode::BadArguments(
+            "on_error = skip_file is not supported by the streaming load of CSV, TSV and NDJSON",
+        ));
+    }
+
+    let stream = Box::pin(body.into_bytes_stream()).into_async_read();
+    let interpreter = StreamingLoadInterpreter::try_create(
+        ctx.clone(),
+        args.catalog.clone(),
+        args.database.clone(),
+        args.table.clone(),
+        stage_info,
+        Box::new(stream),
+    )?;
+    execute(ctx, interpreter).await
+}
+
+/// Spool the body to the user stage, then load it with an `INSERT` from the stage attachment.
+async fn load_spooled(ctx: &Arc<QueryContext>, args: &StreamingLoadArgs, body: Body) -> Result<()> {
+    let user_stage = StageInfo::new_user_stage(ctx.get_current_user()?.name.as_str());
+    let op = StageTable::get_op(&user_stage)?;
+    let path = format!("{}/{}", STREAMING_LOAD_SPOOL_DIR, ctx.get_id());
+
+    let res = async {
+        let mut writer = op.writer(&path).await?;
+        let mut stream = body.into_bytes_stream();
+        while let Some(bytes) = stream.next().await {
+            writer.write(bytes?).await?;
+        }
+        writer.close().await?;
+
+        ctx.attach_stage(StageAttachment {
+            location: format!("@~/{}", path),
+            file_format_options: Some(args.file_format_options.clone()),
+            copy_options: Some(args.copy_options.clone()),
+        });
+
+        let settings = ctx.get_settings();
+        let dialect = settings.get_sql_dialect()?;
+        let case_sensitive = settings.get_quoted_ident_case_sensitive()?;
+        let sql = format!(
+            "INSERT INTO {}.{} VALUES",
+            display_ident(&args.database, case_sensitive, dialect),
+            display_ident(&args.table, case_sensitive, dialect)
+        );
+        let (plan, _) = Planner::new(ctx.clone()).plan_sql(&sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        execute(ctx, interpreter).await
+    }
+    .await;
+
+    if let Err(e) = op.delete(&path).await {
+        warn!("failed to remove the spooled file {}: {}", path, e);
+    }
+    res
+}
+
+async fn execute(ctx: &Arc<QueryContext>, interpreter: InterpreterPtr) -> Result<()> {
+    let mut blocks = interpreter.execute(ctx.clone()).await?;
+    while let Some(block) = blocks.next().await {
+        block?;
+    }
+    Ok(())
+}
+
+fn bad_request(e: ErrorCode) -> poem::Error {
+    poem::Error::from_string(e.message(), StatusCode::BAD_REQUEST)
+}
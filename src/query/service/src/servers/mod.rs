@@ -17,6 +17,7 @@
 pub use server::Server;
 pub use server::ShutdownHandle;
 
+pub use self::clickhouse::ClickHouseHandler;
 pub use self::flight_sql::FlightSQLServer;
 pub use self::http::HttpHandler;
 pub use self::http::HttpHandlerKind;
@@ -26,6 +27,7 @@ pub use self::mysql::MySQLHandler;
 pub use self::mysql::MySQLTlsConfig;
 
 pub mod admin;
+mod clickhouse;
 pub(crate) mod federated_helper;
 pub mod flight;
 pub mod flight_sql;
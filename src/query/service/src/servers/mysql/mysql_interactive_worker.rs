@@ -12,15 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
 use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -32,6 +40,8 @@ use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::client_session::ClientSession;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::count_placeholders;
+use databend_common_sql::Planner;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
 use fastrace::func_path;
@@ -40,17 +50,22 @@ use futures_util::StreamExt;
 use log::error;
 use log::info;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use uuid::Uuid;
 
-use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::interpreter_plan_sql_with_params;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::servers::mysql::writers::result_columns;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -66,6 +81,9 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    // Statements prepared by COM_STMT_PREPARE, keyed by the statement id.
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -177,7 +195,19 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
             ));
         }
 
-        self.base.do_execute(id, param, writer).await
+        // The result set of COM_STMT_EXECUTE is written in the binary protocol.
+        match self.base.do_execute(id, param) {
+            Ok((query, params)) => {
+                self.do_query_with_writer(&query, Some(&params), writer, true)
+                    .await
+            }
+            Err(cause) => {
+                let format = self.base.session.get_format_settings();
+                DFQueryResultWriter::create_binary(writer, self.base.session.clone())
+                    .write(Err(cause), &format)
+                    .await
+            }
+        }
     }
 
     /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
@@ -193,55 +223,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         query: &'a str,
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
-        let query_id = Uuid::new_v4().to_string();
-        let root = Span::root(func_path!(), SpanContext::random())
-            .with_properties(|| self.base.session.to_fastrace_properties());
-
-        let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.query_id = Some(query_id.clone());
-        let _guard = ThreadTracker::tracking(tracking_payload);
-
-        ThreadTracker::tracking_future(async {
-            if self.base.session.is_aborting() {
-                writer
-                    .error(
-                        ErrorKind::ER_ABORTING_CONNECTION,
-                        "Aborting this connection. because we are try aborting server.".as_bytes(),
-                    )
-                    .await?;
-
-                return Err(ErrorCode::AbortedSession(
-                    "Aborting this connection. because we are try aborting server.",
-                ));
-            }
-
-            let mut writer = DFQueryResultWriter::create(writer, self.base.session.clone());
-            if !self.keep_alive_task_started {
-                self.start_keep_alive().await
-            }
-
-            let instant = Instant::now();
-            let query_result = self
-                .base
-                .do_query(query_id, query)
-                .await
-                .map_err(|err| err.display_with_sql(query));
-
-            let format = self.base.session.get_format_settings();
-
-            let mut write_result = writer.write(query_result, &format).await;
-
-            if let Err(cause) = write_result {
-                self.base.session.txn_mgr().lock().set_fail();
-                let suffix = format!("(while in query {})", query);
-                write_result = Err(cause.add_message_back(suffix));
-            }
-            observe_mysql_process_request_duration(instant.elapsed());
-
-            write_result
-        })
-        .in_span(root)
-        .await
+        self.do_query_with_writer(query, None, writer, false).await
     }
 
     #[async_backtrace::framed]
@@ -300,36 +282,100 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        let statement = match self.parse_prepared_statement(query) {
+            Ok(statement) => statement,
+            Err(cause) => {
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, cause.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let id = self.next_statement_id;
+        self.next_statement_id = self.next_statement_id.wrapping_add(1);
+
+        // The types of the parameters are unknown until execution, so they are all described as strings.
+        let params = (0..statement.param_count)
+            .map(|_| Column {
+                table: String::new(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect::<Vec<_>>();
+        let columns = self.describe_prepared_statement(&statement).await;
+        self.prepared_statements.insert(id, statement);
+        writer.reply(id, &params, &columns).await?;
         Ok(())
     }
 
+    // Describe the result columns of a prepared query by planning it with the parameters
+    // bound to NULL. The other statements, and the queries which can not be planned
+    // without the parameters, are described without result columns.
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
-        &mut self,
-        _: u32,
-        _: ParamParser<'_>,
-        writer: QueryResultWriter<'_, W>,
-    ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+    async fn describe_prepared_statement(&self, prepared: &PreparedStatement) -> Vec<Column> {
+        if !matches!(prepared.statement, Statement::Query(_)) {
+            return vec![];
+        }
+
+        let plan = async {
+            let params = vec![
+                Expr::Literal {
+                    span: None,
+                    value: Literal::Null,
+                };
+                prepared.param_count
+            ];
+            let context = self.session.create_query_context().await?;
+            let mut planner = Planner::new(context);
+            let (plan, _) = planner
+                .plan_sql_with_params(&prepared.statement.to_string(), &params)
+                .await?;
+            Ok::<_, ErrorCode>(plan)
+        };
+
+        match plan.await {
+            Ok(plan) => result_columns(&plan.schema(), true).unwrap_or_default(),
+            Err(cause) => {
+                info!("Cannot describe the prepared statement: {}", cause);
+                vec![]
+            }
+        }
+    }
+
+    fn parse_prepared_statement(&self, query: &str) -> Result<PreparedStatement> {
+        let sql_dialect = self.session.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(query)?;
+        let (statement, _) = parse_sql(&tokens, sql_dialect)?;
+        let param_count = count_placeholders(&statement);
+        Ok(PreparedStatement {
+            statement,
+            param_count,
+        })
+    }
+
+    // Return the SQL of the prepared statement and the parameters of COM_STMT_EXECUTE, they are
+    // bound by the planner, which reuses the plan of the statement across the executions.
+    fn do_execute(&self, id: u32, params: ParamParser<'_>) -> Result<(String, Vec<Expr>)> {
+        let prepared = self.prepared_statements.get(&id).ok_or_else(|| {
+            ErrorCode::UnknownPreparedStatement(format!("Unknown prepared statement id {}", id))
+        })?;
+
+        let params = params
+            .into_iter()
+            .map(|param| param_to_expr(param.value.into_inner()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((prepared.statement.to_string(), params))
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -353,6 +399,7 @@ impl InteractiveWorkerBase {
         &mut self,
         query_id: String,
         query: &str,
+        params: Option<&[Expr]>,
     ) -> Result<(QueryResult, Option<FormatSettings>)> {
         match self.federated_server_command_check(query) {
             Some((schema, data_block)) => {
@@ -378,9 +425,10 @@ impl InteractiveWorkerBase {
                 context.set_id(query_id);
 
                 // Use interpreter_plan_sql, we can write the query log if an error occurs.
-                let (plan, extras) = interpreter_plan_sql(context.clone(), query).await?;
+                let (plan, extras) =
+                    interpreter_plan_sql_with_params(context.clone(), query, params).await?;
 
-                let entry = QueryEntry::create(&context, &plan, &extras)?;
+                let entry = QueryEntry::create(&context, &plan, &extras).await?;
                 let _guard = QueriesQueueManager::instance().acquire(entry).await?;
 
                 let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
@@ -455,7 +503,8 @@ impl InteractiveWorkerBase {
         tracking_payload.query_id = Some(query_id.clone());
         let _guard = ThreadTracker::tracking(tracking_payload);
 
-        let do_query = ThreadTracker::tracking_future(self.do_query(query_id, &init_query)).await;
+        let do_query =
+            ThreadTracker::tracking_future(self.do_query(query_id, &init_query, None)).await;
         match do_query {
             Ok((_, _)) => Ok(()),
             Err(error_code) => Err(error_code),
@@ -478,7 +527,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 1,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
@@ -486,6 +539,68 @@ impl InteractiveWorker {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn do_query_with_writer<W: AsyncWrite + Send + Sync + Unpin>(
+        &mut self,
+        query: &str,
+        params: Option<&[Expr]>,
+        writer: QueryResultWriter<'_, W>,
+        binary: bool,
+    ) -> Result<()> {
+        let query_id = Uuid::new_v4().to_string();
+        let root = Span::root(func_path!(), SpanContext::random())
+            .with_properties(|| self.base.session.to_fastrace_properties());
+
+        let mut tracking_payload = ThreadTracker::new_tracking_payload();
+        tracking_payload.query_id = Some(query_id.clone());
+        let _guard = ThreadTracker::tracking(tracking_payload);
+
+        ThreadTracker::tracking_future(async {
+            if self.base.session.is_aborting() {
+                writer
+                    .error(
+                        ErrorKind::ER_ABORTING_CONNECTION,
+                        "Aborting this connection. because we are try aborting server.".as_bytes(),
+                    )
+                    .await?;
+
+                return Err(ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+            }
+
+            let mut writer = match binary {
+                true => DFQueryResultWriter::create_binary(writer, self.base.session.clone()),
+                false => DFQueryResultWriter::create(writer, self.base.session.clone()),
+            };
+            if !self.keep_alive_task_started {
+                self.start_keep_alive().await
+            }
+
+            let instant = Instant::now();
+            let query_result = self
+                .base
+                .do_query(query_id, query, params)
+                .await
+                .map_err(|err| err.display_with_sql(query));
+
+            let format = self.base.session.get_format_settings();
+
+            let mut write_result = writer.write(query_result, &format).await;
+
+            if let Err(cause) = write_result {
+                self.base.session.txn_mgr().lock().set_fail();
+                let suffix = format!("(while in query {})", query);
+                write_result = Err(cause.add_message_back(suffix));
+            }
+            observe_mysql_process_request_duration(instant.elapsed());
+
+            write_result
+        })
+        .in_span(root)
+        .await
+    }
+
     async fn start_keep_alive(&mut self) {
         let session = &self.base.session;
         let tenant = session.get_current_tenant();
@@ -545,3 +660,85 @@ impl ProgressReporter for ContextProgressReporter {
         progress.rows as u64
     }
 }
+
+// Convert a parameter of the binary protocol to a literal of the AST.
+fn param_to_expr(value: ValueInner<'_>) -> Result<Expr> {
+    let literal = |value| Expr::Literal { span: None, value };
+    let expr = match value {
+        ValueInner::NULL => literal(Literal::Null),
+        ValueInner::Int(v) if v < 0 => Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+        },
+        ValueInner::Int(v) => literal(Literal::UInt64(v as u64)),
+        ValueInner::UInt(v) => literal(Literal::UInt64(v)),
+        ValueInner::Double(v) => literal(Literal::Float64(v)),
+        ValueInner::Bytes(v) => literal(Literal::String(
+            String::from_utf8(v.to_vec())
+                .map_err(|_| ErrorCode::BadBytes("string parameter is not a valid utf8 string"))?,
+        )),
+        ValueInner::Date(v) | ValueInner::Datetime(v) => {
+            literal(Literal::String(decode_binary_datetime(v)?))
+        }
+        ValueInner::Time(v) => literal(Literal::String(decode_binary_time(v)?)),
+    };
+    Ok(expr)
+}
+
+// https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_binary_resultset.html
+fn decode_binary_datetime(v: &[u8]) -> Result<String> {
+    let (year, month, day) = match v.len() {
+        0 => (0, 0, 0),
+        4 | 7 | 11 => (u16::from_le_bytes([v[0], v[1]]), v[2], v[3]),
+        len => {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid length {} of datetime parameter",
+                len
+            )));
+        }
+    };
+    if v.len() <= 4 {
+        return Ok(format!("{:04}-{:02}-{:02}", year, month, day));
+    }
+    let (hour, minute, second) = (v[4], v[5], v[6]);
+    let micros = match v.len() {
+        11 => u32::from_le_bytes([v[7], v[8], v[9], v[10]]),
+        _ => 0,
+    };
+    Ok(format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}",
+        year, month, day, hour, minute, second, micros
+    ))
+}
+
+fn decode_binary_time(v: &[u8]) -> Result<String> {
+    let (negative, days, hour, minute, second) = match v.len() {
+        0 => (false, 0, 0, 0, 0),
+        8 | 12 => (
+            v[0] == 1,
+            u32::from_le_bytes([v[1], v[2], v[3], v[4]]),
+            v[5],
+            v[6],
+            v[7],
+        ),
+        len => {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid length {} of time parameter",
+                len
+            )));
+        }
+    };
+    let micros = match v.len() {
+        12 => u32::from_le_bytes([v[8], v[9], v[10], v[11]]),
+        _ => 0,
+    };
+    Ok(format!(
+        "{}{:02}:{:02}:{:02}.{:06}",
+        if negative { "-" } else { "" },
+        days * 24 + hour as u32,
+        minute,
+        second,
+        micros
+    ))
+}
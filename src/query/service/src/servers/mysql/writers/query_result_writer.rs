@@ -71,6 +71,82 @@ impl QueryResult {
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
     session: Arc<Session>,
+    binary: bool,
+}
+
+fn convert_field_type(field: &DataField, binary: bool) -> Result<ColumnType> {
+    match field.data_type().remove_nullable() {
+        // The values are written as text, which is not a valid encoding of the temporal
+        // types in the binary protocol.
+        DataType::Date | DataType::Timestamp | DataType::Time if binary => {
+            Ok(ColumnType::MYSQL_TYPE_VAR_STRING)
+        }
+        DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
+        DataType::EmptyArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::EmptyMap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
+        DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+            NumberDataType::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+            NumberDataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+            NumberDataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+            NumberDataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+            NumberDataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+            NumberDataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+            NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+            NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+            NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+        },
+        DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
+        DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
+        DataType::Geography => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
+        DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Time => Ok(ColumnType::MYSQL_TYPE_TIME),
+        DataType::TimestampTz => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Vector(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Enum(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Uuid => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
+        DataType::LargeInt(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "Unsupported column type:{:?}",
+            field.data_type()
+        ))),
+    }
+}
+
+fn make_column_from_field(field: &DataField, binary: bool) -> Result<Column> {
+    // The binary protocol encodes the integers by the signedness of the column.
+    let colflags = match field.data_type().remove_nullable() {
+        DataType::Number(num_ty) if binary && num_ty.is_integer() && !num_ty.is_signed() => {
+            ColumnFlags::UNSIGNED_FLAG
+        }
+        _ => ColumnFlags::empty(),
+    };
+    convert_field_type(field, binary).map(|column_type| Column {
+        table: "".to_string(),
+        column: field.name().to_string(),
+        coltype: column_type,
+        colflags,
+    })
+}
+
+/// The columns of a result set, `binary` is true for the result sets of the prepared
+/// statements, which are written in the binary protocol.
+pub fn result_columns(schema: &DataSchemaRef, binary: bool) -> Result<Vec<Column>> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| make_column_from_field(field, binary))
+        .collect()
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -94,6 +170,20 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         DFQueryResultWriter::<'a, W> {
             inner: Some(inner),
             session,
+            binary: false,
+        }
+    }
+
+    /// Create a writer of the result set of a prepared statement, which is written in
+    /// the binary protocol.
+    pub fn create_binary(
+        inner: QueryResultWriter<'a, W>,
+        session: Arc<Session>,
+    ) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            session,
+            binary: true,
         }
     }
 
@@ -161,58 +251,8 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             return Ok(());
         }
 
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
-            match field.data_type().remove_nullable() {
-                DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                DataType::EmptyArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::EmptyMap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Number(num_ty) => match num_ty {
-                    NumberDataType::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
-                    NumberDataType::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                    NumberDataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                    NumberDataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
-                    NumberDataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
-                    NumberDataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                    NumberDataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                    NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
-                    NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                    NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
-                },
-                DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
-                DataType::Geography => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
-                DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
-                _ => Err(ErrorCode::Unimplemented(format!(
-                    "Unsupported column type:{:?}",
-                    field.data_type()
-                ))),
-            }
-        }
-
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
-            })
-        }
-
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
-        }
-
         let _tz = format.timezone;
-        match convert_schema(&query_result.schema) {
+        match result_columns(&query_result.schema, self.binary) {
             Err(error) => self.err(&error, dataset_writer).await,
             Ok(columns) => {
                 let mut row_writer = dataset_writer.start(&columns).await?;
@@ -283,6 +323,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     NumberScalar::Int64(v) => {
                                         row_writer.write_col(v)?;
                                     }
+                                    NumberScalar::Float32(v) if self.binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
+                                    NumberScalar::Float64(v) if self.binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
                                     _ => {
                                         write_field(
                                             &mut row_writer,
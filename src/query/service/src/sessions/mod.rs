@@ -24,6 +24,8 @@ mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
 mod session_type;
+mod user_limits;
+mod workload_group;
 
 pub use databend_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -31,10 +33,14 @@ pub use query_ctx::convert_query_log_timestamp;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
 pub use queue_mgr::AcquireQueueGuard;
+pub use queue_mgr::NamedQueue;
 pub use queue_mgr::QueriesQueueManager;
 pub use queue_mgr::QueryEntry;
 pub use queue_mgr::QueueData;
 pub use queue_mgr::QueueManager;
+pub use queue_mgr::QueueOptions;
+pub use queue_mgr::QueuePosition;
+pub use queue_mgr::QueueResources;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
@@ -43,3 +49,6 @@ pub use session_mgr_status::SessionManagerStatus;
 pub use session_privilege_mgr::SessionPrivilegeManager;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use user_limits::UserLimitsGuard;
+pub use workload_group::WorkloadGroupResource;
+pub use workload_group::WorkloadGroupResourceManager;
@@ -36,6 +36,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_base::JoinHandle;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
@@ -54,7 +55,9 @@ use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::ContextError;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::WorkloadGroupStatus;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -131,6 +134,9 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::UserLimitsGuard;
+use crate::sessions::WorkloadGroupResource;
+use crate::sessions::WorkloadGroupResourceManager;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
@@ -339,6 +345,22 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn get_workload_group(&self) -> Option<Arc<WorkloadGroupResource>> {
+        self.shared.workload_group.read().clone()
+    }
+
+    pub fn set_workload_group(&self, workload_group: Arc<WorkloadGroupResource>) {
+        *self.shared.workload_group.write() = Some(workload_group);
+    }
+
+    pub fn set_user_limits_guard(&self, guard: Arc<UserLimitsGuard>) {
+        *self.shared.user_limits_guard.lock() = Some(guard);
+    }
+
+    pub fn get_user_limits_guard(&self) -> Option<Arc<UserLimitsGuard>> {
+        self.shared.user_limits_guard.lock().clone()
+    }
+
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
@@ -718,7 +740,9 @@ impl TableContext for QueryContext {
     fn get_fuse_version(&self) -> String {
         let session = self.get_current_session();
         match session.get_type() {
-            SessionType::ClickHouseHttpHandler => self.clickhouse_version.clone(),
+            SessionType::ClickHouseHttpHandler | SessionType::Clickhouse => {
+                self.clickhouse_version.clone()
+            }
             SessionType::MySQL => self.mysql_version.clone(),
             _ => self.version.clone(),
         }
@@ -1140,6 +1164,15 @@ impl TableContext for QueryContext {
         SessionManager::instance().get_queries_profiles()
     }
 
+    fn get_workload_groups_status(&self) -> Vec<WorkloadGroupStatus> {
+        WorkloadGroupResourceManager::instance().list_status()
+    }
+
+    fn get_workload_group_mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.get_workload_group()
+            .map(|workload_group| workload_group.mem_stat())
+    }
+
     fn set_merge_into_join(&self, join: MergeIntoJoin) {
         let mut merge_into_join = self.shared.merge_into_join.write();
         *merge_into_join = join;
@@ -1250,6 +1283,24 @@ impl TableContext for QueryContext {
         self.shared.session.session_ctx.get_all_variables()
     }
 
+    fn set_prepared_statement(&self, name: String, stmt: PreparedStatement) {
+        self.shared
+            .session
+            .session_ctx
+            .set_prepared_statement(name, stmt)
+    }
+
+    fn get_prepared_statement(&self, name: &str) -> Option<PreparedStatement> {
+        self.shared.session.session_ctx.get_prepared_statement(name)
+    }
+
+    fn remove_prepared_statement(&self, name: &str) -> Option<PreparedStatement> {
+        self.shared
+            .session
+            .session_ctx
+            .remove_prepared_statement(name)
+    }
+
     #[async_backtrace::framed]
     async fn load_datalake_schema(
         &self,
@@ -61,6 +61,8 @@ use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::sessions::UserLimitsGuard;
+use crate::sessions::WorkloadGroupResource;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -136,6 +138,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    pub(in crate::sessions) workload_group: Arc<RwLock<Option<Arc<WorkloadGroupResource>>>>,
+
+    pub(in crate::sessions) user_limits_guard: Arc<Mutex<Option<Arc<UserLimitsGuard>>>>,
 }
 
 impl QueryContextShared {
@@ -189,6 +195,8 @@ impl QueryContextShared {
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            workload_group: Default::default(),
+            user_limits_guard: Default::default(),
         }))
     }
 
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::future::Future;
@@ -29,9 +31,14 @@ use std::time::SystemTime;
 
 use databend_common_ast::ast::ExplainKind;
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::spawn;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_config::QueryQueueConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::QueueLease;
+use databend_common_management::QueueLeaseMgr;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_metrics::session::dec_session_running_acquired_queries;
 use databend_common_metrics::session::inc_session_running_acquired_queries;
@@ -40,17 +47,34 @@ use databend_common_metrics::session::incr_session_queue_acquire_error_count;
 use databend_common_metrics::session::incr_session_queue_acquire_timeout_count;
 use databend_common_metrics::session::record_session_queue_acquire_duration_ms;
 use databend_common_metrics::session::set_session_queued_queries;
+use databend_common_sql::optimizer::RelExpr;
+use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOperator;
 use databend_common_sql::PlanExtras;
+use databend_common_users::RoleCacheManager;
 use log::info;
+use log::warn;
 use parking_lot::Mutex;
 use pin_project_lite::pin_project;
-use tokio::sync::AcquireError;
+use tokio::sync::oneshot;
 use tokio::sync::OwnedSemaphorePermit;
 use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
 use tokio::time::error::Elapsed;
 
+use crate::clusters::ClusterDiscovery;
 use crate::sessions::QueryContext;
+use crate::sessions::UserLimitsGuard;
+use crate::sessions::WorkloadGroupResource;
+use crate::sessions::WorkloadGroupResourceManager;
+
+/// The estimated resources of a queue item, used by the admission of the queue.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct QueueResources {
+    pub memory_usage: u64,
+    pub threads: u64,
+}
 
 pub trait QueueData: Send + Sync + 'static {
     type Key: Send + Sync + Eq + Hash + Display + Clone + 'static;
@@ -63,6 +87,21 @@ pub trait QueueData: Send + Sync + 'static {
 
     fn need_acquire_to_queue(&self) -> bool;
 
+    /// The semaphores acquired before the admission of the queue, e.g. the semaphore
+    /// of the named queue or the workload group.
+    fn semaphores(&self) -> Vec<Arc<Semaphore>> {
+        vec![]
+    }
+
+    /// The items with higher priority are admitted first.
+    fn priority(&self) -> u64 {
+        0
+    }
+
+    fn resources(&self) -> QueueResources {
+        QueueResources::default()
+    }
+
     fn enter_wait_pending(&self) {}
 
     fn exit_wait_pending(&self, _wait_time: Duration) {}
@@ -75,15 +114,360 @@ pub(crate) struct Inner<Data: QueueData> {
     pub is_abort: Arc<AtomicBool>,
 }
 
-pub struct QueueManager<Data: QueueData> {
+/// The position of a waiting item in the queue, and how long it has waited.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QueuePosition {
+    pub position: u64,
+    pub wait_time: Duration,
+}
+
+/// A named queue configured by `[[query.queues]]`.
+pub struct NamedQueue {
+    pub name: String,
+    pub priority: u64,
+    pub timeout: Option<Duration>,
     semaphore: Arc<Semaphore>,
+}
+
+impl NamedQueue {
+    fn create(config: &QueryQueueConfig) -> Self {
+        let permits = match config.max_running_queries {
+            0 => usize::MAX >> 4,
+            permits => permits as usize,
+        };
+
+        NamedQueue {
+            name: config.name.clone(),
+            priority: config.priority,
+            timeout: match config.timeout_secs {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            },
+            semaphore: Arc::new(Semaphore::new(permits)),
+        }
+    }
+
+    pub fn semaphore(&self) -> Arc<Semaphore> {
+        self.semaphore.clone()
+    }
+}
+
+#[derive(Default)]
+pub struct QueueOptions {
+    /// The max running items of the node, 0 means unlimited.
+    pub permits: usize,
+    /// The max total estimated memory usage of the running items, 0 means unlimited.
+    pub max_memory_usage: u64,
+    /// The max total threads of the running items, 0 means unlimited.
+    pub max_threads: u64,
+    pub queues: Vec<QueryQueueConfig>,
+    pub node_id: String,
+    /// The max running items of the cluster, only works with the `cluster_lease_mgr`.
+    pub cluster_permits: u64,
+    pub cluster_lease_mgr: Option<Arc<QueueLeaseMgr>>,
+}
+
+struct ResourcePoolState {
+    available: QueueResources,
+    available_permits: u64,
+    waiters: BTreeMap<(Reverse<u64>, u64), ResourcePoolWaiter>,
+    next_seq: u64,
+}
+
+impl ResourcePoolState {
+    fn fits(&self, resources: &QueueResources) -> bool {
+        self.available_permits >= 1
+            && self.available.memory_usage >= resources.memory_usage
+            && self.available.threads >= resources.threads
+    }
+
+    fn take(&mut self, resources: &QueueResources) {
+        self.available_permits -= 1;
+        self.available.memory_usage -= resources.memory_usage;
+        self.available.threads -= resources.threads;
+    }
+
+    fn give_back(&mut self, resources: &QueueResources) {
+        self.available_permits += 1;
+        self.available.memory_usage += resources.memory_usage;
+        self.available.threads += resources.threads;
+    }
+}
+
+struct ResourcePoolWaiter {
+    resources: QueueResources,
+    sender: oneshot::Sender<ResourcePoolPermit>,
+}
+
+/// The permits, memory and threads of the node shared by the running items.
+///
+/// The waiters are admitted by priority, and in arrival order with the same priority.
+/// The first waiter blocks the others until there are enough resources for it, so that
+/// a heavy item would not be starved by the light ones.
+struct ResourcePool {
+    capacity: QueueResources,
+    state: Mutex<ResourcePoolState>,
+}
+
+impl ResourcePool {
+    fn create(permits: usize, max_memory_usage: u64, max_threads: u64) -> Arc<Self> {
+        let permits = match permits {
+            0 => usize::MAX >> 4,
+            permits => permits,
+        };
+        let capacity = QueueResources {
+            memory_usage: match max_memory_usage {
+                0 => u64::MAX,
+                v => v,
+            },
+            threads: match max_threads {
+                0 => u64::MAX,
+                v => v,
+            },
+        };
+
+        Arc::new(ResourcePool {
+            capacity,
+            state: Mutex::new(ResourcePoolState {
+                available: capacity,
+                available_permits: permits as u64,
+                waiters: BTreeMap::new(),
+                next_seq: 0,
+            }),
+        })
+    }
+
+    async fn acquire(
+        self: &Arc<Self>,
+        priority: u64,
+        resources: QueueResources,
+    ) -> Result<ResourcePoolPermit> {
+        // An item requires more than the capacity is admitted when the pool is idle.
+        let resources = QueueResources {
+            memory_usage: std::cmp::min(resources.memory_usage, self.capacity.memory_usage),
+            threads: std::cmp::min(resources.threads, self.capacity.threads),
+        };
+
+        let (key, receiver) = {
+            let mut state = self.state.lock();
+            if state.waiters.is_empty() && state.fits(&resources) {
+                state.take(&resources);
+                return Ok(ResourcePoolPermit {
+                    pool: Some(self.clone()),
+                    resources,
+                });
+            }
+
+            let key = (Reverse(priority), state.next_seq);
+            let (sender, receiver) = oneshot::channel();
+            state.next_seq += 1;
+            state
+                .waiters
+                .insert(key, ResourcePoolWaiter { resources, sender });
+            (key, receiver)
+        };
+
+        let _guard = ResourcePoolWaiterGuard {
+            pool: self.clone(),
+            key,
+        };
+
+        receiver
+            .await
+            .map_err(|_| ErrorCode::TokioError("acquire queue failure."))
+    }
+
+    fn release(self: &Arc<Self>, resources: &QueueResources) {
+        let mut state = self.state.lock();
+        state.give_back(resources);
+        self.dispatch(&mut state);
+    }
+
+    fn dispatch(self: &Arc<Self>, state: &mut ResourcePoolState) {
+        while let Some((key, resources)) = state
+            .waiters
+            .first_key_value()
+            .map(|(key, waiter)| (*key, waiter.resources))
+        {
+            if !state.fits(&resources) {
+                break;
+            }
+
+            let Some(waiter) = state.waiters.remove(&key) else {
+                break;
+            };
+
+            state.take(&resources);
+            let permit = ResourcePoolPermit {
+                pool: Some(self.clone()),
+                resources,
+            };
+
+            if let Err(mut permit) = waiter.sender.send(permit) {
+                // The waiter is gone, return the resources without dispatching again.
+                permit.pool = None;
+                state.give_back(&resources);
+            }
+        }
+    }
+}
+
+struct ResourcePoolWaiterGuard {
+    pool: Arc<ResourcePool>,
+    key: (Reverse<u64>, u64),
+}
+
+impl Drop for ResourcePoolWaiterGuard {
+    fn drop(&mut self) {
+        let mut state = self.pool.state.lock();
+        if state.waiters.remove(&self.key).is_some() {
+            // The waiter may block the others.
+            self.pool.dispatch(&mut state);
+        }
+    }
+}
+
+struct ResourcePoolPermit {
+    pool: Option<Arc<ResourcePool>>,
+    resources: QueueResources,
+}
+
+impl Drop for ResourcePoolPermit {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.release(&self.resources);
+        }
+    }
+}
+
+/// Coordinates the running items of the cluster by the leases in meta service.
+struct ClusterQueue {
+    node_id: String,
+    permits: u64,
+    lease_mgr: Arc<QueueLeaseMgr>,
+}
+
+impl ClusterQueue {
+    /// Try to acquire a lease of the cluster, returns None if the cluster is full.
+    async fn try_acquire(&self, lease_id: &str) -> Result<Option<ClusterLeaseGuard>> {
+        let lease = QueueLease {
+            id: lease_id.to_string(),
+            node_id: self.node_id.clone(),
+            permits: 1,
+        };
+
+        if !self.lease_mgr.try_acquire(&lease, self.permits).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(ClusterLeaseGuard::create(
+            self.lease_mgr.clone(),
+            lease,
+        )))
+    }
+
+    /// The max time to wait for a release before trying again, the leases expired by
+    /// ttl may be noticed late by the watcher.
+    fn wait_interval(&self) -> Duration {
+        self.lease_mgr.lease_ttl() / 3
+    }
+}
+
+pub(crate) struct ClusterLeaseGuard {
+    lease_id: String,
+    lease_mgr: Arc<QueueLeaseMgr>,
+    heartbeat: JoinHandle<()>,
+}
+
+impl ClusterLeaseGuard {
+    pub(crate) fn create(lease_mgr: Arc<QueueLeaseMgr>, lease: QueueLease) -> Self {
+        let lease_id = lease.id.clone();
+        let heartbeat = spawn({
+            let lease_mgr = lease_mgr.clone();
+            async move {
+                let interval = lease_mgr.lease_ttl() / 3;
+                loop {
+                    tokio::time::sleep(interval).await;
+                    match lease_mgr.heartbeat(&lease).await {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            // The query is still running, put the lease back so that the
+                            // permits of the cluster are not over-committed.
+                            warn!("queue lease {} has expired, renew it", lease.id);
+                            if let Err(cause) = lease_mgr.renew(&lease).await {
+                                warn!("failed to renew queue lease {}, {:?}", lease.id, cause);
+                            }
+                        }
+                        Err(cause) => {
+                            warn!("failed to heartbeat queue lease {}, {:?}", lease.id, cause);
+                        }
+                    }
+                }
+            }
+        });
+
+        ClusterLeaseGuard {
+            lease_id,
+            lease_mgr,
+            heartbeat,
+        }
+    }
+}
+
+impl Drop for ClusterLeaseGuard {
+    fn drop(&mut self) {
+        self.heartbeat.abort();
+
+        let lease_id = self.lease_id.clone();
+        let lease_mgr = self.lease_mgr.clone();
+        spawn(async move {
+            // The lease expires by ttl if failed to release.
+            if let Err(cause) = lease_mgr.release(&lease_id).await {
+                warn!("failed to release queue lease {}, {:?}", lease_id, cause);
+            }
+        });
+    }
+}
+
+pub struct QueueManager<Data: QueueData> {
+    pool: Arc<ResourcePool>,
+    cluster: Option<Arc<ClusterQueue>>,
+    named_queues: HashMap<String, Arc<NamedQueue>>,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
 }
 
 impl<Data: QueueData> QueueManager<Data> {
-    pub fn init(permits: usize) -> Result<()> {
-        info!("queue manager permits: {:?}", permits);
-        GlobalInstance::set(Self::create(permits));
+    #[async_backtrace::framed]
+    pub async fn init(conf: &InnerConfig) -> Result<()> {
+        let mut options = QueueOptions {
+            permits: conf.query.max_running_queries as usize,
+            max_memory_usage: conf.query.queries_queue_max_memory_usage,
+            max_threads: conf.query.queries_queue_max_threads,
+            queues: conf.query.queues.clone(),
+            node_id: conf.query.node_id.clone(),
+            cluster_permits: conf.query.cluster_max_running_queries,
+            cluster_lease_mgr: None,
+        };
+
+        if options.cluster_permits != 0 {
+            let metastore = ClusterDiscovery::create_meta_client(conf).await?;
+            options.cluster_lease_mgr = Some(Arc::new(QueueLeaseMgr::create(
+                metastore,
+                conf.query.tenant_id.tenant_name(),
+                &conf.query.cluster_id,
+                Duration::from_secs(60),
+            )?));
+        }
+
+        info!(
+            "queue manager permits: {:?}, max memory usage: {:?}, max threads: {:?}, cluster permits: {:?}, queues: {:?}",
+            options.permits,
+            options.max_memory_usage,
+            options.max_threads,
+            options.cluster_permits,
+            options.queues
+        );
+        GlobalInstance::set(Self::create_with_options(options)?);
         Ok(())
     }
 
@@ -91,17 +475,68 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
-        if permits == 0 {
-            permits = usize::MAX >> 4;
-        }
-
+    pub fn create(permits: usize) -> Arc<QueueManager<Data>> {
         Arc::new(QueueManager {
+            pool: ResourcePool::create(permits, 0, 0),
+            cluster: None,
+            named_queues: HashMap::new(),
             queue: Mutex::new(HashMap::new()),
-            semaphore: Arc::new(Semaphore::new(permits)),
         })
     }
 
+    pub fn create_with_options(options: QueueOptions) -> Result<Arc<QueueManager<Data>>> {
+        let mut named_queues = HashMap::with_capacity(options.queues.len());
+        for config in &options.queues {
+            if config.name.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "query queue name can not be empty",
+                ));
+            }
+
+            let queue = Arc::new(NamedQueue::create(config));
+            if named_queues.insert(config.name.clone(), queue).is_some() {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "duplicate query queue name '{}'",
+                    config.name
+                )));
+            }
+        }
+
+        let cluster = options.cluster_lease_mgr.map(|lease_mgr| {
+            Arc::new(ClusterQueue {
+                node_id: options.node_id,
+                permits: options.cluster_permits,
+                lease_mgr,
+            })
+        });
+
+        Ok(Arc::new(QueueManager {
+            pool: ResourcePool::create(
+                options.permits,
+                options.max_memory_usage,
+                options.max_threads,
+            ),
+            cluster,
+            named_queues,
+            queue: Mutex::new(HashMap::new()),
+        }))
+    }
+
+    /// Get the named queue, the empty name is the default queue.
+    pub fn named_queue(&self, name: &str) -> Result<Option<Arc<NamedQueue>>> {
+        if name.is_empty() {
+            return Ok(None);
+        }
+
+        match self.named_queues.get(name) {
+            Some(queue) => Ok(Some(queue.clone())),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown query queue '{}', the queues are configured by [[query.queues]]",
+                name
+            ))),
+        }
+    }
+
     /// The length of the queue.
     pub fn length(&self) -> usize {
         let queue = self.queue.lock();
@@ -113,6 +548,26 @@ impl<Data: QueueData> QueueManager<Data> {
         queue.values().map(|x| x.data.clone()).collect::<Vec<_>>()
     }
 
+    /// The position of the waiting item in the queue, starts from 1.
+    /// The items with higher priority, and then the earlier ones, are ahead.
+    pub fn position(&self, key: &Data::Key) -> Option<QueuePosition> {
+        let queue = self.queue.lock();
+        let inner = queue.get(key)?;
+        let priority = inner.data.priority();
+        let ahead = queue
+            .values()
+            .filter(|x| {
+                let x_priority = x.data.priority();
+                x_priority > priority || (x_priority == priority && x.instant < inner.instant)
+            })
+            .count();
+
+        Some(QueuePosition {
+            position: ahead as u64 + 1,
+            wait_time: inner.instant.elapsed(),
+        })
+    }
+
     pub fn remove(&self, key: Data::Key) -> bool {
         let mut queue = self.queue.lock();
         if let Some(inner) = queue.remove(&key) {
@@ -132,9 +587,52 @@ impl<Data: QueueData> QueueManager<Data> {
     pub async fn acquire(self: &Arc<Self>, data: Data) -> Result<AcquireQueueGuard> {
         if data.need_acquire_to_queue() {
             let timeout = data.timeout();
+            let semaphores = data.semaphores();
+            let priority = data.priority();
+            let resources = data.resources();
+            let lease_id = data.get_key().to_string();
+            let pool = self.pool.clone();
+            let cluster = self.cluster.clone();
+            let acquire_permits = async move {
+                loop {
+                    let mut permits = Vec::with_capacity(semaphores.len());
+                    for semaphore in &semaphores {
+                        let permit = semaphore
+                            .clone()
+                            .acquire_owned()
+                            .await
+                            .map_err(|_| ErrorCode::TokioError("acquire queue failure."))?;
+                        permits.push(permit);
+                    }
+
+                    let pool_permit = pool.acquire(priority, resources).await?;
+                    let Some(cluster) = &cluster else {
+                        return Ok(AcquireQueueGuard {
+                            permits,
+                            pool_permit: Some(pool_permit),
+                            cluster_lease: None,
+                        });
+                    };
+
+                    let watcher = cluster.lease_mgr.watch_release().await?;
+                    if let Some(cluster_lease) = cluster.try_acquire(&lease_id).await? {
+                        return Ok(AcquireQueueGuard {
+                            permits,
+                            pool_permit: Some(pool_permit),
+                            cluster_lease: Some(cluster_lease),
+                        });
+                    }
+
+                    // Give the permits of the node back while waiting for the cluster, so
+                    // that they are not held by the items which can not run.
+                    drop(permits);
+                    drop(pool_permit);
+                    watcher.wait(cluster.wait_interval()).await;
+                }
+            };
             let future = AcquireQueueFuture::create(
                 Arc::new(data),
-                tokio::time::timeout(timeout, self.semaphore.clone().acquire_owned()),
+                tokio::time::timeout(timeout, acquire_permits),
                 self.clone(),
             );
             let start_time = SystemTime::now();
@@ -164,7 +662,7 @@ impl<Data: QueueData> QueueManager<Data> {
             };
         }
 
-        Ok(AcquireQueueGuard::create(None))
+        Ok(AcquireQueueGuard::create_passed())
     }
 
     pub(crate) fn add_entity(&self, inner: Inner<Data>) -> Data::Key {
@@ -200,26 +698,33 @@ impl<Data: QueueData> QueueManager<Data> {
 
 pub struct AcquireQueueGuard {
     #[allow(dead_code)]
-    permit: Option<OwnedSemaphorePermit>,
+    permits: Vec<OwnedSemaphorePermit>,
+    pool_permit: Option<ResourcePoolPermit>,
+    #[allow(dead_code)]
+    cluster_lease: Option<ClusterLeaseGuard>,
 }
 
 impl Drop for AcquireQueueGuard {
     fn drop(&mut self) {
-        if self.permit.is_some() {
+        if self.pool_permit.is_some() {
             dec_session_running_acquired_queries();
         }
     }
 }
 
 impl AcquireQueueGuard {
-    pub fn create(permit: Option<OwnedSemaphorePermit>) -> Self {
-        AcquireQueueGuard { permit }
+    pub fn create_passed() -> Self {
+        AcquireQueueGuard {
+            permits: vec![],
+            pool_permit: None,
+            cluster_lease: None,
+        }
     }
 }
 
 pin_project! {
     pub struct AcquireQueueFuture<Data: QueueData, T>
-where T: Future<Output =  std::result::Result< std::result::Result<OwnedSemaphorePermit, AcquireError>, Elapsed>>
+where T: Future<Output = std::result::Result<Result<AcquireQueueGuard>, Elapsed>>
 {
     #[pin]
     inner: T,
@@ -234,12 +739,7 @@ where T: Future<Output =  std::result::Result< std::result::Result<OwnedSemaphor
 }
 
 impl<Data: QueueData, T> AcquireQueueFuture<Data, T>
-where T: Future<
-        Output = std::result::Result<
-            std::result::Result<OwnedSemaphorePermit, AcquireError>,
-            Elapsed,
-        >,
-    >
+where T: Future<Output = std::result::Result<Result<AcquireQueueGuard>, Elapsed>>
 {
     pub fn create(data: Arc<Data>, inner: T, mgr: Arc<QueueManager<Data>>) -> Self {
         AcquireQueueFuture {
@@ -254,12 +754,7 @@ where T: Future<
 }
 
 impl<Data: QueueData, T> Future for AcquireQueueFuture<Data, T>
-where T: Future<
-        Output = std::result::Result<
-            std::result::Result<OwnedSemaphorePermit, AcquireError>,
-            Elapsed,
-        >,
-    >
+where T: Future<Output = std::result::Result<Result<AcquireQueueGuard>, Elapsed>>
 {
     type Output = Result<AcquireQueueGuard>;
 
@@ -279,8 +774,8 @@ where T: Future<
                 }
 
                 Poll::Ready(match res {
-                    Ok(Ok(v)) => Ok(AcquireQueueGuard::create(Some(v))),
-                    Ok(Err(_)) => Err(ErrorCode::TokioError("acquire queue failure.")),
+                    Ok(Ok(guard)) => Ok(guard),
+                    Ok(Err(cause)) => Err(cause),
                     Err(_elapsed) => Err(ErrorCode::Timeout("query queuing timeout")),
                 })
             }
@@ -313,6 +808,9 @@ pub struct QueryEntry {
     pub user_info: UserInfo,
     pub timeout: Duration,
     pub need_acquire_to_queue: bool,
+    pub workload_group: Option<Arc<WorkloadGroupResource>>,
+    pub queue: Option<Arc<NamedQueue>>,
+    pub resources: QueueResources,
 }
 
 impl QueryEntry {
@@ -320,29 +818,152 @@ impl QueryEntry {
         ctx: &Arc<QueryContext>,
         plan_extras: &PlanExtras,
         need_acquire_to_queue: bool,
+        workload_group: Option<Arc<WorkloadGroupResource>>,
+        resources: QueueResources,
     ) -> Result<QueryEntry> {
         let settings = ctx.get_settings();
+        let queue = QueriesQueueManager::instance().named_queue(&settings.get_query_queue()?)?;
+        let mut timeout = match queue.as_ref().and_then(|x| x.timeout) {
+            Some(timeout) => timeout,
+            None => match settings.get_statement_queued_timeout()? {
+                0 => Duration::from_secs(60 * 60 * 24 * 365 * 35),
+                timeout => Duration::from_secs(timeout),
+            },
+        };
+        if let Some(queue_timeout) = workload_group.as_ref().and_then(|x| x.queue_timeout()) {
+            timeout = std::cmp::min(timeout, queue_timeout);
+        }
+
         Ok(QueryEntry {
             ctx: ctx.clone(),
             need_acquire_to_queue,
             query_id: ctx.get_id(),
             create_time: ctx.get_created_time(),
-            sql: plan_extras.statement.to_mask_sql(),
+            sql: plan_extras
+                .statement
+                .as_ref()
+                .map(|stmt| stmt.to_mask_sql())
+                .unwrap_or_default(),
             user_info: ctx.get_current_user()?,
-            timeout: match settings.get_statement_queued_timeout()? {
-                0 => Duration::from_secs(60 * 60 * 24 * 365 * 35),
-                timeout => Duration::from_secs(timeout),
-            },
+            timeout,
+            workload_group,
+            queue,
+            resources,
         })
     }
 
-    pub fn create(
+    #[async_backtrace::framed]
+    pub async fn create(
         ctx: &Arc<QueryContext>,
         plan: &Plan,
         plan_extras: &PlanExtras,
     ) -> Result<QueryEntry> {
         let need_add_to_queue = Self::is_heavy_action(plan);
-        QueryEntry::create_entry(ctx, plan_extras, need_add_to_queue)
+        let workload_group = Self::attach_workload_group(ctx).await?;
+        // Check the limits of the user before the query is queued.
+        UserLimitsGuard::attach(ctx).await?;
+        let resources = Self::estimate_resources(ctx, plan)?;
+        QueryEntry::create_entry(
+            ctx,
+            plan_extras,
+            need_add_to_queue,
+            workload_group,
+            resources,
+        )
+    }
+
+    /// Estimate the resources of the query, the threads are limited by the `max_threads`
+    /// setting, and the memory usage is estimated by the statistics of the plan.
+    fn estimate_resources(ctx: &Arc<QueryContext>, plan: &Plan) -> Result<QueueResources> {
+        let settings = ctx.get_settings();
+        let mut memory_usage = Self::estimate_memory_usage(plan)?;
+        let max_memory_usage = settings.get_max_memory_usage()?;
+        if max_memory_usage != 0 {
+            memory_usage = std::cmp::min(memory_usage, max_memory_usage);
+        }
+
+        Ok(QueueResources {
+            memory_usage,
+            threads: settings.get_max_threads()?,
+        })
+    }
+
+    fn estimate_memory_usage(plan: &Plan) -> Result<u64> {
+        match plan {
+            Plan::Query { s_expr, .. } => Self::estimate_scan_bytes(s_expr),
+            Plan::ExplainAnalyze { plan, .. }
+            | Plan::Explain {
+                kind: ExplainKind::AnalyzePlan,
+                plan,
+                ..
+            } => Self::estimate_memory_usage(plan),
+            _ => Ok(0),
+        }
+    }
+
+    /// The bytes of the rows read by the scans, the rows are the estimated cardinality
+    /// of the scan with the pushed down predicates, and the row size is the average row
+    /// size of the table.
+    fn estimate_scan_bytes(s_expr: &SExpr) -> Result<u64> {
+        if let RelOperator::Scan(scan) = s_expr.plan() {
+            let Some(table_stats) = &scan.statistics.table_stats else {
+                return Ok(0);
+            };
+
+            return match (table_stats.num_rows, table_stats.data_size) {
+                (Some(num_rows), Some(data_size)) if num_rows != 0 => {
+                    let cardinality = RelExpr::with_s_expr(s_expr).derive_cardinality()?;
+                    let row_size = data_size as f64 / num_rows as f64;
+                    Ok((cardinality.cardinality * row_size) as u64)
+                }
+                _ => Ok(0),
+            };
+        }
+
+        let mut bytes = 0;
+        for child in s_expr.children() {
+            bytes += Self::estimate_scan_bytes(child)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Attach the query to the workload group of the current user, or of the current role
+    /// if the user has none. The cpu quota of the group limits the max threads of the query,
+    /// and the memory quota is enforced by the memory stat of the group, which is the parent
+    /// of the query memory stat.
+    async fn attach_workload_group(
+        ctx: &Arc<QueryContext>,
+    ) -> Result<Option<Arc<WorkloadGroupResource>>> {
+        let tenant = ctx.get_tenant();
+        let user_info = ctx.get_current_user()?;
+        let name = match user_info.option.workload_group() {
+            Some(name) => Some(name.clone()),
+            None => match ctx.get_current_role() {
+                // The role of the session may be stale, read it from the role cache.
+                Some(role) => RoleCacheManager::instance()
+                    .find_role(&tenant, &role.name)
+                    .await?
+                    .and_then(|role| role.workload_group),
+                None => None,
+            },
+        };
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        let workload_group = WorkloadGroupResourceManager::instance()
+            .get_workload_group(&tenant, &name)
+            .await?;
+
+        if let Some(max_threads) = workload_group.max_threads() {
+            let settings = ctx.get_settings();
+            if settings.get_max_threads()? > max_threads {
+                settings.set_max_threads(max_threads)?;
+            }
+        }
+
+        ctx.set_workload_group(workload_group.clone());
+        Ok(Some(workload_group))
     }
 
     /// Check a plan is heavy action or not.
@@ -445,11 +1066,36 @@ impl QueueData for QueryEntry {
         self.need_acquire_to_queue
     }
 
+    fn semaphores(&self) -> Vec<Arc<Semaphore>> {
+        let mut semaphores = Vec::with_capacity(2);
+        if let Some(workload_group) = &self.workload_group {
+            semaphores.push(workload_group.semaphore());
+        }
+        if let Some(queue) = &self.queue {
+            semaphores.push(queue.semaphore());
+        }
+        semaphores
+    }
+
+    fn priority(&self) -> u64 {
+        self.queue.as_ref().map_or(0, |x| x.priority)
+    }
+
+    fn resources(&self) -> QueueResources {
+        self.resources
+    }
+
     fn enter_wait_pending(&self) {
+        if let Some(workload_group) = &self.workload_group {
+            workload_group.enter_queue();
+        }
         self.ctx.set_status_info("resources scheduling");
     }
 
     fn exit_wait_pending(&self, wait_time: Duration) {
+        if let Some(workload_group) = &self.workload_group {
+            workload_group.exit_queue();
+        }
         self.ctx
             .set_status_info(format!("resource scheduled(elapsed: {:?})", wait_time).as_str());
         self.ctx.set_query_queued_duration(wait_time)
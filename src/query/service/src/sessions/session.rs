@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::drop_guard;
 use databend_common_catalog::cluster_info::Cluster;
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -142,6 +143,18 @@ impl Session {
         }
     }
 
+    /// Kill the running query of the session only if it is the query of `query_id`,
+    /// returns whether the query is killed.
+    pub fn force_kill_query_by_id<C>(&self, query_id: &str, cause: ErrorCode<C>) -> bool {
+        match self.session_ctx.get_query_context_shared() {
+            Some(context_shared) if *context_shared.init_query_id.read() == query_id => {
+                context_shared.kill(cause);
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Create a query context for query.
     /// For a query, execution environment(e.g cluster) should be immutable.
     /// We can bind the environment to the context in create_context method.
@@ -371,6 +384,18 @@ impl Session {
         self.session_ctx.set_all_variables(variables)
     }
 
+    pub fn set_prepared_statement(&self, name: String, stmt: PreparedStatement) {
+        self.session_ctx.set_prepared_statement(name, stmt)
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Option<PreparedStatement> {
+        self.session_ctx.remove_prepared_statement(name)
+    }
+
+    pub fn get_all_prepared_statements(&self) -> HashMap<String, PreparedStatement> {
+        self.session_ctx.get_all_prepared_statements()
+    }
+
     pub fn get_client_session_id(&self) -> Option<String> {
         self.session_ctx.get_client_session_id()
     }
@@ -19,6 +19,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_expression::Scalar;
@@ -74,6 +75,8 @@ pub struct SessionContext {
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     // Used in set variables inside session
     variables: Arc<RwLock<HashMap<String, Scalar>>>,
+    // Statements prepared by `PREPARE`, by name
+    prepared_statements: RwLock<HashMap<String, PreparedStatement>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
     temp_tbl_mgr: Mutex<TempTblMgrRef>,
@@ -102,6 +105,7 @@ impl SessionContext {
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
             variables: Default::default(),
+            prepared_statements: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
             client_session_id: Default::default(),
@@ -343,6 +347,22 @@ impl SessionContext {
         *self.variables.write() = variables
     }
 
+    pub fn set_prepared_statement(&self, name: String, stmt: PreparedStatement) {
+        self.prepared_statements.write().insert(name, stmt);
+    }
+
+    pub fn get_prepared_statement(&self, name: &str) -> Option<PreparedStatement> {
+        self.prepared_statements.read().get(name).cloned()
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Option<PreparedStatement> {
+        self.prepared_statements.write().remove(name)
+    }
+
+    pub fn get_all_prepared_statements(&self) -> HashMap<String, PreparedStatement> {
+        self.prepared_statements.read().clone()
+    }
+
     pub fn session_state(&self) -> SessionState {
         SessionState {
             txn_mgr: self.txn_mgr(),
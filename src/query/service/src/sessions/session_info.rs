@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 
 use databend_common_base::base::ProgressValues;
@@ -20,6 +21,8 @@ pub use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::ProcessInfoState;
 use databend_common_storage::StorageMetrics;
 
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::QueuePosition;
 use crate::sessions::Session;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionType;
@@ -42,6 +45,9 @@ impl Session {
             }
         }
 
+        let current_query_id = self.get_current_query_id();
+        let queue_position = Self::query_queue_position(&current_query_id);
+
         ProcessInfo {
             id: self.id.clone(),
             typ: self.get_type().to_string(),
@@ -59,7 +65,24 @@ impl Session {
             status_info: shared_query_context
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
-            current_query_id: self.get_current_query_id(),
+            current_query_id,
+            queue_position: queue_position.map(|x| x.position),
+            queued_duration: match queue_position {
+                Some(queue_position) => queue_position.wait_time,
+                None => Self::query_queued_duration(session_ctx),
+            },
+        }
+    }
+
+    fn query_queue_position(query_id: &Option<String>) -> Option<QueuePosition> {
+        let query_id = query_id.as_ref()?;
+        QueriesQueueManager::instance().position(query_id)
+    }
+
+    fn query_queued_duration(status: &SessionContext) -> Duration {
+        match status.get_query_context_shared() {
+            None => Duration::from_secs(0),
+            Some(v) => *v.query_queued_duration.read(),
         }
     }
 
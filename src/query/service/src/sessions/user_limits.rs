@@ -0,0 +1,229 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::Progress;
+use databend_common_base::runtime::spawn;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_management::QueueLease;
+use databend_common_management::UserUsageMgr;
+use databend_common_meta_app::principal::UserLimits;
+use databend_common_users::UserApiProvider;
+use futures_util::StreamExt;
+use log::warn;
+use tokio::task::JoinHandle;
+
+use crate::sessions::queue_mgr::ClusterLeaseGuard;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+
+const RUNNING_QUERY_LEASE_TTL: Duration = Duration::from_secs(60);
+
+const SCAN_BYTES_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Enforces the limits of the current user on a query, see [`UserLimits`].
+///
+/// The running queries and the daily usage of the user are counted in the meta service,
+/// so that the limits apply to the whole cluster. The guard is held by the query context
+/// and releases the running query when the query is finished.
+pub struct UserLimitsGuard {
+    user: String,
+    max_result_rows: Option<u64>,
+    _running_query: Option<ClusterLeaseGuard>,
+    watchdog: Option<JoinHandle<()>>,
+    daily_usage: Option<(Arc<UserUsageMgr>, String)>,
+    scan_progress: Arc<Progress>,
+}
+
+impl UserLimitsGuard {
+    /// Check the limits of the current user and attach the guard to the query, it's
+    /// called before every query is executed, whatever the handler of the query is.
+    /// Does nothing if the guard is already attached.
+    #[async_backtrace::framed]
+    pub async fn attach(ctx: &Arc<QueryContext>) -> Result<()> {
+        if ctx.get_user_limits_guard().is_some() {
+            return Ok(());
+        }
+
+        if let Some(guard) = Self::try_create(ctx).await? {
+            ctx.set_user_limits_guard(Arc::new(guard));
+        }
+        Ok(())
+    }
+
+    /// Fail the result stream of the query once the rows exceed the `MAX_RESULT_ROWS`
+    /// limit of the user, the rows are never truncated silently.
+    pub fn limit_result_rows(
+        ctx: &Arc<QueryContext>,
+        stream: SendableDataBlockStream,
+    ) -> SendableDataBlockStream {
+        let Some(guard) = ctx.get_user_limits_guard() else {
+            return stream;
+        };
+        let Some(max_result_rows) = guard.max_result_rows else {
+            return stream;
+        };
+
+        let mut result_rows = 0;
+        Box::pin(stream.map(move |block| {
+            let block = block?;
+            result_rows += block.num_rows() as u64;
+            if result_rows > max_result_rows {
+                return Err(ErrorCode::UserLimitExceeded(format!(
+                    "The result of the query exceeds the limit MAX_RESULT_ROWS = {} of user '{}'",
+                    max_result_rows, guard.user
+                )));
+            }
+            Ok(block)
+        }))
+    }
+
+    /// Check the limits of the current user before the query runs, returns `None` if the
+    /// user has no limits.
+    #[async_backtrace::framed]
+    async fn try_create(ctx: &Arc<QueryContext>) -> Result<Option<UserLimitsGuard>> {
+        let user_info = ctx.get_current_user()?;
+        // A limit of `0` means unlimited, it never rejects the queries.
+        let limits = user_info.option.limits().normalized();
+        if limits.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(max_execution_time) = limits.max_execution_time_secs {
+            let settings = ctx.get_settings();
+            let max_execute_time = settings.get_max_execute_time_in_seconds()?;
+            if max_execute_time == 0 || max_execute_time > max_execution_time {
+                settings.set_max_execute_time_in_seconds(max_execution_time)?;
+            }
+        }
+
+        let mut guard = UserLimitsGuard {
+            user: user_info.name.clone(),
+            max_result_rows: limits.max_result_rows,
+            _running_query: None,
+            watchdog: None,
+            daily_usage: None,
+            scan_progress: ctx.get_scan_progress(),
+        };
+        if limits.max_concurrent_queries.is_none()
+            && limits.max_scan_bytes_per_query.is_none()
+            && limits.max_scan_bytes_per_day.is_none()
+        {
+            return Ok(Some(guard));
+        }
+
+        let usage_mgr = Arc::new(
+            UserApiProvider::instance().user_usage_api(&ctx.get_tenant(), &user_info.name)?,
+        );
+
+        let mut daily_scan_bytes = 0;
+        if let Some(max_scan_bytes_per_day) = limits.max_scan_bytes_per_day {
+            let day = Utc::now().format("%Y%m%d").to_string();
+            daily_scan_bytes = usage_mgr.get_daily_usage(&day).await?.scan_bytes;
+            if daily_scan_bytes >= max_scan_bytes_per_day {
+                return Err(ErrorCode::UserLimitExceeded(format!(
+                    "User '{}' has scanned {} bytes today, exceeds the limit MAX_SCAN_BYTES_PER_DAY = {}",
+                    user_info.name, daily_scan_bytes, max_scan_bytes_per_day
+                )));
+            }
+            guard.daily_usage = Some((usage_mgr.clone(), day));
+        }
+
+        if let Some(max_concurrent_queries) = limits.max_concurrent_queries {
+            let lease_mgr = Arc::new(usage_mgr.running_queries(RUNNING_QUERY_LEASE_TTL));
+            let lease = QueueLease {
+                id: ctx.get_id(),
+                node_id: ctx.get_cluster().local_id.clone(),
+                permits: 1,
+            };
+
+            if !lease_mgr
+                .try_acquire(&lease, max_concurrent_queries)
+                .await?
+            {
+                return Err(ErrorCode::UserLimitExceeded(format!(
+                    "User '{}' has too many running queries, exceeds the limit MAX_CONCURRENT_QUERIES = {}",
+                    user_info.name, max_concurrent_queries
+                )));
+            }
+            guard._running_query = Some(ClusterLeaseGuard::create(lease_mgr, lease));
+        }
+
+        guard.watchdog = Self::spawn_watchdog(
+            Arc::downgrade(&ctx.get_current_session()),
+            guard.scan_progress.clone(),
+            &limits,
+            daily_scan_bytes,
+        );
+        Ok(Some(guard))
+    }
+
+    /// Abort the query once the scanned bytes exceed the limits.
+    fn spawn_watchdog(
+        session: Weak<Session>,
+        scan_progress: Arc<Progress>,
+        limits: &UserLimits,
+        daily_scan_bytes: u64,
+    ) -> Option<JoinHandle<()>> {
+        let mut max_scan_bytes = limits.max_scan_bytes_per_query.unwrap_or(u64::MAX);
+        if let Some(max_scan_bytes_per_day) = limits.max_scan_bytes_per_day {
+            max_scan_bytes =
+                std::cmp::min(max_scan_bytes, max_scan_bytes_per_day - daily_scan_bytes);
+        }
+
+        if max_scan_bytes == u64::MAX {
+            return None;
+        }
+
+        Some(spawn(async move {
+            loop {
+                tokio::time::sleep(SCAN_BYTES_CHECK_INTERVAL).await;
+                let scan_bytes = scan_progress.get_values().bytes as u64;
+                if scan_bytes > max_scan_bytes {
+                    if let Some(session) = session.upgrade() {
+                        session.force_kill_query(ErrorCode::UserLimitExceeded(format!(
+                            "Aborted query, because the scanned bytes {} exceeds the limit of the user",
+                            scan_bytes
+                        )));
+                    }
+                    return;
+                }
+            }
+        }))
+    }
+}
+
+impl Drop for UserLimitsGuard {
+    fn drop(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            watchdog.abort();
+        }
+
+        if let Some((usage_mgr, day)) = self.daily_usage.take() {
+            let scan_bytes = self.scan_progress.get_values().bytes as u64;
+            spawn(async move {
+                if let Err(cause) = usage_mgr.add_daily_usage(&day, scan_bytes).await {
+                    warn!("failed to add the daily usage of user, {:?}", cause);
+                }
+            });
+        }
+    }
+}
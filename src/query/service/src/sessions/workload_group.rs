@@ -0,0 +1,330 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::spawn;
+use databend_common_base::runtime::MemStat;
+use databend_common_catalog::table_context::WorkloadGroupStatus;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
+use log::info;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+
+// Same as the queries queue, a group without concurrency limit still has a semaphore,
+// so that the running queries can be counted.
+const UNLIMITED_CONCURRENCY: usize = usize::MAX >> 4;
+
+/// The definitions of the workload groups are reloaded from meta at most once in the
+/// interval, the changes made on other nodes take effect after it.
+const WORKLOAD_GROUP_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The concurrency limit of a workload group, enforced by a semaphore.
+struct Concurrency {
+    /// The max running queries of the group.
+    permits: usize,
+    /// The permits added to the semaphore, including the ones taken back by `reserved`.
+    capacity: usize,
+    semaphore: Arc<Semaphore>,
+    /// The excess permits taken from the semaphore after the concurrency is decreased,
+    /// and the number of them.
+    reserved: Option<(OwnedSemaphorePermit, usize)>,
+    /// The task waiting for the excess permits, until the running queries release them.
+    shrink: Option<JoinHandle<()>>,
+    /// Increased by every resize, the permits taken by a stale shrink task are released.
+    version: u64,
+    /// The replaced semaphores and their capacities, the queries admitted by them may be
+    /// still running.
+    retired: Vec<(Arc<Semaphore>, usize)>,
+}
+
+impl Concurrency {
+    fn create(permits: usize) -> Arc<Mutex<Concurrency>> {
+        Arc::new(Mutex::new(Concurrency {
+            permits,
+            capacity: permits,
+            semaphore: Arc::new(Semaphore::new(permits)),
+            reserved: None,
+            shrink: None,
+            version: 0,
+            retired: vec![],
+        }))
+    }
+
+    fn resize(concurrency: &Arc<Mutex<Concurrency>>, permits: usize) {
+        let mut inner = concurrency.lock();
+        if inner.permits == permits {
+            return;
+        }
+
+        // Cancel the pending shrink and give back the excess permits, the semaphore is
+        // resized from its capacity.
+        inner.version += 1;
+        if let Some(shrink) = inner.shrink.take() {
+            shrink.abort();
+        }
+        inner.reserved = None;
+        inner.permits = permits;
+
+        if permits >= inner.capacity {
+            inner.semaphore.add_permits(permits - inner.capacity);
+            inner.capacity = permits;
+            return;
+        }
+
+        let Ok(excess) = u32::try_from(inner.capacity - permits) else {
+            // Too many permits to take back, e.g. the group was unlimited. Swap in a new
+            // semaphore, the queries admitted by the old one are not limited by it.
+            let semaphore = Arc::new(Semaphore::new(permits));
+            let retired = std::mem::replace(&mut inner.semaphore, semaphore);
+            let capacity = inner.capacity;
+            inner.retired.push((retired, capacity));
+            inner.capacity = permits;
+            return;
+        };
+
+        let semaphore = inner.semaphore.clone();
+        let version = inner.version;
+        let concurrency = Arc::downgrade(concurrency);
+        inner.shrink = Some(spawn(async move {
+            if let Ok(permit) = semaphore.acquire_many_owned(excess).await {
+                if let Some(concurrency) = concurrency.upgrade() {
+                    let mut inner = concurrency.lock();
+                    if inner.version == version {
+                        inner.reserved = Some((permit, excess as usize));
+                        inner.shrink = None;
+                    }
+                }
+            }
+        }));
+    }
+
+    fn running_queries(&mut self) -> usize {
+        self.retired
+            .retain(|(semaphore, capacity)| semaphore.available_permits() < *capacity);
+        let reserved = self.reserved.as_ref().map_or(0, |(_, permits)| *permits);
+        let running = self.capacity - self.semaphore.available_permits() - reserved;
+        self.retired
+            .iter()
+            .fold(running, |running, (semaphore, capacity)| {
+                running + capacity - semaphore.available_permits()
+            })
+    }
+}
+
+/// The runtime resources of a workload group on this node, shared by all the queries
+/// of the group.
+///
+/// The resources are updated in place when the group is altered, so that the running
+/// queries and the following ones share the same concurrency and memory limits.
+pub struct WorkloadGroupResource {
+    meta: RwLock<WorkloadGroup>,
+    concurrency: Arc<Mutex<Concurrency>>,
+    mem_stat: Arc<MemStat>,
+    queued_queries: AtomicU64,
+}
+
+impl WorkloadGroupResource {
+    pub fn create(meta: WorkloadGroup) -> WorkloadGroupResource {
+        let permits = Self::permits_of(&meta);
+        let mem_stat = MemStat::create(format!("WorkloadGroupMemStat-{}", meta.name));
+        let resource = WorkloadGroupResource {
+            meta: RwLock::new(meta),
+            concurrency: Concurrency::create(permits),
+            mem_stat,
+            queued_queries: AtomicU64::new(0),
+        };
+        resource.set_memory_limit();
+        resource
+    }
+
+    fn permits_of(meta: &WorkloadGroup) -> usize {
+        match meta.max_concurrency {
+            0 => UNLIMITED_CONCURRENCY,
+            max_concurrency => max_concurrency as usize,
+        }
+    }
+
+    fn set_memory_limit(&self) {
+        let memory_quota = self.meta.read().memory_quota;
+        let max_server_memory_usage = GlobalConfig::instance().query.max_server_memory_usage;
+        let limit = match memory_quota > 0 && max_server_memory_usage > 0 {
+            true => (max_server_memory_usage * memory_quota / 100) as i64,
+            false => 0,
+        };
+        self.mem_stat.set_limit(limit);
+    }
+
+    /// Apply the altered definition of the group.
+    ///
+    /// If the concurrency is decreased, the excess permits are taken from the semaphore
+    /// as soon as the running queries release them, unless the group is resized again
+    /// before that. If an unlimited group becomes limited, the following queries acquire
+    /// a new semaphore.
+    pub fn update(&self, meta: WorkloadGroup) {
+        let permits = Self::permits_of(&meta);
+        *self.meta.write() = meta;
+        self.set_memory_limit();
+        Concurrency::resize(&self.concurrency, permits);
+    }
+
+    pub fn name(&self) -> String {
+        self.meta.read().name.clone()
+    }
+
+    pub fn semaphore(&self) -> Arc<Semaphore> {
+        self.concurrency.lock().semaphore.clone()
+    }
+
+    /// The parent of the memory stat of every query in the group.
+    pub fn mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
+    /// The max seconds a query can wait in the queue, `None` if not limited by the group.
+    pub fn queue_timeout(&self) -> Option<Duration> {
+        match self.meta.read().queue_timeout_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    /// The max threads of a query in the group, `None` if not limited by the group.
+    pub fn max_threads(&self) -> Option<u64> {
+        match self.meta.read().cpu_quota {
+            0 => None,
+            cpu_quota => {
+                let num_cpus = num_cpus::get() as u64;
+                Some(std::cmp::max(1, (num_cpus * cpu_quota).div_ceil(100)))
+            }
+        }
+    }
+
+    pub fn enter_queue(&self) {
+        self.queued_queries.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn exit_queue(&self) {
+        self.queued_queries.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub fn status(&self) -> WorkloadGroupStatus {
+        WorkloadGroupStatus {
+            name: self.name(),
+            // The permits taken by a pending shrink are counted as running queries.
+            running_queries: self.concurrency.lock().running_queries() as u64,
+            queued_queries: self.queued_queries.load(Ordering::SeqCst),
+            memory_usage: self.mem_stat.get_memory_usage(),
+        }
+    }
+}
+
+struct CachedWorkloadGroup {
+    resource: Arc<WorkloadGroupResource>,
+    /// `None` if the definition is known to be changed.
+    refreshed_at: Option<Instant>,
+}
+
+/// Keeps the runtime resources of the workload groups used on this node.
+pub struct WorkloadGroupResourceManager {
+    groups: Mutex<HashMap<(String, String), CachedWorkloadGroup>>,
+}
+
+impl WorkloadGroupResourceManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(WorkloadGroupResourceManager {
+            groups: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupResourceManager> {
+        GlobalInstance::get()
+    }
+
+    /// Get the resources of a workload group.
+    ///
+    /// The resources are cached, the definition is reloaded from meta if it has not been
+    /// refreshed in `WORKLOAD_GROUP_REFRESH_INTERVAL`, and the altered quotas are applied
+    /// to the cached resources.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+    ) -> Result<Arc<WorkloadGroupResource>> {
+        let key = (tenant.tenant_name().to_string(), name.to_string());
+        if let Some(cached) = self.groups.lock().get(&key) {
+            let refreshed_at = cached.refreshed_at.as_ref();
+            if refreshed_at.is_some_and(|x| x.elapsed() < WORKLOAD_GROUP_REFRESH_INTERVAL) {
+                return Ok(cached.resource.clone());
+            }
+        }
+
+        let meta = UserApiProvider::instance()
+            .get_workload_group(tenant, name)
+            .await?;
+
+        let mut groups = self.groups.lock();
+        let resource = match groups.get(&key) {
+            Some(cached) => {
+                if *cached.resource.meta.read() != meta {
+                    info!("update resources of workload group: {:?}", meta);
+                    cached.resource.update(meta);
+                }
+                cached.resource.clone()
+            }
+            None => {
+                info!("create resources of workload group: {:?}", meta);
+                Arc::new(WorkloadGroupResource::create(meta))
+            }
+        };
+
+        groups.insert(key, CachedWorkloadGroup {
+            resource: resource.clone(),
+            refreshed_at: Some(Instant::now()),
+        });
+        Ok(resource)
+    }
+
+    /// Reload the definition of the group on the next use, called after the group is
+    /// altered or dropped on this node.
+    pub fn invalidate(&self, tenant: &Tenant, name: &str) {
+        let key = (tenant.tenant_name().to_string(), name.to_string());
+        if let Some(cached) = self.groups.lock().get_mut(&key) {
+            cached.refreshed_at = None;
+        }
+    }
+
+    pub fn list_status(&self) -> Vec<WorkloadGroupStatus> {
+        let groups = self.groups.lock();
+        groups
+            .values()
+            .map(|cached| cached.resource.status())
+            .collect()
+    }
+}
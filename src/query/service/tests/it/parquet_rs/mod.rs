@@ -16,3 +16,4 @@ mod data;
 mod prune_pages;
 mod prune_row_groups;
 mod utils;
+mod uuid;
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::UuidType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_table_meta::table::TableCompression;
+use futures_util::TryStreamExt;
+use parquet::basic::LogicalType;
+use parquet::basic::Repetition;
+use parquet::basic::Type as PhysicalType;
+use parquet::data_type::ByteArray;
+use parquet::data_type::FixedLenByteArray;
+use parquet::data_type::FixedLenByteArrayType;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::FileReader;
+use parquet::file::reader::SerializedFileReader;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use tempfile::NamedTempFile;
+
+use crate::parquet_rs::utils::create_parquet_test_fixture;
+
+const UUIDS: [u128; 2] = [
+    0x0191_9a3b_1c2d_7e4f_8a5b_6c7d_8e9f_a0b1,
+    0xf81d_4fae_7dec_11d0_a765_00a0_c91e_6bf6,
+];
+
+fn temp_parquet_file() -> NamedTempFile {
+    tempfile::Builder::new()
+        .prefix("parquet_uuid")
+        .suffix(".parquet")
+        .tempfile()
+        .expect("tempfile creation")
+}
+
+/// Select the column `id` of the parquet file, and check it's read as UUID.
+async fn read_uuids(file: &NamedTempFile) -> Vec<Option<u128>> {
+    let file_path = file.path().to_string_lossy();
+    let fixture = create_parquet_test_fixture().await;
+    let stream = fixture
+        .execute_query(&format!("select id from 'fs://{file_path}'"))
+        .await
+        .unwrap();
+    let blocks = stream.try_collect::<Vec<_>>().await.unwrap();
+    let block = DataBlock::concat(&blocks).unwrap();
+
+    let entry = block.get_by_offset(0);
+    assert_eq!(entry.data_type.remove_nullable(), DataType::Uuid);
+    (0..block.num_rows())
+        .map(|row| match entry.value.index(row).unwrap() {
+            ScalarRef::Uuid(uuid) => Some(uuid),
+            ScalarRef::Null => None,
+            other => unreachable!("unexpected scalar {other}"),
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_uuid_written_by_other_writers() {
+    // Written with the low level parquet writer, there is no arrow schema in the meta,
+    // only the `UUID` logical type of `FIXED_LEN_BYTE_ARRAY(16)`.
+    let schema = Type::group_type_builder("schema")
+        .with_fields(vec![Arc::new(
+            Type::primitive_type_builder("id", PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                .with_repetition(Repetition::OPTIONAL)
+                .with_length(16)
+                .with_logical_type(Some(LogicalType::Uuid))
+                .build()
+                .unwrap(),
+        )])
+        .build()
+        .unwrap();
+
+    let mut file = temp_parquet_file();
+    let props = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(&mut file, Arc::new(schema), props).unwrap();
+    let mut row_group_writer = writer.next_row_group().unwrap();
+    let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+    let values = UUIDS
+        .iter()
+        .map(|uuid| FixedLenByteArray::from(ByteArray::from(uuid.to_be_bytes().to_vec())))
+        .collect::<Vec<_>>();
+    column_writer
+        .typed::<FixedLenByteArrayType>()
+        .write_batch(&values, Some(&[1, 0, 1]), None)
+        .unwrap();
+    column_writer.close().unwrap();
+    row_group_writer.close().unwrap();
+    writer.close().unwrap();
+
+    assert_eq!(read_uuids(&file).await, vec![
+        Some(UUIDS[0]),
+        None,
+        Some(UUIDS[1])
+    ]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_uuid_round_trip() {
+    let schema = TableSchema::new(vec![TableField::new(
+        "id",
+        TableDataType::Nullable(Box::new(TableDataType::Uuid)),
+    )]);
+    let block = DataBlock::new_from_columns(vec![UuidType::from_opt_data(vec![
+        Some(UUIDS[0]),
+        None,
+        Some(UUIDS[1]),
+    ])]);
+    let mut buf = Vec::new();
+    blocks_to_parquet(&schema, vec![block], &mut buf, TableCompression::Zstd).unwrap();
+
+    let mut file = temp_parquet_file();
+    file.write_all(&buf).unwrap();
+
+    // Other readers can tell the column is UUID by the logical type.
+    let reader = SerializedFileReader::new(file.reopen().unwrap()).unwrap();
+    let column = reader.metadata().file_metadata().schema_descr().column(0);
+    assert_eq!(column.physical_type(), PhysicalType::FIXED_LEN_BYTE_ARRAY);
+    assert_eq!(column.type_length(), 16);
+    assert_eq!(column.logical_type(), Some(LogicalType::Uuid));
+
+    assert_eq!(read_uuids(&file).await, vec![
+        Some(UUIDS[0]),
+        None,
+        Some(UUIDS[1])
+    ]);
+}
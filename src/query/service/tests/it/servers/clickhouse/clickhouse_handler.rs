@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_exception::Result;
+use databend_query::servers::ClickHouseHandler;
+use databend_query::test_kits::TestFixture;
+
+const CLIENT_HELLO: u64 = 0;
+const CLIENT_PING: u64 = 4;
+const SERVER_HELLO: u64 = 0;
+const SERVER_EXCEPTION: u64 = 2;
+const SERVER_PONG: u64 = 4;
+const CLIENT_REVISION: u64 = 54429;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_clickhouse_handshake_and_ping() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = ClickHouseHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+
+    let mut stream = TcpStream::connect(runnable_server).await?;
+    stream.write_all(&client_hello("root", "")).await?;
+
+    assert_eq!(read_varuint(&mut stream).await?, SERVER_HELLO);
+    assert_eq!(read_string(&mut stream).await?, "Databend");
+    // version major, minor and revision
+    read_varuint(&mut stream).await?;
+    read_varuint(&mut stream).await?;
+    assert_eq!(read_varuint(&mut stream).await?, CLIENT_REVISION);
+    // timezone, display name and version patch
+    read_string(&mut stream).await?;
+    read_string(&mut stream).await?;
+    read_varuint(&mut stream).await?;
+
+    let mut ping = vec![];
+    write_varuint(&mut ping, CLIENT_PING);
+    stream.write_all(&ping).await?;
+    assert_eq!(read_varuint(&mut stream).await?, SERVER_PONG);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_clickhouse_handshake_with_unknown_user() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = ClickHouseHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+
+    let mut stream = TcpStream::connect(runnable_server).await?;
+    stream
+        .write_all(&client_hello("unknown_user", "password"))
+        .await?;
+
+    assert_eq!(read_varuint(&mut stream).await?, SERVER_EXCEPTION);
+
+    Ok(())
+}
+
+fn client_hello(user: &str, password: &str) -> Vec<u8> {
+    let mut buf = vec![];
+    write_varuint(&mut buf, CLIENT_HELLO);
+    write_string(&mut buf, "test-client");
+    write_varuint(&mut buf, 22);
+    write_varuint(&mut buf, 8);
+    write_varuint(&mut buf, CLIENT_REVISION);
+    write_string(&mut buf, "default");
+    write_string(&mut buf, user);
+    write_string(&mut buf, password);
+    buf
+}
+
+fn write_varuint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_varuint(buf, value.len() as u64);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+async fn read_varuint(stream: &mut TcpStream) -> Result<u64> {
+    let mut value = 0;
+    for i in 0..10 {
+        let byte = stream.read_u8().await?;
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte < 0x80 {
+            break;
+        }
+    }
+    Ok(value)
+}
+
+async fn read_string(stream: &mut TcpStream) -> Result<String> {
+    let len = read_varuint(stream).await? as usize;
+    let mut buf = vec![0; len];
+    stream.read_exact(&mut buf).await?;
+    Ok(String::from_utf8_lossy(&buf).to_string())
+}
@@ -16,12 +16,37 @@
 
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::Int64Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::ActionBeginSavepointRequest;
+use arrow_flight::sql::ActionBeginTransactionRequest;
+use arrow_flight::sql::ActionBeginTransactionResult;
+use arrow_flight::sql::ActionCancelQueryRequest;
+use arrow_flight::sql::ActionCancelQueryResult;
+use arrow_flight::sql::ActionEndTransactionRequest;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CancelResult;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::CommandStatementIngest;
+use arrow_flight::sql::EndTransaction;
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::sql::TableDefinitionOptions;
+use arrow_flight::sql::TableExistsOption;
+use arrow_flight::sql::TableNotExistOption;
+use arrow_flight::Action;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::Runtime;
 use databend_common_config::InnerConfig;
@@ -35,6 +60,7 @@ use databend_query::test_kits::TestFixture;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -75,6 +101,108 @@ async fn run_query(
     Ok(res)
 }
 
+async fn fetch_batches(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> std::result::Result<Vec<RecordBatch>, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    flight_data.try_collect().await
+}
+
+async fn do_action<T: ProstMessageExt>(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+    action_type: &str,
+    action: T,
+) -> std::result::Result<Option<Any>, tonic::Status> {
+    let mut request = tonic::Request::new(Action {
+        r#type: action_type.to_string(),
+        body: action.as_any().encode_to_vec().into(),
+    });
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    let mut stream = client.inner_mut().do_action(request).await?.into_inner();
+    let result = stream.message().await?;
+    Ok(result.map(|r| Any::decode(r.body).unwrap()))
+}
+
+async fn cancel_query(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+    flight_info: FlightInfo,
+) -> std::result::Result<CancelResult, tonic::Status> {
+    let result = do_action(client, token, "CancelQuery", ActionCancelQueryRequest {
+        info: flight_info.encode_to_vec().into(),
+    })
+    .await?
+    .unwrap()
+    .unpack::<ActionCancelQueryResult>()
+    .unwrap()
+    .unwrap();
+    Ok(result.result())
+}
+
+async fn begin_transaction(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+) -> std::result::Result<Vec<u8>, tonic::Status> {
+    let result = do_action(
+        client,
+        token,
+        "BeginTransaction",
+        ActionBeginTransactionRequest {},
+    )
+    .await?
+    .unwrap();
+    let result: ActionBeginTransactionResult = result.unpack().unwrap().unwrap();
+    Ok(result.transaction_id.to_vec())
+}
+
+async fn end_transaction(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+    transaction_id: Vec<u8>,
+    action: EndTransaction,
+) -> std::result::Result<(), tonic::Status> {
+    do_action(
+        client,
+        token,
+        "EndTransaction",
+        ActionEndTransactionRequest {
+            transaction_id: transaction_id.into(),
+            action: action.into(),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+async fn ingest(
+    client: &mut FlightSqlServiceClient<Channel>,
+    table: &str,
+    if_not_exist: TableNotExistOption,
+    if_exists: TableExistsOption,
+    batches: Vec<RecordBatch>,
+) -> std::result::Result<i64, ArrowError> {
+    let command = CommandStatementIngest {
+        table_definition_options: Some(TableDefinitionOptions {
+            if_not_exist: if_not_exist.into(),
+            if_exists: if_exists.into(),
+        }),
+        table: table.to_string(),
+        schema: None,
+        catalog: None,
+        temporary: false,
+        transaction_id: None,
+        options: Default::default(),
+    };
+    client
+        .execute_ingest(command, futures::stream::iter(batches.into_iter().map(Ok)))
+        .await
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -156,3 +284,367 @@ async fn test_query() -> Result<()> {
         Ok(())
     })
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_metadata_and_transaction() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let runtime = Runtime::with_default_worker_threads()?;
+    runtime.block_on(async {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.into_temp_path().to_str().unwrap().to_string();
+        let _ = fs::remove_file(path.clone());
+
+        let uds = UnixListener::bind(path.clone()).unwrap();
+        let stream = UnixListenerStream::new(uds);
+
+        let service = FlightSqlServiceImpl::create();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve_future = Server::builder()
+            .add_service(FlightServiceServer::new(service))
+            .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+        let request_future = async {
+            let mut client = client_with_uds(path).await;
+            let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+            let token = String::from_utf8(token.to_vec()).unwrap();
+
+            // catalogs
+            let flight_info = client.get_catalogs().await.unwrap();
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            let res = pretty_format_batches(&batches).unwrap().to_string();
+            assert!(res.contains("default"), "{res}");
+
+            // schemas filtered by pattern
+            let flight_info = client
+                .get_db_schemas(CommandGetDbSchemas {
+                    catalog: Some("default".to_string()),
+                    db_schema_filter_pattern: Some("def%".to_string()),
+                })
+                .await
+                .unwrap();
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+            assert_eq!(num_rows, 1);
+
+            // table types
+            let flight_info = client.get_table_types().await.unwrap();
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            let res = pretty_format_batches(&batches).unwrap().to_string();
+            assert!(res.contains("table") && res.contains("view"), "{res}");
+
+            // xdbc type info
+            let flight_info = client
+                .get_xdbc_type_info(CommandGetXdbcTypeInfo { data_type: None })
+                .await
+                .unwrap();
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            let res = pretty_format_batches(&batches).unwrap().to_string();
+            assert!(res.contains("BIGINT") && res.contains("VARCHAR"), "{res}");
+
+            run_query(&mut client, "create or replace table test_txn(a int)")
+                .await
+                .unwrap();
+
+            // no primary keys
+            let flight_info = client
+                .get_primary_keys(CommandGetPrimaryKeys {
+                    catalog: None,
+                    db_schema: None,
+                    table: "test_txn".to_string(),
+                })
+                .await
+                .unwrap();
+            let batches = fetch_batches(&mut client, flight_info).await.unwrap();
+            let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+            assert_eq!(num_rows, 0);
+
+            // rollback
+            let txn_id = begin_transaction(&mut client, &token).await.unwrap();
+            assert!(begin_transaction(&mut client, &token).await.is_err());
+            run_query(&mut client, "insert into test_txn values (1)")
+                .await
+                .unwrap();
+            end_transaction(&mut client, &token, txn_id, EndTransaction::Rollback)
+                .await
+                .unwrap();
+            let res = run_query(&mut client, "select count(*) from test_txn")
+                .await
+                .unwrap();
+            assert!(res.contains("| 0 "), "{res}");
+
+            // commit
+            let txn_id = begin_transaction(&mut client, &token).await.unwrap();
+            run_query(&mut client, "insert into test_txn values (1)")
+                .await
+                .unwrap();
+            let err = end_transaction(
+                &mut client,
+                &token,
+                b"unknown".to_vec(),
+                EndTransaction::Commit,
+            )
+            .await
+            .unwrap_err();
+            assert_eq!(err.code(), tonic::Code::NotFound);
+            end_transaction(&mut client, &token, txn_id, EndTransaction::Commit)
+                .await
+                .unwrap();
+            let res = run_query(&mut client, "select count(*) from test_txn")
+                .await
+                .unwrap();
+            assert!(res.contains("| 1 "), "{res}");
+
+            // savepoints are not supported
+            let err = do_action(
+                &mut client,
+                &token,
+                "BeginSavepoint",
+                ActionBeginSavepointRequest {
+                    transaction_id: Default::default(),
+                    name: "sp".to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+            assert_eq!(err.code(), tonic::Code::Unimplemented);
+
+            // cancel the query of a flight info without a query handle
+            let err = cancel_query(&mut client, &token, FlightInfo::default())
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), tonic::Code::NotFound);
+
+            // cancel a finished query
+            let mut stmt = client.prepare("select 1".to_string(), None).await.unwrap();
+            let flight_info = stmt.execute().await.unwrap();
+            fetch_batches(&mut client, flight_info.clone())
+                .await
+                .unwrap();
+            let result = cancel_query(&mut client, &token, flight_info)
+                .await
+                .unwrap();
+            assert_eq!(result, CancelResult::NotCancellable);
+
+            // cancel a running query
+            let mut stmt = client
+                .prepare(
+                    "select count(*) from numbers(100000000000)".to_string(),
+                    None,
+                )
+                .await
+                .unwrap();
+            let flight_info = stmt.execute().await.unwrap();
+            let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+            let flight_data = client.do_get(ticket).await.unwrap();
+            let result = cancel_query(&mut client, &token, flight_info)
+                .await
+                .unwrap();
+            assert_eq!(result, CancelResult::Cancelled);
+            let res: std::result::Result<Vec<RecordBatch>, _> = flight_data.try_collect().await;
+            assert!(res.is_err());
+        };
+        tokio::pin!(serve_future);
+
+        tokio::select! {
+            _ = &mut serve_future => panic!("server returned first"),
+            _ = request_future => {
+                debug!("Client finished!");
+            }
+        }
+        shutdown_tx.send(()).unwrap();
+        serve_future.await.unwrap();
+        debug!("Server shutdown!");
+
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_ingest() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let runtime = Runtime::with_default_worker_threads()?;
+    runtime.block_on(async {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.into_temp_path().to_str().unwrap().to_string();
+        let _ = fs::remove_file(path.clone());
+
+        let uds = UnixListener::bind(path.clone()).unwrap();
+        let stream = UnixListenerStream::new(uds);
+
+        let service = FlightSqlServiceImpl::create();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve_future = Server::builder()
+            .add_service(FlightServiceServer::new(service))
+            .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+        let request_future = async {
+            let mut client = client_with_uds(path.clone()).await;
+            client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("b", DataType::Utf8, true),
+            ]));
+            let batch = |a: Vec<i64>, b: Vec<Option<&str>>| {
+                RecordBatch::try_new(schema.clone(), vec![
+                    Arc::new(Int64Array::from(a)),
+                    Arc::new(StringArray::from(b)),
+                ])
+                .unwrap()
+            };
+
+            run_query(&mut client, "drop table if exists test_ingest")
+                .await
+                .unwrap();
+
+            // create
+            let rows = ingest(
+                &mut client,
+                "test_ingest",
+                TableNotExistOption::Create,
+                TableExistsOption::Fail,
+                vec![
+                    batch(vec![1, 2], vec![Some("x"), None]),
+                    batch(vec![3], vec![Some("z")]),
+                ],
+            )
+            .await
+            .unwrap();
+            assert_eq!(rows, 3);
+
+            // fail if exists
+            let res = ingest(
+                &mut client,
+                "test_ingest",
+                TableNotExistOption::Create,
+                TableExistsOption::Fail,
+                vec![batch(vec![4], vec![Some("w")])],
+            )
+            .await;
+            assert!(res.is_err());
+
+            // append
+            ingest(
+                &mut client,
+                "test_ingest",
+                TableNotExistOption::Fail,
+                TableExistsOption::Append,
+                vec![batch(vec![4], vec![Some("w")])],
+            )
+            .await
+            .unwrap();
+            let res = run_query(&mut client, "select count(*), sum(a) from test_ingest")
+                .await
+                .unwrap();
+            assert!(res.contains("| 4 ") && res.contains("| 10 "), "{res}");
+
+            // replace
+            ingest(
+                &mut client,
+                "test_ingest",
+                TableNotExistOption::Fail,
+                TableExistsOption::Replace,
+                vec![batch(vec![5], vec![None])],
+            )
+            .await
+            .unwrap();
+            let res = run_query(&mut client, "select a, b is null from test_ingest")
+                .await
+                .unwrap();
+            assert!(res.contains("| 5 ") && res.contains("true"), "{res}");
+
+            // fail if not exists
+            let res = ingest(
+                &mut client,
+                "test_ingest_not_exists",
+                TableNotExistOption::Fail,
+                TableExistsOption::Append,
+                vec![batch(vec![1], vec![None])],
+            )
+            .await;
+            assert!(res.is_err());
+
+            // the ingestion is checked with the privileges of the user
+            for sql in [
+                "drop user if exists test_ingest_user",
+                "create user test_ingest_user identified by 'p1'",
+                "grant select on default.test_ingest to test_ingest_user",
+            ] {
+                run_query(&mut client, sql).await.unwrap();
+            }
+            let mut user_client = client_with_uds(path).await;
+            user_client
+                .handshake("test_ingest_user", "p1")
+                .await
+                .unwrap();
+
+            // no CREATE privilege on the database
+            let res = ingest(
+                &mut user_client,
+                "test_ingest_denied",
+                TableNotExistOption::Create,
+                TableExistsOption::Fail,
+                vec![batch(vec![1], vec![None])],
+            )
+            .await;
+            assert!(res.unwrap_err().to_string().contains("Permission denied"));
+
+            // no INSERT privilege on the table
+            let res = ingest(
+                &mut user_client,
+                "test_ingest",
+                TableNotExistOption::Fail,
+                TableExistsOption::Append,
+                vec![batch(vec![6], vec![None])],
+            )
+            .await;
+            assert!(res.unwrap_err().to_string().contains("Permission denied"));
+
+            // replacing the table also needs the DELETE privilege
+            run_query(
+                &mut client,
+                "grant insert on default.test_ingest to test_ingest_user",
+            )
+            .await
+            .unwrap();
+            let res = ingest(
+                &mut user_client,
+                "test_ingest",
+                TableNotExistOption::Fail,
+                TableExistsOption::Replace,
+                vec![batch(vec![6], vec![None])],
+            )
+            .await;
+            assert!(res.unwrap_err().to_string().contains("Permission denied"));
+
+            ingest(
+                &mut user_client,
+                "test_ingest",
+                TableNotExistOption::Fail,
+                TableExistsOption::Append,
+                vec![batch(vec![6], vec![None])],
+            )
+            .await
+            .unwrap();
+            let res = run_query(&mut client, "select count(*), sum(a) from test_ingest")
+                .await
+                .unwrap();
+            assert!(res.contains("| 2 ") && res.contains("| 11 "), "{res}");
+        };
+        tokio::pin!(serve_future);
+
+        tokio::select! {
+            _ = &mut serve_future => panic!("server returned first"),
+            _ = request_future => {
+                debug!("Client finished!");
+            }
+        }
+        shutdown_tx.send(()).unwrap();
+        serve_future.await.unwrap();
+        debug!("Server shutdown!");
+
+        Ok(())
+    })
+}
@@ -17,6 +17,8 @@ use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -44,6 +46,7 @@ use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 use databend_storages_common_session::TxnState;
 use futures_util::future::try_join_all;
+use futures_util::StreamExt;
 use headers::Header;
 use headers::HeaderMapExt;
 use http::header;
@@ -56,6 +59,7 @@ use jwt_simple::algorithms::RSAKeyPairLike;
 use jwt_simple::claims::JWTClaims;
 use jwt_simple::claims::NoCustomClaims;
 use jwt_simple::prelude::Clock;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Request;
@@ -823,6 +827,56 @@ async fn test_query_log_killed() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_events() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint()?;
+
+    let json = serde_json::json!({"sql": "select sleep(1)", "pagination": {"wait_time_secs": 0}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let response = get_uri(
+        &ep,
+        &format!("/v1/query/{}/events?interval_ms=100", result.id),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.content_type(), Some("text/event-stream"));
+    let body = response.into_body().into_string().await.unwrap();
+    assert!(body.starts_with("event: progress\ndata: {"), "{}", body);
+    // the stream ends with the final state
+    let (_, last) = body.trim_end().rsplit_once("\n\n").unwrap();
+    assert!(last.starts_with("event: state\ndata: "), "{}", body);
+    assert!(last.contains(r#""state":"Succeeded""#), "{}", body);
+
+    // the query is canceled when the events stream is closed
+    let json = serde_json::json!({"sql": "select sleep(2)", "pagination": {"wait_time_secs": 0}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+
+    let response = get_uri(
+        &ep,
+        &format!("/v1/query/{}/events?cancel_on_close=true", result.id),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = response.into_body().into_bytes_stream();
+    let event = events.next().await.unwrap().unwrap();
+    assert!(event.starts_with(b"event: progress"));
+    drop(events);
+
+    sleep(Duration::from_millis(500)).await;
+    let response = get_uri(&ep, result.stats_uri.as_ref().unwrap()).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.into_body().into_string().await.unwrap();
+    assert!(body.contains("canceled"), "{}", body);
+
+    Ok(())
+}
+
 async fn check_response(response: Response) -> Result<(StatusCode, QueryResponse)> {
     let status = response.status();
     let body = response.into_body().into_string().await.unwrap();
@@ -1746,3 +1800,274 @@ async fn test_null_response() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_query_params() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let wait_time_secs = 5;
+
+    {
+        let json = serde_json::json!({
+            "sql": "select ? + ?, ?, ?",
+            "params": [1, -2, "a'b", null],
+            "pagination": {"wait_time_secs": wait_time_secs}
+        });
+        let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+        assert!(reply.last().1.error.is_none(), "{:?}", reply.last().1.error);
+        let data = reply.data();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0][0], Some("-1".to_string()));
+        assert_eq!(data[0][1], Some("a'b".to_string()));
+    }
+
+    {
+        let json = serde_json::json!({
+            "sql": "select ?",
+            "params": [1, 2],
+            "pagination": {"wait_time_secs": wait_time_secs}
+        });
+        let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+        let error = reply.last().1.error.unwrap();
+        assert_eq!(error.code, ErrorCode::BAD_ARGUMENTS);
+    }
+
+    // prepared statements are carried by the session
+    {
+        let json = serde_json::json!({
+            "sql": "prepare p from select ? * 2",
+            "pagination": {"wait_time_secs": wait_time_secs}
+        });
+        let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+        assert!(reply.last().1.error.is_none(), "{:?}", reply.last().1.error);
+        let session = reply.last().1.session.unwrap();
+        assert!(session.internal.is_some());
+
+        let json = serde_json::json!({
+            "sql": "execute p using 21",
+            "session": session,
+            "pagination": {"wait_time_secs": wait_time_secs}
+        });
+        let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+        assert!(reply.last().1.error.is_none(), "{:?}", reply.last().1.error);
+        assert_eq!(reply.data()[0][0], Some("42".to_string()));
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_persist_result() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let wait_time_secs = 5;
+
+    let json = serde_json::json!({
+        "sql": "select * from numbers(3)",
+        "persist_result": true,
+        "pagination": {"wait_time_secs": wait_time_secs}
+    });
+    let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+    assert!(reply.error().is_none(), "{:?}", reply.error());
+    assert_eq!(reply.state(), ExecuteStateKind::Succeeded);
+    // the result is persisted instead of being returned
+    assert!(reply.data().is_empty());
+    let query_id = reply.last().1.id;
+
+    // retrieve the result by the query id
+    let mut req = TestHttpQueryRequest::new(serde_json::json!({}));
+    let (status, resp, body) = req
+        .do_request(Method::GET, &format!("/v1/query/{query_id}/result"))
+        .await?;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let resp = resp.unwrap();
+    assert!(resp.error.is_none(), "{:?}", resp.error);
+    let mut data = resp.data;
+    req.next_uri = resp.next_uri;
+    while req.next_uri.is_some() {
+        let (status, resp, body) = req.fetch_next().await?;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        data.extend(resp.unwrap().data);
+    }
+    let mut rows = unwrap_data(&data, "NULL");
+    rows.sort();
+    assert_eq!(rows, vec![vec!["0"], vec!["1"], vec!["2"]]);
+
+    // the result is also available to RESULT_SCAN in other sessions
+    let json = serde_json::json!({
+        "sql": format!("select count(*) from result_scan('{query_id}')"),
+        "pagination": {"wait_time_secs": wait_time_secs}
+    });
+    let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+    assert!(reply.error().is_none(), "{:?}", reply.error());
+    assert_eq!(reply.data(), vec![vec![Some("3".to_string())]]);
+
+    let (status, _, _) = req
+        .do_request(Method::GET, "/v1/query/not.a.query.id/result")
+        .await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // the result can not be read by other users
+    fixture
+        .execute_command("create user u1 identified by 'p1'")
+        .await?;
+    let mut req = TestHttpQueryRequest::new(serde_json::json!({})).with_basic_auth("u1", "p1");
+    let (status, resp, body) = req
+        .do_request(Method::GET, &format!("/v1/query/{query_id}/result"))
+        .await?;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let error = resp.unwrap().error.unwrap();
+    assert!(
+        error.message.contains("belongs to another user"),
+        "{:?}",
+        error
+    );
+
+    let json = serde_json::json!({
+        "sql": format!("select count(*) from result_scan('{query_id}')"),
+        "pagination": {"wait_time_secs": wait_time_secs}
+    });
+    let reply = TestHttpQueryRequest::new(json)
+        .with_basic_auth("u1", "p1")
+        .fetch_total()
+        .await?;
+    let error = reply.error().unwrap();
+    assert!(
+        error.message.contains("belongs to another user"),
+        "{:?}",
+        error
+    );
+
+    Ok(())
+}
+
+async fn post_json_raw(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+    accept: Option<&str>,
+) -> Response {
+    let basic = headers::Authorization::basic("root", "");
+    let mut req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(basic);
+    if let Some(accept) = accept {
+        req = req.header(header::ACCEPT, accept);
+    }
+    ep.call(req.body(serde_json::to_vec(json).unwrap()))
+        .await
+        .unwrap_or_else(|err| err.into_response())
+}
+
+/// Decode an Arrow IPC stream body, return the JSON response in the metadata and the batches.
+async fn read_arrow_response(response: Response) -> Result<(QueryResponse, Vec<RecordBatch>)> {
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/vnd.apache.arrow.stream"
+    );
+    let body = response.into_body().into_vec().await.unwrap();
+    let reader = StreamReader::try_new(std::io::Cursor::new(body), None)?;
+    let meta = reader.schema().metadata().get("databend.response").cloned();
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    let result = serde_json::from_str::<QueryResponse>(&meta.unwrap_or_default())?;
+    Ok((result, batches))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_result_format() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint()?;
+
+    let sql = "select number, number::string as s, if(number = 1, null, number) as n from numbers(5) order by number";
+    let json = serde_json::json!({"sql": sql, "result_format": "arrow", "pagination": {"wait_time_secs": 5, "max_rows_per_page": 3}});
+    let response = post_json_raw(&ep, &json, None).await;
+    assert_eq!(
+        response
+            .headers()
+            .get("x-databend-query-page-rows")
+            .unwrap(),
+        "3"
+    );
+    let (result, batches) = read_arrow_response(response).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert!(result.data.is_empty());
+    assert_eq!(result.schema.len(), 3);
+    let schema = batches[0].schema();
+    assert_eq!(schema.field(0).data_type(), &arrow_schema::DataType::UInt64);
+    assert_eq!(
+        schema.field(1).data_type(),
+        &arrow_schema::DataType::LargeUtf8
+    );
+    assert!(schema.field(2).is_nullable());
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+    assert_eq!(
+        batches
+            .iter()
+            .map(|b| b.column(2).null_count())
+            .sum::<usize>(),
+        1
+    );
+
+    let next_uri = result.next_uri.unwrap();
+    let (result, batches) = read_arrow_response(get_uri(&ep, &next_uri).await).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+
+    // state and final responses are still in JSON
+    let final_uri = result.final_uri.unwrap();
+    let (status, result) = get_uri_checked(&ep, &final_uri).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_parquet_result_format() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint()?;
+
+    let json = serde_json::json!({"sql": "select number, 'a' as s from numbers(4)", "pagination": {"wait_time_secs": 5}});
+    let response = post_json_raw(&ep, &json, Some("application/vnd.apache.parquet")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/vnd.apache.parquet"
+    );
+    let body = response.into_body().into_bytes().await.unwrap();
+    let builder = ParquetRecordBatchReaderBuilder::try_new(body)?;
+    let meta = builder
+        .schema()
+        .metadata()
+        .get("databend.response")
+        .cloned();
+    let result = serde_json::from_str::<QueryResponse>(&meta.unwrap_or_default())?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    let batches = builder
+        .build()?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 4);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_result_stream() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint()?;
+
+    let json = serde_json::json!({"sql": "select * from numbers(10)", "pagination": {"wait_time_secs": 5, "max_rows_per_page": 2}});
+    let response = post_json_raw(&ep, &json, Some("application/vnd.apache.arrow.stream")).await;
+    let (result, batches) = read_arrow_response(response).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    let first_rows = batches.iter().map(|b| b.num_rows()).sum::<usize>();
+    assert_eq!(first_rows, 2);
+
+    let stream_uri = format!("/v1/query/{}/stream", result.id);
+    let (_, batches) = read_arrow_response(get_uri(&ep, &stream_uri).await).await?;
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 8);
+
+    // JSON queries can not be streamed
+    let (_, result) = post_sql_to_endpoint(&ep, "select 1", 5).await?;
+    let response = get_uri(&ep, &format!("/v1/query/{}/stream", result.id)).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    Ok(())
+}
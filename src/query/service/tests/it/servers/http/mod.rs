@@ -15,3 +15,4 @@
 mod clickhouse_handler;
 mod http_query_handlers;
 mod json_block;
+mod streaming_load;
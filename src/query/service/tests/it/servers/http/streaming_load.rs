@@ -0,0 +1,218 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_sorted_eq;
+use databend_common_expression::DataBlock;
+use databend_query::servers::http::middleware::EndpointKind;
+use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::streaming_load;
+use databend_query::servers::http::v1::StreamingLoadResponse;
+use databend_query::servers::HttpHandlerKind;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+use http::Method;
+use http::StatusCode;
+use poem::web::headers::Authorization;
+use poem::Body;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::IntoResponse;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+fn create_endpoint() -> impl Endpoint {
+    Route::new().at(
+        "/v1/streaming_load",
+        poem::put(streaming_load).with(HTTPSessionMiddleware::create(
+            HttpHandlerKind::Query,
+            EndpointKind::StartQuery,
+        )),
+    )
+}
+
+async fn load(ep: &impl Endpoint, query: &str, body: Body) -> (StatusCode, String) {
+    load_as(ep, "root", "", query, body).await
+}
+
+async fn load_as(
+    ep: &impl Endpoint,
+    user: &str,
+    password: &str,
+    query: &str,
+    body: Body,
+) -> (StatusCode, String) {
+    let req = Request::builder()
+        .uri(format!("/v1/streaming_load?{}", query).parse().unwrap())
+        .method(Method::PUT)
+        .typed_header(Authorization::basic(user, password))
+        .body(body);
+    let response = match ep.call(req).await {
+        Ok(response) => response.into_response(),
+        Err(err) => err.into_response(),
+    };
+    let status = response.status();
+    let body = response.into_body().into_string().await.unwrap();
+    (status, body)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_streaming_load() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t1(a int, b string default 'x')")
+        .await?;
+    let ep = create_endpoint();
+
+    // the body arrives in chunks which split the rows
+    let chunks = vec!["a,b\n1,o", "ne\n2,\n3,th", "ree"]
+        .into_iter()
+        .map(|chunk| Ok::<_, std::io::Error>(chunk.as_bytes().to_vec()));
+    let (status, body) = load(
+        &ep,
+        "table=t1&type=csv&skip_header=1&empty_field_as=field_default",
+        Body::from_bytes_stream(futures_util::stream::iter(chunks)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let resp: StreamingLoadResponse = serde_json::from_str(&body)?;
+    assert_eq!(resp.rows_loaded, 3);
+    assert_eq!(resp.error_count, 0);
+
+    let blocks = fixture
+        .execute_query("select a, b from default.t1")
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 'one'    |",
+        "| 2        | 'x'      |",
+        "| 3        | 'three'  |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the bad rows are skipped with on_error = continue
+    let ndjson = "{\"a\": 4, \"b\": \"four\"}\n{\"a\": \"bad\"}\n{\"a\": 5}\n";
+    let (status, body) = load(
+        &ep,
+        "database=default&table=t1&type=ndjson&on_error=continue",
+        Body::from_string(ndjson.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let resp: StreamingLoadResponse = serde_json::from_str(&body)?;
+    assert_eq!(resp.rows_loaded, 2);
+    assert_eq!(resp.error_count, 1);
+    assert!(resp.first_error.is_some());
+
+    // nothing is loaded when the load is aborted
+    let (status, body) = load(
+        &ep,
+        "table=t1&type=ndjson",
+        Body::from_string(ndjson.to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+
+    let blocks = fixture
+        .execute_query("select count(*) from default.t1")
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 5        |",
+        "+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let (status, body) = load(&ep, "type=csv", Body::from_string("1,a".to_string())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert!(body.contains("missing query parameter table"), "{}", body);
+
+    let (status, body) = load(
+        &ep,
+        "table=t2&type=csv",
+        Body::from_string("1,a".to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_streaming_load_privilege() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table default.t1(a int, b string)")
+        .await?;
+    fixture
+        .execute_command("create user u1 identified by 'p1'")
+        .await?;
+    fixture
+        .execute_command("grant select on default.t1 to u1")
+        .await?;
+    let ep = create_endpoint();
+
+    // the user can not load into a table without the INSERT privilege
+    let (status, body) = load_as(
+        &ep,
+        "u1",
+        "p1",
+        "table=t1&type=csv",
+        Body::from_string("1,a\n".to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+
+    let blocks = fixture
+        .execute_query("select count(*) from default.t1")
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 0        |",
+        "+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    fixture
+        .execute_command("grant insert on default.t1 to u1")
+        .await?;
+    let (status, body) = load_as(
+        &ep,
+        "u1",
+        "p1",
+        "table=t1&type=csv",
+        Body::from_string("1,a\n".to_string()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let resp: StreamingLoadResponse = serde_json::from_str(&body)?;
+    assert_eq!(resp.rows_loaded, 1);
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod admin;
+mod clickhouse;
 mod flight;
 mod flight_sql;
 mod http;
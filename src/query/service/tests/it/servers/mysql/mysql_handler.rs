@@ -53,6 +53,50 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement_with_binary_protocol() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let sql = "SELECT number + ?, to_date('2024-10-19'), to_timestamp('2024-10-19 01:02:03'), \
+        to_uint64(number), number::Float64 / 2, 'abc' FROM numbers(2) WHERE number >= ?";
+    let statement = connection
+        .prep(sql)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    // The result columns are described when the statement is prepared.
+    assert_eq!(statement.num_params(), 2);
+    let names = statement
+        .columns()
+        .iter()
+        .map(|column| column.name_str().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names.len(), 6);
+
+    let rows: Vec<Row> = connection
+        .exec(&statement, (10, 1))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows.len(), 1);
+
+    let row = &rows[0];
+    assert_eq!(row.get::<i64, _>(0), Some(11));
+    assert_eq!(row.get::<String, _>(1), Some("2024-10-19".to_string()));
+    let timestamp = row.get::<String, _>(2).unwrap();
+    assert!(timestamp.starts_with("2024-10-19 01:02:03"), "{timestamp}");
+    assert_eq!(row.get::<u64, _>(3), Some(1));
+    assert_eq!(row.get::<f64, _>(4), Some(0.5));
+    assert_eq!(row.get::<String, _>(5), Some("abc".to_string()));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -18,15 +18,23 @@ use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use databend_common_config::QueryQueueConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::QueueLease;
+use databend_common_management::QueueLeaseMgr;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_store::MetaStore;
 use databend_common_sql::Planner;
 use databend_query::interpreters::InterpreterFactory;
 use databend_query::sessions::QueryEntry;
 use databend_query::sessions::QueueData;
 use databend_query::sessions::QueueManager;
+use databend_query::sessions::QueueOptions;
+use databend_query::sessions::QueueResources;
 use databend_query::test_kits::TestFixture;
 use log::error;
+use tokio::sync::Semaphore;
 
 #[derive(Debug)]
 struct TestData<const PASSED: bool = false>(String);
@@ -51,6 +59,63 @@ impl<const PASSED: bool> QueueData for TestData<PASSED> {
     }
 }
 
+#[derive(Debug)]
+struct TestResourceData(String, u64);
+
+impl QueueData for TestResourceData {
+    type Key = String;
+
+    fn get_key(&self) -> Self::Key {
+        self.0.clone()
+    }
+
+    fn remove_error_message(key: Option<Self::Key>) -> ErrorCode {
+        ErrorCode::Internal(format!("{:?}", key))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(1000)
+    }
+
+    fn need_acquire_to_queue(&self) -> bool {
+        true
+    }
+
+    fn resources(&self) -> QueueResources {
+        QueueResources {
+            memory_usage: self.1,
+            threads: 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TestSemaphoreData(String, Arc<Semaphore>);
+
+impl QueueData for TestSemaphoreData {
+    type Key = String;
+
+    fn get_key(&self) -> Self::Key {
+        self.0.clone()
+    }
+
+    fn remove_error_message(key: Option<Self::Key>) -> ErrorCode {
+        ErrorCode::Internal(format!("{:?}", key))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(1000)
+    }
+
+    fn need_acquire_to_queue(&self) -> bool {
+        true
+    }
+
+    fn semaphores(&self) -> Vec<Arc<Semaphore>> {
+        vec![self.1.clone()]
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_passed_acquire() -> Result<()> {
     let test_count = (SystemTime::now()
@@ -337,7 +402,7 @@ async fn test_heavy_actions() -> Result<()> {
         let mut planner = Planner::new(ctx.clone());
         let (plan, extras) = planner.plan_sql(query.sql).await?;
 
-        let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
+        let query_entry = QueryEntry::create(&ctx, &plan, &extras).await?;
         if query.add_to_queue != query_entry.need_acquire_to_queue() {
             error!(
                 "query: {:?}, query-entry: {:?}",
@@ -350,3 +415,126 @@ async fn test_heavy_actions() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_resources_acquire() -> Result<()> {
+    let test_count = 4;
+    let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
+    let queue = QueueManager::<TestResourceData>::create_with_options(QueueOptions {
+        permits: 10,
+        max_memory_usage: 100,
+        ..Default::default()
+    })?;
+    let mut join_handles = Vec::with_capacity(test_count);
+
+    let instant = Instant::now();
+    for index in 0..test_count {
+        join_handles.push({
+            let queue = queue.clone();
+            let barrier = barrier.clone();
+            databend_common_base::runtime::spawn(async move {
+                barrier.wait().await;
+                // Only one item fits into the memory of the queue at a time.
+                let _guard = queue
+                    .acquire(TestResourceData(format!("TestData{}", index), 60))
+                    .await?;
+
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                Result::<()>::Ok(())
+            })
+        })
+    }
+
+    for join_handle in join_handles {
+        let _ = join_handle.await;
+    }
+
+    assert!(instant.elapsed() >= Duration::from_secs(test_count as u64));
+    assert_eq!(queue.length(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_named_queues() -> Result<()> {
+    let queue_config = |name: &str| QueryQueueConfig {
+        name: name.to_string(),
+        max_running_queries: 1,
+        priority: 1,
+        timeout_secs: 10,
+    };
+
+    let queue = QueueManager::<TestData>::create_with_options(QueueOptions {
+        queues: vec![queue_config("etl"), queue_config("adhoc")],
+        ..Default::default()
+    })?;
+
+    assert!(queue.named_queue("")?.is_none());
+    let etl = queue.named_queue("etl")?.unwrap();
+    assert_eq!(etl.priority, 1);
+    assert_eq!(etl.timeout, Some(Duration::from_secs(10)));
+    assert!(queue.named_queue("unknown").is_err());
+
+    let duplicated = QueueManager::<TestData>::create_with_options(QueueOptions {
+        queues: vec![queue_config("etl"), queue_config("etl")],
+        ..Default::default()
+    });
+    assert!(duplicated.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_cluster_acquire() -> Result<()> {
+    let metastore = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    let lease_mgr = Arc::new(QueueLeaseMgr::create(
+        metastore,
+        "test-tenant",
+        "test-cluster",
+        Duration::from_secs(60),
+    )?);
+    let queue = QueueManager::<TestSemaphoreData>::create_with_options(QueueOptions {
+        node_id: "test-node".to_string(),
+        cluster_permits: 1,
+        cluster_lease_mgr: Some(lease_mgr.clone()),
+        ..Default::default()
+    })?;
+
+    // The only permit of the cluster is held by another node.
+    let other = QueueLease {
+        id: "other".to_string(),
+        node_id: "other-node".to_string(),
+        permits: 1,
+    };
+    assert!(lease_mgr.try_acquire(&other, 1).await?);
+
+    let semaphore = Arc::new(Semaphore::new(1));
+    let handle = databend_common_base::runtime::spawn({
+        let queue = queue.clone();
+        let semaphore = semaphore.clone();
+        async move {
+            let _guard = queue
+                .acquire(TestSemaphoreData("TestData".to_string(), semaphore))
+                .await?;
+            Result::<()>::Ok(())
+        }
+    });
+
+    // The permit of the node is not held while waiting for the cluster.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(queue.length(), 1);
+    let permit = tokio::time::timeout(Duration::from_secs(5), semaphore.acquire())
+        .await
+        .unwrap()
+        .unwrap();
+
+    lease_mgr.release(&other.id).await?;
+    drop(permit);
+    tokio::time::timeout(Duration::from_secs(10), handle)
+        .await
+        .unwrap()
+        .unwrap()?;
+    assert_eq!(queue.length(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_query::sessions::WorkloadGroupResource;
+use databend_query::test_kits::TestFixture;
+
+fn workload_group(max_concurrency: u64) -> WorkloadGroup {
+    WorkloadGroup {
+        name: "test_group".to_string(),
+        max_concurrency,
+        ..Default::default()
+    }
+}
+
+/// Wait for the pending resize of the group, until the available permits and the running
+/// queries are as expected.
+async fn wait_resized(group: &WorkloadGroupResource, available_permits: usize, running: u64) {
+    let resized = || {
+        group.semaphore().available_permits() == available_permits
+            && group.status().running_queries == running
+    };
+    for _ in 0..500 {
+        if resized() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(group.semaphore().available_permits(), available_permits);
+    assert_eq!(group.status().running_queries, running);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_workload_group_unlimited_to_limited() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let group = WorkloadGroupResource::create(workload_group(0));
+    let running = group.semaphore().try_acquire_many_owned(3).unwrap();
+    assert_eq!(group.status().running_queries, 3);
+
+    group.update(workload_group(2));
+    let admitted = group.semaphore().try_acquire_many_owned(2).unwrap();
+    assert!(group.semaphore().try_acquire_owned().is_err());
+    // The queries admitted before the group is limited are still counted.
+    assert_eq!(group.status().running_queries, 5);
+
+    drop(running);
+    assert_eq!(group.status().running_queries, 2);
+    assert!(group.semaphore().try_acquire_owned().is_err());
+
+    drop(admitted);
+    assert_eq!(group.status().running_queries, 0);
+    assert_eq!(group.semaphore().available_permits(), 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_workload_group_limited_to_unlimited() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let group = WorkloadGroupResource::create(workload_group(2));
+    let semaphore = group.semaphore();
+    let _running = semaphore.clone().try_acquire_many_owned(2).unwrap();
+    assert!(semaphore.clone().try_acquire_owned().is_err());
+
+    // The queries waiting for the semaphore are admitted at once.
+    group.update(workload_group(0));
+    let _admitted = semaphore.try_acquire_many_owned(100).unwrap();
+    assert_eq!(group.status().running_queries, 102);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_workload_group_shrink_then_grow() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    // Grow before the pending shrink is done, the shrink is cancelled.
+    let group = WorkloadGroupResource::create(workload_group(3));
+    let running = group.semaphore().try_acquire_many_owned(3).unwrap();
+    group.update(workload_group(1));
+    assert_eq!(group.status().running_queries, 3);
+    group.update(workload_group(3));
+    drop(running);
+    wait_resized(&group, 3, 0).await;
+    let running = group.semaphore().try_acquire_many_owned(3).unwrap();
+    assert!(group.semaphore().try_acquire_owned().is_err());
+    drop(running);
+
+    // Grow after the shrink is done, the excess permits are given back.
+    let group = WorkloadGroupResource::create(workload_group(3));
+    let mut running = vec![
+        group.semaphore().try_acquire_owned().unwrap(),
+        group.semaphore().try_acquire_owned().unwrap(),
+    ];
+    group.update(workload_group(1));
+    running.pop();
+    wait_resized(&group, 0, 1).await;
+    assert!(group.semaphore().try_acquire_owned().is_err());
+
+    group.update(workload_group(2));
+    wait_resized(&group, 1, 1).await;
+    running.pop();
+    wait_resized(&group, 2, 0).await;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::Planner;
+use databend_query::test_kits::TestFixture;
+
+fn find_join(s_expr: &SExpr) -> Option<&SExpr> {
+    if let RelOperator::Join(_) = s_expr.plan() {
+        return Some(s_expr);
+    }
+    s_expr.children().find_map(find_join)
+}
+
+/// Whether the join is evaluated as an equi-join on the grams of both sides.
+fn is_ngram_join(s_expr: &SExpr) -> bool {
+    let RelOperator::Join(join) = s_expr.plan() else {
+        unreachable!()
+    };
+    !join.equi_conditions.is_empty()
+        && s_expr
+            .children()
+            .all(|child| matches!(child.plan(), RelOperator::ProjectSet(_)))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_ngram_join() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t1(a string)"))
+        .await?;
+    fixture
+        .execute_command(&format!("create table {db}.t2(b string)"))
+        .await?;
+
+    for (sql, expected) in [
+        (
+            "select * from {db}.t1 join {db}.t2 on ngram_distance(t1.a, t2.b) < 0.5",
+            true,
+        ),
+        (
+            "select * from {db}.t1, {db}.t2 where ngram_distance(t2.b, t1.a) <= 0.5",
+            true,
+        ),
+        (
+            "select * from {db}.t1, {db}.t2 where 1 > ngram_distance(t1.a, t2.b)",
+            true,
+        ),
+        // Strings sharing no gram have a distance of 1.
+        (
+            "select * from {db}.t1, {db}.t2 where ngram_distance(t1.a, t2.b) <= 1",
+            false,
+        ),
+        (
+            "select * from {db}.t1 left join {db}.t2 on ngram_distance(t1.a, t2.b) < 0.5",
+            false,
+        ),
+        (
+            "select * from {db}.t1 join {db}.t2 on ngram_distance(t1.a, 'abc') < 0.5",
+            false,
+        ),
+    ] {
+        let sql = sql.replace("{db}", &db);
+        let ctx = fixture.new_query_ctx().await?;
+        let (plan, _) = Planner::new(ctx).plan_sql(&sql).await?;
+        let Plan::Query { s_expr, .. } = plan else {
+            unreachable!("{sql} is not a query");
+        };
+        let join = find_join(&s_expr).unwrap();
+        assert_eq!(is_ngram_join(join), expected, "{sql}");
+    }
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_sql::SubstraitProducer;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+use prost::Message;
+
+async fn execute(ctx: Arc<QueryContext>, plan: &Plan) -> Result<String> {
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan).await?;
+    let blocks = interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    pretty_format_blocks(&blocks)
+}
+
+/// Produce the Substrait plan of a SQL query.
+async fn produce(fixture: &TestFixture, sql: &str) -> Result<substrait::proto::Plan> {
+    let ctx = fixture.new_query_ctx().await?;
+    let (plan, _) = Planner::new(ctx).plan_sql(sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = &plan
+    else {
+        unreachable!("{sql} is not a query");
+    };
+    SubstraitProducer::produce(&metadata.read(), s_expr, bind_context)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_substrait_round_trip() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t(a int, b string null)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "insert into {db}.t values (1, 'x'), (2, 'y'), (3, 'z'), (3, null)"
+        ))
+        .await?;
+
+    for sql in [
+        "select a, b from {db}.t where a > 1 order by a, b limit 2",
+        "select b, sum(a), count(*), count(distinct a) from {db}.t group by b order by b",
+        "select t1.a, t2.b from {db}.t t1 left join {db}.t t2 on t1.a = t2.a + 1 order by t1.a, t2.b",
+        "select a + 1, b is null from {db}.t where b = 'x' or b is null order by a",
+    ] {
+        let sql = sql.replace("{db}", &db);
+        let expected = {
+            let ctx = fixture.new_query_ctx().await?;
+            let (plan, _) = Planner::new(ctx.clone()).plan_sql(&sql).await?;
+            execute(ctx, &plan).await?
+        };
+
+        let bytes = produce(&fixture, &sql).await?.encode_to_vec();
+        let ctx = fixture.new_query_ctx().await?;
+        let (plan, extras) = Planner::new(ctx.clone()).plan_substrait(&bytes).await?;
+        assert!(matches!(plan, Plan::Query { .. }), "{sql}");
+        assert!(extras.statement.is_none(), "{sql}");
+        assert_eq!(execute(ctx, &plan).await?, expected, "{sql}");
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_substrait_unknown_function() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t(a int)"))
+        .await?;
+
+    let mut plan = produce(&fixture, &format!("select a from {db}.t where a > 1")).await?;
+    plan.extensions.clear();
+    let ctx = fixture.new_query_ctx().await?;
+    let err = Planner::new(ctx)
+        .plan_substrait(&plan.encode_to_vec())
+        .await
+        .unwrap_err();
+    assert!(err.message().contains("unknown substrait function anchor"));
+    Ok(())
+}
@@ -21,6 +21,7 @@ use dashmap::DashMap;
 use databend_common_base::base::tokio;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::MemStat;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::cluster_info::Cluster;
 use databend_common_catalog::database::Database;
@@ -36,9 +37,11 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::ContextError;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::PreparedStatement;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_context::WorkloadGroupStatus;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
@@ -735,6 +738,14 @@ impl TableContext for CtxDelegation {
         HashMap::new()
     }
 
+    fn set_prepared_statement(&self, _name: String, _stmt: PreparedStatement) {}
+    fn get_prepared_statement(&self, _name: &str) -> Option<PreparedStatement> {
+        None
+    }
+    fn remove_prepared_statement(&self, _name: &str) -> Option<PreparedStatement> {
+        None
+    }
+
     fn set_materialized_cte(
         &self,
         _idx: (usize, usize),
@@ -845,6 +856,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_workload_groups_status(&self) -> Vec<WorkloadGroupStatus> {
+        todo!()
+    }
+
+    fn get_workload_group_mem_stat(&self) -> Option<Arc<MemStat>> {
+        todo!()
+    }
+
     fn get_read_block_thresholds(&self) -> BlockThresholds {
         todo!()
     }
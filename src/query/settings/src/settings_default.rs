@@ -430,6 +430,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("persist_query_result", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Persists the complete result of the query, which can be retrieved later by the query id from any node.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("persist_query_result_ttl_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(86400), // seconds
+                    desc: "Sets the time-to-live (TTL) in seconds for persisted query results.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables hive parquet predict pushdown  by setting this variable to 1, default value: 1",
@@ -854,6 +866,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("query_queue", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "The named queue the heavy queries wait in, configured by `[[query.queues]]`. The default value is ''(the default queue).",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("geometry_output_format", DefaultSettingValue {
                     value: UserSettingValue::String("GeoJSON".to_owned()),
                     desc: "Display format for GEOMETRY values.",
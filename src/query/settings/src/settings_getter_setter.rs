@@ -210,6 +210,11 @@ impl Settings {
         self.try_get_u64("max_execute_time_in_seconds")
     }
 
+    // Set max_execute_time_in_seconds.
+    pub fn set_max_execute_time_in_seconds(&self, val: u64) -> Result<()> {
+        self.try_set_u64("max_execute_time_in_seconds", val)
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         self.try_get_u64("flight_client_timeout")
@@ -387,6 +392,18 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_persist_query_result(&self) -> Result<bool> {
+        Ok(self.try_get_u64("persist_query_result")? != 0)
+    }
+
+    pub fn set_persist_query_result(&self, val: bool) -> Result<()> {
+        self.try_set_u64("persist_query_result", u64::from(val))
+    }
+
+    pub fn get_persist_query_result_ttl_secs(&self) -> Result<u64> {
+        self.try_get_u64("persist_query_result_ttl_secs")
+    }
+
     pub fn get_aggregate_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("aggregate_spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -708,6 +725,10 @@ impl Settings {
         self.try_get_u64("statement_queued_timeout_in_seconds")
     }
 
+    pub fn get_query_queue(&self) -> Result<String> {
+        self.try_get_string("query_queue")
+    }
+
     pub fn get_geometry_output_format(&self) -> Result<GeometryDataType> {
         let v = self.try_get_string("geometry_output_format")?;
         v.parse()
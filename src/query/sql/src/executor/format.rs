@@ -1407,6 +1407,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Spatial => "SpatialJoin".to_string(),
         },
         children,
     ))
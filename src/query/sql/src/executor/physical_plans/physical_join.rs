@@ -80,7 +80,10 @@ fn check_condition(
 ) {
     if let ScalarExpr::FunctionCall(func) = expr {
         if func.arguments.len() != 2
-            || !matches!(func.func_name.as_str(), "gt" | "lt" | "gte" | "lte")
+            || !matches!(
+                func.func_name.as_str(),
+                "gt" | "lt" | "gte" | "lte" | "st_intersects" | "st_contains" | "st_within"
+            )
         {
             other_conditions.push(expr.clone());
             return;
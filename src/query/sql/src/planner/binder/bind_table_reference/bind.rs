@@ -77,6 +77,12 @@ impl Binder {
                 options,
                 alias,
             } => self.bind_location(bind_context, location, options, alias),
+            TableReference::JsonTable {
+                span,
+                lateral: _,
+                json_table,
+                alias,
+            } => self.bind_json_table(bind_context, span, json_table, alias),
             TableReference::Join { join, .. } => self.bind_join(bind_context, join),
         }
     }
@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall as ASTFunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::JsonTable;
+use databend_common_ast::ast::JsonTableBehavior;
+use databend_common_ast::ast::JsonTableColumn;
+use databend_common_ast::ast::JsonTableOnError;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_functions::srfs::JsonTableBehaviorSpec;
+use databend_common_functions::srfs::JsonTableColumnSpec;
+use databend_common_functions::srfs::JsonTableSpec;
+use jsonb::jsonpath::parse_json_path;
+
+use crate::binder::Binder;
+use crate::optimizer::SExpr;
+use crate::planner::resolve_type_name;
+use crate::planner::semantic::normalize_identifier;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind a `JSON_TABLE` in the FROM clause, it is bound as the set-returning
+    /// function `json_table` which takes the columns definition as parameter.
+    pub(crate) fn bind_json_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        json_table: &JsonTable,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let (srf, fields) = self.json_table_to_srf(span, json_table)?;
+        self.bind_srf_table_function(bind_context, span, srf, Some(fields), alias)
+    }
+
+    /// Convert the `JSON_TABLE` to the call of `json_table` and its output column names.
+    pub(crate) fn json_table_to_srf(
+        &self,
+        span: &Span,
+        json_table: &JsonTable,
+    ) -> Result<(Expr, Vec<String>)> {
+        check_json_path(span, &json_table.path)?;
+        let error_on_error = json_table.on_error == Some(JsonTableOnError::Error);
+        let spec = JsonTableSpec {
+            path: json_table.path.clone(),
+            columns: self.json_table_columns(span, &json_table.columns, error_on_error)?,
+            error_on_error,
+        };
+
+        let fields = spec.column_names();
+        let mut names = HashSet::with_capacity(fields.len());
+        for name in fields.iter() {
+            if !names.insert(name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate column name '{name}' in JSON_TABLE"
+                ))
+                .set_span(*span));
+            }
+        }
+
+        let srf = Expr::FunctionCall {
+            span: *span,
+            func: ASTFunctionCall {
+                distinct: false,
+                name: Identifier::from_name(*span, "json_table"),
+                args: vec![json_table.input.clone()],
+                params: vec![Expr::Literal {
+                    span: *span,
+                    value: Literal::String(spec.to_param()),
+                }],
+                window: None,
+                lambda: None,
+            },
+        };
+        Ok((srf, fields))
+    }
+
+    fn json_table_columns(
+        &self,
+        span: &Span,
+        columns: &[JsonTableColumn],
+        error_on_error: bool,
+    ) -> Result<Vec<JsonTableColumnSpec>> {
+        columns
+            .iter()
+            .map(|column| {
+                Ok(match column {
+                    JsonTableColumn::Ordinality { name } => JsonTableColumnSpec::Ordinality {
+                        name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                    },
+                    JsonTableColumn::Path {
+                        name,
+                        data_type,
+                        path,
+                        on_empty,
+                        on_error,
+                    } => {
+                        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                        let path = column_path(span, &name, path)?;
+                        let default_on_error = if error_on_error {
+                            JsonTableBehaviorSpec::Error
+                        } else {
+                            JsonTableBehaviorSpec::Null
+                        };
+                        JsonTableColumnSpec::Path {
+                            data_type: column_type(data_type)?,
+                            path,
+                            on_empty: on_empty
+                                .as_ref()
+                                .map_or(JsonTableBehaviorSpec::Null, behavior_spec),
+                            on_error: on_error.as_ref().map_or(default_on_error, behavior_spec),
+                            name,
+                        }
+                    }
+                    JsonTableColumn::Exists {
+                        name,
+                        data_type,
+                        path,
+                    } => {
+                        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                        JsonTableColumnSpec::Exists {
+                            data_type: column_type(data_type)?,
+                            path: column_path(span, &name, path)?,
+                            name,
+                        }
+                    }
+                    JsonTableColumn::Nested { path, columns } => {
+                        check_json_path(span, path)?;
+                        JsonTableColumnSpec::Nested {
+                            path: path.clone(),
+                            columns: self.json_table_columns(span, columns, error_on_error)?,
+                        }
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+fn check_json_path(span: &Span, path: &str) -> Result<()> {
+    parse_json_path(path.as_bytes()).map_err(|_| {
+        ErrorCode::SemanticError(format!("Invalid JSON Path '{path}' in JSON_TABLE"))
+            .set_span(*span)
+    })?;
+    Ok(())
+}
+
+// The path of a column defaults to the member with the same name.
+fn column_path(span: &Span, name: &str, path: &Option<String>) -> Result<String> {
+    let path = match path {
+        Some(path) => path.clone(),
+        None if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => {
+            format!("$.{name}")
+        }
+        None => format!("$[\"{}\"]", name.replace('"', "\\\"")),
+    };
+    check_json_path(span, &path)?;
+    Ok(path)
+}
+
+fn column_type(type_name: &TypeName) -> Result<DataType> {
+    Ok(DataType::from(&resolve_type_name(type_name, true)?).remove_nullable())
+}
+
+fn behavior_spec(behavior: &JsonTableBehavior) -> JsonTableBehaviorSpec {
+    match behavior {
+        JsonTableBehavior::Null => JsonTableBehaviorSpec::Null,
+        JsonTableBehavior::Error => JsonTableBehaviorSpec::Error,
+        JsonTableBehavior::Default(value) => JsonTableBehaviorSpec::Default(match value {
+            Literal::String(s) => jsonb::Value::String(s.clone().into()).to_string(),
+            Literal::Boolean(b) => b.to_string(),
+            Literal::Null => "null".to_string(),
+            other => other.to_string(),
+        }),
+    }
+}
@@ -34,6 +34,7 @@ use databend_common_expression::types::NumberScalar;
 use databend_common_expression::FunctionKind;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_storages_result_cache::gen_result_persist_meta_key;
 use databend_common_storages_result_cache::ResultCacheMetaManager;
 use databend_common_storages_result_cache::ResultScan;
 use databend_common_users::UserApiProvider;
@@ -73,46 +74,19 @@ impl Binder {
         {
             // If it is a set-returning function, we bind it as a subquery.
             let args = parse_table_function_args(span, &func_name, params, named_params)?;
-
-            let select_stmt = SelectStmt {
+            let srf = Expr::FunctionCall {
                 span: *span,
-                hints: None,
-                distinct: false,
-                top_n: None,
-                select_list: vec![SelectTarget::AliasedExpr {
-                    expr: Box::new(databend_common_ast::ast::Expr::FunctionCall {
-                        span: *span,
-                        func: ASTFunctionCall {
-                            distinct: false,
-                            name: databend_common_ast::ast::Identifier::from_name(
-                                *span,
-                                &func_name.name,
-                            ),
-                            params: vec![],
-                            args,
-                            window: None,
-                            lambda: None,
-                        },
-                    }),
-                    alias: None,
-                }],
-                from: vec![],
-                selection: None,
-                group_by: None,
-                having: None,
-                window_list: None,
-                qualify: None,
+                func: ASTFunctionCall {
+                    distinct: false,
+                    name: func_name.clone(),
+                    params: vec![],
+                    args,
+                    window: None,
+                    lambda: None,
+                },
             };
-            let (srf_expr, mut bind_context) =
-                self.bind_select(bind_context, &select_stmt, &[], None)?;
-
-            return self.extract_srf_table_function_columns(
-                &mut bind_context,
-                span,
-                &func_name,
-                srf_expr,
-                alias,
-            );
+            let fields = srf_table_function_fields(&func_name);
+            return self.bind_srf_table_function(bind_context, span, srf, fields, alias);
         }
 
         let mut scalar_binder = ScalarBinder::new(
@@ -174,19 +148,47 @@ impl Binder {
         }
         let kv_store = UserApiProvider::instance().get_meta_store_client();
         let meta_key = self.ctx.get_result_cache_key(&query_id);
-        if meta_key.is_none() {
-            return Err(ErrorCode::EmptyData(format!(
-                "`RESULT_SCAN` failed: No cache key found in current session for query ID '{}'.",
-                query_id
-            ))
-            .set_span(*span));
-        }
+        let tenant = self.ctx.get_tenant();
+        let user = self
+            .ctx
+            .get_current_user()?
+            .identity()
+            .display()
+            .to_string();
 
         databend_common_base::runtime::block_on(async move {
             let result_cache_mgr = ResultCacheMetaManager::create(kv_store, 0);
-            let meta_key = meta_key.unwrap();
-            let location = match result_cache_mgr.get(meta_key.clone()).await? {
-                Some(value) => value.location,
+            let (meta_key, value) = match meta_key {
+                Some(meta_key) => {
+                    let value = result_cache_mgr.get(meta_key.clone()).await?;
+                    (meta_key, value)
+                }
+                None => {
+                    // The result of a query in other sessions (or on other nodes) is only
+                    // available when it is persisted by the query id.
+                    let meta_key = gen_result_persist_meta_key(tenant.tenant_name(), &query_id);
+                    match result_cache_mgr.get(meta_key.clone()).await? {
+                        // The persisted result can only be read by the user who executed the query.
+                        Some(value) if value.owner.as_deref() != Some(user.as_str()) => {
+                            return Err(ErrorCode::PermissionDenied(format!(
+                                "`RESULT_SCAN` failed: The result of query ID '{}' belongs to another user.",
+                                query_id
+                            ))
+                            .set_span(*span));
+                        }
+                        Some(value) => (meta_key, Some(value)),
+                        None => {
+                            return Err(ErrorCode::EmptyData(format!(
+                                "`RESULT_SCAN` failed: No cache key found in current session for query ID '{}'.",
+                                query_id
+                            ))
+                            .set_span(*span));
+                        }
+                    }
+                }
+            };
+            let locations = match value {
+                Some(value) => value.files(),
                 None => {
                     return Err(ErrorCode::EmptyData(format!(
                         "`RESULT_SCAN` failed: Unable to fetch cached data for query ID '{}'. The data may have exceeded its TTL or been cleaned up. Cache key: '{}'",
@@ -194,7 +196,7 @@ impl Binder {
                     )).set_span(*span));
                 }
             };
-            let table = ResultScan::try_create(query_id, location).await?;
+            let table = ResultScan::try_create(query_id, locations).await?;
 
             let table_alias_name = if let Some(table_alias) = alias {
                 Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
@@ -222,29 +224,55 @@ impl Binder {
         })
     }
 
+    /// Bind a set-returning function in the FROM clause as a subquery
+    /// `SELECT <srf>`, the output columns are named with `fields`.
+    pub(crate) fn bind_srf_table_function(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        srf: Expr,
+        fields: Option<Vec<String>>,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let select_stmt = SelectStmt {
+            span: *span,
+            hints: None,
+            distinct: false,
+            top_n: None,
+            select_list: vec![SelectTarget::AliasedExpr {
+                expr: Box::new(srf),
+                alias: None,
+            }],
+            from: vec![],
+            selection: None,
+            group_by: None,
+            having: None,
+            window_list: None,
+            qualify: None,
+        };
+        let (srf_expr, mut bind_context) =
+            self.bind_select(bind_context, &select_stmt, &[], None)?;
+
+        self.extract_srf_table_function_columns(&mut bind_context, span, fields, srf_expr, alias)
+    }
+
     /// Extract the srf inner tuple fields as columns.
     fn extract_srf_table_function_columns(
         &mut self,
         bind_context: &mut BindContext,
         span: &Span,
-        func_name: &Identifier,
+        fields: Option<Vec<String>>,
         srf_expr: SExpr,
         alias: &Option<TableAlias>,
     ) -> Result<(SExpr, BindContext)> {
-        let fields = if func_name.name.eq_ignore_ascii_case("flatten") {
-            Some(vec![
-                "seq".to_string(),
-                "key".to_string(),
-                "path".to_string(),
-                "index".to_string(),
-                "value".to_string(),
-                "this".to_string(),
-            ])
-        } else if func_name.name.eq_ignore_ascii_case("json_each") {
-            Some(vec!["key".to_string(), "value".to_string()])
-        } else {
-            None
-        };
+        // A single field tuple has been extracted to the top level column.
+        if let Some(fields) = fields.as_ref().filter(|fields| fields.len() == 1) {
+            bind_context.columns[0].column_name = fields[0].clone();
+            if let Some(alias) = alias {
+                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+            }
+            return Ok((srf_expr, bind_context.clone()));
+        }
 
         if let Some(fields) = fields {
             if let RelOperator::EvalScalar(plan) = (*srf_expr.plan).clone() {
@@ -326,7 +354,6 @@ impl Binder {
                 alias,
                 ..
             } => {
-                let mut bind_context = BindContext::with_parent(Box::new(parent_context.clone()));
                 let func_name = normalize_identifier(name, &self.name_resolution_ctx);
 
                 if BUILTIN_FUNCTIONS
@@ -348,70 +375,8 @@ impl Binder {
                             lambda: None,
                         },
                     };
-                    let select_list = vec![SelectTarget::AliasedExpr {
-                        expr: Box::new(srf.clone()),
-                        alias: None,
-                    }];
-                    let mut select_list =
-                        self.normalize_select_list(&mut bind_context, &select_list)?;
-                    // analyze set returning functions
-                    self.analyze_project_set_select(&mut bind_context, &mut select_list)?;
-                    // bind set returning functions
-                    let srf_expr = self.bind_project_set(&mut bind_context, child)?;
-
-                    if let Some(item) = select_list.items.pop() {
-                        let srf_result = item.scalar;
-                        let column_binding =
-                            if let ScalarExpr::BoundColumnRef(column_ref) = &srf_result {
-                                column_ref.column.clone()
-                            } else {
-                                // Add result column to metadata
-                                let data_type = srf_result.data_type()?;
-                                let index = self.metadata.write().add_derived_column(
-                                    srf.to_string(),
-                                    data_type.clone(),
-                                    Some(srf_result.clone()),
-                                );
-                                ColumnBindingBuilder::new(
-                                    srf.to_string(),
-                                    index,
-                                    Box::new(data_type),
-                                    Visibility::Visible,
-                                )
-                                .build()
-                            };
-
-                        let eval_scalar = EvalScalar {
-                            items: vec![ScalarItem {
-                                scalar: srf_result,
-                                index: column_binding.index,
-                            }],
-                        };
-                        // Add srf result column
-                        bind_context.add_column_binding(column_binding);
-
-                        let flatten_expr =
-                            SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(srf_expr));
-
-                        let (new_expr, mut bind_context) = self
-                            .extract_srf_table_function_columns(
-                                &mut bind_context,
-                                span,
-                                &func_name,
-                                flatten_expr,
-                                alias,
-                            )?;
-
-                        // add left table columns.
-                        let mut new_columns = parent_context.columns.clone();
-                        new_columns.extend_from_slice(&bind_context.columns);
-                        bind_context.columns = new_columns;
-
-                        Ok((new_expr, bind_context))
-                    } else {
-                        Err(ErrorCode::Internal("Failed to bind project_set for lateral join. This may indicate an issue with the SRF (Set Returning Function) processing or an internal logic error.")
-                            .set_span(*span))
-                    }
+                    let fields = srf_table_function_fields(&func_name);
+                    self.bind_lateral_srf(parent_context, child, span, srf, fields, alias)
                 } else {
                     Err(ErrorCode::InvalidArgument(format!(
                         "The function '{}' is not supported for lateral joins. Lateral joins currently support only Set Returning Functions (SRFs).",
@@ -420,9 +385,110 @@ impl Binder {
                     .set_span(*span))
                 }
             }
+            TableReference::JsonTable {
+                span,
+                json_table,
+                alias,
+                ..
+            } => {
+                let (srf, fields) = self.json_table_to_srf(span, json_table)?;
+                self.bind_lateral_srf(parent_context, child, span, srf, Some(fields), alias)
+            }
             _ => unreachable!(),
         }
     }
+
+    /// Bind a set-returning function which can reference the columns of `child`.
+    fn bind_lateral_srf(
+        &mut self,
+        parent_context: &mut BindContext,
+        child: SExpr,
+        span: &Span,
+        srf: Expr,
+        fields: Option<Vec<String>>,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut bind_context = BindContext::with_parent(Box::new(parent_context.clone()));
+        let select_list = vec![SelectTarget::AliasedExpr {
+            expr: Box::new(srf.clone()),
+            alias: None,
+        }];
+        let mut select_list = self.normalize_select_list(&mut bind_context, &select_list)?;
+        // analyze set returning functions
+        self.analyze_project_set_select(&mut bind_context, &mut select_list)?;
+        // bind set returning functions
+        let srf_expr = self.bind_project_set(&mut bind_context, child)?;
+
+        if let Some(item) = select_list.items.pop() {
+            let srf_result = item.scalar;
+            let column_binding = if let ScalarExpr::BoundColumnRef(column_ref) = &srf_result {
+                column_ref.column.clone()
+            } else {
+                // Add result column to metadata
+                let data_type = srf_result.data_type()?;
+                let index = self.metadata.write().add_derived_column(
+                    srf.to_string(),
+                    data_type.clone(),
+                    Some(srf_result.clone()),
+                );
+                ColumnBindingBuilder::new(
+                    srf.to_string(),
+                    index,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build()
+            };
+
+            let eval_scalar = EvalScalar {
+                items: vec![ScalarItem {
+                    scalar: srf_result,
+                    index: column_binding.index,
+                }],
+            };
+            // Add srf result column
+            bind_context.add_column_binding(column_binding);
+
+            let flatten_expr =
+                SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(srf_expr));
+
+            let (new_expr, mut bind_context) = self.extract_srf_table_function_columns(
+                &mut bind_context,
+                span,
+                fields,
+                flatten_expr,
+                alias,
+            )?;
+
+            // add left table columns.
+            let mut new_columns = parent_context.columns.clone();
+            new_columns.extend_from_slice(&bind_context.columns);
+            bind_context.columns = new_columns;
+
+            Ok((new_expr, bind_context))
+        } else {
+            Err(ErrorCode::Internal("Failed to bind project_set for lateral join. This may indicate an issue with the SRF (Set Returning Function) processing or an internal logic error.")
+                .set_span(*span))
+        }
+    }
+}
+
+// the output column names of the srf table functions with multiple fields
+fn srf_table_function_fields(func_name: &Identifier) -> Option<Vec<String>> {
+    if func_name.name.eq_ignore_ascii_case("flatten") {
+        Some(vec![
+            "seq".to_string(),
+            "key".to_string(),
+            "path".to_string(),
+            "index".to_string(),
+            "value".to_string(),
+            "this".to_string(),
+        ])
+    } else if func_name.name.eq_ignore_ascii_case("json_each") {
+        Some(vec!["key".to_string(), "value".to_string()])
+    } else {
+        None
+    }
 }
 
 // parse flatten named params to arguments
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use chrono_tz::Tz;
+use databend_common_ast::ast::AlterRoleAction;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Statement;
@@ -52,6 +53,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::plans::AlterRolePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
@@ -345,6 +347,14 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole(stmt) => Plan::AlterRole(Box::new(AlterRolePlan {
+                if_exists: stmt.if_exists,
+                role_name: stmt.role_name.clone(),
+                workload_group: match &stmt.action {
+                    AlterRoleAction::SetWorkloadGroup(name) => Some(name.clone()),
+                    AlterRoleAction::UnsetWorkloadGroup => None,
+                },
+            })),
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -532,6 +542,16 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups => self.bind_rewrite_to_query(bind_context, "SELECT name, cpu_quota, memory_quota, max_concurrency, queue_timeout, running_queries, queued_queries, memory_usage FROM system.workload_groups ORDER BY name", RewriteKind::ShowWorkloadGroups).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -626,6 +646,9 @@ impl<'a> Binder {
                     return Err(ErrorCode::SyntaxException("CALL PROCEDURE, set enable_experimental_procedure=1"));
                 }
                 }
+            Statement::Prepare(stmt) => self.bind_prepare(stmt).await?,
+            Statement::Execute(stmt) => self.bind_execute(bind_context, stmt).await?,
+            Statement::Deallocate(stmt) => self.bind_deallocate(stmt).await?,
         };
 
         match plan.kind() {
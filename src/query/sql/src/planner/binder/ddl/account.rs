@@ -23,6 +23,7 @@ use databend_common_ast::ast::PrincipalIdentity as AstPrincipalIdentity;
 use databend_common_ast::ast::RevokeStmt;
 use databend_common_ast::ast::ShowObjectPrivilegesStmt;
 use databend_common_ast::ast::ShowOptions;
+use databend_common_ast::ast::UserOptionItem;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
@@ -311,6 +312,20 @@ impl Binder {
             auth_option,
             user_options,
         } = stmt;
+        // The limits of a user can not be changed by the user self.
+        if user.is_none()
+            && user_options.iter().any(|option| {
+                matches!(
+                    option,
+                    UserOptionItem::SetLimits(_) | UserOptionItem::UnsetLimits
+                )
+            })
+        {
+            return Err(ErrorCode::PermissionDenied(
+                "Can not change the limits of the current user, please specify the user name",
+            ));
+        }
+
         // None means current user
         let user_info = if user.is_none() {
             self.ctx.get_current_user()?
@@ -67,6 +67,7 @@ static INDEX_TOKENIZER_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|
     let mut r = HashSet::new();
     r.insert("english");
     r.insert("chinese");
+    r.insert("ngram");
     r
 });
 
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_ast::ast::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+use crate::plans::WorkloadGroupOptions;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            create_option,
+            name,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+
+        let plan = CreateWorkloadGroupPlan {
+            create_option: create_option.clone().into(),
+            tenant,
+            name: name.to_string(),
+            options: parse_workload_group_options(options)?,
+        };
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+
+        let plan = AlterWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            options: parse_workload_group_options(options)?,
+        };
+        Ok(Plan::AlterWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+}
+
+fn parse_workload_group_options(
+    options: &BTreeMap<String, String>,
+) -> Result<WorkloadGroupOptions> {
+    let mut workload_group_options = WorkloadGroupOptions::default();
+    for (key, value) in options {
+        match key.as_str() {
+            "cpu_quota" => {
+                workload_group_options.cpu_quota = Some(parse_percentage(key, value)?);
+            }
+            "memory_quota" => {
+                workload_group_options.memory_quota = Some(parse_percentage(key, value)?);
+            }
+            "max_concurrency" => {
+                workload_group_options.max_concurrency = Some(parse_number(key, value)?);
+            }
+            "queue_timeout" => {
+                workload_group_options.queue_timeout_secs = Some(parse_number(key, value)?);
+            }
+            _ => {
+                return Err(ErrorCode::IllegalWorkloadGroup(format!(
+                    "unknown workload group option {}, available options: cpu_quota, memory_quota, max_concurrency, queue_timeout",
+                    key
+                )));
+            }
+        }
+    }
+    Ok(workload_group_options)
+}
+
+// Percentages are accepted with or without the trailing `%`, like '40%' or 40.
+fn parse_percentage(key: &str, value: &str) -> Result<u64> {
+    let percentage = parse_number(key, value.trim().trim_end_matches('%'))?;
+    if percentage > 100 {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid value {} of workload group option {}, it must be between 0% and 100%",
+            value, key
+        )));
+    }
+    Ok(percentage)
+}
+
+fn parse_number(key: &str, value: &str) -> Result<u64> {
+    value.trim().parse::<u64>().map_err(|_| {
+        ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid value {} of workload group option {}, it must be a non-negative integer",
+            value, key
+        ))
+    })
+}
@@ -35,6 +35,7 @@ mod insert_multi_table;
 mod internal_column_factory;
 mod kill;
 mod location;
+mod prepare;
 mod presign;
 mod project;
 mod project_set;
@@ -74,6 +75,9 @@ pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::get_storage_params_from_options;
 pub use location::parse_storage_params_from_uri;
 pub use location::parse_uri_location;
+pub(crate) use prepare::get_prepared_statement;
+pub(crate) use prepare::resolve_execute;
+pub(crate) use prepare::resolve_placeholder_values;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
 pub use stream_column_factory::STREAM_COLUMN_FACTORY;
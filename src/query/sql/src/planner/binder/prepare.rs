@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::DeallocateStmt;
+use databend_common_ast::ast::ExecuteStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::PrepareStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_catalog::table_context::PreparedStatement;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+use parking_lot::RwLock;
+
+use crate::normalize_identifier;
+use crate::planner::binder::BindContext;
+use crate::planner::binder::Binder;
+use crate::planner::semantic::bind_placeholders;
+use crate::planner::semantic::count_placeholders;
+use crate::plans::DeallocatePlan;
+use crate::plans::Plan;
+use crate::plans::PreparePlan;
+use crate::plans::ScalarExpr;
+use crate::Metadata;
+use crate::NameResolutionContext;
+use crate::TypeChecker;
+
+/// Get the prepared statement of `EXECUTE name USING ...`.
+pub(crate) fn get_prepared_statement(
+    ctx: &dyn TableContext,
+    name_resolution_ctx: &NameResolutionContext,
+    stmt: &ExecuteStmt,
+) -> Result<PreparedStatement> {
+    let name = normalize_identifier(&stmt.name, name_resolution_ctx).name;
+    ctx.get_prepared_statement(&name).ok_or_else(|| {
+        ErrorCode::UnknownPreparedStatement(format!("Unknown prepared statement '{}'", name))
+    })
+}
+
+/// Resolve `EXECUTE name USING ...` to the prepared statement with the parameters bound.
+pub(crate) fn resolve_execute(
+    ctx: &dyn TableContext,
+    name_resolution_ctx: &NameResolutionContext,
+    stmt: &ExecuteStmt,
+) -> Result<Statement> {
+    let prepared = get_prepared_statement(ctx, name_resolution_ctx, stmt)?;
+    bind_placeholders(&prepared.statement, &stmt.params)
+}
+
+/// Evaluate the parameters of a prepared statement to constants, return `None` if
+/// any of them is not a constant expression.
+pub(crate) fn resolve_placeholder_values(
+    ctx: Arc<dyn TableContext>,
+    name_resolution_ctx: &NameResolutionContext,
+    params: &[Expr],
+) -> Result<Option<Vec<Scalar>>> {
+    let mut bind_context = BindContext::new();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        name_resolution_ctx,
+        metadata,
+        &[],
+        true,
+    )?;
+
+    let mut values = Vec::with_capacity(params.len());
+    for param in params {
+        match type_checker.resolve(param).map(|scalar| scalar.0) {
+            Ok(ScalarExpr::ConstantExpr(constant)) => values.push(constant.value),
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(values))
+}
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(super) async fn bind_prepare(&mut self, stmt: &PrepareStmt) -> Result<Plan> {
+        if matches!(
+            stmt.statement.as_ref(),
+            Statement::Prepare(_) | Statement::Execute(_) | Statement::Deallocate(_)
+        ) {
+            return Err(ErrorCode::SyntaxException(
+                "PREPARE, EXECUTE and DEALLOCATE can not be prepared",
+            ));
+        }
+
+        let name = self.normalize_identifier(&stmt.name).name;
+        let statement = stmt.statement.as_ref().clone();
+        let param_count = count_placeholders(&statement);
+        Ok(Plan::Prepare(Box::new(PreparePlan {
+            name,
+            prepared: PreparedStatement {
+                statement,
+                param_count,
+            },
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn bind_execute(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ExecuteStmt,
+    ) -> Result<Plan> {
+        let stmt = resolve_execute(self.ctx.as_ref(), &self.name_resolution_ctx, stmt)?;
+        self.bind_statement(bind_context, &stmt).await
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn bind_deallocate(&mut self, stmt: &DeallocateStmt) -> Result<Plan> {
+        let name = self.normalize_identifier(&stmt.name).name;
+        Ok(Plan::Deallocate(Box::new(DeallocatePlan { name })))
+    }
+}
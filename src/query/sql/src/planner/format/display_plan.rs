@@ -139,6 +139,7 @@ impl Plan {
             Plan::DescUser(_) => Ok("DescUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
             Plan::Set(_) => Ok("Set".to_string()),
@@ -169,6 +170,11 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // workload group
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -211,6 +217,10 @@ impl Plan {
             Plan::CreateDictionary(_) => Ok("CreateDictionary".to_string()),
             Plan::DropDictionary(_) => Ok("DropDictionary".to_string()),
             Plan::ShowCreateDictionary(_) => Ok("ShowCreateDictionary".to_string()),
+
+            // Prepared statements
+            Plan::Prepare(_) => Ok("Prepare".to_string()),
+            Plan::Deallocate(_) => Ok("Deallocate".to_string()),
         }
     }
 }
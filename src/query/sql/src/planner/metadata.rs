@@ -21,6 +21,7 @@ use std::sync::Arc;
 use ahash::HashMap;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Literal;
+use databend_common_ast::Span;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::table::Table;
@@ -74,6 +75,8 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// Values of the `?` placeholders of a prepared statement, keyed by their spans.
+    placeholder_values: Vec<(Span, Scalar)>,
 }
 
 impl Metadata {
@@ -457,6 +460,21 @@ impl Metadata {
     pub fn get_max_column_position(&self) -> usize {
         self.max_column_position
     }
+
+    pub fn set_placeholder_values(&mut self, values: Vec<(Span, Scalar)>) {
+        self.placeholder_values = values
+    }
+
+    pub fn placeholder_values(&self) -> &[(Span, Scalar)] {
+        &self.placeholder_values
+    }
+
+    pub fn placeholder_value(&self, span: Span) -> Option<&Scalar> {
+        self.placeholder_values
+            .iter()
+            .find(|(s, _)| *s == span)
+            .map(|(_, value)| value)
+    }
 }
 
 #[derive(Clone)]
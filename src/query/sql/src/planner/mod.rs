@@ -26,6 +26,7 @@ pub mod optimizer;
 mod planner_cache;
 pub mod plans;
 mod stream_column;
+mod substrait;
 mod udf_validator;
 
 pub use binder::parse_result_scan_args;
@@ -50,3 +51,5 @@ pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
 pub use semantic::*;
 pub use stream_column::*;
+pub use substrait::SubstraitConsumer;
+pub use substrait::SubstraitProducer;
@@ -189,6 +189,7 @@ impl RuleStatsAggregateOptimizer {
                 | DataType::Timestamp
                 | DataType::String
                 | DataType::Decimal(_)
+                | DataType::LargeInt(_)
         )
     }
 }
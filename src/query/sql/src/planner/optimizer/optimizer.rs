@@ -55,6 +55,7 @@ use crate::plans::RelOperator;
 use crate::plans::SetScalarsOrQuery;
 use crate::InsertInputSource;
 use crate::MetadataRef;
+use crate::SubstraitProducer;
 
 #[derive(Clone, Educe)]
 #[educe(Debug)]
@@ -218,6 +219,32 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
                     ))
                 }
             }
+            ExplainKind::Substrait(_) => {
+                if let box Plan::Query {
+                    ref s_expr,
+                    ref metadata,
+                    ref bind_context,
+                    ..
+                } = plan
+                {
+                    let s_expr = optimize_query(&mut opt_ctx, *s_expr.clone()).await?;
+                    let substrait_plan =
+                        SubstraitProducer::produce(&metadata.read(), &s_expr, bind_context)?;
+                    let display_string =
+                        serde_json::to_string_pretty(&substrait_plan).map_err(|e| {
+                            ErrorCode::Internal(format!("Cannot serialize substrait plan: {e}"))
+                        })?;
+                    Ok(Plan::Explain {
+                        config,
+                        kind: ExplainKind::Substrait(display_string),
+                        plan,
+                    })
+                } else {
+                    Err(ErrorCode::BadArguments(
+                        "Cannot use EXPLAIN SUBSTRAIT with a non-query statement",
+                    ))
+                }
+            }
             _ => {
                 if config.optimized || !config.logical {
                     let optimized_plan = Box::pin(optimize(opt_ctx.clone(), *plan)).await?;
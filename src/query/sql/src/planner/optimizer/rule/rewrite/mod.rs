@@ -22,6 +22,7 @@ mod rule_eliminate_union;
 mod rule_fold_count_aggregate;
 mod rule_merge_eval_scalar;
 mod rule_merge_filter;
+mod rule_ngram_join;
 mod rule_normalize_scalar;
 mod rule_push_down_filter_aggregate;
 mod rule_push_down_filter_eval_scalar;
@@ -54,6 +55,7 @@ pub use rule_eliminate_union::RuleEliminateUnion;
 pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
 pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
+pub use rule_ngram_join::RuleNgramJoin;
 pub use rule_normalize_scalar::RuleNormalizeScalarFilter;
 pub use rule_push_down_filter_aggregate::RulePushDownFilterAggregate;
 pub use rule_push_down_filter_eval_scalar::RulePushDownFilterEvalScalar;
@@ -0,0 +1,269 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_number;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::F64;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::satisfied_by;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinEquiCondition;
+use crate::plans::JoinType;
+use crate::plans::ProjectSet;
+use crate::plans::RelOp;
+use crate::plans::ScalarItem;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Evaluate a fuzzy join on `ngram_distance` as an equi-join on the shared grams,
+/// so the hash table works as an n-gram inverted index of the build side, and only
+/// the pairs sharing a gram are compared, instead of all the pairs.
+///
+/// Two strings share a gram if and only if their `ngram_distance` is less than `1`,
+/// so an inner join on `ngram_distance(l, r) < c` with `c <= 1` can be rewritten to:
+///
+/// Input:   Join(ngram_distance(l, r) < c)
+///           /  \
+///          *    *
+///
+/// Output:  Join(l_gram = r_gram, ngram_distance(l, r) < c, l_gram = first_common_ngram(l, r))
+///           /  \
+///   ProjectSet  ProjectSet
+///  unnest(ngrams(l))  unnest(ngrams(r))
+///          |    |
+///          *    *
+///
+/// A pair of rows is joined once for every gram they share, only the one joined
+/// by their first common gram is kept. The condition can also be in a filter on
+/// top of an inner or cross join, it's kept in the filter in that case.
+pub struct RuleNgramJoin {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+}
+
+impl RuleNgramJoin {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::NgramJoin,
+            matchers: vec![
+                // Filter
+                //  \
+                //   Join
+                //   |  \
+                //   *   *
+                Matcher::MatchOp {
+                    op_type: RelOp::Filter,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Join,
+                        children: vec![Matcher::Leaf, Matcher::Leaf],
+                    }],
+                },
+                // Join
+                // |  \
+                // *   *
+                Matcher::MatchOp {
+                    op_type: RelOp::Join,
+                    children: vec![Matcher::Leaf, Matcher::Leaf],
+                },
+            ],
+            metadata,
+        }
+    }
+
+    fn rewrite_join(&self, s_expr: &SExpr, predicates: &[ScalarExpr]) -> Result<Option<SExpr>> {
+        let mut join: Join = s_expr.plan().clone().try_into()?;
+        if !matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+            || !join.equi_conditions.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let rel_expr = RelExpr::with_s_expr(s_expr);
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+        let Some((left, right)) = predicates
+            .iter()
+            .chain(join.non_equi_conditions.iter())
+            .find_map(|predicate| ngram_join_keys(predicate, &left_prop, &right_prop))
+        else {
+            return Ok(None);
+        };
+
+        let (left_srf, left_gram) = self.unnest_ngrams(left.clone())?;
+        let (right_srf, right_gram) = self.unnest_ngrams(right.clone())?;
+        join.join_type = JoinType::Inner;
+        join.equi_conditions
+            .push(JoinEquiCondition::new(left_gram.clone(), right_gram, false));
+        join.non_equi_conditions
+            .push(function_call("eq", vec![], vec![
+                left_gram,
+                function_call("first_common_ngram", vec![], vec![left, right]),
+            ]));
+
+        let left_child = SExpr::create_unary(
+            Arc::new(
+                ProjectSet {
+                    srfs: vec![left_srf],
+                }
+                .into(),
+            ),
+            Arc::new(s_expr.child(0)?.clone()),
+        );
+        let right_child = SExpr::create_unary(
+            Arc::new(
+                ProjectSet {
+                    srfs: vec![right_srf],
+                }
+                .into(),
+            ),
+            Arc::new(s_expr.child(1)?.clone()),
+        );
+        Ok(Some(SExpr::create_binary(
+            Arc::new(join.into()),
+            Arc::new(left_child),
+            Arc::new(right_child),
+        )))
+    }
+
+    /// Build `unnest(ngrams(arg))`, and the gram it returns.
+    fn unnest_ngrams(&self, arg: ScalarExpr) -> Result<(ScalarItem, ScalarExpr)> {
+        let srf = function_call("unnest", vec![], vec![function_call(
+            "ngrams",
+            vec![],
+            vec![arg],
+        )]);
+        let data_type = srf.data_type()?;
+        let index = self.metadata.write().add_derived_column(
+            "unnest".to_string(),
+            data_type.clone(),
+            Some(srf.clone()),
+        );
+        let column = ColumnBindingBuilder::new(
+            "unnest".to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::InVisible,
+        )
+        .build();
+
+        // The set returning function returns a tuple of a single field.
+        let gram = function_call("get", vec![Scalar::Number(NumberScalar::Int64(1))], vec![
+            BoundColumnRef { span: None, column }.into(),
+        ]);
+        Ok((ScalarItem { index, scalar: srf }, gram))
+    }
+}
+
+impl Rule for RuleNgramJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let result = match s_expr.plan().rel_op() {
+            RelOp::Filter => {
+                let filter: Filter = s_expr.plan().clone().try_into()?;
+                self.rewrite_join(s_expr.child(0)?, &filter.predicates)?
+                    .map(|join| s_expr.replace_children(vec![Arc::new(join)]))
+            }
+            _ => self.rewrite_join(s_expr, &[])?,
+        };
+        if let Some(result) = result {
+            state.add_result(result);
+        }
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
+
+fn function_call(name: &str, params: Vec<Scalar>, arguments: Vec<ScalarExpr>) -> ScalarExpr {
+    ScalarExpr::FunctionCall(FunctionCall {
+        span: None,
+        func_name: name.to_string(),
+        params,
+        arguments,
+    })
+}
+
+/// Match `ngram_distance(l, r) < c` with `c <= 1`, or `ngram_distance(l, r) <= c`
+/// with `c < 1`, where `l` and `r` come from different sides of the join, and
+/// return `l` and `r` ordered by the sides.
+fn ngram_join_keys(
+    predicate: &ScalarExpr,
+    left_prop: &RelationalProperty,
+    right_prop: &RelationalProperty,
+) -> Option<(ScalarExpr, ScalarExpr)> {
+    let ScalarExpr::FunctionCall(func) = predicate else {
+        return None;
+    };
+    let (distance, threshold, inclusive) = match (func.func_name.as_str(), &func.arguments[..]) {
+        ("lt", [distance, threshold]) | ("gt", [threshold, distance]) => {
+            (distance, threshold, false)
+        }
+        ("lte", [distance, threshold]) | ("gte", [threshold, distance]) => {
+            (distance, threshold, true)
+        }
+        _ => return None,
+    };
+
+    let ScalarExpr::FunctionCall(distance) = distance else {
+        return None;
+    };
+    if distance.func_name != "ngram_distance" || !threshold.used_columns().is_empty() {
+        return None;
+    }
+    let threshold = check_number::<_, F64>(
+        None,
+        &FunctionContext::default(),
+        &threshold.as_expr().ok()?,
+        &BUILTIN_FUNCTIONS,
+    )
+    .ok()?;
+    if threshold > F64::from(1.0) || (inclusive && threshold == F64::from(1.0)) {
+        return None;
+    }
+
+    let [a, b] = &distance.arguments[..] else {
+        return None;
+    };
+    if satisfied_by(a, left_prop) && satisfied_by(b, right_prop) {
+        Some((a.clone(), b.clone()))
+    } else if satisfied_by(b, left_prop) && satisfied_by(a, right_prop) {
+        Some((b.clone(), a.clone()))
+    } else {
+        None
+    }
+}
@@ -51,6 +51,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownLimitOuterJoin,
         RuleID::PushDownLimitScan,
         RuleID::SemiToInnerJoin,
+        RuleID::NgramJoin,
         RuleID::FoldCountAggregate,
         RuleID::TryApplyAggIndex,
         RuleID::PushDownFilterScan,
@@ -99,6 +100,7 @@ pub enum RuleID {
     PushDownSortEvalScalar,
     PushDownSortScan,
     SemiToInnerJoin,
+    NgramJoin,
     EliminateEvalScalar,
     EliminateFilter,
     EliminateSort,
@@ -153,6 +155,7 @@ impl Display for RuleID {
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
+            RuleID::NgramJoin => write!(f, "NgramJoin"),
             RuleID::EliminateUnion => write!(f, "EliminateUnion"),
         }
     }
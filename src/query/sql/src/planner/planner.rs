@@ -24,29 +24,42 @@ use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::token::Token;
 use databend_common_ast::parser::token::TokenKind;
 use databend_common_ast::parser::token::Tokenizer;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Scalar;
 use derive_visitor::DriveMut;
 use log::info;
 use log::warn;
 use parking_lot::RwLock;
 
+use super::semantic::bind_placeholders;
+use super::semantic::placeholder_spans;
+use super::semantic::rebind_placeholders;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use crate::binder::get_prepared_statement;
+use crate::binder::resolve_execute;
+use crate::binder::resolve_placeholder_values;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::optimizer::QuerySampleExecutor;
+use crate::optimizer::SExpr;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
+use crate::plans::Limit;
 use crate::plans::Plan;
+use crate::plans::RelOperator;
 use crate::Binder;
 use crate::CountSetOps;
 use crate::Metadata;
+use crate::MetadataRef;
 use crate::NameResolutionContext;
+use crate::SubstraitConsumer;
 use crate::VariableNormalizer;
 
 const PROBE_INSERT_INITIAL_TOKENS: usize = 128;
@@ -60,7 +73,8 @@ pub struct Planner {
 #[derive(Debug, Clone)]
 pub struct PlanExtras {
     pub format: Option<String>,
-    pub statement: Statement,
+    /// The planned statement, `None` for plans which are not planned from SQL, e.g. Substrait.
+    pub statement: Option<Statement>,
 }
 
 impl Planner {
@@ -148,7 +162,7 @@ impl Planner {
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
-                let (mut stmt, format) = if is_insert_stmt {
+                let (stmt, format) = if is_insert_stmt {
                     (parse_raw_insert_stmt(&tokens, sql_dialect)?, None)
                 } else if is_replace_stmt {
                     (parse_raw_replace_stmt(&tokens, sql_dialect)?, None)
@@ -162,74 +176,7 @@ impl Planner {
                     return Err(ErrorCode::SyntaxException("convert prql to sql failed."));
                 }
 
-                self.replace_stmt(&mut stmt)?;
-
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let mut enable_planner_cache =
-                    self.ctx.get_settings().get_enable_planner_cache()?;
-                let planner_cache_key = if enable_planner_cache {
-                    Some(Self::planner_cache_key(&stmt.to_string()))
-                } else {
-                    None
-                };
-
-                if enable_planner_cache {
-                    let (c, plan) = self.get_cache(
-                        name_resolution_ctx.clone(),
-                        planner_cache_key.as_ref().unwrap(),
-                        &stmt,
-                    );
-                    if let Some(mut plan) = plan {
-                        info!("logical plan from cache, time used: {:?}", start.elapsed());
-                        // update for clickhouse handler
-                        plan.extras.format = format;
-                        self.ctx
-                            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-                        return Ok((plan.plan, plan.extras));
-                    }
-                    enable_planner_cache = c;
-                }
-
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-
-                // Indicate binder there is no need to collect column statistics for the binding table.
-                self.ctx
-                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-                let plan = binder.bind(&stmt).await?;
-                // attach again to avoid the query kind is overwritten by the subquery
-                self.ctx
-                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
-                    .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
-                    .with_enable_dphyp(settings.get_enable_dphyp()?)
-                    .with_sample_executor(self.sample_executor.clone());
-
-                let optimized_plan = optimize(opt_ctx, plan).await?;
-                let result = (optimized_plan, PlanExtras {
-                    format,
-                    statement: stmt,
-                });
-
-                if enable_planner_cache {
-                    self.set_cache(
-                        planner_cache_key.clone().unwrap(),
-                        result.0.clone(),
-                        result.1.clone(),
-                    );
-                    Ok(result)
-                } else {
-                    Ok(result)
-                }
+                self.plan_stmt(stmt, format, start).await
             }
             .await;
 
@@ -274,6 +221,311 @@ impl Planner {
         }
     }
 
+    /// Plan a parsed statement, step 3 and 4 of `plan_sql`.
+    #[async_backtrace::framed]
+    async fn plan_stmt(
+        &self,
+        mut stmt: Statement,
+        format: Option<String>,
+        start: Instant,
+    ) -> Result<(Plan, PlanExtras)> {
+        // Plan `EXECUTE` with the plan of the prepared query, if it can be reused.
+        if let Statement::Execute(execute) = &stmt {
+            let name_resolution_ctx =
+                NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
+            let prepared =
+                get_prepared_statement(self.ctx.as_ref(), &name_resolution_ctx, execute)?;
+            if let Some(result) = self
+                .plan_prepared_query(&prepared.statement, &execute.params, &format)
+                .await?
+            {
+                return Ok(result);
+            }
+        }
+
+        self.resolve_prepared_stmt(&mut stmt)?;
+        // The prepared statement is stored as written, it's rewritten when executed.
+        if !matches!(stmt, Statement::Prepare(_)) {
+            self.replace_stmt(&mut stmt)?;
+        }
+
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let settings = self.ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let mut enable_planner_cache = self.ctx.get_settings().get_enable_planner_cache()?;
+        let planner_cache_key = if enable_planner_cache {
+            Some(Self::planner_cache_key(&stmt.to_string()))
+        } else {
+            None
+        };
+
+        if enable_planner_cache {
+            let (c, plan) = self.get_cache(
+                name_resolution_ctx.clone(),
+                planner_cache_key.as_ref().unwrap(),
+                &stmt,
+            );
+            if let Some(mut plan) = plan {
+                info!("logical plan from cache, time used: {:?}", start.elapsed());
+                // update for clickhouse handler
+                plan.extras.format = format;
+                self.ctx
+                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+                return Ok((plan.plan, plan.extras));
+            }
+            enable_planner_cache = c;
+        }
+
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+
+        // Indicate binder there is no need to collect column statistics for the binding table.
+        self.ctx
+            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+        let plan = binder.bind(&stmt).await?;
+        // attach again to avoid the query kind is overwritten by the subquery
+        self.ctx
+            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let optimized_plan = self.optimize_plan(plan, metadata).await?;
+        let result = (optimized_plan, PlanExtras {
+            format,
+            statement: Some(stmt),
+        });
+
+        if enable_planner_cache {
+            self.set_cache(
+                planner_cache_key.clone().unwrap(),
+                result.0.clone(),
+                result.1.clone(),
+            );
+            Ok(result)
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Plan a statement with `?` placeholders and the values of the parameters bound to them.
+    #[async_backtrace::framed]
+    pub async fn plan_sql_with_params(
+        &mut self,
+        sql: &str,
+        params: &[Expr],
+    ) -> Result<(Plan, PlanExtras)> {
+        let start = Instant::now();
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(sql)?;
+        let (stmt, format) = parse_sql(&tokens, sql_dialect)?;
+        if let Some(result) = self.plan_prepared_query(&stmt, params, &format).await? {
+            return Ok(result);
+        }
+
+        let stmt = bind_placeholders(&stmt, params)?;
+        self.plan_stmt(stmt, format, start).await
+    }
+
+    /// Plan a prepared query with the values of the parameters bound to its placeholders.
+    ///
+    /// The placeholders are resolved to the values by the binder, and the bound plan is
+    /// cached by the query with placeholders and the types of the values. The executions
+    /// with other values of the same types bind their values into the cached plan, and
+    /// only optimize it. Return `None` if the query can't be planned this way, e.g. a
+    /// placeholder is used where a literal is required, the parameters are bound to the
+    /// statement as literals then.
+    #[async_backtrace::framed]
+    async fn plan_prepared_query(
+        &self,
+        template: &Statement,
+        params: &[Expr],
+        format: &Option<String>,
+    ) -> Result<Option<(Plan, PlanExtras)>> {
+        if !matches!(template, Statement::Query(_)) {
+            return Ok(None);
+        }
+
+        let start = Instant::now();
+        let settings = self.ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let spans = placeholder_spans(template)?;
+        let Some(values) =
+            resolve_placeholder_values(self.ctx.clone(), &name_resolution_ctx, params)?
+        else {
+            return Ok(None);
+        };
+        if spans.len() != values.len() {
+            return Ok(None);
+        }
+
+        // The statement with the parameters bound as literals is used for the query log
+        // and the query result cache.
+        let mut stmt = bind_placeholders(template, params)?;
+        self.replace_stmt(&mut stmt)?;
+        let mut template = template.clone();
+        self.replace_stmt(&mut template)?;
+        self.ctx
+            .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+
+        let types = values
+            .iter()
+            .map(|value| value.as_ref().infer_data_type().to_string())
+            .collect::<Vec<_>>();
+        let planner_cache_key =
+            Self::planner_cache_key(&format!("{template} USING {}", types.join(", ")));
+        let mut enable_planner_cache = settings.get_enable_planner_cache()?;
+        let mut cached_plan = None;
+        if enable_planner_cache {
+            let (c, item) =
+                self.get_cache(name_resolution_ctx.clone(), &planner_cache_key, &template);
+            if let Some(item) = item {
+                cached_plan = Self::rebind_plan(item.plan, &values)?;
+            }
+            enable_planner_cache = c;
+        }
+
+        let mut plan = match cached_plan {
+            Some(plan) => {
+                info!("bound plan from cache, time used: {:?}", start.elapsed());
+                plan
+            }
+            None => {
+                let metadata = Arc::new(RwLock::new(Metadata::default()));
+                metadata.write().set_placeholder_values(
+                    spans.into_iter().zip(values.iter().cloned()).collect(),
+                );
+                let binder = Binder::new(
+                    self.ctx.clone(),
+                    CatalogManager::instance(),
+                    name_resolution_ctx,
+                    metadata,
+                );
+                let Ok(plan) = binder.bind(&template).await else {
+                    return Ok(None);
+                };
+                self.ctx
+                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
+
+                // Only cache the plan which the values can be bound into.
+                if enable_planner_cache && Self::rebind_plan(plan.clone(), &values)?.is_some() {
+                    self.set_cache(planner_cache_key, plan.clone(), PlanExtras {
+                        format: None,
+                        statement: Some(template),
+                    });
+                }
+                plan
+            }
+        };
+
+        let Plan::Query {
+            metadata,
+            formatted_ast,
+            ..
+        } = &mut plan
+        else {
+            return Ok(None);
+        };
+        if let Some(formatted_ast) = formatted_ast {
+            *formatted_ast = stmt.to_string();
+        }
+        let metadata = metadata.clone();
+        let optimized_plan = self.optimize_plan(plan, metadata).await?;
+        Ok(Some((optimized_plan, PlanExtras {
+            format: format.clone(),
+            statement: Some(stmt),
+        })))
+    }
+
+    /// Bind the values of the placeholders into a plan of a prepared query, the values
+    /// are in the order of the placeholders.
+    fn rebind_plan(plan: Plan, values: &[Scalar]) -> Result<Option<Plan>> {
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            rewrite_kind,
+            formatted_ast,
+            ignore_result,
+        } = plan
+        else {
+            return Ok(None);
+        };
+
+        let mut metadata = metadata.read().clone();
+        let bound = metadata.placeholder_values().to_vec();
+        if bound.len() != values.len() {
+            return Ok(None);
+        }
+        let Some(s_expr) = rebind_placeholders(&s_expr, &bound, values)? else {
+            return Ok(None);
+        };
+        metadata.set_placeholder_values(
+            bound
+                .into_iter()
+                .map(|(span, _)| span)
+                .zip(values.iter().cloned())
+                .collect(),
+        );
+        Ok(Some(Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: Arc::new(RwLock::new(metadata)),
+            bind_context,
+            rewrite_kind,
+            formatted_ast,
+            ignore_result,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn optimize_plan(&self, plan: Plan, metadata: MetadataRef) -> Result<Plan> {
+        let settings = self.ctx.get_settings();
+        let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata)
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
+            .with_enable_dphyp(settings.get_enable_dphyp()?)
+            .with_sample_executor(self.sample_executor.clone());
+        optimize(opt_ctx, plan).await
+    }
+
+    /// Plan a serialized Substrait plan. The plan is bound into a `SExpr` directly and then
+    /// optimized like a query.
+    #[async_backtrace::framed]
+    pub async fn plan_substrait(&mut self, plan: &[u8]) -> Result<(Plan, PlanExtras)> {
+        let plan = SubstraitConsumer::decode_plan(plan)?;
+        // There is no SQL text of the plan to log.
+        self.ctx.attach_query_str(QueryKind::Query, String::new());
+        let (mut s_expr, bind_context, metadata) =
+            SubstraitConsumer::bind(self.ctx.clone(), &plan).await?;
+
+        // Limit the result rows like `add_max_rows_limit` does for SQL queries.
+        let max_rows = self.ctx.get_settings().get_max_result_rows()?;
+        if max_rows > 0 && !matches!(s_expr.plan(), RelOperator::Limit(_)) {
+            let limit = Limit {
+                before_exchange: false,
+                limit: Some(max_rows as usize),
+                offset: 0,
+            };
+            s_expr = SExpr::create_unary(Arc::new(limit.into()), Arc::new(s_expr));
+        }
+        let plan = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: metadata.clone(),
+            bind_context: Box::new(bind_context),
+            rewrite_kind: None,
+            formatted_ast: None,
+            ignore_result: false,
+        };
+        let optimized_plan = self.optimize_plan(plan, metadata).await?;
+        Ok((optimized_plan, PlanExtras {
+            format: None,
+            statement: None,
+        }))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -290,6 +542,24 @@ impl Planner {
         }
     }
 
+    /// Replace `EXECUTE name USING ...` with the prepared statement bound with the parameters
+    /// as literals. It's planned as an ordinary statement, and only shares the plan cache
+    /// with the executions of the same values.
+    fn resolve_prepared_stmt(&self, stmt: &mut Statement) -> Result<()> {
+        match stmt {
+            Statement::Execute(execute) => {
+                let name_resolution_ctx =
+                    NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
+                *stmt = resolve_execute(self.ctx.as_ref(), &name_resolution_ctx, execute)?;
+                Ok(())
+            }
+            Statement::Explain { query, .. } | Statement::ExplainAnalyze { query, .. } => {
+                self.resolve_prepared_stmt(query)
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn replace_stmt(&self, stmt: &mut Statement) -> Result<()> {
         let name_resolution_ctx =
             NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
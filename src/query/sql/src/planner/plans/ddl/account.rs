@@ -95,6 +95,14 @@ pub struct DropRolePlan {
     pub role_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterRolePlan {
+    pub if_exists: bool,
+    pub role_name: String,
+    /// `None` unsets the workload group of the role.
+    pub workload_group: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GrantRolePlan {
     pub principal: PrincipalIdentity,
@@ -279,3 +287,53 @@ impl DescPasswordPolicyPlan {
         ])
     }
 }
+
+/// The quotas set by `CREATE/ALTER WORKLOAD GROUP`, `None` if not specified.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkloadGroupOptions {
+    pub cpu_quota: Option<u64>,
+    pub memory_quota: Option<u64>,
+    pub max_concurrency: Option<u64>,
+    pub queue_timeout_secs: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateWorkloadGroupPlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl CreateWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl AlterWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
+
+impl DropWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
@@ -38,6 +38,7 @@ mod mutation_source;
 mod operator;
 mod optimize;
 mod plan;
+mod prepare;
 mod presign;
 mod project_set;
 mod r_cte_scan;
@@ -85,6 +86,7 @@ pub use mutation_source::MutationSource;
 pub use operator::*;
 pub use optimize::*;
 pub use plan::*;
+pub use prepare::*;
 pub use presign::*;
 pub use project_set::*;
 pub use r_cte_scan::*;
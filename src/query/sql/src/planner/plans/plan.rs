@@ -34,12 +34,14 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallProcedurePlan;
 use crate::plans::CopyIntoTableMode;
@@ -66,6 +68,8 @@ use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DeallocatePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
@@ -98,6 +102,7 @@ use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::Exchange;
 use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
@@ -111,6 +116,7 @@ use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
+use crate::plans::PreparePlan;
 use crate::plans::PresignPlan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshTableIndexPlan;
@@ -283,6 +289,7 @@ pub enum Plan {
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
@@ -334,6 +341,11 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -371,6 +383,10 @@ pub enum Plan {
     CreateDictionary(Box<CreateDictionaryPlan>),
     DropDictionary(Box<DropDictionaryPlan>),
     ShowCreateDictionary(Box<ShowCreateDictionaryPlan>),
+
+    // Prepared statements
+    Prepare(Box<PreparePlan>),
+    Deallocate(Box<DeallocatePlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -403,6 +419,7 @@ pub enum RewriteKind {
     ListStage,
     ShowRoles,
     ShowPasswordPolicies,
+    ShowWorkloadGroups,
     ShowGrants,
 
     Call,
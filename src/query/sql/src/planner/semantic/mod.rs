@@ -20,6 +20,7 @@ mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod placeholder;
 mod type_check;
 mod udf_rewriter;
 mod view_rewriter;
@@ -41,6 +42,10 @@ pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
 pub use name_resolution::NameResolutionSuggest;
 pub use name_resolution::VariableNormalizer;
+pub use placeholder::bind_placeholders;
+pub use placeholder::count_placeholders;
+pub(crate) use placeholder::placeholder_spans;
+pub(crate) use placeholder::rebind_placeholders;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
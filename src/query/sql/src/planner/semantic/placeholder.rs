@@ -0,0 +1,269 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::PLACEHOLDER;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+
+use crate::optimizer::SExpr;
+use crate::plans::AsyncFunctionCall;
+use crate::plans::ConstantExpr;
+use crate::plans::LambdaFunc;
+use crate::plans::RelOperator;
+use crate::plans::SubqueryExpr;
+use crate::plans::VisitorMut as ScalarVisitorMut;
+use crate::plans::WindowFunc;
+
+/// Collect the spans of the `?` placeholders in a statement.
+#[derive(Visitor)]
+#[visitor(Expr(enter))]
+struct PlaceholderCollector {
+    spans: Vec<Span>,
+}
+
+impl PlaceholderCollector {
+    fn enter_expr(&mut self, expr: &Expr) {
+        if let Expr::Hole { span, name } = expr {
+            if name == PLACEHOLDER {
+                self.spans.push(*span);
+            }
+        }
+    }
+}
+
+/// Replace the `?` placeholders with the parameters, the placeholders are numbered
+/// by their position in the SQL text.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct PlaceholderReplacer<'a> {
+    spans: &'a [Span],
+    params: &'a [Expr],
+    error: Option<ErrorCode>,
+}
+
+impl PlaceholderReplacer<'_> {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Hole { span, name } = expr {
+            if name == PLACEHOLDER {
+                match self.spans.binary_search(span) {
+                    Ok(index) => *expr = self.params[index].clone(),
+                    Err(_) => {
+                        self.error = Some(ErrorCode::SemanticError(
+                            "placeholder `?` is not found in the prepared statement",
+                        ))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Return the number of `?` placeholders in a statement.
+pub fn count_placeholders(stmt: &Statement) -> usize {
+    let mut collector = PlaceholderCollector { spans: vec![] };
+    stmt.drive(&mut collector);
+    collector.spans.len()
+}
+
+/// Return the spans of the `?` placeholders in a statement, in the order of the SQL text.
+pub(crate) fn placeholder_spans(stmt: &Statement) -> Result<Vec<Span>> {
+    let mut collector = PlaceholderCollector { spans: vec![] };
+    stmt.drive(&mut collector);
+    let mut spans = collector.spans;
+    spans.sort();
+    // The placeholders are numbered by their spans, so they must be known and distinct.
+    if spans.iter().any(|span| span.is_none()) || spans.windows(2).any(|w| w[0] == w[1]) {
+        return Err(ErrorCode::SemanticError(
+            "placeholders `?` of a prepared statement must have distinct positions",
+        ));
+    }
+    Ok(spans)
+}
+
+/// Bind the parameters to the `?` placeholders of a prepared statement.
+pub fn bind_placeholders(stmt: &Statement, params: &[Expr]) -> Result<Statement> {
+    let spans = placeholder_spans(stmt)?;
+    if spans.len() != params.len() {
+        return Err(ErrorCode::BadArguments(format!(
+            "prepared statement expects {} parameters, but got {}",
+            spans.len(),
+            params.len()
+        )));
+    }
+
+    let mut stmt = stmt.clone();
+    let mut replacer = PlaceholderReplacer {
+        spans: &spans,
+        params,
+        error: None,
+    };
+    stmt.drive_mut(&mut replacer);
+    match replacer.error {
+        Some(error) => Err(error),
+        None => Ok(stmt),
+    }
+}
+
+/// Replace the values of the placeholders in a plan bound with the `bound` values.
+///
+/// Return `None` if the plan can't be rebound, which is the case when a placeholder
+/// is not kept as a constant of its bound value, e.g. it's folded with other constants,
+/// or it's in an operator whose expressions are not rewritten here.
+pub(crate) fn rebind_placeholders(
+    s_expr: &SExpr,
+    bound: &[(Span, Scalar)],
+    values: &[Scalar],
+) -> Result<Option<SExpr>> {
+    let mut rebinder = PlaceholderRebinder {
+        bound,
+        values,
+        found: vec![false; bound.len()],
+        supported: true,
+    };
+    let s_expr = rebinder.rebind(s_expr)?;
+    Ok((rebinder.supported && rebinder.found.iter().all(|found| *found)).then_some(s_expr))
+}
+
+struct PlaceholderRebinder<'a> {
+    bound: &'a [(Span, Scalar)],
+    values: &'a [Scalar],
+    found: Vec<bool>,
+    supported: bool,
+}
+
+impl PlaceholderRebinder<'_> {
+    fn rebind(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut plan = s_expr.plan().clone();
+        match &mut plan {
+            RelOperator::Scan(scan) => {
+                if scan.agg_index.is_some() || scan.inverted_index.is_some() {
+                    self.supported = false;
+                }
+                for predicate in scan.push_down_predicates.iter_mut().flatten() {
+                    self.visit(predicate)?;
+                }
+                if let Some(prewhere) = &mut scan.prewhere {
+                    for predicate in prewhere.predicates.iter_mut() {
+                        self.visit(predicate)?;
+                    }
+                }
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                for item in eval_scalar.items.iter_mut() {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter_mut() {
+                    self.visit(predicate)?;
+                }
+            }
+            RelOperator::Join(join) => {
+                for condition in join.equi_conditions.iter_mut() {
+                    self.visit(&mut condition.left)?;
+                    self.visit(&mut condition.right)?;
+                }
+                for condition in join.non_equi_conditions.iter_mut() {
+                    self.visit(condition)?;
+                }
+            }
+            RelOperator::Aggregate(aggregate) => {
+                for item in aggregate
+                    .group_items
+                    .iter_mut()
+                    .chain(aggregate.aggregate_functions.iter_mut())
+                {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::Sort(sort) => {
+                for item in sort.window_partition.iter_mut() {
+                    self.visit(&mut item.scalar)?;
+                }
+            }
+            RelOperator::UnionAll(union_all) => {
+                for (_, scalar) in union_all
+                    .left_outputs
+                    .iter_mut()
+                    .chain(union_all.right_outputs.iter_mut())
+                {
+                    if let Some(scalar) = scalar {
+                        self.visit(scalar)?;
+                    }
+                }
+            }
+            RelOperator::Limit(_) | RelOperator::DummyTableScan(_) => {}
+            _ => self.supported = false,
+        }
+
+        let children = s_expr
+            .children()
+            .map(|child| Ok(Arc::new(self.rebind(child)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr
+            .replace_plan(Arc::new(plan))
+            .replace_children(children))
+    }
+}
+
+impl<'a> ScalarVisitorMut<'a> for PlaceholderRebinder<'_> {
+    fn visit_constant_expr(&mut self, constant: &'a mut ConstantExpr) -> Result<()> {
+        if let Some(index) = self
+            .bound
+            .iter()
+            .position(|(span, _)| *span == constant.span)
+        {
+            if constant.value != self.bound[index].1 {
+                self.supported = false;
+            }
+            self.found[index] = true;
+            constant.value = self.values[index].clone();
+        }
+        Ok(())
+    }
+
+    fn visit_subquery_expr(&mut self, subquery: &'a mut SubqueryExpr) -> Result<()> {
+        if let Some(child_expr) = subquery.child_expr.as_mut() {
+            self.visit(child_expr)?;
+        }
+        subquery.subquery = Box::new(self.rebind(&subquery.subquery)?);
+        Ok(())
+    }
+
+    // The body of a lambda function is compiled, its constants can't be rewritten.
+    fn visit_lambda_function(&mut self, _lambda: &'a mut LambdaFunc) -> Result<()> {
+        self.supported = false;
+        Ok(())
+    }
+
+    fn visit_window_function(&mut self, _window: &'a mut WindowFunc) -> Result<()> {
+        self.supported = false;
+        Ok(())
+    }
+
+    fn visit_async_function_call(&mut self, _async_func: &'a mut AsyncFunctionCall) -> Result<()> {
+        self.supported = false;
+        Ok(())
+    }
+}
@@ -42,6 +42,7 @@ use databend_common_ast::ast::Window;
 use databend_common_ast::ast::WindowFrame;
 use databend_common_ast::ast::WindowFrameBound;
 use databend_common_ast::ast::WindowFrameUnits;
+use databend_common_ast::ast::PLACEHOLDER;
 use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
@@ -67,8 +68,11 @@ use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::decimal::MAX_DECIMAL128_PRECISION;
 use databend_common_expression::types::decimal::MAX_DECIMAL256_PRECISION;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::EnumType;
+use databend_common_expression::types::LargeIntDataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::VectorType;
 use databend_common_expression::types::F32;
 use databend_common_expression::ColumnIndex;
 use databend_common_expression::ConstantFolder;
@@ -896,7 +900,24 @@ impl<'a> TypeChecker<'a> {
                     .unwrap_or(false)
                 {
                     // Set returning function
-                    self.resolve_set_returning_function(*span, func_name, &args)?
+                    let mut new_params: Vec<Scalar> = Vec::with_capacity(params.len());
+                    for param in params {
+                        let box (scalar, _data_type) = self.resolve(param)?;
+                        let expr = scalar.as_expr()?;
+                        let (expr, _) =
+                            ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        let constant = expr
+                            .into_constant()
+                            .map_err(|_| {
+                                ErrorCode::SemanticError(format!(
+                                    "invalid parameter {param} for set-returning function, expected constant",
+                                ))
+                                .set_span(*span)
+                            })?
+                            .1;
+                        new_params.push(constant);
+                    }
+                    self.resolve_set_returning_function(*span, func_name, new_params, &args)?
                 } else {
                     // Scalar function
                     let mut new_params: Vec<Scalar> = Vec::with_capacity(params.len());
@@ -1075,6 +1096,17 @@ impl<'a> TypeChecker<'a> {
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs)?,
 
+            Expr::Hole { span, name } if name == PLACEHOLDER => {
+                // The values of a prepared statement are bound by the planner.
+                let Some(value) = self.metadata.read().placeholder_value(*span).cloned() else {
+                    return Err(ErrorCode::SemanticError(
+                        "placeholder `?` is only allowed in prepared statements".to_string(),
+                    )
+                    .set_span(*span));
+                };
+                let data_type = value.as_ref().infer_data_type();
+                (ConstantExpr { span: *span, value }.into(), data_type)
+            }
             Expr::Hole { .. } => unreachable!("hole is impossible in trivial query"),
         };
 
@@ -2528,6 +2560,7 @@ impl<'a> TypeChecker<'a> {
         &mut self,
         span: Span,
         func_name: &str,
+        params: Vec<Scalar>,
         args: &[&Expr],
     ) -> Result<Box<(ScalarExpr, DataType)>> {
         if matches!(
@@ -2568,7 +2601,7 @@ impl<'a> TypeChecker<'a> {
         let srf_scalar = ScalarExpr::FunctionCall(FunctionCall {
             span,
             func_name: func_name.to_string(),
-            params: vec![],
+            params,
             arguments,
         });
         let srf_expr = srf_scalar.as_expr()?;
@@ -4790,6 +4823,10 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Int16 => TableDataType::Number(NumberDataType::Int16),
         TypeName::Int32 => TableDataType::Number(NumberDataType::Int32),
         TypeName::Int64 => TableDataType::Number(NumberDataType::Int64),
+        TypeName::Int128 => TableDataType::LargeInt(LargeIntDataType::Int128),
+        TypeName::UInt128 => TableDataType::LargeInt(LargeIntDataType::UInt128),
+        TypeName::Int256 => TableDataType::LargeInt(LargeIntDataType::Int256),
+        TypeName::UInt256 => TableDataType::LargeInt(LargeIntDataType::UInt256),
         TypeName::Float32 => TableDataType::Number(NumberDataType::Float32),
         TypeName::Float64 => TableDataType::Number(NumberDataType::Float64),
         TypeName::Decimal { precision, scale } => {
@@ -4861,6 +4898,18 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
         TypeName::Geography => TableDataType::Geography,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::Time => TableDataType::Time,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
+        TypeName::Vector(dimension) => {
+            VectorType::check_dimension(*dimension)?;
+            TableDataType::Vector(*dimension)
+        }
+        TypeName::Enum(values) => {
+            EnumType::check_values(values)?;
+            TableDataType::Enum(values.clone().into())
+        }
+        TypeName::Uuid => TableDataType::Uuid,
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -0,0 +1,1143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalScalar;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::F32;
+use databend_common_expression::types::F64;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use parking_lot::RwLock;
+use prost::Message;
+use substrait::proto::aggregate_function::AggregationInvocation;
+use substrait::proto::expression::field_reference::ReferenceType;
+use substrait::proto::expression::literal::LiteralType;
+use substrait::proto::expression::reference_segment;
+use substrait::proto::expression::Literal;
+use substrait::proto::expression::RexType;
+use substrait::proto::extensions::simple_extension_declaration::MappingType;
+use substrait::proto::function_argument::ArgType;
+use substrait::proto::plan_rel;
+use substrait::proto::read_rel::ReadType;
+use substrait::proto::rel::RelType;
+use substrait::proto::rel_common::EmitKind;
+use substrait::proto::sort_field::SortDirection;
+use substrait::proto::sort_field::SortKind;
+use substrait::proto::r#type::Kind;
+use substrait::proto::r#type::Nullability;
+use substrait::proto::AggregateRel;
+use substrait::proto::Expression;
+use substrait::proto::FunctionArgument;
+use substrait::proto::JoinRel;
+use substrait::proto::Plan;
+use substrait::proto::ProjectRel;
+use substrait::proto::ReadRel;
+use substrait::proto::Rel;
+use substrait::proto::RelCommon;
+use substrait::proto::SortRel;
+use substrait::proto::Type;
+
+use crate::binder::split_conjunctions;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinEquiCondition;
+use crate::plans::JoinType;
+use crate::plans::Limit;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::plans::Statistics;
+use crate::BindContext;
+use crate::ColumnBinding;
+use crate::ColumnBindingBuilder;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::Visibility;
+
+// Values of `JoinRel.type`, matched numerically because the variant names differ between
+// Substrait releases (e.g. `SEMI` was renamed to `LEFT_SEMI`).
+const JOIN_TYPE_INNER: i32 = 1;
+const JOIN_TYPE_OUTER: i32 = 2;
+const JOIN_TYPE_LEFT: i32 = 3;
+const JOIN_TYPE_RIGHT: i32 = 4;
+const JOIN_TYPE_LEFT_SEMI: i32 = 5;
+const JOIN_TYPE_LEFT_ANTI: i32 = 6;
+const JOIN_TYPE_RIGHT_SEMI: i32 = 8;
+const JOIN_TYPE_RIGHT_ANTI: i32 = 9;
+
+// `Cast.failure_behavior` of `FAILURE_BEHAVIOR_RETURN_NULL`.
+const CAST_FAILURE_RETURN_NULL: i32 = 1;
+
+/// Bind a Substrait plan into a query `SExpr`.
+///
+/// The output columns of every relation are tracked as a list of column bindings, so that
+/// positional field references can be turned into column references, the other way round
+/// of the producer.
+pub struct SubstraitConsumer {
+    metadata: MetadataRef,
+    // function anchor => function name, without the signature suffix.
+    functions: HashMap<u32, String>,
+    // names of a named table => (catalog, database, table)
+    tables: HashMap<Vec<String>, (String, String, Arc<dyn Table>)>,
+}
+
+impl SubstraitConsumer {
+    pub fn decode_plan(bytes: &[u8]) -> Result<Plan> {
+        Plan::decode(bytes).map_err(|e| ErrorCode::BadBytes(format!("invalid substrait plan: {e}")))
+    }
+
+    /// Bind the plan, the tables it reads are resolved with `ctx`. Return the unoptimized
+    /// `SExpr` and the bind context of its result columns.
+    #[async_backtrace::framed]
+    pub async fn bind(
+        ctx: Arc<dyn TableContext>,
+        plan: &Plan,
+    ) -> Result<(SExpr, BindContext, MetadataRef)> {
+        let functions = plan
+            .extensions
+            .iter()
+            .filter_map(|ext| match &ext.mapping_type {
+                Some(MappingType::ExtensionFunction(f)) => Some((
+                    f.function_anchor,
+                    f.name.split(':').next().unwrap_or_default().to_lowercase(),
+                )),
+                _ => None,
+            })
+            .collect();
+        let mut consumer = SubstraitConsumer {
+            metadata: Arc::new(RwLock::new(Metadata::default())),
+            functions,
+            tables: HashMap::new(),
+        };
+
+        let relation = match plan.relations.as_slice() {
+            [rel] => rel,
+            [] => return Err(ErrorCode::BadArguments("substrait plan has no relation")),
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "substrait plan with multiple relations is not supported",
+                ));
+            }
+        };
+        let (input, names) = match &relation.rel_type {
+            Some(plan_rel::RelType::Root(root)) => {
+                let input = root
+                    .input
+                    .as_ref()
+                    .ok_or_else(|| ErrorCode::BadArguments("substrait root has no input"))?;
+                (input, root.names.as_slice())
+            }
+            Some(plan_rel::RelType::Rel(rel)) => (rel, &[] as &[String]),
+            None => return Err(ErrorCode::BadArguments("substrait plan relation is empty")),
+        };
+
+        consumer.resolve_tables(ctx.as_ref(), input).await?;
+        let (s_expr, outputs) = consumer.rel(input)?;
+        if !names.is_empty() && names.len() < outputs.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "substrait root has {} names but the relation has {} columns",
+                names.len(),
+                outputs.len()
+            )));
+        }
+
+        let mut bind_context = BindContext::new();
+        for (i, mut column) in outputs.into_iter().enumerate() {
+            if let Some(name) = names.get(i) {
+                column.column_name = name.clone();
+            }
+            bind_context.add_column_binding(column);
+        }
+        Ok((s_expr, bind_context, consumer.metadata))
+    }
+
+    /// Resolve the named tables read by the relation, so that the binding doesn't need to
+    /// be async.
+    async fn resolve_tables(&mut self, ctx: &dyn TableContext, rel: &Rel) -> Result<()> {
+        let mut reads = vec![];
+        named_tables(rel, &mut reads);
+        for names in reads {
+            if self.tables.contains_key(names) {
+                continue;
+            }
+            let (catalog, database, table) = match names {
+                [table] => (ctx.get_current_catalog(), ctx.get_current_database(), table),
+                [database, table] => (ctx.get_current_catalog(), database.clone(), table),
+                [catalog, database, table] => (catalog.clone(), database.clone(), table),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid substrait table name '{}'",
+                        names.join(".")
+                    )));
+                }
+            };
+            let table = ctx.get_table(&catalog, &database, table).await?;
+            self.tables
+                .insert(names.to_vec(), (catalog, database, table));
+        }
+        Ok(())
+    }
+
+    fn rel(&mut self, rel: &Rel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let rel_type = rel
+            .rel_type
+            .as_ref()
+            .ok_or_else(|| ErrorCode::BadArguments("substrait relation is empty"))?;
+        let ((s_expr, outputs), common) = match rel_type {
+            RelType::Read(read) => (self.read(read)?, &read.common),
+            RelType::Filter(filter) => {
+                let (input, outputs) = self.rel(required(&filter.input, "filter input")?)?;
+                let condition =
+                    self.scalar(required(&filter.condition, "filter condition")?, &outputs)?;
+                (
+                    (filter_s_expr(input, vec![condition]), outputs),
+                    &filter.common,
+                )
+            }
+            RelType::Project(project) => (self.project(project)?, &project.common),
+            RelType::Aggregate(aggregate) => (self.aggregate(aggregate)?, &aggregate.common),
+            RelType::Sort(sort) => (self.sort(sort)?, &sort.common),
+            RelType::Fetch(fetch) => {
+                let (input, outputs) = self.rel(required(&fetch.input, "fetch input")?)?;
+                let limit = Limit {
+                    before_exchange: false,
+                    // A negative count means all the rows.
+                    limit: usize::try_from(fetch.count).ok(),
+                    offset: usize::try_from(fetch.offset).unwrap_or(0),
+                };
+                let s_expr = SExpr::create_unary(Arc::new(limit.into()), Arc::new(input));
+                ((s_expr, outputs), &fetch.common)
+            }
+            RelType::Join(join) => (self.join(join)?, &join.common),
+            RelType::Cross(cross) => {
+                let (left, mut outputs) = self.rel(required(&cross.left, "cross left")?)?;
+                let (right, right_outputs) = self.rel(required(&cross.right, "cross right")?)?;
+                outputs.extend(right_outputs);
+                let join = Join {
+                    join_type: JoinType::Cross,
+                    ..Default::default()
+                };
+                let s_expr =
+                    SExpr::create_binary(Arc::new(join.into()), Arc::new(left), Arc::new(right));
+                ((s_expr, outputs), &cross.common)
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "substrait relation {} is not supported",
+                    rel_type_name(rel_type)
+                )));
+            }
+        };
+        Ok((s_expr, emit(outputs, common)?))
+    }
+
+    fn read(&mut self, read: &ReadRel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let (catalog, database, table) = match &read.read_type {
+            Some(ReadType::NamedTable(table)) => {
+                self.tables.get(&table.names).cloned().ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "substrait table '{}' is not resolved",
+                        table.names.join(".")
+                    ))
+                })?
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "only named table reads are supported in substrait plans",
+                ));
+            }
+        };
+        let table_name = table.name().to_string();
+        let table_index = self.metadata.write().add_table(
+            catalog,
+            database.clone(),
+            table,
+            None,
+            false,
+            false,
+            false,
+            false,
+        );
+
+        // The base schema is in the order of the field references, it may be a subset of
+        // the table columns.
+        let schema = required(&read.base_schema, "read base schema")?;
+        let table_columns = self.metadata.read().columns_by_table_index(table_index);
+        let mut columns = Vec::with_capacity(table_columns.len());
+        for name in top_level_names(schema)? {
+            let column = table_columns
+                .iter()
+                .find_map(|column| match column {
+                    ColumnEntry::BaseTableColumn(column)
+                        if column.path_indices.is_none() && column.column_name == name =>
+                    {
+                        Some(column)
+                    }
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "column {name} of the substrait base schema is not in table {table_name}"
+                    ))
+                })?;
+            let binding = ColumnBindingBuilder::new(
+                column.column_name.clone(),
+                column.column_index,
+                Box::new(DataType::from(&column.data_type)),
+                Visibility::Visible,
+            )
+            .table_name(Some(table_name.clone()))
+            .database_name(Some(database.clone()))
+            .table_index(Some(table_index))
+            .column_position(column.column_position)
+            .build();
+            columns.push(binding);
+        }
+
+        let scan = Scan {
+            table_index,
+            columns: columns.iter().map(|c| c.index).collect(),
+            statistics: Arc::new(Statistics::default()),
+            ..Default::default()
+        };
+        let predicates = [&read.filter, &read.best_effort_filter]
+            .into_iter()
+            .flatten()
+            .map(|f| self.scalar(f, &columns))
+            .collect::<Result<Vec<_>>>()?;
+        let s_expr = filter_s_expr(SExpr::create_leaf(Arc::new(scan.into())), predicates);
+
+        let outputs = match read.projection.as_ref().and_then(|p| p.select.as_ref()) {
+            Some(select) => select
+                .struct_items
+                .iter()
+                .map(|item| column_at(&columns, item.field).cloned())
+                .collect::<Result<Vec<_>>>()?,
+            None => columns,
+        };
+        Ok((s_expr, outputs))
+    }
+
+    fn project(&mut self, project: &ProjectRel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let (input, mut outputs) = self.rel(required(&project.input, "project input")?)?;
+        if project.expressions.is_empty() {
+            return Ok((input, outputs));
+        }
+        // The expressions only refer to the input columns, not to each other.
+        let scalars = project
+            .expressions
+            .iter()
+            .map(|expr| self.scalar(expr, &outputs))
+            .collect::<Result<Vec<_>>>()?;
+        let mut items = Vec::with_capacity(scalars.len());
+        for scalar in scalars {
+            let column = self.derived_column(format!("_{}", outputs.len()), &scalar)?;
+            items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            outputs.push(column);
+        }
+        let eval_scalar = EvalScalar { items };
+        let s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(input));
+        Ok((s_expr, outputs))
+    }
+
+    fn aggregate(&mut self, aggregate: &AggregateRel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let (input, input_outputs) = self.rel(required(&aggregate.input, "aggregate input")?)?;
+        let grouping_expressions: &[Expression] = match aggregate.groupings.as_slice() {
+            [] => &[],
+            [grouping] => &grouping.grouping_expressions,
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "grouping sets are not supported in substrait plans",
+                ));
+            }
+        };
+
+        // The output is the grouping expressions followed by the measures. Like the binder,
+        // the group items and the arguments of the aggregate functions are evaluated by an
+        // `EvalScalar` below the aggregate.
+        let mut outputs = Vec::with_capacity(grouping_expressions.len() + aggregate.measures.len());
+        let mut scalar_items: Vec<ScalarItem> = vec![];
+        let mut group_items = Vec::with_capacity(grouping_expressions.len());
+        for expr in grouping_expressions.iter() {
+            let scalar = self.scalar(expr, &input_outputs)?;
+            let column = self.evaluated_column(
+                format!("_{}", outputs.len()),
+                scalar.clone(),
+                &mut scalar_items,
+            )?;
+            group_items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            outputs.push(column);
+        }
+
+        let mut aggregate_functions = Vec::with_capacity(aggregate.measures.len());
+        for measure in aggregate.measures.iter() {
+            let func = required(&measure.measure, "aggregate measure")?;
+            let mut name =
+                aggregate_name(&self.function_name(func.function_reference)?).to_string();
+            let mut args = self.arguments(&func.arguments, &input_outputs)?;
+            if let Some(filter) = &measure.filter {
+                args.push(self.scalar(filter, &input_outputs)?);
+                name = format!("{name}_if");
+            }
+            // Rewrite `xxx(DISTINCT ...)` to `xxx_distinct(...)` like the type checker.
+            let distinct = func.invocation == AggregationInvocation::Distinct as i32;
+            let func_name = if distinct && name == "count" {
+                "count_distinct".to_string()
+            } else if distinct {
+                format!("{name}_distinct")
+            } else {
+                name
+            };
+
+            let mut arg_refs = Vec::with_capacity(args.len());
+            for (i, arg) in args.into_iter().enumerate() {
+                let column = self.evaluated_column(
+                    format!("_{}_arg_{i}", outputs.len()),
+                    arg,
+                    &mut scalar_items,
+                )?;
+                arg_refs.push(column_ref(&column));
+            }
+            let arg_types = arg_refs
+                .iter()
+                .map(|arg| arg.data_type())
+                .collect::<Result<Vec<_>>>()?;
+            let return_type = AggregateFunctionFactory::instance()
+                .get(&func_name, vec![], arg_types)?
+                .return_type()?;
+            let scalar: ScalarExpr = AggregateFunction {
+                span: None,
+                func_name: func_name.clone(),
+                distinct: false,
+                params: vec![],
+                args: arg_refs,
+                return_type: Box::new(return_type),
+                display_name: func_name,
+            }
+            .into();
+            let column = self.derived_column(format!("_{}", outputs.len()), &scalar)?;
+            aggregate_functions.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            outputs.push(column);
+        }
+
+        let mut s_expr = input;
+        if !scalar_items.is_empty() {
+            scalar_items.sort_by_key(|item| item.index);
+            let eval_scalar = EvalScalar {
+                items: scalar_items,
+            };
+            s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        }
+        let aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items,
+            aggregate_functions,
+            from_distinct: false,
+            rank_limit: None,
+            grouping_sets: None,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(aggregate.into()), Arc::new(s_expr));
+        Ok((s_expr, outputs))
+    }
+
+    fn sort(&mut self, sort: &SortRel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let (input, outputs) = self.rel(required(&sort.input, "sort input")?)?;
+        // Sort keys which are not columns are evaluated below the sort.
+        let mut scalar_items = vec![];
+        let mut items = Vec::with_capacity(sort.sorts.len());
+        for field in sort.sorts.iter() {
+            let scalar = self.scalar(required(&field.expr, "sort expression")?, &outputs)?;
+            let index = match &scalar {
+                ScalarExpr::BoundColumnRef(column) => column.column.index,
+                _ => {
+                    let column = self.derived_column(format!("_sort_{}", items.len()), &scalar)?;
+                    scalar_items.push(ScalarItem {
+                        scalar,
+                        index: column.index,
+                    });
+                    column.index
+                }
+            };
+            let (asc, nulls_first) = match field.sort_kind {
+                Some(SortKind::Direction(d)) if d == SortDirection::AscNullsFirst as i32 => {
+                    (true, true)
+                }
+                Some(SortKind::Direction(d)) if d == SortDirection::AscNullsLast as i32 => {
+                    (true, false)
+                }
+                Some(SortKind::Direction(d)) if d == SortDirection::DescNullsFirst as i32 => {
+                    (false, true)
+                }
+                Some(SortKind::Direction(d)) if d == SortDirection::DescNullsLast as i32 => {
+                    (false, false)
+                }
+                _ => {
+                    return Err(ErrorCode::Unimplemented(
+                        "only ordered sort directions are supported in substrait plans",
+                    ));
+                }
+            };
+            items.push(SortItem {
+                index,
+                asc,
+                nulls_first,
+            });
+        }
+
+        let mut s_expr = input;
+        if !scalar_items.is_empty() {
+            let eval_scalar = EvalScalar {
+                items: scalar_items,
+            };
+            s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        }
+        let sort = Sort {
+            items,
+            limit: None,
+            after_exchange: None,
+            pre_projection: None,
+            window_partition: vec![],
+        };
+        let s_expr = SExpr::create_unary(Arc::new(sort.into()), Arc::new(s_expr));
+        Ok((s_expr, outputs))
+    }
+
+    fn join(&mut self, join: &JoinRel) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        let (left, left_outputs) = self.rel(required(&join.left, "join left")?)?;
+        let (right, right_outputs) = self.rel(required(&join.right, "join right")?)?;
+        let all_outputs = left_outputs
+            .iter()
+            .chain(right_outputs.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // The columns of the outer side of an outer join become nullable, like the binder.
+        let (join_type, outputs) = match join.r#type {
+            JOIN_TYPE_INNER => (JoinType::Inner, all_outputs.clone()),
+            JOIN_TYPE_OUTER => (
+                JoinType::Full,
+                [nullable(&left_outputs), nullable(&right_outputs)].concat(),
+            ),
+            JOIN_TYPE_LEFT => (
+                JoinType::Left,
+                [left_outputs.clone(), nullable(&right_outputs)].concat(),
+            ),
+            JOIN_TYPE_RIGHT => (
+                JoinType::Right,
+                [nullable(&left_outputs), right_outputs.clone()].concat(),
+            ),
+            JOIN_TYPE_LEFT_SEMI => (JoinType::LeftSemi, left_outputs.clone()),
+            JOIN_TYPE_LEFT_ANTI => (JoinType::LeftAnti, left_outputs.clone()),
+            JOIN_TYPE_RIGHT_SEMI => (JoinType::RightSemi, right_outputs.clone()),
+            JOIN_TYPE_RIGHT_ANTI => (JoinType::RightAnti, right_outputs.clone()),
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "substrait join type {other} is not supported"
+                )));
+            }
+        };
+
+        let mut conjuncts = vec![];
+        match &join.expression {
+            Some(expr) => self.conjuncts(expr, &mut conjuncts),
+            None if join_type == JoinType::Inner => {}
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "substrait {join_type:?} join requires a join expression"
+                )));
+            }
+        }
+        let left_columns = left_outputs.iter().map(|c| c.index).collect::<ColumnSet>();
+        let right_columns = right_outputs.iter().map(|c| c.index).collect::<ColumnSet>();
+        let mut equi_conditions = vec![];
+        let mut non_equi_conditions = vec![];
+        for expr in conjuncts {
+            match self.equi_condition(expr, &all_outputs, &left_columns, &right_columns)? {
+                Some(condition) => equi_conditions.push(condition),
+                None => non_equi_conditions.push(self.scalar(expr, &all_outputs)?),
+            }
+        }
+        let join_type = if join_type == JoinType::Inner
+            && equi_conditions.is_empty()
+            && non_equi_conditions.is_empty()
+        {
+            JoinType::Cross
+        } else {
+            join_type
+        };
+
+        let join_plan = Join {
+            equi_conditions,
+            non_equi_conditions,
+            join_type,
+            ..Default::default()
+        };
+        let s_expr =
+            SExpr::create_binary(Arc::new(join_plan.into()), Arc::new(left), Arc::new(right));
+        let predicates = match &join.post_join_filter {
+            Some(filter) => vec![self.scalar(filter, &outputs)?],
+            None => vec![],
+        };
+        Ok((filter_s_expr(s_expr, predicates), outputs))
+    }
+
+    /// Split the conjunctions of a join expression.
+    fn conjuncts<'a>(&self, expr: &'a Expression, conjuncts: &mut Vec<&'a Expression>) {
+        if let Some(RexType::ScalarFunction(func)) = &expr.rex_type {
+            if self
+                .functions
+                .get(&func.function_reference)
+                .is_some_and(|name| name == "and")
+            {
+                let args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| match &arg.arg_type {
+                        Some(ArgType::Value(arg)) => Some(arg),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+                if let Some(args) = args {
+                    for arg in args {
+                        self.conjuncts(arg, conjuncts);
+                    }
+                    return;
+                }
+            }
+        }
+        conjuncts.push(expr);
+    }
+
+    /// Return the equi condition of a conjunction of the join expression, if it compares a
+    /// expression of the left side with a expression of the right side.
+    fn equi_condition(
+        &self,
+        expr: &Expression,
+        inputs: &[ColumnBinding],
+        left_columns: &ColumnSet,
+        right_columns: &ColumnSet,
+    ) -> Result<Option<JoinEquiCondition>> {
+        let Some(RexType::ScalarFunction(func)) = &expr.rex_type else {
+            return Ok(None);
+        };
+        let is_null_equal = match self.functions.get(&func.function_reference) {
+            Some(name) if name == "equal" => false,
+            Some(name) if name == "is_not_distinct_from" => true,
+            _ => return Ok(None),
+        };
+        let args = self.arguments(&func.arguments, inputs)?;
+        let [left, right] = args.as_slice() else {
+            return Ok(None);
+        };
+        let side_of = |scalar: &ScalarExpr| {
+            let columns = scalar.used_columns();
+            if columns.is_empty() {
+                None
+            } else if columns.is_subset(left_columns) {
+                Some(true)
+            } else if columns.is_subset(right_columns) {
+                Some(false)
+            } else {
+                None
+            }
+        };
+        Ok(match (side_of(left), side_of(right)) {
+            (Some(true), Some(false)) => Some(JoinEquiCondition::new(
+                left.clone(),
+                right.clone(),
+                is_null_equal,
+            )),
+            (Some(false), Some(true)) => Some(JoinEquiCondition::new(
+                right.clone(),
+                left.clone(),
+                is_null_equal,
+            )),
+            _ => None,
+        })
+    }
+
+    /// Add a derived column computed by the scalar.
+    fn derived_column(&self, name: String, scalar: &ScalarExpr) -> Result<ColumnBinding> {
+        let data_type = scalar.data_type()?;
+        let index = self.metadata.write().add_derived_column(
+            name.clone(),
+            data_type.clone(),
+            Some(scalar.clone()),
+        );
+        Ok(
+            ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+                .build(),
+        )
+    }
+
+    /// Evaluate the scalar by the `EvalScalar` below an aggregate, columns are used as is.
+    fn evaluated_column(
+        &self,
+        name: String,
+        scalar: ScalarExpr,
+        items: &mut Vec<ScalarItem>,
+    ) -> Result<ColumnBinding> {
+        let column = match &scalar {
+            ScalarExpr::BoundColumnRef(column) => column.column.clone(),
+            _ => self.derived_column(name, &scalar)?,
+        };
+        if !items.iter().any(|item| item.index == column.index) {
+            items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+        }
+        Ok(column)
+    }
+
+    fn function_name(&self, anchor: u32) -> Result<String> {
+        self.functions.get(&anchor).cloned().ok_or_else(|| {
+            ErrorCode::BadArguments(format!("unknown substrait function anchor {anchor}"))
+        })
+    }
+
+    fn arguments(
+        &self,
+        args: &[FunctionArgument],
+        inputs: &[ColumnBinding],
+    ) -> Result<Vec<ScalarExpr>> {
+        args.iter()
+            .map(|arg| match &arg.arg_type {
+                Some(ArgType::Value(expr)) => self.scalar(expr, inputs),
+                _ => Err(ErrorCode::Unimplemented(
+                    "only value arguments are supported in substrait functions",
+                )),
+            })
+            .collect()
+    }
+
+    fn scalar(&self, expr: &Expression, inputs: &[ColumnBinding]) -> Result<ScalarExpr> {
+        let rex = expr
+            .rex_type
+            .as_ref()
+            .ok_or_else(|| ErrorCode::BadArguments("substrait expression is empty"))?;
+        let scalar = match rex {
+            RexType::Literal(literal) => return to_scalar(literal),
+            RexType::Selection(field) => {
+                let segment = match &field.reference_type {
+                    Some(ReferenceType::DirectReference(segment)) => segment,
+                    _ => {
+                        return Err(ErrorCode::Unimplemented(
+                            "only direct field references are supported in substrait plans",
+                        ));
+                    }
+                };
+                return match &segment.reference_type {
+                    Some(reference_segment::ReferenceType::StructField(f)) if f.child.is_none() => {
+                        Ok(column_ref(column_at(inputs, f.field)?))
+                    }
+                    _ => Err(ErrorCode::Unimplemented(
+                        "nested field references are not supported in substrait plans",
+                    )),
+                };
+            }
+            RexType::ScalarFunction(func) => {
+                let name = self.function_name(func.function_reference)?;
+                let args = self.arguments(&func.arguments, inputs)?;
+                scalar_function(&name, args)?
+            }
+            RexType::Cast(cast) => CastExpr {
+                span: None,
+                is_try: cast.failure_behavior == CAST_FAILURE_RETURN_NULL,
+                argument: Box::new(self.scalar(required(&cast.input, "cast input")?, inputs)?),
+                target_type: Box::new(to_data_type(required(&cast.r#type, "cast type")?)?),
+            }
+            .into(),
+            RexType::IfThen(if_then) => {
+                let mut args = Vec::with_capacity(if_then.ifs.len() * 2 + 1);
+                for clause in if_then.ifs.iter() {
+                    args.push(self.scalar(required(&clause.r#if, "if condition")?, inputs)?);
+                    args.push(self.scalar(required(&clause.then, "if result")?, inputs)?);
+                }
+                args.push(match &if_then.r#else {
+                    Some(r#else) => self.scalar(r#else, inputs)?,
+                    None => ConstantExpr {
+                        span: None,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                });
+                function_call("if", args)
+            }
+            RexType::SingularOrList(list) => {
+                let value = self.scalar(required(&list.value, "in value")?, inputs)?;
+                let options = list
+                    .options
+                    .iter()
+                    .map(|o| {
+                        Ok(function_call("eq", vec![
+                            value.clone(),
+                            self.scalar(o, inputs)?,
+                        ]))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                scalar_function("or", options)?
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "substrait expression is not supported",
+                ));
+            }
+        };
+        // Type check the function calls, so that an invalid plan fails to bind.
+        scalar.data_type()?;
+        Ok(scalar)
+    }
+}
+
+/// Collect the names of the tables read in the relation.
+fn named_tables<'a>(rel: &'a Rel, names: &mut Vec<&'a [String]>) {
+    let inputs = match &rel.rel_type {
+        Some(RelType::Read(read)) => {
+            if let Some(ReadType::NamedTable(table)) = &read.read_type {
+                names.push(table.names.as_slice());
+            }
+            return;
+        }
+        Some(RelType::Filter(filter)) => vec![&filter.input],
+        Some(RelType::Project(project)) => vec![&project.input],
+        Some(RelType::Aggregate(aggregate)) => vec![&aggregate.input],
+        Some(RelType::Sort(sort)) => vec![&sort.input],
+        Some(RelType::Fetch(fetch)) => vec![&fetch.input],
+        Some(RelType::Join(join)) => vec![&join.left, &join.right],
+        Some(RelType::Cross(cross)) => vec![&cross.left, &cross.right],
+        _ => return,
+    };
+    for input in inputs.into_iter().flatten() {
+        named_tables(input, names);
+    }
+}
+
+/// Apply the emit of a relation, which selects and reorders its output columns.
+fn emit(outputs: Vec<ColumnBinding>, common: &Option<RelCommon>) -> Result<Vec<ColumnBinding>> {
+    match common.as_ref().and_then(|c| c.emit_kind.as_ref()) {
+        Some(EmitKind::Emit(emit)) => emit
+            .output_mapping
+            .iter()
+            .map(|i| column_at(&outputs, *i).cloned())
+            .collect(),
+        _ => Ok(outputs),
+    }
+}
+
+fn filter_s_expr(input: SExpr, predicates: Vec<ScalarExpr>) -> SExpr {
+    let predicates = predicates
+        .iter()
+        .flat_map(split_conjunctions)
+        .collect::<Vec<_>>();
+    if predicates.is_empty() {
+        return input;
+    }
+    let filter = Filter { predicates };
+    SExpr::create_unary(Arc::new(filter.into()), Arc::new(input))
+}
+
+fn nullable(columns: &[ColumnBinding]) -> Vec<ColumnBinding> {
+    columns
+        .iter()
+        .map(|column| {
+            let mut column = column.clone();
+            column.data_type = Box::new(column.data_type.wrap_nullable());
+            column
+        })
+        .collect()
+}
+
+fn required<'a, T>(value: &'a Option<T>, what: &str) -> Result<&'a T> {
+    value
+        .as_ref()
+        .ok_or_else(|| ErrorCode::BadArguments(format!("substrait {what} is missing")))
+}
+
+fn column_at<T>(columns: &[T], index: i32) -> Result<&T> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| columns.get(i))
+        .ok_or_else(|| {
+            ErrorCode::BadArguments(format!("substrait field reference {index} is out of range"))
+        })
+}
+
+fn column_ref(column: &ColumnBinding) -> ScalarExpr {
+    BoundColumnRef {
+        span: None,
+        column: column.clone(),
+    }
+    .into()
+}
+
+fn function_call(name: &str, arguments: Vec<ScalarExpr>) -> ScalarExpr {
+    FunctionCall {
+        span: None,
+        func_name: name.to_string(),
+        params: vec![],
+        arguments,
+    }
+    .into()
+}
+
+/// The names of a `NamedStruct` are in depth-first order, keep the top-level ones.
+fn top_level_names(schema: &substrait::proto::NamedStruct) -> Result<Vec<String>> {
+    let types = match &schema.r#struct {
+        Some(s) => &s.types,
+        None => return Ok(schema.names.clone()),
+    };
+    let mut names = Vec::with_capacity(types.len());
+    let mut pos = 0;
+    for ty in types {
+        let name = schema
+            .names
+            .get(pos)
+            .ok_or_else(|| ErrorCode::BadArguments("substrait base schema names mismatch"))?;
+        names.push(name.clone());
+        pos += 1 + nested_names(ty);
+    }
+    Ok(names)
+}
+
+fn nested_names(ty: &Type) -> usize {
+    match &ty.kind {
+        Some(Kind::Struct(s)) => s.types.iter().map(|t| 1 + nested_names(t)).sum(),
+        Some(Kind::List(l)) => l.r#type.as_deref().map(nested_names).unwrap_or(0),
+        Some(Kind::Map(m)) => {
+            m.key.as_deref().map(nested_names).unwrap_or(0)
+                + m.value.as_deref().map(nested_names).unwrap_or(0)
+        }
+        _ => 0,
+    }
+}
+
+/// Build the Databend function call of a Substrait scalar function.
+fn scalar_function(name: &str, mut args: Vec<ScalarExpr>) -> Result<ScalarExpr> {
+    match name {
+        // `and` and `or` are variadic in Substrait but binary in Databend.
+        "and" | "or" => args
+            .into_iter()
+            .reduce(|l, r| function_call(name, vec![l, r]))
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!("substrait function {name} expects arguments"))
+            }),
+        "between" => {
+            expect_args(name, &args, 3)?;
+            let high = args.pop().unwrap();
+            let low = args.pop().unwrap();
+            let value = args.pop().unwrap();
+            Ok(function_call("and", vec![
+                function_call("gte", vec![value.clone(), low]),
+                function_call("lte", vec![value, high]),
+            ]))
+        }
+        // Rewritten like `IS [NOT] DISTINCT FROM` in the type checker.
+        "is_distinct_from" | "is_not_distinct_from" => {
+            expect_args(name, &args, 2)?;
+            let not = name == "is_not_distinct_from";
+            let right = args.pop().unwrap();
+            let left = args.pop().unwrap();
+            let is_null = |arg: &ScalarExpr| function_call("is_null", vec![arg.clone()]);
+            let boolean = |v: bool| -> ScalarExpr {
+                ConstantExpr {
+                    span: None,
+                    value: Scalar::Boolean(v),
+                }
+                .into()
+            };
+            let scalar = function_call("if", vec![
+                function_call("and", vec![is_null(&left), is_null(&right)]),
+                boolean(not),
+                function_call("or", vec![is_null(&left), is_null(&right)]),
+                boolean(!not),
+                function_call(if not { "eq" } else { "noteq" }, vec![left, right]),
+            ]);
+            Ok(function_call("assume_not_null", vec![scalar]))
+        }
+        _ => Ok(function_call(databend_function_name(name), args)),
+    }
+}
+
+fn expect_args(name: &str, args: &[ScalarExpr], n: usize) -> Result<()> {
+    if args.len() != n {
+        return Err(ErrorCode::BadArguments(format!(
+            "substrait function {name} expects {n} arguments, got {}",
+            args.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Map the name in the Substrait extensions to the Databend function name.
+fn databend_function_name(name: &str) -> &str {
+    match name {
+        "add" => "plus",
+        "subtract" | "negate" => "minus",
+        "modulus" => "modulo",
+        "equal" => "eq",
+        "not_equal" => "noteq",
+        other => other,
+    }
+}
+
+fn aggregate_name(name: &str) -> &str {
+    match name {
+        "any_value" => "any",
+        "std_dev" => "stddev",
+        other => other,
+    }
+}
+
+#[allow(deprecated)]
+fn to_data_type(ty: &Type) -> Result<DataType> {
+    let (data_type, nullability) = match &ty.kind {
+        Some(Kind::Bool(t)) => (DataType::Boolean, t.nullability),
+        Some(Kind::I8(t)) => (DataType::Number(NumberDataType::Int8), t.nullability),
+        Some(Kind::I16(t)) => (DataType::Number(NumberDataType::Int16), t.nullability),
+        Some(Kind::I32(t)) => (DataType::Number(NumberDataType::Int32), t.nullability),
+        Some(Kind::I64(t)) => (DataType::Number(NumberDataType::Int64), t.nullability),
+        Some(Kind::Fp32(t)) => (DataType::Number(NumberDataType::Float32), t.nullability),
+        Some(Kind::Fp64(t)) => (DataType::Number(NumberDataType::Float64), t.nullability),
+        Some(Kind::String(t)) => (DataType::String, t.nullability),
+        Some(Kind::Varchar(t)) => (DataType::String, t.nullability),
+        Some(Kind::FixedChar(t)) => (DataType::String, t.nullability),
+        Some(Kind::Binary(t)) => (DataType::Binary, t.nullability),
+        Some(Kind::FixedBinary(t)) => (DataType::Binary, t.nullability),
+        Some(Kind::Date(t)) => (DataType::Date, t.nullability),
+        Some(Kind::Timestamp(t)) => (DataType::Timestamp, t.nullability),
+        Some(Kind::Decimal(t)) => {
+            let size = decimal_size(t.precision, t.scale)?;
+            (
+                DataType::Decimal(DecimalDataType::from_size(size)?),
+                t.nullability,
+            )
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "substrait type {ty:?} is not supported"
+            )));
+        }
+    };
+    if nullability == Nullability::Nullable as i32 {
+        Ok(data_type.wrap_nullable())
+    } else {
+        Ok(data_type)
+    }
+}
+
+#[allow(deprecated)]
+fn to_scalar(literal: &Literal) -> Result<ScalarExpr> {
+    let value = literal
+        .literal_type
+        .as_ref()
+        .ok_or_else(|| ErrorCode::BadArguments("substrait literal is empty"))?;
+    let value = match value {
+        LiteralType::Boolean(v) => Scalar::Boolean(*v),
+        LiteralType::I8(v) => Scalar::Number(NumberScalar::Int8(*v as i8)),
+        LiteralType::I16(v) => Scalar::Number(NumberScalar::Int16(*v as i16)),
+        LiteralType::I32(v) => Scalar::Number(NumberScalar::Int32(*v)),
+        LiteralType::I64(v) => Scalar::Number(NumberScalar::Int64(*v)),
+        LiteralType::Fp32(v) => Scalar::Number(NumberScalar::Float32(F32::from(*v))),
+        LiteralType::Fp64(v) => Scalar::Number(NumberScalar::Float64(F64::from(*v))),
+        LiteralType::String(v) | LiteralType::FixedChar(v) => Scalar::String(v.clone()),
+        LiteralType::VarChar(v) => Scalar::String(v.value.clone()),
+        LiteralType::Binary(v) | LiteralType::FixedBinary(v) => Scalar::Binary(v.clone()),
+        LiteralType::Date(v) => Scalar::Date(*v),
+        LiteralType::Timestamp(v) => Scalar::Timestamp(*v),
+        LiteralType::Decimal(d) => {
+            let bytes: [u8; 16] = d.value.as_slice().try_into().map_err(|_| {
+                ErrorCode::BadArguments("substrait decimal literal must be 16 bytes")
+            })?;
+            Scalar::Decimal(DecimalScalar::Decimal128(
+                i128::from_le_bytes(bytes),
+                decimal_size(d.precision, d.scale)?,
+            ))
+        }
+        // A typed NULL.
+        LiteralType::Null(ty) => {
+            return Ok(CastExpr {
+                span: None,
+                is_try: false,
+                argument: Box::new(
+                    ConstantExpr {
+                        span: None,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                ),
+                target_type: Box::new(to_data_type(ty)?.wrap_nullable()),
+            }
+            .into());
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "substrait literal {value:?} is not supported"
+            )));
+        }
+    };
+    Ok(ConstantExpr { span: None, value }.into())
+}
+
+fn decimal_size(precision: i32, scale: i32) -> Result<DecimalSize> {
+    match (u8::try_from(precision), u8::try_from(scale)) {
+        (Ok(precision), Ok(scale)) if scale <= precision => Ok(DecimalSize { precision, scale }),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "invalid substrait decimal precision {precision} and scale {scale}"
+        ))),
+    }
+}
+
+fn rel_type_name(rel_type: &RelType) -> &'static str {
+    match rel_type {
+        RelType::Set(_) => "set",
+        RelType::ExtensionSingle(_) | RelType::ExtensionMulti(_) | RelType::ExtensionLeaf(_) => {
+            "extension"
+        }
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion between Substrait plans and Databend plans.
+//!
+//! A Substrait plan is consumed by binding its relations into a `SExpr` directly, which is
+//! then optimized like a bound SQL query. The producer goes the other way, from an optimized
+//! `SExpr` to a Substrait plan, and backs `EXPLAIN SUBSTRAIT`.
+
+mod consumer;
+mod producer;
+
+pub use consumer::SubstraitConsumer;
+pub use producer::SubstraitProducer;
@@ -0,0 +1,780 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use substrait::proto::aggregate_function::AggregationInvocation;
+use substrait::proto::aggregate_rel::Grouping;
+use substrait::proto::aggregate_rel::Measure;
+use substrait::proto::expression::field_reference::ReferenceType;
+use substrait::proto::expression::field_reference::RootType;
+use substrait::proto::expression::if_then::IfClause;
+use substrait::proto::expression::literal;
+use substrait::proto::expression::literal::LiteralType;
+use substrait::proto::expression::reference_segment;
+use substrait::proto::expression::FieldReference;
+use substrait::proto::expression::IfThen;
+use substrait::proto::expression::Literal;
+use substrait::proto::expression::ReferenceSegment;
+use substrait::proto::expression::RexType;
+use substrait::proto::expression::ScalarFunction;
+use substrait::proto::extensions::simple_extension_declaration::ExtensionFunction;
+use substrait::proto::extensions::simple_extension_declaration::MappingType;
+use substrait::proto::extensions::SimpleExtensionDeclaration;
+use substrait::proto::extensions::SimpleExtensionUri;
+use substrait::proto::function_argument::ArgType;
+use substrait::proto::plan_rel;
+use substrait::proto::read_rel::NamedTable;
+use substrait::proto::read_rel::ReadType;
+use substrait::proto::rel::RelType;
+use substrait::proto::rel_common::Emit;
+use substrait::proto::rel_common::EmitKind;
+use substrait::proto::sort_field::SortDirection;
+use substrait::proto::sort_field::SortKind;
+use substrait::proto::r#type;
+use substrait::proto::r#type::Kind;
+use substrait::proto::r#type::Nullability;
+use substrait::proto::AggregateFunction;
+use substrait::proto::AggregateRel;
+use substrait::proto::AggregationPhase;
+use substrait::proto::CrossRel;
+use substrait::proto::Expression;
+use substrait::proto::FetchRel;
+use substrait::proto::FilterRel;
+use substrait::proto::FunctionArgument;
+use substrait::proto::JoinRel;
+use substrait::proto::NamedStruct;
+use substrait::proto::Plan;
+use substrait::proto::PlanRel;
+use substrait::proto::ProjectRel;
+use substrait::proto::ReadRel;
+use substrait::proto::Rel;
+use substrait::proto::RelCommon;
+use substrait::proto::RelRoot;
+use substrait::proto::SortField;
+use substrait::proto::SortRel;
+use substrait::proto::Type;
+use substrait::proto::Version;
+
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::JoinType;
+use crate::plans::Operator;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::Metadata;
+
+const EXTENSION_ARITHMETIC: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_arithmetic.yaml";
+const EXTENSION_COMPARISON: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_comparison.yaml";
+const EXTENSION_BOOLEAN: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_boolean.yaml";
+const EXTENSION_AGGREGATE_GENERIC: &str = "https://github.com/substrait-io/substrait/blob/main/extensions/functions_aggregate_generic.yaml";
+const EXTENSION_STRING: &str =
+    "https://github.com/substrait-io/substrait/blob/main/extensions/functions_string.yaml";
+// Databend functions without a counterpart in the Substrait standard extensions.
+const EXTENSION_DATABEND: &str = "urn:databend:functions";
+
+// Values of `JoinRel.type`, see the consumer for why they are not matched by name.
+const JOIN_TYPE_INNER: i32 = 1;
+const JOIN_TYPE_OUTER: i32 = 2;
+const JOIN_TYPE_LEFT: i32 = 3;
+const JOIN_TYPE_RIGHT: i32 = 4;
+const JOIN_TYPE_LEFT_SEMI: i32 = 5;
+const JOIN_TYPE_LEFT_ANTI: i32 = 6;
+const JOIN_TYPE_RIGHT_SEMI: i32 = 8;
+const JOIN_TYPE_RIGHT_ANTI: i32 = 9;
+
+// `Cast.failure_behavior` of `FAILURE_BEHAVIOR_RETURN_NULL`.
+const CAST_FAILURE_RETURN_NULL: i32 = 1;
+
+/// Produce a Substrait plan from an optimized query `SExpr`.
+///
+/// The output columns of every relation are tracked as a list of column indexes, so that
+/// column references can be turned into positional field references.
+pub struct SubstraitProducer<'a> {
+    metadata: &'a Metadata,
+    extension_uris: Vec<SimpleExtensionUri>,
+    extensions: Vec<SimpleExtensionDeclaration>,
+    // function name => function anchor
+    functions: HashMap<String, u32>,
+}
+
+impl<'a> SubstraitProducer<'a> {
+    pub fn produce(
+        metadata: &Metadata,
+        s_expr: &SExpr,
+        bind_context: &BindContext,
+    ) -> Result<Plan> {
+        let mut producer = SubstraitProducer {
+            metadata,
+            extension_uris: vec![],
+            extensions: vec![],
+            functions: HashMap::new(),
+        };
+        let (rel, outputs) = producer.rel(s_expr)?;
+
+        // Project the result columns of the query in order.
+        let output_mapping = bind_context
+            .columns
+            .iter()
+            .map(|c| position(&outputs, c.index))
+            .collect::<Result<Vec<_>>>()?;
+        let names = bind_context
+            .columns
+            .iter()
+            .map(|c| c.column_name.clone())
+            .collect();
+        let input = Rel {
+            rel_type: Some(RelType::Project(Box::new(ProjectRel {
+                common: Some(emit(output_mapping)),
+                input: Some(Box::new(rel)),
+                ..Default::default()
+            }))),
+        };
+
+        Ok(Plan {
+            version: Some(Version {
+                producer: "databend".to_string(),
+                ..Default::default()
+            }),
+            extension_uris: producer.extension_uris,
+            extensions: producer.extensions,
+            relations: vec![PlanRel {
+                rel_type: Some(plan_rel::RelType::Root(RelRoot {
+                    input: Some(input),
+                    names,
+                })),
+            }],
+            ..Default::default()
+        })
+    }
+
+    fn rel(&mut self, s_expr: &SExpr) -> Result<(Rel, Vec<IndexType>)> {
+        let (rel_type, outputs) = match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                let table = self.metadata.table(scan.table_index);
+                let mut columns = scan.columns.iter().copied().collect::<Vec<_>>();
+                columns.sort();
+                let mut names = Vec::with_capacity(columns.len());
+                let mut types = Vec::with_capacity(columns.len());
+                for index in columns.iter() {
+                    match self.metadata.column(*index) {
+                        ColumnEntry::BaseTableColumn(column) if column.path_indices.is_none() => {
+                            names.push(column.column_name.clone());
+                            types.push(to_type(&DataType::from(&column.data_type))?);
+                        }
+                        column => {
+                            return Err(ErrorCode::Unimplemented(format!(
+                                "column {} can not be converted to substrait",
+                                column.name()
+                            )));
+                        }
+                    }
+                }
+                let filter = match &scan.push_down_predicates {
+                    Some(predicates) if !predicates.is_empty() => {
+                        Some(Box::new(self.conjunction(predicates, &columns)?))
+                    }
+                    _ => None,
+                };
+                let mut table_names = vec![];
+                if table.catalog() != "default" {
+                    table_names.push(table.catalog().to_string());
+                }
+                table_names.push(table.database().to_string());
+                table_names.push(table.name().to_string());
+                let read = ReadRel {
+                    base_schema: Some(NamedStruct {
+                        names,
+                        r#struct: Some(r#type::Struct {
+                            types,
+                            nullability: Nullability::Required as i32,
+                            ..Default::default()
+                        }),
+                    }),
+                    filter,
+                    read_type: Some(ReadType::NamedTable(NamedTable {
+                        names: table_names,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                };
+                (RelType::Read(Box::new(read)), columns)
+            }
+            RelOperator::Filter(filter) => {
+                let (input, outputs) = self.rel(s_expr.child(0)?)?;
+                let condition = self.conjunction(&filter.predicates, &outputs)?;
+                let filter = FilterRel {
+                    input: Some(Box::new(input)),
+                    condition: Some(Box::new(condition)),
+                    ..Default::default()
+                };
+                (RelType::Filter(Box::new(filter)), outputs)
+            }
+            RelOperator::EvalScalar(eval) => {
+                let (input, mut outputs) = self.rel(s_expr.child(0)?)?;
+                let expressions = eval
+                    .items
+                    .iter()
+                    .map(|item| self.scalar(&item.scalar, &outputs))
+                    .collect::<Result<Vec<_>>>()?;
+                outputs.extend(eval.items.iter().map(|item| item.index));
+                let project = ProjectRel {
+                    input: Some(Box::new(input)),
+                    expressions,
+                    ..Default::default()
+                };
+                (RelType::Project(Box::new(project)), outputs)
+            }
+            RelOperator::Aggregate(aggregate) => {
+                if aggregate.grouping_sets.is_some() {
+                    return Err(ErrorCode::Unimplemented(
+                        "grouping sets can not be converted to substrait",
+                    ));
+                }
+                let mut child = s_expr.child(0)?;
+                match (&aggregate.mode, child.plan()) {
+                    // Convert the aggregate as a whole, skip the partial phase.
+                    (AggregateMode::Final, RelOperator::Aggregate(partial))
+                        if partial.mode == AggregateMode::Partial =>
+                    {
+                        child = child.child(0)?;
+                    }
+                    (AggregateMode::Partial, _) => return self.rel(child),
+                    _ => {}
+                }
+                let (input, input_outputs) = self.rel(child)?;
+                let grouping_expressions = aggregate
+                    .group_items
+                    .iter()
+                    .map(|item| self.scalar(&item.scalar, &input_outputs))
+                    .collect::<Result<Vec<_>>>()?;
+                let measures = aggregate
+                    .aggregate_functions
+                    .iter()
+                    .map(|item| self.measure(&item.scalar, &input_outputs))
+                    .collect::<Result<Vec<_>>>()?;
+                let outputs = aggregate
+                    .group_items
+                    .iter()
+                    .chain(aggregate.aggregate_functions.iter())
+                    .map(|item| item.index)
+                    .collect();
+                let aggregate = AggregateRel {
+                    input: Some(Box::new(input)),
+                    groupings: vec![Grouping {
+                        grouping_expressions,
+                        ..Default::default()
+                    }],
+                    measures,
+                    ..Default::default()
+                };
+                (RelType::Aggregate(Box::new(aggregate)), outputs)
+            }
+            RelOperator::Join(join) => {
+                let (left, left_outputs) = self.rel(s_expr.child(0)?)?;
+                let (right, right_outputs) = self.rel(s_expr.child(1)?)?;
+                let all_outputs = left_outputs
+                    .iter()
+                    .chain(right_outputs.iter())
+                    .copied()
+                    .collect::<Vec<_>>();
+                let (join_type, outputs) = match join.join_type {
+                    JoinType::Cross => {
+                        let cross = CrossRel {
+                            left: Some(Box::new(left)),
+                            right: Some(Box::new(right)),
+                            ..Default::default()
+                        };
+                        let rel = Rel {
+                            rel_type: Some(RelType::Cross(Box::new(cross))),
+                        };
+                        return Ok((rel, all_outputs));
+                    }
+                    JoinType::Inner => (JOIN_TYPE_INNER, all_outputs.clone()),
+                    JoinType::Left => (JOIN_TYPE_LEFT, all_outputs.clone()),
+                    JoinType::Right => (JOIN_TYPE_RIGHT, all_outputs.clone()),
+                    JoinType::Full => (JOIN_TYPE_OUTER, all_outputs.clone()),
+                    JoinType::LeftSemi => (JOIN_TYPE_LEFT_SEMI, left_outputs),
+                    JoinType::LeftAnti => (JOIN_TYPE_LEFT_ANTI, left_outputs),
+                    JoinType::RightSemi => (JOIN_TYPE_RIGHT_SEMI, right_outputs),
+                    JoinType::RightAnti => (JOIN_TYPE_RIGHT_ANTI, right_outputs),
+                    other => {
+                        return Err(ErrorCode::Unimplemented(format!(
+                            "{other:?} join can not be converted to substrait"
+                        )));
+                    }
+                };
+                let mut conditions =
+                    Vec::with_capacity(join.equi_conditions.len() + join.non_equi_conditions.len());
+                for condition in join.equi_conditions.iter() {
+                    let name = if condition.is_null_equal {
+                        "is_not_distinct_from"
+                    } else {
+                        "equal"
+                    };
+                    let args = vec![
+                        self.scalar(&condition.left, &all_outputs)?,
+                        self.scalar(&condition.right, &all_outputs)?,
+                    ];
+                    conditions.push(self.function(name, args, Some(DataType::Boolean))?);
+                }
+                for condition in join.non_equi_conditions.iter() {
+                    conditions.push(self.scalar(condition, &all_outputs)?);
+                }
+                let expression = match conditions.len() {
+                    0 => Some(literal(LiteralType::Boolean(true), false)),
+                    1 => conditions.pop(),
+                    _ => Some(self.function("and", conditions, Some(DataType::Boolean))?),
+                };
+                let join = JoinRel {
+                    left: Some(Box::new(left)),
+                    right: Some(Box::new(right)),
+                    expression: expression.map(Box::new),
+                    r#type: join_type,
+                    ..Default::default()
+                };
+                (RelType::Join(Box::new(join)), outputs)
+            }
+            RelOperator::Sort(sort) => {
+                let (input, outputs) = self.rel(s_expr.child(0)?)?;
+                let sorts = sort
+                    .items
+                    .iter()
+                    .map(|item| {
+                        let direction = match (item.asc, item.nulls_first) {
+                            (true, true) => SortDirection::AscNullsFirst,
+                            (true, false) => SortDirection::AscNullsLast,
+                            (false, true) => SortDirection::DescNullsFirst,
+                            (false, false) => SortDirection::DescNullsLast,
+                        };
+                        Ok(SortField {
+                            expr: Some(field_reference(position(&outputs, item.index)?)),
+                            sort_kind: Some(SortKind::Direction(direction as i32)),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let sort = SortRel {
+                    input: Some(Box::new(input)),
+                    sorts,
+                    ..Default::default()
+                };
+                (RelType::Sort(Box::new(sort)), outputs)
+            }
+            RelOperator::Limit(limit) => {
+                let (input, outputs) = self.rel(s_expr.child(0)?)?;
+                let fetch = FetchRel {
+                    input: Some(Box::new(input)),
+                    offset: limit.offset as i64,
+                    count: limit.limit.map(|l| l as i64).unwrap_or(-1),
+                    ..Default::default()
+                };
+                (RelType::Fetch(Box::new(fetch)), outputs)
+            }
+            RelOperator::Exchange(_) => return self.rel(s_expr.child(0)?),
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "{:?} can not be converted to substrait",
+                    other.rel_op()
+                )));
+            }
+        };
+        let rel = Rel {
+            rel_type: Some(rel_type),
+        };
+        Ok((rel, outputs))
+    }
+
+    fn measure(&mut self, scalar: &ScalarExpr, input: &[IndexType]) -> Result<Measure> {
+        let func = match scalar {
+            ScalarExpr::AggregateFunction(func) => func,
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "aggregate item should be an aggregate function",
+                ));
+            }
+        };
+        if !func.params.is_empty() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "parametric aggregate function {} can not be converted to substrait",
+                func.func_name
+            )));
+        }
+        let arguments = func
+            .args
+            .iter()
+            .map(|arg| Ok(value_argument(self.scalar(arg, input)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let invocation = if func.distinct {
+            AggregationInvocation::Distinct
+        } else {
+            AggregationInvocation::All
+        };
+        let name = substrait_function_name(&func.func_name, func.args.len());
+        Ok(Measure {
+            measure: Some(AggregateFunction {
+                function_reference: self.function_anchor(name),
+                arguments,
+                output_type: Some(to_type(&func.return_type)?),
+                phase: AggregationPhase::InitialToResult as i32,
+                invocation: invocation as i32,
+                ..Default::default()
+            }),
+            filter: None,
+        })
+    }
+
+    fn conjunction(
+        &mut self,
+        predicates: &[ScalarExpr],
+        input: &[IndexType],
+    ) -> Result<Expression> {
+        let mut args = predicates
+            .iter()
+            .map(|p| self.scalar(p, input))
+            .collect::<Result<Vec<_>>>()?;
+        if args.len() == 1 {
+            return Ok(args.remove(0));
+        }
+        self.function("and", args, Some(DataType::Boolean))
+    }
+
+    fn scalar(&mut self, scalar: &ScalarExpr, input: &[IndexType]) -> Result<Expression> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column) => {
+                Ok(field_reference(position(input, column.column.index)?))
+            }
+            ScalarExpr::ConstantExpr(constant) => to_literal(&constant.value, &scalar.data_type()?),
+            ScalarExpr::FunctionCall(func) if func.func_name == "if" => {
+                let mut args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.scalar(arg, input))
+                    .collect::<Result<Vec<_>>>()?;
+                let r#else = args.pop().map(Box::new);
+                let mut ifs = vec![];
+                let mut args = args.into_iter();
+                while let (Some(cond), Some(then)) = (args.next(), args.next()) {
+                    ifs.push(IfClause {
+                        r#if: Some(cond),
+                        then: Some(then),
+                    });
+                }
+                Ok(Expression {
+                    rex_type: Some(RexType::IfThen(Box::new(IfThen { ifs, r#else }))),
+                })
+            }
+            ScalarExpr::FunctionCall(func) => {
+                if !func.params.is_empty() {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "parametric function {} can not be converted to substrait",
+                        func.func_name
+                    )));
+                }
+                let args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.scalar(arg, input))
+                    .collect::<Result<Vec<_>>>()?;
+                let name = substrait_function_name(&func.func_name, args.len());
+                self.function(name, args, Some(scalar.data_type()?))
+            }
+            ScalarExpr::CastExpr(cast) => {
+                let input = self.scalar(&cast.argument, input)?;
+                let cast = substrait::proto::expression::Cast {
+                    r#type: Some(to_type(&cast.target_type)?),
+                    input: Some(Box::new(input)),
+                    failure_behavior: if cast.is_try {
+                        CAST_FAILURE_RETURN_NULL
+                    } else {
+                        0
+                    },
+                };
+                Ok(Expression {
+                    rex_type: Some(RexType::Cast(Box::new(cast))),
+                })
+            }
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "expression {:?} can not be converted to substrait",
+                scalar
+            ))),
+        }
+    }
+
+    fn function(
+        &mut self,
+        name: &str,
+        args: Vec<Expression>,
+        output_type: Option<DataType>,
+    ) -> Result<Expression> {
+        let output_type = output_type.as_ref().map(to_type).transpose()?;
+        Ok(Expression {
+            rex_type: Some(RexType::ScalarFunction(ScalarFunction {
+                function_reference: self.function_anchor(name),
+                arguments: args.into_iter().map(value_argument).collect(),
+                output_type,
+                ..Default::default()
+            })),
+        })
+    }
+
+    fn function_anchor(&mut self, name: &str) -> u32 {
+        if let Some(anchor) = self.functions.get(name) {
+            return *anchor;
+        }
+        let uri = extension_uri(name);
+        let uri_anchor = match self.extension_uris.iter().find(|u| u.uri == uri) {
+            Some(u) => u.extension_uri_anchor,
+            None => {
+                let anchor = self.extension_uris.len() as u32 + 1;
+                self.extension_uris.push(SimpleExtensionUri {
+                    extension_uri_anchor: anchor,
+                    uri: uri.to_string(),
+                });
+                anchor
+            }
+        };
+        let anchor = self.functions.len() as u32 + 1;
+        self.extensions.push(SimpleExtensionDeclaration {
+            mapping_type: Some(MappingType::ExtensionFunction(ExtensionFunction {
+                extension_uri_reference: uri_anchor,
+                function_anchor: anchor,
+                name: name.to_string(),
+            })),
+        });
+        self.functions.insert(name.to_string(), anchor);
+        anchor
+    }
+}
+
+fn position(outputs: &[IndexType], index: IndexType) -> Result<i32> {
+    outputs
+        .iter()
+        .position(|i| *i == index)
+        .map(|p| p as i32)
+        .ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "column {index} is not in the input of the substrait relation"
+            ))
+        })
+}
+
+fn emit(output_mapping: Vec<i32>) -> RelCommon {
+    RelCommon {
+        emit_kind: Some(EmitKind::Emit(Emit { output_mapping })),
+        ..Default::default()
+    }
+}
+
+fn field_reference(field: i32) -> Expression {
+    let segment = ReferenceSegment {
+        reference_type: Some(reference_segment::ReferenceType::StructField(Box::new(
+            reference_segment::StructField { field, child: None },
+        ))),
+    };
+    Expression {
+        rex_type: Some(RexType::Selection(Box::new(FieldReference {
+            reference_type: Some(ReferenceType::DirectReference(segment)),
+            root_type: Some(RootType::RootReference(Default::default())),
+        }))),
+    }
+}
+
+fn value_argument(expr: Expression) -> FunctionArgument {
+    FunctionArgument {
+        arg_type: Some(ArgType::Value(expr)),
+    }
+}
+
+fn literal(value: LiteralType, nullable: bool) -> Expression {
+    Expression {
+        rex_type: Some(RexType::Literal(Literal {
+            nullable,
+            literal_type: Some(value),
+            ..Default::default()
+        })),
+    }
+}
+
+/// Map the Databend function name to the name in the Substrait extensions.
+fn substrait_function_name(name: &str, num_args: usize) -> &str {
+    match name {
+        "plus" => "add",
+        "minus" if num_args == 1 => "negate",
+        "minus" => "subtract",
+        "modulo" => "modulus",
+        "eq" => "equal",
+        "noteq" => "not_equal",
+        "and_filters" => "and",
+        "any" => "any_value",
+        "stddev" | "stddev_pop" => "std_dev",
+        other => other,
+    }
+}
+
+fn extension_uri(name: &str) -> &'static str {
+    match name {
+        "add" | "subtract" | "multiply" | "divide" | "modulus" | "negate" | "abs" | "sum"
+        | "avg" | "min" | "max" | "std_dev" => EXTENSION_ARITHMETIC,
+        "equal"
+        | "not_equal"
+        | "lt"
+        | "lte"
+        | "gt"
+        | "gte"
+        | "is_null"
+        | "is_not_null"
+        | "is_distinct_from"
+        | "is_not_distinct_from"
+        | "between"
+        | "coalesce" => EXTENSION_COMPARISON,
+        "and" | "or" | "not" | "xor" => EXTENSION_BOOLEAN,
+        "count" | "any_value" => EXTENSION_AGGREGATE_GENERIC,
+        "concat" | "like" | "substring" | "lower" | "upper" | "trim" | "ltrim" | "rtrim"
+        | "replace" | "starts_with" | "ends_with" | "contains" | "char_length" => EXTENSION_STRING,
+        _ => EXTENSION_DATABEND,
+    }
+}
+
+#[allow(deprecated)]
+fn to_type(data_type: &DataType) -> Result<Type> {
+    let (data_type, nullability) = match data_type {
+        DataType::Nullable(inner) => (inner.as_ref(), Nullability::Nullable as i32),
+        // The type of NULL literals, any nullable type will do.
+        DataType::Null => (&DataType::String, Nullability::Nullable as i32),
+        other => (other, Nullability::Required as i32),
+    };
+    let kind = match data_type {
+        DataType::Boolean => Kind::Bool(r#type::Boolean {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Number(NumberDataType::Int8) => Kind::I8(r#type::I8 {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Number(NumberDataType::Int16 | NumberDataType::UInt8) => Kind::I16(r#type::I16 {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Number(NumberDataType::Int32 | NumberDataType::UInt16) => {
+            Kind::I32(r#type::I32 {
+                nullability,
+                ..Default::default()
+            })
+        }
+        DataType::Number(NumberDataType::Int64 | NumberDataType::UInt32) => {
+            Kind::I64(r#type::I64 {
+                nullability,
+                ..Default::default()
+            })
+        }
+        // Substrait has no unsigned types, UInt64 needs a wider decimal.
+        DataType::Number(NumberDataType::UInt64) => Kind::Decimal(r#type::Decimal {
+            precision: 20,
+            scale: 0,
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Number(NumberDataType::Float32) => Kind::Fp32(r#type::Fp32 {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Number(NumberDataType::Float64) => Kind::Fp64(r#type::Fp64 {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::String => Kind::String(r#type::String {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Binary => Kind::Binary(r#type::Binary {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Date => Kind::Date(r#type::Date {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Timestamp => Kind::Timestamp(r#type::Timestamp {
+            nullability,
+            ..Default::default()
+        }),
+        DataType::Decimal(DecimalDataType::Decimal128(size)) => Kind::Decimal(r#type::Decimal {
+            precision: size.precision as i32,
+            scale: size.scale as i32,
+            nullability,
+            ..Default::default()
+        }),
+        other => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "type {other} can not be converted to substrait"
+            )));
+        }
+    };
+    Ok(Type { kind: Some(kind) })
+}
+
+#[allow(deprecated)]
+fn to_literal(value: &Scalar, data_type: &DataType) -> Result<Expression> {
+    let nullable = data_type.is_nullable_or_null();
+    let value = match value {
+        Scalar::Null => LiteralType::Null(to_type(data_type)?),
+        Scalar::Boolean(v) => LiteralType::Boolean(*v),
+        Scalar::Number(NumberScalar::Int8(v)) => LiteralType::I8(*v as i32),
+        Scalar::Number(NumberScalar::Int16(v)) => LiteralType::I16(*v as i32),
+        Scalar::Number(NumberScalar::UInt8(v)) => LiteralType::I16(*v as i32),
+        Scalar::Number(NumberScalar::Int32(v)) => LiteralType::I32(*v),
+        Scalar::Number(NumberScalar::UInt16(v)) => LiteralType::I32(*v as i32),
+        Scalar::Number(NumberScalar::Int64(v)) => LiteralType::I64(*v),
+        Scalar::Number(NumberScalar::UInt32(v)) => LiteralType::I64(*v as i64),
+        Scalar::Number(NumberScalar::UInt64(v)) => LiteralType::Decimal(literal::Decimal {
+            value: (*v as i128).to_le_bytes().to_vec(),
+            precision: 20,
+            scale: 0,
+        }),
+        Scalar::Number(NumberScalar::Float32(v)) => LiteralType::Fp32(v.into_inner()),
+        Scalar::Number(NumberScalar::Float64(v)) => LiteralType::Fp64(v.into_inner()),
+        Scalar::Decimal(DecimalScalar::Decimal128(v, size)) => {
+            LiteralType::Decimal(literal::Decimal {
+                value: v.to_le_bytes().to_vec(),
+                precision: size.precision as i32,
+                scale: size.scale as i32,
+            })
+        }
+        Scalar::String(v) => LiteralType::String(v.clone()),
+        Scalar::Binary(v) => LiteralType::Binary(v.clone()),
+        Scalar::Date(v) => LiteralType::Date(*v),
+        Scalar::Timestamp(v) => LiteralType::Timestamp(*v),
+        other => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "literal {other} can not be converted to substrait"
+            )));
+        }
+    };
+    Ok(literal(value, nullable))
+}
@@ -14,16 +14,26 @@
 
 use std::sync::Arc;
 
+use arrow_schema::Schema as ArrowSchema;
 use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
 use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchema;
 use databend_storages_common_table_meta::table::TableCompression;
-use parquet::arrow::ArrowWriter;
+use parquet::arrow::add_encoded_arrow_schema_to_metadata;
+use parquet::arrow::arrow_to_parquet_schema;
+use parquet::arrow::arrow_writer::compute_leaves;
+use parquet::arrow::arrow_writer::get_column_writers;
 use parquet::basic::Encoding;
+use parquet::basic::LogicalType;
+use parquet::basic::Type as PhysicalType;
 use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
 use parquet::format::FileMetaData;
+use parquet::schema::types::SchemaDescriptor;
+use parquet::schema::types::Type;
 
 /// Serialize data blocks to parquet format.
 pub fn blocks_to_parquet(
@@ -33,7 +43,7 @@ pub fn blocks_to_parquet(
     compression: TableCompression,
 ) -> Result<FileMetaData> {
     assert!(!blocks.is_empty());
-    let props = WriterProperties::builder()
+    let mut props = WriterProperties::builder()
         .set_compression(compression.into())
         // use `usize::MAX` to effectively limit the number of row groups to 1
         .set_max_row_group_size(usize::MAX)
@@ -47,10 +57,62 @@ pub fn blocks_to_parquet(
         .map(|block| block.to_record_batch(table_schema))
         .collect::<Result<Vec<_>>>()?;
     let arrow_schema = Arc::new(table_schema_to_arrow_schema(table_schema));
-    let mut writer = ArrowWriter::try_new(write_buffer, arrow_schema, Some(props))?;
+    let parquet_schema = table_schema_to_parquet_schema(table_schema, &arrow_schema)?;
+    add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
+    let props = Arc::new(props);
+
+    // `ArrowWriter` derives the parquet schema by itself, which has no logical type
+    // for UUID, so the columns are written into a single row group directly.
+    let mut column_writers = get_column_writers(&parquet_schema, &props, &arrow_schema)?;
     for batch in batches {
-        writer.write(&batch)?;
+        let mut writers = column_writers.iter_mut();
+        for (field, column) in arrow_schema.fields().iter().zip(batch.columns()) {
+            for leaf in compute_leaves(field, column)? {
+                writers.next().unwrap().write(&leaf)?;
+            }
+        }
+    }
+    let mut writer =
+        SerializedFileWriter::new(write_buffer, parquet_schema.root_schema_ptr(), props)?;
+    let mut row_group_writer = writer.next_row_group()?;
+    for column_writer in column_writers {
+        column_writer
+            .close()?
+            .append_to_row_group(&mut row_group_writer)?;
     }
+    row_group_writer.close()?;
     let file_meta = writer.close()?;
     Ok(file_meta)
 }
+
+/// Convert the table schema to parquet schema, UUID columns are annotated
+/// with the `UUID` logical type.
+fn table_schema_to_parquet_schema(
+    table_schema: &TableSchema,
+    arrow_schema: &ArrowSchema,
+) -> Result<SchemaDescriptor> {
+    let parquet_schema = arrow_to_parquet_schema(arrow_schema)?;
+    let root_schema = parquet_schema.root_schema();
+    let fields = root_schema
+        .get_fields()
+        .iter()
+        .zip(table_schema.fields())
+        .map(|(parquet_type, field)| {
+            if field.data_type().remove_nullable() != TableDataType::Uuid {
+                return Ok(parquet_type.clone());
+            }
+            let info = parquet_type.get_basic_info();
+            let uuid_type =
+                Type::primitive_type_builder(info.name(), PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                    .with_repetition(info.repetition())
+                    .with_length(16)
+                    .with_logical_type(Some(LogicalType::Uuid))
+                    .build()?;
+            Ok(Arc::new(uuid_type))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let root_schema = Type::group_type_builder(root_schema.name())
+        .with_fields(fields)
+        .build()?;
+    Ok(SchemaDescriptor::new(Arc::new(root_schema)))
+}
@@ -12,16 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Error;
+use std::io::ErrorKind;
 use std::sync::Arc;
 
 use databend_common_arrow::parquet::metadata::FileMetaData;
 use databend_common_cache::MemSized;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_index::filters::Filter;
 use databend_storages_common_index::filters::Xor8Filter;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_index::InvertedIndexFile;
 use databend_storages_common_index::InvertedIndexMeta;
+use databend_storages_common_index::VirtualColumnIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -30,27 +36,33 @@ use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 
 use crate::manager::CacheManager;
 use crate::CacheAccessor;
+use crate::DiskCacheable;
+use crate::HybridCache;
 use crate::InMemoryLruCache;
 
-/// In memory object cache of SegmentInfo
-pub type CompactSegmentInfoCache = InMemoryLruCache<CompactSegmentInfo>;
+/// Object cache of SegmentInfo, with an optional disk tier
+pub type CompactSegmentInfoCache = HybridCache<CompactSegmentInfo>;
 
 pub type BlockMetaCache = InMemoryLruCache<Vec<Arc<BlockMeta>>>;
 
-/// In memory object cache of TableSnapshot
-pub type TableSnapshotCache = InMemoryLruCache<TableSnapshot>;
+/// Object cache of TableSnapshot, with an optional disk tier
+pub type TableSnapshotCache = HybridCache<TableSnapshot>;
 /// In memory object cache of TableSnapshotStatistics
 pub type TableSnapshotStatisticCache = InMemoryLruCache<TableSnapshotStatistics>;
-/// In memory object cache of bloom filter.
+/// Object cache of bloom filter, with an optional disk tier.
 /// For each indexed data block, the bloom xor8 filter of column is cached individually
-pub type BloomIndexFilterCache = InMemoryLruCache<Xor8Filter>;
-/// In memory object cache of parquet FileMetaData of bloom index data
-pub type BloomIndexMetaCache = InMemoryLruCache<BloomIndexMeta>;
+pub type BloomIndexFilterCache = HybridCache<Xor8Filter>;
+/// Object cache of parquet FileMetaData of bloom index data, with an optional disk tier
+pub type BloomIndexMetaCache = HybridCache<BloomIndexMeta>;
 
 pub type InvertedIndexMetaCache = InMemoryLruCache<InvertedIndexMeta>;
-pub type InvertedIndexFileCache = InMemoryLruCache<InvertedIndexFile>;
+pub type InvertedIndexFileCache = HybridCache<InvertedIndexFile>;
 
-/// In memory object cache of parquet FileMetaData of external parquet files
+/// In memory object cache of the virtual column index of blocks
+pub type VirtualColumnIndexCache = InMemoryLruCache<VirtualColumnIndex>;
+
+/// In memory object cache of parquet FileMetaData of external parquet files.
+/// External files may be overwritten in place, so their meta is not persisted in the disk tier
 pub type FileMetaDataCache = InMemoryLruCache<FileMetaData>;
 
 pub type PrunePartitionsCache = InMemoryLruCache<(PartStatistics, Partitions)>;
@@ -143,6 +155,13 @@ impl CachedObject<InvertedIndexMeta> for InvertedIndexMeta {
     }
 }
 
+impl CachedObject<VirtualColumnIndex> for VirtualColumnIndex {
+    type Cache = VirtualColumnIndexCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_virtual_column_index_cache()
+    }
+}
+
 pub struct CacheValue<T> {
     inner: Arc<T>,
     mem_bytes: usize,
@@ -234,6 +253,15 @@ impl From<InvertedIndexFile> for CacheValue<InvertedIndexFile> {
     }
 }
 
+impl From<VirtualColumnIndex> for CacheValue<VirtualColumnIndex> {
+    fn from(value: VirtualColumnIndex) -> Self {
+        CacheValue {
+            inner: Arc::new(value),
+            mem_bytes: 0,
+        }
+    }
+}
+
 impl From<FileMetaData> for CacheValue<FileMetaData> {
     fn from(value: FileMetaData) -> Self {
         CacheValue {
@@ -277,3 +305,78 @@ impl<T> MemSized for CacheValue<T> {
         self.mem_bytes
     }
 }
+
+// Table meta objects are persisted in the disk tier in MessagePack, the same encoding as the
+// one used by the meta files.
+fn encode_msgpack<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+    Ok(rmp_serde::to_vec_named(value).map_err(|e| Error::new(ErrorKind::InvalidData, e))?)
+}
+
+fn decode_msgpack<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    Ok(rmp_serde::from_slice(bytes).map_err(|e| Error::new(ErrorKind::InvalidData, e))?)
+}
+
+impl DiskCacheable for CompactSegmentInfo {
+    fn to_disk_bytes(&self) -> Result<Vec<u8>> {
+        encode_msgpack(self)
+    }
+
+    fn from_disk_bytes(bytes: &[u8]) -> Result<Self> {
+        decode_msgpack(bytes)
+    }
+}
+
+impl DiskCacheable for TableSnapshot {
+    fn to_disk_bytes(&self) -> Result<Vec<u8>> {
+        encode_msgpack(self)
+    }
+
+    fn from_disk_bytes(bytes: &[u8]) -> Result<Self> {
+        decode_msgpack(bytes)
+    }
+}
+
+impl DiskCacheable for BloomIndexMeta {
+    fn to_disk_bytes(&self) -> Result<Vec<u8>> {
+        encode_msgpack(self)
+    }
+
+    fn from_disk_bytes(bytes: &[u8]) -> Result<Self> {
+        decode_msgpack(bytes)
+    }
+}
+
+impl DiskCacheable for Xor8Filter {
+    fn to_disk_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.to_bytes()?)
+    }
+
+    fn from_disk_bytes(bytes: &[u8]) -> Result<Self> {
+        let (filter, _) = Xor8Filter::from_bytes(bytes)?;
+        Ok(filter)
+    }
+}
+
+// Layout: name length (u64, le) | name | data
+impl DiskCacheable for InvertedIndexFile {
+    fn to_disk_bytes(&self) -> Result<Vec<u8>> {
+        let name = self.name.as_bytes();
+        let mut buf = Vec::with_capacity(8 + name.len() + self.data.len());
+        buf.extend_from_slice(&(name.len() as u64).to_le_bytes());
+        buf.extend_from_slice(name);
+        buf.extend_from_slice(self.data.as_slice());
+        Ok(buf)
+    }
+
+    fn from_disk_bytes(bytes: &[u8]) -> Result<Self> {
+        let invalid = || ErrorCode::StorageOther("invalid inverted index file cache item");
+        let name_len_bytes: [u8; 8] = bytes
+            .get(0..8)
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(invalid)?;
+        let name_end = 8 + u64::from_le_bytes(name_len_bytes) as usize;
+        let name = bytes.get(8..name_end).ok_or_else(invalid)?;
+        let name = String::from_utf8(name.to_vec())?;
+        InvertedIndexFile::try_create(name, bytes[name_end..].to_vec())
+    }
+}
@@ -33,6 +33,8 @@ pub use manager::CacheManager;
 pub use providers::DiskCacheError;
 pub use providers::DiskCacheKey;
 pub use providers::DiskCacheResult;
+pub use providers::DiskCacheable;
+pub use providers::HybridCache;
 pub use providers::InMemoryLruCache;
 pub use providers::LruDiskCache;
 pub use providers::LruDiskCacheBuilder;
@@ -41,8 +43,10 @@ pub use providers::TableDataCache;
 pub use providers::TableDataCacheBuilder;
 pub use providers::TableDataCacheKey;
 pub use providers::DISK_TABLE_DATA_CACHE_NAME;
+pub use providers::DISK_TABLE_META_CACHE_NAME;
 pub use read::CacheKey;
 pub use read::CachedReader;
+pub use read::HybridCacheReader;
 pub use read::InMemoryCacheReader;
 pub use read::InMemoryItemCacheReader;
 pub use read::LoadParams;
@@ -34,11 +34,15 @@ use crate::caches::InvertedIndexMetaCache;
 use crate::caches::PrunePartitionsCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
+use crate::caches::VirtualColumnIndexCache;
+use crate::HybridCache;
 use crate::InMemoryLruCache;
 use crate::TableDataCache;
 use crate::TableDataCacheBuilder;
+use crate::DISK_TABLE_META_CACHE_NAME;
 
 static DEFAULT_FILE_META_DATA_CACHE_ITEMS: usize = 3000;
+static DEFAULT_VIRTUAL_COLUMN_INDEX_CACHE_ITEMS: usize = 3000;
 
 /// Where all the caches reside
 pub struct CacheManager {
@@ -49,11 +53,13 @@ pub struct CacheManager {
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
     inverted_index_meta_cache: Option<InvertedIndexMetaCache>,
     inverted_index_file_cache: Option<InvertedIndexFileCache>,
+    virtual_column_index_cache: Option<VirtualColumnIndexCache>,
     prune_partitions_cache: Option<PrunePartitionsCache>,
     parquet_file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
     in_memory_table_data_cache: Option<ColumnArrayCache>,
     block_meta_cache: Option<BlockMetaCache>,
+    table_meta_disk_cache: Option<TableDataCache>,
 }
 
 impl CacheManager {
@@ -63,34 +69,35 @@ impl CacheManager {
         max_server_memory_usage: &u64,
         tenant_id: impl Into<String>,
     ) -> Result<()> {
+        let tenant_id: String = tenant_id.into();
+        let population_queue_size: u32 = if config.table_data_cache_population_queue_size > 0 {
+            config.table_data_cache_population_queue_size
+        } else {
+            std::cmp::max(
+                1,
+                std::thread::available_parallelism()
+                    .expect("Cannot get thread count")
+                    .get() as u32,
+            ) * 5
+        };
+
         // setup table data cache
         let table_data_cache = {
             match config.data_cache_storage {
                 CacheStorageTypeInnerConfig::None => None,
                 CacheStorageTypeInnerConfig::Disk => {
                     let real_disk_cache_root = PathBuf::from(&config.disk_cache_config.path)
-                        .join(tenant_id.into())
+                        .join(&tenant_id)
                         .join("v1");
 
-                    let queue_size: u32 = if config.table_data_cache_population_queue_size > 0 {
-                        config.table_data_cache_population_queue_size
-                    } else {
-                        std::cmp::max(
-                            1,
-                            std::thread::available_parallelism()
-                                .expect("Cannot get thread count")
-                                .get() as u32,
-                        ) * 5
-                    };
-
                     info!(
                         "disk cache enabled, cache population queue size {}",
-                        queue_size
+                        population_queue_size
                     );
 
                     Self::new_block_data_cache(
                         &real_disk_cache_root,
-                        queue_size,
+                        population_queue_size,
                         config.disk_cache_config.max_bytes as usize,
                         config.data_cache_key_reload_policy.clone(),
                         config.disk_cache_config.sync_data,
@@ -121,33 +128,70 @@ impl CacheManager {
                 bloom_index_meta_cache: None,
                 inverted_index_meta_cache: None,
                 inverted_index_file_cache: None,
+                virtual_column_index_cache: None,
                 prune_partitions_cache: None,
                 parquet_file_meta_data_cache: None,
                 table_statistic_cache: None,
                 table_data_cache,
                 in_memory_table_data_cache,
                 block_meta_cache: None,
+                table_meta_disk_cache: None,
             }));
         } else {
-            let table_snapshot_cache = Self::new_named_items_cache(
-                config.table_meta_snapshot_count as usize,
-                MEMORY_CACHE_TABLE_SNAPSHOT,
+            // setup the disk tier of table meta caches
+            let table_meta_disk_cache = if config.table_meta_disk_cache_bytes > 0 {
+                let meta_disk_cache_root = PathBuf::from(&config.disk_cache_config.path)
+                    .join(&tenant_id)
+                    .join("meta_v1");
+                info!(
+                    "table meta disk cache enabled, path {:?}, capacity {} bytes",
+                    meta_disk_cache_root, config.table_meta_disk_cache_bytes
+                );
+                // Meta objects are immutable, and items are validated by checksum when
+                // loaded, so the cache keys are always reloaded during restart.
+                Some(TableDataCacheBuilder::new_named_disk_cache(
+                    DISK_TABLE_META_CACHE_NAME,
+                    &meta_disk_cache_root,
+                    population_queue_size,
+                    config.table_meta_disk_cache_bytes as usize,
+                    DiskCacheKeyReloadPolicy::Fuzzy,
+                    config.disk_cache_config.sync_data,
+                )?)
+            } else {
+                None
+            };
+
+            let table_snapshot_cache = Self::new_hybrid_cache(
+                Self::new_named_items_cache(
+                    config.table_meta_snapshot_count as usize,
+                    MEMORY_CACHE_TABLE_SNAPSHOT,
+                ),
+                &table_meta_disk_cache,
             );
             let table_statistic_cache = Self::new_named_items_cache(
                 config.table_meta_statistic_count as usize,
                 MEMORY_CACHE_TABLE_STATISTICS,
             );
-            let compact_segment_info_cache = Self::new_named_bytes_cache(
-                MEMORY_CACHE_COMPACT_SEGMENT_INFO,
-                config.table_meta_segment_bytes as usize,
+            let compact_segment_info_cache = Self::new_hybrid_cache(
+                Self::new_named_bytes_cache(
+                    MEMORY_CACHE_COMPACT_SEGMENT_INFO,
+                    config.table_meta_segment_bytes as usize,
+                ),
+                &table_meta_disk_cache,
             );
-            let bloom_index_filter_cache = Self::new_named_bytes_cache(
-                MEMORY_CACHE_BLOOM_INDEX_FILTER,
-                config.table_bloom_index_filter_size as usize,
+            let bloom_index_filter_cache = Self::new_hybrid_cache(
+                Self::new_named_bytes_cache(
+                    MEMORY_CACHE_BLOOM_INDEX_FILTER,
+                    config.table_bloom_index_filter_size as usize,
+                ),
+                &table_meta_disk_cache,
             );
-            let bloom_index_meta_cache = Self::new_named_items_cache(
-                config.table_bloom_index_meta_count as usize,
-                MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA,
+            let bloom_index_meta_cache = Self::new_hybrid_cache(
+                Self::new_named_items_cache(
+                    config.table_bloom_index_meta_count as usize,
+                    MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA,
+                ),
+                &table_meta_disk_cache,
             );
             let inverted_index_meta_cache = Self::new_named_items_cache(
                 config.inverted_index_meta_count as usize,
@@ -162,9 +206,16 @@ impl CacheManager {
             } else {
                 config.inverted_index_filter_size as usize
             };
-            let inverted_index_file_cache = Self::new_named_bytes_cache(
-                MEMORY_CACHE_INVERTED_INDEX_FILE,
-                inverted_index_file_size,
+            let inverted_index_file_cache = Self::new_hybrid_cache(
+                Self::new_named_bytes_cache(
+                    MEMORY_CACHE_INVERTED_INDEX_FILE,
+                    inverted_index_file_size,
+                ),
+                &table_meta_disk_cache,
+            );
+            let virtual_column_index_cache = Self::new_named_items_cache(
+                DEFAULT_VIRTUAL_COLUMN_INDEX_CACHE_ITEMS,
+                MEMORY_CACHE_VIRTUAL_COLUMN_INDEX,
             );
             let prune_partitions_cache = Self::new_named_items_cache(
                 config.table_prune_partitions_count as usize,
@@ -188,12 +239,14 @@ impl CacheManager {
                 bloom_index_meta_cache,
                 inverted_index_meta_cache,
                 inverted_index_file_cache,
+                virtual_column_index_cache,
                 prune_partitions_cache,
                 parquet_file_meta_data_cache,
                 table_statistic_cache,
                 table_data_cache,
                 in_memory_table_data_cache,
                 block_meta_cache,
+                table_meta_disk_cache,
             }));
         }
 
@@ -236,6 +289,10 @@ impl CacheManager {
         self.inverted_index_file_cache.clone()
     }
 
+    pub fn get_virtual_column_index_cache(&self) -> Option<VirtualColumnIndexCache> {
+        self.virtual_column_index_cache.clone()
+    }
+
     pub fn get_prune_partitions_cache(&self) -> Option<PrunePartitionsCache> {
         self.prune_partitions_cache.clone()
     }
@@ -252,6 +309,10 @@ impl CacheManager {
         self.in_memory_table_data_cache.clone()
     }
 
+    pub fn get_table_meta_disk_cache(&self) -> Option<TableDataCache> {
+        self.table_meta_disk_cache.clone()
+    }
+
     pub fn new_named_items_cache<V: Into<CacheValue<V>>>(
         capacity: usize,
         name: impl Into<String>,
@@ -275,6 +336,13 @@ impl CacheManager {
         }
     }
 
+    fn new_hybrid_cache<V: Into<CacheValue<V>>>(
+        memory_cache: Option<InMemoryLruCache<V>>,
+        disk_cache: &Option<TableDataCache>,
+    ) -> Option<HybridCache<V>> {
+        memory_cache.map(|c| HybridCache::new(c, disk_cache.clone()))
+    }
+
     fn new_block_data_cache(
         path: &PathBuf,
         population_queue_size: u32,
@@ -304,6 +372,8 @@ const MEMORY_CACHE_INVERTED_INDEX_FILE: &str = "memory_cache_inverted_index_file
 const MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA: &str =
     "memory_cache_inverted_index_file_meta_data";
 
+const MEMORY_CACHE_VIRTUAL_COLUMN_INDEX: &str = "memory_cache_virtual_column_index";
+
 const MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA: &str = "memory_cache_bloom_index_file_meta_data";
 const MEMORY_CACHE_BLOOM_INDEX_FILTER: &str = "memory_cache_bloom_index_filter";
 const MEMORY_CACHE_COMPACT_SEGMENT_INFO: &str = "memory_cache_compact_segment_info";
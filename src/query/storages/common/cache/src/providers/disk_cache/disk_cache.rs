@@ -33,6 +33,7 @@ use log::warn;
 use parking_lot::RwLock;
 use rayon::prelude::*;
 use rayon::ThreadPoolBuilder;
+use uuid::Uuid;
 
 use crate::CacheValue;
 use crate::DiskCacheKey;
@@ -177,6 +178,13 @@ impl DiskCache {
                 let canonical_root =
                     fs::canonicalize(cache_root).unwrap_or_else(|_| PathBuf::from(cache_root));
                 if let Ok(entry_path) = entry.path().canonicalize() {
+                    // leftovers of interrupted writes
+                    if is_temp_file(&entry_path) {
+                        if let Err(e) = fs::remove_file(&entry_path) {
+                            warn!("failed to remove temp file {:?}. {}", entry_path, e);
+                        }
+                        return;
+                    }
                     if let Ok(size) = entry.metadata().map(|m| m.len()) {
                         if let Ok(relative_path) = entry_path.strip_prefix(&canonical_root) {
                             let cache_key = recovery_cache_key_from_path(relative_path);
@@ -301,17 +309,25 @@ impl DiskCache {
         if let Some(parent_path) = path.parent() {
             fs::create_dir_all(parent_path)?;
         }
-        let mut f = File::create(&path)?;
-        let mut bufs = Vec::with_capacity(bytes.len());
-        for slick in bytes {
-            bufs.push(IoSlice::new(slick));
+        // Write to a temporary file first and rename it afterwards, so that a crash in
+        // the middle of writing never leaves a partially written item under the key path.
+        // Temporary files are named uniquely, concurrent writers of the same key may not
+        // write into the same file.
+        let tmp_path = path.with_extension(format!("{}.{}", Uuid::new_v4(), TEMP_FILE_EXTENSION));
+        {
+            let mut f = File::create(&tmp_path)?;
+            let mut bufs = Vec::with_capacity(bytes.len());
+            for slick in bytes {
+                bufs.push(IoSlice::new(slick));
+            }
+            f.write_all_vectored(&mut bufs)?;
+            if self.sync_data {
+                f.sync_data()?;
+            }
         }
-        f.write_all_vectored(&mut bufs)?;
+        fs::rename(&tmp_path, &path)?;
         self.cache
             .insert(cache_key.0, Into::into(FileSize(bytes_len)));
-        if self.sync_data {
-            f.sync_data()?;
-        }
         Ok(())
     }
 
@@ -345,6 +361,13 @@ impl DiskCache {
     }
 }
 
+const TEMP_FILE_EXTENSION: &str = "tmp";
+
+fn is_temp_file(path: &Path) -> bool {
+    path.extension()
+        .map_or(false, |ext| ext == TEMP_FILE_EXTENSION)
+}
+
 fn recovery_cache_key_from_path(relative_path: &Path) -> String {
     let key_string = match relative_path.file_name() {
         Some(file_name) => match file_name.to_str() {
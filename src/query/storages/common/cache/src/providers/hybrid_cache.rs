@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use databend_common_exception::Result;
+use databend_common_metrics::cache::metrics_inc_cache_miss_bytes;
+use log::warn;
+
+use crate::caches::CacheValue;
+use crate::CacheAccessor;
+use crate::InMemoryLruCache;
+use crate::TableDataCache;
+use crate::Unit;
+
+/// Objects which could be persisted in the disk tier of [HybridCache].
+pub trait DiskCacheable: Sized {
+    fn to_disk_bytes(&self) -> Result<Vec<u8>>;
+
+    fn from_disk_bytes(bytes: &[u8]) -> Result<Self>;
+}
+
+/// An in-memory LRU cache, backed by an optional disk cache.
+///
+/// Items inserted are also written to the disk tier (asynchronously, by the population
+/// worker of the disk cache), items missing in memory are looked up in the disk tier
+/// and promoted to memory if found. Since the disk cache survives restarts, the working
+/// set of table meta can be recovered without touching the object storage.
+pub struct HybridCache<V: Into<CacheValue<V>>> {
+    memory_cache: InMemoryLruCache<V>,
+    disk_cache: Option<TableDataCache>,
+}
+
+impl<V: Into<CacheValue<V>>> Clone for HybridCache<V> {
+    fn clone(&self) -> Self {
+        Self {
+            memory_cache: self.memory_cache.clone(),
+            disk_cache: self.disk_cache.clone(),
+        }
+    }
+}
+
+impl<V: Into<CacheValue<V>>> HybridCache<V> {
+    pub fn new(memory_cache: InMemoryLruCache<V>, disk_cache: Option<TableDataCache>) -> Self {
+        Self {
+            memory_cache,
+            disk_cache,
+        }
+    }
+
+    pub fn in_memory_cache(&self) -> &InMemoryLruCache<V> {
+        &self.memory_cache
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.memory_cache.unit()
+    }
+
+    // The disk tier is shared by several caches, keys are prefixed by the cache name
+    // to avoid conflicts.
+    fn disk_cache_key(&self, k: &str) -> String {
+        format!("{}/{}", self.memory_cache.name(), k)
+    }
+}
+
+impl<V: Into<CacheValue<V>> + DiskCacheable> CacheAccessor for HybridCache<V> {
+    type V = V;
+
+    fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<V>> {
+        let k = k.as_ref();
+        if let Some(v) = self.memory_cache.get(k) {
+            return Some(v);
+        }
+
+        let disk_cache = self.disk_cache.as_ref()?;
+        let disk_cache_key = self.disk_cache_key(k);
+        let bytes = disk_cache.get(&disk_cache_key)?;
+        match V::from_disk_bytes(&bytes) {
+            Ok(v) => Some(self.memory_cache.insert(k.to_string(), v)),
+            Err(e) => {
+                // the checksum is verified by disk cache, but the layout may be changed
+                // by a newer version, just drop the item.
+                warn!(
+                    "failed to decode disk cache item of {}, key {k}. {e}",
+                    self.memory_cache.name()
+                );
+                disk_cache.evict(&disk_cache_key);
+                None
+            }
+        }
+    }
+
+    fn get_sized<Q: AsRef<str>>(&self, k: Q, len: u64) -> Option<Arc<V>> {
+        let Some(cached_value) = self.get(k) else {
+            metrics_inc_cache_miss_bytes(len, self.name());
+            return None;
+        };
+
+        Some(cached_value)
+    }
+
+    fn insert(&self, key: String, value: V) -> Arc<V> {
+        if let Some(disk_cache) = &self.disk_cache {
+            let disk_cache_key = self.disk_cache_key(&key);
+            if !disk_cache.contains_key(&disk_cache_key) {
+                match value.to_disk_bytes() {
+                    Ok(bytes) => {
+                        disk_cache.insert(disk_cache_key, Bytes::from(bytes));
+                    }
+                    Err(e) => {
+                        warn!(
+                            "failed to encode disk cache item of {}, key {key}. {e}",
+                            self.memory_cache.name()
+                        );
+                    }
+                }
+            }
+        }
+        self.memory_cache.insert(key, value)
+    }
+
+    fn evict(&self, k: &str) -> bool {
+        let evicted = match &self.disk_cache {
+            Some(disk_cache) => disk_cache.evict(&self.disk_cache_key(k)),
+            None => false,
+        };
+        self.memory_cache.evict(k) || evicted
+    }
+
+    fn contains_key(&self, k: &str) -> bool {
+        self.memory_cache.contains_key(k)
+            || self
+                .disk_cache
+                .as_ref()
+                .is_some_and(|c| c.contains_key(&self.disk_cache_key(k)))
+    }
+
+    fn bytes_size(&self) -> u64 {
+        self.memory_cache.bytes_size()
+    }
+
+    fn items_capacity(&self) -> u64 {
+        self.memory_cache.items_capacity()
+    }
+
+    fn bytes_capacity(&self) -> u64 {
+        self.memory_cache.bytes_capacity()
+    }
+
+    fn len(&self) -> usize {
+        self.memory_cache.len()
+    }
+
+    fn name(&self) -> &str {
+        self.memory_cache.name()
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod disk_cache;
+mod hybrid_cache;
 mod memory_cache;
 mod table_data_cache;
 
@@ -22,8 +23,11 @@ pub use disk_cache::DiskCacheKey;
 pub use disk_cache::LruDiskCache;
 pub use disk_cache::LruDiskCacheBuilder;
 pub use disk_cache::LruDiskCacheHolder;
+pub use hybrid_cache::DiskCacheable;
+pub use hybrid_cache::HybridCache;
 pub use memory_cache::InMemoryLruCache;
 pub use table_data_cache::TableDataCache;
 pub use table_data_cache::TableDataCacheBuilder;
 pub use table_data_cache::TableDataCacheKey;
 pub use table_data_cache::DISK_TABLE_DATA_CACHE_NAME;
+pub use table_data_cache::DISK_TABLE_META_CACHE_NAME;
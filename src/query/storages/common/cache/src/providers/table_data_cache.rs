@@ -63,12 +63,14 @@ impl AsRef<str> for TableDataCacheKey {
 
 #[derive(Clone)]
 pub struct TableDataCache<T = LruDiskCacheHolder> {
+    name: String,
     external_cache: T,
     population_queue: crossbeam_channel::Sender<CacheItem>,
     _cache_populator: DiskCachePopulator,
 }
 
 pub const DISK_TABLE_DATA_CACHE_NAME: &str = "disk_cache_table_data";
+pub const DISK_TABLE_META_CACHE_NAME: &str = "disk_cache_table_meta";
 
 pub struct TableDataCacheBuilder;
 
@@ -80,6 +82,26 @@ impl TableDataCacheBuilder {
         disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
     ) -> Result<TableDataCache<LruDiskCacheHolder>> {
+        Self::new_named_disk_cache(
+            DISK_TABLE_DATA_CACHE_NAME,
+            path,
+            population_queue_size,
+            disk_cache_bytes_size,
+            disk_cache_reload_policy,
+            sync_data,
+        )
+    }
+
+    /// Creates a disk cache of raw bytes, which is populated asynchronously by a background thread.
+    pub fn new_named_disk_cache(
+        name: impl Into<String>,
+        path: &PathBuf,
+        population_queue_size: u32,
+        disk_cache_bytes_size: usize,
+        disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
+        sync_data: bool,
+    ) -> Result<TableDataCache<LruDiskCacheHolder>> {
+        let name = name.into();
         let disk_cache = LruDiskCacheBuilder::new_disk_cache(
             path,
             disk_cache_bytes_size,
@@ -89,9 +111,10 @@ impl TableDataCacheBuilder {
         let (tx, rx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
         Ok(TableDataCache {
+            name: name.clone(),
             external_cache: disk_cache.clone(),
             population_queue: tx,
-            _cache_populator: DiskCachePopulator::new(rx, disk_cache, num_population_thread)?,
+            _cache_populator: DiskCachePopulator::new(name, rx, disk_cache, num_population_thread)?,
         })
     }
 }
@@ -100,25 +123,25 @@ impl CacheAccessor for TableDataCache {
     type V = Bytes;
 
     fn name(&self) -> &str {
-        DISK_TABLE_DATA_CACHE_NAME
+        &self.name
     }
 
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<Bytes>> {
-        metrics_inc_cache_access_count(1, DISK_TABLE_DATA_CACHE_NAME);
+        metrics_inc_cache_access_count(1, &self.name);
         let k = k.as_ref();
         if let Some(item) = self.external_cache.get(k) {
             Profile::record_usize_profile(ProfileStatisticsName::ScanCacheBytes, item.len());
-            metrics_inc_cache_hit_count(1, DISK_TABLE_DATA_CACHE_NAME);
+            metrics_inc_cache_hit_count(1, &self.name);
             Some(item)
         } else {
-            metrics_inc_cache_miss_count(1, DISK_TABLE_DATA_CACHE_NAME);
+            metrics_inc_cache_miss_count(1, &self.name);
             None
         }
     }
 
     fn get_sized<Q: AsRef<str>>(&self, k: Q, len: u64) -> Option<Arc<Self::V>> {
         let Some(cached_value) = self.get(k) else {
-            metrics_inc_cache_miss_bytes(len, DISK_TABLE_DATA_CACHE_NAME);
+            metrics_inc_cache_miss_bytes(len, &self.name);
             return None;
         };
 
@@ -135,14 +158,14 @@ impl CacheAccessor for TableDataCache {
             };
             match self.population_queue.try_send(msg) {
                 Ok(_) => {
-                    metrics_inc_cache_population_pending_count(1, DISK_TABLE_DATA_CACHE_NAME);
+                    metrics_inc_cache_population_pending_count(1, &self.name);
                 }
                 Err(TrySendError::Full(_)) => {
-                    metrics_inc_cache_population_pending_count(-1, DISK_TABLE_DATA_CACHE_NAME);
-                    metrics_inc_cache_population_overflow_count(1, DISK_TABLE_DATA_CACHE_NAME);
+                    metrics_inc_cache_population_pending_count(-1, &self.name);
+                    metrics_inc_cache_population_overflow_count(1, &self.name);
                 }
                 Err(TrySendError::Disconnected(_)) => {
-                    error!("table data cache population thread is down");
+                    error!("{} population thread is down", self.name);
                 }
             }
         }
@@ -175,6 +198,7 @@ impl CacheAccessor for TableDataCache {
 }
 
 struct CachePopulationWorker<T> {
+    name: String,
     cache: T,
     population_queue: crossbeam_channel::Receiver<CacheItem>,
 }
@@ -190,10 +214,10 @@ impl<T: CacheAccessor<V = Bytes> + Send + Sync + 'static> CachePopulationWorker<
                         }
                     }
                     self.cache.insert(key, value);
-                    metrics_inc_cache_population_pending_count(-1, DISK_TABLE_DATA_CACHE_NAME);
+                    metrics_inc_cache_population_pending_count(-1, &self.name);
                 }
                 Err(_) => {
-                    info!("table data cache worker shutdown");
+                    info!("{} population worker shutdown", self.name);
                     break;
                 }
             }
@@ -201,8 +225,7 @@ impl<T: CacheAccessor<V = Bytes> + Send + Sync + 'static> CachePopulationWorker<
     }
 
     fn start(self: Arc<Self>) -> Result<JoinHandle<()>> {
-        let thread_builder =
-            std::thread::Builder::new().name("table-data-cache-population".to_owned());
+        let thread_builder = std::thread::Builder::new().name(format!("{}-population", self.name));
         thread_builder.spawn(move || self.populate()).map_err(|e| {
             ErrorCode::StorageOther(format!("spawn cache population worker thread failed, {e}"))
         })
@@ -214,6 +237,7 @@ struct DiskCachePopulator;
 
 impl DiskCachePopulator {
     fn new<T>(
+        name: String,
         incoming: crossbeam_channel::Receiver<CacheItem>,
         cache: T,
         _num_worker_thread: usize,
@@ -222,6 +246,7 @@ impl DiskCachePopulator {
         T: CacheAccessor<V = Bytes> + Send + Sync + 'static,
     {
         let worker = Arc::new(CachePopulationWorker {
+            name,
             cache,
             population_queue: incoming,
         });
@@ -19,9 +19,7 @@ use databend_common_exception::Result;
 use databend_common_metrics::cache::*;
 
 use super::loader::LoadParams;
-use crate::caches::CacheValue;
 use crate::CacheAccessor;
-use crate::InMemoryLruCache;
 use crate::Loader;
 
 /// A cache-aware reader
@@ -30,10 +28,12 @@ pub struct CachedReader<L, C> {
     loader: L,
 }
 
-impl<V: Into<CacheValue<V>>, L> CachedReader<L, InMemoryLruCache<V>>
-where L: Loader<V> + Sync
+impl<V, L, C> CachedReader<L, C>
+where
+    L: Loader<V> + Sync,
+    C: CacheAccessor<V = V>,
 {
-    pub fn new(cache: Option<InMemoryLruCache<V>>, loader: L) -> Self {
+    pub fn new(cache: Option<C>, loader: L) -> Self {
         Self { cache, loader }
     }
 
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use crate::read::cached_reader::CachedReader;
+use crate::HybridCache;
 use crate::InMemoryLruCache;
 
 pub type InMemoryItemCacheReader<T, L> = CachedReader<L, InMemoryLruCache<T>>;
 pub type InMemoryCacheReader<T, L> = CachedReader<L, InMemoryLruCache<T>>;
+pub type HybridCacheReader<T, L> = CachedReader<L, HybridCache<T>>;
@@ -200,6 +200,32 @@ fn test_fuzzy_restart_parallelism() {
     }
 }
 
+#[test]
+fn test_fuzzy_restart_remove_temp_files() {
+    let f = TestFixture::new();
+    let cache_root = f.tmp();
+    {
+        let mut c =
+            DiskCache::new(cache_root, 1024, DiskCacheKeyReloadPolicy::Fuzzy, false).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+    }
+
+    // simulate a write which is interrupted before the temp file is renamed
+    let key = DiskCacheKey::from("file2");
+    let temp_file_path = cache_root
+        .join(PathBuf::from(&key))
+        .with_extension("6f1c3a5e-2d4b-4e8f-9a7c-0b1d2e3f4a5b.tmp");
+    fs::create_dir_all(temp_file_path.parent().unwrap()).unwrap();
+    fs::write(&temp_file_path, [2; 5]).unwrap();
+
+    let c = DiskCache::new(cache_root, 1024, DiskCacheKeyReloadPolicy::Fuzzy, false).unwrap();
+    assert!(c.contains_key("file1"));
+    assert!(!c.contains_key("file2"));
+    assert_eq!(c.len(), 1);
+    assert_eq!(c.size(), 10);
+    assert!(!temp_file_path.exists());
+}
+
 #[test]
 fn test_reset_restart_parallelism() {
     let f = TestFixture::new();
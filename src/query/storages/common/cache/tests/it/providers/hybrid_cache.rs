@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_config::DiskCacheKeyReloadPolicy;
+use databend_common_exception::Result;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::DiskCacheable;
+use databend_storages_common_cache::HybridCache;
+use databend_storages_common_cache::InMemoryLruCache;
+use databend_storages_common_cache::TableDataCache;
+use databend_storages_common_cache::TableDataCacheBuilder;
+use databend_storages_common_index::InvertedIndexFile;
+
+fn new_disk_cache(path: &std::path::Path) -> Result<TableDataCache> {
+    TableDataCacheBuilder::new_named_disk_cache(
+        "test_disk_cache",
+        &path.to_path_buf(),
+        16,
+        1024 * 1024,
+        DiskCacheKeyReloadPolicy::Fuzzy,
+        false,
+    )
+}
+
+// items are written to disk by the population worker asynchronously
+fn wait_population(disk_cache: &TableDataCache, len: usize) {
+    let start = Instant::now();
+    while disk_cache.len() < len {
+        assert!(start.elapsed() < Duration::from_secs(10));
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn test_hybrid_cache() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let disk_cache = new_disk_cache(dir.path())?;
+    let memory_cache = InMemoryLruCache::with_items_capacity("test_memory_cache".to_string(), 1);
+    let cache = HybridCache::new(memory_cache, Some(disk_cache.clone()));
+
+    cache.insert(
+        "a".to_string(),
+        InvertedIndexFile::try_create("a".to_string(), vec![1, 2, 3])?,
+    );
+    wait_population(&disk_cache, 1);
+    cache.insert(
+        "b".to_string(),
+        InvertedIndexFile::try_create("b".to_string(), vec![4, 5])?,
+    );
+    wait_population(&disk_cache, 2);
+
+    // "a" is evicted from memory, but kept in the disk tier
+    assert!(!cache.in_memory_cache().contains_key("a"));
+    assert!(cache.contains_key("a"));
+    let a = cache.get("a").unwrap();
+    assert_eq!(a.name, "a");
+    assert_eq!(a.data.as_slice(), &[1, 2, 3]);
+    // and promoted to memory
+    assert!(cache.in_memory_cache().contains_key("a"));
+
+    assert!(cache.evict("b"));
+    assert!(!cache.contains_key("b"));
+    assert!(cache.get("b").is_none());
+
+    // the disk tier survives restarts
+    drop(cache);
+    let disk_cache = new_disk_cache(dir.path())?;
+    let memory_cache = InMemoryLruCache::with_items_capacity("test_memory_cache".to_string(), 1);
+    let cache = HybridCache::new(memory_cache, Some(disk_cache));
+    let a = cache.get("a").unwrap();
+    assert_eq!(a.data.as_slice(), &[1, 2, 3]);
+    assert!(cache.get("b").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index_file_disk_bytes() -> Result<()> {
+    let file = InvertedIndexFile::try_create("idx".to_string(), vec![7; 16])?;
+    let bytes = file.to_disk_bytes()?;
+    let decoded = InvertedIndexFile::from_disk_bytes(&bytes)?;
+    assert_eq!(decoded.name, "idx");
+    assert_eq!(decoded.data.as_slice(), &[7; 16]);
+
+    assert!(InvertedIndexFile::from_disk_bytes(&bytes[0..4]).is_err());
+    Ok(())
+}
@@ -65,7 +65,7 @@ use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
 
-#[derive(Clone)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
 }
@@ -543,7 +543,7 @@ impl BloomIndex {
 
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
-    fn check_large_string(column: &Column) -> bool {
+    pub(crate) fn check_large_string(column: &Column) -> bool {
         if let Column::String(v) = &column {
             let bytes_per_row = v.data().len() / v.len().max(1);
             if bytes_per_row > 256 {
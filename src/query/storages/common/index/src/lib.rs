@@ -21,6 +21,7 @@ mod index;
 mod inverted_index;
 mod page_index;
 mod range_index;
+mod virtual_column_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
@@ -35,3 +36,4 @@ pub use inverted_index::InvertedIndexMeta;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
+pub use virtual_column_index::VirtualColumnIndex;
@@ -21,15 +21,19 @@ use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::decimal::Decimal256Type;
 use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalDomain;
+use databend_common_expression::types::large_int::LargeIntDataType;
+use databend_common_expression::types::large_int::LargeIntType;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::string::StringDomain;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::ValueType;
+use databend_common_expression::with_large_int_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnId;
 use databend_common_expression::ConstantFolder;
@@ -39,10 +43,13 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::geometry_bbox;
+use databend_common_io::BoundingBox;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::Index;
+use crate::VirtualColumnIndex;
 
 #[derive(Clone)]
 pub struct RangeIndex {
@@ -79,11 +86,40 @@ impl RangeIndex {
 
     pub fn apply<F>(&self, stats: &StatisticsOfColumns, column_is_default: F) -> Result<bool>
     where F: Fn(&ColumnId) -> bool {
-        let input_domains = self
-            .expr
+        self.apply_expr(&self.expr, stats, HashMap::new(), column_is_default)
+    }
+
+    /// Apply the filter rewritten by the [`VirtualColumnIndex`] of the block,
+    /// so that the casts of virtual columns can be evaluated with the statistics of JSON values.
+    pub fn apply_with_virtual_column_index(
+        &self,
+        stats: &StatisticsOfColumns,
+        virtual_column_index: &VirtualColumnIndex,
+    ) -> Result<bool> {
+        let (expr, virtual_domains) =
+            virtual_column_index.rewrite_filter(&self.func_ctx, &self.expr)?;
+        self.apply_expr(&expr, stats, virtual_domains, |_| false)
+    }
+
+    fn apply_expr<F>(
+        &self,
+        expr: &Expr<String>,
+        stats: &StatisticsOfColumns,
+        mut virtual_domains: HashMap<String, Domain>,
+        column_is_default: F,
+    ) -> Result<bool>
+    where
+        F: Fn(&ColumnId) -> bool,
+    {
+        let input_domains = expr
             .column_refs()
             .into_iter()
             .map(|(name, ty)| {
+                // columns generated by the virtual column index have domains from its statistics
+                if let Some(domain) = virtual_domains.remove(&name) {
+                    return Ok((name, domain));
+                }
+
                 // internal column and stream column are not actual stored columns
                 // variant type may be virtual columns that are not included in leaf columns
                 if is_internal_column(&name)
@@ -122,17 +158,92 @@ impl RangeIndex {
             })
             .collect::<Result<_>>()?;
         let (new_expr, _) = ConstantFolder::fold_with_domain(
-            &self.expr,
+            expr,
             &input_domains,
             &self.func_ctx,
             &BUILTIN_FUNCTIONS,
         );
 
         // Only return false, which means to skip this block, when the expression is folded to a constant false.
-        Ok(!matches!(new_expr, Expr::Constant {
+        if matches!(new_expr, Expr::Constant {
             scalar: Scalar::Boolean(false),
             ..
-        }))
+        }) {
+            return Ok(false);
+        }
+
+        // Geometry columns have no domain, check spatial predicates by the bounding box instead.
+        Ok(self.spatial_may_match(&new_expr, stats))
+    }
+
+    /// Return false if a spatial predicate between a geometry column and a constant geometry
+    /// can not be satisfied, because their bounding boxes are disjoint.
+    fn spatial_may_match(&self, expr: &Expr<String>, stats: &StatisticsOfColumns) -> bool {
+        let (id, args) = match expr {
+            Expr::FunctionCall { id, args, .. } => (id, args),
+            // Casting a boolean keeps false and NULL, e.g. the filter of a nullable column
+            // is pushed down as `is_true(try_cast(<predicate> as boolean))`.
+            Expr::Cast { expr, .. } if expr.data_type().remove_nullable() == DataType::Boolean => {
+                return self.spatial_may_match(expr, stats);
+            }
+            _ => return true,
+        };
+        match id.name().as_ref() {
+            "and" | "and_filters" => args.iter().all(|arg| self.spatial_may_match(arg, stats)),
+            // A block is skipped only if every branch rules it out.
+            "or" => args.iter().any(|arg| self.spatial_may_match(arg, stats)),
+            "is_true" => self.spatial_may_match(&args[0], stats),
+            "st_intersects" | "st_contains" | "st_within" | "st_dwithin" => {
+                let distance = match args.get(2) {
+                    None => 0.0,
+                    Some(Expr::Constant {
+                        scalar: Scalar::Number(NumberScalar::Float64(distance)),
+                        ..
+                    }) => distance.0,
+                    Some(_) => return true,
+                };
+                let (name, geometry) = match (&args[0], &args[1]) {
+                    (
+                        Expr::ColumnRef { id, .. },
+                        Expr::Constant {
+                            scalar: Scalar::Geometry(geometry),
+                            ..
+                        },
+                    )
+                    | (
+                        Expr::Constant {
+                            scalar: Scalar::Geometry(geometry),
+                            ..
+                        },
+                        Expr::ColumnRef { id, .. },
+                    ) => (id, geometry),
+                    _ => return true,
+                };
+                let column_ids = self.schema.leaf_columns_of(name);
+                let Some(stat) = column_ids.first().and_then(|id| stats.get(id)) else {
+                    return true;
+                };
+                match (stat.min(), stat.max()) {
+                    // All values are NULL, the predicate can not be true.
+                    (Scalar::Null, Scalar::Null) => false,
+                    (Scalar::Geometry(min), Scalar::Geometry(max)) => {
+                        match (
+                            BoundingBox::from_ewkb_points(min, max),
+                            geometry_bbox(geometry),
+                        ) {
+                            (Ok(column_bbox), Ok(Some(bbox))) => {
+                                column_bbox.expand(distance).intersects(&bbox)
+                            }
+                            // Empty geometry intersects nothing.
+                            (Ok(_), Ok(None)) => false,
+                            _ => true,
+                        }
+                    }
+                    _ => true,
+                }
+            }
+            _ => true,
+        }
     }
 
     #[fastrace::trace]
@@ -240,6 +351,16 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                         *sz,
                     )),
                 },
+                DataType::LargeInt(ty) => with_large_int_mapped_type!(|LARGE_INT| match ty {
+                    LargeIntDataType::LARGE_INT => {
+                        LargeIntType::<LARGE_INT>::upcast_domain(SimpleDomain {
+                            min: LargeIntType::<LARGE_INT>::try_downcast_scalar(&min.as_ref())
+                                .unwrap(),
+                            max: LargeIntType::<LARGE_INT>::try_downcast_scalar(&max.as_ref())
+                                .unwrap(),
+                        })
+                    }
+                }),
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
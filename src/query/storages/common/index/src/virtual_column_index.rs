@@ -0,0 +1,440 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Domain;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+use crate::filters::Xor8Builder;
+use crate::filters::Xor8Filter;
+use crate::statistics_to_domain;
+use crate::BloomIndex;
+use crate::Index;
+
+/// VirtualColumnIndex holds the statistics and filters of the virtual columns of a block.
+///
+/// Virtual columns are stored as `Variant`, which has no domain, so the index is built on
+/// the scalar values inside the JSON documents. If all the non-null values of a virtual
+/// column in the block are integers, floats or strings, the min/max of the extracted
+/// values is recorded, and string values additionally get a xor filter.
+///
+/// The index is only used for filters that cast a virtual column to a scalar type,
+/// like `v['id']::int64 > 10` or `v['name']::string = 'abc'`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct VirtualColumnIndex {
+    /// Statistics of the extracted scalar values, keyed by virtual column name.
+    pub col_stats: HashMap<String, ColumnStatistics>,
+    /// Serialized xor filters of the extracted string values, keyed by virtual column name.
+    pub filters: HashMap<String, Vec<u8>>,
+}
+
+impl VirtualColumnIndex {
+    /// Create the index from the materialized virtual columns of a block.
+    ///
+    /// The columns of the block are in the same order as `names`.
+    pub fn try_create(
+        func_ctx: &FunctionContext,
+        block: &DataBlock,
+        names: &[String],
+    ) -> Result<Self> {
+        let mut index = VirtualColumnIndex::default();
+        for (name, entry) in names.iter().zip(block.columns()) {
+            if entry.data_type.remove_nullable() != DataType::Variant {
+                continue;
+            }
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+
+            let mut null_count = 0;
+            let mut values = Vec::with_capacity(column.len());
+            for val in column.iter() {
+                match val {
+                    ScalarRef::Variant(v) => values.push(v),
+                    _ => null_count += 1,
+                }
+            }
+
+            let Some(stats) = scalar_statistics(&values, null_count) else {
+                continue;
+            };
+            if let Scalar::String(_) = stats.min {
+                if let Some(filter) = build_string_filter(func_ctx, &values)? {
+                    index.filters.insert(name.clone(), filter);
+                }
+            }
+            index.col_stats.insert(name.clone(), stats);
+        }
+        Ok(index)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.col_stats.is_empty()
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        rmp_serde::to_vec_named(self).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to serialize virtual column index: {e}"))
+        })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        rmp_serde::from_slice(bytes).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to deserialize virtual column index: {e}"))
+        })
+    }
+
+    /// Rewrite the filter expression with the index.
+    ///
+    /// - `<virtual column>::string = <constant>` is replaced with a column of `false` domain
+    ///   if the filter of the virtual column doesn't contain the constant.
+    /// - Other casts of virtual columns are replaced with casts of typed columns,
+    ///   whose domains are generated from the statistics of the extracted values.
+    ///
+    /// Returns the new expression and the domains of the columns introduced by the rewriting.
+    pub fn rewrite_filter(
+        &self,
+        func_ctx: &FunctionContext,
+        expr: &Expr<String>,
+    ) -> Result<(Expr<String>, HashMap<String, Domain>)> {
+        let mut expr = expr.clone();
+        let mut domains = HashMap::new();
+        self.visit_expr(func_ctx, &mut expr, &mut domains)?;
+        Ok((expr, domains))
+    }
+
+    fn visit_expr(
+        &self,
+        func_ctx: &FunctionContext,
+        expr: &mut Expr<String>,
+        domains: &mut HashMap<String, Domain>,
+    ) -> Result<()> {
+        let new_expr = match &*expr {
+            Expr::FunctionCall {
+                span,
+                id,
+                args,
+                return_type,
+                ..
+            } if id.name() == "eq" && args.len() == 2 => {
+                match self.apply_filter(func_ctx, &args[0], &args[1])? {
+                    FilterApplied::Uncertain => self.apply_filter(func_ctx, &args[1], &args[0])?,
+                    other => other,
+                }
+                .into_expr(*span, return_type, domains)
+            }
+            _ => self.rewrite_cast(expr, domains),
+        };
+        if let Some(new_expr) = new_expr {
+            *expr = new_expr;
+            return Ok(());
+        }
+
+        match expr {
+            Expr::Cast { expr, .. } => {
+                self.visit_expr(func_ctx, expr, domains)?;
+            }
+            Expr::FunctionCall { args, .. } => {
+                for arg in args.iter_mut() {
+                    self.visit_expr(func_ctx, arg, domains)?;
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    // Check `<virtual column>::string = <constant>` with the filter of the virtual column.
+    fn apply_filter(
+        &self,
+        func_ctx: &FunctionContext,
+        column: &Expr<String>,
+        constant: &Expr<String>,
+    ) -> Result<FilterApplied> {
+        let Some(cast) = VirtualColumnCast::try_from_expr(column) else {
+            return Ok(FilterApplied::Uncertain);
+        };
+        let Expr::Constant {
+            scalar: scalar @ Scalar::String(_),
+            ..
+        } = constant
+        else {
+            return Ok(FilterApplied::Uncertain);
+        };
+        if cast.dest_type.remove_nullable() != DataType::String {
+            return Ok(FilterApplied::Uncertain);
+        }
+        let Some(bytes) = self.filters.get(cast.name) else {
+            return Ok(FilterApplied::Uncertain);
+        };
+
+        let (filter, _) = Xor8Filter::from_bytes(bytes)?;
+        let digest = BloomIndex::calculate_scalar_digest(func_ctx, scalar, &DataType::String)?;
+        if filter.contains_digest(digest) {
+            Ok(FilterApplied::Uncertain)
+        } else {
+            Ok(FilterApplied::MustFalse(cast.name.to_string()))
+        }
+    }
+
+    // Replace the cast of a virtual column with the cast of a typed column.
+    fn rewrite_cast(
+        &self,
+        expr: &Expr<String>,
+        domains: &mut HashMap<String, Domain>,
+    ) -> Option<Expr<String>> {
+        let cast = VirtualColumnCast::try_from_expr(expr)?;
+        let stats = self.col_stats.get(cast.name)?;
+
+        // Only rewrite the casts that keep the values unchanged.
+        let scalar_type = match (&stats.min, cast.dest_type.remove_nullable()) {
+            (Scalar::Null, dest_type) => dest_type,
+            (Scalar::Number(NumberScalar::Int64(_)), DataType::Number(_)) => {
+                DataType::Number(NumberDataType::Int64)
+            }
+            (Scalar::Number(NumberScalar::Float64(_)), DataType::Number(num_ty))
+                if num_ty.is_float() =>
+            {
+                DataType::Number(NumberDataType::Float64)
+            }
+            (Scalar::String(_), DataType::String) => DataType::String,
+            _ => return None,
+        };
+        let data_type = if cast.column_type.is_nullable() || stats.min.is_null() {
+            scalar_type.wrap_nullable()
+        } else {
+            scalar_type
+        };
+
+        let new_col_name = format!("__virtual_column_{}_{}", cast.name, domains.len());
+        domains.insert(
+            new_col_name.clone(),
+            statistics_to_domain(vec![stats], &data_type),
+        );
+        let column = Expr::ColumnRef {
+            span: cast.span,
+            id: new_col_name.clone(),
+            data_type: data_type.clone(),
+            display_name: new_col_name,
+        };
+        if &data_type == cast.dest_type {
+            Some(column)
+        } else {
+            Some(Expr::Cast {
+                span: cast.span,
+                is_try: cast.is_try,
+                expr: Box::new(column),
+                dest_type: cast.dest_type.clone(),
+            })
+        }
+    }
+}
+
+impl Index for VirtualColumnIndex {}
+
+enum FilterApplied {
+    Uncertain,
+    MustFalse(String),
+}
+
+impl FilterApplied {
+    fn into_expr(
+        self,
+        span: Span,
+        return_type: &DataType,
+        domains: &mut HashMap<String, Domain>,
+    ) -> Option<Expr<String>> {
+        let FilterApplied::MustFalse(name) = self else {
+            return None;
+        };
+        let new_col_name = format!("__virtual_bloom_column_{}_{}", name, domains.len());
+        let bool_domain = Domain::Boolean(BooleanDomain {
+            has_false: true,
+            has_true: false,
+        });
+        let new_domain = if return_type.is_nullable() {
+            Domain::Nullable(NullableDomain {
+                has_null: true,
+                value: Some(Box::new(bool_domain)),
+            })
+        } else {
+            bool_domain
+        };
+        domains.insert(new_col_name.clone(), new_domain);
+
+        Some(Expr::ColumnRef {
+            span,
+            id: new_col_name.clone(),
+            data_type: return_type.clone(),
+            display_name: new_col_name,
+        })
+    }
+}
+
+/// A cast of a virtual column, either `CAST(<virtual column> AS <type>)`
+/// or a simple cast function like `to_int64(<virtual column>)`.
+struct VirtualColumnCast<'a> {
+    span: Span,
+    is_try: bool,
+    name: &'a str,
+    column_type: &'a DataType,
+    dest_type: &'a DataType,
+}
+
+impl<'a> VirtualColumnCast<'a> {
+    fn try_from_expr(expr: &'a Expr<String>) -> Option<Self> {
+        let cast = match expr {
+            Expr::Cast {
+                span,
+                is_try,
+                expr: box Expr::ColumnRef { id, data_type, .. },
+                dest_type,
+            } => VirtualColumnCast {
+                span: *span,
+                is_try: *is_try,
+                name: id,
+                column_type: data_type,
+                dest_type,
+            },
+            Expr::FunctionCall {
+                span,
+                id: func_id,
+                args,
+                return_type,
+                ..
+            } if args.len() == 1 => {
+                let func_name = func_id.name();
+                let is_try = func_name.starts_with("try_to_");
+                if !is_try && !func_name.starts_with("to_") {
+                    return None;
+                }
+                let Expr::ColumnRef { id, data_type, .. } = &args[0] else {
+                    return None;
+                };
+                VirtualColumnCast {
+                    span: *span,
+                    is_try,
+                    name: id,
+                    column_type: data_type,
+                    dest_type: return_type,
+                }
+            }
+            _ => return None,
+        };
+        if cast.column_type.remove_nullable() != DataType::Variant {
+            return None;
+        }
+        Some(cast)
+    }
+}
+
+// Generate the statistics of the scalar values in JSON documents,
+// returns None if the values are not all integers, floats or strings.
+fn scalar_statistics(values: &[&[u8]], null_count: u64) -> Option<ColumnStatistics> {
+    let in_memory_size = values.iter().map(|v| v.len() as u64).sum();
+    if values.is_empty() {
+        return Some(ColumnStatistics::new(
+            Scalar::Null,
+            Scalar::Null,
+            null_count,
+            in_memory_size,
+            Some(0),
+        ));
+    }
+
+    let (min, max) = if let Some(nums) = values
+        .iter()
+        .map(|v| jsonb::as_i64(v))
+        .collect::<Option<Vec<_>>>()
+    {
+        let min = *nums.iter().min()?;
+        let max = *nums.iter().max()?;
+        (
+            Scalar::Number(NumberScalar::Int64(min)),
+            Scalar::Number(NumberScalar::Int64(max)),
+        )
+    } else if let Some(nums) = values
+        .iter()
+        .map(|v| jsonb::as_f64(v).map(F64::from))
+        .collect::<Option<Vec<_>>>()
+    {
+        if nums.iter().any(|v| v.is_nan()) {
+            return None;
+        }
+        let min = *nums.iter().min()?;
+        let max = *nums.iter().max()?;
+        (
+            Scalar::Number(NumberScalar::Float64(min)),
+            Scalar::Number(NumberScalar::Float64(max)),
+        )
+    } else if let Some(strs) = values
+        .iter()
+        .map(|v| jsonb::as_str(v))
+        .collect::<Option<Vec<_>>>()
+    {
+        let min = strs.iter().min()?.to_string();
+        let max = strs.iter().max()?.to_string();
+        (Scalar::String(min), Scalar::String(max))
+    } else {
+        return None;
+    };
+
+    Some(ColumnStatistics::new(
+        min,
+        max,
+        null_count,
+        in_memory_size,
+        None,
+    ))
+}
+
+// Build the xor filter of the string values in JSON documents.
+fn build_string_filter(func_ctx: &FunctionContext, values: &[&[u8]]) -> Result<Option<Vec<u8>>> {
+    let mut builder = ColumnBuilder::with_capacity(&DataType::String, values.len());
+    for val in values {
+        match jsonb::as_str(val) {
+            Some(str_val) => builder.push(ScalarRef::String(&str_val)),
+            None => return Ok(None),
+        }
+    }
+    let column = builder.build();
+    if BloomIndex::check_large_string(&column) {
+        return Ok(None);
+    }
+
+    let (digests, _) =
+        BloomIndex::calculate_nullable_column_digest(func_ctx, &column, &DataType::String)?;
+    let mut filter_builder = Xor8Builder::create();
+    filter_builder.add_digests(digests.iter());
+    let filter = filter_builder.build()?;
+    Ok(Some(filter.to_bytes()?))
+}
@@ -16,3 +16,5 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod filters;
+mod range_index;
+mod virtual_column_index;
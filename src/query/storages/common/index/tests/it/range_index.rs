@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::parse_to_ewkb;
+use databend_common_io::BoundingBox;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+
+fn column() -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: "g".to_string(),
+        data_type: DataType::Nullable(Box::new(DataType::Geometry)),
+        display_name: "g".to_string(),
+    }
+}
+
+fn point(x: f64, y: f64) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::Geometry(parse_to_ewkb(&format!("POINT({x} {y})"), None).unwrap()),
+        data_type: DataType::Geometry,
+    }
+}
+
+fn func(name: &str, args: &[Expr<String>]) -> Expr<String> {
+    check_function(None, name, &[], args, &BUILTIN_FUNCTIONS).unwrap()
+}
+
+/// Apply the filter to a block whose geometries are in the box from (0, 0) to (1, 1).
+fn may_match(expr: Expr<String>) -> Result<bool> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "g",
+        TableDataType::Nullable(Box::new(TableDataType::Geometry)),
+    )]));
+    let bbox = BoundingBox {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 1.0,
+        max_y: 1.0,
+    };
+    let (min, max) = bbox.to_ewkb_points()?;
+    let stats = StatisticsOfColumns::from([(
+        0,
+        ColumnStatistics::new(Scalar::Geometry(min), Scalar::Geometry(max), 0, 0, None),
+    )]);
+
+    // the same as the filter pushed down to the table scan
+    let expr = func("is_true", &[check_cast(
+        None,
+        true,
+        expr,
+        &DataType::Boolean,
+        &BUILTIN_FUNCTIONS,
+    )?]);
+    let range_index = RangeIndex::try_create(
+        FunctionContext::default(),
+        &expr,
+        schema,
+        StatisticsOfColumns::default(),
+    )?;
+    range_index.apply(&stats, |_| false)
+}
+
+#[test]
+fn test_spatial_pruning() -> Result<()> {
+    let inside = || func("st_intersects", &[column(), point(0.5, 0.5)]);
+    let outside = |x| func("st_intersects", &[column(), point(x, x)]);
+
+    assert!(may_match(inside())?);
+    assert!(!may_match(outside(5.0))?);
+    assert!(!may_match(func("st_dwithin", &[
+        column(),
+        point(5.0, 5.0),
+        Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::Float64(1.0.into())),
+            data_type: DataType::Number(NumberDataType::Float64),
+        },
+    ]))?);
+
+    // and
+    assert!(!may_match(func("and", &[inside(), outside(5.0)]))?);
+    assert!(may_match(func("and", &[inside(), inside()]))?);
+
+    // or, the block is skipped only if every branch rules it out
+    assert!(!may_match(func("or", &[outside(5.0), outside(6.0)]))?);
+    assert!(may_match(func("or", &[outside(5.0), inside()]))?);
+    assert!(may_match(func("or", &[
+        outside(5.0),
+        func("is_not_null", &[column()])
+    ]))?);
+    assert!(!may_match(func("or", &[
+        func("and", &[inside(), outside(5.0)]),
+        outside(6.0)
+    ]))?);
+
+    // not can not be pruned by the bounding box
+    assert!(may_match(func("not", &[outside(5.0)]))?);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_index::VirtualColumnIndex;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+
+fn variant_column(values: &[&str]) -> databend_common_expression::Column {
+    VariantType::from_opt_data(
+        values
+            .iter()
+            .map(|v| match *v {
+                "NULL" => None,
+                v => Some(jsonb::parse_value(v.as_bytes()).unwrap().to_vec()),
+            })
+            .collect(),
+    )
+}
+
+fn build_index() -> Result<VirtualColumnIndex> {
+    let block = DataBlock::new_from_columns(vec![
+        variant_column(&["1", "5", "10", "NULL"]),
+        variant_column(&["1.5", "2", "3.5", "4"]),
+        variant_column(&[r#""abc""#, r#""def""#, "NULL", r#""xyz""#]),
+        variant_column(&["1", r#""abc""#, "true", "NULL"]),
+    ]);
+    let names = vec![
+        "v['a']".to_string(),
+        "v['b']".to_string(),
+        "v['c']".to_string(),
+        "v['d']".to_string(),
+    ];
+    VirtualColumnIndex::try_create(&FunctionContext::default(), &block, &names)
+}
+
+fn eval_index(index: &VirtualColumnIndex, func_name: &str, name: &str, val: Scalar) -> bool {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "v",
+        TableDataType::Nullable(Box::new(TableDataType::Variant)),
+    )]));
+    let dest_type = val.as_ref().infer_data_type();
+    let column = Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: DataType::Nullable(Box::new(DataType::Variant)),
+        display_name: name.to_string(),
+    };
+    let cast_expr = check_cast(
+        None,
+        false,
+        column,
+        &dest_type.wrap_nullable(),
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    let expr = check_function(
+        None,
+        func_name,
+        &[],
+        &[cast_expr, Expr::Constant {
+            span: None,
+            scalar: val,
+            data_type: dest_type,
+        }],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    // the same as the filter pushed down to the table scan
+    let expr = check_function(
+        None,
+        "is_true",
+        &[],
+        &[check_cast(None, true, expr, &DataType::Boolean, &BUILTIN_FUNCTIONS).unwrap()],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let range_index = RangeIndex::try_create(
+        FunctionContext::default(),
+        &expr,
+        schema,
+        StatisticsOfColumns::default(),
+    )
+    .unwrap();
+    range_index
+        .apply_with_virtual_column_index(&StatisticsOfColumns::default(), index)
+        .unwrap()
+}
+
+#[test]
+fn test_virtual_column_index() -> Result<()> {
+    let index = build_index()?;
+    let index = VirtualColumnIndex::from_bytes(&index.to_bytes()?)?;
+
+    // integer values
+    let stats = &index.col_stats["v['a']"];
+    assert_eq!(stats.min, Scalar::Number(NumberScalar::Int64(1)));
+    assert_eq!(stats.max, Scalar::Number(NumberScalar::Int64(10)));
+    assert_eq!(stats.null_count, 1);
+    let int64 = |v| Scalar::Number(NumberScalar::Int64(v));
+    assert!(eval_index(&index, "eq", "v['a']", int64(5)));
+    assert!(!eval_index(&index, "eq", "v['a']", int64(11)));
+    assert!(!eval_index(&index, "gt", "v['a']", int64(10)));
+    assert!(eval_index(&index, "lt", "v['a']", int64(2)));
+    assert!(!eval_index(&index, "lt", "v['a']", int64(1)));
+
+    // float values
+    let float64 = |v: f64| Scalar::Number(NumberScalar::Float64(v.into()));
+    assert!(eval_index(&index, "gt", "v['b']", float64(3.0)));
+    assert!(!eval_index(&index, "gt", "v['b']", float64(4.0)));
+    // casting float values to integers is not rewritten
+    assert!(eval_index(&index, "gt", "v['b']", int64(4)));
+
+    // string values with filter
+    assert!(index.filters.contains_key("v['c']"));
+    let string = |v: &str| Scalar::String(v.to_string());
+    assert!(eval_index(&index, "eq", "v['c']", string("def")));
+    assert!(!eval_index(&index, "eq", "v['c']", string("ghi")));
+    assert!(!eval_index(&index, "lt", "v['c']", string("abc")));
+
+    // mixed values have no index
+    assert!(!index.col_stats.contains_key("v['d']"));
+    assert!(!index.filters.contains_key("v['d']"));
+    assert!(eval_index(&index, "eq", "v['d']", int64(100)));
+
+    // virtual columns without index are kept
+    assert!(eval_index(&index, "eq", "v['e']", int64(100)));
+
+    Ok(())
+}
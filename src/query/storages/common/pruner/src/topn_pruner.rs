@@ -73,10 +73,13 @@ impl TopNPrunner {
             return Ok(metas);
         };
 
-        // String Type min/max is truncated
+        // String Type min/max is truncated, Geometry Type min/max are corners of bounding box
         if matches!(
-            self.schema.field_with_name(column)?.data_type(),
-            TableDataType::String
+            self.schema
+                .field_with_name(column)?
+                .data_type()
+                .remove_nullable(),
+            TableDataType::String | TableDataType::Geometry
         ) {
             return Ok(metas);
         }
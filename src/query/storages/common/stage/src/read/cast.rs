@@ -80,6 +80,14 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (_, Tuple(_)) | (Tuple(_), _) => false,
 
+        // [specificity] parquet fixed size lists and float arrays, the dimension is checked per row
+        (Array(box Number(_) | box Decimal(_)), Vector(_)) => true,
+        (_, Vector(_)) | (Vector(_), _) => false,
+
+        // [specificity] string values are checked against the dictionary per row
+        (String | Enum(_), Enum(_)) => true,
+        (_, Enum(_)) | (Enum(_), _) => false,
+
         (Array(box from_ty), Array(box to_ty)) => load_can_auto_cast_to(from_ty, to_ty),
         (EmptyArray, Array(_)) => true,
         (_, Array(_)) | (Array(_), _) => false,
@@ -112,6 +120,11 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
         (String | Number(_) | Decimal(_) | Boolean, Number(_) | Decimal(_)) => true,
         (_, Number(_) | Decimal(_)) => false,
 
+        // [specificity] floats are not accepted, to avoid silently truncating them
+        (String | LargeInt(_), LargeInt(_)) => true,
+        (Number(nt), LargeInt(_)) => nt.is_integer(),
+        (_, LargeInt(_)) => false,
+
         // [useless]: in SQL, cast a single int8 to Timestamp|Date is Ok, but cast such a column is not reasonable
         // (Int16, Date) => false: 1880-01-03 to 2159-12-30, disabled for now
         (Number(Int64 | Int32), Date) => true,
@@ -121,6 +134,8 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
         (Number(Int64 | Int32), Timestamp) => true,
         // [specificity]
         (String | Variant | Timestamp | Date, Timestamp | Date) => true,
+        // [convenience] parquet timestamps adjusted to UTC are read as TimestampTz
+        (TimestampTz, Timestamp) => true,
         (_, Timestamp | Date) => false,
 
         // [useless]
@@ -143,5 +158,21 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
         // TODO:
         // (String | Binary | Variant, Geography) => true,
         (_, Geography) => false,
+
+        // [specificity]
+        (String, Interval) => true,
+        (_, Interval) => false,
+
+        // [specificity]
+        (String, Time) => true,
+        (_, Time) => false,
+
+        // [specificity]
+        (String | Timestamp, TimestampTz) => true,
+        (_, TimestampTz) => false,
+
+        // [specificity] parquet FIXED_LEN_BYTE_ARRAY(16) is read as binary, the length is checked per row
+        (String | Binary, Uuid) => true,
+        (_, Uuid) => false,
     }
 }
@@ -36,6 +36,7 @@ use crate::index::InvertedIndexFile;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VIRTUAL_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
 static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
@@ -197,6 +198,15 @@ impl TableMetaLocationGenerator {
             InvertedIndexFile::VERSION,
         )
     }
+
+    pub fn gen_virtual_index_location_from_block_location(loc: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_vacuum2_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        format!("{}/{}/{}.mpk", prefix, FUSE_TBL_VIRTUAL_INDEX_PREFIX, id)
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -20,7 +20,7 @@ use databend_common_expression::ColumnId;
 use databend_common_metrics::storage::metrics_inc_block_index_read_bytes;
 use databend_storages_common_cache::CacheKey;
 use databend_storages_common_cache::CachedObject;
-use databend_storages_common_cache::InMemoryCacheReader;
+use databend_storages_common_cache::HybridCacheReader;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_cache::Loader;
 use databend_storages_common_index::filters::Filter;
@@ -35,7 +35,7 @@ use parquet::schema::types::SchemaDescPtr;
 
 use crate::io::read::block::parquet::RowGroupImplBuilder;
 
-type CachedReader = InMemoryCacheReader<Xor8Filter, Xor8FilterLoader>;
+type CachedReader = HybridCacheReader<Xor8Filter, Xor8FilterLoader>;
 
 /// Load the filter of a given bloom index column. Also
 /// - generates the proper cache key
@@ -24,7 +24,7 @@ use databend_common_exception::Result;
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_read_milliseconds;
 use databend_storages_common_cache::CacheKey;
 use databend_storages_common_cache::CachedObject;
-use databend_storages_common_cache::InMemoryCacheReader;
+use databend_storages_common_cache::HybridCacheReader;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_cache::Loader;
 use databend_storages_common_index::InvertedIndexDirectory;
@@ -36,7 +36,7 @@ use opendal::Operator;
 use crate::index::InvertedIndexFile;
 use crate::io::MetaReaders;
 
-type CachedReader = InMemoryCacheReader<InvertedIndexFile, InvertedIndexFileLoader>;
+type CachedReader = HybridCacheReader<InvertedIndexFile, InvertedIndexFileLoader>;
 
 const INDEX_COLUMN_NAMES: [&str; 8] = [
     "fast",
@@ -21,11 +21,13 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_FOOTER_READ_SIZE;
 use databend_storages_common_cache::CacheManager;
+use databend_storages_common_cache::HybridCacheReader;
 use databend_storages_common_cache::InMemoryItemCacheReader;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_cache::Loader;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_index::InvertedIndexMeta;
+use databend_storages_common_index::VirtualColumnIndex;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::SegmentInfoVersion;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
@@ -45,12 +47,14 @@ use self::thrift_file_meta_read::read_thrift_file_metadata;
 
 pub type TableSnapshotStatisticsReader =
     InMemoryItemCacheReader<TableSnapshotStatistics, LoaderWrapper<Operator>>;
-pub type BloomIndexMetaReader = InMemoryItemCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
-pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
+pub type BloomIndexMetaReader = HybridCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
+pub type TableSnapshotReader = HybridCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
 pub type CompactSegmentInfoReader =
-    InMemoryItemCacheReader<CompactSegmentInfo, LoaderWrapper<(Operator, TableSchemaRef)>>;
+    HybridCacheReader<CompactSegmentInfo, LoaderWrapper<(Operator, TableSchemaRef)>>;
 pub type InvertedIndexMetaReader =
     InMemoryItemCacheReader<InvertedIndexMeta, LoaderWrapper<Operator>>;
+pub type VirtualColumnIndexReader =
+    InMemoryItemCacheReader<VirtualColumnIndex, LoaderWrapper<Operator>>;
 
 pub struct MetaReaders;
 
@@ -100,6 +104,13 @@ impl MetaReaders {
             LoaderWrapper(dal),
         )
     }
+
+    pub fn virtual_column_index_reader(dal: Operator) -> VirtualColumnIndexReader {
+        VirtualColumnIndexReader::new(
+            CacheManager::instance().get_virtual_column_index_cache(),
+            LoaderWrapper(dal),
+        )
+    }
 }
 
 // workaround for the orphan rules
@@ -254,6 +265,15 @@ impl Loader<InvertedIndexMeta> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<VirtualColumnIndex> for LoaderWrapper<Operator> {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<VirtualColumnIndex> {
+        let buffer = bytes_reader(&self.0, params.location.as_str(), params.len_hint).await?;
+        VirtualColumnIndex::from_bytes(&buffer.to_vec())
+    }
+}
+
 async fn bytes_reader(op: &Operator, path: &str, len_hint: Option<u64>) -> Result<Buffer> {
     let reader = if let Some(len) = len_hint {
         op.read_with(path).range(0..len).await?
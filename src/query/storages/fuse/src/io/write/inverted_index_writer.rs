@@ -45,6 +45,7 @@ use tantivy::schema::TextFieldIndexing;
 use tantivy::schema::TextOptions;
 use tantivy::tokenizer::Language;
 use tantivy::tokenizer::LowerCaser;
+use tantivy::tokenizer::NgramTokenizer;
 use tantivy::tokenizer::SimpleTokenizer;
 use tantivy::tokenizer::Stemmer;
 use tantivy::tokenizer::StopWordFilter;
@@ -327,8 +328,15 @@ pub(crate) fn create_tokenizer_manager(
         (english_analyzer.build(), chinese_analyzer.build())
     };
 
+    // split the text into overlapping grams of 3 characters, similar strings share
+    // most of their grams, so it can be used to find the candidates of fuzzy matching.
+    let ngram_analyzer = TextAnalyzer::builder(NgramTokenizer::new(3, 3, false).unwrap())
+        .filter(LowerCaser)
+        .build();
+
     tokenizer_manager.register("english", english_analyzer);
     tokenizer_manager.register("chinese", chinese_analyzer);
+    tokenizer_manager.register("ngram", ngram_analyzer);
     tokenizer_manager
 }
 
@@ -29,6 +29,7 @@ use databend_storages_common_cache::LoadParams;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_index::InvertedIndexFile;
 use databend_storages_common_index::InvertedIndexMeta;
+use databend_storages_common_index::VirtualColumnIndex;
 use databend_storages_common_io::Files;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::Location;
@@ -417,11 +418,15 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             let mut inverted_indexes_to_be_purged = HashSet::new();
+            let mut virtual_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
                 if locations_referenced_by_root.block_location.contains(loc) {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
+                virtual_indexes_to_be_purged.insert(
+                    TableMetaLocationGenerator::gen_virtual_index_location_from_block_location(loc),
+                );
                 for index_id in table_agg_index_ids {
                     agg_indexes_to_be_purged.insert(
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
@@ -474,6 +479,7 @@ impl FuseTable {
                 blocks_to_be_purged,
                 agg_indexes_to_be_purged,
                 inverted_indexes_to_be_purged,
+                virtual_indexes_to_be_purged,
                 blooms_to_be_purged,
                 segment_locations_to_be_purged,
             )
@@ -528,12 +534,23 @@ impl FuseTable {
             ));
         }
 
+        // The virtual column index files of blocks that have not been refreshed don't exist,
+        // removing them is a no-op.
+        let virtual_indexes_to_be_purged = root_location_tuple
+            .block_location
+            .iter()
+            .map(|loc| {
+                TableMetaLocationGenerator::gen_virtual_index_location_from_block_location(loc)
+            })
+            .collect();
+
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
             inverted_indexes_to_be_purged,
+            virtual_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
         )
@@ -559,6 +576,7 @@ impl FuseTable {
         blocks_to_be_purged: HashSet<String>,
         agg_indexes_to_be_purged: HashSet<String>,
         inverted_indexes_to_be_purged: HashSet<String>,
+        virtual_indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
@@ -599,6 +617,16 @@ impl FuseTable {
             .await?;
         }
 
+        let virtual_index_count = virtual_indexes_to_be_purged.len();
+        if virtual_index_count > 0 {
+            counter.virtual_indexes += virtual_index_count;
+            self.try_purge_location_files_and_cache::<VirtualColumnIndex, _>(
+                ctx.clone(),
+                virtual_indexes_to_be_purged,
+            )
+            .await?;
+        }
+
         // 2. Try to purge bloom index file chunks.
         let blooms_count = blooms_to_be_purged.len();
         if blooms_count > 0 {
@@ -787,6 +815,7 @@ struct PurgeCounter {
     blocks: usize,
     agg_indexes: usize,
     inverted_indexes: usize,
+    virtual_indexes: usize,
     blooms: usize,
     segments: usize,
     table_statistics: usize,
@@ -800,6 +829,7 @@ impl PurgeCounter {
             blocks: 0,
             agg_indexes: 0,
             inverted_indexes: 0,
+            virtual_indexes: 0,
             blooms: 0,
             segments: 0,
             table_statistics: 0,
@@ -613,7 +613,8 @@ impl AggregationContext {
         key_min: &Scalar,
         key_max: &Scalar,
     ) -> bool {
-        if let Some(stats) = column_stats {
+        // the min/max of geometry are the corners of bounding box, assume overlapped
+        if let Some(stats) = column_stats.filter(|s| !matches!(s.min(), Scalar::Geometry(_))) {
             let max = stats.max();
             let min = stats.min();
             std::cmp::min(key_max, max) >= std::cmp::max(key_min,min)
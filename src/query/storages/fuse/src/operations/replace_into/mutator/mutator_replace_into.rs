@@ -157,7 +157,8 @@ impl ReplaceIntoMutator {
                 let column: &Value<AnyType> = &data_block.columns()[field.field_index].value;
                 let value = column.row_scalar(row_idx)?;
                 let stats = column_stats.get(&field.table_field.column_id);
-                if let Some(stats) = stats {
+                // the min/max of geometry are the corners of bounding box, not comparable with values
+                if let Some(stats) = stats.filter(|s| !matches!(s.min(), Scalar::Geometry(_))) {
                     should_keep = !(value < stats.min().as_ref() || value > stats.max().as_ref());
                     if !should_keep {
                         // if one column outsides the table level range, no need to check other columns
@@ -54,8 +54,9 @@ impl BlockPruner {
         // Apply block pruning.
         if self.pruning_ctx.bloom_pruner.is_some()
             || self.pruning_ctx.inverted_index_pruner.is_some()
+            || self.pruning_ctx.virtual_column_pruner.is_some()
         {
-            // async pruning with bloom index or inverted index.
+            // async pruning with bloom index, inverted index or virtual column index.
             self.block_pruning(segment_location, block_metas, block_meta_indexes)
                 .await
         } else {
@@ -86,7 +87,7 @@ impl BlockPruner {
         }
     }
 
-    // async pruning with bloom index or inverted index.
+    // async pruning with bloom index, inverted index or virtual column index.
     #[async_backtrace::framed]
     async fn block_pruning(
         &self,
@@ -102,6 +103,7 @@ impl BlockPruner {
         let page_pruner = self.pruning_ctx.page_pruner.clone();
         let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
         let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
+        let virtual_column_pruner = self.pruning_ctx.virtual_column_pruner.clone();
 
         let mut block_meta_indexes = block_meta_indexes.into_iter();
         let pruning_tasks = std::iter::from_fn(|| {
@@ -149,6 +151,7 @@ impl BlockPruner {
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
                     let inverted_index_pruner = inverted_index_pruner.clone();
+                    let virtual_column_pruner = virtual_column_pruner.clone();
                     let block_location = block_meta.location.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
                     let index_size = block_meta.bloom_filter_index_size;
@@ -157,7 +160,17 @@ impl BlockPruner {
                     let v: BlockPruningFuture = Box::new(move |permit: OwnedSemaphorePermit| {
                         Box::pin(async move {
                             let _permit = permit;
-                            let keep = if let Some(bloom_pruner) = bloom_pruner {
+                            let keep_by_virtual_column = match &virtual_column_pruner {
+                                Some(virtual_column_pruner) => {
+                                    virtual_column_pruner
+                                        .should_keep(&block_location.0, &block_meta.col_stats)
+                                        .await
+                                }
+                                None => true,
+                            };
+                            let keep = if !keep_by_virtual_column {
+                                false
+                            } else if let Some(bloom_pruner) = bloom_pruner {
                                 // Perf.
                                 {
                                     metrics_inc_blocks_bloom_pruning_before(1);
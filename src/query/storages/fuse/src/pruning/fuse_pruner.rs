@@ -60,6 +60,7 @@ use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VirtualColumnPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -73,6 +74,7 @@ pub struct PruningContext {
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
     pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub virtual_column_pruner: Option<Arc<VirtualColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
 }
@@ -155,6 +157,15 @@ impl PruningContext {
         // inverted index pruner, used to search matched rows in block
         let inverted_index_pruner = InvertedIndexPruner::try_create(dal.clone(), push_down)?;
 
+        // Virtual column pruner, used to prune blocks by the statistics and filters of virtual columns
+        let virtual_column_pruner = VirtualColumnPruner::try_create(
+            func_ctx.clone(),
+            &table_schema,
+            dal.clone(),
+            push_down,
+            filter_expr.as_ref(),
+        )?;
+
         // Internal column pruner, if there are predicates using internal columns,
         // we can use them to prune segments and blocks.
         let internal_column_pruner =
@@ -182,6 +193,7 @@ impl PruningContext {
             page_pruner,
             internal_column_pruner,
             inverted_index_pruner,
+            virtual_column_pruner,
             pruning_stats,
         });
         Ok(pruning_ctx)
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -20,12 +21,16 @@ use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
 use databend_common_expression::types::F32;
 use opendal::Operator;
+use tantivy::query::BooleanQuery;
+use tantivy::query::Occur;
 use tantivy::query::Query;
 use tantivy::query::QueryClone;
 use tantivy::query::QueryParser;
+use tantivy::query::TermQuery;
 use tantivy::schema::Field;
 use tantivy::schema::IndexRecordOption;
 use tantivy::tokenizer::TokenizerManager;
+use tantivy::Term;
 
 use crate::io::create_index_schema;
 use crate::io::create_tokenizer_manager;
@@ -181,8 +186,8 @@ pub fn create_inverted_index_query(
         .and_then(|o| o.fuzziness);
     if let Some(fuzziness) = fuzziness {
         // Fuzzy query matches rows containing a specific term that is within Levenshtein distance.
-        for field in query_fields {
-            query_parser.set_field_fuzzy(field, false, fuzziness, true);
+        for field in query_fields.iter() {
+            query_parser.set_field_fuzzy(*field, false, fuzziness, true);
         }
     }
     let operator = inverted_index_info
@@ -200,7 +205,17 @@ pub fn create_inverted_index_query(
         .as_ref()
         .map(|o| o.lenient)
         .unwrap_or_default();
-    let query = if lenient {
+    let is_ngram = inverted_index_info
+        .index_options
+        .get("tokenizer")
+        .is_some_and(|tokenizer| tokenizer == "ngram");
+    let query = if is_ngram {
+        create_ngram_query(
+            &query_fields,
+            &inverted_index_info.query_text,
+            &tokenizer_manager,
+        )
+    } else if lenient {
         // If lenient is TRUE, invalid query text will not report an error.
         let (query, _) = query_parser.parse_query_lenient(&inverted_index_info.query_text);
         query
@@ -210,3 +225,33 @@ pub fn create_inverted_index_query(
 
     Ok((query, fuzziness, tokenizer_manager))
 }
+
+// The n-gram index is used to find the candidates of fuzzy matching, so the rows
+// sharing any gram with the query text are matched, instead of parsing the text
+// with query syntax, which requires all the grams of a word as a phrase.
+fn create_ngram_query(
+    query_fields: &[Field],
+    query_text: &str,
+    tokenizer_manager: &TokenizerManager,
+) -> Box<dyn Query> {
+    let mut grams = BTreeSet::new();
+    if let Some(mut analyzer) = tokenizer_manager.get("ngram") {
+        let mut token_stream = analyzer.token_stream(query_text);
+        token_stream.process(&mut |token| {
+            grams.insert(token.text.clone());
+        });
+    }
+
+    let mut sub_queries: Vec<(Occur, Box<dyn Query>)> =
+        Vec::with_capacity(query_fields.len() * grams.len());
+    for field in query_fields {
+        for gram in &grams {
+            let term = Term::from_field_text(*field, gram);
+            sub_queries.push((
+                Occur::Should,
+                Box::new(TermQuery::new(term, IndexRecordOption::Basic)),
+            ));
+        }
+    }
+    Box::new(BooleanQuery::new(sub_queries))
+}
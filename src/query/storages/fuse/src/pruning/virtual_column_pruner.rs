@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_index::VirtualColumnIndex;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use log::warn;
+use opendal::Operator;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+
+// Each block with refreshed virtual columns has a corresponding virtual column index file,
+// which contains the statistics and filters of the JSON values in the virtual columns.
+// The filter is rewritten with the index of each block, and the block can be pruned
+// if the rewritten filter is folded to constant false.
+pub struct VirtualColumnPruner {
+    dal: Operator,
+    range_index: RangeIndex,
+}
+
+impl VirtualColumnPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        push_down: &Option<PushDownInfo>,
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<VirtualColumnPruner>>> {
+        let (Some(virtual_columns), Some(filter_expr)) = (
+            push_down.as_ref().and_then(|p| p.virtual_columns.as_ref()),
+            filter_expr,
+        ) else {
+            return Ok(None);
+        };
+
+        // Only create the pruner if the filter uses virtual columns.
+        let column_refs = filter_expr.column_refs();
+        if !virtual_columns
+            .iter()
+            .any(|v| column_refs.contains_key(&v.name))
+        {
+            return Ok(None);
+        }
+
+        let range_index =
+            RangeIndex::try_create(func_ctx, filter_expr, schema.clone(), Default::default())?;
+        Ok(Some(Arc::new(VirtualColumnPruner { dal, range_index })))
+    }
+
+    // returns true, if target should NOT be pruned (false positive allowed)
+    #[async_backtrace::framed]
+    pub async fn should_keep(&self, block_loc: &str, stats: &StatisticsOfColumns) -> bool {
+        let index_loc =
+            TableMetaLocationGenerator::gen_virtual_index_location_from_block_location(block_loc);
+
+        match self.load_index(&index_loc).await {
+            Ok(Some(index)) => self
+                .range_index
+                .apply_with_virtual_column_index(stats, index.as_ref())
+                .unwrap_or_else(|e| {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    warn!(
+                        "failed to apply virtual column index, returning true. {}",
+                        e
+                    );
+                    true
+                }),
+            Ok(None) => true,
+            Err(e) => {
+                warn!(
+                    "failed to load virtual column index {}, returning true. {}",
+                    index_loc, e
+                );
+                true
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn load_index(&self, index_loc: &str) -> Result<Option<Arc<VirtualColumnIndex>>> {
+        let reader = MetaReaders::virtual_column_index_reader(self.dal.clone());
+        let load_params = LoadParams {
+            location: index_loc.to_string(),
+            len_hint: None,
+            ver: 0,
+            put_cache: true,
+        };
+        match reader.read(&load_params).await {
+            Ok(index) => Ok(Some(index)),
+            // The virtual columns of this block may not be refreshed yet.
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::NumberType;
@@ -23,10 +24,13 @@ use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::Value;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COLUMN_ID;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_common_io::geometry_bbox;
+use databend_common_io::BoundingBox;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -64,6 +68,14 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        // Geometry columns keep the bounding box of the block, used by spatial pruning.
+        if data_type.remove_nullable() == DataType::Geometry {
+            if let Some(col_stats) = gen_geometry_statistics(col, data_type, rows)? {
+                statistics.insert(column_id, col_stats);
+            }
+            continue;
+        }
+
         // Ignore the range index does not supported type.
         if !RangeIndex::supported_type(data_type) {
             continue;
@@ -177,6 +189,46 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+/// The statistics of a geometry column store the lower-left and upper-right corners
+/// of the bounding box as `min` and `max`.
+fn gen_geometry_statistics(
+    value: &Value<AnyType>,
+    data_type: &DataType,
+    rows: usize,
+) -> Result<Option<ColumnStatistics>> {
+    let column = value.convert_to_full_column(data_type, rows);
+    let mut bbox: Option<BoundingBox> = None;
+    let mut null_count = 0;
+    for scalar in column.iter() {
+        match scalar {
+            ScalarRef::Geometry(buf) => {
+                if let Some(row_bbox) = geometry_bbox(buf)? {
+                    bbox = Some(bbox.map_or(row_bbox, |v| v.merge(&row_bbox)));
+                }
+            }
+            _ => null_count += 1,
+        }
+    }
+
+    let (min, max) = match bbox {
+        Some(bbox) => {
+            let (min, max) = bbox.to_ewkb_points()?;
+            (Scalar::Geometry(min), Scalar::Geometry(max))
+        }
+        None if null_count == rows => (Scalar::Null, Scalar::Null),
+        // Only empty geometries, which have no bounding box.
+        None => return Ok(None),
+    };
+    let distinct_of_values = calc_column_distinct_of_values(&column, rows)?;
+    Ok(Some(ColumnStatistics::new(
+        min,
+        max,
+        null_count as u64,
+        column.memory_size() as u64,
+        Some(distinct_of_values),
+    )))
+}
+
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
     if RangeIndex::supported_type(data_type) {
         if let Some((min, Some(max))) = scalar
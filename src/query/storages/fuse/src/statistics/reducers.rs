@@ -18,6 +18,7 @@ use std::collections::HashMap;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
+use databend_common_io::BoundingBox;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -62,17 +63,26 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            let min = min_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .min_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+            let (min, max) = if min_stats.iter().any(|s| matches!(s, Scalar::Geometry(_))) {
+                // The min/max of geometry are the corners of bounding box.
+                match reduce_geometry_bbox(&min_stats, &max_stats) {
+                    Some(min_max) => min_max,
+                    None => return acc,
+                }
+            } else {
+                let min = min_stats
+                    .into_iter()
+                    .filter(|s| !s.is_null())
+                    .min_by(|x, y| x.cmp(y))
+                    .unwrap_or(Scalar::Null);
 
-            let max = max_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .max_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+                let max = max_stats
+                    .into_iter()
+                    .filter(|s| !s.is_null())
+                    .max_by(|x, y| x.cmp(y))
+                    .unwrap_or(Scalar::Null);
+                (min, max)
+            };
 
             acc.insert(
                 *id,
@@ -82,6 +92,22 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+fn reduce_geometry_bbox(min_stats: &[Scalar], max_stats: &[Scalar]) -> Option<(Scalar, Scalar)> {
+    let mut bbox: Option<BoundingBox> = None;
+    for (min, max) in min_stats.iter().zip(max_stats) {
+        match (min, max) {
+            (Scalar::Geometry(min), Scalar::Geometry(max)) => {
+                let other = BoundingBox::from_ewkb_points(min, max).ok()?;
+                bbox = Some(bbox.map_or(other, |v| v.merge(&other)));
+            }
+            (Scalar::Null, Scalar::Null) => {}
+            _ => return None,
+        }
+    }
+    let (min, max) = bbox?.to_ewkb_points().ok()?;
+    Some((Scalar::Geometry(min), Scalar::Geometry(max)))
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
@@ -16,6 +16,7 @@ use sha2::Digest;
 use sha2::Sha256;
 
 const RESULT_CACHE_PREFIX: &str = "_result_cache";
+const RESULT_PERSIST_PREFIX: &str = "_result_persist";
 
 #[inline(always)]
 pub fn gen_result_cache_key(raw: &str) -> String {
@@ -37,6 +38,24 @@ pub(crate) fn gen_result_cache_dir(key: &str) -> String {
     format!("{RESULT_CACHE_PREFIX}/{key}")
 }
 
+/// The meta key of the persisted result of a query, which is looked up by the query id
+/// instead of the query text.
+#[inline(always)]
+pub fn gen_result_persist_meta_key(tenant: &str, query_id: &str) -> String {
+    format!("{RESULT_PERSIST_PREFIX}/{tenant}/{query_id}")
+}
+
+/// The prefix of the meta keys, and also the directory of the files, of the persisted results.
+#[inline(always)]
+pub(crate) fn gen_result_persist_prefix(tenant: &str) -> String {
+    format!("{RESULT_PERSIST_PREFIX}/{tenant}/")
+}
+
+#[inline(always)]
+pub(crate) fn gen_result_persist_dir(tenant: &str, query_id: &str) -> String {
+    format!("{RESULT_PERSIST_PREFIX}/{tenant}/{query_id}")
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ResultCacheValue {
     /// The original query SQL.
@@ -55,4 +74,22 @@ pub struct ResultCacheValue {
     pub partitions_shas: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
+    /// The locations of all the files, the persisted result may be written to multiple files.
+    #[serde(default)]
+    pub locations: Vec<String>,
+    /// The user who executed the query, set for the persisted result which can only be
+    /// read by its owner.
+    #[serde(default)]
+    pub owner: Option<String>,
+}
+
+impl ResultCacheValue {
+    /// The files of the result, the result cache is always written to a single file.
+    pub fn files(&self) -> Vec<String> {
+        if self.locations.is_empty() {
+            vec![self.location.clone()]
+        } else {
+            self.locations.clone()
+        }
+    }
 }
@@ -24,6 +24,7 @@ mod write;
 pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use common::gen_result_persist_meta_key;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
@@ -91,7 +91,11 @@ impl ResultCacheReader {
                     if value.num_rows == 0 {
                         Ok(Some(vec![DataBlock::empty()]))
                     } else {
-                        Ok(Some(self.read_result_from_cache(&value.location).await?))
+                        let mut blocks = vec![];
+                        for location in value.files() {
+                            blocks.extend(self.read_result_from_cache(&location).await?);
+                        }
+                        Ok(Some(blocks))
                     }
                 } else {
                     // The cache is invalid (due to data update or other reasons).
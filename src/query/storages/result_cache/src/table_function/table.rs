@@ -48,16 +48,21 @@ const RESULT_SCAN: &str = "result_scan";
 pub struct ResultScan {
     table_info: TableInfo,
     query_id: String,
-    location: String,
+    files: Vec<(String, u64)>,
     schema: Schema,
-    file_size: u64,
 }
 
 impl ResultScan {
-    pub async fn try_create(query_id: String, location: String) -> Result<Arc<dyn Table>> {
+    /// Create the table of the result files, which are written with the same schema.
+    pub async fn try_create(query_id: String, locations: Vec<String>) -> Result<Arc<dyn Table>> {
         let op = DataOperator::instance().operator();
-        let file_size = op.stat(&location).await?.content_length();
-        let metadata = read_metadata_async(&location, &op, Some(file_size)).await?;
+        let mut files = Vec::with_capacity(locations.len());
+        for location in locations {
+            let file_size = op.stat(&location).await?.content_length();
+            files.push((location, file_size));
+        }
+        let (location, file_size) = &files[0];
+        let metadata = read_metadata_async(location, &op, Some(*file_size)).await?;
         let schema = infer_schema_with_extension(metadata.file_metadata())?;
         let table_schema = TableSchema::try_from(&schema)?;
 
@@ -77,8 +82,7 @@ impl ResultScan {
             table_info,
             query_id,
             schema,
-            location,
-            file_size,
+            files,
         }))
     }
 
@@ -86,9 +90,8 @@ impl ResultScan {
         Ok(Arc::new(ResultScan {
             table_info: info.table_info.clone(),
             query_id: info.query_id.clone(),
-            location: info.location.clone(),
+            files: info.files.clone(),
             schema: info.schema.clone(),
-            file_size: info.file_size,
         }))
     }
 }
@@ -111,9 +114,8 @@ impl Table for ResultScan {
         DataSourceInfo::ResultScanSource(ResultScanTableInfo {
             table_info: self.table_info.clone(),
             query_id: self.query_id.clone(),
-            location: self.location.clone(),
+            files: self.files.clone(),
             schema: self.schema.clone(),
-            file_size: self.file_size,
         })
     }
 
@@ -124,15 +126,22 @@ impl Table for ResultScan {
         _push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
-        let part = ParquetPart::ParquetFiles(ParquetFilesPart {
-            files: vec![(self.location.clone(), self.file_size)],
-            estimated_uncompressed_size: self.file_size,
-        });
-
-        let part_info: Box<dyn PartInfo> = Box::new(part);
+        // A partition for each file, which are read one by one in order.
+        let parts = self
+            .files
+            .iter()
+            .map(|(location, file_size)| {
+                let part = ParquetPart::ParquetFiles(ParquetFilesPart {
+                    files: vec![(location.clone(), *file_size)],
+                    estimated_uncompressed_size: *file_size,
+                });
+                let part_info: Box<dyn PartInfo> = Box::new(part);
+                Arc::new(part_info) as _
+            })
+            .collect();
         Ok((
             PartStatistics::default(),
-            Partitions::create(PartitionsShuffleKind::Mod, vec![Arc::new(part_info) as _]),
+            Partitions::create(PartitionsShuffleKind::Seq, parts),
         ))
     }
 
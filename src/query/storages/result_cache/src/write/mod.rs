@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod sink;
+mod vacuum;
 mod writer;
 
 pub use sink::WriteResultCacheSink;
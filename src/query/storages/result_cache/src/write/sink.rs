@@ -27,14 +27,23 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sinks::AsyncMpscSink;
 use databend_common_pipeline_sinks::AsyncMpscSinker;
 use databend_common_storage::DataOperator;
+use log::warn;
 use tokio::time::Instant;
 
+use super::vacuum::vacuum_persisted_results;
 use super::writer::ResultCacheWriter;
 use crate::common::gen_result_cache_dir;
 use crate::common::gen_result_cache_meta_key;
+use crate::common::gen_result_persist_dir;
+use crate::common::gen_result_persist_meta_key;
 use crate::common::ResultCacheValue;
 use crate::meta_manager::ResultCacheMetaManager;
 
+/// The persisted result is written to multiple files of about this size.
+const PERSISTED_RESULT_FILE_BYTES: usize = 64 * 1024 * 1024;
+/// The max number of the expired persisted results removed after a result is persisted.
+const VACUUM_PERSISTED_RESULTS_LIMIT: usize = 100;
+
 pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
@@ -50,6 +59,10 @@ pub struct WriteResultCacheSink {
     // A flag indicates at least one block has been consumed.
     consumed_one_block: bool,
     terminated: bool,
+    // Whether the result is persisted by the query id, an empty result is also written.
+    persisted: bool,
+    // The user who executed the query, only set for the persisted result.
+    owner: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -73,6 +86,9 @@ impl AsyncMpscSink for WriteResultCacheSink {
 
         if !self.cache_writer.over_limit() {
             self.cache_writer.append_block(block);
+            if self.cache_writer.need_flush() {
+                self.cache_writer.flush().await?;
+            }
             Ok(false)
         } else {
             self.terminated = true;
@@ -83,12 +99,12 @@ impl AsyncMpscSink for WriteResultCacheSink {
 
     #[async_backtrace::framed]
     async fn on_finish(&mut self) -> Result<()> {
-        if self.terminated || self.cache_writer.num_rows() == 0 {
+        if self.terminated || (!self.persisted && self.cache_writer.num_rows() == 0) {
             return Ok(());
         }
 
         // 1. Write the result cache to the storage, blocks must be !empty.
-        let location = self.cache_writer.write_to_storage().await?;
+        let locations = self.cache_writer.write_to_storage().await?;
 
         // 2. Set result cache key-value pair to meta.
         let now = SeqV::<()>::now_ms() / 1000;
@@ -103,13 +119,31 @@ impl AsyncMpscSink for WriteResultCacheSink {
             partitions_shas: self.partitions_shas.clone(),
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
-            location,
+            location: locations[0].clone(),
+            locations,
+            owner: self.owner.clone(),
         };
         self.meta_mgr
             .set(self.meta_key.clone(), value, MatchSeq::GE(0), ttl_interval)
             .await?;
         self.ctx
             .set_query_id_result_cache(self.ctx.get_id(), self.meta_key.clone());
+
+        // 3. Remove the files of the expired persisted results, it is rate limited per tenant.
+        if self.persisted {
+            let tenant = self.ctx.get_tenant();
+            let operator = DataOperator::instance().operator();
+            if let Err(e) = vacuum_persisted_results(
+                &operator,
+                &self.meta_mgr,
+                tenant.tenant_name(),
+                VACUUM_PERSISTED_RESULTS_LIMIT,
+            )
+            .await
+            {
+                warn!("failed to vacuum the expired persisted results: {}", e);
+            }
+        }
         Ok(())
     }
 }
@@ -127,8 +161,6 @@ impl WriteResultCacheSink {
         let min_execute_secs = settings.get_query_result_cache_min_execute_secs()?;
         let ttl = settings.get_query_result_cache_ttl_secs()?;
         let tenant = ctx.get_tenant();
-        let sql = ctx.get_query_str();
-        let partitions_shas = ctx.get_partitions_shas();
 
         let meta_key = gen_result_cache_meta_key(tenant.tenant_name(), key);
         let location = gen_result_cache_dir(key);
@@ -137,19 +169,70 @@ impl WriteResultCacheSink {
         let cache_writer =
             ResultCacheWriter::create(schema, location, operator, max_bytes, min_execute_secs);
 
-        Ok(ProcessorPtr::create(AsyncMpscSinker::create(
+        Ok(Self::create(
+            ctx,
             inputs,
-            WriteResultCacheSink {
-                ctx,
-                sql,
-                partitions_shas,
-                meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
-                meta_key,
-                cache_writer,
-                create_time: Instant::now(),
-                consumed_one_block: false,
-                terminated: false,
-            },
-        )))
+            ResultCacheMetaManager::create(kv_store, ttl),
+            meta_key,
+            cache_writer,
+            None,
+        ))
+    }
+
+    /// Create a sink to persist the complete result of the query, which is looked up by the query id.
+    ///
+    /// Unlike the result cache, the result is written regardless of its size and execution time.
+    pub fn try_create_persisted(
+        ctx: Arc<dyn TableContext>,
+        schema: TableSchemaRef,
+        inputs: Vec<Arc<InputPort>>,
+        kv_store: Arc<MetaStore>,
+    ) -> Result<ProcessorPtr> {
+        let ttl = ctx.get_settings().get_persist_query_result_ttl_secs()?;
+        let tenant = ctx.get_tenant();
+        let query_id = ctx.get_id();
+
+        let meta_key = gen_result_persist_meta_key(tenant.tenant_name(), &query_id);
+        let location = gen_result_persist_dir(tenant.tenant_name(), &query_id);
+
+        let operator = DataOperator::instance().operator();
+        let cache_writer = ResultCacheWriter::create(schema, location, operator, usize::MAX, 0)
+            .with_flush_bytes(PERSISTED_RESULT_FILE_BYTES);
+        let owner = ctx.get_current_user()?.identity().display().to_string();
+
+        Ok(Self::create(
+            ctx,
+            inputs,
+            ResultCacheMetaManager::create(kv_store, ttl),
+            meta_key,
+            cache_writer,
+            Some(owner),
+        ))
+    }
+
+    fn create(
+        ctx: Arc<dyn TableContext>,
+        inputs: Vec<Arc<InputPort>>,
+        meta_mgr: ResultCacheMetaManager,
+        meta_key: String,
+        cache_writer: ResultCacheWriter,
+        owner: Option<String>,
+    ) -> ProcessorPtr {
+        let sql = ctx.get_query_str();
+        let partitions_shas = ctx.get_partitions_shas();
+
+        ProcessorPtr::create(AsyncMpscSinker::create(inputs, WriteResultCacheSink {
+            ctx,
+            sql,
+            partitions_shas,
+            meta_mgr,
+            meta_key,
+            cache_writer,
+            create_time: Instant::now(),
+            consumed_one_block: false,
+            terminated: false,
+            persisted: owner.is_some(),
+            owner,
+        }))
     }
 }
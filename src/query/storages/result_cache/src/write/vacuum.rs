@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use databend_common_exception::Result;
+use databend_common_meta_types::seq_value::SeqV;
+use opendal::Metakey;
+use opendal::Operator;
+
+use crate::common::gen_result_persist_prefix;
+use crate::meta_manager::ResultCacheMetaManager;
+
+/// The files of a running query are written before its meta, so the files without meta
+/// are only removed if they are not modified in this duration.
+const ORPHAN_RETAIN_SECS: u64 = 24 * 60 * 60;
+
+/// The expired persisted results of a tenant are vacuumed by a node at most once in
+/// this duration, the listing of the results is not repeated after every query.
+const VACUUM_INTERVAL_SECS: u64 = 10 * 60;
+
+/// The last time the persisted results of each tenant are vacuumed by this node.
+static LAST_VACUUM_TIME: LazyLock<Mutex<HashMap<String, u64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Return true if the persisted results of the tenant are not vacuumed in the last
+/// `VACUUM_INTERVAL_SECS`, and take `now` as the last vacuum time.
+fn need_vacuum(tenant: &str, now: u64) -> bool {
+    let mut last_vacuum_time = LAST_VACUUM_TIME.lock().unwrap();
+    match last_vacuum_time.get(tenant) {
+        Some(last) if *last + VACUUM_INTERVAL_SECS > now => false,
+        _ => {
+            last_vacuum_time.insert(tenant.to_string(), now);
+            true
+        }
+    }
+}
+
+/// Remove the files of the persisted results whose meta has expired, returns the number
+/// of the removed results.
+///
+/// At most `limit` results are removed, the others are left to the next vacuum. It's
+/// skipped if the tenant has been vacuumed by this node in `VACUUM_INTERVAL_SECS`.
+#[async_backtrace::framed]
+pub(crate) async fn vacuum_persisted_results(
+    operator: &Operator,
+    meta_mgr: &ResultCacheMetaManager,
+    tenant: &str,
+    limit: usize,
+) -> Result<usize> {
+    let now = SeqV::<()>::now_ms() / 1000;
+    if !need_vacuum(tenant, now) {
+        return Ok(0);
+    }
+
+    let prefix = gen_result_persist_prefix(tenant);
+    let alive = meta_mgr
+        .list(&prefix)
+        .await?
+        .into_iter()
+        .map(|value| value.query_id)
+        .collect::<HashSet<_>>();
+
+    let mut removed = 0;
+    for entry in operator.list_with(&prefix).metakey(Metakey::Mode).await? {
+        if removed >= limit {
+            break;
+        }
+        let query_id = entry.name().trim_end_matches('/');
+        if !entry.metadata().is_dir()
+            || entry.path() == prefix
+            || query_id.is_empty()
+            || alive.contains(query_id)
+        {
+            continue;
+        }
+
+        let last_modified = operator
+            .list_with(entry.path())
+            .metakey(Metakey::LastModified)
+            .await?
+            .iter()
+            .filter_map(|file| file.metadata().last_modified())
+            .map(|time| time.timestamp().max(0) as u64)
+            .max()
+            .unwrap_or(0);
+        if last_modified + ORPHAN_RETAIN_SECS > now {
+            continue;
+        }
+        operator.remove_all(entry.path()).await?;
+        removed += 1;
+    }
+    Ok(removed)
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_table_meta::table::TableCompression;
@@ -32,6 +35,11 @@ pub(super) struct ResultCacheWriter {
 
     schema: TableSchemaRef,
     blocks: Vec<DataBlock>,
+
+    // The blocks are written to a new file once their size reaches `flush_bytes`.
+    buffered_bytes: usize,
+    flush_bytes: usize,
+    files: Vec<String>,
 }
 
 impl ResultCacheWriter {
@@ -51,15 +59,31 @@ impl ResultCacheWriter {
             num_rows: 0,
             schema,
             blocks: vec![],
+            buffered_bytes: 0,
+            flush_bytes: usize::MAX,
+            files: vec![],
         }
     }
 
+    /// Write the buffered blocks to a new file once their size reaches `flush_bytes`,
+    /// instead of holding the whole result in memory.
+    pub fn with_flush_bytes(mut self, flush_bytes: usize) -> Self {
+        self.flush_bytes = flush_bytes;
+        self
+    }
+
     pub fn append_block(&mut self, block: DataBlock) {
-        self.current_bytes += block.memory_size();
+        let memory_size = block.memory_size();
+        self.current_bytes += memory_size;
+        self.buffered_bytes += memory_size;
         self.num_rows += block.num_rows();
         self.blocks.push(block);
     }
 
+    pub fn need_flush(&self) -> bool {
+        self.buffered_bytes >= self.flush_bytes
+    }
+
     pub fn over_limit(&self) -> bool {
         self.current_bytes > self.max_bytes
     }
@@ -68,21 +92,34 @@ impl ResultCacheWriter {
         instant.elapsed().as_secs() < self.min_execute_secs as u64
     }
 
-    /// Write the result cache to the storage and return the location.
+    /// Write the buffered blocks to a new file.
     #[async_backtrace::framed]
-    pub async fn write_to_storage(&self) -> Result<String> {
-        let mut buf = Vec::with_capacity(self.current_bytes);
-        let _ = blocks_to_parquet(
-            &self.schema,
-            self.blocks.clone(),
-            &mut buf,
-            TableCompression::None,
-        )?;
+    pub async fn flush(&mut self) -> Result<()> {
+        let mut buf = Vec::with_capacity(self.buffered_bytes);
+        // An empty result is written as an empty block to keep the schema.
+        let blocks = if self.blocks.is_empty() {
+            let schema = Arc::new(DataSchema::from(&self.schema));
+            vec![DataBlock::empty_with_schema(schema)]
+        } else {
+            std::mem::take(&mut self.blocks)
+        };
+        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::None)?;
 
         let file_location = format!("{}/{}.parquet", self.location, Uuid::new_v4().as_simple());
 
         self.operator.write(&file_location, buf).await?;
-        Ok(file_location)
+        self.buffered_bytes = 0;
+        self.files.push(file_location);
+        Ok(())
+    }
+
+    /// Write the remaining blocks to the storage and return the locations of all the files.
+    #[async_backtrace::framed]
+    pub async fn write_to_storage(&mut self) -> Result<Vec<String>> {
+        if !self.blocks.is_empty() || self.files.is_empty() {
+            self.flush().await?;
+        }
+        Ok(self.files.clone())
     }
 
     pub fn current_bytes(&self) -> usize {
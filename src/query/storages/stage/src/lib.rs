@@ -23,4 +23,5 @@ mod compression;
 mod read;
 mod stage_table;
 
+pub use read::row_based::BytesStream;
 pub use stage_table::StageTable;
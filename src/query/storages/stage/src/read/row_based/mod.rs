@@ -19,4 +19,5 @@ mod processors;
 mod read_pipeline;
 mod utils;
 
+pub use processors::BytesStream;
 pub use read_pipeline::RowBasedReadPipelineBuilder;
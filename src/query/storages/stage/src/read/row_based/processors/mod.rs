@@ -16,9 +16,12 @@ mod block_builder;
 mod decompressor;
 mod reader;
 mod separator;
+mod stream_reader;
 
 pub use block_builder::BlockBuilder;
 pub use block_builder::BlockBuilderState;
 pub use decompressor::Decompressor;
 pub use reader::BytesReader;
 pub use separator::Separator;
+pub use stream_reader::BytesStream;
+pub use stream_reader::BytesStreamReader;
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_sources::AsyncSource;
+use futures::AsyncRead;
+use log::debug;
+
+use super::reader::read_full;
+use crate::read::row_based::batch::BytesBatch;
+
+pub type BytesStream = Box<dyn AsyncRead + Send + Unpin>;
+
+/// Read a single file from a stream of bytes, e.g. the body of an HTTP request.
+///
+/// Unlike [`super::BytesReader`], the size of the file is unknown in advance,
+/// so one batch is read ahead to mark the last batch with `is_eof`.
+pub struct BytesStreamReader {
+    table_ctx: Arc<dyn TableContext>,
+    reader: BytesStream,
+    path: String,
+    read_batch_size: usize,
+    offset: usize,
+    read_ahead: Option<Vec<u8>>,
+    finished: bool,
+}
+
+impl BytesStreamReader {
+    pub fn create(
+        table_ctx: Arc<dyn TableContext>,
+        reader: BytesStream,
+        path: String,
+        read_batch_size: usize,
+    ) -> Self {
+        Self {
+            table_ctx,
+            reader,
+            path,
+            read_batch_size,
+            offset: 0,
+            read_ahead: None,
+            finished: false,
+        }
+    }
+
+    async fn read_chunk(&mut self) -> Result<Vec<u8>> {
+        let mut buffer = vec![0u8; self.read_batch_size];
+        let n = read_full(&mut self.reader, &mut buffer[..]).await?;
+        buffer.truncate(n);
+
+        Profile::record_usize_profile(ProfileStatisticsName::ScanBytes, n);
+        self.table_ctx
+            .get_scan_progress()
+            .incr(&ProgressValues { rows: 0, bytes: n });
+        Ok(buffer)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for BytesStreamReader {
+    const NAME: &'static str = "BytesStreamReader";
+
+    const SKIP_EMPTY_DATA_BLOCK: bool = false;
+
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let data = match self.read_ahead.take() {
+            Some(data) => data,
+            None => self.read_chunk().await?,
+        };
+
+        // A short read means the stream is drained, otherwise peek the next chunk.
+        let is_eof = if data.len() < self.read_batch_size {
+            true
+        } else {
+            let next = self.read_chunk().await?;
+            let is_eof = next.is_empty();
+            if !is_eof {
+                self.read_ahead = Some(next);
+            }
+            is_eof
+        };
+        self.finished = is_eof;
+
+        if data.is_empty() && self.offset == 0 {
+            // empty stream, nothing to load
+            return Ok(None);
+        }
+
+        debug!("read {} bytes from {}", data.len(), self.path);
+        let offset = self.offset;
+        self.offset += data.len();
+        Ok(Some(DataBlock::empty_with_meta(Box::new(BytesBatch {
+            data,
+            path: self.path.clone(),
+            offset,
+            is_eof,
+        }))))
+    }
+}
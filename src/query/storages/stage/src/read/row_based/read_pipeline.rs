@@ -13,16 +13,19 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
 use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_sources::PrefetchAsyncSourcer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
@@ -34,6 +37,8 @@ use crate::read::load_context::LoadContext;
 use crate::read::row_based::format::create_row_based_file_format;
 use crate::read::row_based::processors::BlockBuilder;
 use crate::read::row_based::processors::BytesReader;
+use crate::read::row_based::processors::BytesStream;
+use crate::read::row_based::processors::BytesStreamReader;
 use crate::read::row_based::processors::Decompressor;
 use crate::read::row_based::processors::Separator;
 
@@ -97,6 +102,44 @@ impl RowBasedReadPipelineBuilder<'_> {
         let num_sources = std::cmp::min(max_threads, plan.parts.len());
         self.build_read_stage_source(ctx.clone(), pipeline, &settings, num_sources)?;
 
+        self.build_parse_pipeline(ctx, pipeline, pos_projection, max_threads)
+    }
+
+    /// Read a single file from a stream of bytes, the rows are parsed as the bytes arrive.
+    pub fn read_stream(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        stream: BytesStream,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let batch_size = settings.get_input_read_buffer_size()? as usize;
+        let path = self.stage_table_info.files_info.path.clone();
+
+        let stream = Mutex::new(Some(stream));
+        pipeline.add_source(
+            |output| {
+                let stream = stream.lock().unwrap().take().ok_or_else(|| {
+                    ErrorCode::Internal("Bytes stream can only be read by one source.")
+                })?;
+                let reader =
+                    BytesStreamReader::create(ctx.clone(), stream, path.clone(), batch_size);
+                AsyncSourcer::create(ctx.clone(), output, reader)
+            },
+            1,
+        )?;
+
+        self.build_parse_pipeline(ctx, pipeline, None, max_threads)
+    }
+
+    fn build_parse_pipeline(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        pos_projection: Option<Vec<usize>>,
+        max_threads: usize,
+    ) -> Result<()> {
         let format =
             create_row_based_file_format(&self.stage_table_info.stage_info.file_format_params);
 
@@ -38,6 +38,7 @@ use databend_common_storages_parquet::ParquetTableForCopy;
 use databend_storages_common_stage::SingleFilePartition;
 use opendal::Operator;
 
+use crate::read::row_based::BytesStream;
 use crate::read::row_based::RowBasedReadPipelineBuilder;
 
 /// TODO: we need to track the data metrics in stage table.
@@ -120,6 +121,32 @@ impl StageTable {
             Partitions::create(PartitionsShuffleKind::Seq, partitions),
         ))
     }
+
+    /// Parse the rows of a single file from `stream` as the bytes arrive, instead of
+    /// reading the files in the stage. `files_info.path` names the stream in error messages.
+    ///
+    /// Only the row based formats can be read this way, the others need to seek the file.
+    pub fn read_stream(
+        ctx: Arc<dyn TableContext>,
+        stage_table_info: &StageTableInfo,
+        stream: BytesStream,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        match stage_table_info.stage_info.file_format_params {
+            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
+                let compact_threshold = ctx.get_read_block_thresholds();
+                RowBasedReadPipelineBuilder {
+                    stage_table_info,
+                    compact_threshold,
+                }
+                .read_stream(ctx, stream, pipeline)
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "format {} can not be read from a stream",
+                stage_table_info.stage_info.file_format_params.get_type()
+            ))),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -35,8 +35,8 @@ use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CacheManager;
 use databend_storages_common_cache::CacheValue;
 use databend_storages_common_cache::InMemoryLruCache;
+use databend_storages_common_cache::TableDataCache;
 use databend_storages_common_cache::Unit;
-use databend_storages_common_cache::DISK_TABLE_DATA_CACHE_NAME;
 
 use crate::SyncOneBlockSystemTable;
 use crate::SyncSystemTable;
@@ -79,30 +79,48 @@ impl SyncSystemTable for CachesTable {
         let block_meta_cache = cache_manager.get_block_meta_cache();
         let inverted_index_meta_cache = cache_manager.get_inverted_index_meta_cache();
         let inverted_index_file_cache = cache_manager.get_inverted_index_file_cache();
+        let virtual_column_index_cache = cache_manager.get_virtual_column_index_cache();
         let prune_partitions_cache = cache_manager.get_prune_partitions_cache();
         let file_meta_data_cache = cache_manager.get_file_meta_data_cache();
         let table_data_cache = cache_manager.get_table_data_cache();
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
+        let table_meta_disk_cache = cache_manager.get_table_meta_disk_cache();
 
         let mut columns = CachesTableColumns::default();
 
         if let Some(table_snapshot_cache) = table_snapshot_cache {
-            Self::append_row(&table_snapshot_cache, &local_node, &mut columns);
+            Self::append_row(
+                table_snapshot_cache.in_memory_cache(),
+                &local_node,
+                &mut columns,
+            );
         }
         if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
             Self::append_row(&table_snapshot_statistic_cache, &local_node, &mut columns);
         }
 
         if let Some(segment_info_cache) = segment_info_cache {
-            Self::append_row(&segment_info_cache, &local_node, &mut columns);
+            Self::append_row(
+                segment_info_cache.in_memory_cache(),
+                &local_node,
+                &mut columns,
+            );
         }
 
         if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
-            Self::append_row(&bloom_index_filter_cache, &local_node, &mut columns);
+            Self::append_row(
+                bloom_index_filter_cache.in_memory_cache(),
+                &local_node,
+                &mut columns,
+            );
         }
 
         if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
-            Self::append_row(&bloom_index_meta_cache, &local_node, &mut columns);
+            Self::append_row(
+                bloom_index_meta_cache.in_memory_cache(),
+                &local_node,
+                &mut columns,
+            );
         }
 
         if let Some(block_meta_cache) = block_meta_cache {
@@ -114,7 +132,15 @@ impl SyncSystemTable for CachesTable {
         }
 
         if let Some(inverted_index_file_cache) = inverted_index_file_cache {
-            Self::append_row(&inverted_index_file_cache, &local_node, &mut columns);
+            Self::append_row(
+                inverted_index_file_cache.in_memory_cache(),
+                &local_node,
+                &mut columns,
+            );
+        }
+
+        if let Some(virtual_column_index_cache) = virtual_column_index_cache {
+            Self::append_row(&virtual_column_index_cache, &local_node, &mut columns);
         }
 
         if let Some(prune_partitions_cache) = prune_partitions_cache {
@@ -126,19 +152,11 @@ impl SyncSystemTable for CachesTable {
         }
 
         if let Some(cache) = table_data_cache {
-            // table data cache is not a named cache yet
-            columns.nodes.push(local_node.clone());
-            columns.names.push(DISK_TABLE_DATA_CACHE_NAME.to_string());
-            columns.num_items.push(cache.len() as u64);
-            columns.size.push(cache.bytes_size());
-            columns.capacity.push(cache.bytes_capacity());
-            columns.unit.push(Unit::Bytes.to_string());
-            let access = get_cache_access_count(DISK_TABLE_DATA_CACHE_NAME);
-            let hit = get_cache_hit_count(DISK_TABLE_DATA_CACHE_NAME);
-            let miss = get_cache_miss_count(DISK_TABLE_DATA_CACHE_NAME);
-            columns.access.push(access);
-            columns.hit.push(hit);
-            columns.miss.push(miss);
+            Self::append_disk_cache_row(&cache, &local_node, &mut columns);
+        }
+
+        if let Some(cache) = table_meta_disk_cache {
+            Self::append_disk_cache_row(&cache, &local_node, &mut columns);
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
@@ -217,4 +235,25 @@ impl CachesTable {
         columns.hit.push(hit);
         columns.miss.push(miss);
     }
+
+    fn append_disk_cache_row(
+        cache: &TableDataCache,
+        local_node: &str,
+        columns: &mut CachesTableColumns,
+    ) {
+        columns.nodes.push(local_node.to_string());
+        columns.names.push(cache.name().to_string());
+        columns.num_items.push(cache.len() as u64);
+        columns.size.push(cache.bytes_size());
+        columns.capacity.push(cache.bytes_capacity());
+        columns.unit.push(Unit::Bytes.to_string());
+
+        let access = get_cache_access_count(cache.name());
+        let hit = get_cache_hit_count(cache.name());
+        let miss = get_cache_miss_count(cache.name());
+
+        columns.access.push(access);
+        columns.hit.push(hit);
+        columns.miss.push(miss);
+    }
 }
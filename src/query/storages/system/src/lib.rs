@@ -65,6 +65,7 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod workload_groups_table;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -127,3 +128,4 @@ pub use temporary_tables_table::TemporaryTablesTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use workload_groups_table::WorkloadGroupsTable;
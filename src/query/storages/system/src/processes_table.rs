@@ -74,6 +74,8 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_created_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_queue_position = Vec::with_capacity(processes_info.len());
+        let mut processes_queued_duration_ms = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -115,6 +117,10 @@ impl SyncSystemTable for ProcessesTable {
 
             // Status info.
             processes_status.push(process_info.status_info.clone().unwrap_or("".to_owned()));
+
+            // Queue info.
+            processes_queue_position.push(process_info.queue_position);
+            processes_queued_duration_ms.push(process_info.queued_duration.as_millis() as u64);
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -135,6 +141,8 @@ impl SyncSystemTable for ProcessesTable {
             UInt64Type::from_data(processes_time),
             TimestampType::from_data(processes_created_time),
             StringType::from_data(processes_status),
+            UInt64Type::from_opt_data(processes_queue_position),
+            UInt64Type::from_data(processes_queued_duration_ms),
         ]))
     }
 }
@@ -177,6 +185,14 @@ impl ProcessesTable {
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("created_time", TableDataType::Timestamp),
             TableField::new("status", TableDataType::String),
+            TableField::new(
+                "queue_position",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "queued_duration_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &Tenant,
+        workload_group: WorkloadGroup,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.workload_group_api(tenant);
+        client.add(workload_group, create_option).await?;
+        Ok(())
+    }
+
+    // Update the quotas of a workload group, `None` keeps the current value.
+    #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        cpu_quota: Option<u64>,
+        memory_quota: Option<u64>,
+        max_concurrency: Option<u64>,
+        queue_timeout_secs: Option<u64>,
+        if_exists: bool,
+    ) -> Result<Option<u64>> {
+        let client = self.workload_group_api(tenant);
+        let seq_workload_group = match client.get(name, MatchSeq::GE(0)).await {
+            Ok(seq_workload_group) => seq_workload_group,
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    return Err(
+                        ErrorCode::from(meta_err).add_message_back(" (while alter workload group)")
+                    );
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        return Ok(None);
+                    } else {
+                        return Err(ErrorCode::from(unknown)
+                            .add_message_back(" (while alter workload group)"));
+                    }
+                }
+            },
+        };
+
+        let seq = seq_workload_group.seq;
+        let mut workload_group = seq_workload_group.data;
+        if let Some(cpu_quota) = cpu_quota {
+            workload_group.cpu_quota = cpu_quota;
+        }
+        if let Some(memory_quota) = memory_quota {
+            workload_group.memory_quota = memory_quota;
+        }
+        if let Some(max_concurrency) = max_concurrency {
+            workload_group.max_concurrency = max_concurrency;
+        }
+        if let Some(queue_timeout_secs) = queue_timeout_secs {
+            workload_group.queue_timeout_secs = queue_timeout_secs;
+        }
+        workload_group.update_on = Some(Utc::now());
+
+        match client.update(workload_group, MatchSeq::Exact(seq)).await {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                Err(e.add_message_back(" (while alter workload group)."))
+            }
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if let Some(workload_group) = user_info.option.workload_group() {
+                if workload_group == name {
+                    return Err(ErrorCode::WorkloadGroupIsUsedByUser(format!(
+                        "workload group `{}` is used by user",
+                        name,
+                    )));
+                }
+            }
+        }
+
+        let role_infos = self.get_roles(tenant).await?;
+        for role_info in role_infos {
+            if role_info.workload_group.as_deref() == Some(name) {
+                return Err(ErrorCode::WorkloadGroupIsUsedByRole(format!(
+                    "workload group `{}` is used by role `{}`",
+                    name, role_info.name,
+                )));
+            }
+        }
+
+        let client = self.workload_group_api(tenant);
+        match client.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    return Err(
+                        ErrorCode::from(meta_err).add_message_back(" (while drop workload group)")
+                    );
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        return Ok(());
+                    } else {
+                        return Err(ErrorCode::from(unknown)
+                            .add_message_back(" (while drop workload group)"));
+                    }
+                }
+            },
+        }
+    }
+
+    // Get a workload group by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &Tenant, name: &str) -> Result<WorkloadGroup> {
+        let client = self.workload_group_api(tenant);
+        let workload_group = client.get(name, MatchSeq::GE(0)).await?.data;
+        Ok(workload_group)
+    }
+
+    // Get all workload groups by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &Tenant) -> Result<Vec<WorkloadGroup>> {
+        let client = self.workload_group_api(tenant);
+        let workload_groups = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get workload groups).")
+        })?;
+        Ok(workload_groups)
+    }
+}
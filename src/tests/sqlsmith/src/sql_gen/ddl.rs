@@ -43,6 +43,10 @@ const SIMPLE_COLUMN_TYPES: [TypeName; 21] = [
     TypeName::Int16,
     TypeName::Int32,
     TypeName::Int64,
+    TypeName::Int128,
+    TypeName::UInt128,
+    TypeName::Int256,
+    TypeName::UInt256,
     TypeName::Float32,
     TypeName::Float64,
     TypeName::Decimal {
@@ -61,6 +65,10 @@ const SIMPLE_COLUMN_TYPES: [TypeName; 21] = [
     TypeName::Binary,
     TypeName::Geometry,
     TypeName::Geography,
+    TypeName::Interval,
+    TypeName::Time,
+    TypeName::TimestampTz,
+    TypeName::Uuid,
 ];
 
 impl<'a, R: Rng> SqlGenerator<'a, R> {
@@ -230,7 +238,11 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
         | TypeName::Int8
         | TypeName::Int16
         | TypeName::Int32
-        | TypeName::Int64 => Expr::Literal {
+        | TypeName::Int64
+        | TypeName::Int128
+        | TypeName::UInt128
+        | TypeName::Int256
+        | TypeName::UInt256 => Expr::Literal {
             span: None,
             value: Literal::UInt64(0),
         },
@@ -290,6 +302,35 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("POINT(0 0)".to_string()),
         },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            value: Literal::String("00:00:00".to_string()),
+        },
+        TypeName::Time => Expr::Literal {
+            span: None,
+            value: Literal::String("00:00:00".to_string()),
+        },
+        TypeName::TimestampTz => Expr::Literal {
+            span: None,
+            value: Literal::String("1970-01-01 00:00:00+00:00".to_string()),
+        },
+        TypeName::Vector(dimension) => Expr::Array {
+            span: None,
+            exprs: (0..*dimension)
+                .map(|_| Expr::Literal {
+                    span: None,
+                    value: Literal::Float64(0.0),
+                })
+                .collect(),
+        },
+        TypeName::Enum(values) => Expr::Literal {
+            span: None,
+            value: Literal::String(values[0].clone()),
+        },
+        TypeName::Uuid => Expr::Literal {
+            span: None,
+            value: Literal::String("00000000-0000-0000-0000-000000000000".to_string()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             value: Literal::Null,
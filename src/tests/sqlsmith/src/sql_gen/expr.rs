@@ -28,6 +28,7 @@ use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UnaryOperator;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::LargeIntDataType;
 use databend_common_expression::types::NumberDataType;
 use ethnum::I256;
 use rand::distributions::Alphanumeric;
@@ -791,6 +792,10 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         DataType::Number(NumberDataType::Int16) => TypeName::Int16,
         DataType::Number(NumberDataType::Int32) => TypeName::Int32,
         DataType::Number(NumberDataType::Int64) => TypeName::Int64,
+        DataType::LargeInt(LargeIntDataType::Int128) => TypeName::Int128,
+        DataType::LargeInt(LargeIntDataType::UInt128) => TypeName::UInt128,
+        DataType::LargeInt(LargeIntDataType::Int256) => TypeName::Int256,
+        DataType::LargeInt(LargeIntDataType::UInt256) => TypeName::UInt256,
         DataType::Number(NumberDataType::Float32) => TypeName::Float32,
         DataType::Number(NumberDataType::Float64) => TypeName::Float64,
         DataType::Decimal(DecimalDataType::Decimal128(size)) => TypeName::Decimal {
@@ -808,6 +813,7 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         DataType::Variant => TypeName::Variant,
         DataType::Binary => TypeName::Binary,
         DataType::Geometry => TypeName::Geometry,
+        DataType::Uuid => TypeName::Uuid,
         DataType::Nullable(box inner_ty) => {
             TypeName::Nullable(Box::new(convert_to_type_name(inner_ty)))
         }